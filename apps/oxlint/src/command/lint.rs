@@ -1,7 +1,7 @@
 use std::{path::PathBuf, str::FromStr};
 
 use bpaf::Bpaf;
-use oxc_linter::AllowWarnDeny;
+use oxc_linter::{AllowWarnDeny, FixKind};
 
 use super::{
     expand_glob,
@@ -52,6 +52,16 @@ pub struct LintOptions {
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
 
+    /// Print the extended explanation of a parser or semantic diagnostic code,
+    /// e.g. `oxc(parse::unexpected_token)`
+    #[bpaf(argument("CODE"), hide_usage)]
+    pub explain: Option<String>,
+
+    /// Convert an ESLint configuration file to `.oxlintrc.json`, print it,
+    /// and report the rules which are not supported
+    #[bpaf(argument("./.eslintrc.json"), hide_usage)]
+    pub migrate_eslint: Option<PathBuf>,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
@@ -69,6 +79,16 @@ pub struct BasicOptions {
     #[bpaf(long, short, argument("./oxlintrc.json"))]
     pub config: Option<PathBuf>,
 
+    /// Use the nearest `.oxlintrc.json` in the directory of each linted file or its parents
+    /// instead of the `--config` file.
+    #[bpaf(switch, hide_usage)]
+    pub nested_config: bool,
+
+    /// Merge the `.oxlintrc.json` files of all parent directories on top of the `--config` file,
+    /// up to the first one with `"root": true`. Implies `--nested-config`.
+    #[bpaf(switch, hide_usage)]
+    pub cascade_config: bool,
+
     /// TypeScript `tsconfig.json` path for reading path alias and project references for import plugin
     #[bpaf(argument("./tsconfig.json"), hide_usage)]
     pub tsconfig: Option<PathBuf>,
@@ -123,9 +143,43 @@ impl LintFilter {
 /// Fix Problems
 #[derive(Debug, Clone, Bpaf)]
 pub struct FixOptions {
-    /// Fix as many issues as possible. Only unfixed issues are reported in the output
-    #[bpaf(switch)]
+    /// Fix as many issues as possible with safe fixes, which never change the behavior of the
+    /// code. Only unfixed issues are reported in the output
+    #[bpaf(long("fix"), long("fix-safe"), switch)]
     pub fix: bool,
+
+    /// Apply suggested fixes, which may change the behavior of the code
+    #[bpaf(switch)]
+    pub fix_suggestions: bool,
+
+    /// Apply dangerous fixes, which change the behavior of the code
+    #[bpaf(switch)]
+    pub fix_dangerously: bool,
+
+    /// Print the changes the fixes would make as JSON, without writing any files.
+    /// Safe fixes are used unless another kind is selected
+    #[bpaf(switch)]
+    pub fix_dry_run: bool,
+
+    /// Print the changes of `--fix-dry-run` as unified diffs instead of JSON
+    #[bpaf(switch)]
+    pub diff: bool,
+}
+
+impl FixOptions {
+    /// Whether changes are printed instead of written, `--diff` implies `--fix-dry-run`.
+    pub fn is_dry_run(&self) -> bool {
+        self.fix_dry_run || self.diff
+    }
+
+    /// The kinds of fixes selected by `--fix`, `--fix-suggestions` and `--fix-dangerously`.
+    pub fn fix_kind(&self) -> FixKind {
+        let mut kind = FixKind::empty();
+        kind.set(FixKind::Safe, self.fix);
+        kind.set(FixKind::Suggestion, self.fix_suggestions);
+        kind.set(FixKind::Dangerous, self.fix_dangerously);
+        kind
+    }
 }
 
 /// Handle Warnings
@@ -222,6 +276,10 @@ pub struct EnablePlugins {
     /// Enable the React performance plugin and detect rendering performance problems
     #[bpaf(switch, hide_usage)]
     pub react_perf_plugin: bool,
+
+    /// Enable the promise plugin and detect promise usage problems
+    #[bpaf(switch, hide_usage)]
+    pub promise_plugin: bool,
 }
 
 #[cfg(test)]
@@ -260,7 +318,7 @@ mod lint_options {
 
     use oxc_linter::AllowWarnDeny;
 
-    use super::{lint_command, LintOptions, OutputFormat};
+    use super::{lint_command, FixKind, LintOptions, OutputFormat};
 
     fn get_lint_options(arg: &str) -> LintOptions {
         let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -335,6 +393,30 @@ mod lint_options {
     fn fix() {
         let options = get_lint_options("--fix test.js");
         assert!(options.fix_options.fix);
+        assert!(!options.fix_options.is_dry_run());
+    }
+
+    #[test]
+    fn fix_kind() {
+        let options = get_lint_options("--fix test.js");
+        assert_eq!(options.fix_options.fix_kind(), FixKind::Safe);
+        let options = get_lint_options("--fix-safe test.js");
+        assert_eq!(options.fix_options.fix_kind(), FixKind::Safe);
+        let options = get_lint_options("--fix-suggestions --fix-dangerously test.js");
+        assert_eq!(options.fix_options.fix_kind(), FixKind::Suggestion | FixKind::Dangerous);
+        let options = get_lint_options("--fix-dry-run test.js");
+        assert_eq!(options.fix_options.fix_kind(), FixKind::empty());
+    }
+
+    #[test]
+    fn fix_dry_run() {
+        let options = get_lint_options("--fix-dry-run test.js");
+        assert!(options.fix_options.is_dry_run());
+        assert!(!options.fix_options.diff);
+
+        let options = get_lint_options("--fix-dry-run --diff test.js");
+        assert!(options.fix_options.is_dry_run());
+        assert!(options.fix_options.diff);
     }
 
     #[test]
@@ -373,4 +455,16 @@ mod lint_options {
         let options = get_lint_options("--rules");
         assert!(options.list_rules);
     }
+
+    #[test]
+    fn explain() {
+        let options = get_lint_options("--explain parse::unexpected_token");
+        assert_eq!(options.explain, Some("parse::unexpected_token".to_string()));
+    }
+
+    #[test]
+    fn migrate_eslint() {
+        let options = get_lint_options("--migrate-eslint .eslintrc.json");
+        assert_eq!(options.migrate_eslint, Some(PathBuf::from(".eslintrc.json")));
+    }
 }
@@ -1,9 +1,15 @@
 use ignore::gitignore::Gitignore;
-use std::{env, io::BufWriter, time::Instant};
+use std::{
+    env,
+    io::{BufWriter, Write},
+    path::Path,
+    time::Instant,
+};
 
-use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler};
+use oxc_diagnostics::{DiagnosticService, Error, GraphicalReportHandler};
 use oxc_linter::{
-    partial_loader::LINT_PARTIAL_LOADER_EXT, LintOptions, LintService, LintServiceOptions, Linter,
+    partial_loader::LINT_PARTIAL_LOADER_EXT, EslintMigration, LintOptions, LintService,
+    LintServiceOptions, Linter,
 };
 use oxc_span::VALID_EXTENSIONS;
 
@@ -31,6 +37,20 @@ impl Runner for LintRunner {
             return CliRunResult::None;
         }
 
+        if let Some(code) = &self.options.explain {
+            let mut stdout = BufWriter::new(std::io::stdout());
+            if Linter::print_explanation(&mut stdout, code) {
+                return CliRunResult::None;
+            }
+            return CliRunResult::InvalidOptions {
+                message: format!("Unknown diagnostic code `{code}`."),
+            };
+        }
+
+        if let Some(path) = &self.options.migrate_eslint {
+            return Self::migrate_eslint(path);
+        }
+
         let CliLintOptions {
             paths,
             filter,
@@ -93,7 +113,10 @@ impl Runner for LintRunner {
         let lint_options = LintOptions::default()
             .with_filter(filter)
             .with_config_path(basic_options.config)
-            .with_fix(fix_options.fix)
+            .with_nested_config(basic_options.nested_config)
+            .with_cascade_config(basic_options.cascade_config)
+            .with_fix(fix_options.fix_kind())
+            .with_fix_dry_run(fix_options.is_dry_run())
             .with_react_plugin(enable_plugins.react_plugin)
             .with_unicorn_plugin(enable_plugins.unicorn_plugin)
             .with_typescript_plugin(enable_plugins.typescript_plugin)
@@ -103,7 +126,8 @@ impl Runner for LintRunner {
             .with_jest_plugin(enable_plugins.jest_plugin)
             .with_jsx_a11y_plugin(enable_plugins.jsx_a11y_plugin)
             .with_nextjs_plugin(enable_plugins.nextjs_plugin)
-            .with_react_perf_plugin(enable_plugins.react_perf_plugin);
+            .with_react_perf_plugin(enable_plugins.react_perf_plugin)
+            .with_promise_plugin(enable_plugins.promise_plugin);
 
         let linter = match Linter::from_options(lint_options) {
             Ok(lint_service) => lint_service,
@@ -143,6 +167,10 @@ impl Runner for LintRunner {
         });
         diagnostic_service.run();
 
+        if fix_options.is_dry_run() {
+            Self::print_patches(&lint_service, fix_options.diff);
+        }
+
         CliRunResult::LintResult(LintResult {
             duration: now.elapsed(),
             number_of_rules: lint_service.linter().number_of_rules(),
@@ -157,6 +185,53 @@ impl Runner for LintRunner {
 }
 
 impl LintRunner {
+    /// Print the `.oxlintrc.json` converted from the ESLint configuration at `path`, and report
+    /// what is not converted on stderr.
+    fn migrate_eslint(path: &Path) -> CliRunResult {
+        let migration = match EslintMigration::from_file(path) {
+            Ok(migration) => migration,
+            Err(diagnostic) => {
+                let handler = GraphicalReportHandler::new();
+                let mut err = String::new();
+                handler.render_report(&mut err, Error::from(diagnostic).as_ref()).unwrap();
+                eprintln!("{err}");
+                return CliRunResult::InvalidOptions {
+                    message: "Failed to migrate the ESLint configuration.".to_string(),
+                };
+            }
+        };
+        println!("{:#}", migration.config);
+        for rule in &migration.unsupported_rules {
+            if rule.alternatives.is_empty() {
+                eprintln!("Unsupported rule `{}`.", rule.name);
+            } else {
+                let alternatives = rule.alternatives.iter().map(|name| format!("`{name}`"));
+                let alternatives = alternatives.collect::<Vec<_>>().join(", ");
+                eprintln!("Unsupported rule `{}`, consider {alternatives}.", rule.name);
+            }
+        }
+        for note in &migration.notes {
+            eprintln!("{note}");
+        }
+        CliRunResult::None
+    }
+
+    /// Print the changes collected by `--fix-dry-run`.
+    fn print_patches(lint_service: &LintService, diff: bool) {
+        let patches = lint_service.take_patches();
+        let mut stdout = BufWriter::new(std::io::stdout());
+        if diff {
+            for patch in &patches {
+                stdout.write_all(patch.to_unified_diff().as_bytes()).unwrap();
+            }
+        } else {
+            let patches =
+                patches.iter().map(|patch| patch.to_json().to_string()).collect::<Vec<_>>();
+            writeln!(stdout, "[{}]", patches.join(",")).unwrap();
+        }
+        stdout.flush().unwrap();
+    }
+
     fn get_diagnostic_service(
         warning_options: &WarningOptions,
         output_options: &OutputOptions,
@@ -233,6 +308,35 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn fix_dry_run() {
+        let args = &[
+            "--fix-dry-run",
+            "--fix-suggestions",
+            "--diff",
+            "-D",
+            "correctness",
+            "fixtures/linter/debugger.js",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        // The fixed issue is still reported, and the file is not written.
+        assert_eq!(result.number_of_errors, 1);
+        let source_text = std::fs::read_to_string("fixtures/linter/debugger.js").unwrap();
+        assert_eq!(source_text, "debugger;\n");
+    }
+
+    #[test]
+    fn fix_kinds() {
+        // Removing `debugger` is a suggestion, which is only applied with `--fix-suggestions`.
+        let args = &["--fix-dry-run", "--diff", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+        let args = &["--fix-dry-run", "--fix-dangerously", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+    }
+
     #[test]
     fn multi_files() {
         let args = &["fixtures/linter/debugger.js", "fixtures/linter/nan.js"];
@@ -332,6 +436,24 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn nested_config() {
+        let args = &["--nested-config", "fixtures/nested_config"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 3);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 2);
+    }
+
+    #[test]
+    fn cascade_config() {
+        let args = &["--cascade-config", "fixtures/nested_config"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 3);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 3);
+    }
+
     #[test]
     fn eslintrc_no_undef() {
         let args = &[
@@ -487,4 +609,16 @@ mod test {
         assert!(test_invalid_options(&["--tsconfig", "oxc/tsconfig.json"])
             .contains("oxc/tsconfig.json\" does not exist, Please provide a valid tsconfig file."));
     }
+
+    #[test]
+    fn migrate_eslint_missing_file() {
+        let args = &["--migrate-eslint", "fixtures/missing/.eslintrc.json"];
+        assert_eq!(test_invalid_options(args), "Failed to migrate the ESLint configuration.");
+    }
+
+    #[test]
+    fn explain_unknown_code() {
+        let args = &["--explain", "oxc(parse::unknown)"];
+        assert_eq!(test_invalid_options(args), "Unknown diagnostic code `oxc(parse::unknown)`.");
+    }
 }
@@ -7,7 +7,7 @@
 use std::{cell::Cell, fmt, hash::Hash};
 
 use oxc_allocator::{Box, Vec};
-use oxc_ast_macros::visited_node;
+use oxc_ast_macros::{visited_node, CloneIn};
 use oxc_span::{Atom, CompactStr, SourceType, Span};
 use oxc_syntax::{
     operator::{
@@ -46,7 +46,7 @@ export interface FormalParameterRest extends Span {
     scope(ScopeFlags::Top),
     strict_if(self.source_type.is_strict() || self.directives.iter().any(Directive::is_use_strict))
 )]
-#[derive(Debug)]
+#[derive(Debug, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Program<'a> {
@@ -99,7 +99,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum Expression<'a> {
@@ -423,7 +423,7 @@ impl<'a> Expression<'a> {
 
 /// Identifier Name
 #[visited_node]
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename = "Identifier"))]
 pub struct IdentifierName<'a> {
@@ -440,7 +440,7 @@ impl<'a> IdentifierName<'a> {
 
 /// Identifier Reference
 #[visited_node]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename = "Identifier"))]
 pub struct IdentifierReference<'a> {
@@ -468,7 +468,7 @@ impl<'a> IdentifierReference<'a> {
 
 /// Binding Identifier
 #[visited_node]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename = "Identifier"))]
 pub struct BindingIdentifier<'a> {
@@ -494,7 +494,7 @@ impl<'a> BindingIdentifier<'a> {
 
 /// Label Identifier
 #[visited_node]
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename = "Identifier"))]
 pub struct LabelIdentifier<'a> {
@@ -505,7 +505,7 @@ pub struct LabelIdentifier<'a> {
 
 /// This Expression
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ThisExpression {
@@ -515,7 +515,7 @@ pub struct ThisExpression {
 
 /// <https://tc39.es/ecma262/#prod-ArrayLiteral>
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ArrayExpression<'a> {
@@ -537,7 +537,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ArrayExpressionElement<'a> {
@@ -559,14 +559,14 @@ impl<'a> ArrayExpressionElement<'a> {
 /// Array Expression Elision Element
 /// Serialized as `null` in JSON AST. See `serialize.rs`.
 #[visited_node]
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn)]
 pub struct Elision {
     pub span: Span,
 }
 
 /// Object Expression
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ObjectExpression<'a> {
@@ -585,7 +585,7 @@ impl<'a> ObjectExpression<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ObjectPropertyKind<'a> {
@@ -594,7 +594,7 @@ pub enum ObjectPropertyKind<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ObjectProperty<'a> {
@@ -617,7 +617,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum PropertyKey<'a> {
@@ -683,7 +683,7 @@ impl<'a> PropertyKey<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
 pub enum PropertyKind {
@@ -696,7 +696,7 @@ pub enum PropertyKind {
 ///
 /// This is interpreted by interleaving the expression elements in between the quasi elements.
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TemplateLiteral<'a> {
@@ -718,7 +718,7 @@ impl<'a> TemplateLiteral<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TaggedTemplateExpression<'a> {
@@ -730,7 +730,7 @@ pub struct TaggedTemplateExpression<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TemplateElement<'a> {
@@ -741,7 +741,7 @@ pub struct TemplateElement<'a> {
 }
 
 /// See [template-strings-cooked-vs-raw](https://exploringjs.com/impatient-js/ch_template-literals.html#template-strings-cooked-vs-raw)
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct TemplateElementValue<'a> {
     /// A raw interpretation where backslashes do not have special meaning.
@@ -758,7 +758,7 @@ pub struct TemplateElementValue<'a> {
 /// <https://tc39.es/ecma262/#prod-MemberExpression>
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum MemberExpression<'a> {
@@ -866,7 +866,7 @@ impl<'a> MemberExpression<'a> {
 
 /// `MemberExpression[?Yield, ?Await] [ Expression[+In, ?Yield, ?Await] ]`
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ComputedMemberExpression<'a> {
@@ -879,7 +879,7 @@ pub struct ComputedMemberExpression<'a> {
 
 /// `MemberExpression[?Yield, ?Await] . IdentifierName`
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct StaticMemberExpression<'a> {
@@ -892,7 +892,7 @@ pub struct StaticMemberExpression<'a> {
 
 /// `MemberExpression[?Yield, ?Await] . PrivateIdentifier`
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct PrivateFieldExpression<'a> {
@@ -905,7 +905,7 @@ pub struct PrivateFieldExpression<'a> {
 
 /// Call Expression
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct CallExpression<'a> {
@@ -967,7 +967,7 @@ impl<'a> CallExpression<'a> {
 
 /// New Expression
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct NewExpression<'a> {
@@ -980,7 +980,7 @@ pub struct NewExpression<'a> {
 
 /// Meta Property `new.target` | `import.meta`
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct MetaProperty<'a> {
@@ -992,7 +992,7 @@ pub struct MetaProperty<'a> {
 
 /// Spread Element
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct SpreadElement<'a> {
@@ -1009,7 +1009,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum Argument<'a> {
@@ -1027,7 +1027,7 @@ impl Argument<'_> {
 
 /// Update Expression
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct UpdateExpression<'a> {
@@ -1040,7 +1040,7 @@ pub struct UpdateExpression<'a> {
 
 /// Unary Expression
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct UnaryExpression<'a> {
@@ -1052,7 +1052,7 @@ pub struct UnaryExpression<'a> {
 
 /// Binary Expression
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct BinaryExpression<'a> {
@@ -1065,7 +1065,7 @@ pub struct BinaryExpression<'a> {
 
 /// Private Identifier in Shift Expression
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct PrivateInExpression<'a> {
@@ -1078,7 +1078,7 @@ pub struct PrivateInExpression<'a> {
 
 /// Binary Logical Operators
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct LogicalExpression<'a> {
@@ -1091,7 +1091,7 @@ pub struct LogicalExpression<'a> {
 
 /// Conditional Expression
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ConditionalExpression<'a> {
@@ -1104,7 +1104,7 @@ pub struct ConditionalExpression<'a> {
 
 /// Assignment Expression
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct AssignmentExpression<'a> {
@@ -1124,7 +1124,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum AssignmentTarget<'a> {
@@ -1169,7 +1169,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum SimpleAssignmentTarget<'a> {
@@ -1224,7 +1224,7 @@ impl<'a> SimpleAssignmentTarget<'a> {
 
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum AssignmentTargetPattern<'a> {
@@ -1243,7 +1243,7 @@ pub use match_assignment_target_pattern;
 
 // See serializer in serialize.rs
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ArrayAssignmentTarget<'a> {
@@ -1271,7 +1271,7 @@ impl<'a> ArrayAssignmentTarget<'a> {
 
 // See serializer in serialize.rs
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ObjectAssignmentTarget<'a> {
@@ -1304,7 +1304,7 @@ impl<'a> ObjectAssignmentTarget<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename = "RestElement"))]
 pub struct AssignmentTargetRest<'a> {
@@ -1322,7 +1322,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum AssignmentTargetMaybeDefault<'a> {
@@ -1349,7 +1349,7 @@ impl<'a> AssignmentTargetMaybeDefault<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct AssignmentTargetWithDefault<'a> {
@@ -1360,7 +1360,7 @@ pub struct AssignmentTargetWithDefault<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum AssignmentTargetProperty<'a> {
@@ -1370,7 +1370,7 @@ pub enum AssignmentTargetProperty<'a> {
 
 /// Assignment Property - Identifier Reference
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct AssignmentTargetPropertyIdentifier<'a> {
@@ -1382,7 +1382,7 @@ pub struct AssignmentTargetPropertyIdentifier<'a> {
 
 /// Assignment Property - Property Name
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct AssignmentTargetPropertyProperty<'a> {
@@ -1394,7 +1394,7 @@ pub struct AssignmentTargetPropertyProperty<'a> {
 
 /// Sequence Expression
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct SequenceExpression<'a> {
@@ -1404,7 +1404,7 @@ pub struct SequenceExpression<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct Super {
@@ -1414,7 +1414,7 @@ pub struct Super {
 
 /// Await Expression
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct AwaitExpression<'a> {
@@ -1424,7 +1424,7 @@ pub struct AwaitExpression<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ChainExpression<'a> {
@@ -1441,7 +1441,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ChainElement<'a> {
@@ -1453,7 +1453,7 @@ pub enum ChainElement<'a> {
 
 /// Parenthesized Expression
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ParenthesizedExpression<'a> {
@@ -1471,7 +1471,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum Statement<'a> {
@@ -1516,7 +1516,7 @@ impl<'a> Statement<'a> {
 
 /// Directive Prologue
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct Directive<'a> {
@@ -1539,7 +1539,7 @@ impl<'a> Directive<'a> {
 
 /// Hashbang
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct Hashbang<'a> {
@@ -1550,7 +1550,7 @@ pub struct Hashbang<'a> {
 
 /// Block Statement
 #[visited_node(scope(ScopeFlags::empty()))]
-#[derive(Debug)]
+#[derive(Debug, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct BlockStatement<'a> {
@@ -1576,7 +1576,7 @@ impl<'a> Hash for BlockStatement<'a> {
 /// Declarations and the Variable Statement
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum Declaration<'a> {
@@ -1647,7 +1647,7 @@ impl<'a> Declaration<'a> {
 
 /// Variable Declaration
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct VariableDeclaration<'a> {
@@ -1665,7 +1665,7 @@ impl<'a> VariableDeclaration<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
 pub enum VariableDeclarationKind {
@@ -1704,7 +1704,7 @@ impl fmt::Display for VariableDeclarationKind {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct VariableDeclarator<'a> {
@@ -1720,7 +1720,7 @@ pub struct VariableDeclarator<'a> {
 /// Using Declaration
 /// * <https://github.com/tc39/proposal-explicit-resource-management>
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct UsingDeclaration<'a> {
@@ -1733,7 +1733,7 @@ pub struct UsingDeclaration<'a> {
 
 /// Empty Statement
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct EmptyStatement {
@@ -1743,7 +1743,7 @@ pub struct EmptyStatement {
 
 /// Expression Statement
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ExpressionStatement<'a> {
@@ -1754,7 +1754,7 @@ pub struct ExpressionStatement<'a> {
 
 /// If Statement
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct IfStatement<'a> {
@@ -1767,7 +1767,7 @@ pub struct IfStatement<'a> {
 
 /// Do-While Statement
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct DoWhileStatement<'a> {
@@ -1779,7 +1779,7 @@ pub struct DoWhileStatement<'a> {
 
 /// While Statement
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct WhileStatement<'a> {
@@ -1794,7 +1794,7 @@ pub struct WhileStatement<'a> {
     scope(ScopeFlags::empty()),
     scope_if(self.init.as_ref().is_some_and(ForStatementInit::is_lexical_declaration))
 )]
-#[derive(Debug)]
+#[derive(Debug, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ForStatement<'a> {
@@ -1837,7 +1837,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ForStatementInit<'a> {
@@ -1858,7 +1858,7 @@ impl<'a> ForStatementInit<'a> {
 
 /// For-In Statement
 #[visited_node(scope(ScopeFlags::empty()), scope_if(self.left.is_lexical_declaration()))]
-#[derive(Debug)]
+#[derive(Debug, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ForInStatement<'a> {
@@ -1892,7 +1892,7 @@ impl<'a> Hash for ForInStatement<'a> {
 
 /// For-Of Statement
 #[visited_node(scope(ScopeFlags::empty()), scope_if(self.left.is_lexical_declaration()))]
-#[derive(Debug)]
+#[derive(Debug, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ForOfStatement<'a> {
@@ -1935,7 +1935,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ForStatementLeft<'a> {
@@ -1956,7 +1956,7 @@ impl<'a> ForStatementLeft<'a> {
 
 /// Continue Statement
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ContinueStatement<'a> {
@@ -1967,7 +1967,7 @@ pub struct ContinueStatement<'a> {
 
 /// Break Statement
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct BreakStatement<'a> {
@@ -1978,7 +1978,7 @@ pub struct BreakStatement<'a> {
 
 /// Return Statement
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ReturnStatement<'a> {
@@ -1989,7 +1989,7 @@ pub struct ReturnStatement<'a> {
 
 /// With Statement
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct WithStatement<'a> {
@@ -2001,7 +2001,7 @@ pub struct WithStatement<'a> {
 
 /// Switch Statement
 #[visited_node(scope(ScopeFlags::empty()), enter_scope_before(cases))]
-#[derive(Debug)]
+#[derive(Debug, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct SwitchStatement<'a> {
@@ -2027,7 +2027,7 @@ impl<'a> Hash for SwitchStatement<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct SwitchCase<'a> {
@@ -2045,7 +2045,7 @@ impl<'a> SwitchCase<'a> {
 
 /// Labelled Statement
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct LabeledStatement<'a> {
@@ -2057,7 +2057,7 @@ pub struct LabeledStatement<'a> {
 
 /// Throw Statement
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ThrowStatement<'a> {
@@ -2068,7 +2068,7 @@ pub struct ThrowStatement<'a> {
 
 /// Try Statement
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TryStatement<'a> {
@@ -2080,7 +2080,7 @@ pub struct TryStatement<'a> {
 }
 
 #[visited_node(scope(ScopeFlags::empty()), scope_if(self.param.is_some()))]
-#[derive(Debug)]
+#[derive(Debug, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct CatchClause<'a> {
@@ -2110,7 +2110,7 @@ impl<'a> Hash for CatchClause<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct CatchParameter<'a> {
@@ -2121,7 +2121,7 @@ pub struct CatchParameter<'a> {
 
 /// Debugger Statement
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct DebuggerStatement {
@@ -2132,7 +2132,7 @@ pub struct DebuggerStatement {
 /// Destructuring Binding Patterns
 /// * <https://tc39.es/ecma262/#prod-BindingPattern>
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
 pub struct BindingPattern<'a> {
@@ -2158,7 +2158,7 @@ impl<'a> BindingPattern<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum BindingPatternKind<'a> {
@@ -2198,7 +2198,7 @@ impl<'a> BindingPatternKind<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct AssignmentPattern<'a> {
@@ -2210,7 +2210,7 @@ pub struct AssignmentPattern<'a> {
 
 // See serializer in serialize.rs
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ObjectPattern<'a> {
@@ -2233,7 +2233,7 @@ impl<'a> ObjectPattern<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct BindingProperty<'a> {
@@ -2247,7 +2247,7 @@ pub struct BindingProperty<'a> {
 
 // See serializer in serialize.rs
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ArrayPattern<'a> {
@@ -2273,7 +2273,7 @@ impl<'a> ArrayPattern<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename = "RestElement"))]
 pub struct BindingRestElement<'a> {
@@ -2291,7 +2291,7 @@ pub struct BindingRestElement<'a> {
     scope_if(!matches!(ctx.ancestor(2).unwrap(), Ancestor::MethodDefinitionValue(_))),
     strict_if(self.body.as_ref().is_some_and(|body| body.has_use_strict_directive()))
 )]
-#[derive(Debug)]
+#[derive(Debug, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
 pub struct Function<'a> {
@@ -2402,7 +2402,7 @@ impl<'a> Hash for Function<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub enum FunctionType {
     FunctionDeclaration,
@@ -2415,7 +2415,7 @@ pub enum FunctionType {
 /// <https://tc39.es/ecma262/#prod-FormalParameters>
 // See serializer in serialize.rs
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct FormalParameters<'a> {
@@ -2438,7 +2438,7 @@ impl<'a> FormalParameters<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct FormalParameter<'a> {
@@ -2457,7 +2457,7 @@ impl<'a> FormalParameter<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub enum FormalParameterKind {
     /// <https://tc39.es/ecma262/#prod-FormalParameters>
@@ -2484,7 +2484,7 @@ impl<'a> FormalParameters<'a> {
 
 /// <https://tc39.es/ecma262/#prod-FunctionBody>
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct FunctionBody<'a> {
@@ -2506,7 +2506,7 @@ impl<'a> FunctionBody<'a> {
 
 /// Arrow Function Definitions
 #[visited_node(scope(ScopeFlags::Function | ScopeFlags::Arrow))]
-#[derive(Debug)]
+#[derive(Debug, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ArrowFunctionExpression<'a> {
@@ -2571,7 +2571,7 @@ impl<'a> Hash for ArrowFunctionExpression<'a> {
 
 /// Generator Function Definitions
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct YieldExpression<'a> {
@@ -2583,7 +2583,7 @@ pub struct YieldExpression<'a> {
 
 /// Class Definitions
 #[visited_node(scope(ScopeFlags::StrictMode), enter_scope_before(id))]
-#[derive(Debug)]
+#[derive(Debug, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
 pub struct Class<'a> {
@@ -2663,7 +2663,7 @@ impl<'a> Hash for Class<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub enum ClassType {
     ClassDeclaration,
@@ -2671,7 +2671,7 @@ pub enum ClassType {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ClassBody<'a> {
@@ -2681,7 +2681,7 @@ pub struct ClassBody<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ClassElement<'a> {
@@ -2788,7 +2788,7 @@ impl<'a> ClassElement<'a> {
     strict_if(self.value.is_strict()),
     enter_scope_before(value)
 )]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
 pub struct MethodDefinition<'a> {
@@ -2806,7 +2806,7 @@ pub struct MethodDefinition<'a> {
     pub accessibility: Option<TSAccessibility>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub enum MethodDefinitionType {
     MethodDefinition,
@@ -2814,7 +2814,7 @@ pub enum MethodDefinitionType {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
 pub struct PropertyDefinition<'a> {
@@ -2835,14 +2835,14 @@ pub struct PropertyDefinition<'a> {
     pub decorators: Vec<'a, Decorator<'a>>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub enum PropertyDefinitionType {
     PropertyDefinition,
     TSAbstractPropertyDefinition,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
 pub enum MethodDefinitionKind {
@@ -2874,7 +2874,7 @@ impl MethodDefinitionKind {
 }
 
 #[visited_node]
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct PrivateIdentifier<'a> {
@@ -2890,7 +2890,7 @@ impl<'a> PrivateIdentifier<'a> {
 }
 
 #[visited_node(scope(ScopeFlags::ClassStaticBlock))]
-#[derive(Debug)]
+#[derive(Debug, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct StaticBlock<'a> {
@@ -2915,7 +2915,7 @@ impl<'a> Hash for StaticBlock<'a> {
 
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ModuleDeclaration<'a> {
@@ -2993,7 +2993,7 @@ impl<'a> ModuleDeclaration<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub enum AccessorPropertyType {
     AccessorProperty,
@@ -3007,7 +3007,7 @@ impl AccessorPropertyType {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct AccessorProperty<'a> {
     pub r#type: AccessorPropertyType,
@@ -3021,7 +3021,7 @@ pub struct AccessorProperty<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ImportExpression<'a> {
@@ -3032,7 +3032,7 @@ pub struct ImportExpression<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ImportDeclaration<'a> {
@@ -3048,7 +3048,7 @@ pub struct ImportDeclaration<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ImportDeclarationSpecifier<'a> {
@@ -3064,7 +3064,7 @@ pub enum ImportDeclarationSpecifier<'a> {
 // import {imported} from "source"
 // import {imported as local} from "source"
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ImportSpecifier<'a> {
@@ -3077,7 +3077,7 @@ pub struct ImportSpecifier<'a> {
 
 // import local from "source"
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ImportDefaultSpecifier<'a> {
@@ -3088,7 +3088,7 @@ pub struct ImportDefaultSpecifier<'a> {
 
 // import * as local from "source"
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ImportNamespaceSpecifier<'a> {
@@ -3098,7 +3098,7 @@ pub struct ImportNamespaceSpecifier<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct WithClause<'a> {
@@ -3109,7 +3109,7 @@ pub struct WithClause<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ImportAttribute<'a> {
@@ -3120,7 +3120,7 @@ pub struct ImportAttribute<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ImportAttributeKey<'a> {
@@ -3138,7 +3138,7 @@ impl<'a> ImportAttributeKey<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ExportNamedDeclaration<'a> {
@@ -3165,7 +3165,7 @@ impl<'a> ExportNamedDeclaration<'a> {
 /// export default ClassDeclaration
 /// export default AssignmentExpression
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct ExportDefaultDeclaration<'a> {
@@ -3182,7 +3182,7 @@ impl<'a> ExportDefaultDeclaration<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ExportAllDeclaration<'a> {
@@ -3201,7 +3201,7 @@ impl<'a> ExportAllDeclaration<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct ExportSpecifier<'a> {
@@ -3226,7 +3226,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ExportDefaultDeclarationKind<'a> {
@@ -3259,7 +3259,7 @@ impl<'a> ExportDefaultDeclarationKind<'a> {
 /// * es2022: <https://github.com/estree/estree/blob/master/es2022.md#modules>
 /// * <https://github.com/tc39/ecma262/pull/2154>
 #[visited_node]
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum ModuleExportName<'a> {
@@ -7,7 +7,7 @@
 #![allow(non_snake_case)]
 
 use oxc_allocator::{Box, Vec};
-use oxc_ast_macros::visited_node;
+use oxc_ast_macros::{visited_node, CloneIn};
 use oxc_span::{Atom, Span};
 #[cfg(feature = "serialize")]
 use serde::Serialize;
@@ -22,7 +22,7 @@ use super::inherit_variants;
 
 /// JSX Element
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSXElement<'a> {
@@ -35,7 +35,7 @@ pub struct JSXElement<'a> {
 
 /// JSX Opening Element
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSXOpeningElement<'a> {
@@ -49,7 +49,7 @@ pub struct JSXOpeningElement<'a> {
 
 /// JSX Closing Element
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct JSXClosingElement<'a> {
@@ -60,7 +60,7 @@ pub struct JSXClosingElement<'a> {
 
 /// JSX Fragment
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSXFragment<'a> {
@@ -71,7 +71,7 @@ pub struct JSXFragment<'a> {
     pub children: Vec<'a, JSXChild<'a>>,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct JSXOpeningFragment {
@@ -79,7 +79,7 @@ pub struct JSXOpeningFragment {
     pub span: Span,
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct JSXClosingFragment {
@@ -89,7 +89,7 @@ pub struct JSXClosingFragment {
 
 /// JSX Element Name
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum JSXElementName<'a> {
@@ -103,7 +103,7 @@ pub enum JSXElementName<'a> {
 
 /// JSX Namespaced Name
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct JSXNamespacedName<'a> {
@@ -121,7 +121,7 @@ impl<'a> std::fmt::Display for JSXNamespacedName<'a> {
 
 /// JSX Member Expression
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct JSXMemberExpression<'a> {
@@ -141,7 +141,7 @@ impl<'a> JSXMemberExpression<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum JSXMemberExpressionObject<'a> {
@@ -150,7 +150,7 @@ pub enum JSXMemberExpressionObject<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct JSXExpressionContainer<'a> {
@@ -167,7 +167,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum JSXExpression<'a> {
@@ -185,7 +185,7 @@ impl<'a> JSXExpression<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct JSXEmptyExpression {
@@ -197,7 +197,7 @@ pub struct JSXEmptyExpression {
 
 /// JSX Attributes
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum JSXAttributeItem<'a> {
@@ -207,7 +207,7 @@ pub enum JSXAttributeItem<'a> {
 
 /// JSX Attribute
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct JSXAttribute<'a> {
@@ -229,7 +229,7 @@ impl<'a> JSXAttribute<'a> {
 
 /// JSX Spread Attribute
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct JSXSpreadAttribute<'a> {
@@ -240,7 +240,7 @@ pub struct JSXSpreadAttribute<'a> {
 
 /// JSX Attribute Name
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum JSXAttributeName<'a> {
@@ -250,7 +250,7 @@ pub enum JSXAttributeName<'a> {
 
 /// JSX Attribute Value
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum JSXAttributeValue<'a> {
@@ -261,7 +261,7 @@ pub enum JSXAttributeValue<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct JSXIdentifier<'a> {
@@ -280,7 +280,7 @@ impl<'a> JSXIdentifier<'a> {
 
 /// JSX Child
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum JSXChild<'a> {
@@ -292,7 +292,7 @@ pub enum JSXChild<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct JSXSpreadChild<'a> {
@@ -302,7 +302,7 @@ pub struct JSXSpreadChild<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct JSXText<'a> {
@@ -12,7 +12,7 @@ use std::{
 };
 
 use bitflags::bitflags;
-use oxc_ast_macros::visited_node;
+use oxc_ast_macros::{visited_node, CloneIn};
 use oxc_span::{Atom, Span};
 use oxc_syntax::number::{BigintBase, NumberBase};
 #[cfg(feature = "serialize")]
@@ -21,7 +21,7 @@ use serde::Serialize;
 use tsify::Tsify;
 
 #[visited_node]
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct BooleanLiteral {
@@ -45,7 +45,7 @@ impl BooleanLiteral {
 }
 
 #[visited_node]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct NullLiteral {
@@ -66,7 +66,7 @@ impl NullLiteral {
 }
 
 #[visited_node]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct NumericLiteral<'a> {
@@ -118,7 +118,7 @@ impl<'a> Hash for NumericLiteral<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct BigIntLiteral<'a> {
@@ -136,7 +136,7 @@ impl<'a> BigIntLiteral<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct RegExpLiteral<'a> {
@@ -148,7 +148,7 @@ pub struct RegExpLiteral<'a> {
     pub regex: RegExp<'a>,
 }
 
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct RegExp<'a> {
     pub pattern: Atom<'a>,
@@ -239,12 +239,12 @@ impl fmt::Display for RegExpFlags {
     }
 }
 
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 pub struct EmptyObject;
 
 #[visited_node]
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct StringLiteral<'a> {
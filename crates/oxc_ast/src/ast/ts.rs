@@ -12,7 +12,7 @@
 use std::{cell::Cell, hash::Hash};
 
 use oxc_allocator::{Box, Vec};
-use oxc_ast_macros::visited_node;
+use oxc_ast_macros::{visited_node, CloneIn};
 use oxc_span::{Atom, GetSpan, Span};
 use oxc_syntax::scope::ScopeId;
 #[cfg(feature = "serialize")]
@@ -33,7 +33,7 @@ export interface TSIndexSignatureName extends Span {
 "#;
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSThisParameter<'a> {
@@ -47,7 +47,7 @@ pub struct TSThisParameter<'a> {
 ///
 /// `const_opt` enum `BindingIdentifier` { `EnumBody_opt` }
 #[visited_node(scope(ScopeFlags::empty()), enter_scope_before(members))]
-#[derive(Debug)]
+#[derive(Debug, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSEnumDeclaration<'a> {
@@ -81,7 +81,7 @@ impl<'a> Hash for TSEnumDeclaration<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSEnumMember<'a> {
@@ -99,7 +99,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum TSEnumMemberName<'a> {
@@ -114,7 +114,7 @@ pub enum TSEnumMemberName<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeAnnotation<'a> {
@@ -124,7 +124,7 @@ pub struct TSTypeAnnotation<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSLiteralType<'a> {
@@ -134,7 +134,7 @@ pub struct TSLiteralType<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged, rename_all = "camelCase"))]
 pub enum TSLiteral<'a> {
@@ -150,7 +150,7 @@ pub enum TSLiteral<'a> {
 
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged, rename_all = "camelCase"))]
 pub enum TSType<'a> {
@@ -258,7 +258,7 @@ impl<'a> TSType<'a> {
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/conditional-types.html#handbook-content>
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSConditionalType<'a> {
@@ -274,7 +274,7 @@ pub struct TSConditionalType<'a> {
 ///
 /// <https://www.typescriptlang.org/docs/handbook/typescript-in-5-minutes-func.html#unions>
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSUnionType<'a> {
@@ -287,7 +287,7 @@ pub struct TSUnionType<'a> {
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/objects.html#intersection-types>
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSIntersectionType<'a> {
@@ -300,7 +300,7 @@ pub struct TSIntersectionType<'a> {
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/keyof-types.html>
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeOperator<'a> {
@@ -310,7 +310,7 @@ pub struct TSTypeOperator<'a> {
     pub type_annotation: TSType<'a>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
 pub enum TSTypeOperatorOperator {
@@ -323,7 +323,7 @@ pub enum TSTypeOperatorOperator {
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/objects.html#the-array-type>
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSArrayType<'a> {
@@ -336,7 +336,7 @@ pub struct TSArrayType<'a> {
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/indexed-access-types.html#handbook-content>
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSIndexedAccessType<'a> {
@@ -350,7 +350,7 @@ pub struct TSIndexedAccessType<'a> {
 ///
 /// <https://www.typescriptlang.org/docs/handbook/2/objects.html#tuple-types>
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTupleType<'a> {
@@ -360,7 +360,7 @@ pub struct TSTupleType<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSNamedTupleMember<'a> {
@@ -372,7 +372,7 @@ pub struct TSNamedTupleMember<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSOptionalType<'a> {
@@ -382,7 +382,7 @@ pub struct TSOptionalType<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSRestType<'a> {
@@ -399,7 +399,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged, rename_all = "camelCase"))]
 pub enum TSTupleElement<'a> {
@@ -413,7 +413,7 @@ pub enum TSTupleElement<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSAnyKeyword {
@@ -422,7 +422,7 @@ pub struct TSAnyKeyword {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSStringKeyword {
@@ -431,7 +431,7 @@ pub struct TSStringKeyword {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSBooleanKeyword {
@@ -440,7 +440,7 @@ pub struct TSBooleanKeyword {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSNumberKeyword {
@@ -449,7 +449,7 @@ pub struct TSNumberKeyword {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSNeverKeyword {
@@ -458,7 +458,7 @@ pub struct TSNeverKeyword {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSUnknownKeyword {
@@ -467,7 +467,7 @@ pub struct TSUnknownKeyword {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSNullKeyword {
@@ -476,7 +476,7 @@ pub struct TSNullKeyword {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSUndefinedKeyword {
@@ -485,7 +485,7 @@ pub struct TSUndefinedKeyword {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSVoidKeyword {
@@ -494,7 +494,7 @@ pub struct TSVoidKeyword {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSSymbolKeyword {
@@ -503,7 +503,7 @@ pub struct TSSymbolKeyword {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSThisType {
@@ -512,7 +512,7 @@ pub struct TSThisType {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSObjectKeyword {
@@ -521,7 +521,7 @@ pub struct TSObjectKeyword {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type"))]
 pub struct TSBigIntKeyword {
@@ -533,7 +533,7 @@ pub struct TSBigIntKeyword {
 /// type D = B.a;
 /// type E = D.c.b.a;
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeReference<'a> {
@@ -548,7 +548,7 @@ pub struct TSTypeReference<'a> {
 ///     NamespaceName . IdentifierReference
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum TSTypeName<'a> {
@@ -601,7 +601,7 @@ impl GetSpan for TSTypeName<'_> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSQualifiedName<'a> {
@@ -612,7 +612,7 @@ pub struct TSQualifiedName<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeParameterInstantiation<'a> {
@@ -622,7 +622,7 @@ pub struct TSTypeParameterInstantiation<'a> {
 }
 
 #[visited_node(scope(ScopeFlags::empty()))]
-#[derive(Debug)]
+#[derive(Debug, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeParameter<'a> {
@@ -664,7 +664,7 @@ impl<'a> Hash for TSTypeParameter<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeParameterDeclaration<'a> {
@@ -674,7 +674,7 @@ pub struct TSTypeParameterDeclaration<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeAliasDeclaration<'a> {
@@ -687,7 +687,7 @@ pub struct TSTypeAliasDeclaration<'a> {
     pub modifiers: Modifiers<'a>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
 pub enum TSAccessibility {
@@ -697,7 +697,7 @@ pub enum TSAccessibility {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSClassImplements<'a> {
@@ -711,7 +711,7 @@ pub struct TSClassImplements<'a> {
 ///
 ///   interface `BindingIdentifier` `TypeParameters_opt` `InterfaceExtendsClause_opt` `ObjectType`
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSInterfaceDeclaration<'a> {
@@ -726,7 +726,7 @@ pub struct TSInterfaceDeclaration<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSInterfaceBody<'a> {
@@ -736,7 +736,7 @@ pub struct TSInterfaceBody<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSPropertySignature<'a> {
@@ -750,7 +750,7 @@ pub struct TSPropertySignature<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged, rename_all = "camelCase"))]
 pub enum TSSignature<'a> {
@@ -762,7 +762,7 @@ pub enum TSSignature<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSIndexSignature<'a> {
@@ -774,7 +774,7 @@ pub struct TSIndexSignature<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSCallSignatureDeclaration<'a> {
@@ -786,7 +786,7 @@ pub struct TSCallSignatureDeclaration<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
 pub enum TSMethodSignatureKind {
@@ -796,7 +796,7 @@ pub enum TSMethodSignatureKind {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSMethodSignature<'a> {
@@ -813,7 +813,7 @@ pub struct TSMethodSignature<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSConstructSignatureDeclaration<'a> {
@@ -825,7 +825,7 @@ pub struct TSConstructSignatureDeclaration<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
 #[cfg_attr(
     feature = "serialize",
@@ -839,7 +839,7 @@ pub struct TSIndexSignatureName<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSInterfaceHeritage<'a> {
@@ -850,7 +850,7 @@ pub struct TSInterfaceHeritage<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypePredicate<'a> {
@@ -862,7 +862,7 @@ pub struct TSTypePredicate<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged, rename_all = "camelCase"))]
 pub enum TSTypePredicateName<'a> {
@@ -871,7 +871,7 @@ pub enum TSTypePredicateName<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSModuleDeclaration<'a> {
@@ -893,7 +893,7 @@ pub struct TSModuleDeclaration<'a> {
     pub modifiers: Modifiers<'a>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "lowercase"))]
 pub enum TSModuleDeclarationKind {
@@ -903,7 +903,7 @@ pub enum TSModuleDeclarationKind {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum TSModuleDeclarationName<'a> {
@@ -925,7 +925,7 @@ impl<'a> TSModuleDeclarationName<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum TSModuleDeclarationBody<'a> {
@@ -934,7 +934,7 @@ pub enum TSModuleDeclarationBody<'a> {
 }
 
 #[visited_node(scope(ScopeFlags::TsModuleBlock))]
-#[derive(Debug)]
+#[derive(Debug, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSModuleBlock<'a> {
@@ -958,7 +958,7 @@ impl<'a> Hash for TSModuleBlock<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeLiteral<'a> {
@@ -968,7 +968,7 @@ pub struct TSTypeLiteral<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSInferType<'a> {
@@ -978,7 +978,7 @@ pub struct TSInferType<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeQuery<'a> {
@@ -996,7 +996,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum TSTypeQueryExprName<'a> {
@@ -1007,7 +1007,7 @@ pub enum TSTypeQueryExprName<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSImportType<'a> {
@@ -1020,7 +1020,7 @@ pub struct TSImportType<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSImportAttributes<'a> {
@@ -1030,7 +1030,7 @@ pub struct TSImportAttributes<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSImportAttribute<'a> {
@@ -1041,7 +1041,7 @@ pub struct TSImportAttribute<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged))]
 pub enum TSImportAttributeName<'a> {
@@ -1050,7 +1050,7 @@ pub enum TSImportAttributeName<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSFunctionType<'a> {
@@ -1063,7 +1063,7 @@ pub struct TSFunctionType<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSConstructorType<'a> {
@@ -1076,7 +1076,7 @@ pub struct TSConstructorType<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSMappedType<'a> {
@@ -1089,7 +1089,7 @@ pub struct TSMappedType<'a> {
     pub readonly: TSMappedTypeModifierOperator,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
 pub enum TSMappedTypeModifierOperator {
@@ -1102,7 +1102,7 @@ pub enum TSMappedTypeModifierOperator {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTemplateLiteralType<'a> {
@@ -1113,7 +1113,7 @@ pub struct TSTemplateLiteralType<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSAsExpression<'a> {
@@ -1124,7 +1124,7 @@ pub struct TSAsExpression<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSSatisfiesExpression<'a> {
@@ -1135,7 +1135,7 @@ pub struct TSSatisfiesExpression<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeAssertion<'a> {
@@ -1146,7 +1146,7 @@ pub struct TSTypeAssertion<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSImportEqualsDeclaration<'a> {
@@ -1165,7 +1165,7 @@ inherit_variants! {
 /// [`ast` module docs]: `super`
 #[visited_node]
 #[repr(C, u8)]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(untagged, rename_all = "camelCase"))]
 pub enum TSModuleReference<'a> {
@@ -1176,7 +1176,7 @@ pub enum TSModuleReference<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSExternalModuleReference<'a> {
@@ -1186,7 +1186,7 @@ pub struct TSExternalModuleReference<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSNonNullExpression<'a> {
@@ -1196,7 +1196,7 @@ pub struct TSNonNullExpression<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Decorator<'a> {
@@ -1228,7 +1228,7 @@ impl<'a> Decorator<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
 pub enum ModifierKind {
@@ -1255,7 +1255,7 @@ impl ModifierKind {
     }
 }
 
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct Modifier {
@@ -1264,7 +1264,7 @@ pub struct Modifier {
     pub kind: ModifierKind,
 }
 
-#[derive(Debug, Default, Hash)]
+#[derive(Debug, Default, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(transparent))]
 pub struct Modifiers<'a>(Option<Vec<'a, Modifier>>);
@@ -1303,7 +1303,7 @@ impl<'a> Modifiers<'a> {
 ///
 /// `export = foo`
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSExportAssignment<'a> {
@@ -1316,7 +1316,7 @@ pub struct TSExportAssignment<'a> {
 ///
 /// `export as namespace foo`
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSNamespaceExportDeclaration<'a> {
@@ -1326,7 +1326,7 @@ pub struct TSNamespaceExportDeclaration<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSInstantiationExpression<'a> {
@@ -1336,7 +1336,7 @@ pub struct TSInstantiationExpression<'a> {
     pub type_parameters: Box<'a, TSTypeParameterInstantiation<'a>>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(rename_all = "camelCase"))]
 pub enum ImportOrExportKind {
@@ -1357,7 +1357,7 @@ impl ImportOrExportKind {
 // [`JSDoc`](https://github.com/microsoft/TypeScript/blob/54a554d8af2657630307cbfa8a3e4f3946e36507/src/compiler/types.ts#L393)
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSDocNullableType<'a> {
@@ -1368,7 +1368,7 @@ pub struct JSDocNullableType<'a> {
 }
 
 #[visited_node]
-#[derive(Debug, Hash)]
+#[derive(Debug, Hash, CloneIn)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify))]
 #[cfg_attr(feature = "serialize", serde(tag = "type", rename_all = "camelCase"))]
 pub struct JSDocUnknownType {
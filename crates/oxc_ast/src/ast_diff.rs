@@ -0,0 +1,562 @@
+//! Structural diffing of ASTs.
+//!
+//! Two nodes are equal by content ([ContentEq]) when they have the same type,
+//! the same content (names, values, operators and flags) and their children are equal by content.
+//! Spans are ignored, so the AST of a program and the AST of its printed output
+//! are equal by content when the printer is correct.
+//!
+//! [AstDiff] reports the nodes which were added, removed or changed between two programs,
+//! aligning sibling nodes by their longest common subsequence.
+//! [AstDiff::new_normalized] also ignores the differences which a printer may introduce without
+//! changing the meaning of the program.
+//!
+//! [AstSnapshot] is an owned copy of the structure of a program which keeps the spans of its
+//! nodes, so that the nodes of a mutated program which still have the content of the source text
+//! at their span can be told from the new and changed ones.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::precedence::{GetPrecedence, Precedence};
+
+use crate::{
+    ast::{Expression, Program, Statement},
+    visit::walk::{walk_expression, walk_statement},
+    AstKind, AstType, Visit,
+};
+
+/// Equality of AST nodes, ignoring their spans.
+pub trait ContentEq {
+    fn content_eq(&self, other: &Self) -> bool;
+}
+
+impl<'a> ContentEq for Program<'a> {
+    fn content_eq(&self, other: &Self) -> bool {
+        Snapshot::build(false, |v| v.visit_program(self))
+            == Snapshot::build(false, |v| v.visit_program(other))
+    }
+}
+
+impl<'a> ContentEq for Statement<'a> {
+    fn content_eq(&self, other: &Self) -> bool {
+        Snapshot::build(false, |v| v.visit_statement(self))
+            == Snapshot::build(false, |v| v.visit_statement(other))
+    }
+}
+
+impl<'a> ContentEq for Expression<'a> {
+    fn content_eq(&self, other: &Self) -> bool {
+        Snapshot::build(false, |v| v.visit_expression(self))
+            == Snapshot::build(false, |v| v.visit_expression(other))
+    }
+}
+
+/// A single difference between two ASTs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AstChange {
+    /// A node of the new AST which has no counterpart in the old AST.
+    Added { path: String, node: String, span: Span },
+    /// A node of the old AST which has no counterpart in the new AST.
+    Removed { path: String, node: String, span: Span },
+    /// A node whose type or content differs between the two ASTs.
+    Changed { path: String, old: String, new: String, old_span: Span, new_span: Span },
+}
+
+impl fmt::Display for AstChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added { path, node, span } => {
+                write!(f, "+ {path}: {node} @ {}..{}", span.start, span.end)
+            }
+            Self::Removed { path, node, span } => {
+                write!(f, "- {path}: {node} @ {}..{}", span.start, span.end)
+            }
+            Self::Changed { path, old, new, old_span, new_span } => write!(
+                f,
+                "~ {path}: {old} @ {}..{} -> {new} @ {}..{}",
+                old_span.start, old_span.end, new_span.start, new_span.end
+            ),
+        }
+    }
+}
+
+/// The differences between two programs.
+///
+/// ```ignore
+/// let diff = AstDiff::new(&parser_result1.program, &parser_result2.program);
+/// if !diff.is_empty() {
+///     println!("{diff}");
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct AstDiff {
+    changes: Vec<AstChange>,
+}
+
+impl AstDiff {
+    pub fn new(old: &Program<'_>, new: &Program<'_>) -> Self {
+        Self::build(old, new, false)
+    }
+
+    /// Like [AstDiff::new], but ignores parenthesized expressions, which a printer adds where
+    /// the precedence requires them, and compares template literals without substitutions as
+    /// the string literals they may be printed as.
+    pub fn new_normalized(old: &Program<'_>, new: &Program<'_>) -> Self {
+        Self::build(old, new, true)
+    }
+
+    fn build(old: &Program<'_>, new: &Program<'_>, normalize: bool) -> Self {
+        let old = Snapshot::build(normalize, |v| v.visit_program(old));
+        let new = Snapshot::build(normalize, |v| v.visit_program(new));
+        let mut diff = Self::default();
+        diff.diff_children(&old, &new, "");
+        diff
+    }
+
+    /// Whether the programs are equal by content.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn changes(&self) -> &[AstChange] {
+        &self.changes
+    }
+
+    fn diff_node(&mut self, old: &SnapshotNode, new: &SnapshotNode, path: &str) {
+        if old == new {
+            return;
+        }
+        if old.ty != new.ty || old.content != new.content {
+            self.changes.push(AstChange::Changed {
+                path: path.to_string(),
+                old: old.to_string(),
+                new: new.to_string(),
+                old_span: old.span,
+                new_span: new.span,
+            });
+            if old.ty != new.ty {
+                return;
+            }
+        }
+        self.diff_children(&old.children, &new.children, path);
+    }
+
+    fn diff_children(&mut self, old: &[SnapshotNode], new: &[SnapshotNode], path: &str) {
+        let child_path = |index: usize, node: &SnapshotNode| {
+            if path.is_empty() {
+                format!("{:?}[{index}]", node.ty)
+            } else {
+                format!("{path} > {:?}[{index}]", node.ty)
+            }
+        };
+
+        let mut old_start = 0;
+        let mut new_start = 0;
+        for (old_end, new_end) in longest_common_subsequence(old, new)
+            .into_iter()
+            .chain(std::iter::once((old.len(), new.len())))
+        {
+            let old_gap = &old[old_start..old_end];
+            let new_gap = &new[new_start..new_end];
+            for (i, (old_node, new_node)) in old_gap.iter().zip(new_gap).enumerate() {
+                self.diff_node(old_node, new_node, &child_path(old_start + i, old_node));
+            }
+            let paired = old_gap.len().min(new_gap.len());
+            for (i, node) in old_gap.iter().enumerate().skip(paired) {
+                self.changes.push(AstChange::Removed {
+                    path: child_path(old_start + i, node),
+                    node: node.to_string(),
+                    span: node.span,
+                });
+            }
+            for (i, node) in new_gap.iter().enumerate().skip(paired) {
+                self.changes.push(AstChange::Added {
+                    path: child_path(new_start + i, node),
+                    node: node.to_string(),
+                    span: node.span,
+                });
+            }
+            old_start = old_end + 1;
+            new_start = new_end + 1;
+        }
+    }
+}
+
+impl fmt::Display for AstDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+const MAX_LCS_TABLE_SIZE: usize = 1 << 22;
+
+/// Index pairs of the equal nodes in the longest common subsequence of `old` and `new`.
+fn longest_common_subsequence(old: &[SnapshotNode], new: &[SnapshotNode]) -> Vec<(usize, usize)> {
+    // Common prefix and suffix are matched without the quadratic table.
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let mut pairs: Vec<(usize, usize)> = (0..prefix).map(|i| (i, i)).collect();
+    let old_suffix_start = old.len() - suffix;
+    let new_suffix_start = new.len() - suffix;
+    let suffix_pairs = (0..suffix).map(|i| (old_suffix_start + i, new_suffix_start + i));
+
+    // Too large for the quadratic table, the middle is compared positionally instead.
+    if old_mid.len().saturating_mul(new_mid.len()) > MAX_LCS_TABLE_SIZE {
+        pairs.extend(suffix_pairs);
+        return pairs;
+    }
+
+    // lengths[i][j] is the length of the LCS of `old_mid[i..]` and `new_mid[j..]`.
+    let width = new_mid.len() + 1;
+    let mut lengths = vec![0u32; (old_mid.len() + 1) * width];
+    for i in (0..old_mid.len()).rev() {
+        for j in (0..new_mid.len()).rev() {
+            lengths[i * width + j] = if old_mid[i] == new_mid[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    while i < old_mid.len() && j < new_mid.len() {
+        if old_mid[i] == new_mid[j] {
+            pairs.push((prefix + i, prefix + j));
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    pairs.extend(suffix_pairs);
+    pairs
+}
+
+/// An owned copy of the structure of a program, taken before the program is mutated.
+///
+/// ```ignore
+/// let snapshot = AstSnapshot::new(&program);
+/// mutate(&mut program);
+/// let changed = snapshot.root() != AstSnapshot::new(&program).root();
+/// ```
+#[derive(Debug)]
+pub struct AstSnapshot {
+    root: SnapshotNode,
+}
+
+impl AstSnapshot {
+    pub fn new(program: &Program<'_>) -> Self {
+        let mut roots = Snapshot::build(false, |v| v.visit_program(program));
+        // The program is always entered.
+        let root = roots.pop().unwrap_or(SnapshotNode {
+            ty: AstType::Program,
+            content: None,
+            span: program.span,
+            children: vec![],
+            hash: 0,
+            index: 0,
+            role: None,
+        });
+        Self { root }
+    }
+
+    /// The node of the program.
+    pub fn root(&self) -> &SnapshotNode {
+        &self.root
+    }
+}
+
+/// Where a node of an [AstSnapshot] is in the AST.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeRole {
+    Statement,
+    /// An expression of the given precedence, where the expressions without an operator, e.g.
+    /// literals, bind like member expressions.
+    Expression(Precedence),
+}
+
+/// An owned copy of the structure of an AST node, without references into the arena.
+///
+/// Two nodes are equal when they are equal by content, see [ContentEq].
+#[derive(Debug)]
+pub struct SnapshotNode {
+    ty: AstType,
+    content: Option<String>,
+    span: Span,
+    children: Vec<SnapshotNode>,
+    /// Hash of `ty`, `content` and `children`, for fast inequality checks.
+    hash: u64,
+    /// The number of nodes entered before this one by [Visit::enter_node].
+    index: usize,
+    role: Option<NodeRole>,
+}
+
+impl SnapshotNode {
+    fn new(kind: AstKind<'_>) -> Self {
+        Self {
+            ty: kind.ty(),
+            content: node_content(kind),
+            span: kind.span(),
+            children: vec![],
+            hash: 0,
+            index: 0,
+            role: None,
+        }
+    }
+
+    pub fn ty(&self) -> AstType {
+        self.ty
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// The content of the node which is not part of its children, e.g. the operator of a binary
+    /// expression or the name of an identifier.
+    pub fn content(&self) -> Option<&str> {
+        self.content.as_deref()
+    }
+
+    pub fn children(&self) -> &[SnapshotNode] {
+        &self.children
+    }
+
+    /// The position of the node in the order in which [Visit::enter_node] enters the nodes of
+    /// the program, so that a visitor can find the AST node of a snapshot node.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Whether the node is a statement or an expression, `None` for the other nodes, e.g. a
+    /// property key or a switch case.
+    pub fn role(&self) -> Option<NodeRole> {
+        self.role
+    }
+
+    /// Whether the nodes have the same type and content, regardless of their children.
+    pub fn shallow_eq(&self, other: &Self) -> bool {
+        self.ty == other.ty && self.content == other.content
+    }
+
+    /// A template literal without substitutions is recorded as the string literal of its
+    /// cooked value.
+    fn new_normalized(kind: AstKind<'_>) -> Self {
+        let mut node = Self::new(kind);
+        if let AstKind::TemplateLiteral(lit) = kind {
+            if let ([quasi], true) = (lit.quasis.as_slice(), lit.expressions.is_empty()) {
+                if let Some(cooked) = &quasi.value.cooked {
+                    node.ty = AstType::StringLiteral;
+                    node.content = Some(format!("{:?}", cooked.as_str()));
+                }
+            }
+        }
+        node
+    }
+
+    fn finish(&mut self) {
+        let mut hasher = DefaultHasher::new();
+        self.ty.hash(&mut hasher);
+        self.content.hash(&mut hasher);
+        for child in &self.children {
+            child.hash.hash(&mut hasher);
+        }
+        self.hash = hasher.finish();
+    }
+}
+
+/// Spans are ignored.
+impl PartialEq for SnapshotNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash
+            && self.ty == other.ty
+            && self.content == other.content
+            && self.children == other.children
+    }
+}
+
+impl fmt::Display for SnapshotNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.content {
+            Some(content) => write!(f, "{:?}({content})", self.ty),
+            None => write!(f, "{:?}", self.ty),
+        }
+    }
+}
+
+/// Collects the [SnapshotNode]s of an AST from the `enter_node` and `leave_node` callbacks.
+///
+/// AST types without an [AstKind] are not recorded,
+/// their descendants become the children of their closest recorded ancestor.
+#[derive(Default)]
+struct Snapshot {
+    /// See [AstDiff::new_normalized].
+    normalize: bool,
+    stack: Vec<SnapshotNode>,
+    roots: Vec<SnapshotNode>,
+    /// The number of nodes entered so far.
+    entered: usize,
+    /// The role of the statement or expression being visited, for the node of its span which is
+    /// entered next.
+    role: Option<(Span, NodeRole)>,
+}
+
+impl Snapshot {
+    fn build<F: FnOnce(&mut Self)>(normalize: bool, visit: F) -> Vec<SnapshotNode> {
+        let mut snapshot = Self { normalize, ..Self::default() };
+        visit(&mut snapshot);
+        snapshot.roots
+    }
+
+    fn is_skipped(&self, kind: AstKind<'_>) -> bool {
+        self.normalize && matches!(kind, AstKind::ParenthesizedExpression(_))
+    }
+}
+
+impl<'a> Visit<'a> for Snapshot {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let index = self.entered;
+        self.entered += 1;
+        let role = self.role.take().filter(|(span, _)| *span == kind.span()).map(|(_, role)| role);
+        if self.is_skipped(kind) {
+            return;
+        }
+        let mut node = if self.normalize {
+            SnapshotNode::new_normalized(kind)
+        } else {
+            SnapshotNode::new(kind)
+        };
+        node.index = index;
+        node.role = role;
+        self.stack.push(node);
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        if self.is_skipped(kind) {
+            return;
+        }
+        let Some(mut node) = self.stack.pop() else { return };
+        node.finish();
+        match self.stack.last_mut() {
+            Some(parent) => parent.children.push(node),
+            None => self.roots.push(node),
+        }
+    }
+
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        self.role = Some((stmt.span(), NodeRole::Statement));
+        walk_statement(self, stmt);
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        self.role = Some((expr.span(), NodeRole::Expression(expression_precedence(expr))));
+        walk_expression(self, expr);
+    }
+}
+
+fn expression_precedence(expr: &Expression<'_>) -> Precedence {
+    match expr {
+        Expression::SequenceExpression(_)
+        | Expression::AssignmentExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::ConditionalExpression(_)
+        | Expression::LogicalExpression(_)
+        | Expression::BinaryExpression(_)
+        | Expression::UnaryExpression(_)
+        | Expression::UpdateExpression(_)
+        | Expression::AwaitExpression(_)
+        | Expression::NewExpression(_)
+        | Expression::CallExpression(_) => expr.precedence(),
+        // `a?.b` can not be the object of a member expression without changing its meaning.
+        Expression::ChainExpression(_) => Precedence::Call,
+        Expression::PrivateInExpression(_) => Precedence::Relational,
+        _ => Precedence::Member,
+    }
+}
+
+/// The content of a node which is not part of its children.
+fn node_content(kind: AstKind<'_>) -> Option<String> {
+    let content = match kind {
+        AstKind::Directive(directive) => directive.directive.to_string(),
+        AstKind::Hashbang(hashbang) => hashbang.value.to_string(),
+        AstKind::IdentifierName(ident) => ident.name.to_string(),
+        AstKind::IdentifierReference(ident) => ident.name.to_string(),
+        AstKind::BindingIdentifier(ident) => ident.name.to_string(),
+        AstKind::LabelIdentifier(ident) => ident.name.to_string(),
+        AstKind::PrivateIdentifier(ident) => ident.name.to_string(),
+        AstKind::JSXIdentifier(ident) => ident.name.to_string(),
+        AstKind::JSXText(text) => text.value.to_string(),
+        AstKind::NumericLiteral(lit) => lit.value.to_string(),
+        AstKind::StringLiteral(lit) => format!("{:?}", lit.value.as_str()),
+        AstKind::BooleanLiteral(lit) => lit.value.to_string(),
+        AstKind::BigintLiteral(lit) => lit.raw.to_string(),
+        AstKind::RegExpLiteral(lit) => lit.regex.to_string(),
+        AstKind::TemplateLiteral(lit) => {
+            let quasis = lit.quasis.iter().map(|quasi| quasi.value.raw.as_str());
+            format!("`{}`", quasis.collect::<Vec<_>>().join("${}"))
+        }
+        AstKind::VariableDeclaration(decl) => decl.kind.to_string(),
+        AstKind::BinaryExpression(expr) => expr.operator.as_str().to_string(),
+        AstKind::LogicalExpression(expr) => expr.operator.as_str().to_string(),
+        AstKind::UnaryExpression(expr) => expr.operator.as_str().to_string(),
+        AstKind::AssignmentExpression(expr) => expr.operator.as_str().to_string(),
+        AstKind::UpdateExpression(expr) => {
+            let operator = expr.operator.as_str();
+            if expr.prefix {
+                format!("{operator}x")
+            } else {
+                format!("x{operator}")
+            }
+        }
+        AstKind::MemberExpression(expr) if expr.optional() => "?.".to_string(),
+        AstKind::CallExpression(expr) if expr.optional => "?.".to_string(),
+        AstKind::YieldExpression(expr) if expr.delegate => "*".to_string(),
+        AstKind::ForOfStatement(stmt) if stmt.r#await => "await".to_string(),
+        AstKind::Function(func) => flags(&[("async", func.r#async), ("*", func.generator)])?,
+        AstKind::ArrowFunctionExpression(func) => {
+            flags(&[("async", func.r#async), ("expression", func.expression)])?
+        }
+        AstKind::ObjectProperty(prop) => {
+            let flags = flags(&[
+                ("method", prop.method),
+                ("shorthand", prop.shorthand),
+                ("computed", prop.computed),
+            ]);
+            format!("{:?}{}", prop.kind, flags.map(|f| format!(" {f}")).unwrap_or_default())
+        }
+        AstKind::MethodDefinition(def) => {
+            let flags = flags(&[("static", def.r#static), ("computed", def.computed)]);
+            format!("{:?}{}", def.kind, flags.map(|f| format!(" {f}")).unwrap_or_default())
+        }
+        AstKind::PropertyDefinition(def) => {
+            flags(&[("static", def.r#static), ("computed", def.computed)])?
+        }
+        _ => return None,
+    };
+    Some(content)
+}
+
+/// Names of the set flags, separated by spaces.
+fn flags(flags: &[(&str, bool)]) -> Option<String> {
+    let names = flags.iter().filter(|(_, set)| *set).map(|(name, _)| *name).collect::<Vec<_>>();
+    (!names.is_empty()).then(|| names.join(" "))
+}
@@ -5,7 +5,7 @@ use crate::ast::*;
 
 macro_rules! ast_kinds {
     { $($ident:ident($type:ty),)* } => (
-        #[derive(Debug, Clone, Copy)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
         pub enum AstType {
             $($ident,)*
         }
@@ -15,6 +15,15 @@ macro_rules! ast_kinds {
         pub enum AstKind<'a> {
             $($ident($type),)*
         }
+
+        impl<'a> AstKind<'a> {
+            /// The type of this node, without a reference to the node.
+            pub fn ty(&self) -> AstType {
+                match self {
+                    $(Self::$ident(_) => AstType::$ident,)*
+                }
+            }
+        }
     )
 }
 
@@ -0,0 +1,214 @@
+//! Deep cloning of AST nodes into an allocator.
+//!
+//! [CloneIn] is derived for every AST node type. A clone can be made into the same allocator,
+//! to duplicate code inside a program, or into another allocator, to move a subtree out of an
+//! AST which is about to be dropped.
+//!
+//! ```ignore
+//! let cloned = expr.clone_in(&allocator);
+//!
+//! let mut ctx = CloneCtx::new(&allocator).with_reset_spans(true).with_ids(&mut ids);
+//! let cloned = expr.clone_in_with(&mut ctx);
+//! ```
+//!
+//! By default the clone keeps the spans and the scope, symbol and reference IDs of the original
+//! nodes. [CloneCtx::with_reset_spans] blanks the spans, and [CloneCtx::with_ids] gives the
+//! cloned nodes the IDs created by a [CloneIds], so that both copies can be analyzed as separate
+//! code.
+
+use std::cell::Cell;
+
+use oxc_allocator::{Allocator, Box, Vec};
+use oxc_span::{Atom, SourceType, Span, SPAN};
+use oxc_syntax::{
+    number::{BigintBase, NumberBase},
+    operator::{
+        AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
+    },
+    reference::{ReferenceFlag, ReferenceId},
+    scope::ScopeId,
+    symbol::SymbolId,
+};
+
+use crate::ast::RegExpFlags;
+
+/// Clone a value, and everything it owns, into an allocator.
+pub trait CloneIn<'new_alloc>: Sized {
+    /// The same type, with its lifetime replaced by the lifetime of the new allocator.
+    type Cloned;
+
+    fn clone_in_with(&self, ctx: &mut CloneCtx<'new_alloc, '_>) -> Self::Cloned;
+
+    /// Clone with the same spans and IDs.
+    fn clone_in(&self, allocator: &'new_alloc Allocator) -> Self::Cloned {
+        self.clone_in_with(&mut CloneCtx::new(allocator))
+    }
+}
+
+/// Creates the IDs of cloned nodes.
+///
+/// Each method is called with the ID of an original node, and returns the ID for its clone. It is
+/// called once for every occurrence of the ID in the cloned subtree, so implementations should
+/// return the same new ID for repeated occurrences.
+pub trait CloneIds {
+    fn clone_scope_id(&mut self, scope_id: ScopeId) -> ScopeId;
+
+    fn clone_symbol_id(&mut self, symbol_id: SymbolId) -> SymbolId;
+
+    fn clone_reference_id(&mut self, reference_id: ReferenceId) -> ReferenceId;
+}
+
+/// Options of a clone, see the module documentation.
+pub struct CloneCtx<'new_alloc, 'ids> {
+    allocator: &'new_alloc Allocator,
+    reset_spans: bool,
+    ids: Option<&'ids mut dyn CloneIds>,
+}
+
+impl<'new_alloc, 'ids> CloneCtx<'new_alloc, 'ids> {
+    pub fn new(allocator: &'new_alloc Allocator) -> Self {
+        Self { allocator, reset_spans: false, ids: None }
+    }
+
+    /// Replace the spans of cloned nodes with empty spans.
+    #[must_use]
+    pub fn with_reset_spans(mut self, yes: bool) -> Self {
+        self.reset_spans = yes;
+        self
+    }
+
+    /// Give cloned nodes the scope, symbol and reference IDs created by `ids`.
+    #[must_use]
+    pub fn with_ids(mut self, ids: &'ids mut dyn CloneIds) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    pub fn allocator(&self) -> &'new_alloc Allocator {
+        self.allocator
+    }
+}
+
+impl<'old_alloc, 'new_alloc, T: CloneIn<'new_alloc>> CloneIn<'new_alloc> for Box<'old_alloc, T> {
+    type Cloned = Box<'new_alloc, T::Cloned>;
+
+    fn clone_in_with(&self, ctx: &mut CloneCtx<'new_alloc, '_>) -> Self::Cloned {
+        let cloned = (**self).clone_in_with(ctx);
+        Box::new_in(cloned, ctx.allocator)
+    }
+}
+
+impl<'old_alloc, 'new_alloc, T: CloneIn<'new_alloc>> CloneIn<'new_alloc> for Vec<'old_alloc, T> {
+    type Cloned = Vec<'new_alloc, T::Cloned>;
+
+    fn clone_in_with(&self, ctx: &mut CloneCtx<'new_alloc, '_>) -> Self::Cloned {
+        let mut cloned = Vec::with_capacity_in(self.len(), ctx.allocator);
+        for item in self {
+            cloned.push(item.clone_in_with(ctx));
+        }
+        cloned
+    }
+}
+
+impl<'new_alloc, T: CloneIn<'new_alloc>> CloneIn<'new_alloc> for Option<T> {
+    type Cloned = Option<T::Cloned>;
+
+    fn clone_in_with(&self, ctx: &mut CloneCtx<'new_alloc, '_>) -> Self::Cloned {
+        self.as_ref().map(|value| value.clone_in_with(ctx))
+    }
+}
+
+impl<'old_alloc, 'new_alloc> CloneIn<'new_alloc> for &'old_alloc str {
+    type Cloned = &'new_alloc str;
+
+    fn clone_in_with(&self, ctx: &mut CloneCtx<'new_alloc, '_>) -> Self::Cloned {
+        ctx.allocator.alloc_str(self)
+    }
+}
+
+impl<'old_alloc, 'new_alloc> CloneIn<'new_alloc> for Atom<'old_alloc> {
+    type Cloned = Atom<'new_alloc>;
+
+    fn clone_in_with(&self, ctx: &mut CloneCtx<'new_alloc, '_>) -> Self::Cloned {
+        Atom::from(self.as_str().clone_in_with(ctx))
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for Span {
+    type Cloned = Self;
+
+    fn clone_in_with(&self, ctx: &mut CloneCtx<'new_alloc, '_>) -> Self::Cloned {
+        if ctx.reset_spans {
+            SPAN
+        } else {
+            *self
+        }
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for Cell<Option<ScopeId>> {
+    type Cloned = Self;
+
+    fn clone_in_with(&self, ctx: &mut CloneCtx<'new_alloc, '_>) -> Self::Cloned {
+        let scope_id = match (self.get(), ctx.ids.as_mut()) {
+            (Some(scope_id), Some(ids)) => Some(ids.clone_scope_id(scope_id)),
+            (scope_id, _) => scope_id,
+        };
+        Cell::new(scope_id)
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for Cell<Option<SymbolId>> {
+    type Cloned = Self;
+
+    fn clone_in_with(&self, ctx: &mut CloneCtx<'new_alloc, '_>) -> Self::Cloned {
+        let symbol_id = match (self.get(), ctx.ids.as_mut()) {
+            (Some(symbol_id), Some(ids)) => Some(ids.clone_symbol_id(symbol_id)),
+            (symbol_id, _) => symbol_id,
+        };
+        Cell::new(symbol_id)
+    }
+}
+
+impl<'new_alloc> CloneIn<'new_alloc> for Cell<Option<ReferenceId>> {
+    type Cloned = Self;
+
+    fn clone_in_with(&self, ctx: &mut CloneCtx<'new_alloc, '_>) -> Self::Cloned {
+        let reference_id = match (self.get(), ctx.ids.as_mut()) {
+            (Some(reference_id), Some(ids)) => Some(ids.clone_reference_id(reference_id)),
+            (reference_id, _) => reference_id,
+        };
+        Cell::new(reference_id)
+    }
+}
+
+/// Types without allocated or identified content, which are cloned by copying.
+macro_rules! impl_clone_in_by_copy {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<'new_alloc> CloneIn<'new_alloc> for $ty {
+                type Cloned = Self;
+
+                #[inline]
+                fn clone_in_with(&self, _ctx: &mut CloneCtx<'new_alloc, '_>) -> Self::Cloned {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+impl_clone_in_by_copy!(
+    bool,
+    f64,
+    SourceType,
+    ReferenceFlag,
+    RegExpFlags,
+    NumberBase,
+    BigintBase,
+    AssignmentOperator,
+    BinaryOperator,
+    LogicalOperator,
+    UnaryOperator,
+    UpdateOperator,
+);
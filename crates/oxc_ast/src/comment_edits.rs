@@ -0,0 +1,327 @@
+//! Comment-preserving source edits
+//!
+//! Edits of the source text of a program which keep its comments with the nodes they describe,
+//! shared by lint fixes, codemods and transforms which splice printed nodes into the source.
+//!
+//! [CommentMap] attaches every comment to an owner, one of the node spans it is built with:
+//!
+//! * Trailing: the comment follows the node on the line where the node ends, e.g. `a(); // c`.
+//! * Leading: the comment is on the lines directly above the node, or before it on its line,
+//!   with only whitespace and other leading comments in between. A blank line detaches it.
+//! * Inner: the comment is inside of the smallest node which contains it.
+//!
+//! A comment which is none of these, e.g. a file header followed by a blank line, has no owner.
+//! When nodes are nested, a comment is attached to the outermost node which starts or ends next
+//! to it, e.g. to a statement rather than to its expression.
+//!
+//! [CommentEdits] moves the comments with their nodes:
+//!
+//! * A moved node takes its leading and trailing comments along.
+//! * A replaced node keeps its leading and trailing comments, and the comments inside of it are
+//!   kept before the replacement.
+//! * A deleted node leaves its comments behind, unless they are deleted with it.
+//! * Text inserted before or after a node goes outside of its leading or trailing comments.
+
+use std::collections::BTreeMap;
+
+use oxc_span::Span;
+
+use crate::{CommentKind, Trivias};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentPosition {
+    Leading,
+    Trailing,
+    Inner,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AttachedComment {
+    /// The span of the comment, including its delimiters.
+    pub span: Span,
+    pub kind: CommentKind,
+    /// The node which owns the comment, `None` for a detached comment.
+    pub owner: Option<(Span, CommentPosition)>,
+}
+
+/// The comments of a program and the nodes they are attached to.
+pub struct CommentMap<'s> {
+    source_text: &'s str,
+    comments: Vec<AttachedComment>,
+}
+
+impl<'s> CommentMap<'s> {
+    /// Attach the comments of `trivias` to `nodes`, the only nodes which may own a comment.
+    pub fn new(source_text: &'s str, trivias: &Trivias, nodes: &[Span]) -> Self {
+        let mut starts = BTreeMap::<u32, Span>::new();
+        let mut ends = BTreeMap::<u32, Span>::new();
+        for &node in nodes {
+            for (position, map) in [(node.start, &mut starts), (node.end, &mut ends)] {
+                let outer = map.entry(position).or_insert(node);
+                if node.size() > outer.size() {
+                    *outer = node;
+                }
+            }
+        }
+
+        let mut comments: Vec<AttachedComment> = trivias
+            .comments()
+            .map(|(kind, span)| {
+                // Trivias exclude the comment delimiters.
+                let span = match kind {
+                    CommentKind::SingleLine => Span::new(span.start - 2, span.end),
+                    CommentKind::MultiLine => Span::new(span.start - 2, span.end + 2),
+                };
+                AttachedComment { span, kind, owner: None }
+            })
+            .collect();
+
+        for i in 0..comments.len() {
+            let before = &source_text[..comments[i].span.start as usize];
+            let gap = before.len() - before.trim_end_matches([' ', '\t']).len();
+            #[allow(clippy::cast_possible_truncation)]
+            let position = comments[i].span.start - gap as u32;
+            let previous = i.checked_sub(1).map(|j| comments[j]);
+            comments[i].owner = match previous {
+                Some(previous) if previous.span.end == position => previous
+                    .owner
+                    .filter(|(_, owner_position)| *owner_position == CommentPosition::Trailing),
+                _ => ends.get(&position).map(|&node| (node, CommentPosition::Trailing)),
+            };
+        }
+
+        for i in (0..comments.len()).rev() {
+            if comments[i].owner.is_some() {
+                continue;
+            }
+            let comment = comments[i].span;
+            let after = &source_text[comment.end as usize..];
+            let gap = &after[..after.len() - after.trim_start().len()];
+            let newlines = gap.matches('\n').count();
+            let line_start = source_text[..comment.start as usize].rfind('\n').map_or(0, |i| i + 1);
+            let starts_line = source_text[line_start..comment.start as usize].trim().is_empty();
+            // After other code on its line, the comment only leads a node on the same line.
+            if newlines > 1 || (newlines == 1 && !starts_line) {
+                continue;
+            }
+            #[allow(clippy::cast_possible_truncation)]
+            let position = comment.end + gap.len() as u32;
+            let next = comments.get(i + 1).copied();
+            comments[i].owner = match next {
+                Some(next) if next.span.start == position => next
+                    .owner
+                    .filter(|(_, owner_position)| *owner_position == CommentPosition::Leading),
+                _ => starts.get(&position).map(|&node| (node, CommentPosition::Leading)),
+            };
+        }
+
+        for comment in comments.iter_mut().filter(|comment| comment.owner.is_none()) {
+            comment.owner = nodes
+                .iter()
+                .filter(|node| contains(**node, comment.span))
+                .min_by_key(|node| node.size())
+                .map(|&node| (node, CommentPosition::Inner));
+        }
+
+        Self { source_text, comments }
+    }
+
+    pub fn source_text(&self) -> &'s str {
+        self.source_text
+    }
+
+    /// All comments, in source order.
+    pub fn comments(&self) -> &[AttachedComment] {
+        &self.comments
+    }
+
+    /// The comments owned by `node`.
+    pub fn comments_of(&self, node: Span) -> impl Iterator<Item = &AttachedComment> + '_ {
+        self.comments
+            .iter()
+            .filter(move |comment| comment.owner.is_some_and(|(owner, _)| owner == node))
+    }
+
+    /// The comments inside of `span`.
+    pub fn comments_in(&self, span: Span) -> impl Iterator<Item = &AttachedComment> + '_ {
+        self.comments.iter().filter(move |comment| contains(span, comment.span))
+    }
+
+    /// The span of `node` extended with its leading and trailing comments.
+    pub fn full_span(&self, node: Span) -> Span {
+        self.comments_of(node).fold(node, |span, comment| match comment.owner {
+            Some((_, CommentPosition::Leading)) => {
+                Span::new(comment.span.start.min(span.start), span.end)
+            }
+            Some((_, CommentPosition::Trailing)) => {
+                Span::new(span.start, comment.span.end.max(span.end))
+            }
+            _ => span,
+        })
+    }
+
+    /// `span` extended to whole lines, with the line break, when nothing else is on its lines.
+    fn line_span(&self, span: Span) -> Span {
+        let text = self.source_text;
+        let line_start = text[..span.start as usize].rfind('\n').map_or(0, |i| i + 1);
+        let line_end =
+            text[span.end as usize..].find('\n').map_or(text.len(), |i| i + span.end as usize);
+        if !text[line_start..span.start as usize].trim().is_empty()
+            || !text[span.end as usize..line_end].trim().is_empty()
+        {
+            return span;
+        }
+        let end = if line_end < text.len() { line_end + 1 } else { line_end };
+        #[allow(clippy::cast_possible_truncation)]
+        Span::new(line_start as u32, end as u32)
+    }
+
+    /// The indentation of the line where `position` is.
+    fn indentation(&self, position: u32) -> &'s str {
+        let text = self.source_text;
+        let line_start = text[..position as usize].rfind('\n').map_or(0, |i| i + 1);
+        let line = &text[line_start..];
+        &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// A replacement of a span of the source text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub content: String,
+}
+
+/// Edits of the source text which keep the comments of [CommentMap] with their nodes.
+pub struct CommentEdits<'m, 's> {
+    map: &'m CommentMap<'s>,
+    edits: Vec<TextEdit>,
+}
+
+impl<'m, 's> CommentEdits<'m, 's> {
+    pub fn new(map: &'m CommentMap<'s>) -> Self {
+        Self { map, edits: vec![] }
+    }
+
+    /// Insert `text` before `node` and its leading comments.
+    pub fn insert_before(&mut self, node: Span, text: &str) {
+        let start = self.map.full_span(node).start;
+        self.push(Span::new(start, start), text.to_string());
+    }
+
+    /// Insert `text` after `node` and its trailing comments.
+    pub fn insert_after(&mut self, node: Span, text: &str) {
+        let end = self.map.full_span(node).end;
+        self.push(Span::new(end, end), text.to_string());
+    }
+
+    /// Replace `node` with `text`, which is preceded by the comments inside of `node`.
+    pub fn replace(&mut self, node: Span, text: &str) {
+        let mut content = self.inner_comments(node, !text.is_empty());
+        content.push_str(text);
+        self.push(node, content);
+    }
+
+    /// Delete `node`, and keep the comments inside of it and attached to it.
+    pub fn delete(&mut self, node: Span) {
+        let rest = &self.map.source_text[node.end as usize..];
+        let rest_of_line = rest.split('\n').next().unwrap_or_default();
+        let content = self.inner_comments(node, !rest_of_line.trim().is_empty());
+        let span = if content.is_empty() { self.map.line_span(node) } else { node };
+        self.push(span, content);
+    }
+
+    /// Delete `node` with its comments.
+    pub fn delete_with_comments(&mut self, node: Span) {
+        let span = self.map.line_span(self.map.full_span(node));
+        self.push(span, String::new());
+    }
+
+    /// Move `node` and its comments before `anchor` and its leading comments, followed by
+    /// `separator`.
+    pub fn move_before(&mut self, node: Span, anchor: Span, separator: &str) {
+        let text = self.map.full_span(node).source_text(self.map.source_text);
+        self.insert_before(anchor, &format!("{text}{separator}"));
+        self.delete_with_comments(node);
+    }
+
+    /// Move `node` and its comments after `anchor` and its trailing comments, preceded by
+    /// `separator`.
+    pub fn move_after(&mut self, node: Span, anchor: Span, separator: &str) {
+        let text = self.map.full_span(node).source_text(self.map.source_text);
+        self.insert_after(anchor, &format!("{separator}{text}"));
+        self.delete_with_comments(node);
+    }
+
+    /// The edits, in source order. Insertions at the same position keep their order.
+    pub fn edits(&self) -> Vec<TextEdit> {
+        let mut edits = self.edits.clone();
+        edits.sort_by_key(|edit| (edit.span.start, edit.span.size() > 0));
+        edits
+    }
+
+    /// The source text after the edits, or `None` if two edits overlap.
+    pub fn apply(&self) -> Option<String> {
+        let source_text = self.map.source_text;
+        let mut output = String::with_capacity(source_text.len());
+        let mut last = 0;
+        for edit in self.edits() {
+            if (edit.span.start as usize) < last {
+                return None;
+            }
+            output.push_str(&source_text[last..edit.span.start as usize]);
+            output.push_str(&edit.content);
+            last = edit.span.end as usize;
+        }
+        output.push_str(&source_text[last..]);
+        Some(output)
+    }
+
+    /// A single edit which replaces the span of all edits, or `None` if there are no edits or two
+    /// edits overlap.
+    pub fn merged(&self) -> Option<TextEdit> {
+        let edits = self.edits();
+        let start = edits.first()?.span.start;
+        let end = edits.iter().map(|edit| edit.span.end).max()?;
+        let mut content = String::new();
+        let mut last = start as usize;
+        for edit in edits {
+            if (edit.span.start as usize) < last {
+                return None;
+            }
+            content.push_str(&self.map.source_text[last..edit.span.start as usize]);
+            content.push_str(&edit.content);
+            last = edit.span.end as usize;
+        }
+        Some(TextEdit { span: Span::new(start, end), content })
+    }
+
+    fn push(&mut self, span: Span, content: String) {
+        self.edits.push(TextEdit { span, content });
+    }
+
+    /// The comments inside of `node`, separated from each other and from the text which follows
+    /// them if `followed` is true.
+    fn inner_comments(&self, node: Span, followed: bool) -> String {
+        let mut content = String::new();
+        let comments: Vec<_> = self.map.comments_in(node).collect();
+        for (i, comment) in comments.iter().enumerate() {
+            content.push_str(comment.span.source_text(self.map.source_text));
+            if i + 1 == comments.len() && !followed {
+                break;
+            }
+            // A line comment would comment out the rest of its line.
+            if comment.kind == CommentKind::SingleLine {
+                content.push('\n');
+                content.push_str(self.map.indentation(node.start));
+            } else {
+                content.push(' ');
+            }
+        }
+        content
+    }
+}
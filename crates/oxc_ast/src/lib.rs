@@ -6,6 +6,14 @@
 //! * `Identifier` is replaced with explicit `BindingIdentifier`, `IdentifierReference`, `IdentifierName` per spec
 //! * `AssignmentExpression`.`left` `Pattern` is replaced with `AssignmentTarget`
 //!
+//! ## AST Schema
+//! `ast_schema.json` describes the fields and variants of the AST types, see [`visit`] for its
+//! stability guarantees.
+//!
+//! `estree_schema.json` describes the JSON of `Program::to_json` with the `serialize` feature:
+//! the fields of every node and the strings of every enum, after the renames of the serializers.
+//! Tools in other languages can generate typed bindings for the JSON AST from it.
+//!
 //! ## Cargo Features
 //! * `"serde"` enables support for serde serialization
 
@@ -14,7 +22,10 @@ mod serialize;
 
 pub mod ast;
 mod ast_builder;
+mod ast_diff;
 mod ast_kind;
+mod clone_in;
+mod comment_edits;
 pub mod precedence;
 mod span;
 pub mod syntax_directed_operations;
@@ -25,7 +36,10 @@ pub use num_bigint::BigUint;
 
 pub use crate::{
     ast_builder::AstBuilder,
+    ast_diff::{AstChange, AstDiff, AstSnapshot, ContentEq, NodeRole, SnapshotNode},
     ast_kind::{AstKind, AstType},
+    clone_in::{CloneCtx, CloneIds, CloneIn},
+    comment_edits::{AttachedComment, CommentEdits, CommentMap, CommentPosition, TextEdit},
     trivia::{Comment, CommentKind, Trivias, TriviasMap},
     visit::{Visit, VisitMut},
 };
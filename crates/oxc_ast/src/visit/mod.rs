@@ -1,3 +1,15 @@
+//! Visitors of the AST
+//!
+//! [`Visit`] and [`VisitMut`] are stable within a minor version of `oxc_ast`, like the AST itself:
+//!
+//! * A visitor method for a new AST type has a default implementation which walks it, so
+//!   implementations of the traits outside this crate keep compiling.
+//! * The existing AST types, listed in `ast_schema.json` at the root of this crate, only change
+//!   in a new minor version, which is checked before a release with `just ast-schema-check`.
+//!
+//! The schema describes the fields and variants of every AST type, so crates outside this repo
+//! can generate their own code for the AST from it, like `oxc_traverse` does for `Traverse`.
+
 #![allow(clippy::module_inception)]
 
 mod visit;
@@ -2621,6 +2621,12 @@ pub mod walk {
         if let Some(parameters) = &decl.type_parameters {
             visitor.visit_ts_type_parameter_declaration(parameters);
         }
+        for heritage in decl.extends.iter().flatten() {
+            visitor.visit_expression(&heritage.expression);
+            if let Some(parameters) = &heritage.type_parameters {
+                visitor.visit_ts_type_parameter_instantiation(parameters);
+            }
+        }
         for signature in &decl.body.body {
             visitor.visit_ts_signature(signature);
         }
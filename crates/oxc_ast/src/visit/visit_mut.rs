@@ -2773,6 +2773,12 @@ pub mod walk_mut {
         if let Some(parameters) = &mut decl.type_parameters {
             visitor.visit_ts_type_parameter_declaration(parameters);
         }
+        for heritage in decl.extends.iter_mut().flat_map(|extends| extends.iter_mut()) {
+            visitor.visit_expression(&mut heritage.expression);
+            if let Some(parameters) = &mut heritage.type_parameters {
+                visitor.visit_ts_type_parameter_instantiation(parameters);
+            }
+        }
         for signature in decl.body.body.iter_mut() {
             visitor.visit_ts_signature(signature);
         }
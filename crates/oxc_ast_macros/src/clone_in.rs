@@ -0,0 +1,115 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Index};
+
+pub fn derive_clone_in(input: &DeriveInput) -> TokenStream {
+    let ident = &input.ident;
+    let (body, uses_ctx) = match &input.data {
+        Data::Struct(data) => {
+            clone_fields(&quote!(#ident), &data.fields, |field| quote!(&self.#field))
+        }
+        Data::Enum(data) => {
+            let mut uses_ctx = false;
+            let arms = data.variants.iter().map(|variant| {
+                let variant_ident = &variant.ident;
+                let pattern = bind_fields(&variant.fields);
+                let (construct, variant_uses_ctx) =
+                    clone_fields(&quote!(#ident::#variant_ident), &variant.fields, |field| {
+                        let binding = binding_ident(field);
+                        quote!(#binding)
+                    });
+                uses_ctx |= variant_uses_ctx;
+                quote!(Self::#variant_ident #pattern => #construct)
+            });
+            let arms = arms.collect::<Vec<_>>();
+            (quote!(match self { #(#arms),* }), uses_ctx)
+        }
+        Data::Union(_) => panic!("`CloneIn` cannot be derived for unions"),
+    };
+
+    let ctx = if uses_ctx { quote!(ctx) } else { quote!(_ctx) };
+    if input.generics.lifetimes().next().is_some() {
+        quote! {
+            impl<'old_alloc, 'new_alloc> crate::CloneIn<'new_alloc> for #ident<'old_alloc> {
+                type Cloned = #ident<'new_alloc>;
+
+                fn clone_in_with(&self, #ctx: &mut crate::CloneCtx<'new_alloc, '_>) -> Self::Cloned {
+                    #body
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl<'new_alloc> crate::CloneIn<'new_alloc> for #ident {
+                type Cloned = #ident;
+
+                fn clone_in_with(&self, #ctx: &mut crate::CloneCtx<'new_alloc, '_>) -> Self::Cloned {
+                    #body
+                }
+            }
+        }
+    }
+}
+
+/// A field of a struct or variant, by name or by position.
+enum FieldAccess<'a> {
+    Named(&'a Ident),
+    Unnamed(usize),
+}
+
+impl quote::ToTokens for FieldAccess<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Named(ident) => ident.to_tokens(tokens),
+            Self::Unnamed(index) => Index::from(*index).to_tokens(tokens),
+        }
+    }
+}
+
+fn field_accesses(fields: &Fields) -> Vec<FieldAccess<'_>> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| field.ident.as_ref().map_or(FieldAccess::Unnamed(i), FieldAccess::Named))
+        .collect()
+}
+
+/// Name bound to a field of an enum variant in a match pattern.
+fn binding_ident(field: &FieldAccess) -> Ident {
+    match field {
+        FieldAccess::Named(ident) => (*ident).clone(),
+        FieldAccess::Unnamed(index) => format_ident!("field_{index}"),
+    }
+}
+
+/// Pattern binding every field of an enum variant.
+fn bind_fields(fields: &Fields) -> TokenStream {
+    let bindings = field_accesses(fields).iter().map(binding_ident).collect::<Vec<_>>();
+    match fields {
+        Fields::Named(_) => quote!({ #(#bindings),* }),
+        Fields::Unnamed(_) => quote!((#(#bindings),*)),
+        Fields::Unit => quote!(),
+    }
+}
+
+/// Expression constructing `path` from the clones of `fields`, where `access` is the expression
+/// of a reference to a field. Also returns whether any field is cloned, using the context.
+fn clone_fields<F>(path: &TokenStream, fields: &Fields, access: F) -> (TokenStream, bool)
+where
+    F: Fn(&FieldAccess) -> TokenStream,
+{
+    let accesses = field_accesses(fields);
+    let clones = accesses.iter().map(|field| {
+        let access = access(field);
+        quote!(crate::CloneIn::clone_in_with(#access, ctx))
+    });
+    let construct = match fields {
+        Fields::Named(_) => {
+            let names = accesses.iter();
+            quote!(#path { #(#names: #clones),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#clones),*)),
+        Fields::Unit => quote!(#path),
+    };
+    (construct, !accesses.is_empty())
+}
@@ -1,8 +1,20 @@
-use proc_macro::TokenStream;
-
-/// Attach to AST node type (struct or enum), to signal to codegen to create visitor for this type.
-/// Macro itself does nothing - just passes through the token stream unchanged.
-#[proc_macro_attribute]
-pub fn visited_node(_args: TokenStream, input: TokenStream) -> TokenStream {
-    input
-}
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod clone_in;
+
+/// Attach to AST node type (struct or enum), to signal to codegen to create visitor for this type.
+/// Macro itself does nothing - just passes through the token stream unchanged.
+#[proc_macro_attribute]
+pub fn visited_node(_args: TokenStream, input: TokenStream) -> TokenStream {
+    input
+}
+
+/// Derive `CloneIn` for an AST node type, cloning each field into the new allocator.
+///
+/// Only usable inside `oxc_ast`, the generated impl refers to `crate::CloneIn`.
+#[proc_macro_derive(CloneIn)]
+pub fn derive_clone_in(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    clone_in::derive_clone_in(&input).into()
+}
@@ -26,9 +26,13 @@ fn main() -> std::io::Result<()> {
         return Ok(());
     }
 
-    let codegen_options = CodegenOptions { enable_source_map: true, enable_typescript: true };
+    let codegen_options = CodegenOptions {
+        enable_source_map: true,
+        enable_typescript: true,
+        ..CodegenOptions::default()
+    };
 
-    let CodegenReturn { source_text, source_map } =
+    let CodegenReturn { source_text, source_map, .. } =
         Codegen::<false>::new(path.to_string_lossy().as_ref(), &source_text, codegen_options)
             .build(&ret.program);
 
@@ -0,0 +1,145 @@
+//! Embedded languages inside tagged template literals.
+//!
+//! Template literals tagged with a configured name, e.g.
+//!
+//! ```javascript
+//! const query = gql`
+//!   query { user { id } }
+//! `;
+//! const Button = styled.button`
+//!   color: ${color};
+//! `;
+//! ```
+//!
+//! are printed from their raw source text and are never re-escaped or normalized.
+//! A [TemplateTagHandler] can be attached to a tag to rewrite the raw text of each quasi,
+//! e.g. for removing insignificant whitespace from CSS or GraphQL.
+
+use std::{fmt, sync::Arc};
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+
+/// Rewrites the raw text of the quasis of a tagged template literal.
+pub trait TemplateTagHandler: Send + Sync {
+    /// Transform the raw text of a single quasi.
+    ///
+    /// `is_first` and `is_last` tell whether the quasi starts or ends the template literal.
+    /// Return `None` to print the raw text untouched.
+    fn transform_quasi(&self, raw: &str, is_first: bool, is_last: bool) -> Option<String>;
+}
+
+/// A template tag whose template literals contain an embedded language.
+#[derive(Clone)]
+pub struct TemplateTag {
+    /// Name of the tag.
+    ///
+    /// Matched against the leftmost identifier of the tag expression,
+    /// so `styled` matches `styled.div`, `styled(Button)` and `styled.div.attrs({})`.
+    pub name: String,
+
+    /// Optional handler for rewriting the quasis.
+    pub handler: Option<Arc<dyn TemplateTagHandler>>,
+}
+
+impl TemplateTag {
+    /// Preserve the template literals tagged with `name` as is.
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self { name: name.into(), handler: None }
+    }
+
+    #[must_use]
+    pub fn with_handler<H: TemplateTagHandler + 'static>(mut self, handler: H) -> Self {
+        self.handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// `gql`, `css` and `html`, without any handlers.
+    pub fn defaults() -> Vec<Self> {
+        vec![Self::new("gql"), Self::new("css"), Self::new("html")]
+    }
+
+    pub(crate) fn find<'t>(tags: &'t [Self], tag: &Expression<'_>) -> Option<&'t Self> {
+        if tags.is_empty() {
+            return None;
+        }
+        let name = Self::root_identifier(tag)?;
+        tags.iter().find(|t| t.name == name)
+    }
+
+    fn root_identifier<'b>(expr: &'b Expression<'_>) -> Option<&'b str> {
+        match expr.get_inner_expression() {
+            Expression::Identifier(ident) => Some(ident.name.as_str()),
+            Expression::CallExpression(call) => Self::root_identifier(&call.callee),
+            expr @ match_member_expression!(Expression) => {
+                Self::root_identifier(expr.to_member_expression().object())
+            }
+            _ => None,
+        }
+    }
+
+    /// Raw text of a quasi after running the handler.
+    ///
+    /// Falls back to the original text when the handler produces something
+    /// that would terminate the template literal or start a substitution.
+    pub(crate) fn transform_quasi(
+        &self,
+        raw: &str,
+        is_first: bool,
+        is_last: bool,
+    ) -> Option<String> {
+        let text = self.handler.as_ref()?.transform_quasi(raw, is_first, is_last)?;
+        let is_valid = !text.contains('`') && !text.contains("${") && !text.ends_with('\\');
+        is_valid.then_some(text)
+    }
+}
+
+impl fmt::Debug for TemplateTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TemplateTag")
+            .field("name", &self.name)
+            .field("handler", &self.handler.is_some())
+            .finish()
+    }
+}
+
+/// Collapses every run of whitespace into a single space,
+/// and removes leading and trailing whitespace of the template literal.
+/// Whitespace inside of quoted strings is kept.
+///
+/// Suitable for CSS and GraphQL, where whitespace is insignificant outside of strings.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CollapseWhitespace;
+
+impl TemplateTagHandler for CollapseWhitespace {
+    fn transform_quasi(&self, raw: &str, is_first: bool, is_last: bool) -> Option<String> {
+        let mut text = String::with_capacity(raw.len());
+        let mut in_whitespace = false;
+        let mut escaped = false;
+        let mut quote = None;
+        for c in raw.chars() {
+            if let Some(q) = quote {
+                // Whitespace inside of a string is significant.
+                if c == q && !escaped {
+                    quote = None;
+                }
+            } else if c.is_whitespace() && !escaped {
+                // Escaped whitespace, e.g. a line continuation, is significant.
+                in_whitespace = true;
+                continue;
+            } else if (c == '"' || c == '\'') && !escaped {
+                quote = Some(c);
+            }
+            escaped = c == '\\' && !escaped;
+            if in_whitespace && !(is_first && text.is_empty()) {
+                text.push(' ');
+            }
+            in_whitespace = false;
+            text.push(c);
+        }
+        if in_whitespace && !is_last && !(is_first && text.is_empty()) {
+            text.push(' ');
+        }
+        Some(text)
+    }
+}
@@ -0,0 +1,178 @@
+use std::{fmt, str::FromStr};
+
+use oxc_syntax::identifier::{LS, PS};
+
+use crate::Codegen;
+
+/// Sequences escaped in the printed strings, template literals and regular expressions, so the
+/// code can be embedded in another format.
+///
+/// Tagged templates are printed as is, because the tag can read the raw text, and so are JSX
+/// text and attributes, which have no escapes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EscapeProfile {
+    /// Escape `U+2028` and `U+2029` in string literals, which end the line in engines before
+    /// ES2019.
+    #[default]
+    Default,
+    /// Also escape `U+2028` and `U+2029` in template literals, so the code is valid wherever the
+    /// characters are line terminators, e.g. in a string of a JSON file evaluated by such an
+    /// engine.
+    JsonSafe,
+    /// Like `JsonSafe`, and also escape `</script` and `<!--`, which end or change the script
+    /// data of a `<script>` element in HTML.
+    InlineScriptSafe,
+}
+
+impl EscapeProfile {
+    fn escapes_line_separators(self) -> bool {
+        self != Self::Default
+    }
+
+    fn escapes_html(self) -> bool {
+        self == Self::InlineScriptSafe
+    }
+}
+
+impl FromStr for EscapeProfile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Self::Default),
+            "json-safe" => Ok(Self::JsonSafe),
+            "inline-script-safe" => Ok(Self::InlineScriptSafe),
+            _ => Err(format!("Invalid escape profile \"{s}\".")),
+        }
+    }
+}
+
+impl fmt::Display for EscapeProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Default => "default",
+            Self::JsonSafe => "json-safe",
+            Self::InlineScriptSafe => "inline-script-safe",
+        };
+        f.write_str(s)
+    }
+}
+
+/// The HTML sequence which starts with the `<` at the start of `rest`, if any.
+fn html_sequence(rest: &str) -> Option<HtmlSequence> {
+    let rest = rest.strip_prefix('<')?;
+    if rest.get(..7).is_some_and(|s| s.eq_ignore_ascii_case("/script")) {
+        Some(HtmlSequence::ScriptEnd)
+    } else if rest.starts_with("!--") {
+        Some(HtmlSequence::CommentStart)
+    } else {
+        None
+    }
+}
+
+enum HtmlSequence {
+    /// `</script`, case insensitive.
+    ScriptEnd,
+    /// `<!--`
+    CommentStart,
+}
+
+impl<const MINIFY: bool> Codegen<MINIFY> {
+    /// Print the `<` at the start of `rest`, which is the rest of a string literal or template
+    /// literal, and break up `</script` and `<!--` by escaping the character after it, which the
+    /// caller prints unchanged.
+    pub(crate) fn print_lt_in_string(&mut self, rest: &str) {
+        self.print(b'<');
+        if self.options.escape.escapes_html() && html_sequence(rest).is_some() {
+            // `\/` and `\!` are the characters themselves.
+            self.print(b'\\');
+        }
+    }
+
+    /// Print the raw text of a quasi of an untagged template literal, where escapes do not change
+    /// the value of the template.
+    pub(crate) fn print_template_raw(&mut self, raw: &str) {
+        let profile = self.options.escape;
+        if !profile.escapes_line_separators() {
+            self.print_str(raw.as_bytes());
+            return;
+        }
+        let mut chars = raw.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    // Print the escaped character unchanged.
+                    let Some((i, c)) = chars.next() else { break };
+                    match c {
+                        // A line continuation is not part of the value.
+                        LS | PS => {}
+                        '<' => {
+                            self.print(b'\\');
+                            self.print_lt_in_string(&raw[i..]);
+                        }
+                        _ => {
+                            let mut buf = [0; 4];
+                            self.print(b'\\');
+                            self.print_str(c.encode_utf8(&mut buf).as_bytes());
+                        }
+                    }
+                }
+                LS => self.print_str(b"\\u2028"),
+                PS => self.print_str(b"\\u2029"),
+                '<' => self.print_lt_in_string(&raw[i..]),
+                _ => {
+                    let mut buf = [0; 4];
+                    self.print_str(c.encode_utf8(&mut buf).as_bytes());
+                }
+            }
+        }
+    }
+
+    /// Print the pattern of a regular expression literal, which contains no line terminators.
+    pub(crate) fn print_regexp_pattern(&mut self, pattern: &str) {
+        if !self.options.escape.escapes_html() || !pattern.contains('<') {
+            self.print_str(pattern.as_bytes());
+            return;
+        }
+        let mut in_class = false;
+        let mut escaped = false;
+        let mut chars = pattern.char_indices();
+        while let Some((i, c)) = chars.next() {
+            let mut buf = [0; 4];
+            self.print_str(c.encode_utf8(&mut buf).as_bytes());
+            if escaped {
+                escaped = false;
+            } else {
+                match c {
+                    '\\' => {
+                        escaped = true;
+                        continue;
+                    }
+                    '[' => in_class = true,
+                    ']' => in_class = false,
+                    _ => {}
+                }
+            }
+            if c != '<' {
+                continue;
+            }
+            match html_sequence(&pattern[i..]) {
+                // `/` is only unescaped in a class, where `\/` is `/` too.
+                Some(HtmlSequence::ScriptEnd) => self.print(b'\\'),
+                // `\!` is not a valid escape with the `u` flag.
+                Some(HtmlSequence::CommentStart) => {
+                    if !in_class && pattern[..i].ends_with("(?") {
+                        // The negative lookbehind `(?<!-` is broken up after the `!`.
+                        self.print(b'!');
+                        self.print_str(b"\\x2D");
+                        chars.nth(1);
+                    } else {
+                        self.print_str(b"\\x21");
+                        chars.next();
+                    }
+                }
+                None => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Printing a part of a program on its own.
+//!
+//! For bundlers which move a function, a class or some statements into another chunk or a
+//! worker. The part is printed as a standalone script, with its own source map mapping into the
+//! source text given to [Codegen::new].
+//!
+//! The printed code is only correct where it was taken from if it keeps its strictness, so
+//! `strict` tells whether the part is strict mode code there, e.g. because the program is a
+//! module. A `'use strict'` directive is printed when the part would not be strict on its own.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, Visit};
+use oxc_diagnostics::OxcDiagnostic;
+
+use crate::{Codegen, CodegenReturn, Context, Gen, TargetChecker};
+
+impl<const MINIFY: bool> Codegen<MINIFY> {
+    /// Print a single function.
+    ///
+    /// Function expressions, and function declarations without a name such as
+    /// `export default function() {}`, are printed in parentheses.
+    pub fn build_function(mut self, func: &Function<'_>, strict: bool) -> CodegenReturn {
+        let errors = self.check_target(|checker| checker.visit_function(func, None));
+        if strict && !func.is_strict() {
+            self.print_use_strict();
+        }
+        if func.is_declaration() && func.id.is_some() {
+            func.gen(&mut self, Context::default());
+            self.print_soft_newline();
+        } else {
+            self.print_parenthesized(|p| func.gen(p, Context::default()));
+        }
+        self.into_return(errors)
+    }
+
+    /// Print a single class.
+    ///
+    /// Class expressions, and class declarations without a name such as
+    /// `export default class {}`, are printed in parentheses. Classes are always strict mode
+    /// code.
+    pub fn build_class(mut self, class: &Class<'_>) -> CodegenReturn {
+        let errors = self.check_target(|checker| checker.visit_class(class));
+        if class.is_declaration() && class.id.is_some() {
+            class.gen(&mut self, Context::default());
+            self.print_soft_newline();
+        } else {
+            self.print_parenthesized(|p| class.gen(p, Context::default()));
+        }
+        self.into_return(errors)
+    }
+
+    /// Print a list of statements, which must be valid at the top level of a script, e.g. no
+    /// `return` or `break` outside of the statements.
+    pub fn build_statements(mut self, statements: &[Statement<'_>], strict: bool) -> CodegenReturn {
+        let errors = self.check_target(|checker| {
+            for stmt in statements {
+                checker.visit_statement(stmt);
+            }
+        });
+        if strict {
+            self.print_use_strict();
+        }
+        // No directives, so that a leading string literal statement is not printed as one.
+        self.print_directives_and_statements_with_semicolon_order(
+            Some(&[]),
+            statements,
+            Context::default(),
+            false,
+        );
+        self.into_return(errors)
+    }
+
+    fn check_target<F: FnOnce(&mut TargetChecker)>(&self, visit: F) -> Vec<OxcDiagnostic> {
+        self.options.target.map_or_else(Vec::new, |target| TargetChecker::new(target).check(visit))
+    }
+
+    fn print_use_strict(&mut self) {
+        self.print_str(b"'use strict';");
+        self.print_soft_newline();
+    }
+
+    /// Print an expression as an expression statement which can not be mistaken for a
+    /// declaration.
+    fn print_parenthesized<F: FnMut(&mut Self)>(&mut self, f: F) {
+        self.wrap(true, f);
+        self.print_semicolon_after_statement();
+        self.print_semicolon_if_needed();
+    }
+}
@@ -3,14 +3,14 @@ use oxc_allocator::{Box, Vec};
 use oxc_ast::ast::*;
 use oxc_span::GetSpan;
 use oxc_syntax::{
-    identifier::{LS, PS},
+    identifier::{is_line_terminator, LS, PS},
     keyword::is_reserved_keyword_or_global_object,
     number::NumberBase,
     operator::{BinaryOperator, UnaryOperator},
     precedence::{GetPrecedence, Precedence},
 };
 
-use super::{Codegen, Context, Operator, Separator};
+use super::{Codegen, Context, Operator, Separator, TemplateTag};
 
 pub trait Gen<const MINIFY: bool> {
     fn gen(&self, _p: &mut Codegen<{ MINIFY }>, _ctx: Context) {}
@@ -41,7 +41,12 @@ where
 impl<'a, const MINIFY: bool> Gen<MINIFY> for Program<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
         if let Some(hashbang) = &self.hashbang {
-            hashbang.gen(p, ctx);
+            if !p.options.strip_hashbang {
+                hashbang.gen(p, ctx);
+                if !self.directives.is_empty() || !self.body.is_empty() {
+                    p.print_newline();
+                }
+            }
         }
         print_directives_and_statements(p, &self.directives, &self.body, ctx);
     }
@@ -64,13 +69,14 @@ fn print_directives_and_statements<const MINIFY: bool>(
 impl<'a, const MINIFY: bool> Gen<MINIFY> for Hashbang<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
         p.print_str(b"#!");
-        p.print_str(self.value.as_bytes());
+        // The value ends with the line break after the hashbang, unless it is the whole file.
+        p.print_str(self.value.trim_end_matches(is_line_terminator).as_bytes());
     }
 }
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for Directive<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         // A Use Strict Directive may not contain an EscapeSequence or LineContinuation.
         // So here should print original `directive` value, the `expression` value is escaped str.
         // See https://github.com/babel/babel/blob/main/packages/babel-generator/src/generators/base.ts#L64
@@ -110,7 +116,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for Statement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ExpressionStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.start_of_stmt = p.code_len();
         p.print_expression(&self.expression);
@@ -124,7 +130,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ExpressionStatement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for IfStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         print_if(self, p, ctx);
     }
@@ -147,10 +153,10 @@ fn print_if<const MINIFY: bool>(
             p.print_block1(block, ctx);
         }
         stmt if wrap_to_avoid_ambiguous_else(stmt) => {
-            p.print_block_start(stmt.span().start);
+            p.print_block_start(stmt.span());
             stmt.gen(p, ctx);
             p.needs_semicolon = false;
-            p.print_block_end(stmt.span().end);
+            p.print_block_end(stmt.span());
         }
         stmt => {
             stmt.gen(p, ctx);
@@ -216,7 +222,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for BlockStatement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ForStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str(b"for");
         p.print_soft_space();
@@ -255,7 +261,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ForStatement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ForInStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str(b"for");
         p.print_soft_space();
@@ -274,7 +280,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ForInStatement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ForOfStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str(b"for");
         p.print_soft_space();
@@ -312,7 +318,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ForStatementLeft<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for WhileStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str(b"while");
         p.print(b'(');
@@ -324,7 +330,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for WhileStatement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for DoWhileStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str(b"do ");
         if let Statement::BlockStatement(block) = &self.body {
@@ -347,7 +353,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for DoWhileStatement<'a> {
 
 impl<const MINIFY: bool> Gen<MINIFY> for EmptyStatement {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_semicolon();
     }
@@ -355,7 +361,7 @@ impl<const MINIFY: bool> Gen<MINIFY> for EmptyStatement {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ContinueStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str(b"continue");
         if let Some(label) = &self.label {
@@ -368,7 +374,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ContinueStatement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for BreakStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str(b"break");
         if let Some(label) = &self.label {
@@ -381,18 +387,18 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for BreakStatement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for SwitchStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str(b"switch");
         p.print(b'(');
         p.print_expression(&self.discriminant);
         p.print(b')');
-        p.print_block_start(self.span.start);
+        p.print_block_start(self.span);
         for case in &self.cases {
-            p.add_source_mapping(case.span.start);
+            p.add_source_mapping(case.span);
             case.gen(p, ctx);
         }
-        p.print_block_end(self.span.end);
+        p.print_block_end(self.span);
         p.print_soft_newline();
         p.needs_semicolon = false;
     }
@@ -423,7 +429,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for SwitchCase<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ReturnStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str(b"return");
         if let Some(arg) = &self.argument {
@@ -436,7 +442,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ReturnStatement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for LabeledStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         self.label.gen(p, ctx);
         p.print_colon();
@@ -446,7 +452,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for LabeledStatement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for TryStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str(b"try");
         p.print_block1(&self.block, ctx);
@@ -468,7 +474,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for TryStatement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ThrowStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str(b"throw ");
         p.print_expression(&self.argument);
@@ -478,7 +484,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ThrowStatement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for WithStatement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str(b"with");
         p.print(b'(');
@@ -490,7 +496,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for WithStatement<'a> {
 
 impl<const MINIFY: bool> Gen<MINIFY> for DebuggerStatement {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_indent();
         p.print_str(b"debugger");
         p.print_semicolon_after_statement();
@@ -607,7 +613,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for UsingDeclaration<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for VariableDeclaration<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         if p.options.enable_typescript && self.modifiers.contains(ModifierKind::Declare) {
             p.print_str(b"declare ");
         }
@@ -636,7 +642,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for VariableDeclarator<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for Function<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         if !p.options.enable_typescript && self.is_typescript_syntax() {
             return;
         }
@@ -684,14 +690,14 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for Function<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for FunctionBody<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.print_block_start(self.span.start);
+        p.print_block_start(self.span);
         p.print_directives_and_statements_with_semicolon_order(
             Some(&self.directives),
             &self.statements,
             ctx,
             true,
         );
-        p.print_block_end(self.span.end);
+        p.print_block_end(self.span);
         p.needs_semicolon = false;
     }
 }
@@ -721,7 +727,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for FormalParameters<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ImportDeclaration<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str(b"import ");
         if p.options.enable_typescript && self.import_kind.is_type() {
             p.print_str(b"type ");
@@ -806,7 +812,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ImportDeclaration<'a> {
             p.print_hard_space();
         }
         self.with_clause.gen(p, ctx);
-        p.add_source_mapping(self.span.end);
+        p.add_source_mapping_end(self.span);
         p.print_semicolon_after_statement();
     }
 }
@@ -821,7 +827,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for Option<WithClause<'a>> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for WithClause<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         self.attributes_keyword.gen(p, ctx);
         p.print_soft_space();
         p.print_block(&self.with_entries, Separator::Comma, ctx, self.span);
@@ -843,7 +849,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ImportAttribute<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ExportNamedDeclaration<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         if !p.options.enable_typescript && self.is_typescript_syntax() {
             return;
         }
@@ -899,7 +905,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ModuleExportName<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ExportAllDeclaration<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         if !p.options.enable_typescript && self.is_typescript_syntax() {
             return;
         }
@@ -927,7 +933,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ExportAllDeclaration<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ExportDefaultDeclaration<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         if !p.options.enable_typescript && self.is_typescript_syntax() {
             return;
         }
@@ -962,7 +968,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for Expression<'a> {
             Self::NumericLiteral(lit) => lit.gen(p, ctx),
             Self::BigintLiteral(lit) => lit.gen(p, ctx),
             Self::RegExpLiteral(lit) => lit.gen(p, ctx),
-            Self::StringLiteral(lit) => lit.gen(p, ctx),
+            Self::StringLiteral(lit) => print_string_expression(lit, p, ctx),
             Self::Identifier(ident) => ident.gen(p, ctx),
             Self::ThisExpression(expr) => expr.gen(p, ctx),
             match_member_expression!(Self) => {
@@ -1039,7 +1045,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for IdentifierReference<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for IdentifierName<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str(self.name.as_bytes());
     }
 }
@@ -1059,7 +1065,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for LabelIdentifier<'a> {
 
 impl<const MINIFY: bool> Gen<MINIFY> for BooleanLiteral {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str(self.as_str().as_bytes());
     }
 }
@@ -1067,7 +1073,7 @@ impl<const MINIFY: bool> Gen<MINIFY> for BooleanLiteral {
 impl<const MINIFY: bool> Gen<MINIFY> for NullLiteral {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
         p.print_space_before_identifier();
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str(b"null");
     }
 }
@@ -1082,7 +1088,7 @@ fn need_space_before_dot<const MINIFY: bool>(bytes: &[u8], p: &mut Codegen<{ MIN
 impl<'a, const MINIFY: bool> Gen<MINIFY> for NumericLiteral<'a> {
     #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         if self.value != f64::INFINITY && (MINIFY || self.raw.is_empty()) {
             p.print_space_before_identifier();
             let abs_value = self.value.abs();
@@ -1124,6 +1130,8 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for NumericLiteral<'a> {
 }
 
 // TODO: refactor this with less allocations
+// `Display` for `f64` prints the shortest digits which round-trip, the same on every platform and
+// independent of enabled features, which keeps the output reproducible.
 fn print_non_negative_float<const MINIFY: bool>(value: f64, _p: &Codegen<{ MINIFY }>) -> String {
     let mut result = value.to_string();
     let chars = result.as_bytes();
@@ -1186,14 +1194,14 @@ fn print_non_negative_float<const MINIFY: bool>(value: f64, _p: &Codegen<{ MINIF
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for BigIntLiteral<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str(self.raw.as_bytes());
     }
 }
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for RegExpLiteral<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         let last = p.peek_nth(0);
         // Avoid forming a single-line comment or "</script" sequence
         if Some('/') == last
@@ -1203,7 +1211,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for RegExpLiteral<'a> {
             p.print_hard_space();
         }
         p.print(b'/');
-        p.print_str(self.regex.pattern.as_bytes());
+        p.print_regexp_pattern(self.regex.pattern.as_str());
         p.print(b'/');
         p.print_str(self.regex.flags.to_string().as_bytes());
         p.prev_reg_exp_end = p.code().len();
@@ -1211,12 +1219,12 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for RegExpLiteral<'a> {
 }
 
 fn print_unquoted_str<const MINIFY: bool>(s: &str, quote: char, p: &mut Codegen<{ MINIFY }>) {
-    let mut chars = s.chars();
+    let mut chars = s.char_indices();
 
-    while let Some(c) = chars.next() {
+    while let Some((i, c)) = chars.next() {
         match c {
             '\x00' => {
-                if chars.clone().next().is_some_and(|next| next.is_ascii_digit()) {
+                if chars.clone().next().is_some_and(|(_, next)| next.is_ascii_digit()) {
                     p.print_str(b"\\x00");
                 } else {
                     p.print_str(b"\\0");
@@ -1238,7 +1246,11 @@ fn print_unquoted_str<const MINIFY: bool>(s: &str, quote: char, p: &mut Codegen<
                 p.print_str(b"\\f");
             }
             '\n' => {
-                p.print_str(b"\\n");
+                if quote == '`' {
+                    p.print(b'\n');
+                } else {
+                    p.print_str(b"\\n");
+                }
             }
             '\r' => {
                 p.print_str(b"\\r");
@@ -1271,7 +1283,7 @@ fn print_unquoted_str<const MINIFY: bool>(s: &str, quote: char, p: &mut Codegen<
                 }
             }
             '$' => {
-                if chars.clone().next().is_some_and(|next| next == '{') {
+                if quote == '`' && chars.clone().next().is_some_and(|(_, next)| next == '{') {
                     p.print_str(b"\\$");
                 } else {
                     p.print_str(b"$");
@@ -1285,6 +1297,7 @@ fn print_unquoted_str<const MINIFY: bool>(s: &str, quote: char, p: &mut Codegen<
             '\u{a0}' => {
                 p.print_str(b"\\xA0");
             }
+            '<' => p.print_lt_in_string(&s[i..]),
             _ => p.print_str(c.escape_default().to_string().as_bytes()),
         }
     }
@@ -1292,7 +1305,7 @@ fn print_unquoted_str<const MINIFY: bool>(s: &str, quote: char, p: &mut Codegen<
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for StringLiteral<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         let s = self.value.as_str();
         p.wrap_quote(s, |p, quote| {
             print_unquoted_str(s, quote, p);
@@ -1300,9 +1313,46 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for StringLiteral<'a> {
     }
 }
 
+/// Print a string literal in expression position, where it can be replaced by a template
+/// literal, which is shorter in minify mode for strings with line breaks.
+fn print_string_expression<const MINIFY: bool>(
+    lit: &StringLiteral,
+    p: &mut Codegen<{ MINIFY }>,
+    ctx: Context,
+) {
+    let s = lit.value.as_str();
+    if MINIFY && p.supports_template_literals() && is_shorter_as_template(s) {
+        p.add_source_mapping(lit.span);
+        p.print(b'`');
+        print_unquoted_str(s, '`', p);
+        p.print(b'`');
+    } else {
+        lit.gen(p, ctx);
+    }
+}
+
+/// A line break takes one character in a template literal instead of the two of `\n`, and
+/// quotes need no escaping, but `` ` `` and `${` do.
+fn is_shorter_as_template(s: &str) -> bool {
+    let (mut line_breaks, mut single_quotes, mut double_quotes, mut template_escapes) =
+        (0, 0, 0, 0);
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => line_breaks += 1,
+            '\'' => single_quotes += 1,
+            '"' => double_quotes += 1,
+            '`' => template_escapes += 1,
+            '$' if chars.peek() == Some(&'{') => template_escapes += 1,
+            _ => {}
+        }
+    }
+    template_escapes < line_breaks + usize::min(single_quotes, double_quotes)
+}
+
 impl<const MINIFY: bool> Gen<MINIFY> for ThisExpression {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_space_before_identifier();
         p.print_str(b"this");
     }
@@ -1362,7 +1412,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for CallExpression<'a> {
         let wrap = precedence > self.precedence() || ctx.has_forbid_call();
         let ctx = ctx.and_forbid_call(false);
         p.wrap(wrap, |p| {
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             self.callee.gen_expr(p, self.precedence(), ctx);
             if self.optional {
                 p.print_str(b"?.");
@@ -1375,7 +1425,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for CallExpression<'a> {
             p.print(b'(');
             p.print_list(&self.arguments, ctx);
             p.print(b')');
-            p.add_source_mapping(self.span.end);
+            p.add_source_mapping_end(self.span);
         });
     }
 }
@@ -1405,7 +1455,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ArrayExpressionElement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for SpreadElement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_ellipsis();
         self.argument.gen_expr(p, Precedence::Assign, Context::default());
     }
@@ -1413,14 +1463,14 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for SpreadElement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ArrayExpression<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print(b'[');
         p.print_list(&self.elements, ctx);
         if self.trailing_comma.is_some() {
             p.print_comma();
         }
         p.print(b']');
-        p.add_source_mapping(self.span.end);
+        p.add_source_mapping_end(self.span);
     }
 }
 
@@ -1429,7 +1479,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for ObjectExpression<'a> {
         let n = p.code_len();
         let is_multi_line = !self.properties.is_empty();
         p.wrap(p.start_of_stmt == n || p.start_of_arrow_expr == n, |p| {
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             p.print(b'{');
             if is_multi_line {
                 p.indent();
@@ -1448,7 +1498,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for ObjectExpression<'a> {
                 p.print_indent();
             }
             p.print(b'}');
-            p.add_source_mapping(self.span.end);
+            p.add_source_mapping_end(self.span);
         });
     }
 }
@@ -1465,16 +1515,16 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ObjectPropertyKind<'a> {
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ObjectProperty<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
         if let Expression::FunctionExpression(func) = &self.value {
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             let is_accessor = match &self.kind {
                 PropertyKind::Init => false,
                 PropertyKind::Get => {
-                    p.add_source_mapping(self.span.start);
+                    p.add_source_mapping(self.span);
                     p.print_str(b"get ");
                     true
                 }
                 PropertyKind::Set => {
-                    p.add_source_mapping(self.span.start);
+                    p.add_source_mapping(self.span);
                     p.print_str(b"set ");
                     true
                 }
@@ -1528,6 +1578,8 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for PropertyKey<'a> {
         match self {
             Self::StaticIdentifier(ident) => ident.gen(p, ctx),
             Self::PrivateIdentifier(ident) => ident.gen(p, ctx),
+            // A template literal is not a valid property key.
+            Self::StringLiteral(lit) => lit.gen(p, ctx),
             match_expression!(Self) => {
                 self.to_expression().gen_expr(p, Precedence::Assign, Context::default());
             }
@@ -1539,7 +1591,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for ArrowFunctionExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
         p.wrap(precedence > Precedence::Assign, |p| {
             if self.r#async {
-                p.add_source_mapping(self.span.start);
+                p.add_source_mapping(self.span);
                 p.print_str(b"async");
             }
 
@@ -1558,7 +1610,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for ArrowFunctionExpression<'a> {
                 }
             }
             if !nowrap {
-                p.add_source_mapping(self.span.start);
+                p.add_source_mapping(self.span);
             }
             p.wrap(!nowrap, |p| {
                 self.params.gen(p, ctx);
@@ -1588,7 +1640,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for ArrowFunctionExpression<'a> {
 impl<'a, const MINIFY: bool> GenExpr<MINIFY> for YieldExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
         p.wrap(precedence >= self.precedence(), |p| {
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             p.print_space_before_identifier();
             p.print_str(b"yield");
             if self.delegate {
@@ -1609,7 +1661,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for UpdateExpression<'a> {
         let operator = self.operator.as_str().as_bytes();
         p.wrap(precedence > self.precedence(), |p| {
             if self.prefix {
-                p.add_source_mapping(self.span.start);
+                p.add_source_mapping(self.span);
                 p.print_space_before_operator(self.operator.into());
                 p.print_str(operator);
                 p.prev_op = Some(self.operator.into());
@@ -1809,21 +1861,21 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for AssignmentTargetPattern<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ArrayAssignmentTarget<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print(b'[');
         p.print_list(&self.elements, ctx);
         if let Some(target) = &self.rest {
             if !self.elements.is_empty() {
                 p.print_comma();
             }
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             target.gen(p, ctx);
         }
         if self.trailing_comma.is_some() {
             p.print_comma();
         }
         p.print(b']');
-        p.add_source_mapping(self.span.end);
+        p.add_source_mapping_end(self.span);
     }
 }
 
@@ -1837,18 +1889,18 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for Option<AssignmentTargetMaybeDefault
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ObjectAssignmentTarget<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print(b'{');
         p.print_list(&self.properties, ctx);
         if let Some(target) = &self.rest {
             if !self.properties.is_empty() {
                 p.print_comma();
             }
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             target.gen(p, ctx);
         }
         p.print(b'}');
-        p.add_source_mapping(self.span.end);
+        p.add_source_mapping_end(self.span);
     }
 }
 
@@ -1928,7 +1980,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for ImportExpression<'a> {
         let wrap = precedence > self.precedence() || ctx.has_forbid_call();
         let ctx = ctx.and_forbid_call(false);
         p.wrap(wrap, |p| {
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             p.print_str(b"import(");
             self.source.gen_expr(p, Precedence::Assign, ctx);
             if !self.arguments.is_empty() {
@@ -1942,35 +1994,80 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for ImportExpression<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for TemplateLiteral<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.print(b'`');
-        let mut expressions = self.expressions.iter();
+        print_template_literal(p, self, true);
+    }
+}
 
-        for quasi in &self.quasis {
-            p.add_source_mapping(quasi.span.start);
-            p.print_str(quasi.value.raw.as_bytes());
+/// Print a template literal, escaping its raw text for `CodegenOptions::escape` if it is not
+/// tagged.
+fn print_template_literal<const MINIFY: bool>(
+    p: &mut Codegen<{ MINIFY }>,
+    template: &TemplateLiteral<'_>,
+    escape: bool,
+) {
+    p.print(b'`');
+    let mut expressions = template.expressions.iter();
 
-            if let Some(expr) = expressions.next() {
-                p.print_str(b"${");
-                p.print_expression(expr);
-                p.print(b'}');
-            }
+    for quasi in &template.quasis {
+        p.add_source_mapping(quasi.span);
+        if escape {
+            p.print_template_raw(quasi.value.raw.as_str());
+        } else {
+            p.print_str(quasi.value.raw.as_bytes());
         }
 
-        p.print(b'`');
+        if let Some(expr) = expressions.next() {
+            p.print_str(b"${");
+            p.print_expression(expr);
+            p.print(b'}');
+        }
     }
+
+    p.print(b'`');
 }
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for TaggedTemplateExpression<'a> {
-    fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+    fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
+        p.add_source_mapping(self.span);
         self.tag.gen_expr(p, Precedence::Postfix, Context::default());
-        self.quasi.gen(p, ctx);
+        if let Some(tag) = TemplateTag::find(&p.options.template_tags, &self.tag).cloned() {
+            print_embedded_template(p, &self.quasi, &tag);
+        } else {
+            print_template_literal(p, &self.quasi, false);
+        }
     }
 }
 
+fn print_embedded_template<const MINIFY: bool>(
+    p: &mut Codegen<{ MINIFY }>,
+    quasi: &TemplateLiteral<'_>,
+    tag: &TemplateTag,
+) {
+    p.print(b'`');
+    let mut expressions = quasi.expressions.iter();
+    let last = quasi.quasis.len().saturating_sub(1);
+
+    for (i, element) in quasi.quasis.iter().enumerate() {
+        p.add_source_mapping(element.span);
+        let raw = element.value.raw.as_str();
+        match tag.transform_quasi(raw, i == 0, i == last) {
+            Some(text) => p.print_str(text.as_bytes()),
+            None => p.print_str(raw.as_bytes()),
+        }
+
+        if let Some(expr) = expressions.next() {
+            p.print_str(b"${");
+            p.print_expression(expr);
+            p.print(b'}');
+        }
+    }
+
+    p.print(b'`');
+}
+
 impl<const MINIFY: bool> Gen<MINIFY> for Super {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str(b"super");
     }
 }
@@ -1978,7 +2075,7 @@ impl<const MINIFY: bool> Gen<MINIFY> for Super {
 impl<'a, const MINIFY: bool> GenExpr<MINIFY> for AwaitExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
         p.wrap(precedence > self.precedence(), |p| {
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             p.print_str(b"await ");
             self.argument.gen_expr(p, self.precedence(), ctx);
         });
@@ -1999,7 +2096,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for ChainExpression<'a> {
 impl<'a, const MINIFY: bool> GenExpr<MINIFY> for NewExpression<'a> {
     fn gen_expr(&self, p: &mut Codegen<{ MINIFY }>, precedence: Precedence, ctx: Context) {
         p.wrap(precedence > self.precedence(), |p| {
-            p.add_source_mapping(self.span.start);
+            p.add_source_mapping(self.span);
             p.print_str(b"new ");
             self.callee.gen_expr(p, Precedence::NewWithoutArgs, ctx.and_forbid_call(true));
             p.wrap(true, |p| {
@@ -2011,7 +2108,7 @@ impl<'a, const MINIFY: bool> GenExpr<MINIFY> for NewExpression<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for MetaProperty<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         self.meta.gen(p, ctx);
         p.print(b'.');
         self.property.gen(p, ctx);
@@ -2020,7 +2117,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for MetaProperty<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for Class<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         if !p.options.enable_typescript && self.is_declare() {
             return;
         }
@@ -2041,7 +2138,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for Class<'a> {
                 super_class.gen_expr(p, Precedence::Call, Context::default());
             }
             p.print_soft_space();
-            p.print_block_start(self.body.span.start);
+            p.print_block_start(self.body.span);
             for item in &self.body.body {
                 if !p.options.enable_typescript && item.is_typescript_syntax() {
                     continue;
@@ -2059,7 +2156,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for Class<'a> {
                 }
                 p.print_soft_newline();
             }
-            p.print_block_end(self.body.span.end);
+            p.print_block_end(self.body.span);
             p.needs_semicolon = false;
         });
     }
@@ -2164,7 +2261,10 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for JSXAttributeValue<'a> {
         match self {
             Self::Fragment(fragment) => fragment.gen(p, ctx),
             Self::Element(el) => el.gen(p, ctx),
-            Self::StringLiteral(lit) => lit.gen(p, ctx),
+            Self::StringLiteral(lit) => {
+                p.add_source_mapping(lit.span);
+                p.print_jsx_string(&lit.value);
+            }
             Self::ExpressionContainer(expr_container) => expr_container.gen(p, ctx),
         }
     }
@@ -2189,24 +2289,33 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for JSXAttributeItem<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for JSXOpeningElement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
-        p.print_str(b"<");
-        self.name.gen(p, ctx);
-        for attr in &self.attributes {
-            p.print_hard_space();
-            attr.gen(p, ctx);
-        }
-        if self.self_closing {
-            p.print_str(b"/>");
-        } else {
-            p.print(b'>');
-        }
+        print_jsx_opening_element(self, self.self_closing, p, ctx);
+    }
+}
+
+fn print_jsx_opening_element<const MINIFY: bool>(
+    el: &JSXOpeningElement,
+    self_closing: bool,
+    p: &mut Codegen<{ MINIFY }>,
+    ctx: Context,
+) {
+    p.add_source_mapping(el.span);
+    p.print_str(b"<");
+    el.name.gen(p, ctx);
+    for attr in &el.attributes {
+        p.print_hard_space();
+        attr.gen(p, ctx);
+    }
+    if self_closing {
+        p.print_str(b"/>");
+    } else {
+        p.print(b'>');
     }
 }
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for JSXClosingElement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str(b"</");
         self.name.gen(p, ctx);
         p.print(b'>');
@@ -2215,41 +2324,51 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for JSXClosingElement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for JSXElement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        self.opening_element.gen(p, ctx);
+        let self_closing =
+            p.is_jsx_self_closing(self.opening_element.self_closing, !self.children.is_empty());
+        print_jsx_opening_element(&self.opening_element, self_closing, p, ctx);
+        if self_closing {
+            return;
+        }
         for child in &self.children {
             child.gen(p, ctx);
         }
         if let Some(closing_element) = &self.closing_element {
             closing_element.gen(p, ctx);
+        } else {
+            p.print_str(b"</");
+            self.opening_element.name.gen(p, ctx);
+            p.print(b'>');
         }
     }
 }
 
 impl<const MINIFY: bool> Gen<MINIFY> for JSXOpeningFragment {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str(b"<>");
     }
 }
 
 impl<const MINIFY: bool> Gen<MINIFY> for JSXClosingFragment {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str(b"</>");
     }
 }
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for JSXText<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str(self.value.as_bytes());
     }
 }
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for JSXSpreadChild<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, _ctx: Context) {
-        p.print_str(b"...");
+        p.print_str(b"{...");
         p.print_expression(&self.expression);
+        p.print(b'}');
     }
 }
 
@@ -2258,7 +2377,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for JSXChild<'a> {
         match self {
             Self::Fragment(fragment) => fragment.gen(p, ctx),
             Self::Element(el) => el.gen(p, ctx),
-            Self::Spread(spread) => p.print_expression(&spread.expression),
+            Self::Spread(spread) => spread.gen(p, ctx),
             Self::ExpressionContainer(expr_container) => expr_container.gen(p, ctx),
             Self::Text(text) => text.gen(p, ctx),
         }
@@ -2277,14 +2396,14 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for JSXFragment<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for StaticBlock<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_str(b"static");
-        p.print_block_start(self.span.start);
+        p.print_block_start(self.span);
         for stmt in &self.body {
             p.print_semicolon_if_needed();
             stmt.gen(p, ctx);
         }
-        p.print_block_end(self.span.end);
+        p.print_block_end(self.span);
         p.needs_semicolon = false;
     }
 }
@@ -2294,7 +2413,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for MethodDefinition<'a> {
         if !p.options.enable_typescript && self.value.is_typescript_syntax() {
             return;
         }
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         self.decorators.gen(p, ctx);
 
         if p.options.enable_typescript
@@ -2353,7 +2472,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for MethodDefinition<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for PropertyDefinition<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         self.decorators.gen(p, ctx);
         if p.options.enable_typescript {
             if self.r#type == PropertyDefinitionType::TSAbstractPropertyDefinition {
@@ -2399,7 +2518,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for PropertyDefinition<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for AccessorProperty<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         if p.options.enable_typescript && self.r#type.is_abstract() {
             p.print_str(b"abstract ");
         }
@@ -2452,7 +2571,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for BindingPattern<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ObjectPattern<'a> {
     fn gen(&self, p: &mut Codegen<MINIFY>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print(b'{');
         p.print_list(&self.properties, ctx);
         if let Some(rest) = &self.rest {
@@ -2462,13 +2581,13 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ObjectPattern<'a> {
             rest.gen(p, ctx);
         }
         p.print(b'}');
-        p.add_source_mapping(self.span.end);
+        p.add_source_mapping_end(self.span);
     }
 }
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for BindingProperty<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         if self.computed {
             p.print(b'[');
         }
@@ -2487,7 +2606,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for BindingProperty<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for BindingRestElement<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print_ellipsis();
         self.argument.gen(p, ctx);
     }
@@ -2495,7 +2614,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for BindingRestElement<'a> {
 
 impl<'a, const MINIFY: bool> Gen<MINIFY> for ArrayPattern<'a> {
     fn gen(&self, p: &mut Codegen<{ MINIFY }>, ctx: Context) {
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print(b'[');
         for (index, item) in self.elements.iter().enumerate() {
             if index != 0 {
@@ -2512,7 +2631,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for ArrayPattern<'a> {
             rest.gen(p, ctx);
         }
         p.print(b']');
-        p.add_source_mapping(self.span.end);
+        p.add_source_mapping_end(self.span);
     }
 }
 
@@ -2551,7 +2670,7 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for Decorator<'a> {
             }
         }
 
-        p.add_source_mapping(self.span.start);
+        p.add_source_mapping(self.span);
         p.print(b'@');
         let wrap = need_wrap(&self.expression);
         p.wrap(wrap, |p| {
@@ -535,18 +535,18 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for TSModuleDeclaration<'a> {
         p.print_hard_space();
         match &self.body {
             Some(TSModuleDeclarationBody::TSModuleDeclaration(body)) => {
-                p.print_block_start(body.span.start);
+                p.print_block_start(body.span);
                 body.gen(p, ctx);
-                p.print_block_end(body.span.end);
+                p.print_block_end(body.span);
             }
             Some(TSModuleDeclarationBody::TSModuleBlock(body)) => {
-                p.print_block_start(body.span.start);
+                p.print_block_start(body.span);
                 for item in &body.body {
                     p.print_semicolon_if_needed();
                     item.gen(p, ctx);
                 }
                 p.print_semicolon_if_needed();
-                p.print_block_end(body.span.end);
+                p.print_block_end(body.span);
             }
             None => {}
         }
@@ -577,14 +577,14 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for TSInterfaceDeclaration<'a> {
             }
         }
         p.print_soft_space();
-        p.print_block_start(self.body.span.start);
+        p.print_block_start(self.body.span);
         for item in &self.body.body {
             p.print_indent();
             p.print_semicolon_if_needed();
             item.gen(p, ctx);
             p.print_semicolon_after_statement();
         }
-        p.print_block_end(self.body.span.end);
+        p.print_block_end(self.body.span);
         if MINIFY {
             p.print_hard_space();
         }
@@ -622,9 +622,9 @@ impl<'a, const MINIFY: bool> Gen<MINIFY> for TSEnumDeclaration<'a> {
         p.print_str(b"enum ");
         self.id.gen(p, ctx);
         p.print_space_before_identifier();
-        p.print_block_start(self.span.start);
+        p.print_block_start(self.span);
         p.print_list(&self.members, ctx);
-        p.print_block_end(self.span.end);
+        p.print_block_end(self.span);
         p.print_hard_space();
     }
 }
@@ -0,0 +1,113 @@
+use std::{fmt, str::FromStr};
+
+use crate::Codegen;
+
+/// How JSX is printed.
+///
+/// JSX is printed as is, not transformed into function calls, for the tools downstream which
+/// handle JSX themselves. Text and string attributes have no escapes in JSX, so they are printed
+/// as they are written.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JsxOptions {
+    /// Whether elements without children are printed as self-closing.
+    ///
+    /// See [JsxSelfClosing].
+    pub self_closing: JsxSelfClosing,
+
+    /// The quotes around string attributes.
+    ///
+    /// See [JsxQuote].
+    pub quote: JsxQuote,
+}
+
+/// Whether elements without children are printed as self-closing, e.g. `<div />`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsxSelfClosing {
+    /// Print `<div />` and `<div></div>` as they are written.
+    #[default]
+    Preserve,
+    /// Print `<div></div>` as `<div />`.
+    Always,
+    /// Print `<div />` as `<div></div>`.
+    Never,
+}
+
+/// The quotes around string attributes, e.g. `<a href="/">`.
+///
+/// The other quote is used for a string which contains the preferred one, because a string
+/// attribute can not escape its quote.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsxQuote {
+    #[default]
+    Double,
+    Single,
+}
+
+impl FromStr for JsxSelfClosing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" => Ok(Self::Preserve),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err(format!("Invalid JSX self-closing \"{s}\".")),
+        }
+    }
+}
+
+impl fmt::Display for JsxSelfClosing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Preserve => "preserve",
+            Self::Always => "always",
+            Self::Never => "never",
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromStr for JsxQuote {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "double" => Ok(Self::Double),
+            "single" => Ok(Self::Single),
+            _ => Err(format!("Invalid JSX quote \"{s}\".")),
+        }
+    }
+}
+
+impl fmt::Display for JsxQuote {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Double => "double",
+            Self::Single => "single",
+        };
+        f.write_str(s)
+    }
+}
+
+impl<const MINIFY: bool> Codegen<MINIFY> {
+    /// Whether an element with `children` is printed as self-closing.
+    pub(crate) fn is_jsx_self_closing(&self, self_closing: bool, has_children: bool) -> bool {
+        match self.options.jsx.self_closing {
+            JsxSelfClosing::Preserve => self_closing,
+            JsxSelfClosing::Always => !has_children,
+            JsxSelfClosing::Never => false,
+        }
+    }
+
+    /// Print the value of a string attribute in quotes, without escapes.
+    pub(crate) fn print_jsx_string(&mut self, value: &str) {
+        let (preferred, other) = match self.options.jsx.quote {
+            JsxQuote::Double => (b'"', b'\''),
+            JsxQuote::Single => (b'\'', b'"'),
+        };
+        let quote = if value.as_bytes().contains(&preferred) { other } else { preferred };
+        self.print(quote);
+        self.print_str(value.as_bytes());
+        self.print(quote);
+    }
+}
@@ -4,18 +4,32 @@
 //!
 //! * whitespace removal
 //! * sourcemaps
+//! * target syntax validation
+//! * printing a single function, class or list of statements
+//! * validation of the printed code by parsing it again
+//! * source-preserving edits of a mutated program
+//! * JSX, printed as is
 //!
 //! Code adapted from
 //! * [esbuild](https://github.com/evanw/esbuild/blob/main/internal/js_printer/js_printer.go)
 
 mod context;
+mod embedded;
+mod escape;
+mod fragment;
 mod gen;
 mod gen_ts;
+mod jsx;
+mod newline;
 mod operator;
+mod source_edit;
 mod sourcemap_builder;
+mod target;
+mod validate;
 
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{Atom, Span};
 use oxc_syntax::{
     identifier::is_identifier_part,
@@ -23,12 +37,20 @@ use oxc_syntax::{
     precedence::Precedence,
     symbol::SymbolId,
 };
-use sourcemap_builder::SourcemapBuilder;
 
+use crate::validate::Validator;
 pub use crate::{
     context::Context,
+    embedded::{CollapseWhitespace, TemplateTag, TemplateTagHandler},
+    escape::EscapeProfile,
     gen::{Gen, GenExpr},
+    jsx::{JsxOptions, JsxQuote, JsxSelfClosing},
+    newline::{FinalNewline, Newline},
     operator::Operator,
+    source_edit::{SourceEditor, SourceEdits},
+    sourcemap_builder::SourcemapBuilder,
+    target::{EsTarget, TargetChecker},
+    validate::ValidationError,
 };
 // use crate::mangler::Mangler;
 
@@ -39,15 +61,65 @@ pub struct CodegenOptions {
 
     /// Enable TypeScript code generation.
     pub enable_typescript: bool,
+
+    /// Tags of template literals containing embedded languages, e.g. `gql`, `css` and `html`.
+    ///
+    /// See [TemplateTag].
+    pub template_tags: Vec<TemplateTag>,
+
+    /// Report syntax which is not available in this ECMAScript version,
+    /// e.g. BigInt literals for `es2019`.
+    ///
+    /// The code is still printed as is, see [CodegenReturn::errors].
+    pub target: Option<EsTarget>,
+
+    /// Sequences to escape in strings, for embedding the code in HTML or JSON.
+    ///
+    /// See [EscapeProfile].
+    pub escape: EscapeProfile,
+
+    /// How JSX is printed, which is printed as is.
+    ///
+    /// See [JsxOptions].
+    pub jsx: JsxOptions,
+
+    /// The line break between statements, `\n` by default.
+    pub newline: Newline,
+
+    /// Whether the code ends with a line break.
+    ///
+    /// See [FinalNewline].
+    pub final_newline: FinalNewline,
+
+    /// Do not print the hashbang of the program, e.g. `#!/usr/bin/env node`.
+    pub strip_hashbang: bool,
+
+    /// Print a byte order mark when the source text starts with one.
+    pub preserve_bom: bool,
+
+    /// Parse the printed code and compare its AST with the printed program, to find the code
+    /// which is printed without a space it needs (`a--b` for `a - -b`), or where a semicolon
+    /// would be inserted.
+    ///
+    /// This parses the code again, so it is meant for checking the output of a corpus in CI,
+    /// not for production builds. Parentheses and template literals printed as strings are not
+    /// reported, but the template literals rewritten by [CodegenOptions::template_tags] are.
+    /// TypeScript programs are only validated with [CodegenOptions::enable_typescript].
+    ///
+    /// See [CodegenReturn::validation_error].
+    pub validate: bool,
 }
 
 pub struct CodegenReturn {
     pub source_text: String,
     pub source_map: Option<oxc_sourcemap::SourceMap>,
+    /// Syntax incompatible with [CodegenOptions::target].
+    pub errors: Vec<OxcDiagnostic>,
+    /// The printed code does not mean the same as the program, with [CodegenOptions::validate].
+    pub validation_error: Option<ValidationError>,
 }
 
 pub struct Codegen<const MINIFY: bool> {
-    #[allow(unused)]
     options: CodegenOptions,
 
     // mangler: Option<Mangler>,
@@ -72,6 +144,9 @@ pub struct Codegen<const MINIFY: bool> {
     indentation: u8,
 
     sourcemap_builder: Option<SourcemapBuilder>,
+
+    /// The source text starts with a byte order mark, which is printed with `preserve_bom`.
+    has_bom: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -94,6 +169,8 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
             sourcemap_builder
         });
 
+        let has_bom = source_text.starts_with('\u{feff}');
+
         Self {
             options,
             // mangler: None,
@@ -108,6 +185,7 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
             start_of_default_export: 0,
             indentation: 0,
             sourcemap_builder,
+            has_bom,
         }
     }
 
@@ -116,10 +194,42 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
     // }
 
     pub fn build(mut self, program: &Program<'_>) -> CodegenReturn {
+        let errors = self
+            .options
+            .target
+            .map_or_else(Vec::new, |target| TargetChecker::new(target).build(program));
+        if self.options.preserve_bom && self.has_bom {
+            self.print_str("\u{feff}".as_bytes());
+        }
         program.gen(&mut self, Context::default());
+        self.print_final_newline();
+        let validator = self.options.validate.then(|| Validator::new(&self.options));
+        let mut ret = self.into_return(errors);
+        ret.validation_error =
+            validator.and_then(|validator| validator.validate(program, &ret.source_text));
+        ret
+    }
+
+    fn print_final_newline(&mut self) {
+        match self.options.final_newline {
+            FinalNewline::Auto => {}
+            FinalNewline::Always => {
+                if !self.code.is_empty() && !self.code.ends_with(b"\n") {
+                    self.print_newline();
+                }
+            }
+            FinalNewline::Never => {
+                while let Some(b'\n' | b'\r') = self.code.last() {
+                    self.code.pop();
+                }
+            }
+        }
+    }
+
+    fn into_return(mut self, errors: Vec<OxcDiagnostic>) -> CodegenReturn {
         let source_text = self.into_source_text();
         let source_map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
-        CodegenReturn { source_text, source_map }
+        CodegenReturn { source_text, source_map, errors, validation_error: None }
     }
 
     pub fn into_source_text(&mut self) -> String {
@@ -158,9 +268,14 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
         self.print(b' ');
     }
 
+    /// Print the line break of [CodegenOptions::newline].
+    fn print_newline(&mut self) {
+        self.print_str(self.options.newline.as_bytes());
+    }
+
     fn print_soft_newline(&mut self) {
         if !MINIFY {
-            self.print(b'\n');
+            self.print_newline();
         }
     }
 
@@ -211,7 +326,8 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
         if MINIFY {
             self.needs_semicolon = true;
         } else {
-            self.print_str(b";\n");
+            self.print_semicolon();
+            self.print_newline();
         }
     }
 
@@ -247,24 +363,24 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
         }
     }
 
-    fn print_block_start(&mut self, position: u32) {
-        self.add_source_mapping(position);
+    fn print_block_start(&mut self, span: Span) {
+        self.add_source_mapping(span);
         self.print(b'{');
         self.print_soft_newline();
         self.indent();
     }
 
-    fn print_block_end(&mut self, position: u32) {
+    fn print_block_end(&mut self, span: Span) {
         self.dedent();
         self.print_indent();
-        self.add_source_mapping(position);
+        self.add_source_mapping_end(span);
         self.print(b'}');
     }
 
     fn print_block1(&mut self, stmt: &BlockStatement<'_>, ctx: Context) {
-        self.print_block_start(stmt.span.start);
+        self.print_block_start(stmt.span);
         self.print_directives_and_statements_with_semicolon_order(None, &stmt.body, ctx, true);
-        self.print_block_end(stmt.span.end);
+        self.print_block_end(stmt.span);
         self.needs_semicolon = false;
     }
 
@@ -275,9 +391,9 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
         ctx: Context,
         span: Span,
     ) {
-        self.print_block_start(span.start);
+        self.print_block_start(span);
         self.print_sequence(items, separator, ctx);
-        self.print_block_end(span.end);
+        self.print_block_end(span);
     }
 
     fn print_list<T: Gen<MINIFY>>(&mut self, items: &[T], ctx: Context) {
@@ -366,6 +482,11 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
         }
     }
 
+    /// Whether template literals can be printed for [CodegenOptions::target].
+    fn supports_template_literals(&self) -> bool {
+        self.options.target.map_or(true, |target| target >= EsTarget::ES2015)
+    }
+
     fn wrap_quote<F: FnMut(&mut Self, char)>(&mut self, s: &str, mut f: F) {
         let quote = choose_quote(s);
         self.print(quote as u8);
@@ -413,9 +534,17 @@ impl<const MINIFY: bool> Codegen<MINIFY> {
         }
     }
 
-    fn add_source_mapping(&mut self, position: u32) {
+    /// Map the code printed next to the start of `span`.
+    fn add_source_mapping(&mut self, span: Span) {
+        if let Some(sourcemap_builder) = self.sourcemap_builder.as_mut() {
+            sourcemap_builder.add_span_mapping(&self.code, span);
+        }
+    }
+
+    /// Map the code printed next to the end of `span`.
+    fn add_source_mapping_end(&mut self, span: Span) {
         if let Some(sourcemap_builder) = self.sourcemap_builder.as_mut() {
-            sourcemap_builder.add_source_mapping(&self.code, position, None);
+            sourcemap_builder.add_span_end_mapping(&self.code, span);
         }
     }
 
@@ -0,0 +1,55 @@
+use std::{fmt, str::FromStr};
+
+/// The line break printed between statements and after the hashbang.
+///
+/// Line breaks in template literals are part of their value, and are always printed as `\n`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Newline {
+    #[default]
+    Lf,
+    CrLf,
+}
+
+impl Newline {
+    pub(crate) fn as_bytes(self) -> &'static [u8] {
+        match self {
+            Self::Lf => b"\n",
+            Self::CrLf => b"\r\n",
+        }
+    }
+}
+
+impl FromStr for Newline {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lf" => Ok(Self::Lf),
+            "crlf" => Ok(Self::CrLf),
+            _ => Err(format!("Invalid newline \"{s}\".")),
+        }
+    }
+}
+
+impl fmt::Display for Newline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Lf => "lf",
+            Self::CrLf => "crlf",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Whether the printed program ends with a line break.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FinalNewline {
+    /// End with the line break printed after the last statement, if any. The code is only
+    /// printed with line breaks when it is not minified.
+    #[default]
+    Auto,
+    /// Always end a non-empty program with a line break.
+    Always,
+    /// Never end with a line break.
+    Never,
+}
@@ -0,0 +1,636 @@
+//! Source-preserving edits
+//!
+//! For codemods which mutate the AST of a program, and want to change only the code they
+//! mutate instead of printing the whole program, so that the formatting and the comments of the
+//! rest of the source text are kept:
+//!
+//! ```ignore
+//! let original = AstSnapshot::new(&program);
+//! codemod.visit_program(&mut program);
+//! let edits = SourceEditor::new(source_text, options).build(&original, &program);
+//! let output = edits.apply(source_text);
+//! ```
+//!
+//! The mutated program is compared with the [AstSnapshot] taken before the mutation, from the
+//! program down, and a node keeps its source text while it is equal by content to the original
+//! node at its position:
+//!
+//! * In a list of statements, the statements which keep their original span stay in place, the
+//!   others are deleted with their line, and the new statements are inserted on their own lines.
+//! * Elsewhere, a changed statement or expression replaces the source text of the original one,
+//!   in parentheses when its precedence is lower than its position requires.
+//! * Any other change, e.g. a renamed property key, is printed with the closest statement or
+//!   expression which contains it.
+//!
+//! The new nodes are printed and re-indented to the source text, except the nodes which are
+//! unchanged from the original node at their span, e.g. a statement moved into another block,
+//! whose source text is copied with its comments. The comments inside of a printed node are lost.
+//!
+//! [SourceEdits] are plain [TextEdit]s, so that the edits of several codemods can be merged and
+//! their conflicts resolved before they are applied.
+
+use std::collections::{HashMap, HashSet};
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{
+    ast::*,
+    visit::walk::{walk_expression, walk_statement},
+    AstKind, AstSnapshot, AstType, NodeRole, SnapshotNode, TextEdit, Visit,
+};
+use oxc_span::Span;
+use oxc_syntax::precedence::Precedence;
+
+use crate::{Codegen, CodegenOptions, Context, Gen, GenExpr};
+
+/// Computes the [SourceEdits] of a mutated program, see the [module docs](self).
+pub struct SourceEditor<'s> {
+    source_text: &'s str,
+    options: CodegenOptions,
+    /// One level of indentation in the source text.
+    indent_unit: &'s str,
+    newline: &'static str,
+}
+
+impl<'s> SourceEditor<'s> {
+    pub fn new(source_text: &'s str, options: CodegenOptions) -> Self {
+        let options = CodegenOptions { enable_source_map: false, validate: false, ..options };
+        let newline = if source_text.contains("\r\n") { "\r\n" } else { "\n" };
+        Self { source_text, options, indent_unit: indent_unit(source_text), newline }
+    }
+
+    /// The edits which turn the source text into the code of `program`, where `original` is the
+    /// snapshot of `program` taken before it was mutated.
+    pub fn build(&self, original: &AstSnapshot, program: &Program<'_>) -> SourceEdits {
+        let current = AstSnapshot::new(program);
+        let mut planner = Planner::new(self, original.root());
+        if planner.edit(original.root(), current.root()).is_err() {
+            // The directives or the hashbang changed.
+            let content = self.codegen().build(program).source_text;
+            return SourceEdits { edits: vec![TextEdit { span: original.root().span(), content }] };
+        }
+
+        let requested = planner
+            .edits
+            .iter()
+            .flat_map(|edit| &edit.parts)
+            .filter_map(|part| match part {
+                Part::Print { index, .. } => Some(*index),
+                _ => None,
+            })
+            .collect();
+        let mut printer = Printer { editor: self, requested, printed: HashMap::new(), entered: 0 };
+        printer.visit_program(program);
+
+        let edits = planner.edits.into_iter().map(|edit| edit.render(self, &printer.printed));
+        SourceEdits { edits: edits.collect() }
+    }
+
+    fn codegen(&self) -> Codegen<false> {
+        Codegen::<false>::new("", self.source_text, self.options.clone())
+    }
+}
+
+/// Text edits of a source text, from one or more codemods.
+#[derive(Debug, Default, Clone)]
+pub struct SourceEdits {
+    edits: Vec<TextEdit>,
+}
+
+impl SourceEdits {
+    pub fn new(edits: Vec<TextEdit>) -> Self {
+        Self { edits }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    /// All edits, in the order they were added.
+    pub fn edits(&self) -> &[TextEdit] {
+        &self.edits
+    }
+
+    /// Add the edits of another codemod, which lose to the edits added before them when they
+    /// conflict.
+    pub fn merge(&mut self, other: Self) {
+        self.edits.extend(other.edits);
+    }
+
+    /// The edits which can be applied together, in source order, and the edits which conflict
+    /// with an edit added before them.
+    ///
+    /// Two edits conflict when they replace overlapping text, or when one inserts text inside
+    /// of the text the other replaces. Identical edits are kept once, and the insertions at the
+    /// same position are kept in the order they were added.
+    pub fn resolve(&self) -> (Vec<TextEdit>, Vec<TextEdit>) {
+        let mut accepted: Vec<TextEdit> = vec![];
+        let mut conflicts = vec![];
+        for edit in &self.edits {
+            if accepted.contains(edit) {
+                continue;
+            }
+            if accepted.iter().any(|other| overlaps(other.span, edit.span)) {
+                conflicts.push(edit.clone());
+            } else {
+                accepted.push(edit.clone());
+            }
+        }
+        accepted.sort_by_key(|edit| (edit.span.start, edit.span.size() > 0));
+        (accepted, conflicts)
+    }
+
+    /// The source text after the edits which do not conflict, see [SourceEdits::resolve].
+    pub fn apply(&self, source_text: &str) -> String {
+        let (edits, _) = self.resolve();
+        let mut output = String::with_capacity(source_text.len());
+        let mut last = 0;
+        for edit in edits {
+            output.push_str(&source_text[last..edit.span.start as usize]);
+            output.push_str(&edit.content);
+            last = edit.span.end as usize;
+        }
+        output.push_str(&source_text[last..]);
+        output
+    }
+}
+
+/// Whether the spans overlap, where an empty span only overlaps the spans it is strictly inside.
+fn overlaps(a: Span, b: Span) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+/// An edit whose nodes are not printed yet.
+struct Edit {
+    span: Span,
+    parts: Vec<Part>,
+}
+
+enum Part {
+    Text(String),
+    /// The source text of an unchanged node.
+    Source(Span),
+    /// A node of the mutated program by its [SnapshotNode::index], printed and indented like a
+    /// line with the given indentation.
+    Print {
+        index: usize,
+        indent: String,
+    },
+}
+
+impl Edit {
+    fn render(self, editor: &SourceEditor<'_>, printed: &HashMap<usize, String>) -> TextEdit {
+        let mut content = String::new();
+        for part in self.parts {
+            match part {
+                Part::Text(text) => content.push_str(&text),
+                Part::Source(span) => content.push_str(span.source_text(editor.source_text)),
+                Part::Print { index, indent } => {
+                    if let Some(text) = printed.get(&index) {
+                        content.push_str(&reindent(text, &indent, editor));
+                    }
+                }
+            }
+        }
+        TextEdit { span: self.span, content }
+    }
+}
+
+/// Plans the edits from the snapshots of the original and the mutated program.
+struct Planner<'e, 's, 'o> {
+    editor: &'e SourceEditor<'s>,
+    /// The original nodes by type and span, for copying the source text of moved nodes.
+    originals: HashMap<(AstType, Span), &'o SnapshotNode>,
+    /// The starts of the original expression statements, where an object, function or class
+    /// expression must be parenthesized.
+    statement_starts: HashSet<u32>,
+    edits: Vec<Edit>,
+}
+
+impl<'e, 's, 'o> Planner<'e, 's, 'o> {
+    fn new(editor: &'e SourceEditor<'s>, root: &'o SnapshotNode) -> Self {
+        let mut planner = Self {
+            editor,
+            originals: HashMap::new(),
+            statement_starts: HashSet::new(),
+            edits: vec![],
+        };
+        planner.index(root);
+        planner
+    }
+
+    fn index(&mut self, node: &'o SnapshotNode) {
+        self.originals.insert((node.ty(), node.span()), node);
+        if node.ty() == AstType::ExpressionStatement {
+            self.statement_starts.insert(node.span().start);
+        }
+        for child in node.children() {
+            self.index(child);
+        }
+    }
+
+    /// Plan the edits which turn `old` into `new`, nodes of the same type at the same position.
+    ///
+    /// `Err` when the change can only be printed with an ancestor of the nodes, then the caller
+    /// discards the edits planned for the nodes.
+    fn edit(&mut self, old: &SnapshotNode, new: &SnapshotNode) -> Result<(), ()> {
+        if old == new {
+            return Ok(());
+        }
+        if !old.shallow_eq(new) {
+            return Err(());
+        }
+        let (old_children, new_children) = (old.children(), new.children());
+        match (statements_start(old), statements_start(new)) {
+            (Some(old_start), Some(new_start)) if old_start == new_start => {
+                let (old_head, old_statements) = old_children.split_at(old_start);
+                let (new_head, new_statements) = new_children.split_at(new_start);
+                self.edit_children(old, old_head, new_head)?;
+                self.edit_statements(old, old_head, old_statements, new_statements)
+            }
+            (None, None) => self.edit_children(old, old_children, new_children),
+            _ => Err(()),
+        }
+    }
+
+    /// Edit the children of `parent` pairwise.
+    fn edit_children(
+        &mut self,
+        parent: &SnapshotNode,
+        old: &[SnapshotNode],
+        new: &[SnapshotNode],
+    ) -> Result<(), ()> {
+        if old.len() != new.len() {
+            return Err(());
+        }
+        for (position, (old_child, new_child)) in old.iter().zip(new).enumerate() {
+            self.edit_or_replace(parent, position, old_child, new_child)?;
+        }
+        Ok(())
+    }
+
+    fn edit_or_replace(
+        &mut self,
+        parent: &SnapshotNode,
+        position: usize,
+        old: &SnapshotNode,
+        new: &SnapshotNode,
+    ) -> Result<(), ()> {
+        let planned = self.edits.len();
+        if old.ty() == new.ty() && self.edit(old, new).is_ok() {
+            return Ok(());
+        }
+        self.edits.truncate(planned);
+        self.replace(parent, position, old, new)
+    }
+
+    /// Replace the source text of `old`, the child of `parent` at `position`, with `new`.
+    fn replace(
+        &mut self,
+        parent: &SnapshotNode,
+        position: usize,
+        old: &SnapshotNode,
+        new: &SnapshotNode,
+    ) -> Result<(), ()> {
+        let parenthesize = match (old.role(), new.role()) {
+            // Not the body of an arrow function like `() => a`, which has the span of its
+            // expression statement.
+            (Some(NodeRole::Statement), Some(NodeRole::Statement))
+                if parent.ty() != AstType::FunctionBody || parent.span() != old.span() =>
+            {
+                false
+            }
+            (
+                Some(NodeRole::Expression(old_precedence)),
+                Some(NodeRole::Expression(precedence)),
+            ) => {
+                precedence < required_precedence(parent.ty(), position, old_precedence)
+                    || (self.statement_starts.contains(&old.span().start)
+                        && matches!(
+                            new.ty(),
+                            AstType::ObjectExpression | AstType::Function | AstType::Class
+                        ))
+            }
+            _ => return Err(()),
+        };
+        let indent = line_indent(self.editor.source_text, old.span().start);
+        let part = self.copy_or_print(new, indent);
+        let parts = if parenthesize {
+            vec![Part::Text("(".to_string()), part, Part::Text(")".to_string())]
+        } else {
+            vec![part]
+        };
+        self.edits.push(Edit { span: old.span(), parts });
+        Ok(())
+    }
+
+    /// Edit the statements of `parent`, which follow its other children `head`, e.g. its
+    /// directives.
+    fn edit_statements(
+        &mut self,
+        parent: &SnapshotNode,
+        head: &[SnapshotNode],
+        old: &[SnapshotNode],
+        new: &[SnapshotNode],
+    ) -> Result<(), ()> {
+        // The original statement which each new statement keeps, by its span.
+        let mut kept = vec![None; new.len()];
+        let mut next = 0;
+        for (stmt, kept) in new.iter().zip(&mut kept) {
+            let found = old[next..]
+                .iter()
+                .position(|original| original.ty() == stmt.ty() && original.span() == stmt.span());
+            if let Some(offset) = found {
+                *kept = Some(next + offset);
+                next += offset + 1;
+            }
+        }
+
+        for (stmt, original) in new.iter().zip(&kept) {
+            if let Some(original) = original {
+                self.edit_or_replace(parent, head.len(), &old[*original], stmt)?;
+            }
+        }
+
+        // New statements are inserted after the directives, but not after the test of a case.
+        let head = head.last().filter(|_| parent.ty() != AstType::SwitchCase);
+        // Without a statement to insert them next to, the new statements replace the first one.
+        let replaced = old
+            .first()
+            .filter(|_| kept.iter().all(Option::is_none) && head.is_none() && !new.is_empty());
+        for (i, stmt) in old.iter().enumerate() {
+            if !(kept.contains(&Some(i)) || (i == 0 && replaced.is_some())) {
+                self.delete(stmt.span());
+            }
+        }
+
+        let mut start = 0;
+        while start < new.len() {
+            if kept[start].is_some() {
+                start += 1;
+                continue;
+            }
+            let end =
+                kept[start..].iter().position(Option::is_some).map_or(new.len(), |n| start + n);
+            let previous = start.checked_sub(1).and_then(|i| kept[i]).map(|i| &old[i]);
+            let anchor = if let Some(previous) = previous.or(head) {
+                Anchor::After(previous)
+            } else if let Some(following) = kept.get(end).copied().flatten() {
+                Anchor::Before(&old[following])
+            } else if let Some(replaced) = replaced {
+                Anchor::Replace(replaced)
+            } else {
+                Anchor::Empty(parent)
+            };
+            self.insert(&new[start..end], anchor)?;
+            start = end;
+        }
+        Ok(())
+    }
+
+    fn insert(&mut self, statements: &[SnapshotNode], anchor: Anchor<'_>) -> Result<(), ()> {
+        let source_text = self.editor.source_text;
+        let newline = self.editor.newline;
+        let mut parts = vec![];
+        let span = match anchor {
+            Anchor::After(previous) => {
+                let start = previous.span().start;
+                let indent = line_indent(source_text, start);
+                // `a \n (b)` would call `a`.
+                let mut asi_hazard =
+                    !previous.span().source_text(source_text).ends_with([';', '}']);
+                for stmt in statements {
+                    parts.push(Part::Text(format!("{newline}{indent}")));
+                    if asi_hazard && stmt.ty() == AstType::ExpressionStatement {
+                        parts.push(Part::Text(";".to_string()));
+                    }
+                    parts.push(self.copy_or_print(stmt, indent));
+                    asi_hazard = false;
+                }
+                Span::new(previous.span().end, previous.span().end)
+            }
+            Anchor::Before(following) | Anchor::Replace(following) => {
+                let start = following.span().start;
+                let indent = line_indent(source_text, start);
+                for (i, stmt) in statements.iter().enumerate() {
+                    if i > 0 {
+                        parts.push(Part::Text(format!("{newline}{indent}")));
+                    }
+                    parts.push(self.copy_or_print(stmt, indent));
+                }
+                if matches!(anchor, Anchor::Replace(_)) {
+                    following.span()
+                } else {
+                    parts.push(Part::Text(format!("{newline}{indent}")));
+                    Span::new(start, start)
+                }
+            }
+            Anchor::Empty(parent) if parent.ty() == AstType::Program => {
+                let end = parent.span().end;
+                if !source_text[..end as usize].trim_end_matches([' ', '\t']).ends_with('\n')
+                    && end > 0
+                {
+                    parts.push(Part::Text(newline.to_string()));
+                }
+                for stmt in statements {
+                    parts.push(self.copy_or_print(stmt, ""));
+                    parts.push(Part::Text(newline.to_string()));
+                }
+                Span::new(end, end)
+            }
+            Anchor::Empty(parent) => {
+                if !matches!(
+                    parent.ty(),
+                    AstType::BlockStatement | AstType::FunctionBody | AstType::StaticBlock
+                ) {
+                    return Err(());
+                }
+                let parent_span = parent.span();
+                let text = parent_span.source_text(source_text);
+                let (Some(open), true) = (text.find('{'), text.ends_with('}')) else {
+                    return Err(());
+                };
+                #[allow(clippy::cast_possible_truncation)]
+                let open = parent_span.start + open as u32 + 1;
+                let close = parent_span.end - 1;
+                let indent = line_indent(source_text, parent_span.start);
+                let inner_indent = format!("{indent}{}", self.editor.indent_unit);
+                for stmt in statements {
+                    parts.push(Part::Text(format!("{newline}{inner_indent}")));
+                    parts.push(self.copy_or_print(stmt, &inner_indent));
+                }
+                // `{ }` is replaced, the comments in `{ /* c */ }` are kept after the statements.
+                if Span::new(open, close).source_text(source_text).trim().is_empty() {
+                    parts.push(Part::Text(format!("{newline}{indent}")));
+                    Span::new(open, close)
+                } else {
+                    Span::new(open, open)
+                }
+            }
+        };
+        self.edits.push(Edit { span, parts });
+        Ok(())
+    }
+
+    /// Delete a statement, with its line when it is the only code on the line.
+    fn delete(&mut self, span: Span) {
+        let source_text = self.editor.source_text;
+        let (start, end) = (span.start as usize, span.end as usize);
+        let line_start = source_text[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source_text[end..].find('\n').map_or(source_text.len(), |i| end + i + 1);
+        let span = if source_text[line_start..start].trim().is_empty()
+            && source_text[end..line_end].trim().is_empty()
+        {
+            (line_start, line_end)
+        } else {
+            let after = &source_text[end..];
+            (start, end + after.len() - after.trim_start_matches([' ', '\t']).len())
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let span = Span::new(span.0 as u32, span.1 as u32);
+        self.edits.push(Edit { span, parts: vec![] });
+    }
+
+    /// The source text of `new` when it is unchanged from the original node at its span,
+    /// otherwise `new` printed and indented like a line with the indentation `indent`.
+    fn copy_or_print(&self, new: &SnapshotNode, indent: &str) -> Part {
+        match self.originals.get(&(new.ty(), new.span())) {
+            Some(original) if *original == new => Part::Source(new.span()),
+            _ => Part::Print { index: new.index(), indent: indent.to_string() },
+        }
+    }
+}
+
+/// Where statements are inserted into a list of statements.
+#[derive(Clone, Copy)]
+enum Anchor<'n> {
+    After(&'n SnapshotNode),
+    Before(&'n SnapshotNode),
+    /// Replace the statement, which is the first one of the list.
+    Replace(&'n SnapshotNode),
+    /// The list has no statements.
+    Empty(&'n SnapshotNode),
+}
+
+/// The index of the first statement among the children of a node which has a list of
+/// statements, e.g. after the directives of a function body.
+fn statements_start(node: &SnapshotNode) -> Option<usize> {
+    let children = node.children();
+    match node.ty() {
+        AstType::Program | AstType::BlockStatement | AstType::StaticBlock | AstType::SwitchCase => {
+        }
+        // Not the body of an arrow function like `() => a`.
+        AstType::FunctionBody if !matches!(children, [stmt] if stmt.span() == node.span()) => {}
+        _ => return None,
+    }
+    Some(children.iter().take_while(|child| child.role() != Some(NodeRole::Statement)).count())
+}
+
+/// The precedence an expression needs at `position` among the children of `parent`, given the
+/// precedence of the original expression there.
+fn required_precedence(parent: AstType, position: usize, original: Precedence) -> Precedence {
+    match parent {
+        AstType::ExpressionStatement
+        | AstType::ParenthesizedExpression
+        | AstType::ReturnStatement
+        | AstType::ThrowStatement
+        | AstType::IfStatement
+        | AstType::WhileStatement
+        | AstType::DoWhileStatement
+        | AstType::SwitchStatement
+        | AstType::SwitchCase
+        | AstType::TemplateLiteral => Precedence::lowest(),
+        // `a[b]`
+        AstType::MemberExpression if position == 1 => Precedence::lowest(),
+        AstType::Argument
+        | AstType::ArrayExpressionElement
+        | AstType::ExpressionArrayElement
+        | AstType::SpreadElement
+        | AstType::VariableDeclarator
+        | AstType::ObjectProperty
+        | AstType::PropertyDefinition
+        | AstType::PropertyKey
+        | AstType::AssignmentPattern => Precedence::Assign,
+        AstType::AssignmentExpression if position == 1 => Precedence::Assign,
+        AstType::ConditionalExpression if position > 0 => Precedence::Assign,
+        _ => original,
+    }
+}
+
+/// The indentation of the line of `position`.
+fn line_indent(source_text: &str, position: u32) -> &str {
+    let line_start = source_text[..position as usize].rfind('\n').map_or(0, |i| i + 1);
+    let line = &source_text[line_start..];
+    &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+}
+
+/// The indentation of the first indented line, four spaces by default.
+fn indent_unit(source_text: &str) -> &str {
+    source_text
+        .lines()
+        .map(|line| &line[..line.len() - line.trim_start_matches([' ', '\t']).len()])
+        .find(|indent| !indent.is_empty() && indent.len() < 8)
+        .map_or("    ", |indent| if indent.starts_with('\t') { "\t" } else { indent })
+}
+
+/// Indent the lines of printed code after the first like a line with the indentation `indent`,
+/// with the indentation unit of the source text instead of the tabs of the codegen. Code with
+/// template literals is kept as is, so that their text does not change.
+fn reindent(text: &str, indent: &str, editor: &SourceEditor<'_>) -> String {
+    if text.contains('`') {
+        return text.to_string();
+    }
+    let mut output = String::with_capacity(text.len());
+    for (i, line) in text.lines().enumerate() {
+        if i > 0 {
+            output.push_str(editor.newline);
+            let content = line.trim_start_matches('\t');
+            if !content.is_empty() {
+                output.push_str(indent);
+                for _ in content.len()..line.len() {
+                    output.push_str(editor.indent_unit);
+                }
+            }
+            output.push_str(content);
+        } else {
+            output.push_str(line);
+        }
+    }
+    output
+}
+
+/// Prints the requested statements and expressions of the mutated program.
+struct Printer<'e, 's> {
+    editor: &'e SourceEditor<'s>,
+    /// The [SnapshotNode::index] of the nodes to print.
+    requested: HashSet<usize>,
+    printed: HashMap<usize, String>,
+    /// The number of nodes entered so far.
+    entered: usize,
+}
+
+// A statement or an expression is entered first when it is visited, but an expression without
+// a node of its own, e.g. a TypeScript expression, visits its inner expression first, which then
+// replaces the printed outer expression as the snapshot does.
+impl<'a, 'e, 's> Visit<'a> for Printer<'e, 's> {
+    fn enter_node(&mut self, _kind: AstKind<'a>) {
+        self.entered += 1;
+    }
+
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        if self.requested.contains(&self.entered) {
+            let mut p = self.editor.codegen();
+            stmt.gen(&mut p, Context::default());
+            self.printed.insert(self.entered, p.into_source_text().trim_end().to_string());
+        }
+        walk_statement(self, stmt);
+    }
+
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if self.requested.contains(&self.entered) {
+            let mut p = self.editor.codegen();
+            expr.gen_expr(&mut p, Precedence::lowest(), Context::default());
+            self.printed.insert(self.entered, p.into_source_text());
+        }
+        walk_expression(self, expr);
+    }
+}
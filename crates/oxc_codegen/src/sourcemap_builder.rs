@@ -21,6 +21,17 @@ pub struct LineOffsetTable {
     byte_offset_to_start_of_line: usize,
 }
 
+/// Incremental source map builder.
+///
+/// Maps positions in the original source to the end of the generated `output`, as it is written.
+/// [crate::Codegen] uses it when `enable_source_map` is set, and transforms which print code
+/// themselves can use it the same way, by calling [SourcemapBuilder::add_span_mapping] with
+/// the span of each node before appending its code to `output`.
+///
+/// Nodes created by a transform either have the empty [oxc_span::SPAN], which adds no mapping,
+/// so their code is attributed to the original code printed before them, or borrow the span of
+/// the node which caused them to be inserted, e.g. a helper pointing at the syntax it lowers.
+/// Borrowed spans can be out of order with the rest of the output.
 #[allow(clippy::struct_field_names)]
 pub struct SourcemapBuilder {
     source_id: u32,
@@ -49,6 +60,12 @@ impl Default for SourcemapBuilder {
 }
 
 impl SourcemapBuilder {
+    pub fn new(name: &str, source: &str) -> Self {
+        let mut builder = Self::default();
+        builder.with_name_and_source(name, source);
+        builder
+    }
+
     pub fn with_name_and_source(&mut self, name: &str, source: &str) {
         self.line_offset_tables = Self::generate_line_offset_tables(source);
         self.source_id = self.sourcemap_builder.set_source_and_content(name, source);
@@ -59,7 +76,26 @@ impl SourcemapBuilder {
         self.sourcemap_builder.into_sourcemap()
     }
 
+    /// Map the end of `output` to the start of `span`, unless it is the empty span of a
+    /// created node.
+    pub fn add_span_mapping(&mut self, output: &[u8], span: Span) {
+        if !span.is_unspanned() {
+            self.add_source_mapping(output, span.start, None);
+        }
+    }
+
+    /// Map the end of `output` to the end of `span`, unless it is the empty span of a
+    /// created node.
+    pub fn add_span_end_mapping(&mut self, output: &[u8], span: Span) {
+        if !span.is_unspanned() {
+            self.add_source_mapping(output, span.end, None);
+        }
+    }
+
     pub fn add_source_mapping_for_name(&mut self, output: &[u8], span: Span, name: &str) {
+        if span.is_unspanned() {
+            return;
+        }
         debug_assert!(
             (span.end as usize) <= self.original_source.len(),
             "violated {}:{} <= {} for {name}",
@@ -76,7 +112,9 @@ impl SourcemapBuilder {
     }
 
     pub fn add_source_mapping(&mut self, output: &[u8], position: u32, name: Option<Arc<str>>) {
-        if matches!(self.last_position, Some(last_position) if last_position >= position) {
+        // Nested nodes start at the same position. Positions can also go backwards, when code is
+        // moved or a created node borrows the span of a later node, which still needs a mapping.
+        if self.last_position == Some(position) {
             return;
         }
         let (original_line, original_column) = self.search_original_line_and_column(position);
@@ -397,4 +435,28 @@ mod test {
             Some("b")
         );
     }
+
+    #[test]
+    fn add_span_mapping() {
+        let mut builder = SourcemapBuilder::new("x.js", "a;\nb;");
+        // A created node, and a created node which borrows the span of `b`.
+        let output = "helper();\na;\nb;".as_bytes();
+        builder.add_span_mapping(&output[..0], Span::default());
+        builder.add_span_mapping(&output[..0], Span::new(3, 4));
+        builder.add_span_mapping(&output[..10], Span::new(0, 1));
+        builder.add_span_mapping(&output[..13], Span::new(3, 4));
+        let sm = builder.into_sourcemap();
+        let tokens = sm
+            .get_tokens()
+            .map(|token| {
+                (
+                    token.get_dst_line(),
+                    token.get_dst_col(),
+                    token.get_src_line(),
+                    token.get_src_col(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(tokens, vec![(0, 0, 1, 0), (1, 0, 0, 0), (2, 0, 1, 0)]);
+    }
 }
@@ -0,0 +1,204 @@
+use std::{fmt, str::FromStr};
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, visit::walk::*, Visit};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator};
+
+/// ECMAScript version the generated code must be syntactically compatible with.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EsTarget {
+    ES5,
+    ES2015,
+    ES2016,
+    ES2017,
+    ES2018,
+    ES2019,
+    ES2020,
+    ES2021,
+    ES2022,
+    ES2023,
+    ES2024,
+    #[default]
+    ESNext,
+}
+
+impl FromStr for EsTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "es5" => Ok(Self::ES5),
+            "es6" | "es2015" => Ok(Self::ES2015),
+            "es2016" => Ok(Self::ES2016),
+            "es2017" => Ok(Self::ES2017),
+            "es2018" => Ok(Self::ES2018),
+            "es2019" => Ok(Self::ES2019),
+            "es2020" => Ok(Self::ES2020),
+            "es2021" => Ok(Self::ES2021),
+            "es2022" => Ok(Self::ES2022),
+            "es2023" => Ok(Self::ES2023),
+            "es2024" => Ok(Self::ES2024),
+            "esnext" => Ok(Self::ESNext),
+            _ => Err(format!("Invalid target \"{s}\".")),
+        }
+    }
+}
+
+impl fmt::Display for EsTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::ES5 => "es5",
+            Self::ES2015 => "es2015",
+            Self::ES2016 => "es2016",
+            Self::ES2017 => "es2017",
+            Self::ES2018 => "es2018",
+            Self::ES2019 => "es2019",
+            Self::ES2020 => "es2020",
+            Self::ES2021 => "es2021",
+            Self::ES2022 => "es2022",
+            Self::ES2023 => "es2023",
+            Self::ES2024 => "es2024",
+            Self::ESNext => "esnext",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Reports syntax which cannot be printed as is for the configured [EsTarget].
+///
+/// Only syntax is checked, runtime features such as `Promise` or `Object.hasOwn` are not.
+pub struct TargetChecker {
+    target: EsTarget,
+    errors: Vec<OxcDiagnostic>,
+}
+
+impl TargetChecker {
+    pub fn new(target: EsTarget) -> Self {
+        Self { target, errors: vec![] }
+    }
+
+    pub fn build(mut self, program: &Program<'_>) -> Vec<OxcDiagnostic> {
+        self.visit_program(program);
+        self.errors
+    }
+
+    /// Check the part of a program visited by `visit`.
+    pub(crate) fn check<F: FnOnce(&mut Self)>(mut self, visit: F) -> Vec<OxcDiagnostic> {
+        visit(&mut self);
+        self.errors
+    }
+
+    fn require(&mut self, feature: &str, required: EsTarget, span: Span) {
+        if self.target < required {
+            self.errors.push(
+                OxcDiagnostic::error(format!(
+                    "{feature} are not available in the configured target environment (\"{}\")",
+                    self.target
+                ))
+                .with_help(format!("{feature} require \"{required}\" or later"))
+                .with_label(span),
+            );
+        }
+    }
+}
+
+impl<'a> Visit<'a> for TargetChecker {
+    fn visit_number_literal(&mut self, lit: &NumericLiteral<'a>) {
+        if lit.raw.contains('_') {
+            self.require("Numeric separators", EsTarget::ES2021, lit.span);
+        }
+        let prefix = lit.raw.get(..2).map(str::to_ascii_lowercase);
+        match prefix.as_deref() {
+            Some("0b") => self.require("Binary literals", EsTarget::ES2015, lit.span),
+            Some("0o") => self.require("Octal literals", EsTarget::ES2015, lit.span),
+            _ => {}
+        }
+    }
+
+    fn visit_bigint_literal(&mut self, lit: &BigIntLiteral<'a>) {
+        self.require("BigInt literals", EsTarget::ES2020, lit.span);
+        if lit.raw.contains('_') {
+            self.require("Numeric separators", EsTarget::ES2021, lit.span);
+        }
+    }
+
+    fn visit_template_literal(&mut self, lit: &TemplateLiteral<'a>) {
+        self.require("Template literals", EsTarget::ES2015, lit.span);
+        walk_template_literal(self, lit);
+    }
+
+    fn visit_reg_expr_literal(&mut self, lit: &RegExpLiteral<'a>) {
+        let flags = lit.regex.flags;
+        if flags.intersects(RegExpFlags::U | RegExpFlags::Y) {
+            self.require("Regular expression flags \"u\" and \"y\"", EsTarget::ES2015, lit.span);
+        }
+        if flags.contains(RegExpFlags::S) {
+            self.require("Regular expression flag \"s\"", EsTarget::ES2018, lit.span);
+        }
+        if flags.contains(RegExpFlags::D) {
+            self.require("Regular expression flag \"d\"", EsTarget::ES2022, lit.span);
+        }
+        if flags.contains(RegExpFlags::V) {
+            self.require("Regular expression flag \"v\"", EsTarget::ES2024, lit.span);
+        }
+        let pattern = lit.regex.pattern.as_str();
+        if pattern.contains("(?<=") || pattern.contains("(?<!") {
+            self.require("Regular expression lookbehind assertions", EsTarget::ES2018, lit.span);
+        } else if pattern.contains("(?<") {
+            self.require("Regular expression named capture groups", EsTarget::ES2018, lit.span);
+        }
+    }
+
+    fn visit_binary_expression(&mut self, expr: &BinaryExpression<'a>) {
+        if expr.operator == BinaryOperator::Exponential {
+            self.require("Exponent operators", EsTarget::ES2016, expr.span);
+        }
+        walk_binary_expression(self, expr);
+    }
+
+    fn visit_logical_expression(&mut self, expr: &LogicalExpression<'a>) {
+        if expr.operator == LogicalOperator::Coalesce {
+            self.require("Nullish coalescing operators", EsTarget::ES2020, expr.span);
+        }
+        walk_logical_expression(self, expr);
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        match expr.operator {
+            AssignmentOperator::Exponential => {
+                self.require("Exponent operators", EsTarget::ES2016, expr.span);
+            }
+            AssignmentOperator::LogicalAnd
+            | AssignmentOperator::LogicalOr
+            | AssignmentOperator::LogicalNullish => {
+                self.require("Logical assignment operators", EsTarget::ES2021, expr.span);
+            }
+            _ => {}
+        }
+        walk_assignment_expression(self, expr);
+    }
+
+    fn visit_chain_expression(&mut self, expr: &ChainExpression<'a>) {
+        self.require("Optional chains", EsTarget::ES2020, expr.span);
+        walk_chain_expression(self, expr);
+    }
+
+    fn visit_catch_clause(&mut self, clause: &CatchClause<'a>) {
+        if clause.param.is_none() {
+            self.require("Optional catch bindings", EsTarget::ES2019, clause.span);
+        }
+        walk_catch_clause(self, clause);
+    }
+
+    fn visit_private_in_expression(&mut self, expr: &PrivateInExpression<'a>) {
+        self.require("Private brand checks", EsTarget::ES2022, expr.span);
+        walk_private_in_expression(self, expr);
+    }
+
+    fn visit_static_block(&mut self, block: &StaticBlock<'a>) {
+        self.require("Class static blocks", EsTarget::ES2022, block.span);
+        walk_static_block(self, block);
+    }
+}
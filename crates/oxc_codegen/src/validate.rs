@@ -0,0 +1,112 @@
+use std::fmt;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::Program, AstChange, AstDiff};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_span::Span;
+
+use crate::CodegenOptions;
+
+/// The printed code does not mean the same as the program it was printed from,
+/// e.g. because of a missing space (`a--b` for `a - -b`),
+/// or a line break where a semicolon is inserted (`return\na`).
+///
+/// See [CodegenOptions::validate].
+#[derive(Debug, Clone)]
+pub enum ValidationError {
+    /// The printed code does not parse.
+    Syntax(Vec<OxcDiagnostic>),
+    /// The printed code parses to a different program.
+    Changed(Vec<AstChange>),
+}
+
+impl ValidationError {
+    /// The span of the first offending code in the printed code,
+    /// `None` if it is a node of the program which is missing from the printed code.
+    pub fn output_span(&self) -> Option<Span> {
+        match self {
+            Self::Syntax(errors) => errors
+                .iter()
+                .filter_map(|error| error.labels.as_ref()?.first())
+                .map(|label| {
+                    #[allow(clippy::cast_possible_truncation)]
+                    let start = label.offset() as u32;
+                    #[allow(clippy::cast_possible_truncation)]
+                    let end = (label.offset() + label.len()) as u32;
+                    Span::new(start, end)
+                })
+                .next(),
+            Self::Changed(changes) => changes.iter().find_map(|change| match change {
+                AstChange::Added { span, .. } => Some(*span),
+                AstChange::Changed { new_span, .. } => Some(*new_span),
+                AstChange::Removed { .. } => None,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax(errors) => {
+                writeln!(f, "The printed code does not parse:")?;
+                for error in errors {
+                    writeln!(f, "{}", error.message)?;
+                }
+            }
+            Self::Changed(changes) => {
+                writeln!(f, "The printed code parses to a different program:")?;
+                for change in changes {
+                    writeln!(f, "{change}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Parses the printed code and compares its AST with the AST it was printed from.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Validator {
+    enable_typescript: bool,
+    strip_hashbang: bool,
+}
+
+impl Validator {
+    pub(crate) fn new(options: &CodegenOptions) -> Self {
+        Self {
+            enable_typescript: options.enable_typescript,
+            strip_hashbang: options.strip_hashbang,
+        }
+    }
+
+    pub(crate) fn validate(
+        self,
+        program: &Program<'_>,
+        source_text: &str,
+    ) -> Option<ValidationError> {
+        // The types are not printed, the program is expected to be transformed to JavaScript first.
+        if program.source_type.is_typescript() && !self.enable_typescript {
+            return None;
+        }
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, program.source_type).parse();
+        if !ret.errors.is_empty() {
+            return Some(ValidationError::Syntax(ret.errors));
+        }
+        let diff = AstDiff::new_normalized(program, &ret.program);
+        let changes = diff
+            .changes()
+            .iter()
+            .filter(|change| {
+                !(self.strip_hashbang
+                    && matches!(change, AstChange::Removed { node, .. } if node.starts_with("Hashbang")))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        (!changes.is_empty()).then_some(ValidationError::Changed(changes))
+    }
+}
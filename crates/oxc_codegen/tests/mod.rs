@@ -1,7 +1,16 @@
 use oxc_allocator::Allocator;
-use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_ast::{
+    ast::{ExportDefaultDeclarationKind, Expression, IdentifierReference, Program, Statement},
+    AstBuilder, AstSnapshot, TextEdit, VisitMut,
+};
+use oxc_codegen::{
+    Codegen, CodegenOptions, CodegenReturn, CollapseWhitespace, EsTarget, EscapeProfile,
+    FinalNewline, JsxOptions, JsxQuote, JsxSelfClosing, Newline, SourceEditor, SourceEdits,
+    TemplateTag, ValidationError,
+};
 use oxc_parser::Parser;
-use oxc_span::SourceType;
+use oxc_span::{SourceType, Span, SPAN};
+use oxc_syntax::operator::BinaryOperator;
 
 fn test(source_text: &str, expected: &str) {
     let allocator = Allocator::default();
@@ -14,6 +23,25 @@ fn test(source_text: &str, expected: &str) {
     assert_eq!(expected, result, "for source {source_text}, expect {expected}, got {result}");
 }
 
+fn test_with_options(source_text: &str, expected: &str, codegen_options: CodegenOptions) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let program = allocator.alloc(program);
+    let result = Codegen::<false>::new("", source_text, codegen_options).build(program).source_text;
+    assert_eq!(expected, result, "for source {source_text}, expect {expected}, got {result}");
+}
+
+fn test_with_template_tags(source_text: &str, expected: &str, template_tags: Vec<TemplateTag>) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let program = allocator.alloc(program);
+    let codegen_options = CodegenOptions { template_tags, ..CodegenOptions::default() };
+    let result = Codegen::<false>::new("", source_text, codegen_options).build(program).source_text;
+    assert_eq!(expected, result, "for source {source_text}, expect {expected}, got {result}");
+}
+
 fn test_ts(source_text: &str, expected: &str, is_typescript_definition: bool) {
     let allocator = Allocator::default();
     let source_type = SourceType::default()
@@ -27,6 +55,103 @@ fn test_ts(source_text: &str, expected: &str, is_typescript_definition: bool) {
     assert_eq!(expected, result, "for source {source_text}, expect {expected}, got {result}");
 }
 
+fn test_minify(source_text: &str, expected: &str, codegen_options: CodegenOptions) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let program = allocator.alloc(program);
+    let result = Codegen::<true>::new("", source_text, codegen_options).build(program).source_text;
+    assert_eq!(expected, result, "for source {source_text}, expect {expected}, got {result}");
+}
+
+fn test_jsx(source_text: &str, expected: &str, codegen_options: CodegenOptions) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true).with_jsx(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let program = allocator.alloc(program);
+    let result = Codegen::<true>::new("", source_text, codegen_options).build(program).source_text;
+    assert_eq!(expected, result, "for source {source_text}, expect {expected}, got {result}");
+}
+
+/// Asserts the features reported as unavailable for `target`, the code is printed either way.
+fn test_target(source_text: &str, target: EsTarget, expected: &[&str]) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let program = allocator.alloc(program);
+    let codegen_options = CodegenOptions { target: Some(target), ..CodegenOptions::default() };
+    let ret = Codegen::<true>::new("", source_text, codegen_options).build(program);
+    let expected_output =
+        Codegen::<true>::new("", source_text, CodegenOptions::default()).build(program).source_text;
+    assert_eq!(expected_output, ret.source_text, "for source {source_text}");
+    let features = ret
+        .errors
+        .iter()
+        .map(|error| error.message.split(" are not available").next().unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(expected, features, "for source {source_text} and target {target}");
+}
+
+#[test]
+fn validate() {
+    let sources = [
+        "a - -b; a + +b; a + ++b; a - --b; a++ + b; a-- - b; a-- > b; a < !--b;",
+        "x = a / /re/g; x = 1 .toString(); x = typeof -a; x = 'a' in o;",
+        "function f() { return a } function* g() { yield* a } async function h() { await -a }",
+        "x = (a, b) ? (c = d) : `e`; x = `a${b}c`; x = -(-a); x = +(+a);",
+        "if (a) b; else if (c) d; else { e }",
+        "label: for (const a of b) { if (a) continue label; else break }",
+        "x = class extends (a, b) {}; x = () => ({}); x = { 'a-b': 1, c };",
+    ];
+    for source_text in sources {
+        let options = CodegenOptions { validate: true, ..CodegenOptions::default() };
+        for error in [validate_minify(source_text, options.clone()), validate_print(source_text)] {
+            assert!(error.is_none(), "for source {source_text}: {}", error.unwrap());
+        }
+    }
+
+    let hashbang =
+        CodegenOptions { validate: true, strip_hashbang: true, ..CodegenOptions::default() };
+    assert!(validate_minify("#!/usr/bin/env node\na", hashbang).is_none());
+
+    // The template literals rewritten by a handler are reported.
+    let source_text = "x = css`\n  color: red;\n`";
+    let template_tags = vec![TemplateTag::new("css").with_handler(CollapseWhitespace)];
+    let options = CodegenOptions { validate: true, template_tags, ..CodegenOptions::default() };
+    let error = validate_minify(source_text, options).unwrap();
+    assert!(matches!(error, ValidationError::Changed(_)), "{error}");
+    assert_eq!(error.output_span().map(|span| span.start), Some(5));
+}
+
+/// The validation error of the code printed with `codegen_options` for `source_text`.
+fn validate_minify(source_text: &str, codegen_options: CodegenOptions) -> Option<ValidationError> {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    Codegen::<true>::new("", source_text, codegen_options).build(&program).validation_error
+}
+
+fn validate_print(source_text: &str) -> Option<ValidationError> {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let codegen_options = CodegenOptions { validate: true, ..CodegenOptions::default() };
+    Codegen::<false>::new("", source_text, codegen_options).build(&program).validation_error
+}
+
+/// Asserts the code printed by `build` for the program parsed from `source_text`.
+fn test_fragment<F>(source_text: &str, expected: &str, build: F)
+where
+    F: FnOnce(Codegen<true>, &Program<'_>) -> CodegenReturn,
+{
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let codegen = Codegen::<true>::new("", source_text, CodegenOptions::default());
+    let result = build(codegen, &program).source_text;
+    assert_eq!(expected, result, "for source {source_text}");
+}
+
 #[test]
 fn string() {
     test("let x = ''", "let x = '';\n");
@@ -70,6 +195,24 @@ fn string() {
     // test("let x = '\\uD801\\uDC02\\uDC03\\uD804'", r#"let x = '\U00010402\\uDC03\\uD804';\n"#)
 }
 
+#[test]
+fn string_as_template() {
+    let options = CodegenOptions::default();
+    test_minify("let x = 'a\\nb'", "let x=`a\nb`;", options.clone());
+    test_minify("let x = 'a\\r\\nb'", "let x=`a\\r\nb`;", options.clone());
+    test_minify("let x = \"it's\\n\\\"a\\\"\"", "let x=`it's\n\"a\"`;", options.clone());
+    // Escaping `${` or a backtick costs as much as the line break saves.
+    test_minify("let x = 'a\\n${b}'", "let x='a\\n${b}';", options.clone());
+    test_minify("let x = 'a\\n`'", "let x='a\\n`';", options.clone());
+    test_minify("let x = 'a\\n\\n`${b}`'", "let x='a\\n\\n`${b}`';", options.clone());
+    test_minify("let x = 'a\\n\\n${b}'", "let x=`a\n\n\\${b}`;", options.clone());
+    // Property keys must be strings.
+    test_minify("x = { 'a\\nb': 1 }", "x={'a\\nb':1};", options);
+
+    let options = CodegenOptions { target: Some(EsTarget::ES5), ..CodegenOptions::default() };
+    test_minify("let x = 'a\\nb'", "let x='a\\nb';", options);
+}
+
 #[test]
 fn template() {
     test("let x = `\\0`", "let x = `\\0`;\n");
@@ -179,3 +322,324 @@ fn typescript() {
     test_ts("import { Foo, type Bar } from 'foo';", "import {Foo,type Bar} from 'foo';\n", false);
     test_ts("export { Foo, type Bar } from 'foo';", "export { Foo, type Bar } from 'foo';", false);
 }
+
+#[test]
+fn template_tags() {
+    let source = "const q = gql`\n  query {\n    user(id: ${id}) { name }\n  }\n`;";
+    test_with_template_tags(
+        source,
+        "const q = gql`\n  query {\n    user(id: ${id}) { name }\n  }\n`;\n",
+        TemplateTag::defaults(),
+    );
+    test_with_template_tags(
+        source,
+        "const q = gql`query { user(id: ${id}) { name } }`;\n",
+        vec![TemplateTag::new("gql").with_handler(CollapseWhitespace)],
+    );
+    test_with_template_tags(
+        "const Button = styled.button`\n  color: ${color};\n  margin: 0  auto;\n`;",
+        "const Button = styled.button`color: ${color}; margin: 0 auto;`;\n",
+        vec![TemplateTag::new("styled").with_handler(CollapseWhitespace)],
+    );
+    // Untagged and unconfigured template literals are untouched.
+    test_with_template_tags(
+        "const s = html`  a  `, t = `  b  `;",
+        "const s = html`  a  `, t = `  b  `;\n",
+        vec![TemplateTag::new("css").with_handler(CollapseWhitespace)],
+    );
+    // Escaped whitespace is significant.
+    test_with_template_tags(
+        "css`a\\\n  b`",
+        "css`a\\\n b`;\n",
+        vec![TemplateTag::new("css").with_handler(CollapseWhitespace)],
+    );
+    // Whitespace inside of strings is significant.
+    test_with_template_tags(
+        "css`content:  \"a  b\";  quotes: '  '  `",
+        "css`content: \"a  b\"; quotes: '  '`;\n",
+        vec![TemplateTag::new("css").with_handler(CollapseWhitespace)],
+    );
+}
+
+#[test]
+fn target() {
+    test_target("1n", EsTarget::ES2019, &["BigInt literals"]);
+    test_target("1n", EsTarget::ES2020, &[]);
+    test_target("1_000", EsTarget::ES2020, &["Numeric separators"]);
+    test_target("1_000n", EsTarget::ES2019, &["BigInt literals", "Numeric separators"]);
+    test_target("1_000", EsTarget::ES2021, &[]);
+    test_target("0b11, 0O17, 0x1f", EsTarget::ES5, &["Binary literals", "Octal literals"]);
+    test_target("`a${b}`", EsTarget::ES5, &["Template literals"]);
+    test_target("a ** b; a **= b", EsTarget::ES2015, &["Exponent operators", "Exponent operators"]);
+    test_target(
+        "a ?? b; a?.b",
+        EsTarget::ES2019,
+        &["Nullish coalescing operators", "Optional chains"],
+    );
+    test_target("a ||= b; a &&= b; a ??= b", EsTarget::ES2021, &[]);
+    test_target("a ||= b", EsTarget::ES2020, &["Logical assignment operators"]);
+    test_target("try {} catch {}", EsTarget::ES2018, &["Optional catch bindings"]);
+    test_target("try {} catch (e) {}", EsTarget::ES2018, &[]);
+    test_target("/a/s", EsTarget::ES2017, &["Regular expression flag \"s\""]);
+    test_target("/(?<a>b)/", EsTarget::ES2017, &["Regular expression named capture groups"]);
+    test_target("/(?<=a)b/", EsTarget::ES2017, &["Regular expression lookbehind assertions"]);
+    test_target("/a/d", EsTarget::ES2021, &["Regular expression flag \"d\""]);
+    test_target("/a/v", EsTarget::ES2022, &["Regular expression flag \"v\""]);
+    test_target("/a/uy", EsTarget::ES5, &["Regular expression flags \"u\" and \"y\""]);
+    test_target(
+        "class A { #a; static {} m(o) { return #a in o } }",
+        EsTarget::ES2021,
+        &["Class static blocks", "Private brand checks"],
+    );
+    test_target("1n ?? 1_0", EsTarget::ESNext, &[]);
+}
+
+#[test]
+fn escape() {
+    let options = CodegenOptions::default();
+    test_minify("x = '\u{2028}'", "x='\\u2028';", options.clone());
+    test_minify("x = `\u{2028}`", "x=`\u{2028}`;", options.clone());
+    test_minify("x = '</script><!--'", "x='</script><!--';", options.clone());
+
+    let options = CodegenOptions { escape: EscapeProfile::JsonSafe, ..CodegenOptions::default() };
+    test_minify("x = '\u{2028}\u{2029}'", "x='\\u2028\\u2029';", options.clone());
+    test_minify("x = `\u{2028}${y}\u{2029}`", "x=`\\u2028${y}\\u2029`;", options.clone());
+    test_minify("x = `a\\\u{2028}b`", "x=`ab`;", options.clone());
+    // The raw text of tagged templates is observable.
+    test_minify("x = y`\u{2028}`", "x=y`\u{2028}`;", options.clone());
+    test_minify("x = `</script>`", "x=`</script>`;", options);
+
+    let options =
+        CodegenOptions { escape: EscapeProfile::InlineScriptSafe, ..CodegenOptions::default() };
+    test_minify("x = `\u{2028}`", "x=`\\u2028`;", options.clone());
+    test_minify("x = '</script>'", "x='<\\/script>';", options.clone());
+    test_minify("x = '</SCRIPT'", "x='<\\/SCRIPT';", options.clone());
+    test_minify("x = '<!-- a -->'", "x='<\\!-- a -->';", options.clone());
+    test_minify("x = '</scrip <! <-- </div>'", "x='</scrip <! <-- </div>';", options.clone());
+    test_minify("x = 'a\\n</script>'", "x=`a\n<\\/script>`;", options.clone());
+    test_minify("x = `</script>${y}<!--`", "x=`<\\/script>${y}<\\!--`;", options.clone());
+    test_minify("x = `\\<!--`", "x=`\\<\\!--`;", options.clone());
+    test_minify("x = y`</script>`", "x=y`</script>`;", options.clone());
+    test_minify("x = /[</script>]/", "x=/[<\\/script>]/;", options.clone());
+    test_minify("x = /<!--/u", "x=/<\\x21--/u;", options.clone());
+    test_minify("x = /[<!--]/", "x=/[<\\x21--]/;", options.clone());
+    test_minify("x = /(?<!--)a/", "x=/(?<!\\x2D-)a/;", options.clone());
+    test_minify("x = /\\<!--/", "x=/\\<\\x21--/;", options);
+
+    assert_eq!("inline-script-safe".parse(), Ok(EscapeProfile::InlineScriptSafe));
+    assert_eq!("json-safe".parse(), Ok(EscapeProfile::JsonSafe));
+    assert_eq!(EscapeProfile::InlineScriptSafe.to_string(), "inline-script-safe");
+    assert!("html".parse::<EscapeProfile>().is_err());
+}
+
+#[test]
+fn newlines() {
+    let options = CodegenOptions::default();
+    test_with_options("#!/usr/bin/env node\r\na()", "#!/usr/bin/env node\na();\n", options.clone());
+    test_minify("#!/usr/bin/env node\na()", "#!/usr/bin/env node\na();", options.clone());
+    test_minify("#!/usr/bin/env node", "#!/usr/bin/env node", options.clone());
+    test_minify("\u{feff}a()", "a();", options);
+
+    let options = CodegenOptions { newline: Newline::CrLf, ..CodegenOptions::default() };
+    test_with_options(
+        "#!node\na(); { b() }",
+        "#!node\r\na();\r\n{\r\n\tb();\r\n}\r\n",
+        options.clone(),
+    );
+    test_with_options("x = `a\nb`", "x = `a\nb`;\r\n", options);
+
+    let options =
+        CodegenOptions { final_newline: FinalNewline::Never, ..CodegenOptions::default() };
+    test_with_options("a(); b()", "a();\nb();", options);
+    let options =
+        CodegenOptions { final_newline: FinalNewline::Always, ..CodegenOptions::default() };
+    test_minify("a(); b()", "a();b();\n", options.clone());
+    test_minify("", "", options);
+
+    let options = CodegenOptions { strip_hashbang: true, ..CodegenOptions::default() };
+    test_minify("#!/usr/bin/env node\na()", "a();", options);
+    let options = CodegenOptions { preserve_bom: true, ..CodegenOptions::default() };
+    test_minify("\u{feff}a()", "\u{feff}a();", options);
+
+    assert_eq!("crlf".parse(), Ok(Newline::CrLf));
+    assert_eq!(Newline::Lf.to_string(), "lf");
+    assert!("cr".parse::<Newline>().is_err());
+}
+
+#[test]
+fn jsx() {
+    let options = CodegenOptions::default();
+    test_jsx("x = <a b='c' d=\"e'\" />", "x=<a b=\"c\" d=\"e'\"/>;", options.clone());
+    test_jsx("x = <a b='\"' />", "x=<a b='\"'/>;", options.clone());
+    // Strings and text have no escapes in JSX.
+    test_jsx("x = <a b=\"\\n\" />", "x=<a b=\"\\n\"/>;", options.clone());
+    test_jsx("x = <a>{'b'} c &amp; \\ </a>", "x=<a>{'b'} c &amp; \\ </a>;", options.clone());
+    test_jsx("x = <a>{...b}</a>", "x=<a>{...b}</a>;", options.clone());
+    test_jsx("x = <a.b c:d={1} {...e}></a.b>", "x=<a.b c:d={1} {...e}></a.b>;", options.clone());
+    test_jsx("x = <><a /></>", "x=<><a/></>;", options);
+
+    let jsx = JsxOptions { quote: JsxQuote::Single, ..JsxOptions::default() };
+    let options = CodegenOptions { jsx, ..CodegenOptions::default() };
+    test_jsx("x = <a b=\"c\" d='e\"' f=\"g'\" />", "x=<a b='c' d='e\"' f=\"g'\"/>;", options);
+
+    let jsx = JsxOptions { self_closing: JsxSelfClosing::Always, ..JsxOptions::default() };
+    let options = CodegenOptions { jsx, ..CodegenOptions::default() };
+    test_jsx("x = <a b></a>", "x=<a b/>;", options.clone());
+    test_jsx("x = <a> </a>", "x=<a> </a>;", options);
+
+    let jsx = JsxOptions { self_closing: JsxSelfClosing::Never, ..JsxOptions::default() };
+    let options = CodegenOptions { jsx, ..CodegenOptions::default() };
+    test_jsx("x = <a b />", "x=<a b></a>;", options.clone());
+    test_jsx("x = <a.b><c:d /></a.b>", "x=<a.b><c:d></c:d></a.b>;", options);
+
+    assert_eq!("always".parse(), Ok(JsxSelfClosing::Always));
+    assert_eq!(JsxSelfClosing::Never.to_string(), "never");
+    assert_eq!("single".parse(), Ok(JsxQuote::Single));
+    assert!("backtick".parse::<JsxQuote>().is_err());
+}
+
+#[test]
+fn fragments() {
+    let function = |strict| {
+        move |codegen: Codegen<true>, program: &Program<'_>| match &program.body[0] {
+            Statement::FunctionDeclaration(func) => codegen.build_function(func, strict),
+            Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                    codegen.build_function(func, strict)
+                }
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    };
+    test_fragment("function f(a) { return a }", "function f(a){return a}", function(false));
+    test_fragment("function f() {}", "'use strict';function f(){}", function(true));
+    test_fragment("function f() { 'use strict' }", "function f(){'use strict';}", function(true));
+    // An anonymous function declaration is not a valid statement.
+    test_fragment("export default function() {}", "(function(){});", function(false));
+
+    let class = |codegen: Codegen<true>, program: &Program<'_>| match &program.body[0] {
+        Statement::ClassDeclaration(class) => codegen.build_class(class),
+        Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => codegen.build_class(class),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    test_fragment("class A extends B {}", "class A extends B{}", class);
+    test_fragment("export default class {}", "(class{});", class);
+
+    let statements = |strict| {
+        move |codegen: Codegen<true>, program: &Program<'_>| {
+            codegen.build_statements(&program.body.as_slice()[1..], strict)
+        }
+    };
+    test_fragment("a(); let b = 1; c(b)", "let b=1;c(b);", statements(false));
+    test_fragment("a(); b()", "'use strict';b();", statements(true));
+    // A leading string is not a directive.
+    test_fragment("a(); 'b'; c()", ";'b';c();", statements(false));
+    test_fragment("a(); 'b'", "'use strict';;'b';", statements(true));
+}
+
+#[test]
+fn fragment_source_map() {
+    let source_text = "a();\nfunction f() { return b }";
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let Statement::FunctionDeclaration(func) = &program.body[1] else { unreachable!() };
+    let options = CodegenOptions { enable_source_map: true, ..CodegenOptions::default() };
+    let ret = Codegen::<true>::new("a.js", source_text, options).build_function(func, false);
+    let source_map = ret.source_map.unwrap();
+    // The fragment starts at the start of the function in the original source.
+    let token = source_map.get_token(0).unwrap();
+    assert_eq!((token.get_dst_line(), token.get_dst_col()), (0, 0));
+    assert_eq!((token.get_src_line(), token.get_src_col()), (1, 0));
+}
+
+/// Asserts the source text after the edits of the mutation of the program parsed from
+/// `source_text`.
+fn test_source_edit<F>(source_text: &str, expected: &str, mutate: F)
+where
+    F: for<'a> FnOnce(&AstBuilder<'a>, &mut Program<'a>),
+{
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+    let original = AstSnapshot::new(&program);
+    mutate(&AstBuilder::new(&allocator), &mut program);
+    let edits =
+        SourceEditor::new(source_text, CodegenOptions::default()).build(&original, &program);
+    assert_eq!(expected, edits.apply(source_text), "for source {source_text}");
+}
+
+struct Rename;
+
+impl<'a> VisitMut<'a> for Rename {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        if ident.name == "foo" {
+            ident.name = "bar".into();
+        }
+    }
+}
+
+#[test]
+fn source_edit() {
+    // The code which is not mutated keeps its formatting and comments.
+    test_source_edit(
+        "// header\nconst  x = foo( 1 ,2 );   // foo\nfunction f() {\n  return foo;\n}\n",
+        "// header\nconst  x = bar( 1 ,2 );   // foo\nfunction f() {\n  return bar;\n}\n",
+        |_, program| Rename.visit_program(program),
+    );
+    test_source_edit("foo\n", "foo\n", |_, _| {});
+
+    let replace_operand = |source_text: &str, expected: &str| {
+        test_source_edit(source_text, expected, |ast, program| {
+            let Statement::ExpressionStatement(stmt) = &mut program.body[0] else { unreachable!() };
+            let object = match &mut stmt.expression {
+                Expression::BinaryExpression(expr) => &mut expr.right,
+                Expression::StaticMemberExpression(expr) => &mut expr.object,
+                _ => unreachable!(),
+            };
+            let a = ast.identifier_reference_expression(ast.identifier_reference(SPAN, "a"));
+            let b = ast.identifier_reference_expression(ast.identifier_reference(SPAN, "b"));
+            *object = ast.binary_expression(SPAN, a, BinaryOperator::Addition, b);
+        });
+    };
+    replace_operand("x * y;", "x * (a + b);");
+    replace_operand("y.z;", "(a + b).z;");
+
+    // Statements are deleted with their line and inserted on their own line.
+    test_source_edit(
+        "function f() {\n  debugger;\n  a();\n}\n",
+        "function f() {\n  a();\n  b();\n}\n",
+        |ast, program| {
+            let Statement::FunctionDeclaration(func) = &mut program.body[0] else { unreachable!() };
+            let body = &mut func.body.as_mut().unwrap().statements;
+            body.retain(|stmt| !matches!(stmt, Statement::DebuggerStatement(_)));
+            let callee = ast.identifier_reference_expression(ast.identifier_reference(SPAN, "b"));
+            let call = ast.call_expression(SPAN, callee, ast.new_vec(), false, None);
+            body.push(ast.expression_statement(SPAN, call));
+        },
+    );
+    // A moved statement keeps its source text.
+    test_source_edit("a( 1 );\nif (x) {}\n", "if (x) {\n    a( 1 );\n}\n", |_, program| {
+        let stmt = program.body.remove(0);
+        let Statement::IfStatement(if_stmt) = &mut program.body[0] else { unreachable!() };
+        let Statement::BlockStatement(block) = &mut if_stmt.consequent else { unreachable!() };
+        block.body.push(stmt);
+    });
+}
+
+#[test]
+fn source_edit_conflicts() {
+    let edit = |start, end, content: &str| TextEdit {
+        span: Span::new(start, end),
+        content: content.to_string(),
+    };
+    let mut edits = SourceEdits::new(vec![edit(0, 1, "A"), edit(2, 2, "C")]);
+    edits.merge(SourceEdits::new(vec![edit(0, 3, "B"), edit(0, 1, "A"), edit(3, 3, "D")]));
+    let (accepted, conflicts) = edits.resolve();
+    assert_eq!(accepted, vec![edit(0, 1, "A"), edit(2, 2, "C"), edit(3, 3, "D")]);
+    assert_eq!(conflicts, vec![edit(0, 3, "B")]);
+    assert_eq!(edits.apply("abcd"), "AbCcDd");
+}
@@ -0,0 +1,88 @@
+//! Stable Diagnostic Codes
+//!
+//! Every kind of diagnostic of the parser and of the semantic checker has a code, such as
+//! `oxc(parse::unexpected_token)`, which does not change when its message is reworded, so that
+//! configurations, suppressions and documentation can refer to it. The codes of a crate are
+//! declared with [crate::diagnostic_codes], together with their extended explanations, and are
+//! never renamed or reused once released.
+
+use std::fmt;
+
+/// A stable code which identifies a kind of diagnostic, rendered as `oxc(scope::name)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DiagnosticCode {
+    /// The crate which reports the diagnostic, e.g. `parse` or `semantic`.
+    pub scope: &'static str,
+    /// The name of the diagnostic, e.g. `unexpected_token`.
+    pub name: &'static str,
+    /// The doc comment of the code, one line per attribute.
+    explanation: &'static str,
+}
+
+impl DiagnosticCode {
+    #[doc(hidden)]
+    pub const fn new(scope: &'static str, name: &'static str, explanation: &'static str) -> Self {
+        Self { scope, name, explanation }
+    }
+
+    /// The extended explanation of the diagnostic, in Markdown.
+    pub fn explanation(&self) -> String {
+        self.explanation
+            .lines()
+            .map(|line| line.strip_prefix(' ').unwrap_or(line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Whether `code` is this code, either as rendered, `oxc(parse::unexpected_token)`, or as
+    /// `parse::unexpected_token`.
+    pub fn matches(&self, code: &str) -> bool {
+        let code =
+            code.strip_prefix("oxc(").and_then(|code| code.strip_suffix(')')).unwrap_or(code);
+        code.split_once("::").is_some_and(|(scope, name)| scope == self.scope && name == self.name)
+    }
+
+    /// Find `code` in the `registries` of the crates, see [Self::matches].
+    pub fn find(
+        registries: &[&'static [DiagnosticCode]],
+        code: &str,
+    ) -> Option<&'static DiagnosticCode> {
+        registries.iter().flat_map(|registry| registry.iter()).find(|entry| entry.matches(code))
+    }
+}
+
+impl fmt::Display for DiagnosticCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "oxc({}::{})", self.scope, self.name)
+    }
+}
+
+/// Declare the stable codes of the diagnostics of a crate, whose doc comments are their extended
+/// explanations, and the registry `DIAGNOSTIC_CODES` of all of them.
+///
+/// ```ignore
+/// oxc_diagnostics::diagnostic_codes! {
+///     scope: "parse";
+///
+///     /// The parser found a token which can not appear at this position.
+///     UNEXPECTED_TOKEN = "unexpected_token";
+/// }
+///
+/// OxcDiagnostic::error("Unexpected token").with_oxc_code(&UNEXPECTED_TOKEN)
+/// ```
+#[macro_export]
+macro_rules! diagnostic_codes {
+    (
+        scope: $scope:literal;
+        $( $(#[doc = $doc:literal])+ $code:ident = $name:literal; )*
+    ) => {
+        $(
+            $(#[doc = $doc])+
+            pub const $code: $crate::DiagnosticCode =
+                $crate::DiagnosticCode::new($scope, $name, concat!($($doc, "\n"),+));
+        )*
+
+        /// The stable codes of the diagnostics of this crate.
+        pub const DIAGNOSTIC_CODES: &[$crate::DiagnosticCode] = &[$($code),*];
+    };
+}
@@ -1,6 +1,7 @@
 //! Diagnostics Wrapper
 //! Exports `miette`
 
+mod code;
 mod graphic_reporter;
 mod graphical_theme;
 mod reporter;
@@ -12,6 +13,7 @@ use std::{
 };
 
 pub use crate::{
+    code::DiagnosticCode,
     graphic_reporter::GraphicalReportHandler,
     graphical_theme::GraphicalTheme,
     service::{DiagnosticSender, DiagnosticService, DiagnosticTuple},
@@ -45,6 +47,13 @@ pub struct OxcDiagnosticInner {
     pub labels: Option<Vec<LabeledSpan>>,
     pub help: Option<String>,
     pub severity: Severity,
+    /// Error code shared with other tools, e.g. `TS2300` for TypeScript's "Duplicate identifier".
+    ///
+    /// Not rendered, it allows downstream tools to map diagnostics to their own.
+    pub code: Option<&'static str>,
+    /// The stable code of the diagnostic, e.g. `oxc(parse::unexpected_token)`, whose extended
+    /// explanation is printed by `oxlint --explain`.
+    pub oxc_code: Option<&'static DiagnosticCode>,
 }
 
 impl fmt::Display for OxcDiagnostic {
@@ -82,6 +91,8 @@ impl OxcDiagnostic {
                 labels: None,
                 help: None,
                 severity: Severity::Error,
+                code: None,
+                oxc_code: None,
             }),
         }
     }
@@ -94,6 +105,8 @@ impl OxcDiagnostic {
                 labels: None,
                 help: None,
                 severity: Severity::Warning,
+                code: None,
+                oxc_code: None,
             }),
         }
     }
@@ -110,6 +123,18 @@ impl OxcDiagnostic {
         self
     }
 
+    #[must_use]
+    pub fn with_error_code(mut self, code: &'static str) -> Self {
+        self.inner.code = Some(code);
+        self
+    }
+
+    #[must_use]
+    pub fn with_oxc_code(mut self, code: &'static DiagnosticCode) -> Self {
+        self.inner.oxc_code = Some(code);
+        self
+    }
+
     #[must_use]
     pub fn with_label<T: Into<LabeledSpan>>(mut self, label: T) -> Self {
         self.inner.labels = Some(vec![label.into()]);
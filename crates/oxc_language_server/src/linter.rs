@@ -13,7 +13,7 @@ use oxc_linter::{
         AstroPartialLoader, JavaScriptSource, SveltePartialLoader, VuePartialLoader,
         LINT_PARTIAL_LOADER_EXT,
     },
-    LintContext, Linter,
+    FixKind, LintContext, Linter,
 };
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
@@ -150,6 +150,7 @@ struct ErrorReport {
 pub struct FixedContent {
     pub code: String,
     pub range: Range,
+    pub kind: FixKind,
 }
 
 pub struct IsolatedLintHandler {
@@ -315,6 +316,7 @@ impl IsolatedLintHandler {
                 .map(|msg| {
                     let fixed_content = msg.fix.map(|f| FixedContent {
                         code: f.content.to_string(),
+                        kind: f.kind,
                         range: Range {
                             start: offset_to_position(
                                 f.span.start as usize + start,
@@ -387,7 +389,7 @@ pub struct ServerLinter {
 
 impl ServerLinter {
     pub fn new() -> Self {
-        let linter = Linter::default().with_fix(true);
+        let linter = Linter::default().with_fix(FixKind::all());
         Self { linter: Arc::new(linter) }
     }
 
@@ -4,7 +4,7 @@ use crate::linter::{DiagnosticReport, ServerLinter};
 use globset::Glob;
 use ignore::gitignore::Gitignore;
 use log::{debug, error, info};
-use oxc_linter::{LintOptions, Linter};
+use oxc_linter::{FixKind, LintOptions, Linter};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Debug;
@@ -239,18 +239,21 @@ impl LanguageServer for Backend {
                 .iter()
                 .find(|r| r.diagnostic.range == params.range && r.fixed_content.is_some())
             {
-                let title =
+                let fixed_content = report.fixed_content.clone().unwrap();
+
+                let mut title =
                     report.diagnostic.message.split(':').next().map_or_else(
                         || "Fix this problem".into(),
                         |s| format!("Fix this {s} problem"),
                     );
-
-                let fixed_content = report.fixed_content.clone().unwrap();
+                if fixed_content.kind != FixKind::Safe {
+                    title = format!("{title} ({})", fixed_content.kind.description());
+                }
 
                 return Ok(Some(vec![CodeActionOrCommand::CodeAction(CodeAction {
                     title,
                     kind: Some(CodeActionKind::QUICKFIX),
-                    is_preferred: Some(true),
+                    is_preferred: Some(fixed_content.kind == FixKind::Safe),
                     edit: Some(WorkspaceEdit {
                         changes: Some(HashMap::from([(
                             uri,
@@ -344,7 +347,9 @@ impl Backend {
             let mut linter = self.server_linter.write().await;
             *linter = ServerLinter::new_with_linter(
                 Linter::from_options(
-                    LintOptions::default().with_fix(true).with_config_path(Some(config_path)),
+                    LintOptions::default()
+                        .with_fix(FixKind::all())
+                        .with_config_path(Some(config_path)),
                 )
                 .expect("should have initialized linter with new options"),
             );
@@ -0,0 +1,602 @@
+//! ESLint Configuration Migration
+//!
+//! Converts an ESLint configuration, `.eslintrc.json` or a flat `eslint.config.js`, to an
+//! `.oxlintrc.json`:
+//!
+//! * Rules which oxlint supports are copied with their options. The other rules are reported,
+//!   with the oxlint rules of the same name in other plugins, or with similar names.
+//! * `env`, `globals` and the plugin settings which oxlint reads are copied.
+//! * Flat configurations are merged in order. Configurations for some `files` are reported, as
+//!   every oxlint rule applies to all files.
+//! * `extends`, `overrides`, parsers and other options without an oxlint equivalent are reported.
+//!
+//! JavaScript configurations are not run. Their exported value is evaluated when it only contains
+//! literals, and the parts which are not literals, such as `...js.configs.recommended`, are
+//! skipped and reported. Other configurations can be exported to JSON by ESLint first, with
+//! `npx eslint --print-config file.js > .eslintrc.json`.
+
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, match_expression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+use rustc_hash::FxHashSet;
+use serde_json::{Map, Value};
+
+use super::{
+    rules::{parse_rule_key, parse_rule_value},
+    OxlintConfig,
+};
+use crate::rules::{RuleEnum, RULES};
+
+/// Plugins which are disabled by default, with the `oxlint` flag which enables them.
+const DISABLED_PLUGINS: [(&str, &str); 7] = [
+    ("import", "--import-plugin"),
+    ("jsdoc", "--jsdoc-plugin"),
+    ("jest", "--jest-plugin"),
+    ("jsx_a11y", "--jsx-a11y-plugin"),
+    ("nextjs", "--nextjs-plugin"),
+    ("react_perf", "--react-perf-plugin"),
+    ("promise", "--promise-plugin"),
+];
+
+/// Settings which are read by oxlint plugins.
+const SUPPORTED_SETTINGS: [&str; 4] = ["jsx-a11y", "next", "react", "jsdoc"];
+
+/// The ESLint configuration converted to an `.oxlintrc.json`, with what could not be converted.
+#[derive(Debug)]
+pub struct EslintMigration {
+    /// The `.oxlintrc.json` configuration.
+    pub config: Value,
+    /// Enabled rules which oxlint does not support.
+    pub unsupported_rules: Vec<UnsupportedRule>,
+    /// The parts of the configuration which are not converted, and the plugins to enable.
+    pub notes: Vec<String>,
+}
+
+/// An ESLint rule which oxlint does not support.
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnsupportedRule {
+    /// The rule as it is named in the ESLint configuration, e.g. `@typescript-eslint/indent`.
+    pub name: String,
+    /// The closest oxlint rules, as they are named in `.oxlintrc.json`.
+    pub alternatives: Vec<String>,
+}
+
+impl EslintMigration {
+    /// Convert the ESLint configuration file at `path`: a JSON configuration, the `eslintConfig`
+    /// of a `package.json`, or a JavaScript configuration which exports literals.
+    ///
+    /// # Errors
+    ///
+    /// * The file cannot be read or parsed.
+    /// * The file is a YAML configuration, or a JavaScript configuration which does not export
+    ///   an object or an array.
+    pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
+        let extension = path.extension().and_then(|ext| ext.to_str()).unwrap_or_default();
+        match extension {
+            "js" | "cjs" | "mjs" | "ts" | "cts" | "mts" => {
+                let source_text = std::fs::read_to_string(path).map_err(|e| {
+                    OxcDiagnostic::error(format!("Failed to read config {path:?} with error {e:?}"))
+                })?;
+                let source_type =
+                    SourceType::from_path(path).map_err(|error| OxcDiagnostic::error(error.0))?;
+                let (json, skipped) = evaluate_config(&source_text, source_type).ok_or_else(|| {
+                    OxcDiagnostic::error(format!(
+                        "Failed to evaluate config {path:?}, it does not export an object or an array."
+                    ))
+                    .with_help(
+                        "Export it as JSON with `npx eslint --print-config <file> > .eslintrc.json`, and migrate the JSON file instead.",
+                    )
+                })?;
+                let mut migration = Self::from_json(&json);
+                let skipped = skipped
+                    .into_iter()
+                    .map(|source| format!("`{source}` is not a literal and is skipped."));
+                migration.notes.splice(0..0, skipped);
+                Ok(migration)
+            }
+            "yaml" | "yml" => Err(OxcDiagnostic::error(format!(
+                "Failed to parse config {path:?}, YAML configurations are not supported."
+            ))
+            .with_help("Convert it to `.eslintrc.json` first.")),
+            _ => {
+                let json = OxlintConfig::read_json(path)?;
+                if path.file_name().is_some_and(|name| name == "package.json") {
+                    let Some(json) = json.get("eslintConfig") else {
+                        return Err(OxcDiagnostic::error(format!(
+                            "{path:?} does not have an `eslintConfig`."
+                        )));
+                    };
+                    return Ok(Self::from_json(json));
+                }
+                Ok(Self::from_json(&json))
+            }
+        }
+    }
+
+    /// Convert an `.eslintrc` configuration object, or an array of flat configurations.
+    pub fn from_json(json: &Value) -> Self {
+        let mut migrator = Migrator::default();
+        match json {
+            Value::Array(configs) => {
+                for config in configs {
+                    migrator.add_flat_config(config);
+                }
+            }
+            Value::Object(config) => migrator.add_eslintrc(config),
+            _ => migrator.notes.push("The configuration is not an object or an array.".into()),
+        }
+        migrator.finish()
+    }
+}
+
+#[derive(Default)]
+struct Migrator {
+    rules: Map<String, Value>,
+    env: Map<String, Value>,
+    globals: Map<String, Value>,
+    settings: Map<String, Value>,
+    root: Option<bool>,
+    unsupported_rules: Vec<UnsupportedRule>,
+    notes: Vec<String>,
+}
+
+impl Migrator {
+    fn add_eslintrc(&mut self, config: &Map<String, Value>) {
+        for (key, value) in config {
+            match key.as_str() {
+                "rules" => self.add_rules(value),
+                "env" => {
+                    if let Some(env) = value.as_object() {
+                        self.env.extend(env.clone());
+                    }
+                }
+                "globals" => self.add_globals(value),
+                "settings" => self.add_settings(value),
+                "root" => self.root = value.as_bool(),
+                // Plugins are enabled by the rules which use them.
+                "plugins" => {}
+                "extends" => self.notes.push(format!(
+                    "`extends` is not supported, the rules of {value} are not migrated. oxlint enables the `correctness` rules by default."
+                )),
+                "overrides" => self.notes.push(
+                    "`overrides` is not supported, every rule applies to all files.".into(),
+                ),
+                "ignorePatterns" => self.notes.push(format!(
+                    "`ignorePatterns` is not supported, use `--ignore-pattern` for {value}."
+                )),
+                _ => self.notes.push(format!("`{key}` is not supported.")),
+            }
+        }
+    }
+
+    fn add_flat_config(&mut self, config: &Value) {
+        let Some(config) = config.as_object() else { return };
+        if let Some(files) = config.get("files") {
+            self.notes.push(format!(
+                "The configuration for the files {files} is not migrated, every rule applies to all files."
+            ));
+            return;
+        }
+        for (key, value) in config {
+            match key.as_str() {
+                "rules" => self.add_rules(value),
+                "settings" => self.add_settings(value),
+                "languageOptions" => {
+                    let Some(options) = value.as_object() else { continue };
+                    for (key, value) in options {
+                        if key == "globals" {
+                            self.add_globals(value);
+                        } else {
+                            self.notes.push(format!("`languageOptions.{key}` is not supported."));
+                        }
+                    }
+                }
+                // Plugins are enabled by the rules which use them.
+                "plugins" | "name" => {}
+                "ignores" => self.notes.push(format!(
+                    "`ignores` is not supported, use `--ignore-pattern` for {value}."
+                )),
+                _ => self.notes.push(format!("`{key}` is not supported.")),
+            }
+        }
+    }
+
+    fn add_rules(&mut self, rules: &Value) {
+        let Some(rules) = rules.as_object() else { return };
+        for (key, value) in rules {
+            let (plugin_name, rule_name) = parse_rule_key(key);
+            let is_off = parse_rule_value(value).is_ok_and(|(severity, _)| severity.is_allow());
+            // A later flat configuration overrides the rule.
+            self.unsupported_rules.retain(|rule| rule.name != *key);
+            if RULES
+                .iter()
+                .any(|rule| rule.plugin_name() == plugin_name && rule.name() == rule_name)
+            {
+                self.rules.insert(key.clone(), value.clone());
+            } else if !is_off {
+                self.rules.remove(key);
+                let alternatives = alternatives(&plugin_name, &rule_name);
+                self.unsupported_rules.push(UnsupportedRule { name: key.clone(), alternatives });
+            }
+        }
+    }
+
+    /// `true` and `"writable"` are `"writeable"`, `false` and `"readable"` are `"readonly"`.
+    fn add_globals(&mut self, globals: &Value) {
+        let Some(globals) = globals.as_object() else { return };
+        for (name, value) in globals {
+            let value = match value {
+                Value::Bool(true) => "writeable",
+                Value::Bool(false) => "readonly",
+                Value::String(value) => match value.as_str() {
+                    "writable" | "writeable" => "writeable",
+                    "readonly" | "readable" => "readonly",
+                    "off" => "off",
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            self.globals.insert(name.clone(), Value::from(value));
+        }
+    }
+
+    fn add_settings(&mut self, settings: &Value) {
+        let Some(settings) = settings.as_object() else { return };
+        for (key, value) in settings {
+            if SUPPORTED_SETTINGS.contains(&key.as_str()) {
+                self.settings.insert(key.clone(), value.clone());
+            } else {
+                self.notes.push(format!("`settings.{key}` is not supported."));
+            }
+        }
+    }
+
+    fn finish(mut self) -> EslintMigration {
+        let enabled_plugins: FxHashSet<String> = self
+            .rules
+            .iter()
+            .filter(|(_, value)| {
+                parse_rule_value(value).is_ok_and(|(severity, _)| severity.is_warn_deny())
+            })
+            .map(|(key, _)| parse_rule_key(key).0)
+            .collect();
+        for (plugin_name, flag) in DISABLED_PLUGINS {
+            if enabled_plugins.contains(plugin_name) {
+                self.notes.push(format!("Enable the `{plugin_name}` plugin with `{flag}`."));
+            }
+        }
+
+        let mut config = Map::new();
+        for (key, map) in [
+            ("rules", self.rules),
+            ("env", self.env),
+            ("globals", self.globals),
+            ("settings", self.settings),
+        ] {
+            if !map.is_empty() {
+                config.insert(key.into(), Value::Object(map));
+            }
+        }
+        if let Some(root) = self.root {
+            config.insert("root".into(), Value::Bool(root));
+        }
+        EslintMigration {
+            config: Value::Object(config),
+            unsupported_rules: self.unsupported_rules,
+            notes: self.notes,
+        }
+    }
+}
+
+/// The oxlint rules of the same name in other plugins, e.g. `no-unused-vars` for
+/// `@typescript-eslint/no-unused-vars`, or else the rules with the most similar names.
+fn alternatives(plugin_name: &str, rule_name: &str) -> Vec<String> {
+    let same_name: Vec<String> =
+        RULES.iter().filter(|rule| rule.name() == rule_name).map(rule_key).collect();
+    if !same_name.is_empty() {
+        return same_name;
+    }
+    let max_distance = (rule_name.len() / 4).max(2);
+    let mut similar: Vec<(usize, bool, String)> = RULES
+        .iter()
+        .filter_map(|rule| {
+            let distance = edit_distance(rule.name(), rule_name);
+            (distance <= max_distance)
+                .then(|| (distance, rule.plugin_name() != plugin_name, rule_key(rule)))
+        })
+        .collect();
+    similar.sort_unstable();
+    similar.into_iter().take(3).map(|(_, _, key)| key).collect()
+}
+
+/// The name of the rule in `.oxlintrc.json`.
+fn rule_key(rule: &RuleEnum) -> String {
+    match rule.plugin_name() {
+        "eslint" => rule.name().to_string(),
+        plugin_name => format!("{plugin_name}/{}", rule.name()),
+    }
+}
+
+/// The Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// The value exported by a JavaScript configuration, `export default` or `module.exports =`,
+/// with the source text of the parts which are skipped because they are not literals.
+fn evaluate_config(source_text: &str, source_type: SourceType) -> Option<(Value, Vec<String>)> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if !ret.errors.is_empty() {
+        return None;
+    }
+    let mut evaluator = ConfigEvaluator { program: &ret.program, source_text, skipped: vec![] };
+    let exported = evaluator.exported_value()?;
+    // `defineConfig([...])` or `tseslint.config(a, b)`
+    let value = match exported.get_inner_expression() {
+        Expression::CallExpression(call) if !call.arguments.is_empty() => {
+            let mut configs = vec![];
+            for arg in &call.arguments {
+                match arg.as_expression().and_then(|arg| evaluator.evaluate(arg)) {
+                    Some(Value::Array(array)) => configs.extend(array),
+                    Some(value) => configs.push(value),
+                    None => evaluator.skip(arg.span()),
+                }
+            }
+            Value::Array(configs)
+        }
+        expr => evaluator.evaluate(expr)?,
+    };
+    matches!(value, Value::Array(_) | Value::Object(_)).then_some((value, evaluator.skipped))
+}
+
+struct ConfigEvaluator<'a, 'p> {
+    program: &'p Program<'a>,
+    source_text: &'p str,
+    skipped: Vec<String>,
+}
+
+impl<'a, 'p> ConfigEvaluator<'a, 'p> {
+    fn exported_value(&self) -> Option<&'p Expression<'a>> {
+        self.program.body.iter().find_map(|stmt| match stmt {
+            Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                kind @ match_expression!(ExportDefaultDeclarationKind) => {
+                    Some(kind.to_expression())
+                }
+                _ => None,
+            },
+            Statement::ExpressionStatement(stmt) => {
+                let Expression::AssignmentExpression(assign) = &stmt.expression else {
+                    return None;
+                };
+                let member = assign.left.as_member_expression()?;
+                member.is_specific_member_access("module", "exports").then_some(&assign.right)
+            }
+            _ => None,
+        })
+    }
+
+    /// The initializer of a top level `const`.
+    fn constant(&self, name: &str) -> Option<&'p Expression<'a>> {
+        self.program.body.iter().find_map(|stmt| {
+            let Statement::VariableDeclaration(decl) = stmt else { return None };
+            if !decl.kind.is_const() {
+                return None;
+            }
+            decl.declarations.iter().find_map(|declarator| {
+                let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else {
+                    return None;
+                };
+                if id.name == name {
+                    declarator.init.as_ref()
+                } else {
+                    None
+                }
+            })
+        })
+    }
+
+    fn skip(&mut self, span: Span) {
+        self.skipped.push(span.source_text(self.source_text).to_string());
+    }
+
+    fn evaluate(&mut self, expr: &Expression<'a>) -> Option<Value> {
+        match expr.get_inner_expression() {
+            Expression::NullLiteral(_) => Some(Value::Null),
+            Expression::BooleanLiteral(lit) => Some(Value::Bool(lit.value)),
+            // Severities like `2` must stay integers.
+            #[allow(clippy::cast_possible_truncation, clippy::float_cmp)]
+            Expression::NumericLiteral(lit)
+                if lit.value.trunc() == lit.value && lit.value.abs() < 1e15 =>
+            {
+                Some(Value::from(lit.value as i64))
+            }
+            Expression::NumericLiteral(lit) => {
+                serde_json::Number::from_f64(lit.value).map(Value::Number)
+            }
+            Expression::StringLiteral(lit) => Some(Value::from(lit.value.as_str())),
+            Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+                lit.quasi().map(|quasi| Value::from(quasi.as_str()))
+            }
+            // `const config = {}; export default config`
+            Expression::Identifier(ident) => {
+                let init = self.constant(&ident.name)?;
+                // `const config = config` is a TDZ error, not a loop.
+                if init.get_inner_expression().is_specific_id(&ident.name) {
+                    return None;
+                }
+                self.evaluate(init)
+            }
+            Expression::ArrayExpression(array) => {
+                let mut values = vec![];
+                for element in &array.elements {
+                    match element {
+                        ArrayExpressionElement::Elision(_) => values.push(Value::Null),
+                        element @ match_expression!(ArrayExpressionElement) => {
+                            match self.evaluate(element.to_expression()) {
+                                Some(value) => values.push(value),
+                                None => self.skip(element.span()),
+                            }
+                        }
+                        ArrayExpressionElement::SpreadElement(spread) => self.skip(spread.span),
+                    }
+                }
+                Some(Value::Array(values))
+            }
+            Expression::ObjectExpression(object) => {
+                let mut map = Map::new();
+                for property in &object.properties {
+                    let ObjectPropertyKind::ObjectProperty(property) = property else {
+                        self.skip(property.span());
+                        continue;
+                    };
+                    let key = (!property.computed).then(|| property.key.static_name()).flatten();
+                    let Some(key) = key else {
+                        self.skip(property.span);
+                        continue;
+                    };
+                    match self.evaluate(&property.value) {
+                        Some(value) => {
+                            map.insert(key.to_string(), value);
+                        }
+                        None => self.skip(property.span),
+                    }
+                }
+                Some(Value::Object(map))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+    use serde_json::json;
+
+    use super::{evaluate_config, EslintMigration, UnsupportedRule};
+
+    #[test]
+    fn test_eslintrc() {
+        let migration = EslintMigration::from_json(&json!({
+            "root": true,
+            "extends": ["eslint:recommended"],
+            "env": { "browser": true },
+            "globals": { "foo": true, "bar": "readable" },
+            "settings": { "react": { "version": "18" }, "import/resolver": {} },
+            "rules": {
+                "no-debugger": "error",
+                "eqeqeq": ["warn", "always"],
+                "import/no-cycle": 2,
+                "@typescript-eslint/no-loss-of-precision": "warn",
+                "no-debuger": "error",
+                "unknown-plugin/unknown-rule": "off",
+            },
+        }));
+        assert_eq!(
+            migration.config,
+            json!({
+                "rules": {
+                    "no-debugger": "error",
+                    "eqeqeq": ["warn", "always"],
+                    "import/no-cycle": 2,
+                },
+                "env": { "browser": true },
+                "globals": { "foo": "writeable", "bar": "readonly" },
+                "settings": { "react": { "version": "18" } },
+                "root": true,
+            })
+        );
+        assert_eq!(
+            migration.unsupported_rules[0],
+            UnsupportedRule {
+                name: "@typescript-eslint/no-loss-of-precision".into(),
+                alternatives: vec!["no-loss-of-precision".into()],
+            }
+        );
+        // A typo
+        assert_eq!(migration.unsupported_rules[1].name, "no-debuger");
+        assert_eq!(migration.unsupported_rules[1].alternatives[0], "no-debugger");
+        assert_eq!(migration.unsupported_rules.len(), 2);
+        assert!(migration.notes.iter().any(|note| note.starts_with("`extends` is not supported")));
+        assert!(migration
+            .notes
+            .contains(&"`settings.import/resolver` is not supported.".to_string()));
+        assert!(migration
+            .notes
+            .contains(&"Enable the `import` plugin with `--import-plugin`.".to_string()));
+    }
+
+    #[test]
+    fn test_flat_config() {
+        let migration = EslintMigration::from_json(&json!([
+            { "rules": { "no-console": "warn", "no-debugger": "error" } },
+            { "languageOptions": { "globals": { "process": "readonly" } } },
+            { "files": ["**/*.test.js"], "rules": { "no-console": "off" } },
+            { "rules": { "no-debugger": "off" } },
+        ]));
+        assert_eq!(
+            migration.config,
+            json!({
+                "rules": { "no-console": "warn", "no-debugger": "off" },
+                "globals": { "process": "readonly" },
+            })
+        );
+        assert!(
+            migration.notes[0].starts_with("The configuration for the files [\"**/*.test.js\"]")
+        );
+    }
+
+    #[test]
+    fn test_evaluate_config() {
+        let source_type = SourceType::default().with_module(true);
+        let (json, skipped) = evaluate_config(
+            "import js from '@eslint/js';
+            const rules = { 'no-console': 'warn', eqeqeq: ['error', 'smart'] };
+            export default [js.configs.recommended, { rules, plugins: { js }, ...shared }];",
+            source_type,
+        )
+        .unwrap();
+        assert_eq!(
+            json,
+            json!([{
+                "rules": { "no-console": "warn", "eqeqeq": ["error", "smart"] },
+                "plugins": {},
+            }])
+        );
+        assert_eq!(skipped, ["js.configs.recommended", "js", "...shared"]);
+
+        let (json, _) = evaluate_config(
+            "module.exports = { rules: { 'no-debugger': 2 } }",
+            SourceType::default(),
+        )
+        .unwrap();
+        assert_eq!(json, json!({ "rules": { "no-debugger": 2 } }));
+
+        let (json, _) = evaluate_config(
+            "export default tseslint.config({ rules: { eqeqeq: 'warn' } }, [{ rules: {} }])",
+            source_type,
+        )
+        .unwrap();
+        assert_eq!(json, json!([{ "rules": { "eqeqeq": "warn" } }, { "rules": {} }]));
+
+        assert!(evaluate_config("export default createConfig()", source_type).is_none());
+        assert!(evaluate_config("export default 'config'", source_type).is_none());
+    }
+}
@@ -1,5 +1,7 @@
 mod env;
 mod globals;
+mod migrate;
+mod nested;
 mod rules;
 mod settings;
 
@@ -13,8 +15,13 @@ use serde::Deserialize;
 use crate::{rules::RuleEnum, AllowWarnDeny, RuleWithSeverity};
 
 pub use self::{
-    env::OxlintEnv, globals::OxlintGlobals, rules::OxlintRules,
-    settings::jsdoc::JSDocPluginSettings, settings::OxlintSettings,
+    env::OxlintEnv,
+    globals::OxlintGlobals,
+    migrate::{EslintMigration, UnsupportedRule},
+    nested::{NestedConfigs, ResolvedConfig, NESTED_CONFIG_FILE_NAME},
+    rules::OxlintRules,
+    settings::jsdoc::JSDocPluginSettings,
+    settings::OxlintSettings,
 };
 
 /// Oxlint Configuration File
@@ -56,6 +63,11 @@ pub struct OxlintConfig {
     pub(crate) settings: OxlintSettings,
     pub(crate) env: OxlintEnv,
     pub(crate) globals: OxlintGlobals,
+    /// Stop looking for nested configuration files in parent directories.
+    ///
+    /// Only used with `--cascade-config`, where the nested configuration files of all parent
+    /// directories are merged, up to the first one with `"root": true`.
+    pub(crate) root: bool,
 }
 
 impl OxlintConfig {
@@ -63,6 +75,16 @@ impl OxlintConfig {
     ///
     /// * Parse Failure
     pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
+        let json = Self::read_json(path)?;
+        Self::from_json(&json)
+    }
+
+    /// Read a configuration file as json, with comments stripped.
+    ///
+    /// # Errors
+    ///
+    /// * Parse Failure
+    pub(crate) fn read_json(path: &Path) -> Result<serde_json::Value, OxcDiagnostic> {
         let mut string = std::fs::read_to_string(path).map_err(|e| {
             OxcDiagnostic::error(format!("Failed to parse config {path:?} with error {e:?}"))
         })?;
@@ -72,7 +94,7 @@ impl OxlintConfig {
             OxcDiagnostic::error(format!("Failed to parse jsonc file {path:?}: {err:?}"))
         })?;
 
-        let json = serde_json::from_str::<serde_json::Value>(&string).map_err(|err| {
+        serde_json::from_str::<serde_json::Value>(&string).map_err(|err| {
             let guess = mime_guess::from_path(path);
             let err = match guess.first() {
                 // syntax error
@@ -85,13 +107,16 @@ impl OxlintConfig {
                 }
             };
             OxcDiagnostic::error(format!("Failed to parse eslint config {path:?}.\n{err}"))
-        })?;
+        })
+    }
 
-        let config = Self::deserialize(&json).map_err(|err| {
+    /// # Errors
+    ///
+    /// * Parse Failure
+    pub(crate) fn from_json(json: &serde_json::Value) -> Result<Self, OxcDiagnostic> {
+        Self::deserialize(json).map_err(|err| {
             OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
-        })?;
-
-        Ok(config)
+        })
     }
 
     #[allow(clippy::option_if_let_else)]
@@ -212,7 +237,7 @@ mod test {
         }));
         assert!(config.is_ok());
 
-        let OxlintConfig { rules, settings, env, globals } = config.unwrap();
+        let OxlintConfig { rules, settings, env, globals, .. } = config.unwrap();
         assert!(!rules.is_empty());
         assert_eq!(settings.jsx_a11y.polymorphic_prop_name, Some("role".to_string()));
         assert_eq!(env.iter().count(), 1);
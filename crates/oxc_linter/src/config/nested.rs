@@ -0,0 +1,163 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use oxc_diagnostics::OxcDiagnostic;
+use serde_json::Value;
+
+use super::OxlintConfig;
+use crate::{LintOptions, RuleWithSeverity};
+
+/// Name of the configuration file looked up in the directory of each linted file and its parents.
+pub const NESTED_CONFIG_FILE_NAME: &str = ".oxlintrc.json";
+
+/// The configuration which applies to a file.
+pub struct ResolvedConfig {
+    config_files: Vec<PathBuf>,
+    pub(crate) rules: Vec<RuleWithSeverity>,
+    pub(crate) config: Arc<OxlintConfig>,
+}
+
+impl ResolvedConfig {
+    /// Configuration files this configuration is made of, from the nearest to the farthest.
+    ///
+    /// This is a single file unless configuration files are cascaded.
+    pub fn config_files(&self) -> &[PathBuf] {
+        &self.config_files
+    }
+}
+
+/// Per-directory configuration files, similar to ESLint's `.eslintrc` hierarchy.
+///
+/// * Without cascading, the nearest configuration file replaces the `-c` configuration file.
+/// * With cascading, all configuration files from the nearest one up to the first one with
+///   `"root": true` are merged on top of the `-c` configuration file, nearer files taking
+///   precedence. Objects such as `rules`, `env` and `settings` are merged key by key.
+///
+/// Rule filters from the command line (`-A`, `-W`, `-D`) apply before any configuration file.
+pub struct NestedConfigs {
+    cascade: bool,
+    /// The `-c` configuration file, merged under the nested ones when cascading.
+    base: Option<Value>,
+    /// Keyed by directory, `None` if no configuration file is found.
+    cache: DashMap<Box<Path>, Option<Arc<ResolvedConfig>>>,
+}
+
+impl NestedConfigs {
+    /// # Errors
+    ///
+    /// * Returns `Err` if the `-c` configuration file cannot be parsed.
+    pub fn new(options: &LintOptions) -> Result<Self, OxcDiagnostic> {
+        let base = match &options.config_path {
+            Some(path) if options.cascade_config => Some(OxlintConfig::read_json(path)?),
+            _ => None,
+        };
+        Ok(Self { cascade: options.cascade_config, base, cache: DashMap::default() })
+    }
+
+    /// Resolve the configuration for the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Err` if any of the configuration files cannot be parsed.
+    pub fn resolve(
+        &self,
+        path: &Path,
+        options: &LintOptions,
+    ) -> Result<Option<Arc<ResolvedConfig>>, OxcDiagnostic> {
+        let Some(dir) = path.parent() else { return Ok(None) };
+        if let Some(resolved) = self.cache.get(dir) {
+            return Ok(resolved.value().clone());
+        }
+
+        let resolved = self.resolve_dir(dir, options)?;
+        self.cache.insert(dir.to_path_buf().into_boxed_path(), resolved.clone());
+        Ok(resolved)
+    }
+
+    fn resolve_dir(
+        &self,
+        dir: &Path,
+        options: &LintOptions,
+    ) -> Result<Option<Arc<ResolvedConfig>>, OxcDiagnostic> {
+        let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+
+        // From the nearest to the farthest
+        let mut config_files = vec![];
+        let mut configs = vec![];
+        for ancestor in dir.ancestors() {
+            let config_file = ancestor.join(NESTED_CONFIG_FILE_NAME);
+            if !config_file.is_file() {
+                continue;
+            }
+            let json = OxlintConfig::read_json(&config_file)?;
+            let is_root = json.get("root").and_then(Value::as_bool).unwrap_or(false);
+            config_files.push(config_file);
+            configs.push(json);
+            if !self.cascade || is_root {
+                break;
+            }
+        }
+
+        if configs.is_empty() {
+            return Ok(None);
+        }
+
+        let mut json = self.base.clone().unwrap_or_else(|| Value::Object(serde_json::Map::new()));
+        for config in configs.into_iter().rev() {
+            merge_json(&mut json, config);
+        }
+        let config = OxlintConfig::from_json(&json)?;
+        let rules = options.derive_rules(Some(&config));
+        Ok(Some(Arc::new(ResolvedConfig { config_files, rules, config: Arc::new(config) })))
+    }
+}
+
+/// Merge `other` into `base`, values of `other` taking precedence.
+/// Objects are merged recursively, all other values are replaced.
+fn merge_json(base: &mut Value, other: Value) {
+    match (base, other) {
+        (Value::Object(base), Value::Object(other)) => {
+            for (key, value) in other {
+                match base.get_mut(&key) {
+                    Some(base_value) => merge_json(base_value, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, other) => *base = other,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::merge_json;
+
+    #[test]
+    fn test_merge_json() {
+        let mut base = serde_json::json!({
+            "root": true,
+            "env": { "browser": true },
+            "rules": { "eqeqeq": ["error", "always"], "no-debugger": "error" }
+        });
+        merge_json(
+            &mut base,
+            serde_json::json!({
+                "env": { "node": true },
+                "rules": { "eqeqeq": "off", "no-console": "warn" }
+            }),
+        );
+        assert_eq!(
+            base,
+            serde_json::json!({
+                "root": true,
+                "env": { "browser": true, "node": true },
+                "rules": { "eqeqeq": "off", "no-debugger": "error", "no-console": "warn" }
+            })
+        );
+    }
+}
@@ -85,7 +85,7 @@ impl<'de> Deserialize<'de> for OxlintRules {
     }
 }
 
-fn parse_rule_key(name: &str) -> (String, String) {
+pub(super) fn parse_rule_key(name: &str) -> (String, String) {
     let Some((plugin_name, rule_name)) = name.split_once('/') else {
         return ("eslint".to_string(), name.to_string());
     };
@@ -106,7 +106,7 @@ fn parse_rule_key(name: &str) -> (String, String) {
     (oxlint_plugin_name.to_string(), rule_name.to_string())
 }
 
-fn parse_rule_value(
+pub(super) fn parse_rule_value(
     value: &serde_json::Value,
 ) -> Result<(AllowWarnDeny, Option<serde_json::Value>), Error> {
     match value {
@@ -0,0 +1,21 @@
+use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+// <https://github.com/jest-community/eslint-plugin-jest#aliased-jest-globals>
+#[derive(Debug, Deserialize, Default, JsonSchema)]
+pub struct JestPluginSettings {
+    /// Other global names of the test functions, e.g. `{ "describe": ["context"] }`
+    #[serde(default, rename = "globalAliases")]
+    global_aliases: FxHashMap<String, Vec<String>>,
+}
+
+impl JestPluginSettings {
+    /// The test function which the global `name` is an alias of, e.g. `describe` for `context`.
+    pub fn resolve_global_alias(&self, name: &str) -> Option<&str> {
+        self.global_aliases
+            .iter()
+            .find(|(_, aliases)| aliases.iter().any(|alias| alias == name))
+            .map(|(original, _)| original.as_str())
+    }
+}
@@ -1,3 +1,4 @@
+mod jest;
 pub mod jsdoc;
 mod jsx_a11y;
 mod next;
@@ -7,8 +8,8 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 
 use self::{
-    jsdoc::JSDocPluginSettings, jsx_a11y::JSXA11yPluginSettings, next::NextPluginSettings,
-    react::ReactPluginSettings,
+    jest::JestPluginSettings, jsdoc::JSDocPluginSettings, jsx_a11y::JSXA11yPluginSettings,
+    next::NextPluginSettings, react::ReactPluginSettings,
 };
 
 /// Shared settings for plugins
@@ -26,6 +27,9 @@ pub struct OxlintSettings {
 
     #[serde(default)]
     pub jsdoc: JSDocPluginSettings,
+
+    #[serde(default)]
+    pub jest: JestPluginSettings,
 }
 
 #[cfg(test)]
@@ -57,6 +61,12 @@ mod test {
                     {"name": "MyLink", "linkAttribute": "to"},
                     {"name": "Link", "linkAttribute": ["to", "href"]},
                 ]
+            },
+            "jest": {
+                "globalAliases": {
+                    "describe": ["context"],
+                    "it": ["specify", "scenario"]
+                }
             }
         }))
         .unwrap();
@@ -78,6 +88,9 @@ mod test {
             Some(vec!["to".to_string(), "href".to_string()])
         );
         assert_eq!(settings.react.get_link_component_attrs("Noop"), None);
+        assert_eq!(settings.jest.resolve_global_alias("context"), Some("describe"));
+        assert_eq!(settings.jest.resolve_global_alias("scenario"), Some("it"));
+        assert_eq!(settings.jest.resolve_global_alias("describe"), None);
     }
 
     #[test]
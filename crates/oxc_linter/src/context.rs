@@ -7,7 +7,7 @@ use oxc_span::SourceType;
 
 use crate::{
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
-    fixer::{Fix, Message},
+    fixer::{Fix, FixKind, Message},
     javascript_globals::GLOBALS,
     AllowWarnDeny, OxlintConfig, OxlintEnv, OxlintGlobals, OxlintSettings,
 };
@@ -20,8 +20,8 @@ pub struct LintContext<'a> {
 
     disable_directives: Rc<DisableDirectives<'a>>,
 
-    /// Whether or not to apply code fixes during linting.
-    fix: bool,
+    /// The kinds of code fixes to apply during linting.
+    fix: FixKind,
 
     file_path: Rc<Path>,
 
@@ -41,7 +41,7 @@ impl<'a> LintContext<'a> {
             semantic: Rc::clone(semantic),
             diagnostics: RefCell::new(vec![]),
             disable_directives: Rc::new(disable_directives),
-            fix: false,
+            fix: FixKind::empty(),
             file_path: file_path.into(),
             eslint_config: Arc::new(OxlintConfig::default()),
             current_rule_name: "",
@@ -50,7 +50,7 @@ impl<'a> LintContext<'a> {
     }
 
     #[must_use]
-    pub fn with_fix(mut self, fix: bool) -> Self {
+    pub fn with_fix(mut self, fix: FixKind) -> Self {
         self.fix = fix;
         self
     }
@@ -136,9 +136,38 @@ impl<'a> LintContext<'a> {
         self.add_diagnostic(Message::new(diagnostic, None));
     }
 
+    /// Report a diagnostic with a fix which never changes the behavior of the code.
     pub fn diagnostic_with_fix<F: FnOnce() -> Fix<'a>>(&self, diagnostic: OxcDiagnostic, fix: F) {
-        if self.fix {
-            self.add_diagnostic(Message::new(diagnostic, Some(fix())));
+        self.diagnostic_with_fix_kind(FixKind::Safe, diagnostic, fix);
+    }
+
+    /// Report a diagnostic with a fix which is probably what is intended, but may change the
+    /// behavior of the code.
+    pub fn diagnostic_with_suggestion<F: FnOnce() -> Fix<'a>>(
+        &self,
+        diagnostic: OxcDiagnostic,
+        fix: F,
+    ) {
+        self.diagnostic_with_fix_kind(FixKind::Suggestion, diagnostic, fix);
+    }
+
+    /// Report a diagnostic with a fix which changes the behavior of the code.
+    pub fn diagnostic_with_dangerous_fix<F: FnOnce() -> Fix<'a>>(
+        &self,
+        diagnostic: OxcDiagnostic,
+        fix: F,
+    ) {
+        self.diagnostic_with_fix_kind(FixKind::Dangerous, diagnostic, fix);
+    }
+
+    fn diagnostic_with_fix_kind<F: FnOnce() -> Fix<'a>>(
+        &self,
+        kind: FixKind,
+        diagnostic: OxcDiagnostic,
+        fix: F,
+    ) {
+        if self.fix.contains(kind) {
+            self.add_diagnostic(Message::new(diagnostic, Some(fix().with_kind(kind))));
         } else {
             self.diagnostic(diagnostic);
         }
@@ -1,21 +1,106 @@
 use std::borrow::Cow;
 
+use bitflags::bitflags;
+
+use oxc_ast::{CommentEdits, CommentMap, Trivias};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 
+bitflags! {
+    /// How likely a fix is to change the behavior of the code.
+    ///
+    /// Each fix has one kind, and a set of kinds selects the fixes which are applied.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct FixKind: u8 {
+        /// Never changes the behavior of the code, applied by `--fix`.
+        const Safe = 1 << 0;
+        /// Probably what is intended, but may change the behavior of the code, e.g. removing a
+        /// `debugger` statement. Applied by `--fix-suggestions`.
+        const Suggestion = 1 << 1;
+        /// Changes the behavior of the code, e.g. replacing `null` with `undefined`. Applied by
+        /// `--fix-dangerously`.
+        const Dangerous = 1 << 2;
+    }
+}
+
+impl FixKind {
+    /// A description of a single kind, e.g. for the title of an editor code action.
+    pub fn description(self) -> &'static str {
+        if self.contains(Self::Dangerous) {
+            "dangerous fix"
+        } else if self.contains(Self::Suggestion) {
+            "suggestion"
+        } else {
+            "safe fix"
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Fix<'a> {
     pub content: Cow<'a, str>,
     pub span: Span,
+    pub kind: FixKind,
 }
 
 impl<'a> Fix<'a> {
     pub const fn delete(span: Span) -> Self {
-        Self { content: Cow::Borrowed(""), span }
+        Self { content: Cow::Borrowed(""), span, kind: FixKind::Safe }
     }
 
     pub fn new<T: Into<Cow<'a, str>>>(content: T, span: Span) -> Self {
-        Self { content: content.into(), span }
+        Self { content: content.into(), span, kind: FixKind::Safe }
+    }
+
+    #[must_use]
+    pub fn with_kind(mut self, kind: FixKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// A fix which applies comment-preserving `edits`, or `None` if there are no edits or two of
+    /// them overlap.
+    pub fn from_edits(edits: &CommentEdits) -> Option<Self> {
+        let edit = edits.merged()?;
+        Some(Self::new(edit.content, edit.span))
+    }
+
+    /// Reorder consecutive statements, moving the comments attached to each statement with it
+    /// (see [CommentMap]). The comments leading the first statement stay at the start, as they
+    /// may be the header of the file.
+    ///
+    /// `statements` are the spans of the statements in source order. `order` lists the indices of
+    /// `statements` in the new order, each with the separator printed before it, or `None` to keep
+    /// the whitespace which separated the statements at this position. The separator of the first
+    /// statement is ignored.
+    ///
+    /// Returns `None` if there is code or a detached comment between the statements, which
+    /// would be lost by the fix.
+    pub fn reorder_statements(
+        source_text: &str,
+        trivias: &Trivias,
+        statements: &[Span],
+        order: &[(usize, Option<&str>)],
+    ) -> Option<Self> {
+        let start = statements.first()?.start;
+        let comments = CommentMap::new(source_text, trivias, statements);
+        let mut spans = statements.iter().map(|span| comments.full_span(*span)).collect::<Vec<_>>();
+        spans[0].start = start;
+        let gaps = spans
+            .windows(2)
+            .map(|pair| &source_text[pair[0].end as usize..pair[1].start as usize])
+            .collect::<Vec<_>>();
+        if gaps.iter().any(|gap| !gap.trim().is_empty()) {
+            return None;
+        }
+        let mut content = String::new();
+        for (i, (index, separator)) in order.iter().enumerate() {
+            if i > 0 {
+                content.push_str(separator.unwrap_or(gaps[i - 1]));
+            }
+            content.push_str(spans[*index].source_text(source_text));
+        }
+        Some(Self::new(content, Span::new(start, spans[spans.len() - 1].end)))
     }
 }
 
@@ -23,6 +108,10 @@ pub struct FixResult<'a> {
     pub fixed: bool,
     pub fixed_code: Cow<'a, str>,
     pub messages: Vec<Message<'a>>,
+    /// The applied fixes, in source order.
+    pub applied: Vec<Fix<'a>>,
+    /// Spans of the fixes which are not applied because they overlap with an applied fix.
+    pub conflicts: Vec<Span>,
 }
 
 #[derive(Clone)]
@@ -82,6 +171,8 @@ impl<'a> Fixer<'a> {
                 fixed: false,
                 fixed_code: Cow::Borrowed(source_text),
                 messages: self.messages,
+                applied: vec![],
+                conflicts: vec![],
             };
         }
 
@@ -89,14 +180,27 @@ impl<'a> Fixer<'a> {
         let mut fixed = false;
         let mut output = String::with_capacity(source_text.len());
         let mut last_pos: i64 = -1;
+        let mut applied = vec![];
+        let mut conflicts = vec![];
         self.messages.iter_mut().filter(|m| m.fix.is_some()).for_each(|m| {
-            let Fix { content, span } = m.fix.as_ref().unwrap();
+            let fix = m.fix.as_ref().unwrap();
+            let Fix { content, span, .. } = fix;
             let start = span.start;
             let end = span.end;
             if start > end {
                 return;
             }
+            // A fix shared by several messages, like reordering a block of statements,
+            // is applied once.
+            if applied
+                .last()
+                .is_some_and(|last: &Fix| last.span == *span && last.content == *content)
+            {
+                m.fixed = true;
+                return;
+            }
             if i64::from(start) <= last_pos {
+                conflicts.push(*span);
                 return;
             }
 
@@ -106,6 +210,7 @@ impl<'a> Fixer<'a> {
             output.push_str(&source_text[offset..start as usize]);
             output.push_str(content);
             last_pos = i64::from(end);
+            applied.push(fix.clone());
         });
 
         let offset = usize::try_from(last_pos.max(0)).ok().unwrap();
@@ -113,7 +218,7 @@ impl<'a> Fixer<'a> {
 
         let mut messages = self.messages.into_iter().filter(|m| !m.fixed).collect::<Vec<_>>();
         messages.sort_by_key(|m| (m.start, m.end));
-        FixResult { fixed, fixed_code: Cow::Owned(output), messages }
+        FixResult { fixed, fixed_code: Cow::Owned(output), messages, applied, conflicts }
     }
 }
 
@@ -121,10 +226,14 @@ impl<'a> Fixer<'a> {
 mod test {
     use std::borrow::Cow;
 
+    use oxc_allocator::Allocator;
     use oxc_diagnostics::OxcDiagnostic;
-    use oxc_span::Span;
+    use oxc_parser::Parser;
+    use oxc_span::{GetSpan, SourceType, Span};
 
-    use super::{Fix, FixResult, Fixer, Message};
+    use oxc_ast::{CommentEdits, CommentMap};
+
+    use super::{Fix, FixKind, FixResult, Fixer, Message};
 
     fn insert_at_end() -> OxcDiagnostic {
         OxcDiagnostic::warn("End")
@@ -179,16 +288,23 @@ mod test {
     }
 
     const TEST_CODE: &str = "var answer = 6 * 7;";
-    const INSERT_AT_END: Fix = Fix { span: Span::new(19, 19), content: Cow::Borrowed("// end") };
-    const INSERT_AT_START: Fix = Fix { span: Span::new(0, 0), content: Cow::Borrowed("// start") };
-    const INSERT_AT_MIDDLE: Fix = Fix { span: Span::new(13, 13), content: Cow::Borrowed("5 *") };
-    const REPLACE_ID: Fix = Fix { span: Span::new(4, 10), content: Cow::Borrowed("foo") };
-    const REPLACE_VAR: Fix = Fix { span: Span::new(0, 3), content: Cow::Borrowed("let") };
-    const REPLACE_NUM: Fix = Fix { span: Span::new(13, 14), content: Cow::Borrowed("5") };
+    const INSERT_AT_END: Fix =
+        Fix { span: Span::new(19, 19), content: Cow::Borrowed("// end"), kind: FixKind::Safe };
+    const INSERT_AT_START: Fix =
+        Fix { span: Span::new(0, 0), content: Cow::Borrowed("// start"), kind: FixKind::Safe };
+    const INSERT_AT_MIDDLE: Fix =
+        Fix { span: Span::new(13, 13), content: Cow::Borrowed("5 *"), kind: FixKind::Safe };
+    const REPLACE_ID: Fix =
+        Fix { span: Span::new(4, 10), content: Cow::Borrowed("foo"), kind: FixKind::Safe };
+    const REPLACE_VAR: Fix =
+        Fix { span: Span::new(0, 3), content: Cow::Borrowed("let"), kind: FixKind::Safe };
+    const REPLACE_NUM: Fix =
+        Fix { span: Span::new(13, 14), content: Cow::Borrowed("5"), kind: FixKind::Safe };
     const REMOVE_START: Fix = Fix::delete(Span::new(0, 4));
     const REMOVE_MIDDLE: Fix = Fix::delete(Span::new(5, 10));
     const REMOVE_END: Fix = Fix::delete(Span::new(14, 18));
-    const REVERSE_RANGE: Fix = Fix { span: Span::new(3, 0), content: Cow::Borrowed(" ") };
+    const REVERSE_RANGE: Fix =
+        Fix { span: Span::new(3, 0), content: Cow::Borrowed(" "), kind: FixKind::Safe };
 
     fn get_fix_result(messages: Vec<Message>) -> FixResult {
         Fixer::new(TEST_CODE, messages).fix()
@@ -335,6 +451,8 @@ mod test {
         assert_eq!(result.messages.len(), 1);
         assert_eq!(result.messages[0].error.to_string(), "removemiddle");
         assert!(result.fixed);
+        assert_eq!(result.applied.len(), 1);
+        assert_eq!(result.conflicts, vec![REMOVE_MIDDLE.span]);
     }
 
     #[test]
@@ -398,4 +516,130 @@ mod test {
         assert_eq!(result.messages[1].error.to_string(), "nofix2");
         assert!(result.fixed);
     }
+
+    #[test]
+    fn apply_shared_fix_once() {
+        let result = get_fix_result(vec![
+            create_message(replace_id(), Some(REPLACE_ID)),
+            create_message(no_fix_1(Span::default()), Some(REPLACE_ID)),
+        ]);
+        assert_eq!(result.fixed_code, TEST_CODE.replace("answer", "foo"));
+        assert_eq!(result.messages.len(), 0);
+        assert!(result.conflicts.is_empty());
+    }
+
+    #[test]
+    fn reorder_statements_with_comments() {
+        let source_text = "// header
+import a from 'a'; // trailing a
+/* b */ import b from 'b';
+
+// c
+import c from 'c';
+";
+        let allocator = Allocator::default();
+        let ret =
+            Parser::new(&allocator, source_text, SourceType::default().with_module(true)).parse();
+        let statements = ret.program.body.iter().map(GetSpan::span).collect::<Vec<_>>();
+        let fix = Fix::reorder_statements(
+            source_text,
+            &ret.trivias,
+            &statements,
+            &[(2, None), (1, Some("\n")), (0, None)],
+        )
+        .unwrap();
+        let result =
+            Fixer::new(source_text, vec![create_message(no_fix(Span::default()), Some(fix))]).fix();
+        assert_eq!(
+            result.fixed_code,
+            "// header
+// c
+import c from 'c';
+/* b */ import b from 'b';
+
+import a from 'a'; // trailing a
+"
+        );
+    }
+
+    #[test]
+    fn reorder_statements_with_detached_comment() {
+        let source_text = "import a from 'a';\n\n// section\n\nimport b from 'b';\n";
+        let allocator = Allocator::default();
+        let ret =
+            Parser::new(&allocator, source_text, SourceType::default().with_module(true)).parse();
+        let statements = ret.program.body.iter().map(GetSpan::span).collect::<Vec<_>>();
+        let fix = Fix::reorder_statements(
+            source_text,
+            &ret.trivias,
+            &statements,
+            &[(1, None), (0, None)],
+        );
+        assert!(fix.is_none());
+    }
+
+    /// The source text after `f` edits the statements of `source_text`.
+    fn edit_statements(source_text: &str, f: impl FnOnce(&mut CommentEdits, &[Span])) -> String {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        let statements = ret.program.body.iter().map(GetSpan::span).collect::<Vec<_>>();
+        let comments = CommentMap::new(source_text, &ret.trivias, &statements);
+        let mut edits = CommentEdits::new(&comments);
+        f(&mut edits, &statements);
+        let fix = Fix::from_edits(&edits).unwrap();
+        let result =
+            Fixer::new(source_text, vec![create_message(no_fix(Span::default()), Some(fix))]).fix();
+        result.fixed_code.to_string()
+    }
+
+    #[test]
+    fn comment_edits() {
+        assert_eq!(
+            edit_statements("// a\na(); // after a\nb();\n", |edits, statements| {
+                edits.move_after(statements[0], statements[1], "\n");
+            }),
+            "b();\n// a\na(); // after a\n"
+        );
+        assert_eq!(
+            edit_statements("/** doc */\nfunction f() {}\n", |edits, statements| {
+                edits.insert_before(statements[0], "'use strict';\n");
+            }),
+            "'use strict';\n/** doc */\nfunction f() {}\n"
+        );
+        assert_eq!(
+            edit_statements("a();\n// gone\nb(); // gone too\nc();\n", |edits, statements| {
+                edits.delete_with_comments(statements[1]);
+            }),
+            "a();\nc();\n"
+        );
+    }
+
+    #[test]
+    fn comment_edits_keep_inner_comments() {
+        assert_eq!(
+            edit_statements("a(/* keep */ 1);\nb();\n", |edits, statements| {
+                edits.delete(statements[0]);
+            }),
+            "/* keep */\nb();\n"
+        );
+        assert_eq!(
+            edit_statements("f(\n  // why\n  x\n);\n", |edits, statements| {
+                edits.replace(statements[0], "g(x);");
+            }),
+            "// why\ng(x);\n"
+        );
+    }
+
+    #[test]
+    fn comment_edits_overlap() {
+        let source_text = "a();\n";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        let statements = ret.program.body.iter().map(GetSpan::span).collect::<Vec<_>>();
+        let comments = CommentMap::new(source_text, &ret.trivias, &statements);
+        let mut edits = CommentEdits::new(&comments);
+        edits.delete(statements[0]);
+        edits.replace(statements[0], "b();");
+        assert!(Fix::from_edits(&edits).is_none());
+    }
 }
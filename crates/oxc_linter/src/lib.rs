@@ -12,6 +12,7 @@ mod fixer;
 mod globals;
 mod javascript_globals;
 mod options;
+mod patch;
 mod rule;
 mod rules;
 mod service;
@@ -20,20 +21,24 @@ mod utils;
 pub mod partial_loader;
 pub mod table;
 
-use std::{io::Write, rc::Rc, sync::Arc};
+use std::{io::Write, path::Path, rc::Rc, sync::Arc};
 
-use oxc_diagnostics::Error;
+use oxc_diagnostics::{DiagnosticCode, Error, OxcDiagnostic};
 use oxc_semantic::AstNode;
 
 pub use crate::{
-    config::OxlintConfig,
+    config::{
+        EslintMigration, OxlintConfig, ResolvedConfig, UnsupportedRule, NESTED_CONFIG_FILE_NAME,
+    },
     context::LintContext,
+    fixer::FixKind,
     options::{AllowWarnDeny, LintOptions},
+    patch::FilePatch,
     rule::{RuleCategory, RuleMeta, RuleWithSeverity},
     service::{LintService, LintServiceOptions},
 };
 use crate::{
-    config::{OxlintEnv, OxlintGlobals, OxlintSettings},
+    config::{NestedConfigs, OxlintEnv, OxlintGlobals, OxlintSettings},
     fixer::Fix,
     fixer::{Fixer, Message},
     rules::RuleEnum,
@@ -55,6 +60,7 @@ pub struct Linter {
     rules: Vec<RuleWithSeverity>,
     options: LintOptions,
     eslint_config: Arc<OxlintConfig>,
+    nested_configs: Option<NestedConfigs>,
 }
 
 impl Default for Linter {
@@ -69,7 +75,10 @@ impl Linter {
     /// Returns `Err` if there are any errors parsing the configuration file.
     pub fn from_options(options: LintOptions) -> Result<Self, Error> {
         let (rules, eslint_config) = options.derive_rules_and_config()?;
-        Ok(Self { rules, options, eslint_config: Arc::new(eslint_config) })
+        let nested_configs = (options.nested_config || options.cascade_config)
+            .then(|| NestedConfigs::new(&options))
+            .transpose()?;
+        Ok(Self { rules, options, eslint_config: Arc::new(eslint_config), nested_configs })
     }
 
     #[cfg(test)]
@@ -85,9 +94,18 @@ impl Linter {
         self
     }
 
+    /// Use `config` instead of the configuration file of the options, e.g. a configuration passed
+    /// by an API instead of a file. The rules of `config` override the rules of the filter.
     #[must_use]
-    pub fn with_fix(mut self, yes: bool) -> Self {
-        self.options.fix = yes;
+    pub fn with_config(mut self, config: OxlintConfig) -> Self {
+        self.rules = self.options.derive_rules(Some(&config));
+        self.eslint_config = Arc::new(config);
+        self
+    }
+
+    #[must_use]
+    pub fn with_fix(mut self, kind: FixKind) -> Self {
+        self.options.fix = kind;
         self
     }
 
@@ -99,12 +117,39 @@ impl Linter {
         self.rules.len()
     }
 
+    /// The nested configuration which applies to the file at `path`, e.g. for editor integrations
+    /// to show which configuration files are used.
+    ///
+    /// Returns `None` if nested configuration is disabled or no configuration file is found,
+    /// in which case the `-c` configuration applies.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if any of the configuration files cannot be parsed.
+    pub fn resolve_config(
+        &self,
+        path: &Path,
+    ) -> Result<Option<Arc<ResolvedConfig>>, OxcDiagnostic> {
+        match &self.nested_configs {
+            Some(nested_configs) => nested_configs.resolve(path, &self.options),
+            None => Ok(None),
+        }
+    }
+
     pub fn run<'a>(&self, ctx: LintContext<'a>) -> Vec<Message<'a>> {
         let semantic = Rc::clone(ctx.semantic());
 
-        let ctx = ctx.with_fix(self.options.fix).with_eslint_config(&self.eslint_config);
-        let rules = self
-            .rules
+        let resolved_config = match self.resolve_config(ctx.file_path()) {
+            Ok(resolved_config) => resolved_config,
+            Err(error) => return vec![Message::new(error, None)],
+        };
+        let (rules, eslint_config) =
+            resolved_config.as_ref().map_or((&self.rules, &self.eslint_config), |resolved| {
+                (&resolved.rules, &resolved.config)
+            });
+
+        let ctx = ctx.with_fix(self.options.enabled_fixes()).with_eslint_config(eslint_config);
+        let rules = rules
             .iter()
             .map(|rule| {
                 (rule, ctx.clone().with_rule_name(rule.name()).with_severity(rule.severity))
@@ -139,6 +184,19 @@ impl Linter {
         writeln!(writer, "Default: {}", table.turned_on_by_default_count).unwrap();
         writeln!(writer, "Total: {}", table.total).unwrap();
     }
+
+    /// Print the extended explanation of the parser or semantic diagnostic `code`, such as
+    /// `oxc(parse::unexpected_token)`. Returns `false` if the code is unknown.
+    ///
+    /// # Panics
+    pub fn print_explanation<W: Write>(writer: &mut W, code: &str) -> bool {
+        let registries = [oxc_parser::DIAGNOSTIC_CODES, oxc_semantic::DIAGNOSTIC_CODES];
+        let Some(code) = DiagnosticCode::find(&registries, code) else {
+            return false;
+        };
+        writeln!(writer, "{code}\n\n{}", code.explanation()).unwrap();
+        true
+    }
 }
 
 #[cfg(test)]
@@ -152,6 +210,25 @@ mod test {
         assert!(!writer.is_empty());
     }
 
+    #[test]
+    fn print_explanation() {
+        let mut writer = Vec::new();
+        assert!(Linter::print_explanation(&mut writer, "semantic::with_statement"));
+        let explanation = String::from_utf8(writer).unwrap();
+        assert!(explanation.starts_with("oxc(semantic::with_statement)\n\nA `with` statement"));
+        assert!(!Linter::print_explanation(&mut Vec::new(), "oxc(parse::unknown)"));
+    }
+
+    #[test]
+    fn with_config() {
+        let config: OxlintConfig =
+            serde_json::from_value(serde_json::json!({ "rules": { "no-debugger": "off" } }))
+                .unwrap();
+        let linter = Linter::default();
+        let number_of_rules = linter.number_of_rules();
+        assert_eq!(linter.with_config(config).number_of_rules(), number_of_rules - 1);
+    }
+
     #[test]
     fn test_schema_json() {
         use project_root::get_project_root;
@@ -5,7 +5,9 @@ use serde_json::{Number, Value};
 
 use oxc_diagnostics::{Error, OxcDiagnostic, Severity};
 
-use crate::{config::OxlintConfig, rules::RULES, RuleCategory, RuleEnum, RuleWithSeverity};
+use crate::{
+    config::OxlintConfig, rules::RULES, FixKind, RuleCategory, RuleEnum, RuleWithSeverity,
+};
 
 #[derive(Debug)]
 pub struct LintOptions {
@@ -13,7 +15,16 @@ pub struct LintOptions {
     /// Defaults to [("deny", "correctness")]
     pub filter: Vec<(AllowWarnDeny, String)>,
     pub config_path: Option<PathBuf>,
-    pub fix: bool,
+    /// Use the nearest `.oxlintrc.json` of each linted file instead of `config_path`.
+    pub nested_config: bool,
+    /// Merge the nested `.oxlintrc.json` files of all parent directories on top of `config_path`,
+    /// up to the first one with `"root": true`. Implies `nested_config`.
+    pub cascade_config: bool,
+    /// The kinds of fixes to apply, none by default.
+    pub fix: FixKind,
+    /// Collect the changes of `fix` as [crate::FilePatch]es instead of writing them.
+    /// Safe fixes are collected if `fix` is empty.
+    pub fix_dry_run: bool,
 
     pub react_plugin: bool,
     pub unicorn_plugin: bool,
@@ -25,6 +36,7 @@ pub struct LintOptions {
     pub jsx_a11y_plugin: bool,
     pub nextjs_plugin: bool,
     pub react_perf_plugin: bool,
+    pub promise_plugin: bool,
 }
 
 impl Default for LintOptions {
@@ -32,7 +44,10 @@ impl Default for LintOptions {
         Self {
             filter: vec![(AllowWarnDeny::Warn, String::from("correctness"))],
             config_path: None,
-            fix: false,
+            nested_config: false,
+            cascade_config: false,
+            fix: FixKind::empty(),
+            fix_dry_run: false,
             react_plugin: true,
             unicorn_plugin: true,
             typescript_plugin: true,
@@ -43,6 +58,7 @@ impl Default for LintOptions {
             jsx_a11y_plugin: false,
             nextjs_plugin: false,
             react_perf_plugin: false,
+            promise_plugin: false,
         }
     }
 }
@@ -63,11 +79,38 @@ impl LintOptions {
     }
 
     #[must_use]
-    pub fn with_fix(mut self, yes: bool) -> Self {
-        self.fix = yes;
+    pub fn with_nested_config(mut self, yes: bool) -> Self {
+        self.nested_config = yes;
         self
     }
 
+    #[must_use]
+    pub fn with_cascade_config(mut self, yes: bool) -> Self {
+        self.cascade_config = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_fix(mut self, kind: FixKind) -> Self {
+        self.fix = kind;
+        self
+    }
+
+    #[must_use]
+    pub fn with_fix_dry_run(mut self, yes: bool) -> Self {
+        self.fix_dry_run = yes;
+        self
+    }
+
+    /// The kinds of fixes which are applied or collected.
+    pub(crate) fn enabled_fixes(&self) -> FixKind {
+        if self.fix_dry_run && self.fix.is_empty() {
+            FixKind::Safe
+        } else {
+            self.fix
+        }
+    }
+
     #[must_use]
     pub fn with_react_plugin(mut self, yes: bool) -> Self {
         self.react_plugin = yes;
@@ -127,6 +170,12 @@ impl LintOptions {
         self.react_perf_plugin = yes;
         self
     }
+
+    #[must_use]
+    pub fn with_promise_plugin(mut self, yes: bool) -> Self {
+        self.promise_plugin = yes;
+        self
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -207,7 +256,12 @@ impl LintOptions {
     pub fn derive_rules_and_config(&self) -> Result<(Vec<RuleWithSeverity>, OxlintConfig), Error> {
         let config =
             self.config_path.as_ref().map(|path| OxlintConfig::from_file(path)).transpose()?;
+        let rules = self.derive_rules(config.as_ref());
+        Ok((rules, config.unwrap_or_default()))
+    }
 
+    /// Rules enabled by `self.filter`, overridden by the rules of `config`.
+    pub(crate) fn derive_rules(&self, config: Option<&OxlintConfig>) -> Vec<RuleWithSeverity> {
         let mut rules: FxHashSet<RuleWithSeverity> = FxHashSet::default();
         let all_rules = self.get_filtered_rules();
 
@@ -256,7 +310,7 @@ impl LintOptions {
             }
         }
 
-        if let Some(config) = &config {
+        if let Some(config) = config {
             config.override_rules(&mut rules, &all_rules);
         }
 
@@ -265,7 +319,7 @@ impl LintOptions {
         // for stable diagnostics output ordering
         rules.sort_unstable_by_key(|rule| rule.id());
 
-        Ok((rules, config.unwrap_or_default()))
+        rules
     }
 
     /// Get final filtered rules by reading `self.xxx_plugin`
@@ -282,6 +336,7 @@ impl LintOptions {
                 "jsx_a11y" => self.jsx_a11y_plugin,
                 "nextjs" => self.nextjs_plugin,
                 "react_perf" => self.react_perf_plugin,
+                "promise" => self.promise_plugin,
                 "oxc" => self.oxc_plugin,
                 "eslint" | "tree_shaking" => true,
                 name => panic!("Unhandled plugin: {name}"),
@@ -0,0 +1,159 @@
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+use serde_json::{json, Value};
+use similar::TextDiff;
+
+use oxc_span::Span;
+
+use crate::fixer::FixResult;
+
+/// The changes `--fix` would make to a file, reported instead of writing them with `--fix-dry-run`.
+#[derive(Debug, Clone)]
+pub struct FilePatch {
+    path: PathBuf,
+    source_text: String,
+    fixed_code: String,
+    /// `(span, content)` of the applied fixes, in source order.
+    edits: Vec<(Span, String)>,
+    conflicts: Vec<Span>,
+}
+
+impl FilePatch {
+    pub(crate) fn new(path: &Path, source_text: &str, fix_result: &FixResult) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            source_text: source_text.to_string(),
+            fixed_code: fix_result.fixed_code.to_string(),
+            edits: fix_result
+                .applied
+                .iter()
+                .map(|fix| (fix.span, fix.content.to_string()))
+                .collect(),
+            conflicts: fix_result.conflicts.clone(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn fixed_code(&self) -> &str {
+        &self.fixed_code
+    }
+
+    /// Spans of the fixes which are not applied because they overlap with an applied fix.
+    /// They are applied by running `--fix` again.
+    pub fn conflicts(&self) -> &[Span] {
+        &self.conflicts
+    }
+
+    /// Print the changes as a unified diff, which can be applied with `git apply`.
+    ///
+    /// Conflicting fixes are listed before the diff, where they are ignored by `git apply`.
+    pub fn to_unified_diff(&self) -> String {
+        let path = self.path.to_string_lossy().replace('\\', "/");
+        let mut output = String::new();
+        for span in &self.conflicts {
+            let (line, column) = self.line_column(span.start);
+            writeln!(
+                output,
+                "# {path}:{line}:{column}: skipped a fix which overlaps with another fix"
+            )
+            .unwrap();
+        }
+        let diff = TextDiff::from_lines(&self.source_text, &self.fixed_code);
+        write!(
+            output,
+            "{}",
+            diff.unified_diff().header(&format!("a/{path}"), &format!("b/{path}"))
+        )
+        .unwrap();
+        output
+    }
+
+    /// Machine-readable form of the changes, with offsets in bytes of the original source text.
+    pub fn to_json(&self) -> Value {
+        let edits = self
+            .edits
+            .iter()
+            .map(|(span, content)| json!({ "start": span.start, "end": span.end, "content": content }))
+            .collect::<Vec<_>>();
+        let conflicts = self
+            .conflicts
+            .iter()
+            .map(|span| json!({ "start": span.start, "end": span.end }))
+            .collect::<Vec<_>>();
+        json!({
+            "path": self.path.to_string_lossy(),
+            "edits": edits,
+            "conflicts": conflicts,
+        })
+    }
+
+    /// 1-based line and column of `offset`.
+    fn line_column(&self, offset: u32) -> (usize, usize) {
+        let before = &self.source_text[..offset as usize];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rfind('\n').map_or(before, |i| &before[i + 1..]).chars().count() + 1;
+        (line, column)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{borrow::Cow, path::Path};
+
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_span::Span;
+    use serde_json::json;
+
+    use super::FilePatch;
+    use crate::fixer::{Fix, FixKind, Fixer, Message};
+
+    #[test]
+    fn unified_diff() {
+        let source_text = "var a = 1;\nvar b = 2;\n";
+        let messages = vec![
+            Message::new(
+                OxcDiagnostic::warn("var"),
+                Some(Fix {
+                    span: Span::new(11, 14),
+                    content: Cow::Borrowed("let"),
+                    kind: FixKind::Safe,
+                }),
+            ),
+            Message::new(
+                OxcDiagnostic::warn("overlap"),
+                Some(Fix {
+                    span: Span::new(12, 16),
+                    content: Cow::Borrowed(""),
+                    kind: FixKind::Safe,
+                }),
+            ),
+        ];
+        let fix_result = Fixer::new(source_text, messages).fix();
+        let patch = FilePatch::new(Path::new("src/a.js"), source_text, &fix_result);
+        assert_eq!(
+            patch.to_unified_diff(),
+            "# src/a.js:2:2: skipped a fix which overlaps with another fix
+--- a/src/a.js
++++ b/src/a.js
+@@ -1,2 +1,2 @@
+ var a = 1;
+-var b = 2;
++let b = 2;
+"
+        );
+        assert_eq!(
+            patch.to_json(),
+            json!({
+                "path": "src/a.js",
+                "edits": [{ "start": 11, "end": 14, "content": "let" }],
+                "conflicts": [{ "start": 12, "end": 16 }],
+            })
+        );
+    }
+}
@@ -20,10 +20,13 @@ mod import {
     pub mod no_named_as_default_member;
     pub mod no_self_import;
     // pub mod no_unused_modules;
+    pub mod order;
 }
 
 mod eslint {
+    pub mod accessor_pairs;
     pub mod array_callback_return;
+    pub mod consistent_return;
     pub mod constructor_super;
     pub mod default_case;
     pub mod default_case_last;
@@ -83,6 +86,7 @@ mod eslint {
     pub mod no_redeclare;
     pub mod no_regex_spaces;
     pub mod no_restricted_globals;
+    pub mod no_return_await;
     pub mod no_script_url;
     pub mod no_self_assign;
     pub mod no_self_compare;
@@ -93,6 +97,7 @@ mod eslint {
     pub mod no_ternary;
     pub mod no_this_before_super;
     pub mod no_undef;
+    pub mod no_unreachable;
     pub mod no_unsafe_finally;
     pub mod no_unsafe_negation;
     pub mod no_unsafe_optional_chaining;
@@ -127,6 +132,7 @@ mod typescript {
     pub mod no_empty_interface;
     pub mod no_explicit_any;
     pub mod no_extra_non_null_assertion;
+    pub mod no_floating_promises;
     pub mod no_misused_new;
     pub mod no_namespace;
     pub mod no_non_null_asserted_optional_chain;
@@ -228,6 +234,7 @@ mod unicorn {
     pub mod no_abusive_eslint_disable;
     pub mod no_anonymous_default_export;
     pub mod no_array_for_each;
+    pub mod no_array_method_this_argument;
     pub mod no_array_reduce;
     pub mod no_await_expression_member;
     pub mod no_await_in_promise_methods;
@@ -263,6 +270,7 @@ mod unicorn {
     pub mod number_literal_case;
     pub mod numeric_separators_style;
     pub mod prefer_add_event_listener;
+    pub mod prefer_array_find;
     pub mod prefer_array_flat;
     pub mod prefer_array_flat_map;
     pub mod prefer_array_some;
@@ -323,6 +331,7 @@ mod jsx_a11y {
     pub mod no_autofocus;
     pub mod no_distracting_elements;
     pub mod no_redundant_roles;
+    pub mod no_static_element_interactions;
     pub mod prefer_tag_over_role;
     pub mod role_has_required_aria_props;
     pub mod role_supports_aria_props;
@@ -346,6 +355,10 @@ mod oxc {
     pub mod missing_throw;
     pub mod no_accumulating_spread;
     pub mod no_barrel_file;
+    pub mod no_hardcoded_aws_key;
+    pub mod no_hardcoded_jwt;
+    pub mod no_hardcoded_private_key;
+    pub mod no_hardcoded_secret;
     pub mod number_arg_out_of_range;
     pub mod only_used_in_recursion;
     pub mod uninvoked_array_callback;
@@ -391,12 +404,20 @@ mod jsdoc {
     pub mod require_yields;
 }
 
+/// <https://github.com/eslint-community/eslint-plugin-promise>
+mod promise {
+    pub mod no_nesting;
+    pub mod prefer_await_to_then;
+}
+
 mod tree_shaking {
     pub mod no_side_effects_in_initialization;
 }
 
 oxc_macros::declare_all_lint_rules! {
+    eslint::accessor_pairs,
     eslint::array_callback_return,
+    eslint::consistent_return,
     eslint::constructor_super,
     eslint::default_case,
     eslint::default_case_last,
@@ -464,6 +485,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_shadow_restricted_names,
     eslint::no_sparse_arrays,
     eslint::no_undef,
+    eslint::no_unreachable,
     eslint::no_unsafe_finally,
     eslint::no_unsafe_negation,
     eslint::no_unsafe_optional_chaining,
@@ -485,6 +507,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_await_in_loop,
     eslint::no_new_native_nonconstructor,
     eslint::no_restricted_globals,
+    eslint::no_return_await,
     eslint::prefer_exponentiation_operator,
     typescript::adjacent_overload_signatures,
     typescript::array_type,
@@ -498,6 +521,7 @@ oxc_macros::declare_all_lint_rules! {
     typescript::no_empty_interface,
     typescript::no_explicit_any,
     typescript::no_extra_non_null_assertion,
+    typescript::no_floating_promises,
     typescript::no_misused_new,
     typescript::no_namespace,
     typescript::no_non_null_asserted_optional_chain,
@@ -561,6 +585,7 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::no_abusive_eslint_disable,
     unicorn::no_anonymous_default_export,
     unicorn::no_array_for_each,
+    unicorn::no_array_method_this_argument,
     unicorn::no_array_reduce,
     unicorn::no_await_expression_member,
     unicorn::no_await_in_promise_methods,
@@ -596,6 +621,7 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::number_literal_case,
     unicorn::numeric_separators_style,
     unicorn::prefer_add_event_listener,
+    unicorn::prefer_array_find,
     unicorn::prefer_array_flat_map,
     unicorn::prefer_array_flat,
     unicorn::prefer_array_some,
@@ -670,6 +696,7 @@ oxc_macros::declare_all_lint_rules! {
     // import::no_unused_modules,
     import::no_duplicates,
     import::no_default_export,
+    import::order,
     jsx_a11y::alt_text,
     jsx_a11y::anchor_has_content,
     jsx_a11y::anchor_is_valid,
@@ -688,6 +715,7 @@ oxc_macros::declare_all_lint_rules! {
     jsx_a11y::no_aria_hidden_on_focusable,
     jsx_a11y::no_autofocus,
     jsx_a11y::no_redundant_roles,
+    jsx_a11y::no_static_element_interactions,
     jsx_a11y::prefer_tag_over_role,
     jsx_a11y::role_has_required_aria_props,
     jsx_a11y::scope,
@@ -711,6 +739,10 @@ oxc_macros::declare_all_lint_rules! {
     oxc::missing_throw,
     oxc::no_accumulating_spread,
     oxc::no_barrel_file,
+    oxc::no_hardcoded_aws_key,
+    oxc::no_hardcoded_jwt,
+    oxc::no_hardcoded_private_key,
+    oxc::no_hardcoded_secret,
     oxc::number_arg_out_of_range,
     oxc::only_used_in_recursion,
     oxc::uninvoked_array_callback,
@@ -747,5 +779,7 @@ oxc_macros::declare_all_lint_rules! {
     jsdoc::require_returns,
     jsdoc::require_returns_description,
     jsdoc::require_yields,
+    promise::no_nesting,
+    promise::prefer_await_to_then,
     tree_shaking::no_side_effects_in_initialization,
 }
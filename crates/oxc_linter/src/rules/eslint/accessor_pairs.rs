@@ -0,0 +1,198 @@
+use oxc_ast::{
+    ast::{ClassElement, MethodDefinitionKind, PropertyKey},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{property_key_name, ObjectShape};
+use oxc_span::{CompactStr, GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn missing_getter_diagnostic(owner: &str, name: &str, span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint(accessor-pairs): Getter is not present for {owner}setter '{name}'."
+    ))
+    .with_help("Add a getter, the property reads as `undefined` without one")
+    .with_labels([span0.into()])
+}
+
+fn missing_setter_diagnostic(owner: &str, name: &str, span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint(accessor-pairs): Setter is not present for {owner}getter '{name}'."
+    ))
+    .with_help("Add a setter, assignments to the property are ignored or throw without one")
+    .with_labels([span0.into()])
+}
+
+#[derive(Debug, Clone)]
+pub struct AccessorPairs {
+    set_without_get: bool,
+    get_without_set: bool,
+    enforce_for_class_members: bool,
+}
+
+impl Default for AccessorPairs {
+    fn default() -> Self {
+        Self { set_without_get: true, get_without_set: false, enforce_for_class_members: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce getter and setter pairs in objects and classes
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A property with a setter but no getter can be written but always reads as `undefined`,
+    /// which is most likely a mistake.
+    ///
+    /// Accessors with computed keys which are not literals are not checked.
+    ///
+    /// ### Options
+    ///
+    /// * `setWithoutGet`: report setters without a getter, default `true`
+    /// * `getWithoutSet`: report getters without a setter, default `false`
+    /// * `enforceForClassMembers`: also check the accessors of classes, default `true`
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var o = {
+    ///     set a(value) {
+    ///         this.val = value;
+    ///     }
+    /// };
+    /// ```
+    AccessorPairs,
+    pedantic
+);
+
+impl Rule for AccessorPairs {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let option = |name: &str, default: bool| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(default)
+        };
+        let default = Self::default();
+        Self {
+            set_without_get: option("setWithoutGet", default.set_without_get),
+            get_without_set: option("getWithoutSet", default.get_without_set),
+            enforce_for_class_members: option(
+                "enforceForClassMembers",
+                default.enforce_for_class_members,
+            ),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ObjectExpression(obj_expr) => {
+                let shape = ObjectShape::new(obj_expr);
+                let properties = shape.properties();
+                for pair in shape.accessor_pairs() {
+                    let getter = pair.getter.map(|i| properties[i].span);
+                    let setter = pair.setter.map(|i| properties[i].span);
+                    self.check("", &pair.name, getter, setter, ctx);
+                }
+            }
+            AstKind::ClassBody(body) if self.enforce_for_class_members => {
+                // The spans of the first getter and setter of each static or instance name.
+                let mut accessors =
+                    FxHashMap::<(bool, CompactStr), (Option<Span>, Option<Span>)>::default();
+                let mut names = vec![];
+                for element in &body.body {
+                    let ClassElement::MethodDefinition(def) = element else { continue };
+                    let name = match &def.key {
+                        PropertyKey::PrivateIdentifier(ident) => format!("#{}", ident.name).into(),
+                        key => match property_key_name(key) {
+                            Some(name) => name,
+                            None => continue,
+                        },
+                    };
+                    let key = (def.r#static, name);
+                    let (getter, setter) = accessors.entry(key.clone()).or_insert_with(|| {
+                        names.push(key);
+                        (None, None)
+                    });
+                    match def.kind {
+                        MethodDefinitionKind::Get => *getter = getter.or(Some(def.key.span())),
+                        MethodDefinitionKind::Set => *setter = setter.or(Some(def.key.span())),
+                        MethodDefinitionKind::Method | MethodDefinitionKind::Constructor => {}
+                    }
+                }
+                for key in names {
+                    let (getter, setter) = accessors[&key];
+                    let owner = if key.0 { "class static " } else { "class " };
+                    self.check(owner, &key.1, getter, setter, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl AccessorPairs {
+    fn check(
+        &self,
+        owner: &str,
+        name: &str,
+        getter: Option<Span>,
+        setter: Option<Span>,
+        ctx: &LintContext,
+    ) {
+        match (getter, setter) {
+            (None, Some(setter)) if self.set_without_get => {
+                ctx.diagnostic(missing_getter_diagnostic(owner, name, setter));
+            }
+            (Some(getter), None) if self.get_without_set => {
+                ctx.diagnostic(missing_setter_diagnostic(owner, name, getter));
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var o = { a: 1 };", None),
+        ("var o = { get a() {} };", None),
+        ("var o = { get a() {}, set a(v) {} };", None),
+        ("var o = { set a(v) {}, b: 1, get a() {} };", None),
+        ("var o = { set 1(v) {}, get '1'() {} };", None),
+        ("var o = { set [`a`](v) {}, get a() {} };", None),
+        ("var o = { set [a](v) {} };", None),
+        ("var o = { get a() {}, b() {} };", Some(serde_json::json!([{ "setWithoutGet": false }]))),
+        ("var o = { set a(v) {} };", Some(serde_json::json!([{ "setWithoutGet": false }]))),
+        ("class A { get a() {} set a(v) {} }", None),
+        ("class A { static set a(v) {} static get a() {} }", None),
+        ("class A { set #a(v) {} get #a() {} }", None),
+        ("class A { set a(v) {} }", Some(serde_json::json!([{ "enforceForClassMembers": false }]))),
+        ("(class { set [a](v) {} })", None),
+    ];
+
+    let fail = vec![
+        ("var o = { set a(v) {} };", None),
+        ("var o = { set a(v) {}, get b() {} };", None),
+        ("var o = { get a() {}, a: 1, set a(v) {} };", None),
+        ("var o = { set 1(v) {}, get 2() {} };", None),
+        ("var o = { get a() {} };", Some(serde_json::json!([{ "getWithoutSet": true }]))),
+        (
+            "var o = { get a() {}, set b(v) {} };",
+            Some(serde_json::json!([{ "getWithoutSet": true, "setWithoutGet": true }])),
+        ),
+        ("class A { set a(v) {} }", None),
+        ("class A { static set a(v) {} get a() {} }", None),
+        ("class A { set #a(v) {} }", None),
+        ("(class { get a() {} })", Some(serde_json::json!([{ "getWithoutSet": true }]))),
+    ];
+
+    Tester::new(AccessorPairs::NAME, pass, fail).test_and_snapshot();
+}
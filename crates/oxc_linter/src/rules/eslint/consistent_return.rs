@@ -0,0 +1,299 @@
+use oxc_ast::{
+    ast::{Expression, MethodDefinitionKind, PropertyKind},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn missing_return_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint(consistent-return): Expected to return a value at the end of {x0}"
+    ))
+    .with_help("Return a value on every code path, the function returns a value on other paths")
+    .with_labels([span1.into()])
+}
+
+fn missing_return_value_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("eslint(consistent-return): {x0} expected a return value"))
+        .with_help("Return a value, as an earlier `return` in the function does")
+        .with_labels([span1.into()])
+}
+
+fn unexpected_return_value_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("eslint(consistent-return): {x0} expected no return value"))
+        .with_help("Return no value, as an earlier `return` in the function does")
+        .with_labels([span1.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ConsistentReturn {
+    treat_undefined_as_unspecified: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `return` statements to either always or never specify values
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A function which returns a value on some code paths and `undefined` on others,
+    /// with a bare `return` or by reaching the end of its body, is most likely a mistake.
+    ///
+    /// Class constructors and functions whose name starts with an uppercase letter are not
+    /// required to return a value at their end, as they are called with `new`.
+    ///
+    /// ### Options
+    ///
+    /// `treatUndefinedAsUnspecified`: `return undefined` and `return void 0` count as
+    /// returning no value, `false` by default.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function doSomething(condition) {
+    ///     if (condition) {
+    ///         return true;
+    ///     } else {
+    ///         return;
+    ///     }
+    /// }
+    ///
+    /// function doSomethingElse(condition) {
+    ///     if (condition) {
+    ///         return true;
+    ///     }
+    /// }
+    /// ```
+    ConsistentReturn,
+    nursery
+);
+
+impl Rule for ConsistentReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            treat_undefined_as_unspecified: value
+                .get(0)
+                .and_then(|config| config.get("treatUndefinedAsUnspecified"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let nodes = ctx.nodes();
+
+        // Whether the first `return` of each function returns a value.
+        let mut returns_value = FxHashMap::<AstNodeId, bool>::default();
+        for node in nodes.iter() {
+            let AstKind::ReturnStatement(stmt) = node.kind() else {
+                continue;
+            };
+            let Some(function_id) =
+                nodes.ancestors(node.id()).skip(1).find(|id| nodes.kind(*id).is_function_like())
+            else {
+                continue;
+            };
+            let has_value = stmt.argument.as_ref().is_some_and(|argument| {
+                !(self.treat_undefined_as_unspecified && is_undefined(argument))
+            });
+            let first_has_value = *returns_value.entry(function_id).or_insert(has_value);
+            if has_value != first_has_value {
+                let name = upper_case_first(&function_name(nodes.get_node(function_id), ctx));
+                ctx.diagnostic(if has_value {
+                    unexpected_return_value_diagnostic(&name, stmt.span)
+                } else {
+                    missing_return_value_diagnostic(&name, stmt.span)
+                });
+            }
+        }
+
+        let cfg = ctx.semantic().cfg();
+        for node in nodes.iter() {
+            if returns_value.get(&node.id()) != Some(&true) || is_constructor(node, ctx) {
+                continue;
+            }
+            if cfg.is_function_end_reachable(node.id(), node.cfg_id()) {
+                ctx.diagnostic(missing_return_diagnostic(
+                    &function_name(node, ctx),
+                    function_head(node, ctx),
+                ));
+            }
+        }
+    }
+}
+
+/// `undefined` or `void x`
+fn is_undefined(expr: &Expression) -> bool {
+    let expr = expr.without_parenthesized();
+    expr.is_undefined() || expr.is_void()
+}
+
+/// A class constructor, or a function named like `Foo` which is called with `new`.
+fn is_constructor(node: &AstNode, ctx: &LintContext) -> bool {
+    let AstKind::Function(func) = node.kind() else {
+        return false;
+    };
+    if let Some(AstKind::MethodDefinition(method)) = ctx.nodes().parent_kind(node.id()) {
+        return method.kind == MethodDefinitionKind::Constructor;
+    }
+    func.id.as_ref().is_some_and(|id| id.name.starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+/// The name of a function in the diagnostics, e.g. `method 'foo'`.
+fn function_name(node: &AstNode, ctx: &LintContext) -> String {
+    let func = match node.kind() {
+        AstKind::Function(func) => func,
+        AstKind::ArrowFunctionExpression(arrow) => {
+            return if arrow.r#async { "async arrow function" } else { "arrow function" }
+                .to_string();
+        }
+        _ => unreachable!(),
+    };
+    let (kind, key) = match ctx.nodes().parent_kind(node.id()) {
+        Some(AstKind::MethodDefinition(method)) => (
+            match method.kind {
+                MethodDefinitionKind::Constructor => "constructor",
+                MethodDefinitionKind::Method => "method",
+                MethodDefinitionKind::Get => "getter",
+                MethodDefinitionKind::Set => "setter",
+            },
+            method.key.static_name(),
+        ),
+        Some(AstKind::ObjectProperty(prop)) if prop.method || prop.kind != PropertyKind::Init => (
+            match prop.kind {
+                PropertyKind::Init => "method",
+                PropertyKind::Get => "getter",
+                PropertyKind::Set => "setter",
+            },
+            prop.key.static_name(),
+        ),
+        _ => ("function", func.id.as_ref().map(|id| id.name.to_compact_str())),
+    };
+    let mut name = String::new();
+    if func.r#async {
+        name.push_str("async ");
+    }
+    if func.generator {
+        name.push_str("generator ");
+    }
+    name.push_str(kind);
+    if kind != "constructor" {
+        if let Some(key) = key {
+            name = format!("{name} '{key}'");
+        }
+    }
+    name
+}
+
+/// The name of a function, or its key, or else its parameters.
+fn function_head(node: &AstNode, ctx: &LintContext) -> Span {
+    match node.kind() {
+        AstKind::Function(func) => match ctx.nodes().parent_kind(node.id()) {
+            Some(AstKind::MethodDefinition(method)) => method.key.span(),
+            Some(AstKind::ObjectProperty(prop))
+                if prop.method || prop.kind != PropertyKind::Init =>
+            {
+                prop.key.span()
+            }
+            _ => func.id.as_ref().map_or(func.params.span, |id| id.span),
+        },
+        AstKind::ArrowFunctionExpression(arrow) => arrow.params.span,
+        _ => unreachable!(),
+    }
+}
+
+fn upper_case_first(name: &str) -> String {
+    let mut chars = name.chars();
+    chars.next().map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo() { return; }", None),
+        ("function foo() { if (true) return; }", None),
+        ("function foo() { if (true) return; else return; }", None),
+        ("function foo() { if (true) return true; else return false; }", None),
+        ("f(function() { return; })", None),
+        ("f(function() { if (true) return; })", None),
+        ("f(function() { if (true) return; else return; })", None),
+        ("f(function() { if (true) return true; else return false; })", None),
+        ("function foo() { function bar() { return true; } return; }", None),
+        ("function foo() { function bar() { return; } return false; }", None),
+        ("function Foo() { if (!(this instanceof Foo)) return new Foo(); }", None),
+        (
+            "function foo() { if (true) return; else return undefined; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (true) return; else return void 0; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (true) return undefined; else return; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (true) return void 0; else return; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        ("var x = () => { return {}; };", None),
+        ("class Foo { constructor() { if (true) return foo; } }", None),
+        ("var Foo = class { constructor() { if (true) return foo; } }", None),
+        ("function foo() { if (a) { return 1; } else { return 2; } }", None),
+        ("function foo() { while (true) { if (a) return 1; } }", None),
+        ("function foo() { for (;;) { if (a) return 1; } }", None),
+        ("function foo() { if (a) return 1; throw new Error(); }", None),
+        ("function foo() { switch (a) { case 1: return 1; default: return 2; } }", None),
+        ("function foo() { try { return 1; } catch { return 2; } }", None),
+        ("function foo() { try { return 1; } finally { bar(); } }", None),
+        ("function foo() { if (a) return 1; else throw a; }", None),
+        ("const foo = (a) => a ? 1 : 2;", None),
+    ];
+
+    let fail = vec![
+        ("function foo() { if (true) return true; else return; }", None),
+        ("var foo = () => { if (true) return true; else return; }", None),
+        ("function foo() { if (true) return; else return false; }", None),
+        ("f(function() { if (true) return true; else return; })", None),
+        ("f(function() { if (true) return; else return false; })", None),
+        ("f(a => { if (true) return; else return false; })", None),
+        (
+            "function foo() { if (true) return true; return undefined; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (true) return true; return void 0; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (true) return undefined; return true; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        (
+            "function foo() { if (true) return void 0; return true; }",
+            Some(serde_json::json!([{ "treatUndefinedAsUnspecified": true }])),
+        ),
+        ("function foo() { if (a) return true; }", None),
+        ("f(function foo() { if (a) return true; });", None),
+        ("f(function () { if (a) return true; });", None),
+        ("f(() => { if (a) return true; });", None),
+        ("var obj = { foo() { if (a) return true; } };", None),
+        ("class A { foo() { if (a) return true; } };", None),
+        ("class A { get foo() { if (a) return true; } };", None),
+        ("function foo() { while (a) { return 1; } }", None),
+        ("function foo() { switch (a) { case 1: return 1; } }", None),
+        ("function foo() { for (;;) { if (a) break; return 1; } }", None),
+        ("async function foo() { if (a) return 1; }", None),
+        ("function* foo() { if (a) return 1; }", None),
+    ];
+
+    Tester::new(ConsistentReturn::NAME, pass, fail).test_and_snapshot();
+}
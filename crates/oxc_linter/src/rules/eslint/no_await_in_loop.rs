@@ -62,6 +62,11 @@ impl Rule for NoAwaitInLoop {
             _ => return,
         };
 
+        // Most `await`s are not in a loop, skip walking up their ancestors.
+        if !node.flags().has_loop() {
+            return;
+        }
+
         let nodes = ctx.semantic().nodes();
         // Perform validation for AwaitExpression and ForOfStatement that contains await
         let mut parent_node = nodes.parent_node(node.id());
@@ -1,7 +1,8 @@
-use oxc_ast::AstKind;
+use oxc_ast::{ast::Expression, AstKind};
 use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
 use oxc_span::{GetSpan, Span};
 
 use crate::{ast_util::IsConstant, context::LintContext, rule::Rule, AstNode};
@@ -14,7 +15,17 @@ fn no_constant_condition_diagnostic(span0: Span) -> OxcDiagnostic {
 
 #[derive(Debug, Default, Clone)]
 pub struct NoConstantCondition {
-    _check_loops: bool,
+    check_loops: CheckLoops,
+}
+
+/// Which loops have their test checked, see the `checkLoops` option.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum CheckLoops {
+    All,
+    #[default]
+    None,
+    /// All but the `while (true)` loops which can exit.
+    AllExceptWhileTrue,
 }
 
 declare_oxc_lint!(
@@ -26,6 +37,15 @@ declare_oxc_lint!(
     ///
     /// A constant expression (for example, a literal) as a test condition might be a typo or development trigger for a specific behavior.
     ///
+    /// ### Options
+    ///
+    /// `checkLoops` checks the tests of loops as well, except the loops in generators which
+    /// `yield` in their body:
+    /// * `true` or `"all"`: all loops.
+    /// * `"allExceptWhileTrue"`: all loops but `while (true)` loops with a reachable `break`,
+    ///   `return` or `throw`, so that only the infinite ones are reported.
+    /// * `false` or `"none"` (default): no loops.
+    ///
     /// ### Example
     ///
     /// ```javascript
@@ -42,10 +62,15 @@ impl Rule for NoConstantCondition {
         let obj = value.get(0);
 
         Self {
-            _check_loops: obj
-                .and_then(|v| v.get("checkLoops"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
+            check_loops: match obj.and_then(|v| v.get("checkLoops")) {
+                Some(serde_json::Value::Bool(true)) => CheckLoops::All,
+                Some(serde_json::Value::String(mode)) => match mode.as_str() {
+                    "all" => CheckLoops::All,
+                    "allExceptWhileTrue" => CheckLoops::AllExceptWhileTrue,
+                    _ => CheckLoops::None,
+                },
+                _ => CheckLoops::None,
+            },
         }
     }
 
@@ -61,11 +86,139 @@ impl Rule for NoConstantCondition {
                     ctx.diagnostic(no_constant_condition_diagnostic(condition_expr.test.span()));
                 }
             }
+            AstKind::WhileStatement(stmt) => self.check_loop(node, &stmt.test, ctx),
+            AstKind::DoWhileStatement(stmt) => self.check_loop(node, &stmt.test, ctx),
+            AstKind::ForStatement(stmt) => {
+                if let Some(test) = &stmt.test {
+                    self.check_loop(node, test, ctx);
+                }
+            }
             _ => {}
         }
     }
 }
 
+impl NoConstantCondition {
+    fn check_loop<'a>(&self, node: &AstNode<'a>, test: &Expression<'a>, ctx: &LintContext<'a>) {
+        if self.check_loops == CheckLoops::None
+            || !test.is_constant(true, ctx)
+            || yields_in_loop(node, ctx)
+        {
+            return;
+        }
+        if self.check_loops == CheckLoops::AllExceptWhileTrue
+            && matches!(node.kind(), AstKind::WhileStatement(_))
+            && matches!(test.without_parenthesized(), Expression::BooleanLiteral(lit) if lit.value)
+            && can_exit_loop(node, ctx)
+        {
+            return;
+        }
+        ctx.diagnostic(no_constant_condition_diagnostic(test.span()));
+    }
+}
+
+/// The function which `node_id` is in, not counting `node_id` itself.
+fn enclosing_function(node_id: AstNodeId, ctx: &LintContext) -> Option<AstNodeId> {
+    ctx.nodes().ancestors(node_id).skip(1).find(|id| ctx.nodes().kind(*id).is_function_like())
+}
+
+/// The spans which are run on each iteration after the test, i.e. the body and the update.
+fn loop_body_spans(node: &AstNode) -> Vec<Span> {
+    match node.kind() {
+        AstKind::WhileStatement(stmt) => vec![stmt.body.span()],
+        AstKind::DoWhileStatement(stmt) => vec![stmt.body.span()],
+        AstKind::ForStatement(stmt) => {
+            stmt.update.iter().map(GetSpan::span).chain([stmt.body.span()]).collect()
+        }
+        _ => vec![],
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// Whether the loop is in a generator and `yield`s on each iteration, e.g.
+/// `function* ids() { let id = 0; while (true) yield id++; }`.
+fn yields_in_loop(node: &AstNode, ctx: &LintContext) -> bool {
+    let function_id = enclosing_function(node.id(), ctx);
+    if !function_id
+        .is_some_and(|id| matches!(ctx.nodes().kind(id), AstKind::Function(func) if func.generator))
+    {
+        return false;
+    }
+    let spans = loop_body_spans(node);
+    ctx.nodes().iter().any(|yield_node| {
+        matches!(yield_node.kind(), AstKind::YieldExpression(_))
+            && spans.iter().any(|span| contains(*span, yield_node.kind().span()))
+            && enclosing_function(yield_node.id(), ctx) == function_id
+    })
+}
+
+/// Whether a `break`, `return` or `throw` which leaves the loop is reachable.
+fn can_exit_loop(node: &AstNode, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let function_id = enclosing_function(node.id(), ctx);
+    let spans = loop_body_spans(node);
+    let reachable_blocks = ctx.semantic().cfg().reachable_basic_blocks(
+        nodes
+            .iter()
+            .filter(|node| {
+                matches!(node.kind(), AstKind::Program(_)) || node.kind().is_function_like()
+            })
+            .map(AstNode::cfg_id),
+    );
+    nodes.iter().any(|exit| {
+        let exits = match exit.kind() {
+            AstKind::ReturnStatement(_) | AstKind::ThrowStatement(_) => true,
+            AstKind::BreakStatement(stmt) => match &stmt.label {
+                Some(label) => jumps_out_of_loop(exit.id(), node.id(), &label.name, false, ctx),
+                // The nearest loop or `switch` is this loop.
+                None => nodes
+                    .ancestors(exit.id())
+                    .skip(1)
+                    .find(|id| {
+                        let kind = nodes.kind(*id);
+                        kind.is_iteration_statement() || matches!(kind, AstKind::SwitchStatement(_))
+                    })
+                    .is_some_and(|id| id == node.id()),
+            },
+            AstKind::ContinueStatement(stmt) => stmt.label.as_ref().is_some_and(|label| {
+                jumps_out_of_loop(exit.id(), node.id(), &label.name, true, ctx)
+            }),
+            _ => false,
+        };
+        exits
+            && spans.iter().any(|span| contains(*span, exit.kind().span()))
+            && enclosing_function(exit.id(), ctx) == function_id
+            && reachable_blocks.contains(&exit.cfg_id())
+    })
+}
+
+/// Whether the statement labeled `label` encloses the loop, rather than being in its body.
+/// `continue` with the label of the loop itself starts its next iteration.
+fn jumps_out_of_loop(
+    jump_id: AstNodeId,
+    loop_id: AstNodeId,
+    label: &str,
+    is_continue: bool,
+    ctx: &LintContext,
+) -> bool {
+    let nodes = ctx.nodes();
+    let mut passed_loop = false;
+    for id in nodes.ancestors(jump_id).skip(1) {
+        if id == loop_id {
+            passed_loop = true;
+        } else if let AstKind::LabeledStatement(stmt) = nodes.kind(id) {
+            if stmt.label.name == label {
+                let is_loop_label = nodes.parent_id(loop_id) == Some(id);
+                return passed_loop && !(is_continue && is_loop_label);
+            }
+        }
+    }
+    false
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -197,30 +350,72 @@ fn test() {
         ("`foo${a}` === a ? 1 : 2", None),
         ("tag`a` === a ? 1 : 2", None),
         ("tag`${a}` === a ? 1 : 2", None),
-        //TODO
-        // ("while(~!a);", None),
-        // ("while(a = b);", None),
-        // ("while(`${a}`);", None),
-        // ("for(;x < 10;);", None),
-        // ("for(;;);", None),
-        // ("for(;`${a}`;);", None),
-        // ("do{ }while(x)", None),
-        // ("while(x += 3) {}", None),
-        // ("while(tag`a`) {}", None),
-        // ("while(tag`${a}`) {}", None),
-        // ("while(`\\\n${a}`) {}", None),
-        // ("while(true);", Some(json!([{"checkLoops":false}]))),
-        // ("for(;true;);", Some(json!([{"checkLoops":false}]))),
-        // ("do{}while(true)", Some(json!([{"checkLoops":false}]))),
-        // ("function* foo(){while(true){yield 'foo';}}", None),
-        // ("function* foo(){for(;true;){yield 'foo';}}", None),
-        // ("function* foo(){do{yield 'foo';}while(true)}", None),
-        // ("function* foo(){while (true) { while(true) {yield;}}}", None),
-        // ("function* foo() {for (; yield; ) {}}", None),
-        // ("function* foo() {for (; ; yield) {}}", None),
-        // ("function* foo() {while (true) {function* foo() {yield;}yield;}}", None),
-        // ("function* foo() { for (let x = yield; x < 10; x++) {yield;}yield;}", None),
-        // ("function* foo() { for (let x = yield; ; x++) { yield; }}", None),
+        ("while(~!a);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(a = b);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(`${a}`);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("for(;x < 10;);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("for(;;);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("for(;`${a}`;);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("do{ }while(x)", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(x += 3) {}", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(tag`a`) {}", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(tag`${a}`) {}", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(`\\\n${a}`) {}", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(true);", Some(serde_json::json!([{ "checkLoops": false }]))),
+        ("for(;true;);", Some(serde_json::json!([{ "checkLoops": false }]))),
+        ("do{}while(true)", Some(serde_json::json!([{ "checkLoops": false }]))),
+        (
+            "function* foo(){while(true){yield 'foo';}}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        (
+            "function* foo(){for(;true;){yield 'foo';}}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        (
+            "function* foo(){do{yield 'foo';}while(true)}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        (
+            "function* foo(){while (true) { while(true) {yield;}}}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        ("function* foo() {for (; yield; ) {}}", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("function* foo() {for (; ; yield) {}}", Some(serde_json::json!([{ "checkLoops": true }]))),
+        (
+            "function* foo() {while (true) {function* foo() {yield;}yield;}}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        (
+            "function* foo() { for (let x = yield; x < 10; x++) {yield;}yield;}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        (
+            "function* foo() { for (let x = yield; ; x++) { yield; }}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        ("while(true);", None),
+        ("while(true);", Some(serde_json::json!([{ "checkLoops": "none" }]))),
+        (
+            "while(true) { if (a) break; }",
+            Some(serde_json::json!([{ "checkLoops": "allExceptWhileTrue" }])),
+        ),
+        (
+            "function foo() { while (true) { if (a) return; } }",
+            Some(serde_json::json!([{ "checkLoops": "allExceptWhileTrue" }])),
+        ),
+        (
+            "while (true) { throw new Error(); }",
+            Some(serde_json::json!([{ "checkLoops": "allExceptWhileTrue" }])),
+        ),
+        (
+            "outer: while (true) { while (a) { break outer; } }",
+            Some(serde_json::json!([{ "checkLoops": "allExceptWhileTrue" }])),
+        ),
+        (
+            "outer: for (;;) { while (true) { continue outer; } }",
+            Some(serde_json::json!([{ "checkLoops": "allExceptWhileTrue" }])),
+        ),
     ];
 
     let fail = vec![
@@ -340,40 +535,92 @@ fn test() {
         ("`` ? 1 : 2;", None),
         ("`foo` ? 1 : 2;", None),
         ("`foo${bar}` ? 1 : 2;", None),
-        // TODO
-        // ("for(;true;);", None),
-        // ("for(;``;);", None),
-        // ("for(;`foo`;);", None),
-        // ("for(;`foo${bar}`;);", None),
-        // ("do{}while(true)", None),
-        // ("do{}while('1')", None),
-        // ("do{}while(0)", None),
-        // ("do{}while(t = -2)", None),
-        // ("do{}while(``)", None),
-        // ("do{}while(`foo`)", None),
-        // ("do{}while(`foo${bar}`)", None),
-        // ("while([]);", None),
-        // ("while(~!0);", None),
-        // ("while(x = 1);", None),
-        // ("while(function(){});", None),
-        // ("while(true);", None),
-        // ("while(1);", None),
-        // ("while(() => {});", None),
-        // ("while(`foo`);", None),
-        // ("while(``);", None),
-        // ("while(`${'foo'}`);", None),
-        // ("while(`${'foo' + 'bar'}`);", None),
-        // ("function* foo(){while(true){} yield 'foo';}", None),
-        // ("function* foo(){while(true){if (true) {yield 'foo';}}}", None),
-        // ("function* foo(){while(true){yield 'foo';} while(true) {}}", None),
-        // ("var a = function* foo(){while(true){} yield 'foo';}", None),
-        // ("while (true) { function* foo() {yield;}}", None),
-        // ("function* foo(){if (true) {yield 'foo';}}", None),
-        // ("function* foo() {for (let foo = yield; true;) {}}", None),
-        // ("function* foo() {for (foo = yield; true;) {}}", None),
-        // ("function foo() {while (true) {function* bar() {while (true) {yield;}}}}", None),
-        // ("function foo() {while (true) {const bar = function*() {while (true) {yield;}}}}", None),
-        // ("function* foo() { for (let foo = 1 + 2 + 3 + (yield); true; baz) {}}", None),
+        ("for(;true;);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("for(;``;);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("for(;`foo`;);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("for(;`foo${bar}`;);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("do{}while(true)", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("do{}while('1')", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("do{}while(0)", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("do{}while(t = -2)", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("do{}while(``)", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("do{}while(`foo`)", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("do{}while(`foo${bar}`)", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while([]);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(~!0);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(x = 1);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(function(){});", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(true);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(1);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(() => {});", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(`foo`);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(``);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(`${'foo'}`);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        ("while(`${'foo' + 'bar'}`);", Some(serde_json::json!([{ "checkLoops": true }]))),
+        (
+            "function* foo(){while(true){} yield 'foo';}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        (
+            "function* foo(){while(true){if (true) {yield 'foo';}}}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        (
+            "function* foo(){while(true){yield 'foo';} while(true) {}}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        (
+            "var a = function* foo(){while(true){} yield 'foo';}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        (
+            "while (true) { function* foo() {yield;}}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        (
+            "function* foo(){if (true) {yield 'foo';}}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        (
+            "function* foo() {for (let foo = yield; true;) {}}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        (
+            "function* foo() {for (foo = yield; true;) {}}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        (
+            "function foo() {while (true) {function* bar() {while (true) {yield;}}}}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        (
+            "function foo() {while (true) {const bar = function*() {while (true) {yield;}}}}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        (
+            "function* foo() { for (let foo = 1 + 2 + 3 + (yield); true; baz) {}}",
+            Some(serde_json::json!([{ "checkLoops": true }])),
+        ),
+        ("while(true) {}", Some(serde_json::json!([{ "checkLoops": "allExceptWhileTrue" }]))),
+        (
+            "while(true) { if (a) continue; }",
+            Some(serde_json::json!([{ "checkLoops": "allExceptWhileTrue" }])),
+        ),
+        (
+            "while(true) { switch (a) { case 1: break; } }",
+            Some(serde_json::json!([{ "checkLoops": "allExceptWhileTrue" }])),
+        ),
+        (
+            "while(true) { continue; break; }",
+            Some(serde_json::json!([{ "checkLoops": "allExceptWhileTrue" }])),
+        ),
+        (
+            "A: while(true) { continue A; }",
+            Some(serde_json::json!([{ "checkLoops": "allExceptWhileTrue" }])),
+        ),
+        ("while(1) { break; }", Some(serde_json::json!([{ "checkLoops": "allExceptWhileTrue" }]))),
+        ("for(;true;);", Some(serde_json::json!([{ "checkLoops": "allExceptWhileTrue" }]))),
+        ("do{}while(true)", Some(serde_json::json!([{ "checkLoops": "all" }]))),
     ];
 
     Tester::new(NoConstantCondition::NAME, pass, fail).test_and_snapshot();
@@ -35,7 +35,9 @@ declare_oxc_lint!(
 impl Rule for NoDebugger {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::DebuggerStatement(stmt) = node.kind() {
-            ctx.diagnostic_with_fix(no_debugger_diagnostic(stmt.span), || Fix::delete(stmt.span));
+            ctx.diagnostic_with_suggestion(no_debugger_diagnostic(stmt.span), || {
+                Fix::delete(stmt.span)
+            });
         }
     }
 }
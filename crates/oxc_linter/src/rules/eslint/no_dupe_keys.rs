@@ -1,12 +1,9 @@
-use oxc_ast::{
-    ast::{ObjectPropertyKind, PropertyKind},
-    AstKind,
-};
+use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
-use oxc_span::{GetSpan, Span};
-use rustc_hash::FxHashMap;
+use oxc_semantic::{Duplicate, ObjectShape};
+use oxc_span::Span;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -44,22 +41,13 @@ impl Rule for NoDupeKeys {
         let AstKind::ObjectExpression(obj_expr) = node.kind() else {
             return;
         };
-        let mut map = FxHashMap::default();
-        for prop in &obj_expr.properties {
-            let ObjectPropertyKind::ObjectProperty(prop) = prop else {
-                continue;
-            };
-            let Some(name) = prop.key.static_name() else {
-                return;
-            };
-            if let Some((prev_kind, prev_span)) = map.insert(name, (prop.kind, prop.key.span())) {
-                if prev_kind == PropertyKind::Init
-                    || prop.kind == PropertyKind::Init
-                    || prev_kind == prop.kind
-                {
-                    ctx.diagnostic(no_dupe_keys_diagnostic(prev_span, prop.key.span()));
-                }
-            }
+        let shape = ObjectShape::new(obj_expr);
+        let properties = shape.properties();
+        for Duplicate { earlier, later } in shape.duplicates() {
+            ctx.diagnostic(no_dupe_keys_diagnostic(
+                properties[earlier].span,
+                properties[later].span,
+            ));
         }
     }
 }
@@ -86,8 +74,8 @@ fn test() {
         // Syntax:error: the '0' prefixed octal literals is not allowed.
         // ("var x = { 012: 1, 12: 2 };", None),
         ("var x = { 1_0: 1, 1: 2 };", None),
-        // NOTE: This should fail when we get read the big int value
-        ("var x = { 1n: 1, 1: 2 };", None),
+        ("var x = { __proto__: null, ['__proto__']: null };", None),
+        ("var x = { 0x1n: 1, 1: 2 };", None),
     ];
 
     let fail = vec![
@@ -106,6 +94,10 @@ fn test() {
         ("var x = { a: 1, set a(value) {} };", None),
         ("var x = { a: 1, b: { a: 2 }, get b() {} };", None),
         ("var x = ({ '/(?<zero>0)/': 1, [/(?<zero>0)/]: 2 })", None),
+        ("var x = { 1n: 1, 1: 2 };", None),
+        ("var x = { [a]: 1, b: 1, b: 2 };", None),
+        ("var x = { a: 1, get a() {}, set a(v) {} };", None),
+        ("var x = { a: 1, ...b, a: 2 };", None),
     ];
 
     Tester::new(NoDupeKeys::NAME, pass, fail).test_and_snapshot();
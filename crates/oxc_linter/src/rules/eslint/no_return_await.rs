@@ -0,0 +1,189 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+fn no_return_await_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint(no-return-await): Redundant use of `await` on a return value.")
+        .with_help("Remove redundant `await`.")
+        .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoReturnAwait;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary `return await`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The value returned by an async function is awaited by its caller anyway, so awaiting it
+    /// before returning only delays the result.
+    ///
+    /// An `await` is not redundant inside a `try` block, or a `catch` block followed by `finally`,
+    /// because a rejection is then handled by this function instead of the caller. Such `await`s
+    /// are not reported.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// async function foo() {
+    ///   return await bar();
+    /// }
+    ///
+    /// // Good
+    /// async function foo() {
+    ///   return bar();
+    /// }
+    ///
+    /// async function foo() {
+    ///   try {
+    ///     return await bar();
+    ///   } catch (error) {}
+    /// }
+    /// ```
+    NoReturnAwait,
+    pedantic
+);
+
+impl Rule for NoReturnAwait {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AwaitExpression(await_expr) = node.kind() else {
+            return;
+        };
+        if !is_in_tail_call_position(node, ctx) || has_error_handler(node, ctx) {
+            return;
+        }
+
+        let start = await_expr.span.start;
+        let diagnostic = no_return_await_diagnostic(Span::new(start, start + 5));
+        let argument_span = await_expr.argument.span();
+        // Comments between `await` and its argument would be lost by the fix.
+        let between = Span::new(start + 5, argument_span.start);
+        if !between.source_text(ctx.source_text()).trim().is_empty() {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_suggestion(diagnostic, || {
+            Fix::new(argument_span.source_text(ctx.source_text()), await_expr.span)
+        });
+    }
+}
+
+/// Whether the value of `node` is returned, by a `return` statement or as the expression body of
+/// an arrow function.
+fn is_in_tail_call_position(node: &AstNode, ctx: &LintContext) -> bool {
+    let mut child_span = node.kind().span();
+    for parent in ctx.nodes().iter_parents(node.id()).skip(1) {
+        match parent.kind() {
+            AstKind::ReturnStatement(_) => return true,
+            AstKind::ExpressionStatement(_) => return is_arrow_expression_body(parent, ctx),
+            AstKind::ParenthesizedExpression(_) => {}
+            AstKind::ConditionalExpression(expr) if expr.test.span() != child_span => {}
+            AstKind::LogicalExpression(expr) if expr.right.span() == child_span => {}
+            AstKind::SequenceExpression(expr)
+                if expr.expressions.last().is_some_and(|last| last.span() == child_span) => {}
+            _ => return false,
+        }
+        child_span = parent.kind().span();
+    }
+    false
+}
+
+fn is_arrow_expression_body(stmt: &AstNode, ctx: &LintContext) -> bool {
+    let mut parents = ctx.nodes().iter_parents(stmt.id()).skip(1);
+    matches!(parents.next().map(AstNode::kind), Some(AstKind::FunctionBody(_)))
+        && matches!(
+            parents.next().map(AstNode::kind),
+            Some(AstKind::ArrowFunctionExpression(arrow)) if arrow.expression
+        )
+}
+
+/// Whether a rejection of `node` is handled by a `try` statement in the same function.
+fn has_error_handler(node: &AstNode, ctx: &LintContext) -> bool {
+    let mut child = node;
+    for parent in ctx.nodes().iter_parents(node.id()).skip(1) {
+        match parent.kind() {
+            AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) => return false,
+            AstKind::TryStatement(stmt) => {
+                if stmt.block.span == child.kind().span()
+                    || (matches!(child.kind(), AstKind::CatchClause(_)) && stmt.finalizer.is_some())
+                {
+                    return true;
+                }
+            }
+            _ => {}
+        }
+        child = parent;
+    }
+    false
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async function foo() { await bar(); return; }",
+        "async function foo() { const x = await bar(); return x; }",
+        "async () => { return bar(); }",
+        "async () => bar()",
+        "async function foo() { try { return await bar(); } catch (e) { baz(); } }",
+        "async function foo() { try { return await bar(); } finally { baz(); } }",
+        "async function foo() { try {} catch (e) { return await bar(); } finally { baz(); } }",
+        "async function foo() { try { try {} finally { return await bar(); } } finally { baz(); } }",
+        "async function foo() { return (await bar()) + 1; }",
+        "async () => (await bar()).baz",
+        "async function foo() { return await bar() ? baz : qux; }",
+        "async function foo() { return (await bar(), baz); }",
+        "async function foo() { return await bar() && baz; }",
+        "async () => { await bar(); }",
+        "async function foo() { try { return (async () => 1)(); } catch {} }",
+    ];
+
+    let fail = vec![
+        "async function foo() { return await bar(); }",
+        "async function foo() { return await(bar()); }",
+        "async function foo() { if (a) { return await bar(); } else { return await baz(); } }",
+        "async () => { return await bar(); }",
+        "async () => await bar()",
+        "async function foo() { return a ? await bar() : await baz(); }",
+        "async function foo() { return (a, await bar()); }",
+        "async function foo() { return a || await bar(); }",
+        "async function foo() { try {} catch (e) { return await bar(); } }",
+        "async function foo() { try {} finally { return await bar(); } }",
+        "async function foo() { try { (async () => { return await bar(); })(); } catch {} }",
+        "async function foo() { return await /* comment */ bar(); }",
+    ];
+
+    let fix = vec![
+        (
+            "async function foo() { return await bar(); }",
+            "async function foo() { return bar(); }",
+            None,
+        ),
+        ("async () => await bar()", "async () => bar()", None),
+        (
+            "async function foo() { return await(bar()); }",
+            "async function foo() { return (bar()); }",
+            None,
+        ),
+        (
+            "async function foo() { return a ? await bar() : await baz(); }",
+            "async function foo() { return a ? bar() : baz(); }",
+            None,
+        ),
+        (
+            "async function foo() { return await /* comment */ bar(); }",
+            "async function foo() { return await /* comment */ bar(); }",
+            None,
+        ),
+    ];
+
+    Tester::new(NoReturnAwait::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
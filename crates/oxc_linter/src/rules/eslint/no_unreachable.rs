@@ -0,0 +1,216 @@
+use oxc_ast::{
+    ast::{VariableDeclaration, VariableDeclarationKind},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashSet;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_unreachable_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint(no-unreachable): Unreachable code")
+        .with_help("This code is never executed, as it follows a return, throw, break, continue or infinite loop")
+        .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnreachable;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unreachable code after `return`, `throw`, `continue`, and `break` statements,
+    /// and after infinite loops such as `while (true)` without a `break`
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The code after a statement which always leaves the code path is never executed,
+    /// which is most likely a mistake.
+    ///
+    /// Function declarations and `var` declarations without an initializer are hoisted,
+    /// so they are allowed after such a statement.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function foo() {
+    ///     return true;
+    ///     console.log("done");
+    /// }
+    ///
+    /// while (true) {
+    ///     poll();
+    /// }
+    /// cleanup();
+    /// ```
+    NoUnreachable,
+    correctness
+);
+
+impl Rule for NoUnreachable {
+    fn run_once(&self, ctx: &LintContext) {
+        let nodes = ctx.nodes();
+        let cfg = ctx.semantic().cfg();
+
+        // The program and the body of every function are entered.
+        let reachable_blocks = cfg.reachable_basic_blocks(
+            nodes
+                .iter()
+                .filter(|node| {
+                    matches!(
+                        node.kind(),
+                        AstKind::Program(_)
+                            | AstKind::Function(_)
+                            | AstKind::ArrowFunctionExpression(_)
+                    )
+                })
+                .map(AstNode::cfg_id),
+        );
+
+        // A finally clause is visited once for the try block throwing and once for it
+        // completing, so a statement is reachable when any of its copies is.
+        let reachable_statements = nodes
+            .iter()
+            .filter(|node| is_checked_statement(node) && reachable_blocks.contains(&node.cfg_id()))
+            .map(|node| node.kind().span())
+            .collect::<FxHashSet<_>>();
+
+        // The consecutive unreachable statements in a statement list are reported together.
+        let mut unreachable_spans = Vec::<Span>::new();
+        let mut run: Option<(Option<AstNodeId>, Span)> = None;
+        for node in nodes.iter() {
+            if !is_checked_statement(node) {
+                continue;
+            }
+            let span = node.kind().span();
+            if run.is_some_and(|(_, run_span)| {
+                run_span.start <= span.start && span.end <= run_span.end
+            }) {
+                continue;
+            }
+            if reachable_statements.contains(&span) {
+                unreachable_spans.extend(run.take().map(|(_, run_span)| run_span));
+                continue;
+            }
+            let parent_id = nodes.parent_id(node.id());
+            match &mut run {
+                Some((run_parent_id, run_span)) if *run_parent_id == parent_id => {
+                    run_span.end = span.end;
+                }
+                _ => {
+                    unreachable_spans.extend(run.replace((parent_id, span)).map(|(_, span)| span));
+                }
+            }
+        }
+        unreachable_spans.extend(run.map(|(_, run_span)| run_span));
+
+        // The copies of a finally clause are reported once.
+        let mut reported = FxHashSet::default();
+        for span in unreachable_spans {
+            if reported.insert(span) {
+                ctx.diagnostic(no_unreachable_diagnostic(span));
+            }
+        }
+    }
+}
+
+/// The statements which are reported when unreachable,
+/// which are not empty statements or the hoisted function and `var` declarations.
+fn is_checked_statement(node: &AstNode) -> bool {
+    match node.kind() {
+        AstKind::VariableDeclaration(decl) => !is_hoisted_var_declaration(decl),
+        AstKind::Class(class) => class.is_declaration(),
+        AstKind::EmptyStatement(_) => false,
+        kind => kind.is_statement(),
+    }
+}
+
+/// `var x;`
+fn is_hoisted_var_declaration(decl: &VariableDeclaration) -> bool {
+    decl.kind == VariableDeclarationKind::Var
+        && decl.declarations.iter().all(|declarator| declarator.init.is_none())
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo() { function bar() { return 1; } return bar(); }", None),
+        ("function foo() { return bar(); function bar() { return 1; } }", None),
+        ("function foo() { return x; var x; }", None),
+        ("foo(); function foo() { return 1; }", None),
+        ("function foo() { var x = 1; var y = 2; }", None),
+        ("function foo() { var x = 1; var y = 2; return; }", None),
+        ("while (true) { switch (foo) { case 1: x = 1; x = 2;} }", None),
+        ("while (true) { break; var x; }", None),
+        ("while (true) { continue; var x, y; }", None),
+        ("while (true) { throw 'message'; var x; }", None),
+        ("while (true) { if (true) break; var x = 1; }", None),
+        ("while (true) continue;", None),
+        ("switch (foo) { case 1: break; var x; }", None),
+        ("switch (foo) { case 1: break; var x; default: throw true; };", None),
+        (
+            "const arrow_direction = arrow => {  switch (arrow) { default: throw new Error();  };}",
+            None,
+        ),
+        ("var x = 1; y = 2; throw 'uh oh'; var y;", None),
+        ("function foo() { var x = 1; if (x) { return; } x = 2; }", None),
+        ("function foo() { var x = 1; if (x) { } else { return; } x = 2; }", None),
+        (
+            "function foo() { var x = 1; switch (x) { case 0: break; default: return; } x = 2; }",
+            None,
+        ),
+        ("function foo() { var x = 1; while (x) { return; } x = 2; }", None),
+        ("function foo() { var x = 1; for (x in {}) { return; } x = 2; }", None),
+        ("function foo() { var x = 1; try { return; } finally { x = 2; } }", None),
+        ("function foo() { var x = 1; for (;;) { if (x) break; } x = 2; }", None),
+        ("A: { break A; } foo()", None),
+        ("function* foo() { try { yield 1; return; } catch (err) { return err; } }", None),
+        ("function foo() { try { bar(); return; } catch (err) { return err; } }", None),
+        ("function foo() { try { a.b.c = 1; return; } catch (err) { return err; } }", None),
+        ("function foo() { while (true) { if (x) { break; } } bar(); }", None),
+        ("function foo() { while (true) { switch (x) { case 1: return; } } }", None),
+        ("function foo() { do { if (x) break; } while (true); bar(); }", None),
+        ("function foo() { outer: for (;;) { for (;;) { break outer; } } bar(); }", None),
+        ("function foo() { switch (x) { case 1: return 1; case 2: break; } bar(); }", None),
+        ("function foo() { try { throw x; } catch { bar(); } baz(); }", None),
+        ("function foo() { return; } bar();", None),
+    ];
+
+    let fail = vec![
+        ("function foo() { return x; var x = 1; }", None),
+        ("function foo() { return x; var x, y = 1; }", None),
+        ("while (true) { continue; var x = 1; }", None),
+        ("function foo() { return; x = 1; }", None),
+        ("function foo() { throw error; x = 1; }", None),
+        ("while (true) { break; x = 1; }", None),
+        ("while (true) { continue; x = 1; }", None),
+        ("function foo() { switch (foo) { case 1: return; x = 1; } }", None),
+        ("function foo() { switch (foo) { case 1: throw e; x = 1; } }", None),
+        ("while (true) { switch (foo) { case 1: break; x = 1; } }", None),
+        ("while (true) { switch (foo) { case 1: continue; x = 1; } }", None),
+        ("var x = 1; throw 'uh oh'; var y = 2;", None),
+        ("function foo() { var x = 1; if (x) { return; } else { throw e; } x = 2; }", None),
+        ("function foo() { var x = 1; if (x) return; else throw -1; x = 2; }", None),
+        ("function foo() { var x = 1; try { return; } finally {} x = 2; }", None),
+        ("function foo() { var x = 1; try { } finally { return; } x = 2; }", None),
+        ("function foo() { var x = 1; do { return; } while (x); x = 2; }", None),
+        ("function foo() { var x = 1; while (x) { if (x) break; else continue; x = 2; } }", None),
+        ("function foo() { var x = 1; for (;;) { if (x) continue; } x = 2; }", None),
+        ("function foo() { var x = 1; while (true) { } x = 2; }", None),
+        ("function foo() { var x = 1; do { } while (true); x = 2; }", None),
+        ("function foo() { return; a(); b(); c(); }", None),
+        ("function foo() { return; a(); if (b()) { c(); } else { d(); } }", None),
+        ("function foo() { return; class Bar {} }", None),
+        ("function foo() { return; let x; }", None),
+        ("function foo() { while (true) { switch (x) { case 1: break; } } bar(); }", None),
+        ("A: { foo(); break A; bar(); }", None),
+        ("for (;;) {} foo();", None),
+        ("function foo() { for (;;) { return; } bar(); }", None),
+    ];
+
+    Tester::new(NoUnreachable::NAME, pass, fail).test_and_snapshot();
+}
@@ -96,7 +96,7 @@ impl NoUnsafeNegation {
             Fix::new(modified_code, expr.span)
         };
 
-        ctx.diagnostic_with_fix(diagnostic, fix_producer);
+        ctx.diagnostic_with_suggestion(diagnostic, fix_producer);
     }
 }
 
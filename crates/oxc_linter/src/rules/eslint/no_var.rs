@@ -88,7 +88,7 @@ fn test() {
         ("{ var foo = 1 }", None),
         ("if (true) { var foo = 1 }", None),
         ("var foo = 1", None),
-        ("declare var foo = 2;", None),
+        ("declare var foo: number;", None),
         ("function foo() { var let; }", None),
         ("function foo() { var { let } = {}; }", None),
         (
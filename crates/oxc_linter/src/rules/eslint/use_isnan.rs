@@ -90,12 +90,12 @@ impl Rule for UseIsnan {
             }
             AstKind::BinaryExpression(expr) if expr.operator.is_equality() => {
                 if is_nan_identifier(&expr.left) {
-                    ctx.diagnostic_with_fix(comparison_with_na_n(expr.left.span()), || {
+                    ctx.diagnostic_with_suggestion(comparison_with_na_n(expr.left.span()), || {
                         Fix::new(make_equality_fix(true, expr, ctx), expr.span)
                     });
                 }
                 if is_nan_identifier(&expr.right) {
-                    ctx.diagnostic_with_fix(comparison_with_na_n(expr.right.span()), || {
+                    ctx.diagnostic_with_suggestion(comparison_with_na_n(expr.right.span()), || {
                         Fix::new(make_equality_fix(false, expr, ctx), expr.span)
                     });
                 }
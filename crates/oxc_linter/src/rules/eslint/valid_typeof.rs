@@ -98,7 +98,7 @@ impl Rule for ValidTypeof {
 
         if let Expression::Identifier(ident) = sibling {
             if ident.name == "undefined" && ctx.semantic().is_reference_to_global_variable(ident) {
-                ctx.diagnostic_with_fix(
+                ctx.diagnostic_with_suggestion(
                     if self.require_string_literals {
                         not_string(
                             Some("Use `\"undefined\"` instead of `undefined`."),
@@ -0,0 +1,517 @@
+use std::cmp::Ordering;
+
+use oxc_ast::{
+    ast::{ImportDeclaration, Statement},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_resolver::NODEJS_BUILTINS;
+use oxc_span::Span;
+use serde_json::Value;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule};
+
+fn out_of_order_diagnostic(span0: Span, x1: &str, x2: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-import(order): `{x1}` import should occur before import of `{x2}`"
+    ))
+    .with_labels([span0.into()])
+}
+
+fn missing_newline_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "eslint-plugin-import(order): There should be at least one empty line between import groups",
+    )
+    .with_labels([span0.into()])
+}
+
+fn newline_in_group_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "eslint-plugin-import(order): There should be no empty line within import group",
+    )
+    .with_labels([span0.into()])
+}
+
+fn newline_between_groups_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "eslint-plugin-import(order): There should be no empty line between import groups",
+    )
+    .with_labels([span0.into()])
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/order.md>
+#[derive(Debug, Default, Clone)]
+pub struct Order(Box<OrderConfig>);
+
+#[derive(Debug, Clone)]
+pub struct OrderConfig {
+    /// Import kinds in the same group share a rank. Kinds which are not listed are ranked
+    /// together after the listed groups.
+    groups: Vec<Vec<ImportKind>>,
+    newlines_between: NewlinesBetween,
+    alphabetize: SortOrder,
+    case_insensitive: bool,
+}
+
+impl std::ops::Deref for Order {
+    type Target = OrderConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for OrderConfig {
+    fn default() -> Self {
+        Self {
+            groups: vec![
+                vec![ImportKind::Builtin],
+                vec![ImportKind::External],
+                vec![ImportKind::Parent],
+                vec![ImportKind::Sibling],
+                vec![ImportKind::Index],
+            ],
+            newlines_between: NewlinesBetween::Ignore,
+            alphabetize: SortOrder::Ignore,
+            case_insensitive: false,
+        }
+    }
+}
+
+/// The kind of module an import refers to, decided by the import path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportKind {
+    /// `/path/to/module`
+    Absolute,
+    /// `fs`, `node:fs`
+    Builtin,
+    /// `react`, `@babel/core`
+    External,
+    /// `@/module`, `~/module`, `#module`
+    Internal,
+    /// `../module`
+    Parent,
+    /// `./module`
+    Sibling,
+    /// `.`, `./index`
+    Index,
+    /// `import type`, when listed in `groups`
+    Type,
+    Unknown,
+}
+
+impl ImportKind {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "absolute" => Some(Self::Absolute),
+            "builtin" => Some(Self::Builtin),
+            "external" => Some(Self::External),
+            "internal" => Some(Self::Internal),
+            "parent" => Some(Self::Parent),
+            "sibling" => Some(Self::Sibling),
+            "index" => Some(Self::Index),
+            "type" => Some(Self::Type),
+            "unknown" => Some(Self::Unknown),
+            _ => None,
+        }
+    }
+
+    fn from_source(source: &str) -> Self {
+        if source.starts_with('/') {
+            return Self::Absolute;
+        }
+        if matches!(source, "." | "./" | "./index") || source.starts_with("./index.") {
+            return Self::Index;
+        }
+        if source == ".." || source.starts_with("../") {
+            return Self::Parent;
+        }
+        if source.starts_with("./") {
+            return Self::Sibling;
+        }
+        if source.starts_with("@/") || source.starts_with("~/") || source.starts_with('#') {
+            return Self::Internal;
+        }
+        if is_builtin(source) {
+            return Self::Builtin;
+        }
+        if source.starts_with(|c: char| c == '@' || c == '_' || c.is_ascii_alphanumeric()) {
+            return Self::External;
+        }
+        Self::Unknown
+    }
+}
+
+fn is_builtin(source: &str) -> bool {
+    if source.starts_with("node:") {
+        return true;
+    }
+    // `fs/promises`
+    let name = source.split_once('/').map_or(source, |(name, _)| name);
+    NODEJS_BUILTINS.binary_search(&name).is_ok()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NewlinesBetween {
+    Ignore,
+    Always,
+    AlwaysAndInsideGroups,
+    Never,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortOrder {
+    Ignore,
+    Asc,
+    Desc,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a convention in the order of `import` statements.
+    ///
+    /// Imports are ordered by the kind of module they import, configured by `groups`:
+    /// `builtin`, `external`, `internal`, `parent`, `sibling`, `index`, `absolute`, `type`
+    /// and `unknown`. The default order is
+    /// `["builtin", "external", "parent", "sibling", "index"]`, the kinds which are not listed
+    /// come last. Kinds in a nested array, like `[["parent", "sibling"]]`, share a group.
+    ///
+    /// Type imports are ordered by their path unless `type` is listed in `groups`.
+    ///
+    /// * `newlines-between`: `"ignore"` (default), `"always"`, `"always-and-inside-groups"`
+    ///   or `"never"`, whether groups are separated by an empty line.
+    /// * `alphabetize`: `{ "order": "ignore" | "asc" | "desc", "caseInsensitive": false }`,
+    ///   the order of the imports within a group.
+    ///
+    /// The fix reorders the imports with the comments attached to them. It is not applied when
+    /// there is other code or a detached comment between the imports.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A consistent order makes the dependencies of a module easier to read, and avoids
+    /// merge conflicts between imports added in different places.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // fail
+    /// import foo from './foo';
+    /// import fs from 'fs';
+    ///
+    /// // pass
+    /// import fs from 'fs';
+    /// import foo from './foo';
+    /// ```
+    Order,
+    style
+);
+
+impl Rule for Order {
+    fn from_configuration(value: Value) -> Self {
+        let Some(obj) = value.get(0) else { return Self::default() };
+        let default = OrderConfig::default();
+        let groups = obj.get("groups").and_then(Value::as_array).map_or(default.groups, |groups| {
+            groups
+                .iter()
+                .map(|group| match group {
+                    Value::Array(kinds) => kinds
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .filter_map(ImportKind::from_name)
+                        .collect(),
+                    group => group.as_str().and_then(ImportKind::from_name).into_iter().collect(),
+                })
+                .collect()
+        });
+        let newlines_between =
+            match obj.get("newlines-between").and_then(Value::as_str).unwrap_or_default() {
+                "always" => NewlinesBetween::Always,
+                "always-and-inside-groups" => NewlinesBetween::AlwaysAndInsideGroups,
+                "never" => NewlinesBetween::Never,
+                _ => NewlinesBetween::Ignore,
+            };
+        let alphabetize = obj.get("alphabetize");
+        let order = match alphabetize
+            .and_then(|v| v.get("order"))
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+        {
+            "asc" => SortOrder::Asc,
+            "desc" => SortOrder::Desc,
+            _ => SortOrder::Ignore,
+        };
+        let case_insensitive = alphabetize
+            .and_then(|v| v.get("caseInsensitive"))
+            .and_then(Value::as_bool)
+            .unwrap_or_default();
+        Self(Box::new(OrderConfig {
+            groups,
+            newlines_between,
+            alphabetize: order,
+            case_insensitive,
+        }))
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let Some(root) = ctx.nodes().root_node() else { return };
+        let AstKind::Program(program) = root.kind() else { unreachable!() };
+
+        let mut imports = vec![];
+        let mut is_contiguous = true;
+        let mut last_index = None;
+        for (index, stmt) in program.body.iter().enumerate() {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            if last_index.is_some_and(|last| last + 1 != index) {
+                is_contiguous = false;
+            }
+            last_index = Some(index);
+            imports.push(self.import_entry(decl));
+        }
+        if imports.len() < 2 {
+            return;
+        }
+
+        let mut sorted = (0..imports.len()).collect::<Vec<_>>();
+        sorted.sort_by(|a, b| self.compare(&imports[*a], &imports[*b]));
+
+        let source_text = ctx.source_text();
+        let fix = if is_contiguous { self.fix(ctx, &imports, &sorted) } else { None };
+        let report = |diagnostic: OxcDiagnostic| {
+            if let Some(fix) = &fix {
+                ctx.diagnostic_with_suggestion(diagnostic, || fix.clone());
+            } else {
+                ctx.diagnostic(diagnostic);
+            }
+        };
+
+        for (i, import) in imports.iter().enumerate() {
+            let first_after =
+                imports[..i].iter().find(|prev| self.compare(import, prev) == Ordering::Less);
+            if let Some(prev) = first_after {
+                report(out_of_order_diagnostic(import.span, import.source, prev.source));
+            }
+        }
+
+        if self.newlines_between == NewlinesBetween::Ignore {
+            return;
+        }
+        for pair in imports.windows(2) {
+            let (prev, current) = (&pair[0], &pair[1]);
+            let between = &source_text[prev.span.end as usize..current.span.start as usize];
+            let has_empty_line = between.matches('\n').count() > 1;
+            match self.newlines_between {
+                NewlinesBetween::Always | NewlinesBetween::AlwaysAndInsideGroups
+                    if current.rank != prev.rank && !has_empty_line =>
+                {
+                    report(missing_newline_diagnostic(current.span));
+                }
+                NewlinesBetween::Always if current.rank == prev.rank && has_empty_line => {
+                    report(newline_in_group_diagnostic(current.span));
+                }
+                NewlinesBetween::Never if has_empty_line => {
+                    report(newline_between_groups_diagnostic(current.span));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+struct ImportEntry<'a> {
+    span: Span,
+    source: &'a str,
+    rank: usize,
+}
+
+impl Order {
+    fn import_entry<'a>(&self, decl: &ImportDeclaration<'a>) -> ImportEntry<'a> {
+        let source = decl.source.value.as_str();
+        let has_type_group = self.groups.iter().flatten().any(|kind| *kind == ImportKind::Type);
+        let kind = if decl.import_kind.is_type() && has_type_group {
+            ImportKind::Type
+        } else {
+            ImportKind::from_source(source)
+        };
+        let rank =
+            self.groups.iter().position(|group| group.contains(&kind)).unwrap_or(self.groups.len());
+        ImportEntry { span: decl.span, source, rank }
+    }
+
+    fn compare(&self, a: &ImportEntry, b: &ImportEntry) -> Ordering {
+        a.rank.cmp(&b.rank).then_with(|| {
+            let ordering = if self.case_insensitive {
+                a.source.to_lowercase().cmp(&b.source.to_lowercase())
+            } else {
+                a.source.cmp(b.source)
+            };
+            match self.alphabetize {
+                SortOrder::Ignore => Ordering::Equal,
+                SortOrder::Asc => ordering,
+                SortOrder::Desc => ordering.reverse(),
+            }
+        })
+    }
+
+    /// Reorder all imports, and separate the groups as configured by `newlines-between`.
+    fn fix<'a>(
+        &self,
+        ctx: &LintContext<'a>,
+        imports: &[ImportEntry],
+        sorted: &[usize],
+    ) -> Option<Fix<'a>> {
+        let source_text = ctx.source_text();
+        let spans = imports.iter().map(|import| import.span).collect::<Vec<_>>();
+        let order = sorted
+            .iter()
+            .enumerate()
+            .map(|(i, &index)| {
+                let is_new_group = i > 0 && imports[sorted[i - 1]].rank != imports[index].rank;
+                let separator = match self.newlines_between {
+                    NewlinesBetween::Always | NewlinesBetween::AlwaysAndInsideGroups
+                        if is_new_group =>
+                    {
+                        Some("\n\n")
+                    }
+                    // Keep the whitespace which separated the imports at this position.
+                    NewlinesBetween::Ignore => None,
+                    _ => Some("\n"),
+                };
+                (index, separator)
+            })
+            .collect::<Vec<_>>();
+        Fix::reorder_statements(source_text, ctx.semantic().trivias(), &spans, &order)
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        (
+            r"import fs from 'fs'; import foo from 'foo'; import a from '../a'; import b from './b'; import c from '.';",
+            None,
+        ),
+        (
+            r"import fs from 'node:fs'; import path from 'path/posix'; import react from 'react';",
+            None,
+        ),
+        (r"import b from './b'; import a from './a';", None),
+        (
+            r"import a from './a'; import b from './b';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"import b from './b'; import a from './a';",
+            Some(json!([{ "alphabetize": { "order": "desc" } }])),
+        ),
+        (
+            r"import A from './A'; import b from './b';",
+            Some(json!([{ "alphabetize": { "order": "asc", "caseInsensitive": true } }])),
+        ),
+        (
+            r"import a from '../a'; import b from './b';",
+            Some(json!([{ "groups": [["parent", "sibling"]] }])),
+        ),
+        (
+            r"import b from './b'; import a from '../a';",
+            Some(json!([{ "groups": [["parent", "sibling"]] }])),
+        ),
+        (
+            r"import a from './a'; import type { T } from 'react';",
+            Some(json!([{ "groups": ["sibling", "type"] }])),
+        ),
+        (r"import type { T } from 'react'; import a from './a';", None),
+        (
+            r"import fs from 'fs';
+
+import foo from 'foo';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r"import fs from 'fs';
+import foo from 'foo';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            r"import a from './a';
+
+import b from './b';",
+            Some(json!([{ "newlines-between": "always-and-inside-groups" }])),
+        ),
+        (r"import foo from './foo'; foo(); import fs from 'fs';", Some(json!([{ "groups": [] }]))),
+    ];
+
+    let fail = vec![
+        (r"import foo from './foo'; import fs from 'fs';", None),
+        (r"import a from '../a'; import fs from 'node:fs';", None),
+        (r"import c from '.'; import b from './b';", None),
+        (r"import foo from './foo'; foo(); import fs from 'fs';", None),
+        (
+            r"import b from './b'; import a from './a';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"import A from './A'; import b from './b';",
+            Some(json!([{ "alphabetize": { "order": "desc" } }])),
+        ),
+        (
+            r"import type { T } from 'react'; import a from './a';",
+            Some(json!([{ "groups": ["sibling", "type"] }])),
+        ),
+        (
+            r"import fs from 'fs';
+import foo from 'foo';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r"import a from './a';
+
+import b from './b';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r"import fs from 'fs';
+
+import foo from 'foo';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "import foo from './foo';\nimport fs from 'fs';\n",
+            "import fs from 'fs';\nimport foo from './foo';\n",
+            None,
+        ),
+        (
+            "// header\nimport foo from './foo'; // sibling\n// fs\nimport fs from 'fs';\n",
+            "// header\n// fs\nimport fs from 'fs';\nimport foo from './foo'; // sibling\n",
+            None,
+        ),
+        (
+            "import c from './c';\n\nimport b from './b'; // b\n// a\nimport a from './a';\n",
+            "// a\nimport a from './a';\n\nimport b from './b'; // b\nimport c from './c';\n",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            "import b from './b';\nimport fs from 'fs';\nimport a from './a';\n",
+            "import fs from 'fs';\n\nimport a from './a';\nimport b from './b';\n",
+            Some(json!([{ "newlines-between": "always", "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport foo from 'foo';\n",
+            "import fs from 'fs';\nimport foo from 'foo';\n",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            "import foo from './foo';\n\n// section\n\nimport fs from 'fs';\n",
+            "import foo from './foo';\n\n// section\n\nimport fs from 'fs';\n",
+            None,
+        ),
+    ];
+
+    Tester::new(Order::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
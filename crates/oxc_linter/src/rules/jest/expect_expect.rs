@@ -392,6 +392,7 @@ fn test() {
             "#,
             None,
         ),
+        ("import { test, expect } from 'vitest'; test.fails('works', () => { expect(1).toBe(2) });", None),
     ];
 
     let fail = vec![
@@ -484,6 +485,7 @@ fn test() {
             "#,
             None,
         ),
+        ("import { it } from 'vitest'; it('works', () => {});", None),
     ];
 
     Tester::new(ExpectExpect::NAME, pass, fail).with_jest_plugin(true).test_and_snapshot();
@@ -1,17 +1,13 @@
-use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{
     context::LintContext,
     fixer::Fix,
     rule::Rule,
-    utils::{
-        collect_possible_jest_call_node, parse_general_jest_fn_call, JestFnKind, JestGeneralFnKind,
-        MemberExpressionElement, ParsedGeneralJestFnCall, PossibleJestNode,
-    },
+    utils::{MemberExpressionElement, TestBlock, TestStructure},
 };
 
 fn no_focused_tests_diagnostic(span0: Span) -> OxcDiagnostic {
@@ -57,37 +53,30 @@ declare_oxc_lint!(
 
 impl Rule for NoFocusedTests {
     fn run_once(&self, ctx: &LintContext) {
-        for node in &collect_possible_jest_call_node(ctx) {
-            run(node, ctx);
+        for block in TestStructure::new(ctx).blocks() {
+            run(block, ctx);
         }
     }
 }
 
-fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>) {
-    let node = possible_jest_node.node;
-    let AstKind::CallExpression(call_expr) = node.kind() else {
-        return;
-    };
-    let Some(jest_fn_call) = parse_general_jest_fn_call(call_expr, possible_jest_node, ctx) else {
-        return;
-    };
-    let ParsedGeneralJestFnCall { kind, members, name, .. } = jest_fn_call;
-    if !matches!(kind, JestFnKind::General(JestGeneralFnKind::Describe | JestGeneralFnKind::Test)) {
+fn run<'a>(block: &TestBlock<'a, '_>, ctx: &LintContext<'a>) {
+    if !block.is_focused() {
         return;
     }
+    let span = block.node.kind().span();
 
-    if name.starts_with('f') {
-        ctx.diagnostic_with_fix(no_focused_tests_diagnostic(call_expr.span), || {
-            let start = call_expr.span.start;
+    if block.call.name.starts_with('f') {
+        ctx.diagnostic_with_suggestion(no_focused_tests_diagnostic(span), || {
+            let start = span.start;
             Fix::delete(Span::new(start, start + 1))
         });
 
         return;
     }
 
-    let only_node = members.iter().find(|member| member.is_name_equal("only"));
+    let only_node = block.call.members.iter().find(|member| member.is_name_equal("only"));
     if let Some(only_node) = only_node {
-        ctx.diagnostic_with_fix(no_focused_tests_diagnostic(call_expr.span), || {
+        ctx.diagnostic_with_suggestion(no_focused_tests_diagnostic(span), || {
             let span = only_node.span;
             let start = span.start - 1;
             let end = if matches!(only_node.element, MemberExpressionElement::IdentName(_)) {
@@ -105,42 +94,48 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
-        ("describe()", None),
-        ("it()", None),
-        ("describe.skip()", None),
-        ("it.skip()", None),
-        ("test()", None),
-        ("test.skip()", None),
-        ("var appliedOnly = describe.only; appliedOnly.apply(describe)", None),
-        ("var calledOnly = it.only; calledOnly.call(it)", None),
-        ("it.each()()", None),
-        ("it.each`table`()", None),
-        ("test.each()()", None),
-        ("test.each`table`()", None),
-        ("test.concurrent()", None),
+        ("describe()", None, None),
+        ("it()", None, None),
+        ("describe.skip()", None, None),
+        ("it.skip()", None, None),
+        ("test()", None, None),
+        ("test.skip()", None, None),
+        ("var appliedOnly = describe.only; appliedOnly.apply(describe)", None, None),
+        ("var calledOnly = it.only; calledOnly.call(it)", None, None),
+        ("it.each()()", None, None),
+        ("it.each`table`()", None, None),
+        ("test.each()()", None, None),
+        ("test.each`table`()", None, None),
+        ("test.concurrent()", None, None),
     ];
 
     let fail = vec![
-        ("describe.only()", None),
-        // TODO: this need set setting like `settings: { jest: { globalAliases: { describe: ['context'] } } },`
-        // ("context.only()", None),
-        ("describe.only.each()()", None),
-        ("describe.only.each`table`()", None),
-        ("describe[\"only\"]()", None),
-        ("it.only()", None),
-        ("it.concurrent.only.each``()", None),
-        ("it.only.each()()", None),
-        ("it.only.each`table`()", None),
-        ("it[\"only\"]()", None),
-        ("test.only()", None),
-        ("test.concurrent.only.each()()", None),
-        ("test.only.each()()", None),
-        ("test.only.each`table`()", None),
-        ("test[\"only\"]()", None),
-        ("fdescribe()", None),
-        ("fit()", None),
-        ("fit.each()()", None),
-        ("fit.each`table`()", None),
+        ("describe.only()", None, None),
+        (
+            "context.only()",
+            None,
+            Some(serde_json::json!({
+                "settings": { "jest": { "globalAliases": { "describe": ["context"] } } }
+            })),
+        ),
+        ("describe.only.each()()", None, None),
+        ("describe.only.each`table`()", None, None),
+        ("describe[\"only\"]()", None, None),
+        ("it.only()", None, None),
+        ("it.concurrent.only.each``()", None, None),
+        ("it.only.each()()", None, None),
+        ("it.only.each`table`()", None, None),
+        ("it[\"only\"]()", None, None),
+        ("test.only()", None, None),
+        ("test.concurrent.only.each()()", None, None),
+        ("test.only.each()()", None, None),
+        ("test.only.each`table`()", None, None),
+        ("test[\"only\"]()", None, None),
+        ("fdescribe()", None, None),
+        ("fit()", None, None),
+        ("fit.each()()", None, None),
+        ("fit.each`table`()", None, None),
+        ("import { it } from 'vitest'; it.only('foo', () => {})", None, None),
     ];
 
     let fix = vec![
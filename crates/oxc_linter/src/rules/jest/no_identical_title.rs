@@ -1,22 +1,12 @@
-use std::collections::HashMap;
-
-use oxc_ast::{
-    ast::{Argument, CallExpression},
-    AstKind,
-};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::AstNodeId;
-use oxc_span::{Atom, Span};
+use oxc_span::Span;
+use rustc_hash::FxHashSet;
 
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{
-        collect_possible_jest_call_node, parse_general_jest_fn_call, JestFnKind, JestGeneralFnKind,
-        PossibleJestNode,
-    },
-    AstNode,
+    utils::{JestGeneralFnKind, TestStructure},
 };
 
 fn describe_repeat(span0: Span) -> OxcDiagnostic {
@@ -60,107 +50,35 @@ declare_oxc_lint!(
 
 impl Rule for NoIdenticalTitle {
     fn run_once(&self, ctx: &LintContext) {
-        let possible_jest_nodes = collect_possible_jest_call_node(ctx);
-        let mut title_to_span_mapping = HashMap::new();
-        let mut span_to_parent_mapping = HashMap::new();
-
-        possible_jest_nodes
-            .iter()
-            .filter_map(|possible_jest_node| {
-                let AstKind::CallExpression(call_expr) = possible_jest_node.node.kind() else {
-                    return None;
-                };
-                filter_and_process_jest_result(call_expr, possible_jest_node, ctx)
-            })
-            .for_each(|(span, title, kind, parent_id)| {
-                span_to_parent_mapping.insert(span, parent_id);
-                title_to_span_mapping
-                    .entry(title)
-                    .and_modify(|e: &mut Vec<(JestFnKind, Span)>| e.push((kind, span)))
-                    .or_insert_with(|| vec![(kind, span)]);
-            });
-
-        for kind_and_span in title_to_span_mapping.values() {
-            let mut kind_and_spans = kind_and_span
-                .iter()
-                .filter_map(|(kind, span)| {
-                    let parent = span_to_parent_mapping.get(span)?;
-                    Some((*span, *kind, *parent))
-                })
-                .collect::<Vec<(Span, JestFnKind, AstNodeId)>>();
-            // After being sorted by parent_id, the span with the same parent will be placed nearby.
-            kind_and_spans.sort_by(|a, b| a.2.cmp(&b.2));
-
-            // Skip the first element, for `describe('foo'); describe('foo');`, we only need to check the second one.
-            for i in 1..kind_and_spans.len() {
-                let (span, kind, parent_id) = kind_and_spans[i];
-                let (_, prev_kind, prev_parent) = kind_and_spans[i - 1];
+        let structure = TestStructure::new(ctx);
+        // The titles seen so far in each describe block, by the index of the describe block.
+        let mut titles = FxHashSet::default();
 
-                if kind == prev_kind && parent_id == prev_parent {
-                    match kind {
-                        JestFnKind::General(JestGeneralFnKind::Describe) => {
-                            ctx.diagnostic(describe_repeat(span));
-                        }
-                        JestFnKind::General(JestGeneralFnKind::Test) => {
-                            ctx.diagnostic(test_repeat(span));
-                        }
-                        _ => {}
-                    }
+        for block in structure.blocks() {
+            let Some((title, span)) = block.title else { continue };
+            if block.is_each() {
+                continue;
+            }
+            let is_describe = block.kind == JestGeneralFnKind::Describe;
+            if !titles.insert((block.parent, is_describe, title)) {
+                if is_describe {
+                    ctx.diagnostic(describe_repeat(span));
+                } else {
+                    ctx.diagnostic(test_repeat(span));
                 }
             }
         }
     }
 }
 
-fn filter_and_process_jest_result<'a>(
-    call_expr: &'a CallExpression<'a>,
-    possible_jest_node: &PossibleJestNode<'a, '_>,
-    ctx: &LintContext<'a>,
-) -> Option<(Span, &'a Atom<'a>, JestFnKind, AstNodeId)> {
-    let result = parse_general_jest_fn_call(call_expr, possible_jest_node, ctx)?;
-    let kind = result.kind;
-    // we only need check `describe` or `test` block
-    if !matches!(kind, JestFnKind::General(JestGeneralFnKind::Describe | JestGeneralFnKind::Test)) {
-        return None;
-    }
-
-    if result.members.iter().any(|m| m.is_name_equal("each")) {
-        return None;
-    }
-
-    let parent_id = get_closest_block(possible_jest_node.node, ctx)?;
-
-    match call_expr.arguments.first() {
-        Some(Argument::StringLiteral(string_lit)) => {
-            Some((string_lit.span, &string_lit.value, kind, parent_id))
-        }
-        Some(Argument::TemplateLiteral(template_lit)) => {
-            template_lit.quasi().map(|quasi| (template_lit.span, quasi, kind, parent_id))
-        }
-        _ => None,
-    }
-}
-
-fn get_closest_block(node: &AstNode, ctx: &LintContext) -> Option<AstNodeId> {
-    match node.kind() {
-        AstKind::BlockStatement(_) | AstKind::FunctionBody(_) | AstKind::Program(_) => {
-            Some(node.id())
-        }
-        _ => {
-            let parent = ctx.nodes().parent_node(node.id())?;
-            get_closest_block(parent, ctx)
-        }
-    }
-}
-
 #[test]
 fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
-        ("it(); it();", None),
-        ("describe(); describe();", None),
-        ("describe('foo', () => {}); it('foo', () => {});", None),
+        ("it(); it();", None, None),
+        ("describe(); describe();", None, None),
+        ("describe('foo', () => {}); it('foo', () => {});", None, None),
         (
             "
               describe('foo', () => {
@@ -168,6 +86,7 @@ fn test() {
               });
             ",
             None,
+            None,
         ),
         (
             "
@@ -175,6 +94,7 @@ fn test() {
               it('two', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -182,6 +102,7 @@ fn test() {
               describe('foe', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -189,6 +110,7 @@ fn test() {
               it(`two`, () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -196,6 +118,7 @@ fn test() {
               describe(`foe`, () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -205,6 +128,7 @@ fn test() {
               });
             ",
             None,
+            None,
         ),
         (
             "
@@ -212,6 +136,7 @@ fn test() {
               test.concurrent('that', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -219,6 +144,7 @@ fn test() {
               test.only.concurrent('that', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -226,6 +152,7 @@ fn test() {
               test.concurrent('that', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -233,6 +160,7 @@ fn test() {
               test.only.concurrent('that', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -240,6 +168,7 @@ fn test() {
               test.only('that', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -252,6 +181,7 @@ fn test() {
               });
             ",
             None,
+            None,
         ),
         (
             "
@@ -264,8 +194,9 @@ fn test() {
               });
             ",
             None,
+            None,
         ),
-        ("describe('foo', () => describe('foe', () => {}));", None),
+        ("describe('foo', () => describe('foe', () => {}));", None, None),
         (
             "
               describe('foo', () => {
@@ -275,11 +206,12 @@ fn test() {
               describe('foe', () => {});
             ",
             None,
+            None,
         ),
-        ("test('number' + n, function() {});", None),
-        ("test('number' + n, function() {}); test('number' + n, function() {});", None),
-        // ("it(`${n}`, function() {});", None),
-        // ("it(`${n}`, function() {}); it(`${n}`, function() {});", None),
+        ("test('number' + n, function() {});", None, None),
+        ("test('number' + n, function() {}); test('number' + n, function() {});", None, None),
+        // ("it(`${n}`, function() {});", None, None),
+        // ("it(`${n}`, function() {}); it(`${n}`, function() {});", None, None),
         (
             "
               describe('a class named ' + myClass.name, () => {
@@ -289,6 +221,7 @@ fn test() {
               describe('something else', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -298,6 +231,7 @@ fn test() {
               });
             ",
             None,
+            None,
         ),
         (
             "
@@ -306,6 +240,7 @@ fn test() {
               test.content(`something that is not from jest`, () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -314,6 +249,7 @@ fn test() {
               describe.content(`something that is not from jest`, () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -328,6 +264,7 @@ fn test() {
               `('$description', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -340,6 +277,7 @@ fn test() {
               });
             ",
             None,
+            None,
         ),
         (
             "
@@ -349,6 +287,7 @@ fn test() {
               describe.each([])('my title', value => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -356,6 +295,7 @@ fn test() {
               describe.each([])('when the value is %s', value => {});
             ",
             None,
+            None,
         ),
     ];
 
@@ -368,6 +308,7 @@ fn test() {
               });
             ",
             None,
+            None,
         ),
         (
             "
@@ -375,6 +316,7 @@ fn test() {
               it('works', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -382,6 +324,7 @@ fn test() {
               test('this', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -389,6 +332,7 @@ fn test() {
               test('this', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -396,6 +340,7 @@ fn test() {
               test.only('this', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -403,6 +348,7 @@ fn test() {
               test.concurrent('this', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -410,6 +356,7 @@ fn test() {
               test.concurrent('this', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -417,6 +364,7 @@ fn test() {
               describe('foo', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -424,6 +372,7 @@ fn test() {
               xdescribe('foo', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -431,6 +380,7 @@ fn test() {
               describe('foo', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -440,6 +390,7 @@ fn test() {
               describe('foo', () => {});
             ",
             None,
+            None,
         ),
         (
             "
@@ -449,16 +400,31 @@ fn test() {
               });
             ",
             None,
+            None,
+        ),
+        (
+            "
+              context('foo', () => {
+                describe('foe', () => {});
+              });
+              describe('foo', () => {});
+            ",
+            None,
+            Some(serde_json::json!({
+                "settings": { "jest": { "globalAliases": { "describe": ["context"] } } }
+            })),
+        ),
+        (
+            "
+              import { suite, test } from 'vitest';
+              suite('foo', () => {
+                test('works', () => {});
+                test.sequential('works', () => {});
+              });
+            ",
+            None,
+            None,
         ),
-        // (
-        //     "
-        //       context('foo', () => {
-        //         describe('foe', () => {});
-        //       });
-        //       describe('foo', () => {});
-        //     ",
-        //     None,
-        // ),
     ];
 
     Tester::new(NoIdenticalTitle::NAME, pass, fail).with_jest_plugin(true).test_and_snapshot();
@@ -109,7 +109,7 @@ impl PreferSpyOn {
             return;
         }
 
-        ctx.diagnostic_with_fix(
+        ctx.diagnostic_with_suggestion(
             use_jest_spy_on(Span::new(call_expr.span.start, first_fn_member.span.end)),
             || {
                 let (end, has_mock_implementation) = if jest_fn_call.members.len() > 1 {
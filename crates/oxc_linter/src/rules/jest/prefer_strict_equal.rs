@@ -65,7 +65,7 @@ impl PreferStrictEqual {
         };
 
         if matcher_name.eq("toEqual") {
-            ctx.diagnostic_with_fix(use_to_strict_equal(matcher.span), || {
+            ctx.diagnostic_with_suggestion(use_to_strict_equal(matcher.span), || {
                 let mut formatter = ctx.codegen();
                 formatter.print_str(
                     matcher
@@ -790,6 +790,7 @@ fn test() {
             ",
             None,
         ),
+        ("import { expect } from 'vitest'; expect().toBe(2);", None),
     ];
 
     Tester::new(ValidExpect::NAME, pass, fail).with_jest_plugin(true).test_and_snapshot();
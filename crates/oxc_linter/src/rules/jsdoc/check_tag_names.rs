@@ -1059,7 +1059,7 @@ fn test() {
         (
             "
         			        /** @abstract */
-        			        let a;
+        			        declare let a;
         			      ",
             Some(serde_json::json!([
               {
@@ -1082,8 +1082,10 @@ fn test() {
         ),
         (
             "
-        			        /** @abstract */
-        			        { declare let a; }
+        			        declare module 'm' {
+        			          /** @abstract */
+        			          let a;
+        			        }
         			      ",
             Some(serde_json::json!([
               {
@@ -1094,9 +1096,9 @@ fn test() {
         ),
         (
             "
-        			        function test() {
+        			        declare namespace test {
         			          /** @abstract */
-        			          declare let a;
+        			          let a;
         			        }
         			      ",
             Some(serde_json::json!([
@@ -5,7 +5,9 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
 use crate::{
-    context::LintContext, globals::VALID_ARIA_PROPS, rule::Rule, utils::get_jsx_attribute_name,
+    context::LintContext,
+    rule::Rule,
+    utils::{get_jsx_attribute_name, VALID_ARIA_PROPS},
     AstNode,
 };
 
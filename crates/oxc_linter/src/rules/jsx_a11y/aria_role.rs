@@ -9,9 +9,9 @@ use oxc_span::Span;
 
 use crate::{
     context::LintContext,
-    globals::{HTML_TAG, VALID_ARIA_ROLES},
+    globals::HTML_TAG,
     rule::Rule,
-    utils::{get_element_type, get_prop_value, has_jsx_prop},
+    utils::{get_element_type, get_prop_value, has_jsx_prop, VALID_ARIA_ROLES},
     AstNode,
 };
 
@@ -0,0 +1,211 @@
+use oxc_ast::{
+    ast::{JSXAttributeItem, JSXAttributeValue, JSXExpression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::Rule,
+    utils::{
+        get_element_type, get_explicit_role, get_prop_value, has_jsx_prop, is_abstract_role,
+        is_hidden_from_screen_reader, is_interactive_element, is_interactive_role,
+        is_non_interactive_element, is_non_interactive_role, is_presentation_role,
+    },
+    AstNode,
+};
+
+fn no_static_element_interactions_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint-plugin-jsx-a11y(no-static-element-interactions): Static HTML elements with event handlers require a role.")
+        .with_help("Add a role to the element, or use a semantic element such as `<button>` instead.")
+        .with_labels([span0.into()])
+}
+
+const DEFAULT_HANDLERS: [&str; 6] =
+    ["onClick", "onMouseDown", "onMouseUp", "onKeyPress", "onKeyDown", "onKeyUp"];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoStaticElementInteractions(Box<NoStaticElementInteractionsConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoStaticElementInteractionsConfig {
+    handlers: Vec<String>,
+}
+
+impl std::ops::Deref for NoStaticElementInteractions {
+    type Target = NoStaticElementInteractionsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for NoStaticElementInteractionsConfig {
+    fn default() -> Self {
+        Self { handlers: DEFAULT_HANDLERS.iter().map(ToString::to_string).collect() }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that static, visible elements such as `<div>` which have event handlers
+    /// also have a role.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Static HTML elements do not have semantic meaning, so assistive technology can not tell
+    /// the user that they can interact with them. Either use a semantic element like `<button>`,
+    /// or give the element a role like `role="button"`.
+    ///
+    /// The handlers which are checked can be configured with the `handlers` option, which
+    /// defaults to `onClick`, `onMouseDown`, `onMouseUp`, `onKeyPress`, `onKeyDown` and `onKeyUp`.
+    ///
+    /// ### Example
+    /// ```jsx
+    /// // Good
+    /// <button onClick={() => {}} />
+    /// <div onClick={() => {}} role="button" />
+    /// <div onClick={() => {}} role="presentation" />
+    ///
+    /// // Bad
+    /// <div onClick={() => {}} />
+    /// <span onKeyDown={handler} />
+    /// ```
+    NoStaticElementInteractions,
+    correctness
+);
+
+impl Rule for NoStaticElementInteractions {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mut config = NoStaticElementInteractionsConfig::default();
+        if let Some(handlers) =
+            value.get(0).and_then(|config| config.get("handlers")).and_then(|v| v.as_array())
+        {
+            config.handlers =
+                handlers.iter().filter_map(|v| v.as_str().map(ToString::to_string)).collect();
+        }
+        Self(Box::new(config))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_opening_el) = node.kind() else {
+            return;
+        };
+
+        let Some(element_type) = get_element_type(ctx, jsx_opening_el) else {
+            return;
+        };
+        if !HTML_TAG.contains(&element_type) {
+            return;
+        }
+
+        if !self
+            .handlers
+            .iter()
+            .any(|handler| has_jsx_prop(jsx_opening_el, handler).is_some_and(is_handler_prop))
+        {
+            return;
+        }
+
+        if is_hidden_from_screen_reader(ctx, jsx_opening_el) || is_presentation_role(jsx_opening_el)
+        {
+            return;
+        }
+
+        if is_interactive_element(&element_type, jsx_opening_el)
+            || is_non_interactive_element(&element_type, jsx_opening_el)
+        {
+            return;
+        }
+
+        // Only report unknown roles, the role may be valid when it is not a string literal.
+        if has_jsx_prop(jsx_opening_el, "role").is_some() {
+            match get_explicit_role(jsx_opening_el) {
+                Some(role)
+                    if !is_interactive_role(role)
+                        && !is_non_interactive_role(role)
+                        && !is_abstract_role(role) => {}
+                _ => return,
+            }
+        }
+
+        ctx.diagnostic(no_static_element_interactions_diagnostic(jsx_opening_el.span));
+    }
+}
+
+/// `onClick={handler}`, but not `onClick={null}` or `onClick={undefined}`.
+fn is_handler_prop(item: &JSXAttributeItem) -> bool {
+    match get_prop_value(item) {
+        Some(JSXAttributeValue::ExpressionContainer(container)) => {
+            !matches!(container.expression, JSXExpression::NullLiteral(_))
+                && !container.expression.is_undefined()
+        }
+        _ => true,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"<div />;", None, None, None),
+        (r#"<div className="foo" />;"#, None, None, None),
+        (r#"<div onClick={() => void 0} role="button" />;"#, None, None, None),
+        (r#"<div onClick={() => void 0} role="listitem" />;"#, None, None, None),
+        (r#"<div onClick={() => void 0} role="menuitem checkbox" />;"#, None, None, None),
+        (r"<div onClick={() => void 0} role={role} />;", None, None, None),
+        (r"<div onClick={() => void 0} aria-hidden />;", None, None, None),
+        (r#"<div onClick={() => void 0} role="presentation" />;"#, None, None, None),
+        (r#"<input onClick={() => void 0} type="hidden" />;"#, None, None, None),
+        (r"<button onClick={() => void 0} />;", None, None, None),
+        (r#"<a onClick={() => void 0} href="/" />;"#, None, None, None),
+        (r"<input onClick={() => void 0} />;", None, None, None),
+        (r"<li onClick={() => void 0} />;", None, None, None),
+        (r"<section onClick={() => void 0} />;", None, None, None),
+        (r"<img onClick={() => void 0} />;", None, None, None),
+        (r"<div onClick={null} />;", None, None, None),
+        (r"<div onClick={undefined} />;", None, None, None),
+        (r"<div onMouseEnter={() => void 0} />;", None, None, None),
+        (r"<Foo onClick={() => void 0} />;", None, None, None),
+        (
+            r"<div onClick={() => void 0} />;",
+            Some(serde_json::json!([{ "handlers": ["onKeyDown"] }])),
+            None,
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        (r"<div onClick={() => void 0} />;", None, None, None),
+        (r"<span onKeyDown={handler} />;", None, None, None),
+        (r"<div onMouseUp={handler}></div>;", None, None, None),
+        (r"<a onClick={() => void 0} />;", None, None, None),
+        (r#"<div onClick={() => void 0} role="foo" />;"#, None, None, None),
+        (r"<div onClick={() => void 0} aria-hidden={false} />;", None, None, None),
+        (
+            r"<div onMouseEnter={() => void 0} />;",
+            Some(serde_json::json!([{ "handlers": ["onMouseEnter"] }])),
+            None,
+            None,
+        ),
+        (
+            r"<Box onClick={() => void 0} />;",
+            None,
+            Some(serde_json::json!({
+                "settings": { "jsx-a11y": {
+                    "components": {
+                        "Box": "div",
+                    }
+                } }
+            })),
+            None,
+        ),
+    ];
+
+    Tester::new(NoStaticElementInteractions::NAME, pass, fail).test_and_snapshot();
+}
@@ -10,11 +10,10 @@ use phf::phf_set;
 
 use crate::{
     context::LintContext,
-    globals::{VALID_ARIA_PROPS, VALID_ARIA_ROLES},
     rule::Rule,
     utils::{
         get_element_type, get_jsx_attribute_name, get_string_literal_prop_value,
-        has_jsx_prop_lowercase,
+        has_jsx_prop_lowercase, VALID_ARIA_PROPS, VALID_ARIA_ROLES,
     },
     AstNode,
 };
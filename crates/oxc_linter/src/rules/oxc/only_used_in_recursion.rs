@@ -235,7 +235,7 @@ fn test() {
         }
         "#,
         "function foo() {}
-        declare function foo() {}",
+        declare function foo();",
     ];
 
     let fail = vec![
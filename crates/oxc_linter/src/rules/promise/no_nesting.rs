@@ -0,0 +1,132 @@
+use oxc_ast::{ast::CallExpression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeId;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_promise_callback_call, get_promise_method},
+    AstNode,
+};
+
+fn no_nesting_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint-plugin-promise(no-nesting): Avoid nesting promises.")
+        .with_help("Return the inner promise from the callback and continue the outer chain")
+        .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNesting;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow calling `.then()` or `.catch()` inside the callback of another `.then()` or
+    /// `.catch()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Nested chains recreate the callback pyramid that promises are meant to flatten, and an
+    /// inner chain which is not returned is not awaited by the outer one, so its errors are lost.
+    ///
+    /// A nested chain whose callbacks use the parameters or variables of an outer callback is
+    /// allowed, because it cannot be moved out without losing them.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// doThing().then(() => getB().then((b) => use(b)));
+    ///
+    /// // Good
+    /// doThing().then(() => getB()).then((b) => use(b));
+    /// doThing().then((a) => getB(a).then((b) => use(a, b)));
+    /// ```
+    NoNesting,
+    style
+);
+
+impl Rule for NoNesting {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
+        let Some((span, name)) = get_promise_method(call_expr) else {
+            return;
+        };
+        if name == "finally" {
+            return;
+        }
+
+        let mut is_nested = false;
+        for ancestor in ctx.nodes().iter_parents(node.id()).skip(1) {
+            let Some(scope_id) = get_callback_scope_id(ancestor, ctx) else {
+                continue;
+            };
+            if uses_bindings_of(call_expr, scope_id, ctx) {
+                return;
+            }
+            is_nested = true;
+        }
+        if is_nested {
+            ctx.diagnostic(no_nesting_diagnostic(span));
+        }
+    }
+}
+
+/// The scope of `node`, if it is a callback of `.then()` or `.catch()`.
+fn get_callback_scope_id<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<ScopeId> {
+    get_promise_callback_call(node, ctx)?;
+    match node.kind() {
+        AstKind::Function(func) => func.scope_id.get(),
+        AstKind::ArrowFunctionExpression(arrow) => arrow.scope_id.get(),
+        _ => None,
+    }
+}
+
+/// Whether the arguments of `call_expr` reference a binding declared in `scope_id`.
+fn uses_bindings_of(call_expr: &CallExpression, scope_id: ScopeId, ctx: &LintContext) -> bool {
+    let (Some(first), Some(last)) = (call_expr.arguments.first(), call_expr.arguments.last())
+    else {
+        return false;
+    };
+    let arguments_span = Span::new(first.span().start, last.span().end);
+    ctx.scopes().get_bindings(scope_id).values().any(|&symbol_id| {
+        ctx.symbols().get_resolved_reference_ids(symbol_id).any(|reference_id| {
+            let span = ctx.symbols().get_reference(reference_id).span();
+            arguments_span.start <= span.start && span.end <= arguments_span.end
+        })
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "Promise.resolve(4).then(function(x) { return x })",
+        "Promise.reject(4).then(function(x) { return x }, function(err) { return err })",
+        "doThing().then(function() { return 4 })",
+        "doThing().then(function() { return Promise.all([a, b]) })",
+        "doThing().then(() => 4).then(() => 5)",
+        "doThing().then(function(a) { return getB(a).then(function(b) { return a + b }) })",
+        "doThing().then((a) => getB().then((b) => [a, b]))",
+        "doThing().then(function() { const x = 1; return getB().then(() => x) })",
+        "doThing().then(function() { return getB().finally(() => {}) })",
+        "a.then(() => {}); b.catch(() => {})",
+    ];
+
+    let fail = vec![
+        "doThing().then(function() { a.then() })",
+        "doThing().then(function() { b.catch() })",
+        "doThing().then(function() { return a.then() })",
+        "doThing().catch(function() { return a.then() })",
+        "doThing().then(() => { return b.catch() })",
+        "doThing().then(() => getB().then((b) => use(b)))",
+        "doThing().then((a) => getB(a).then(() => getC().then((c) => use(c))))",
+    ];
+
+    Tester::new(NoNesting::NAME, pass, fail).with_promise_plugin(true).test_and_snapshot();
+}
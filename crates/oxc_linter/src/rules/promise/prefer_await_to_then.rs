@@ -0,0 +1,101 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ast_util::get_enclosing_function,
+    context::LintContext,
+    rule::Rule,
+    utils::{get_promise_method, is_inside_await_or_yield},
+    AstNode,
+};
+
+fn prefer_await_to_then_diagnostic(span0: Span, x0: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-promise(prefer-await-to-then): Prefer `await` to `.{x0}()`"
+    ))
+    .with_help("Use `await` in an async function, with `try` / `catch` / `finally` for errors")
+    .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferAwaitToThen;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Prefer `await` to `.then()`, `.catch()` and `.finally()` for reading the result of a
+    /// promise.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Callback chains are harder to read than sequential code, and each callback is a new
+    /// function scope for variables which would otherwise be shared.
+    ///
+    /// Top-level code, where `await` may be unavailable, is not checked, and neither are chains
+    /// which are already awaited or yielded.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// function foo() {
+    ///   return fetch(url).then((res) => res.json()).catch(report);
+    /// }
+    ///
+    /// // Good
+    /// async function foo() {
+    ///   try {
+    ///     const res = await fetch(url);
+    ///     return await res.json();
+    ///   } catch (error) {
+    ///     report(error);
+    ///   }
+    /// }
+    /// ```
+    PreferAwaitToThen,
+    style
+);
+
+impl Rule for PreferAwaitToThen {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
+        let Some((span, name)) = get_promise_method(call_expr) else {
+            return;
+        };
+        if get_enclosing_function(node, ctx).is_none() || is_inside_await_or_yield(node, ctx) {
+            return;
+        }
+        ctx.diagnostic(prefer_await_to_then_diagnostic(span, name));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async function hi() { await thing() }",
+        "async function hi() { await thing().then() }",
+        "async function hi() { await thing().catch() }",
+        "a = async () => (await something())",
+        "function* gen() { yield promise.then(f) }",
+        "something().then(async () => await somethingElse())",
+        "function foo() { hey.somethingElse(x => {}) }",
+        "const p = fetch(url).then(f)",
+    ];
+
+    let fail = vec![
+        "function foo() { hey.then(x => {}) }",
+        "function foo() { hey.then(function() { }).then() }",
+        "function foo() { hey.then(function() { }).then(x).catch() }",
+        "async function a() { hey.then(function() { }).then(function() { }) }",
+        "function foo() { hey.catch(x => {}) }",
+        "function foo() { hey.finally(x => {}) }",
+    ];
+
+    Tester::new(PreferAwaitToThen::NAME, pass, fail).with_promise_plugin(true).test_and_snapshot();
+}
@@ -162,7 +162,7 @@ impl Rule for BanTsComment {
                     DirectiveConfig::Boolean(on) => {
                         if *on {
                             if directive == "ignore" {
-                                ctx.diagnostic_with_fix(
+                                ctx.diagnostic_with_suggestion(
                                     ignore_instead_of_expect_error(span),
                                     || {
                                         Fix::new(
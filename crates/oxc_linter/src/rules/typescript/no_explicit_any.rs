@@ -97,7 +97,7 @@ impl Rule for NoExplicitAny {
         }
 
         if self.fix_to_unknown {
-            ctx.diagnostic_with_fix(no_explicit_any_diagnostic(any.span), || {
+            ctx.diagnostic_with_suggestion(no_explicit_any_diagnostic(any.span), || {
                 Fix::new("unknown", any.span)
             });
         } else {
@@ -0,0 +1,236 @@
+use oxc_ast::{
+    ast::{
+        CallExpression, ChainElement, Expression, IdentifierReference, MemberExpression, TSType,
+        TSTypeAnnotation, TSTypeName,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::Reference;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    ast_util::{get_declaration_of_variable, get_symbol_id_of_variable, is_global_reference},
+    context::LintContext,
+    rule::Rule,
+    utils::get_promise_method,
+    AstNode,
+};
+
+fn no_floating_promises_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("typescript-eslint(no-floating-promises): Promises must be awaited, end with a call to .catch, or end with a call to .then with a rejection handler.")
+        .with_help("Await the promise, handle its rejection, or mark it as ignored with the `void` operator.")
+        .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoFloatingPromises;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require promises which are created in an expression statement to be handled.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A promise which is neither awaited nor given a rejection handler fails silently, or
+    /// as an unhandled rejection far from its cause, and the code after it runs before it
+    /// settles.
+    ///
+    /// This rule is not type-aware, unlike the rule of typescript-eslint. A promise is only
+    /// recognized by how it is created in the same file:
+    ///
+    /// * Calls of functions declared in the file which are `async`, or whose return type is
+    ///   annotated as `Promise<T>`, including async functions called right away.
+    /// * `.then()`, `.catch()` and `.finally()` calls.
+    /// * `new Promise()`, and the static methods of `Promise` such as `Promise.all()`.
+    ///
+    /// Methods, imported functions and functions which are reassigned are not checked, as their
+    /// return type is unknown without a type checker.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// async function save() {}
+    /// save();
+    /// fetch(url).then(render);
+    ///
+    /// // Good
+    /// await save();
+    /// void save();
+    /// fetch(url).then(render).catch(report);
+    /// fetch(url).then(render, report);
+    /// ```
+    NoFloatingPromises,
+    suspicious
+);
+
+impl Rule for NoFloatingPromises {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else {
+            return;
+        };
+        if is_unhandled_promise(&stmt.expression, ctx) {
+            ctx.diagnostic(no_floating_promises_diagnostic(stmt.expression.span()));
+        }
+    }
+}
+
+/// Whether `expr` is known to be a promise without a rejection handler.
+fn is_unhandled_promise(expr: &Expression, ctx: &LintContext) -> bool {
+    match expr.get_inner_expression() {
+        Expression::CallExpression(call) => is_unhandled_promise_call(call, ctx),
+        Expression::ChainExpression(chain) => match &chain.expression {
+            ChainElement::CallExpression(call) => is_unhandled_promise_call(call, ctx),
+            _ => false,
+        },
+        Expression::NewExpression(new_expr) => match &new_expr.callee {
+            Expression::Identifier(ident) => {
+                ident.name == "Promise" && is_global_reference(ident, ctx)
+            }
+            _ => false,
+        },
+        Expression::ConditionalExpression(cond) => {
+            is_unhandled_promise(&cond.consequent, ctx)
+                || is_unhandled_promise(&cond.alternate, ctx)
+        }
+        Expression::LogicalExpression(logical) => {
+            is_unhandled_promise(&logical.left, ctx) || is_unhandled_promise(&logical.right, ctx)
+        }
+        Expression::SequenceExpression(seq) => {
+            seq.expressions.iter().any(|expr| is_unhandled_promise(expr, ctx))
+        }
+        _ => false,
+    }
+}
+
+fn is_unhandled_promise_call(call: &CallExpression, ctx: &LintContext) -> bool {
+    if let Some((_, name)) = get_promise_method(call) {
+        return match name {
+            "catch" => call.arguments.is_empty(),
+            "then" => call.arguments.len() < 2,
+            // `.finally()` settles like the promise it is called on.
+            _ => match call.callee.get_member_expr().map(MemberExpression::object) {
+                Some(object) if is_promise_method_call(object) => is_unhandled_promise(object, ctx),
+                _ => true,
+            },
+        };
+    }
+    match call.callee.get_inner_expression() {
+        // `Promise.all([..])`
+        Expression::StaticMemberExpression(member) => {
+            matches!(&member.object, Expression::Identifier(ident)
+                if ident.name == "Promise" && is_global_reference(ident, ctx))
+                && matches!(
+                    member.property.name.as_str(),
+                    "all" | "allSettled" | "any" | "race" | "reject" | "resolve"
+                )
+        }
+        // `(async () => {})()`
+        callee @ (Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_)) => {
+            is_async_function(callee)
+        }
+        Expression::Identifier(ident) => is_async_binding(ident, ctx),
+        _ => false,
+    }
+}
+
+/// Whether `expr` is a call of `.then()`, `.catch()` or `.finally()`.
+fn is_promise_method_call(expr: &Expression) -> bool {
+    match expr {
+        Expression::CallExpression(call) => get_promise_method(call).is_some(),
+        Expression::ChainExpression(chain) => {
+            matches!(&chain.expression, ChainElement::CallExpression(call)
+                if get_promise_method(call).is_some())
+        }
+        _ => false,
+    }
+}
+
+/// Whether `ident` refers to a function declared in the file which returns a promise, and is
+/// never reassigned.
+fn is_async_binding(ident: &IdentifierReference, ctx: &LintContext) -> bool {
+    let Some(symbol_id) = get_symbol_id_of_variable(ident, ctx) else {
+        return false;
+    };
+    if ctx.symbols().get_resolved_references(symbol_id).any(Reference::is_write) {
+        return false;
+    }
+    let Some(decl) = get_declaration_of_variable(ident, ctx) else {
+        return false;
+    };
+    match decl.kind() {
+        AstKind::Function(func) => {
+            (func.r#async && !func.generator) || returns_promise(func.return_type.as_deref())
+        }
+        AstKind::VariableDeclarator(declarator) => {
+            declarator.init.as_ref().is_some_and(is_async_function)
+        }
+        _ => false,
+    }
+}
+
+/// Whether `expr` is a function expression which returns a promise.
+fn is_async_function(expr: &Expression) -> bool {
+    match expr {
+        Expression::FunctionExpression(func) => {
+            (func.r#async && !func.generator) || returns_promise(func.return_type.as_deref())
+        }
+        Expression::ArrowFunctionExpression(arrow) => {
+            arrow.r#async || returns_promise(arrow.return_type.as_deref())
+        }
+        _ => false,
+    }
+}
+
+/// `(): Promise<T>`
+fn returns_promise(return_type: Option<&TSTypeAnnotation>) -> bool {
+    matches!(
+        return_type.map(|annotation| &annotation.type_annotation),
+        Some(TSType::TSTypeReference(reference))
+            if matches!(&reference.type_name, TSTypeName::IdentifierReference(ident)
+                if ident.name == "Promise")
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "async function f() {} async function g() { await f() }",
+        "async function f() {} void f()",
+        "async function f() {} const p = f()",
+        "async function f() {} f().catch(report)",
+        "async function f() {} f().then(render, report)",
+        "async function f() {} f().catch(report).finally(done)",
+        "async function* f() {} f()",
+        "function f() {} f()",
+        "let f = async () => {}; f = () => {}; f()",
+        "import { f } from 'mod'; f()",
+        "obj.save()",
+        "const Promise = { all() {} }; Promise.all([])",
+        "function f(): Array<number> { return [] } f()",
+    ];
+
+    let fail = vec![
+        "async function f() {} f()",
+        "const f = async () => {}; f()",
+        "const f = async function () {}; f?.()",
+        "function f(): Promise<void> { return g() } f()",
+        "const f = (): Promise<number> => g(); f()",
+        "p.then(render)",
+        "p.then(render).finally(done)",
+        "p.catch()",
+        "Promise.all([a, b])",
+        "new Promise(resolve => setTimeout(resolve))",
+        "(async () => {})()",
+        "async function f() {} cond ? f() : null",
+        "async function f() {} ready && f()",
+        "async function f() {} (f(), g())",
+    ];
+
+    Tester::new(NoFloatingPromises::NAME, pass, fail).test_and_snapshot();
+}
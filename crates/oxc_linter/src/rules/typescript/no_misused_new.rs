@@ -122,7 +122,7 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
-        "declare abstract class C { foo() {} get new();bar();}",
+        "declare abstract class C { foo(); get new();bar();}",
         "class C { constructor();}",
         "const foo = class { constructor();};",
         "const foo = class { new(): X;};",
@@ -56,20 +56,26 @@ impl Rule for PreferTsExpectError {
 
             if kind.is_single_line() {
                 let comment_span = Span::new(span.start - 2, span.end);
-                ctx.diagnostic_with_fix(prefer_ts_expect_error_diagnostic(comment_span), || {
-                    Fix::new(
-                        format!("//{}", raw.replace("@ts-ignore", "@ts-expect-error")),
-                        comment_span,
-                    )
-                });
+                ctx.diagnostic_with_suggestion(
+                    prefer_ts_expect_error_diagnostic(comment_span),
+                    || {
+                        Fix::new(
+                            format!("//{}", raw.replace("@ts-ignore", "@ts-expect-error")),
+                            comment_span,
+                        )
+                    },
+                );
             } else {
                 let comment_span = Span::new(span.start - 2, span.end + 2);
-                ctx.diagnostic_with_fix(prefer_ts_expect_error_diagnostic(comment_span), || {
-                    Fix::new(
-                        format!("/*{}*/", raw.replace("@ts-ignore", "@ts-expect-error")),
-                        comment_span,
-                    )
-                });
+                ctx.diagnostic_with_suggestion(
+                    prefer_ts_expect_error_diagnostic(comment_span),
+                    || {
+                        Fix::new(
+                            format!("/*{}*/", raw.replace("@ts-ignore", "@ts-expect-error")),
+                            comment_span,
+                        )
+                    },
+                );
             }
         }
     }
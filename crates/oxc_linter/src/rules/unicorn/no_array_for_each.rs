@@ -1,13 +1,20 @@
 use oxc_ast::{
-    ast::{match_member_expression, Expression},
-    AstKind,
+    ast::{
+        match_member_expression, Argument, ArrowFunctionExpression, BindingPatternKind,
+        CallExpression, Expression, Function, IdentifierReference, MemberExpression,
+        ReturnStatement, StaticBlock, VariableDeclaration, VariableDeclarationKind,
+    },
+    syntax_directed_operations::BoundNames,
+    visit::walk::walk_variable_declaration,
+    AstKind, Visit,
 };
 use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_semantic::{Reference, ScopeFlags};
+use oxc_span::{Atom, GetSpan, Span};
 
-use crate::{ast_util::is_method_call, context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::CallPattern, AstNode, Fix};
 
 use phf::phf_set;
 
@@ -45,52 +52,166 @@ declare_oxc_lint!(
     /// const foo = [1, 2, 3];
     /// for (const element of foo) { /* ... */ }
     /// ```
+    ///
+    /// A statement which calls `forEach` with an arrow function of one parameter is replaced
+    /// with a `for…of` loop by a suggestion, which iterates over the entries of a `Map` instead
+    /// of its values.
     NoArrayForEach,
     restriction,
 );
 
+const FOR_EACH: CallPattern<'static> = CallPattern::method(&["forEach"]).optional().spread();
+
 impl Rule for NoArrayForEach {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
         };
 
-        let Some(member_expr) = (call_expr).callee.get_member_expr() else {
-            return;
-        };
-
-        let Some((_span, _)) = member_expr.static_property_info() else {
+        let Some(member_expr) = FOR_EACH.member(call_expr) else {
             return;
         };
 
-        if is_method_call(call_expr, None, Some(&["forEach"]), None, None)
-            && !member_expr.is_computed()
-        {
-            let object = member_expr.object();
+        let object = member_expr.object();
 
-            match object {
-                Expression::Identifier(ident) => {
-                    if IGNORED_OBJECTS.contains(ident.name.as_str()) {
-                        return;
-                    }
+        match object {
+            Expression::Identifier(ident) => {
+                if IGNORED_OBJECTS.contains(ident.name.as_str()) {
+                    return;
                 }
-                match_member_expression!(Expression) => {
-                    if let Some(name) = object.to_member_expression().static_property_name() {
-                        if IGNORED_OBJECTS.contains(name) {
-                            return;
-                        }
+            }
+            match_member_expression!(Expression) => {
+                if let Some(name) = object.to_member_expression().static_property_name() {
+                    if IGNORED_OBJECTS.contains(name) {
+                        return;
                     }
                 }
-                _ => {}
             }
+            _ => {}
+        }
+
+        let Some((span, _)) = member_expr.static_property_info() else {
+            return;
+        };
+
+        let diagnostic = no_array_for_each_diagnostic(span);
+        match for_of_fix(node, call_expr, member_expr, ctx) {
+            Some(fix) => ctx.diagnostic_with_suggestion(diagnostic, || fix),
+            None => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// `foo.forEach(element => bar(element));` -> `for (const element of foo) { bar(element); }`
+fn for_of_fix<'a>(
+    node: &AstNode<'a>,
+    call_expr: &CallExpression<'a>,
+    member_expr: &MemberExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<Fix<'a>> {
+    if call_expr.optional || member_expr.optional() {
+        return None;
+    }
+    let parent = ctx.nodes().parent_node(node.id())?;
+    let AstKind::ExpressionStatement(stmt) = parent.kind() else {
+        return None;
+    };
+    // The body of `() => foo.forEach(callback)` is an expression, not a statement.
+    let body = ctx.nodes().parent_node(parent.id())?;
+    if matches!(
+        ctx.nodes().parent_kind(body.id()),
+        Some(AstKind::ArrowFunctionExpression(arrow)) if arrow.expression
+    ) {
+        return None;
+    }
+    let [Argument::ArrowFunctionExpression(callback)] = call_expr.arguments.as_slice() else {
+        return None;
+    };
+    let [param] = callback.params.items.as_slice() else {
+        return None;
+    };
+    if callback.r#async
+        || callback.params.rest.is_some()
+        || param.pattern.type_annotation.is_some()
+        || matches!(param.pattern.kind, BindingPatternKind::AssignmentPattern(_))
+    {
+        return None;
+    }
+
+    let mut names = vec![];
+    let mut reassigned = false;
+    param.pattern.bound_names(&mut |ident| {
+        names.push(ident.name.clone());
+        reassigned |= ident.symbol_id.get().is_some_and(|symbol_id| {
+            ctx.symbols().get_resolved_references(symbol_id).any(Reference::is_write)
+        });
+    });
+
+    // `for (const x of x)` reads the binding of the loop instead of the outer one.
+    let mut finder = ReferenceFinder { names: &names, found: false };
+    finder.visit_expression(member_expr.object());
+    if finder.found {
+        return None;
+    }
+    let mut finder = CallbackStatementFinder::default();
+    finder.visit_function_body(&callback.body);
+    if finder.found {
+        return None;
+    }
 
-            let Some((span, _)) = member_expr.static_property_info() else {
-                return;
-            };
+    let source_text = ctx.source_text();
+    let kind =
+        if reassigned { VariableDeclarationKind::Let } else { VariableDeclarationKind::Const };
+    let binding = param.pattern.span().source_text(source_text);
+    let object = member_expr.object().span().source_text(source_text);
+    let object = if matches!(member_expr.object(), Expression::SequenceExpression(_)) {
+        format!("({object})")
+    } else {
+        object.to_string()
+    };
+    let body = if callback.expression {
+        format!("{{ {}; }}", callback.get_expression()?.span().source_text(source_text))
+    } else {
+        callback.body.span.source_text(source_text).to_string()
+    };
+    Some(Fix::new(format!("for ({} {binding} of {object}) {body}", kind.as_str()), stmt.span))
+}
 
-            ctx.diagnostic(no_array_for_each_diagnostic(span));
+/// Finds the references to `names`.
+struct ReferenceFinder<'n, 'a> {
+    names: &'n [Atom<'a>],
+    found: bool,
+}
+
+impl<'n, 'a> Visit<'a> for ReferenceFinder<'n, 'a> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.found |= self.names.contains(&ident.name);
+    }
+}
+
+/// Finds the statements which behave differently in the body of a loop than in a callback:
+/// `return`, which would exit the function, and `var`, which would be hoisted out of the loop.
+#[derive(Default)]
+struct CallbackStatementFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for CallbackStatementFinder {
+    fn visit_return_statement(&mut self, _stmt: &ReturnStatement<'a>) {
+        self.found = true;
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if decl.kind == VariableDeclarationKind::Var {
+            self.found = true;
+        } else {
+            walk_variable_declaration(self, decl);
         }
     }
+
+    fn visit_arrow_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {}
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+    fn visit_static_block(&mut self, _block: &StaticBlock<'a>) {}
 }
 
 pub const IGNORED_OBJECTS: phf::Set<&'static str> = phf_set! {
@@ -126,5 +247,65 @@ fn test() {
         r"return foo.forEach(element => {bar(element)});",
     ];
 
-    Tester::new(NoArrayForEach::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (
+            "foo.forEach(element => bar(element));",
+            "for (const element of foo) { bar(element); }",
+            None,
+        ),
+        (
+            "foo.forEach((element) => {\n  bar(element);\n})",
+            "for (const element of foo) {\n  bar(element);\n}",
+            None,
+        ),
+        (
+            "foo.forEach(({ a, b }) => bar(a, b))",
+            "for (const { a, b } of foo) { bar(a, b); }",
+            None,
+        ),
+        ("foo.forEach(element => { element++ })", "for (let element of foo) { element++ }", None),
+        (
+            "(a, b).forEach(element => bar(element))",
+            "for (const element of (a, b)) { bar(element); }",
+            None,
+        ),
+        (
+            "foo.forEach(element => { if (element) return; bar(element) })",
+            "foo.forEach(element => { if (element) return; bar(element) })",
+            None,
+        ),
+        (
+            "foo.forEach(element => { var x = element })",
+            "foo.forEach(element => { var x = element })",
+            None,
+        ),
+        (
+            "foo.forEach((element, index) => bar(element, index))",
+            "foo.forEach((element, index) => bar(element, index))",
+            None,
+        ),
+        (
+            "foo.forEach(function (element) { bar(element) })",
+            "foo.forEach(function (element) { bar(element) })",
+            None,
+        ),
+        (
+            "element.forEach(element => bar(element))",
+            "element.forEach(element => bar(element))",
+            None,
+        ),
+        ("foo?.forEach(element => bar(element))", "foo?.forEach(element => bar(element))", None),
+        (
+            "x = foo.forEach(element => bar(element))",
+            "x = foo.forEach(element => bar(element))",
+            None,
+        ),
+        (
+            "() => foo.forEach(element => bar(element))",
+            "() => foo.forEach(element => bar(element))",
+            None,
+        ),
+    ];
+
+    Tester::new(NoArrayForEach::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -0,0 +1,133 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::CallPattern, AstNode};
+
+fn no_array_method_this_argument_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-unicorn(no-array-method-this-argument): Do not use the `this` argument in `{x1}()`."
+    ))
+    .with_help("Use an arrow function, or bind the callback with `.bind()`.")
+    .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoArrayMethodThisArgument;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows the `thisArg` argument of the array methods which take a callback, such as
+    /// `Array#map()` and `Array.from()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The argument is easy to miss, and does nothing for arrow functions. An arrow function
+    /// or `.bind()` makes the `this` of the callback clear.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// array.map(function (x) { return this.f(x) }, thisArg);
+    /// Array.from(items, function (x) { return this.f(x) }, thisArg);
+    ///
+    /// // Good
+    /// array.map((x) => thisArg.f(x));
+    /// Array.from(items, callback.bind(thisArg));
+    /// ```
+    NoArrayMethodThisArgument,
+    style
+);
+
+const METHODS: CallPattern<'static> = CallPattern::method(&[
+    "every",
+    "filter",
+    "find",
+    "findIndex",
+    "findLast",
+    "findLastIndex",
+    "flatMap",
+    "forEach",
+    "map",
+    "some",
+])
+.arguments(2..=2);
+
+const ARRAY_FROM: CallPattern<'static> =
+    CallPattern::method(&["from", "fromAsync"]).objects(&["Array"]).arguments(3..=3);
+
+/// The libraries whose methods have the same names, such as `_.map(array, fn)`.
+const IGNORED_OBJECTS: [&str; 6] = ["_", "lodash", "underscore", "$", "jQuery", "async"];
+
+impl Rule for NoArrayMethodThisArgument {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
+
+        let (name, this_argument) = if let Some(member_expr) = ARRAY_FROM.member(call_expr) {
+            let name = format!("Array.{}", member_expr.static_property_name().unwrap_or_default());
+            (name, &call_expr.arguments[2])
+        } else if let Some(member_expr) = METHODS.member(call_expr) {
+            if matches!(
+                member_expr.object().without_parenthesized(),
+                Expression::Identifier(ident) if IGNORED_OBJECTS.contains(&ident.name.as_str())
+            ) {
+                return;
+            }
+            let name = format!("Array#{}", member_expr.static_property_name().unwrap_or_default());
+            (name, &call_expr.arguments[1])
+        } else {
+            return;
+        };
+
+        ctx.diagnostic(no_array_method_this_argument_diagnostic(this_argument.span(), &name));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r"array.map(fn)",
+        r"array.map(x => x * 2)",
+        r"array.map(fn, thisArg, extra)",
+        r"array.map(...args)",
+        r"array.map(fn, ...args)",
+        r"array.map?.(fn, thisArg)",
+        r"array?.map(fn, thisArg)",
+        r"array['map'](fn, thisArg)",
+        r"new array.map(fn, thisArg)",
+        r"map(fn, thisArg)",
+        r"array.reduce(fn, initial)",
+        r"array.indexOf(item, fromIndex)",
+        r"_.map(array, fn)",
+        r"lodash.filter(array, fn)",
+        r"$.map(array, fn)",
+        r"Array.from(items)",
+        r"Array.from(items, fn)",
+        r"NotArray.from(items, fn, thisArg)",
+        r"Array.of(a, b, c)",
+    ];
+
+    let fail = vec![
+        r"array.map(fn, thisArg)",
+        r"array.every(fn, thisArg)",
+        r"array.filter(fn, thisArg)",
+        r"array.find(fn, thisArg)",
+        r"array.findIndex(fn, thisArg)",
+        r"array.findLast(fn, thisArg)",
+        r"array.findLastIndex(fn, thisArg)",
+        r"array.flatMap(fn, thisArg)",
+        r"array.forEach(fn, thisArg)",
+        r"array.some(fn, thisArg)",
+        r"array.map(function (x) { return this.f(x) }, this)",
+        r"Array.from(items, fn, thisArg)",
+        r"Array.fromAsync(items, fn, thisArg)",
+    ];
+
+    Tester::new(NoArrayMethodThisArgument::NAME, pass, fail).test_and_snapshot();
+}
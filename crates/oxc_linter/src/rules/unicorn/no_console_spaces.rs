@@ -133,7 +133,7 @@ fn report_diagnostic(
         literal_raw.trim().to_string()
     };
 
-    ctx.diagnostic_with_fix(no_console_spaces_diagnostic(direction, ident, span), || {
+    ctx.diagnostic_with_suggestion(no_console_spaces_diagnostic(direction, ident, span), || {
         Fix::new(fix, Span::new(start, end))
     });
 }
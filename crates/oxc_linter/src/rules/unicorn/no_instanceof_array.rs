@@ -44,7 +44,7 @@ impl Rule for NoInstanceofArray {
 
         match &expr.right.without_parenthesized() {
             Expression::Identifier(identifier) if identifier.name == "Array" => {
-                ctx.diagnostic_with_fix(no_instanceof_array_diagnostic(expr.span), || {
+                ctx.diagnostic_with_suggestion(no_instanceof_array_diagnostic(expr.span), || {
                     let modified_code = {
                         let mut codegen = String::new();
                         codegen.push_str("Array.isArray(");
@@ -89,7 +89,7 @@ fn diagnose_binary_expression(
     }
 
     // checkStrictEquality=true && `if (foo !== null) {}`
-    ctx.diagnostic_with_fix(replace_null_diagnostic(null_literal.span), || {
+    ctx.diagnostic_with_dangerous_fix(replace_null_diagnostic(null_literal.span), || {
         Fix::new("undefined", null_literal.span)
     });
 }
@@ -104,7 +104,7 @@ fn diagnose_variable_declarator(
     if matches!(&variable_declarator.init, Some(Expression::NullLiteral(expr)) if expr.span == null_literal.span)
         && matches!(parent_kind, Some(AstKind::VariableDeclaration(var_declaration)) if !var_declaration.kind.is_const() )
     {
-        ctx.diagnostic_with_fix(remove_null_diagnostic(null_literal.span), || {
+        ctx.diagnostic_with_dangerous_fix(remove_null_diagnostic(null_literal.span), || {
             Fix::delete(Span::new(variable_declarator.id.span().end, null_literal.span.end))
         });
 
@@ -112,7 +112,7 @@ fn diagnose_variable_declarator(
     }
 
     // `const foo = null`
-    ctx.diagnostic_with_fix(replace_null_diagnostic(null_literal.span), || {
+    ctx.diagnostic_with_dangerous_fix(replace_null_diagnostic(null_literal.span), || {
         Fix::new("undefined", null_literal.span)
     });
 }
@@ -201,15 +201,16 @@ impl Rule for NoNull {
 
             // `function foo() { return null; }`,
             if matches!(parent_node.kind(), AstKind::ReturnStatement(_)) {
-                ctx.diagnostic_with_fix(remove_null_diagnostic(null_literal.span), || {
-                    Fix::delete(null_literal.span)
-                });
+                ctx.diagnostic_with_dangerous_fix(
+                    remove_null_diagnostic(null_literal.span),
+                    || Fix::delete(null_literal.span),
+                );
 
                 return;
             }
         }
 
-        ctx.diagnostic_with_fix(replace_null_diagnostic(null_literal.span), || {
+        ctx.diagnostic_with_dangerous_fix(replace_null_diagnostic(null_literal.span), || {
             Fix::new("undefined", null_literal.span)
         });
     }
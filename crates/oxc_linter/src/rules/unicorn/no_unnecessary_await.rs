@@ -61,7 +61,7 @@ impl Rule for NoUnnecessaryAwait {
                     expr.span.start + 5,
                 )));
             } else {
-                ctx.diagnostic_with_fix(
+                ctx.diagnostic_with_suggestion(
                     no_unnecessary_await_diagnostic(Span::new(
                         expr.span.start,
                         expr.span.start + 5,
@@ -0,0 +1,154 @@
+use oxc_ast::{
+    ast::{CallExpression, Expression, MemberExpression, StaticMemberExpression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, utils::CallPattern, AstNode, Fix};
+
+fn prefer_array_find_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "eslint-plugin-unicorn(prefer-array-find): Prefer `.find(…)` over the first element from `.filter(…)`.",
+    )
+    .with_labels([span0.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferArrayFind;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Prefers [`Array#find()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/find) over the first element of the result of [`Array#filter()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/filter).
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `.find(…)` stops at the first element which matches, instead of creating an array of all
+    /// of them, and is easier to read.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// const item = array.filter(x => x.ok)[0];
+    /// const item = array.filter(x => x.ok).shift();
+    ///
+    /// // Good
+    /// const item = array.find(x => x.ok);
+    /// ```
+    ///
+    /// The suggestion calls the callback for fewer elements, up to the first one which matches.
+    PreferArrayFind,
+    pedantic
+);
+
+const FILTER: CallPattern<'static> = CallPattern::method(&["filter"]).arguments(1..=2);
+
+const SHIFT: CallPattern<'static> = CallPattern::method(&["shift"]).arguments(0..=0);
+
+impl Rule for PreferArrayFind {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            // `array.filter(fn)[0]`
+            AstKind::MemberExpression(MemberExpression::ComputedMemberExpression(member_expr)) => {
+                let is_first = matches!(
+                    &member_expr.expression,
+                    Expression::NumericLiteral(lit) if lit.raw == "0"
+                );
+                // `array.filter(fn)[0] = item`
+                let is_assigned = matches!(
+                    ctx.nodes().parent_kind(node.id()),
+                    Some(AstKind::SimpleAssignmentTarget(_))
+                );
+                if member_expr.optional || !is_first || is_assigned {
+                    return;
+                }
+                report(&member_expr.object, member_expr.span, ctx);
+            }
+            // `array.filter(fn).shift()`
+            AstKind::CallExpression(call_expr) => {
+                let Some(member_expr) = SHIFT.member(call_expr) else {
+                    return;
+                };
+                report(member_expr.object(), call_expr.span, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Report the `filter` call `object`, whose first element is read by the expression at `span`.
+fn report<'a>(object: &Expression<'a>, span: Span, ctx: &LintContext<'a>) {
+    let Some((call_expr, member_expr)) = filter_call(object) else {
+        return;
+    };
+    let property = member_expr.property.span;
+    ctx.diagnostic_with_suggestion(prefer_array_find_diagnostic(property), || {
+        let arguments = Span::new(property.end, call_expr.span.end);
+        Fix::new(
+            format!("find{}", arguments.source_text(ctx.source_text())),
+            Span::new(property.start, span.end),
+        )
+    });
+}
+
+/// `array.filter(fn)`, whose callee is not parenthesized, unlike `(array.filter)(fn)`.
+fn filter_call<'b, 'a>(
+    expr: &'b Expression<'a>,
+) -> Option<(&'b CallExpression<'a>, &'b StaticMemberExpression<'a>)> {
+    let Expression::CallExpression(call_expr) = expr else {
+        return None;
+    };
+    match &call_expr.callee {
+        Expression::StaticMemberExpression(member_expr) if FILTER.matches(call_expr) => {
+            Some((call_expr, member_expr))
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r"array.find(fn)",
+        r"array.filter(fn)",
+        r"array.filter(fn)[1]",
+        r"array.filter(fn)[index]",
+        r"array.filter(fn).at(-1)",
+        r"array.filter(fn).pop()",
+        r"array.filter(fn).shift(1)",
+        r"array.filter()[0]",
+        r"array.filter(fn, thisArg, extra)[0]",
+        r"array.filter(...args)[0]",
+        r"array.filter?.(fn)[0]",
+        r"array?.filter(fn)[0]",
+        r"array.filter(fn)?.[0]",
+        r"array['filter'](fn)[0]",
+        r"(array.filter(fn))[0]",
+        r"array.filter(fn)[0] = item",
+        r"array.filter(fn)[0]++",
+        r"filter(fn)[0]",
+    ];
+
+    let fail = vec![
+        r"array.filter(fn)[0]",
+        r"const item = array.filter(x => x.ok)[0];",
+        r"array.filter(fn, thisArg)[0]",
+        r"array.filter(fn).shift()",
+        r"foo(array.filter(fn).shift())",
+        r"a.b.filter(fn)[0].c",
+    ];
+
+    let fix = vec![
+        (r"array.filter(fn)[0]", r"array.find(fn)", None),
+        (r"const item = array.filter(x => x.ok)[0];", r"const item = array.find(x => x.ok);", None),
+        (r"array.filter(fn, thisArg)[0]", r"array.find(fn, thisArg)", None),
+        (r"array.filter(fn).shift()", r"array.find(fn)", None),
+        (r"a.b.filter(fn)[0].c", r"a.b.find(fn).c", None),
+    ];
+
+    Tester::new(PreferArrayFind::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
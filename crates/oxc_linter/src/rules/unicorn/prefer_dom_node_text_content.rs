@@ -44,9 +44,10 @@ impl Rule for PreferDomNodeTextContent {
         if let AstKind::MemberExpression(member_expr) = node.kind() {
             if let Some((span, name)) = member_expr.static_property_info() {
                 if name == "innerText" && !member_expr.is_computed() {
-                    ctx.diagnostic_with_fix(prefer_dom_node_text_content_diagnostic(span), || {
-                        Fix::new("textContent", span)
-                    });
+                    ctx.diagnostic_with_suggestion(
+                        prefer_dom_node_text_content_diagnostic(span),
+                        || Fix::new("textContent", span),
+                    );
                 }
             }
         }
@@ -8,7 +8,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_resolver::NODEJS_BUILTINS;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, AstNode, Fix};
 
 fn prefer_node_protocol_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-unicorn(prefer-node-protocol): Prefer using the `node:` protocol when importing Node.js builtin modules.")
@@ -31,6 +31,8 @@ declare_oxc_lint!(
     /// // Good
     /// import fs from "node:fs";
     /// ```
+    ///
+    /// The `node:` protocol is added to the specifier by the fix.
     PreferNodeProtocol,
     restriction
 );
@@ -74,7 +76,10 @@ impl Rule for PreferNodeProtocol {
             return;
         }
 
-        ctx.diagnostic(prefer_node_protocol_diagnostic(span, &string_lit_value));
+        ctx.diagnostic_with_fix(prefer_node_protocol_diagnostic(span, &string_lit_value), || {
+            let quote = &ctx.source_text()[span.start as usize..=span.start as usize];
+            Fix::new(format!("{quote}node:{string_lit_value}{quote}"), span)
+        });
     }
 }
 
@@ -119,5 +124,13 @@ fn test() {
         r"await import('assert/strict')",
     ];
 
-    Tester::new(PreferNodeProtocol::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r#"import fs from "fs";"#, r#"import fs from "node:fs";"#, None),
+        (r"import fs from 'fs/promises';", r"import fs from 'node:fs/promises';", None),
+        (r#"export {promises} from "fs";"#, r#"export {promises} from "node:fs";"#, None),
+        (r#"const fs = require("fs");"#, r#"const fs = require("node:fs");"#, None),
+        (r"await import('assert/strict')", r"await import('node:assert/strict')", None),
+    ];
+
+    Tester::new(PreferNodeProtocol::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
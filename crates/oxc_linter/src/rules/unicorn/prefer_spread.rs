@@ -159,7 +159,7 @@ impl Rule for PreferSpread {
                     return;
                 }
 
-                ctx.diagnostic_with_fix(
+                ctx.diagnostic_with_suggestion(
                     prefer_spread_diagnostic(call_expr.span, "string.split()"),
                     || {
                         let callee_obj = member_expr.object().without_parenthesized();
@@ -1,10 +1,13 @@
-use oxc_ast::{ast::MemberExpression, AstKind};
+use oxc_ast::{
+    ast::{Argument, CallExpression, MemberExpression},
+    AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::CallPattern, AstNode, Fix};
 
 fn prefer_string_slice_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!(
@@ -27,7 +30,16 @@ declare_oxc_lint!(
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
+    /// foo.substr(1, 2);
+    /// foo.substring(1, 3);
+    ///
+    /// // Good
+    /// foo.slice(1, 3);
     /// ```
+    ///
+    /// The calls without arguments, with the start only, or with non-negative integer literals
+    /// are fixed.
     PreferStringSlice,
     pedantic
 );
@@ -38,21 +50,65 @@ impl Rule for PreferStringSlice {
             return;
         };
 
-        let Some(member_expr) = call_expr.callee.get_member_expr() else {
+        let Some(MemberExpression::StaticMemberExpression(member_expr)) = SUBSTR.member(call_expr)
+        else {
             return;
         };
 
-        let (span, name) = match member_expr {
-            MemberExpression::StaticMemberExpression(v) => {
-                if !matches!(v.property.name.as_str(), "substr" | "substring") {
-                    return;
-                }
-                (v.property.span, &v.property.name)
-            }
-            _ => return,
+        let property = &member_expr.property;
+        let diagnostic = prefer_string_slice_diagnostic(property.span, property.name.as_str());
+        let Some(arguments) = slice_arguments(call_expr, property.name.as_str(), ctx) else {
+            ctx.diagnostic(diagnostic);
+            return;
         };
+        ctx.diagnostic_with_fix(diagnostic, || {
+            let optional = if call_expr.optional { "?." } else { "" };
+            Fix::new(
+                format!("slice{optional}({arguments})"),
+                Span::new(property.span.start, call_expr.span.end),
+            )
+        });
+    }
+}
+
+const SUBSTR: CallPattern<'static> =
+    CallPattern::method(&["substr", "substring"]).optional().spread();
 
-        ctx.diagnostic(prefer_string_slice_diagnostic(span, name.as_str()));
+/// The arguments of the `slice` call which is the same as the `substr` or `substring` call.
+fn slice_arguments(call_expr: &CallExpression, method: &str, ctx: &LintContext) -> Option<String> {
+    if call_expr.type_parameters.is_some() {
+        return None;
+    }
+    match (method, call_expr.arguments.as_slice()) {
+        (_, []) => Some(String::new()),
+        // `substr` and `slice` both count a negative start from the end.
+        ("substr", [start]) => {
+            let start = start.as_expression()?;
+            Some(start.span().source_text(ctx.source_text()).to_string())
+        }
+        ("substring", [start]) => non_negative_integer(start).map(|start| start.to_string()),
+        ("substr", [start, length]) => {
+            let start = non_negative_integer(start)?;
+            let length = non_negative_integer(length)?;
+            Some(format!("{start}, {}", start + length))
+        }
+        // `substring` swaps a start after the end.
+        ("substring", [start, end]) => {
+            let start = non_negative_integer(start)?;
+            let end = non_negative_integer(end)?;
+            Some(format!("{}, {}", start.min(end), start.max(end)))
+        }
+        _ => None,
+    }
+}
+
+#[allow(clippy::float_cmp)]
+fn non_negative_integer(argument: &Argument) -> Option<f64> {
+    match argument {
+        Argument::NumericLiteral(lit) if lit.value >= 0.0 && lit.value.fract() == 0.0 => {
+            Some(lit.value)
+        }
+        _ => None,
     }
 }
 
@@ -125,5 +181,20 @@ fn test() {
         r"foo.substring((10, bar))",
     ];
 
-    Tester::new(PreferStringSlice::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("foo.substr()", "foo.slice()", None),
+        ("foo?.substring?.()", "foo?.slice?.()", None),
+        ("foo.substr(start)", "foo.slice(start)", None),
+        ("foo.substr(-1)", "foo.slice(-1)", None),
+        ("foo.substr(1, 2)", "foo.slice(1, 3)", None),
+        ("foo.substring(1)", "foo.slice(1)", None),
+        ("foo.substring(1, 3)", "foo.slice(1, 3)", None),
+        ("foo.substring(3, 1)", "foo.slice(1, 3)", None),
+        ("foo.substring(start)", "foo.substring(start)", None),
+        ("foo.substring(-1, 2)", "foo.substring(-1, 2)", None),
+        ("foo.substr(start, length)", "foo.substr(start, length)", None),
+        ("foo.substr(...args)", "foo.substr(...args)", None),
+    ];
+
+    Tester::new(PreferStringSlice::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -20,7 +20,7 @@ use oxc_span::{SourceType, VALID_EXTENSIONS};
 
 use crate::{
     partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
-    Fixer, LintContext, Linter, Message,
+    FilePatch, Fixer, LintContext, Linter, Message,
 };
 
 pub struct LintServiceOptions {
@@ -59,6 +59,15 @@ impl LintService {
         self.runtime.module_map.len() - self.runtime.paths.len()
     }
 
+    /// Take the changes collected by `fix_dry_run`, sorted by path.
+    ///
+    /// # Panics
+    pub fn take_patches(&self) -> Vec<FilePatch> {
+        let mut patches = std::mem::take(&mut *self.runtime.patches.lock().unwrap());
+        patches.sort_by(|a, b| a.path().cmp(b.path()));
+        patches
+    }
+
     /// # Panics
     pub fn run(&self, tx_error: &DiagnosticSender) {
         self.runtime
@@ -131,6 +140,8 @@ pub struct Runtime {
     resolver: Option<Resolver>,
     module_map: ModuleMap,
     cache_state: CacheState,
+    /// Changes which are not written with `fix_dry_run`.
+    patches: Mutex<Vec<FilePatch>>,
 }
 
 impl Runtime {
@@ -145,6 +156,7 @@ impl Runtime {
             resolver,
             module_map: ModuleMap::default(),
             cache_state: CacheState::default(),
+            patches: Mutex::default(),
         }
     }
 
@@ -228,10 +240,21 @@ impl Runtime {
                 self.process_source(path, &allocator, source_text, source_type, true, tx_error);
 
             // TODO: Span is wrong, ban this feature for file process by `PartialLoader`.
-            if !is_processed_by_partial_loader && self.linter.options().fix {
-                let fix_result = Fixer::new(source_text, messages).fix();
-                fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-                messages = fix_result.messages;
+            let options = self.linter.options();
+            if !is_processed_by_partial_loader && !options.enabled_fixes().is_empty() {
+                if options.fix_dry_run {
+                    // Nothing is written, so the fixed issues are still reported.
+                    let fix_result = Fixer::new(source_text, messages.clone()).fix();
+                    if fix_result.fixed {
+                        let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+                        let file_patch = FilePatch::new(path, source_text, &fix_result);
+                        self.patches.lock().unwrap().push(file_patch);
+                    }
+                } else {
+                    let fix_result = Fixer::new(source_text, messages).fix();
+                    fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
+                    messages = fix_result.messages;
+                }
             }
 
             if !messages.is_empty() {
@@ -9,8 +9,8 @@ use serde::Deserialize;
 use serde_json::Value;
 
 use crate::{
-    rules::RULES, AllowWarnDeny, Fixer, LintOptions, LintService, LintServiceOptions, Linter,
-    OxlintConfig, RuleEnum, RuleWithSeverity,
+    rules::RULES, AllowWarnDeny, FixKind, Fixer, LintOptions, LintService, LintServiceOptions,
+    Linter, OxlintConfig, RuleEnum, RuleWithSeverity,
 };
 
 #[derive(Eq, PartialEq)]
@@ -78,6 +78,7 @@ pub struct Tester {
     jsx_a11y_plugin: bool,
     nextjs_plugin: bool,
     react_perf_plugin: bool,
+    promise_plugin: bool,
 }
 
 impl Tester {
@@ -104,6 +105,7 @@ impl Tester {
             jsx_a11y_plugin: false,
             nextjs_plugin: false,
             react_perf_plugin: false,
+            promise_plugin: false,
         }
     }
 
@@ -138,6 +140,11 @@ impl Tester {
         self
     }
 
+    pub fn with_promise_plugin(mut self, yes: bool) -> Self {
+        self.promise_plugin = yes;
+        self
+    }
+
     pub fn expect_fix<S: Into<String>>(mut self, expect_fix: Vec<(S, S, Option<Value>)>) -> Self {
         self.expect_fix =
             expect_fix.into_iter().map(|(s1, s2, r)| (s1.into(), s2.into(), r)).collect::<Vec<_>>();
@@ -200,12 +207,13 @@ impl Tester {
         let allocator = Allocator::default();
         let rule = self.find_rule().read_json(rule_config.unwrap_or_default());
         let options = LintOptions::default()
-            .with_fix(is_fix)
+            .with_fix(if is_fix { FixKind::all() } else { FixKind::empty() })
             .with_import_plugin(self.import_plugin)
             .with_jest_plugin(self.jest_plugin)
             .with_jsx_a11y_plugin(self.jsx_a11y_plugin)
             .with_nextjs_plugin(self.nextjs_plugin)
-            .with_react_perf_plugin(self.react_perf_plugin);
+            .with_react_perf_plugin(self.react_perf_plugin)
+            .with_promise_plugin(self.promise_plugin);
         let eslint_config = eslint_config
             .as_ref()
             .map_or_else(OxlintConfig::default, |v| OxlintConfig::deserialize(v).unwrap());
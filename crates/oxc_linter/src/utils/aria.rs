@@ -0,0 +1,349 @@
+//! ARIA metadata shared by the `jsx-a11y` rules.
+//!
+//! The roles and properties of [WAI-ARIA 1.2](https://www.w3.org/TR/wai-aria-1.2/), the
+//! [DPUB-ARIA](https://www.w3.org/TR/dpub-aria-1.0/) and
+//! [Graphics ARIA](https://www.w3.org/TR/graphics-aria-1.0/) modules, and whether a role or an
+//! HTML element is interactive, like [aria-query](https://github.com/A11yance/aria-query).
+
+use oxc_ast::ast::JSXOpeningElement;
+use phf::phf_set;
+
+use super::{get_string_literal_prop_value, has_jsx_prop};
+
+/// set of valid ARIA properties from the WAI-ARIA 1.1 specifications.
+/// Reference: <https://www.w3.org/TR/wai-aria/#state_prop_def>
+pub const VALID_ARIA_PROPS: phf::Set<&'static str> = phf_set! {
+    "aria-activedescendant",
+    "aria-atomic",
+    "aria-autocomplete",
+    "aria-busy",
+    "aria-checked",
+    "aria-colcount",
+    "aria-colindex",
+    "aria-colspan",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-details",
+    "aria-disabled",
+    "aria-dropeffect",
+    "aria-errormessage",
+    "aria-expanded",
+    "aria-flowto",
+    "aria-grabbed",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-level",
+    "aria-live",
+    "aria-modal",
+    "aria-multiline",
+    "aria-multiselectable",
+    "aria-orientation",
+    "aria-owns",
+    "aria-placeholder",
+    "aria-posinset",
+    "aria-pressed",
+    "aria-readonly",
+    "aria-relevant",
+    "aria-required",
+    "aria-roledescription",
+    "aria-rowcount",
+    "aria-rowindex",
+    "aria-rowspan",
+    "aria-selected",
+    "aria-setsize",
+    "aria-sort",
+    "aria-valuemax",
+    "aria-valuemin",
+    "aria-valuenow",
+    "aria-valuetext"
+};
+
+/// set of valid ARIA role definitions
+/// Reference: <https://www.w3.org/TR/wai-aria/#role_definitions>
+/// Reference: <https://github.com/A11yance/aria-query/blob/main/src/rolesMap.js>
+pub const VALID_ARIA_ROLES: phf::Set<&'static str> = phf_set! {
+  "alert",
+  "alertdialog",
+  "application",
+  "article",
+  "banner",
+  "blockquote",
+  "button",
+  "caption",
+  "cell",
+  "checkbox",
+  "code",
+  "columnheader",
+  "combobox",
+  "complementary",
+  "contentinfo",
+  "definition",
+  "deletion",
+  "dialog",
+  "directory",
+  "document",
+  "emphasis",
+  "feed",
+  "figure",
+  "form",
+  "generic",
+  "grid",
+  "gridcell",
+  "group",
+  "heading",
+  "img",
+  "insertion",
+  "link",
+  "list",
+  "listbox",
+  "listitem",
+  "log",
+  "main",
+  "mark",
+  "marquee",
+  "math",
+  "menu",
+  "menubar",
+  "menuitem",
+  "menuitemcheckbox",
+  "menuitemradio",
+  "meter",
+  "navigation",
+  "none",
+  "note",
+  "option",
+  "paragraph",
+  "presentation",
+  "progressbar",
+  "radio",
+  "radiogroup",
+  "region",
+  "row",
+  "rowgroup",
+  "rowheader",
+  "scrollbar",
+  "search",
+  "searchbox",
+  "separator",
+  "slider",
+  "spinbutton",
+  "status",
+  "strong",
+  "subscript",
+  "superscript",
+  "switch",
+  "tab",
+  "table",
+  "tablist",
+  "tabpanel",
+  "term",
+  "textbox",
+  "time",
+  "timer",
+  "toolbar",
+  "tooltip",
+  "tree",
+  "treegrid",
+  "treeitem",
+  "doc-abstract",
+  "doc-acknowledgments",
+  "doc-afterword",
+  "doc-appendix",
+  "doc-backlink",
+  "doc-biblioentry",
+  "doc-bibliography",
+  "doc-biblioref",
+  "doc-chapter",
+  "doc-colophon",
+  "doc-conclusion",
+  "doc-cover",
+  "doc-credit",
+  "doc-credits",
+  "doc-dedication",
+  "doc-endnote",
+  "doc-endnotes",
+  "doc-epigraph",
+  "doc-epilogue",
+  "doc-errata",
+  "doc-example",
+  "doc-footnote",
+  "doc-foreword",
+  "doc-glossary",
+  "doc-glossref",
+  "doc-index",
+  "doc-introduction",
+  "doc-noteref",
+  "doc-notice",
+  "doc-pagebreak",
+  "doc-pagelist",
+  "doc-part",
+  "doc-preface",
+  "doc-prologue",
+  "doc-pullquote",
+  "doc-qna",
+  "doc-subtitle",
+  "doc-tip",
+  "doc-toc",
+  "graphics-document",
+  "graphics-object",
+  "graphics-symbol"
+};
+
+/// Roles which are only used to organize the taxonomy of ARIA, and must not be used in content.
+/// Reference: <https://www.w3.org/TR/wai-aria/#abstract_roles>
+pub const ABSTRACT_ARIA_ROLES: phf::Set<&'static str> = phf_set! {
+    "command",
+    "composite",
+    "input",
+    "landmark",
+    "range",
+    "roletype",
+    "section",
+    "sectionhead",
+    "select",
+    "structure",
+    "widget",
+    "window",
+};
+
+/// Roles of widgets, which the user interacts with, and `toolbar`.
+/// Reference: <https://www.w3.org/TR/wai-aria/#widget_roles>
+pub const INTERACTIVE_ARIA_ROLES: phf::Set<&'static str> = phf_set! {
+    "button",
+    "checkbox",
+    "columnheader",
+    "combobox",
+    "doc-backlink",
+    "doc-biblioref",
+    "doc-glossref",
+    "doc-noteref",
+    "grid",
+    "gridcell",
+    "link",
+    "listbox",
+    "menu",
+    "menubar",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "option",
+    "radio",
+    "radiogroup",
+    "row",
+    "rowheader",
+    "scrollbar",
+    "searchbox",
+    "separator",
+    "slider",
+    "spinbutton",
+    "switch",
+    "tab",
+    "tablist",
+    "textbox",
+    "toolbar",
+    "tree",
+    "treegrid",
+    "treeitem",
+};
+
+/// HTML elements with an implicit role which is not interactive, such as `<article>` or `<li>`.
+/// Reference: <https://www.w3.org/TR/html-aria/#docconformance>
+pub const NON_INTERACTIVE_HTML_ELEMENTS: phf::Set<&'static str> = phf_set! {
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "caption",
+    "code",
+    "dd",
+    "del",
+    "dfn",
+    "dialog",
+    "dir",
+    "dl",
+    "dt",
+    "em",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "html",
+    "img",
+    "ins",
+    "legend",
+    "li",
+    "main",
+    "mark",
+    "marquee",
+    "menu",
+    "meter",
+    "nav",
+    "ol",
+    "optgroup",
+    "output",
+    "p",
+    "pre",
+    "progress",
+    "section",
+    "strong",
+    "sub",
+    "sup",
+    "table",
+    "tbody",
+    "td",
+    "tfoot",
+    "th",
+    "thead",
+    "time",
+    "tr",
+    "ul",
+};
+
+/// A role of an element which the user interacts with, e.g. `button` or `textbox`.
+pub fn is_interactive_role(role: &str) -> bool {
+    INTERACTIVE_ARIA_ROLES.contains(role)
+}
+
+/// A concrete role of an element which the user does not interact with, e.g. `article` or
+/// `listitem`. `presentation`, `none` and `generic` remove the semantics of the element instead.
+pub fn is_non_interactive_role(role: &str) -> bool {
+    VALID_ARIA_ROLES.contains(role)
+        && !INTERACTIVE_ARIA_ROLES.contains(role)
+        && !matches!(role, "presentation" | "none" | "generic")
+}
+
+pub fn is_abstract_role(role: &str) -> bool {
+    ABSTRACT_ARIA_ROLES.contains(role)
+}
+
+/// The first role of the `role` prop, which is a space separated list of roles from the most to
+/// the least specific one, or `None` if the prop is missing or not a string literal.
+pub fn get_explicit_role<'a>(jsx_opening_el: &'a JSXOpeningElement<'_>) -> Option<&'a str> {
+    has_jsx_prop(jsx_opening_el, "role")
+        .and_then(get_string_literal_prop_value)
+        .and_then(|role| role.split_ascii_whitespace().next())
+}
+
+/// An element with an implicit role which is not interactive, e.g. `<li>` or `<img>` without
+/// `usemap`. See [`is_interactive_element`](super::is_interactive_element) for the elements
+/// which are interactive.
+pub fn is_non_interactive_element(element_type: &str, jsx_opening_el: &JSXOpeningElement) -> bool {
+    match element_type {
+        "img" => has_jsx_prop(jsx_opening_el, "usemap").is_none(),
+        element_type => NON_INTERACTIVE_HTML_ELEMENTS.contains(element_type),
+    }
+}
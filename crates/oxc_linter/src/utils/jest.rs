@@ -8,17 +8,18 @@ use oxc_ast::{
     AstKind,
 };
 use oxc_semantic::{AstNode, ReferenceId};
-use oxc_span::Atom;
 use phf::phf_set;
 
 use crate::LintContext;
 
 mod parse_jest_fn;
+mod test_structure;
 pub use crate::utils::jest::parse_jest_fn::{
     parse_jest_fn_call, ExpectError, KnownMemberExpressionParentKind,
     KnownMemberExpressionProperty, MemberExpressionElement, ParsedExpectFnCall,
     ParsedGeneralJestFnCall, ParsedJestFnCall as ParsedJestFnCallNew,
 };
+pub use crate::utils::jest::test_structure::{TestBlock, TestStructure};
 
 const JEST_METHOD_NAMES: phf::Set<&'static str> = phf_set![
     "afterAll",
@@ -38,6 +39,9 @@ const JEST_METHOD_NAMES: phf::Set<&'static str> = phf_set![
     "pending"
 ];
 
+/// The modules which export the test functions.
+const TEST_FRAMEWORK_MODULES: [&str; 2] = ["@jest/globals", "vitest"];
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum JestFnKind {
     Expect,
@@ -135,7 +139,7 @@ pub fn parse_expect_jest_fn_call<'a>(
 
 pub struct PossibleJestNode<'a, 'b> {
     pub node: &'b AstNode<'a>,
-    pub original: Option<&'a Atom<'a>>, // if this node is imported from 'jest/globals', this field will be Some(original_name), otherwise None
+    pub original: Option<&'a str>, // if this node is imported from '@jest/globals' or 'vitest', or is an alias in `settings.jest.globalAliases`, this field will be Some(original_name), otherwise None
 }
 
 /// Collect all possible Jest fn Call Expression,
@@ -152,17 +156,7 @@ pub fn collect_possible_jest_call_node<'a, 'b>(
     // });
     // ```
     let mut reference_id_with_original_list = collect_ids_referenced_to_import(ctx);
-    if JEST_METHOD_NAMES
-        .iter()
-        .any(|name| ctx.scopes().root_unresolved_references().contains_key(*name))
-    {
-        reference_id_with_original_list.extend(
-            collect_ids_referenced_to_global(ctx)
-                .iter()
-                // set the original of global test function to None
-                .map(|id| (*id, None)),
-        );
-    }
+    reference_id_with_original_list.extend(collect_ids_referenced_to_global(ctx));
 
     // get the longest valid chain of Jest Call Expression
     reference_id_with_original_list.iter().fold(vec![], |mut acc, id_with_original| {
@@ -194,11 +188,10 @@ pub fn collect_possible_jest_call_node<'a, 'b>(
 
 fn collect_ids_referenced_to_import<'a, 'b>(
     ctx: &'b LintContext<'a>,
-) -> Vec<(ReferenceId, Option<&'a Atom<'a>>)> {
+) -> Vec<(ReferenceId, Option<&'a str>)> {
     ctx.symbols()
-        .resolved_references
-        .iter_enumerated()
-        .filter_map(|(symbol_id, reference_ids)| {
+        .iter()
+        .filter_map(|symbol_id| {
             if ctx.symbols().get_flag(symbol_id).is_import_binding() {
                 let id = ctx.symbols().get_declaration(symbol_id);
                 let Some(AstKind::ImportDeclaration(import_decl)) = ctx.nodes().parent_kind(id)
@@ -207,12 +200,13 @@ fn collect_ids_referenced_to_import<'a, 'b>(
                 };
                 let name = ctx.symbols().get_name(symbol_id);
 
-                if import_decl.source.value == "@jest/globals" {
+                if TEST_FRAMEWORK_MODULES.contains(&import_decl.source.value.as_str()) {
                     let original = find_original_name(import_decl, name);
-                    let mut ret = vec![];
-                    for reference_id in reference_ids {
-                        ret.push((*reference_id, original));
-                    }
+                    let ret = ctx
+                        .symbols()
+                        .get_resolved_reference_ids(symbol_id)
+                        .map(|reference_id| (reference_id, original))
+                        .collect::<Vec<_>>();
 
                     return Some(ret);
                 }
@@ -221,18 +215,19 @@ fn collect_ids_referenced_to_import<'a, 'b>(
             None
         })
         .flatten()
-        .collect::<Vec<(ReferenceId, Option<&'a Atom<'a>>)>>()
+        .collect::<Vec<(ReferenceId, Option<&'a str>)>>()
 }
 
 /// Find name in the Import Declaration, not use name because of lifetime not long enough.
-fn find_original_name<'a>(
-    import_decl: &'a ImportDeclaration<'a>,
-    name: &str,
-) -> Option<&'a Atom<'a>> {
+fn find_original_name<'a>(import_decl: &'a ImportDeclaration<'a>, name: &str) -> Option<&'a str> {
     import_decl.specifiers.iter().flatten().find_map(|specifier| match specifier {
         ImportDeclarationSpecifier::ImportSpecifier(import_specifier) => {
             if import_specifier.local.name.as_str() == name {
-                return Some(import_specifier.imported.name());
+                // `suite` is the vitest alias of `describe`
+                return match import_specifier.imported.name().as_str() {
+                    "suite" => Some("describe"),
+                    imported => Some(imported),
+                };
             }
             None
         }
@@ -240,13 +235,26 @@ fn find_original_name<'a>(
     })
 }
 
-fn collect_ids_referenced_to_global(ctx: &LintContext) -> Vec<ReferenceId> {
+/// Collect the references to global test functions, with the original name of the aliases in
+/// `settings.jest.globalAliases`.
+fn collect_ids_referenced_to_global<'a>(
+    ctx: &LintContext<'a>,
+) -> Vec<(ReferenceId, Option<&'a str>)> {
+    let settings = &ctx.settings().jest;
     ctx.scopes()
         .root_unresolved_references()
         .iter()
-        .filter(|(name, _)| JEST_METHOD_NAMES.contains(name.as_str()))
-        .flat_map(|(_, reference_ids)| reference_ids.clone())
-        .collect::<Vec<ReferenceId>>()
+        .filter_map(|(name, reference_ids)| {
+            let original = if JEST_METHOD_NAMES.contains(name.as_str()) {
+                None
+            } else {
+                let original = settings.resolve_global_alias(name.as_str())?;
+                Some(*JEST_METHOD_NAMES.get_key(original)?)
+            };
+            Some(reference_ids.iter().map(move |reference_id| (*reference_id, original)))
+        })
+        .flatten()
+        .collect::<Vec<(ReferenceId, Option<&'a str>)>>()
 }
 
 /// join name of the expression. e.g.
@@ -8,7 +8,7 @@ use oxc_ast::{
     AstKind,
 };
 use oxc_semantic::AstNode;
-use oxc_span::{Atom, Span};
+use oxc_span::Span;
 
 use crate::context::LintContext;
 
@@ -53,7 +53,7 @@ pub fn parse_jest_fn_call<'a>(
             return None;
         }
 
-        let name = resolved.original.unwrap_or(resolved.local).as_str();
+        let name = resolved.original.unwrap_or(resolved.local);
         let kind = JestFnKind::from(name);
         let mut members = Vec::new();
         let mut iter = chain.into_iter();
@@ -260,7 +260,7 @@ pub struct ExpectFnCallOptions<'a, 'b> {
     pub call_expr: &'a CallExpression<'a>,
     pub members: Vec<KnownMemberExpressionProperty<'a>>,
     pub name: &'a str,
-    pub local: &'a Atom<'a>,
+    pub local: &'a str,
     pub head: KnownMemberExpressionProperty<'a>,
     pub node: &'b AstNode<'a>,
     pub ctx: &'b LintContext<'a>,
@@ -287,10 +287,10 @@ fn is_valid_jest_call(members: &[Cow<str>]) -> bool {
 
 fn resolve_to_jest_fn<'a>(
     call_expr: &'a CallExpression<'a>,
-    original: Option<&'a Atom<'a>>,
+    original: Option<&'a str>,
 ) -> Option<ResolvedJestFn<'a>> {
     let ident = resolve_first_ident(&call_expr.callee)?;
-    Some(ResolvedJestFn { local: &ident.name, original })
+    Some(ResolvedJestFn { local: ident.name.as_str(), original })
 }
 
 fn resolve_first_ident<'a>(expr: &'a Expression<'a>) -> Option<&'a IdentifierReference<'a>> {
@@ -353,8 +353,8 @@ impl<'a> ParsedExpectFnCall<'a> {
 }
 
 struct ResolvedJestFn<'a> {
-    pub local: &'a Atom<'a>,
-    pub original: Option<&'a Atom<'a>>,
+    pub local: &'a str,
+    pub original: Option<&'a str>,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -523,17 +523,21 @@ fn get_node_chain<'a>(params: &NodeChainParams<'a>) -> Vec<KnownMemberExpression
 }
 
 // sorted list for binary search.
-const VALID_JEST_FN_CALL_CHAINS: [[&str; 4]; 51] = [
+const VALID_JEST_FN_CALL_CHAINS: [[&str; 4]; 59] = [
     ["afterAll", "", "", ""],
     ["afterEach", "", "", ""],
     ["beforeAll", "", "", ""],
     ["beforeEach", "", "", ""],
     ["describe", "", "", ""],
+    ["describe", "concurrent", "", ""],
     ["describe", "each", "", ""],
     ["describe", "only", "", ""],
     ["describe", "only", "each", ""],
+    ["describe", "sequential", "", ""],
+    ["describe", "shuffle", "", ""],
     ["describe", "skip", "", ""],
     ["describe", "skip", "each", ""],
+    ["describe", "todo", "", ""],
     ["fdescribe", "", "", ""],
     ["fdescribe", "each", "", ""],
     ["fit", "", "", ""],
@@ -546,9 +550,11 @@ const VALID_JEST_FN_CALL_CHAINS: [[&str; 4]; 51] = [
     ["it", "concurrent", "skip", "each"],
     ["it", "each", "", ""],
     ["it", "failing", "", ""],
+    ["it", "fails", "", ""],
     ["it", "only", "", ""],
     ["it", "only", "each", ""],
     ["it", "only", "failing", ""],
+    ["it", "sequential", "", ""],
     ["it", "skip", "", ""],
     ["it", "skip", "each", ""],
     ["it", "skip", "failing", ""],
@@ -560,9 +566,11 @@ const VALID_JEST_FN_CALL_CHAINS: [[&str; 4]; 51] = [
     ["test", "concurrent", "skip", "each"],
     ["test", "each", "", ""],
     ["test", "failing", "", ""],
+    ["test", "fails", "", ""],
     ["test", "only", "", ""],
     ["test", "only", "each", ""],
     ["test", "only", "failing", ""],
+    ["test", "sequential", "", ""],
     ["test", "skip", "", ""],
     ["test", "skip", "each", ""],
     ["test", "skip", "failing", ""],
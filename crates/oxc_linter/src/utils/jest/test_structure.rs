@@ -0,0 +1,88 @@
+use oxc_ast::{ast::Argument, AstKind};
+use oxc_semantic::{AstNode, AstNodeId};
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    utils::jest::{
+        collect_possible_jest_call_node, parse_general_jest_fn_call, JestGeneralFnKind,
+        ParsedGeneralJestFnCall,
+    },
+    LintContext,
+};
+
+/// A `describe` or test block, e.g. `it('works', () => {})`.
+pub struct TestBlock<'a, 'b> {
+    /// The call expression of the block.
+    pub node: &'b AstNode<'a>,
+    pub call: ParsedGeneralJestFnCall<'a>,
+    /// `JestGeneralFnKind::Describe` or `JestGeneralFnKind::Test`.
+    pub kind: JestGeneralFnKind,
+    /// The title and its span, if it is a string or a template literal without expressions.
+    pub title: Option<(&'a str, Span)>,
+    /// The index of the innermost `describe` block which contains this block.
+    pub parent: Option<usize>,
+}
+
+impl<'a, 'b> TestBlock<'a, 'b> {
+    /// `describe.only`, `fit`, etc.
+    pub fn is_focused(&self) -> bool {
+        self.call.name.starts_with('f')
+            || self.call.members.iter().any(|member| member.is_name_equal("only"))
+    }
+
+    /// `it.each(table)(title, fn)`, whose title is a format of the titles of its tests.
+    pub fn is_each(&self) -> bool {
+        self.call.members.iter().any(|member| member.is_name_equal("each"))
+    }
+}
+
+/// The tree of the `describe` and test blocks of a file, in source order.
+pub struct TestStructure<'a, 'b> {
+    blocks: Vec<TestBlock<'a, 'b>>,
+}
+
+impl<'a, 'b> TestStructure<'a, 'b> {
+    pub fn new(ctx: &'b LintContext<'a>) -> Self {
+        let mut blocks = collect_possible_jest_call_node(ctx)
+            .iter()
+            .filter_map(|possible_jest_node| {
+                let node = possible_jest_node.node;
+                let AstKind::CallExpression(call_expr) = node.kind() else { return None };
+                let call = parse_general_jest_fn_call(call_expr, possible_jest_node, ctx)?;
+                let kind = call.kind.to_general()?;
+                if !matches!(kind, JestGeneralFnKind::Describe | JestGeneralFnKind::Test) {
+                    return None;
+                }
+                let title = match call_expr.arguments.first() {
+                    Some(Argument::StringLiteral(string_lit)) => {
+                        Some((string_lit.value.as_str(), string_lit.span))
+                    }
+                    Some(Argument::TemplateLiteral(template_lit)) => {
+                        template_lit.quasi().map(|quasi| (quasi.as_str(), template_lit.span))
+                    }
+                    _ => None,
+                };
+                Some(TestBlock { node, call, kind, title, parent: None })
+            })
+            .collect::<Vec<_>>();
+        // Node ids are assigned in source order, so a block comes after the blocks around it.
+        blocks.sort_by_key(|block| block.node.id());
+        blocks.dedup_by_key(|block| block.node.id());
+
+        let mut describes = FxHashMap::<AstNodeId, usize>::default();
+        for (index, block) in blocks.iter_mut().enumerate() {
+            let node_id = block.node.id();
+            block.parent =
+                ctx.nodes().ancestors(node_id).skip(1).find_map(|id| describes.get(&id).copied());
+            if block.kind == JestGeneralFnKind::Describe {
+                describes.insert(node_id, index);
+            }
+        }
+        Self { blocks }
+    }
+
+    pub fn blocks(&self) -> &[TestBlock<'a, 'b>] {
+        &self.blocks
+    }
+}
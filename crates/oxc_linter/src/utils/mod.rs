@@ -1,11 +1,15 @@
+mod aria;
 mod jest;
 mod jsdoc;
 mod nextjs;
+mod promise;
 mod react;
 mod react_perf;
+mod secrets;
 mod tree_shaking;
 mod unicorn;
 
 pub use self::{
-    jest::*, jsdoc::*, nextjs::*, react::*, react_perf::*, tree_shaking::*, unicorn::*,
+    aria::*, jest::*, jsdoc::*, nextjs::*, promise::*, react::*, react_perf::*, secrets::*,
+    tree_shaking::*, unicorn::*,
 };
@@ -0,0 +1,33 @@
+use oxc_ast::{ast::CallExpression, AstKind};
+use oxc_span::Span;
+
+use crate::{context::LintContext, AstNode};
+
+/// If `call` calls a promise method, `.then()`, `.catch()` or `.finally()`, returns the span and
+/// name of the method.
+pub fn get_promise_method<'b>(call: &'b CallExpression) -> Option<(Span, &'b str)> {
+    let (span, name) = call.callee.get_member_expr()?.static_property_info()?;
+    matches!(name, "then" | "catch" | "finally").then_some((span, name))
+}
+
+/// If `node` is a function passed as an argument to `.then()` or `.catch()`, returns that call.
+pub fn get_promise_callback_call<'a, 'b>(
+    node: &'b AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b CallExpression<'a>> {
+    if !matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)) {
+        return None;
+    }
+    let parent = ctx.nodes().iter_parents(node.id()).skip(1).find(|parent| {
+        !matches!(parent.kind(), AstKind::Argument(_) | AstKind::ParenthesizedExpression(_))
+    })?;
+    let AstKind::CallExpression(call) = parent.kind() else { return None };
+    get_promise_method(call).filter(|(_, name)| *name != "finally").map(|_| call)
+}
+
+/// Whether `node` is awaited or yielded, e.g. `await p.then(f)`.
+pub fn is_inside_await_or_yield(node: &AstNode, ctx: &LintContext) -> bool {
+    ctx.nodes().iter_parents(node.id()).any(|parent| {
+        matches!(parent.kind(), AstKind::AwaitExpression(_) | AstKind::YieldExpression(_))
+    })
+}
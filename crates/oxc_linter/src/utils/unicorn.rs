@@ -1,7 +1,8 @@
 mod boolean;
+mod call;
 use crate::LintContext;
 
-pub use self::boolean::*;
+pub use self::{boolean::*, call::*};
 use oxc_ast::{
     ast::{
         BindingPatternKind, Expression, FormalParameters, FunctionBody, LogicalExpression,
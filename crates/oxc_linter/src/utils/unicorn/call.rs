@@ -0,0 +1,131 @@
+use std::ops::RangeInclusive;
+
+use oxc_ast::ast::{Argument, CallExpression, Expression, MemberExpression};
+
+/// A pattern of calls to specific functions or methods, like `isCallExpression` and
+/// `isMethodCall` of eslint-plugin-unicorn.
+///
+/// A pattern never matches computed members, and by default does not match optional calls and
+/// members or spread arguments, whose arguments and callee can not be reasoned about.
+///
+/// ```ignore
+/// // `foo.forEach(callback)` and `foo.forEach(callback, thisArg)`
+/// CallPattern::method(&["forEach"]).arguments(1..=2).matches(call_expr);
+/// // `Array.from(items)`, but not `Array?.from(items)`
+/// CallPattern::method(&["from"]).objects(&["Array"]).matches(call_expr);
+/// // `require("fs")`
+/// CallPattern::function(&["require"]).arguments(1..=1).matches(call_expr);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CallPattern<'p> {
+    /// The names of the functions, or of the methods.
+    names: &'p [&'p str],
+    method: bool,
+    objects: Option<&'p [&'p str]>,
+    min_arguments: usize,
+    max_arguments: usize,
+    optional: bool,
+    spread: bool,
+}
+
+impl<'p> CallPattern<'p> {
+    /// Calls to one of the functions `names`, e.g. `require()`.
+    pub const fn function(names: &'p [&'p str]) -> Self {
+        Self {
+            names,
+            method: false,
+            objects: None,
+            min_arguments: 0,
+            max_arguments: usize::MAX,
+            optional: false,
+            spread: false,
+        }
+    }
+
+    /// Calls to one of the methods `names` of any object, e.g. `foo.forEach()`.
+    pub const fn method(names: &'p [&'p str]) -> Self {
+        Self { method: true, ..Self::function(names) }
+    }
+
+    /// Only calls to the methods of one of the identifiers `objects`, e.g. `Array.from()`.
+    #[must_use]
+    pub const fn objects(mut self, objects: &'p [&'p str]) -> Self {
+        self.objects = Some(objects);
+        self
+    }
+
+    /// Only calls with a number of arguments in `range`, e.g. `1..=2`, or `1..=usize::MAX` for
+    /// at least one.
+    #[must_use]
+    pub const fn arguments(mut self, range: RangeInclusive<usize>) -> Self {
+        self.min_arguments = *range.start();
+        self.max_arguments = *range.end();
+        self
+    }
+
+    /// Also match optional calls and members, e.g. `foo?.forEach?.()`.
+    #[must_use]
+    pub const fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    /// Also match calls with spread arguments, e.g. `foo.forEach(...args)`.
+    #[must_use]
+    pub const fn spread(mut self) -> Self {
+        self.spread = true;
+        self
+    }
+
+    pub fn matches(&self, call_expr: &CallExpression) -> bool {
+        if !self.matches_arguments(call_expr) {
+            return false;
+        }
+        if self.method {
+            return self.matches_member(call_expr).is_some();
+        }
+        matches!(
+            call_expr.callee.without_parenthesized(),
+            Expression::Identifier(ident) if self.names.contains(&ident.name.as_str())
+        )
+    }
+
+    /// The callee of a method call which matches, e.g. `foo.forEach` of `foo.forEach()`.
+    pub fn member<'b, 'a>(
+        &self,
+        call_expr: &'b CallExpression<'a>,
+    ) -> Option<&'b MemberExpression<'a>> {
+        if !self.method || !self.matches_arguments(call_expr) {
+            return None;
+        }
+        self.matches_member(call_expr)
+    }
+
+    fn matches_arguments(&self, call_expr: &CallExpression) -> bool {
+        (self.optional || !call_expr.optional)
+            && (self.min_arguments..=self.max_arguments).contains(&call_expr.arguments.len())
+            && (self.spread || !call_expr.arguments.iter().any(Argument::is_spread))
+    }
+
+    fn matches_member<'b, 'a>(
+        &self,
+        call_expr: &'b CallExpression<'a>,
+    ) -> Option<&'b MemberExpression<'a>> {
+        let member_expr = call_expr.callee.without_parenthesized().as_member_expression()?;
+        if (!self.optional && member_expr.optional())
+            || member_expr.is_computed()
+            || !member_expr.static_property_name().is_some_and(|name| self.names.contains(&name))
+        {
+            return None;
+        }
+        if let Some(objects) = self.objects {
+            let Expression::Identifier(ident) = member_expr.object().without_parenthesized() else {
+                return None;
+            };
+            if !objects.contains(&ident.name.as_str()) {
+                return None;
+            }
+        }
+        Some(member_expr)
+    }
+}
@@ -0,0 +1,323 @@
+//! Array Folding
+//!
+//! Folds pure `Array.prototype` operations on array literals.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, Visit};
+use oxc_span::Atom;
+use oxc_syntax::{number::NumberBase, operator::AssignmentOperator};
+
+use super::{ast_util::MayHaveSideEffects, Compressor, SPAN};
+
+/// Methods of `Array.prototype` which return a new array with the length of the receiver,
+/// and have no side effects other than calling their callback.
+///
+/// `reverse` mutates its receiver, which is unobservable for an array literal.
+const LENGTH_PRESERVING_METHODS: [&str; 4] = ["map", "reverse", "slice", "toReversed"];
+
+impl<'a> Compressor<'a> {
+    /// Enabled by `compress.arrays`
+    ///
+    /// * `Array.of(a, b)` -> `[a, b]`
+    /// * `[..."ab", ...[c]]` -> `["a", "b", c]`
+    /// * `[1, 2, 3].map(x => x * 2).length` -> `3`
+    ///
+    /// Enabled by `compress.unsafe_arrays`
+    ///
+    /// * `a.map(x => f(x)).map(y => g(y))` -> `a.map((x, y) => (y = f(x), g(y)))`
+    pub(crate) fn fold_array_expression(&mut self, expr: &mut Expression<'a>) {
+        if self.options.arrays {
+            let folded_expr = match expr {
+                Expression::ArrayExpression(array_expr) => {
+                    self.inline_array_spreads(array_expr);
+                    None
+                }
+                Expression::CallExpression(call_expr) => self.try_fold_array_of(call_expr),
+                Expression::StaticMemberExpression(member_expr) => {
+                    self.try_fold_array_length(member_expr)
+                }
+                _ => None,
+            };
+            if let Some(folded_expr) = folded_expr {
+                *expr = folded_expr;
+            }
+        }
+        if self.options.unsafe_arrays {
+            self.try_fuse_array_maps(expr);
+        }
+    }
+
+    /// `[..."ab", ...[c]]` -> `["a", "b", c]`
+    ///
+    /// Strings are spread by code points, array literals with holes are kept
+    /// because spreading turns holes into `undefined`.
+    fn inline_array_spreads(&mut self, array_expr: &mut ArrayExpression<'a>) {
+        let is_inlinable = |element: &ArrayExpressionElement<'a>| {
+            let ArrayExpressionElement::SpreadElement(spread) = element else { return false };
+            match &spread.argument {
+                Expression::StringLiteral(_) => true,
+                Expression::ArrayExpression(array_expr) => array_expr
+                    .elements
+                    .iter()
+                    .all(|element| !matches!(element, ArrayExpressionElement::Elision(_))),
+                _ => false,
+            }
+        };
+        if !array_expr.elements.iter().any(is_inlinable) {
+            return;
+        }
+
+        let mut elements = self.ast.new_vec_with_capacity(array_expr.elements.len());
+        for element in array_expr.elements.drain(..) {
+            if !is_inlinable(&element) {
+                elements.push(element);
+                continue;
+            }
+            let ArrayExpressionElement::SpreadElement(mut spread) = element else { unreachable!() };
+            match self.ast.move_expression(&mut spread.argument) {
+                Expression::StringLiteral(lit) => {
+                    for c in lit.value.chars() {
+                        let mut buf = [0; 4];
+                        let lit = self.ast.string_literal(SPAN, c.encode_utf8(&mut buf));
+                        elements.push(ArrayExpressionElement::from(
+                            self.ast.literal_string_expression(lit),
+                        ));
+                    }
+                }
+                Expression::ArrayExpression(mut inner) => elements.append(&mut inner.elements),
+                _ => unreachable!(),
+            }
+        }
+        array_expr.elements = elements;
+    }
+
+    /// `Array.of(a, ...b)` -> `[a, ...b]`
+    fn try_fold_array_of(&mut self, call_expr: &mut CallExpression<'a>) -> Option<Expression<'a>> {
+        let Expression::StaticMemberExpression(callee) = &call_expr.callee else { return None };
+        if call_expr.optional
+            || callee.optional
+            || callee.property.name != "of"
+            || !callee.object.is_specific_id("Array")
+        {
+            return None;
+        }
+        let mut elements = self.ast.new_vec_with_capacity(call_expr.arguments.len());
+        for argument in call_expr.arguments.drain(..) {
+            let element = match argument {
+                Argument::SpreadElement(spread) => ArrayExpressionElement::SpreadElement(spread),
+                argument => ArrayExpressionElement::from(Expression::try_from(argument).ok()?),
+            };
+            elements.push(element);
+        }
+        Some(self.ast.array_expression(call_expr.span, elements, None))
+    }
+
+    /// `[a, , b].length` -> `3`
+    /// `[1, 2, 3].map(x => x * 2).length` -> `3`
+    fn try_fold_array_length(
+        &mut self,
+        member_expr: &StaticMemberExpression<'a>,
+    ) -> Option<Expression<'a>> {
+        if member_expr.optional || member_expr.property.name != "length" {
+            return None;
+        }
+        let length = Self::get_array_length(&member_expr.object)?;
+        #[allow(clippy::cast_precision_loss)]
+        let value = length as f64;
+        let raw = self.ast.new_str(&length.to_string());
+        let lit = self.ast.number_literal(member_expr.span, value, raw, NumberBase::Decimal);
+        Some(self.ast.literal_number_expression(lit))
+    }
+
+    /// Length of the array `expr` evaluates to,
+    /// if `expr` can be removed without any observable side effects.
+    fn get_array_length(expr: &Expression<'a>) -> Option<usize> {
+        match expr.get_inner_expression() {
+            Expression::ArrayExpression(array_expr) => {
+                let is_pure = array_expr.elements.iter().all(|element| match element {
+                    ArrayExpressionElement::SpreadElement(_) => false,
+                    ArrayExpressionElement::Elision(_) => true,
+                    element => !element.to_expression().may_have_side_effects(),
+                });
+                is_pure.then_some(array_expr.elements.len())
+            }
+            Expression::CallExpression(call_expr) => {
+                let Expression::StaticMemberExpression(callee) = &call_expr.callee else {
+                    return None;
+                };
+                let method = callee.property.name.as_str();
+                if call_expr.optional
+                    || callee.optional
+                    || !LENGTH_PRESERVING_METHODS.contains(&method)
+                {
+                    return None;
+                }
+                let has_pure_arguments = match method {
+                    "map" => {
+                        call_expr.arguments.len() == 1
+                            && call_expr.arguments[0]
+                                .as_expression()
+                                .is_some_and(Self::is_pure_callback)
+                    }
+                    _ => call_expr.arguments.is_empty(),
+                };
+                if !has_pure_arguments {
+                    return None;
+                }
+                Self::get_array_length(&callee.object)
+            }
+            _ => None,
+        }
+    }
+
+    /// A function literal whose calls have no side effects.
+    fn is_pure_callback(expr: &Expression<'a>) -> bool {
+        let has_simple_params = |params: &FormalParameters<'a>| {
+            params.rest.is_none()
+                && params.items.iter().all(|param| {
+                    matches!(param.pattern.kind, BindingPatternKind::BindingIdentifier(_))
+                })
+        };
+        match expr.get_inner_expression() {
+            Expression::ArrowFunctionExpression(arrow) => {
+                !arrow.r#async
+                    && has_simple_params(&arrow.params)
+                    && arrow.get_expression().map_or_else(
+                        || arrow.body.statements.is_empty(),
+                        |expr| !expr.may_have_side_effects(),
+                    )
+            }
+            Expression::FunctionExpression(func) => {
+                !func.r#async
+                    && !func.generator
+                    && has_simple_params(&func.params)
+                    && func.body.as_ref().is_some_and(|body| body.statements.is_empty())
+            }
+            _ => false,
+        }
+    }
+
+    /// `a.map(x => f(x)).map(y => g(y))` -> `a.map((x, y) => (y = f(x), g(y)))`
+    /// `a.map(x => f(x)).map(x => g(x))` -> `a.map(x => (x = f(x), g(x)))`
+    ///
+    /// Unsafe because the callbacks are interleaved instead of running one after another,
+    /// and the second callback no longer receives the intermediate array.
+    fn try_fuse_array_maps(&mut self, expr: &mut Expression<'a>) {
+        let Some((inner_name, outer_name)) = Self::get_fusible_map_names(expr) else { return };
+
+        let Expression::CallExpression(outer_call) = expr else { unreachable!() };
+        let Some(Argument::ArrowFunctionExpression(mut outer_arrow)) = outer_call.arguments.pop()
+        else {
+            unreachable!()
+        };
+        let Expression::StaticMemberExpression(outer_callee) = &mut outer_call.callee else {
+            unreachable!()
+        };
+        let mut inner_call = self.ast.move_expression(&mut outer_callee.object);
+        let Expression::CallExpression(inner_call_expr) = &mut inner_call else { unreachable!() };
+        let Some(Argument::ArrowFunctionExpression(inner_arrow)) =
+            inner_call_expr.arguments.first_mut()
+        else {
+            unreachable!()
+        };
+
+        if inner_name != outer_name {
+            let ident = BindingIdentifier::new(SPAN, outer_name.clone());
+            let pattern =
+                self.ast.binding_pattern(self.ast.binding_pattern_identifier(ident), None, false);
+            let param =
+                self.ast.formal_parameter(SPAN, pattern, None, false, false, self.ast.new_vec());
+            inner_arrow.params.items.push(param);
+        }
+
+        let Statement::ExpressionStatement(inner_body) = &mut inner_arrow.body.statements[0] else {
+            unreachable!()
+        };
+        let Statement::ExpressionStatement(outer_body) = &mut outer_arrow.body.statements[0] else {
+            unreachable!()
+        };
+        let target = self
+            .ast
+            .simple_assignment_target_identifier(self.ast.identifier_reference(SPAN, &outer_name));
+        let assignment = self.ast.assignment_expression(
+            SPAN,
+            AssignmentOperator::Assign,
+            target,
+            self.ast.move_expression(&mut inner_body.expression),
+        );
+        let mut expressions = self.ast.new_vec_with_capacity(2);
+        expressions.push(assignment);
+        expressions.push(self.ast.move_expression(&mut outer_body.expression));
+        inner_body.expression = self.ast.sequence_expression(SPAN, expressions);
+
+        *expr = inner_call;
+    }
+
+    /// Parameter names of the callbacks of `a.map(x => ..).map(y => ..)`
+    /// when the maps can be fused into one.
+    fn get_fusible_map_names(expr: &Expression<'a>) -> Option<(Atom<'a>, Atom<'a>)> {
+        let outer_arrow = Self::get_map_callback(expr)?;
+        let Expression::CallExpression(outer_call) = expr else { return None };
+        let Expression::StaticMemberExpression(outer_callee) = &outer_call.callee else {
+            return None;
+        };
+        let inner_arrow = Self::get_map_callback(&outer_callee.object)?;
+        let inner_name = Self::get_single_param_name(inner_arrow)?;
+        let outer_name = Self::get_single_param_name(outer_arrow)?;
+        if inner_name != outer_name {
+            // `y` would shadow a `y` referenced by the first callback,
+            // and `x` would shadow an `x` referenced by the second callback.
+            if references_name(inner_arrow.get_expression()?, outer_name)
+                || references_name(outer_arrow.get_expression()?, inner_name)
+            {
+                return None;
+            }
+        }
+        Some((inner_name.clone(), outer_name.clone()))
+    }
+
+    /// The arrow function callback of `a.map(x => ..)`.
+    fn get_map_callback<'b>(expr: &'b Expression<'a>) -> Option<&'b ArrowFunctionExpression<'a>> {
+        let Expression::CallExpression(call_expr) = expr else { return None };
+        let Expression::StaticMemberExpression(callee) = &call_expr.callee else { return None };
+        if call_expr.optional
+            || callee.optional
+            || callee.property.name != "map"
+            || call_expr.arguments.len() != 1
+        {
+            return None;
+        }
+        let Argument::ArrowFunctionExpression(arrow) = &call_expr.arguments[0] else {
+            return None;
+        };
+        (arrow.expression && !arrow.r#async).then_some(arrow)
+    }
+
+    fn get_single_param_name<'b>(arrow: &'b ArrowFunctionExpression<'a>) -> Option<&'b Atom<'a>> {
+        if arrow.params.rest.is_some() || arrow.params.items.len() != 1 {
+            return None;
+        }
+        match &arrow.params.items[0].pattern.kind {
+            BindingPatternKind::BindingIdentifier(ident) => Some(&ident.name),
+            _ => None,
+        }
+    }
+}
+
+/// Whether `expr` contains a reference named `name`, including in nested functions.
+fn references_name(expr: &Expression<'_>, name: &str) -> bool {
+    struct Finder<'n> {
+        name: &'n str,
+        found: bool,
+    }
+
+    impl<'a, 'n> Visit<'a> for Finder<'n> {
+        fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+            self.found |= ident.name == self.name;
+        }
+    }
+
+    let mut finder = Finder { name, found: false };
+    finder.visit_expression(expr);
+    finder.found
+}
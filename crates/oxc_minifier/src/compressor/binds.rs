@@ -0,0 +1,99 @@
+//! Calls of Bound Functions
+//!
+//! A function which is bound and called right away is called directly instead:
+//!
+//! ```javascript
+//! (function () {}).bind(a, b)(c);
+//! goog.bind(f, a, b)(c);
+//! goog.partial(f, a)(b);
+//! ```
+//!
+//! is compressed to `(function () {}).call(a, b, c); f.call(a, b, c); f(a, b)`.
+//!
+//! `f.bind(a)(b)` is only rewritten for function expressions, as `f` may be any object with a
+//! `bind` method. `goog.bind` and `goog.partial` of the Closure Library are recognized when `goog`
+//! is a global. Without a bound `this`, a method `f.m` is called as `(0, f.m)(a)`, so that it does
+//! not see `f` as its `this`.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_syntax::number::NumberBase;
+
+use super::{Compressor, SPAN};
+
+/// How the function is bound.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Bind {
+    /// `(function () {}).bind(a, ..)`, binds `this` and the arguments.
+    Function,
+    /// `goog.bind(f, a, ..)`, binds `this` and the arguments.
+    Goog,
+    /// `goog.partial(f, a, ..)`, binds the arguments.
+    GoogPartial,
+}
+
+impl<'a> Compressor<'a> {
+    /// Enabled by `compress.bind_calls`
+    ///
+    /// `(function () {}).bind(a)(b)` -> `(function () {}).call(a, b)` and
+    /// `goog.partial(f, a)(b)` -> `f(a, b)`
+    pub(crate) fn fold_bind_call(&mut self, expr: &mut Expression<'a>) {
+        if !self.options.bind_calls {
+            return;
+        }
+        let Expression::CallExpression(call_expr) = expr else { return };
+        let Some(bind) = self.get_bind(call_expr) else { return };
+        let span = call_expr.span;
+        let Expression::CallExpression(bind_call) = &mut call_expr.callee else { unreachable!() };
+        let mut arguments = std::mem::replace(&mut bind_call.arguments, self.ast.new_vec());
+        let Expression::StaticMemberExpression(member) = &mut bind_call.callee else {
+            unreachable!()
+        };
+        let function = if bind == Bind::Function {
+            self.ast.move_expression(&mut member.object)
+        } else {
+            let Ok(function) = Expression::try_from(arguments.remove(0)) else { unreachable!() };
+            function
+        };
+        // The first bound argument is `this`, unless nothing is bound.
+        let binds_this = bind != Bind::GoogPartial && !arguments.is_empty();
+        arguments.extend(call_expr.arguments.drain(..));
+
+        let callee = if binds_this {
+            let property = IdentifierName::new(SPAN, "call".into());
+            self.ast.static_member_expression(SPAN, function, property, false)
+        } else if function.is_member_expression() {
+            let zero = self.ast.number_literal(SPAN, 0.0, "0", NumberBase::Decimal);
+            let expressions =
+                self.ast.new_vec_from_iter([self.ast.literal_number_expression(zero), function]);
+            self.ast.sequence_expression(SPAN, expressions)
+        } else {
+            function
+        };
+        *expr = self.ast.call_expression(span, callee, arguments, false, None);
+    }
+
+    /// How the callee of `call_expr` binds a function, if it does.
+    fn get_bind(&self, call_expr: &CallExpression<'a>) -> Option<Bind> {
+        let Expression::CallExpression(bind_call) = &call_expr.callee else { return None };
+        let Expression::StaticMemberExpression(member) = &bind_call.callee else { return None };
+        if call_expr.optional
+            || bind_call.optional
+            || member.optional
+            || bind_call.arguments.iter().any(Argument::is_spread)
+        {
+            return None;
+        }
+        match (&member.object, member.property.name.as_str()) {
+            (Expression::Identifier(ident), name @ ("bind" | "partial"))
+                if ident.name == "goog"
+                    && self.is_global_reference(ident)
+                    && !bind_call.arguments.is_empty() =>
+            {
+                Some(if name == "bind" { Bind::Goog } else { Bind::GoogPartial })
+            }
+            (object, "bind") if object.is_function() => Some(Bind::Function),
+            _ => None,
+        }
+    }
+}
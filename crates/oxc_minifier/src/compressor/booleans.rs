@@ -0,0 +1,124 @@
+//! Boolean Contexts
+//!
+//! Some expressions are only ever converted to a boolean, so converting them to a boolean
+//! beforehand with `!!a` or `Boolean(a)` is redundant:
+//!
+//! * The tests of `if`, `while`, `do ... while` and `for` statements, and of conditional
+//!   expressions.
+//! * The argument of `!`.
+//! * Both operands of `&&` and `||`, the branches of a conditional expression and the last
+//!   expression of a sequence, when they are in a boolean context themselves.
+//!
+//! `Boolean(a)` is first folded to `!!a`, which is then removed in a boolean context,
+//! e.g. `if (Boolean(a) && !!b) c()` is compressed to `if (a && b) c()`.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_syntax::operator::{LogicalOperator, UnaryOperator};
+
+use super::Compressor;
+
+impl<'a> Compressor<'a> {
+    /// Enabled by `compress.booleans`
+    ///
+    /// `Boolean(a)` -> `!!a` and `Boolean()` -> `false`
+    pub(crate) fn fold_boolean_call(&mut self, expr: &mut Expression<'a>) {
+        if !self.options.booleans {
+            return;
+        }
+        let Expression::CallExpression(call_expr) = expr else { return };
+        let Expression::Identifier(callee) = &call_expr.callee else { return };
+        if call_expr.optional
+            || callee.name != "Boolean"
+            || !self.is_global_reference(callee)
+            || call_expr.arguments.len() > 1
+            || call_expr.arguments.iter().any(Argument::is_spread)
+        {
+            return;
+        }
+        let span = call_expr.span;
+        *expr = match call_expr.arguments.pop().and_then(|arg| Expression::try_from(arg).ok()) {
+            Some(argument) => {
+                let not = self.ast.unary_expression(span, UnaryOperator::LogicalNot, argument);
+                self.ast.unary_expression(span, UnaryOperator::LogicalNot, not)
+            }
+            None => self.ast.literal_boolean_expression(self.ast.boolean_literal(span, false)),
+        };
+    }
+
+    /// Enabled by `compress.booleans`
+    ///
+    /// `!!a ? b : c` -> `a ? b : c` and `!!!a` -> `!a`
+    pub(crate) fn compress_boolean_operand(&mut self, expr: &mut Expression<'a>) {
+        if !self.options.booleans {
+            return;
+        }
+        match expr {
+            Expression::ConditionalExpression(cond_expr) => {
+                self.compress_boolean_context(&mut cond_expr.test);
+            }
+            Expression::UnaryExpression(unary_expr)
+                if unary_expr.operator == UnaryOperator::LogicalNot =>
+            {
+                self.compress_boolean_context(&mut unary_expr.argument);
+            }
+            _ => {}
+        }
+    }
+
+    /// Enabled by `compress.booleans`
+    ///
+    /// `if (!!a) b` -> `if (a) b` and `while (!!a) b` -> `while (a) b`
+    pub(crate) fn compress_statement_test(&mut self, stmt: &mut Statement<'a>) {
+        if !self.options.booleans {
+            return;
+        }
+        let test = match stmt {
+            Statement::IfStatement(if_stmt) => &mut if_stmt.test,
+            Statement::WhileStatement(while_stmt) => &mut while_stmt.test,
+            Statement::DoWhileStatement(do_stmt) => &mut do_stmt.test,
+            Statement::ForStatement(for_stmt) => match &mut for_stmt.test {
+                Some(test) => test,
+                None => return,
+            },
+            _ => return,
+        };
+        self.compress_boolean_context(test);
+    }
+
+    /// Remove `!!` from `expr`, whose value is only converted to a boolean.
+    fn compress_boolean_context(&mut self, expr: &mut Expression<'a>) {
+        while let Expression::UnaryExpression(unary_expr) = expr {
+            if unary_expr.operator != UnaryOperator::LogicalNot {
+                break;
+            }
+            let Expression::UnaryExpression(inner) = &mut unary_expr.argument else { break };
+            if inner.operator != UnaryOperator::LogicalNot {
+                break;
+            }
+            *expr = self.ast.move_expression(&mut inner.argument);
+        }
+        match expr {
+            // `a ?? b` is `a` unless `a` is `null` or `undefined`, which `!!a` never is.
+            Expression::LogicalExpression(logical_expr)
+                if logical_expr.operator != LogicalOperator::Coalesce =>
+            {
+                self.compress_boolean_context(&mut logical_expr.left);
+                self.compress_boolean_context(&mut logical_expr.right);
+            }
+            Expression::ConditionalExpression(cond_expr) => {
+                self.compress_boolean_context(&mut cond_expr.consequent);
+                self.compress_boolean_context(&mut cond_expr.alternate);
+            }
+            Expression::SequenceExpression(sequence_expr) => {
+                if let Some(last) = sequence_expr.expressions.last_mut() {
+                    self.compress_boolean_context(last);
+                }
+            }
+            Expression::ParenthesizedExpression(paren_expr) => {
+                self.compress_boolean_context(&mut paren_expr.expression);
+            }
+            _ => {}
+        }
+    }
+}
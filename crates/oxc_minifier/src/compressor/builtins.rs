@@ -0,0 +1,487 @@
+//! Builtin Predicates and Functions
+//!
+//! Evaluates the calls to the static predicates of the builtins whose arguments are literals,
+//! for example `Array.isArray([])` → `true` and `Object.is(0, -0)` → `false`, and the calls to
+//! the functions of `Math` and the arithmetic on the constants of `Math` and `Number`, for
+//! example `Math.floor(3.7)` → `3` and `Math.PI * 2` → `6.283185307179586`.
+//!
+//! Only the functions whose results are exact are evaluated, so the output does not depend on
+//! the precision of the host: `Math.sqrt`, which is correctly rounded, but not `Math.sin`, and
+//! `Math.pow` only for integer results. A result which is `NaN` or infinite is kept, and so is
+//! a constant which is not part of an arithmetic expression, since `Math.PI` is shorter than
+//! its value.
+//!
+//! The predicates follow the semantics of the specification for `NaN` and `-0`: `Number.isNaN`
+//! does not convert its argument to a number, unlike the global `isNaN`, and `Object.is` tells
+//! `0` and `-0` apart and treats `NaN` as equal to itself. Converting a string or an object to a
+//! number is not modeled, so `isNaN("1")` is kept.
+//!
+//! Only the references to the globals are folded, not to bindings which shadow them.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{BinaryOperator, UnaryOperator},
+};
+
+use super::{
+    ast_util::{is_exact_int64, MayHaveSideEffects},
+    Compressor,
+};
+
+/// `Number.MAX_SAFE_INTEGER`
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+/// A static predicate of a builtin.
+#[derive(Debug, Clone, Copy)]
+enum Predicate {
+    ArrayIsArray,
+    NumberIsFinite,
+    NumberIsInteger,
+    NumberIsNaN,
+    NumberIsSafeInteger,
+    ObjectIs,
+    IsFinite,
+    IsNaN,
+}
+
+/// A function of `Math` whose result is exact.
+#[derive(Debug, Clone, Copy)]
+enum MathFunction {
+    Abs,
+    Ceil,
+    Floor,
+    Fround,
+    Max,
+    Min,
+    Pow,
+    Round,
+    Sign,
+    Sqrt,
+    Trunc,
+}
+
+/// A static predicate or a function of a builtin.
+#[derive(Debug, Clone, Copy)]
+enum Builtin {
+    Predicate(Predicate),
+    Math(MathFunction),
+}
+
+/// The value of an argument which has no side effects.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value<'b> {
+    Number(f64),
+    String(&'b str),
+    Boolean(bool),
+    Null,
+    Undefined,
+    /// A new object, such as `[]`, `{}` or a function, and whether it is an array.
+    Object {
+        array: bool,
+    },
+}
+
+impl<'b> Value<'b> {
+    #[allow(clippy::float_cmp)]
+    fn to_boolean(self) -> bool {
+        match self {
+            Self::Number(num) => num != 0.0 && !num.is_nan(),
+            Self::String(string) => !string.is_empty(),
+            Self::Boolean(boolean) => boolean,
+            Self::Null | Self::Undefined => false,
+            Self::Object { .. } => true,
+        }
+    }
+
+    /// The value of `+value`, except for strings and objects.
+    fn to_number(self) -> Option<f64> {
+        match self {
+            Self::Number(num) => Some(num),
+            Self::Boolean(boolean) => Some(if boolean { 1.0 } else { 0.0 }),
+            Self::Null => Some(0.0),
+            Self::Undefined => Some(f64::NAN),
+            Self::String(_) | Self::Object { .. } => None,
+        }
+    }
+
+    fn as_number(self) -> Option<f64> {
+        match self {
+            Self::Number(num) => Some(num),
+            _ => None,
+        }
+    }
+
+    /// `Object.is(self, other)`
+    #[allow(clippy::float_cmp)]
+    fn same_value(self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Number(left), Self::Number(right)) => {
+                (left.is_nan() && right.is_nan())
+                    || (left == right && left.is_sign_negative() == right.is_sign_negative())
+            }
+            // Every object literal creates a new object.
+            (Self::Object { .. }, _) | (_, Self::Object { .. }) => false,
+            (left, right) => left == right,
+        }
+    }
+}
+
+impl Predicate {
+    fn evaluate(self, arguments: &[Value]) -> Option<bool> {
+        let first = arguments.first().copied().unwrap_or(Value::Undefined);
+        let number = first.as_number();
+        let result = match self {
+            Self::ArrayIsArray => first == Value::Object { array: true },
+            Self::NumberIsFinite => number.is_some_and(f64::is_finite),
+            Self::NumberIsInteger => {
+                number.is_some_and(|num| num.is_finite() && is_exact_int64(num))
+            }
+            Self::NumberIsNaN => number.is_some_and(f64::is_nan),
+            Self::NumberIsSafeInteger => number.is_some_and(|num| {
+                num.is_finite() && is_exact_int64(num) && num.abs() <= MAX_SAFE_INTEGER
+            }),
+            Self::ObjectIs => {
+                first.same_value(arguments.get(1).copied().unwrap_or(Value::Undefined))
+            }
+            Self::IsFinite => first.to_number()?.is_finite(),
+            Self::IsNaN => first.to_number()?.is_nan(),
+        };
+        Some(result)
+    }
+}
+
+impl MathFunction {
+    /// The result for the arguments converted to numbers.
+    #[allow(clippy::float_cmp, clippy::cast_possible_truncation)]
+    fn evaluate(self, arguments: &[f64]) -> f64 {
+        let first = arguments.first().copied().unwrap_or(f64::NAN);
+        match self {
+            Self::Abs => first.abs(),
+            Self::Ceil => first.ceil(),
+            Self::Floor => first.floor(),
+            Self::Fround => f64::from(first as f32),
+            // `Math.max(-0, 0)` is `0` and `Math.min(0, -0)` is `-0`.
+            Self::Max => arguments
+                .iter()
+                .try_fold(f64::NEG_INFINITY, |max, &num| {
+                    if num.is_nan() {
+                        None
+                    } else if num > max || (num == max && max.is_sign_negative()) {
+                        Some(num)
+                    } else {
+                        Some(max)
+                    }
+                })
+                .unwrap_or(f64::NAN),
+            Self::Min => arguments
+                .iter()
+                .try_fold(f64::INFINITY, |min, &num| {
+                    if num.is_nan() {
+                        None
+                    } else if num < min || (num == min && num.is_sign_negative()) {
+                        Some(num)
+                    } else {
+                        Some(min)
+                    }
+                })
+                .unwrap_or(f64::NAN),
+            // Only integer powers which are safe integers, which are computed exactly.
+            Self::Pow => {
+                let exponent = arguments.get(1).copied().unwrap_or(f64::NAN);
+                if !is_exact_int64(first)
+                    || !is_exact_int64(exponent)
+                    || !(0.0..=64.0).contains(&exponent)
+                {
+                    return f64::NAN;
+                }
+                let result = first.powi(exponent as i32);
+                if result.abs() <= MAX_SAFE_INTEGER {
+                    result
+                } else {
+                    f64::NAN
+                }
+            }
+            // Rounds half up, unlike `f64::round`, and keeps the sign of `-0.2` → `-0`.
+            Self::Round => {
+                if !first.is_finite() || is_exact_int64(first) {
+                    return first;
+                }
+                let floor = first.floor();
+                let rounded = if first - floor >= 0.5 { floor + 1.0 } else { floor };
+                if rounded == 0.0 && first.is_sign_negative() {
+                    -0.0
+                } else {
+                    rounded
+                }
+            }
+            // `f64::signum` is `1` for `0`.
+            Self::Sign => {
+                if first == 0.0 || first.is_nan() {
+                    first
+                } else {
+                    1.0_f64.copysign(first)
+                }
+            }
+            Self::Sqrt => first.sqrt(),
+            Self::Trunc => first.trunc(),
+        }
+    }
+}
+
+impl Builtin {
+    fn evaluate(self, arguments: &[Value]) -> Option<Value<'static>> {
+        match self {
+            Self::Predicate(predicate) => predicate.evaluate(arguments).map(Value::Boolean),
+            Self::Math(function) => {
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| argument.to_number())
+                    .collect::<Option<std::vec::Vec<_>>>()?;
+                Some(Value::Number(function.evaluate(&arguments)))
+            }
+        }
+    }
+}
+
+impl<'a> Compressor<'a> {
+    /// Enabled by `compress.builtins`
+    ///
+    /// * `Array.isArray([])` -> `true`
+    /// * `Number.isNaN("a")` -> `false`
+    /// * `Object.is(1, 1)` -> `true`
+    /// * `Math.floor(3.7)` -> `3`
+    pub(crate) fn fold_builtin_call(&mut self, expr: &mut Expression<'a>) {
+        if !self.options.builtins {
+            return;
+        }
+        let Expression::CallExpression(call_expr) = expr else { return };
+        let span = call_expr.span;
+        match self.evaluate_call(call_expr) {
+            Some(Value::Boolean(result)) => {
+                let boolean = self.ast.boolean_literal(span, result);
+                *expr = self.ast.literal_boolean_expression(boolean);
+            }
+            Some(Value::Number(num)) => {
+                if let Some(number) = self.number_expression(span, num) {
+                    *expr = number;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Enabled by `compress.builtins`
+    ///
+    /// * `Math.PI * 2` -> `6.283185307179586`
+    /// * `Number.MAX_SAFE_INTEGER + 1` -> `9007199254740992`
+    ///
+    /// Runs before the operands are visited, so that the whole expression is evaluated at once,
+    /// including the parts without a builtin, like `2 / 3` of `Math.PI * 2 / 3`.
+    pub(crate) fn fold_builtin_arithmetic(&mut self, expr: &mut Expression<'a>) {
+        if !self.options.builtins || !matches!(expr, Expression::BinaryExpression(_)) {
+            return;
+        }
+        if !self.reads_builtin(expr) {
+            return;
+        }
+        let Some(Value::Number(num)) = self.value(expr) else { return };
+        if let Some(number) = self.number_expression(expr.span(), num) {
+            *expr = number;
+        }
+    }
+
+    /// The literal of `num`, negated if it is negative, or `None` for `NaN` and infinities,
+    /// whose expressions are not shorter.
+    fn number_expression(&self, span: Span, num: f64) -> Option<Expression<'a>> {
+        if !num.is_finite() {
+            return None;
+        }
+        let base = if is_exact_int64(num) { NumberBase::Decimal } else { NumberBase::Float };
+        let literal = self.ast.number_literal(span, num.abs(), "", base);
+        let literal = self.ast.literal_number_expression(literal);
+        if num.is_sign_negative() {
+            Some(self.ast.unary_expression(span, UnaryOperator::UnaryNegation, literal))
+        } else {
+            Some(literal)
+        }
+    }
+
+    /// Whether the arithmetic expression `expr` reads a constant or calls a function of the
+    /// builtins, like `Math.PI * 2`.
+    fn reads_builtin(&self, expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::BinaryExpression(binary) => {
+                self.reads_builtin(&binary.left) || self.reads_builtin(&binary.right)
+            }
+            Expression::UnaryExpression(unary) => self.reads_builtin(&unary.argument),
+            Expression::StaticMemberExpression(member) => self.constant(member).is_some(),
+            Expression::CallExpression(call_expr) => self.builtin(&call_expr.callee).is_some(),
+            _ => false,
+        }
+    }
+
+    /// The result of `call_expr`, if it calls a builtin with literal arguments.
+    fn evaluate_call<'b>(&self, call_expr: &'b CallExpression<'a>) -> Option<Value<'b>> {
+        if call_expr.optional {
+            return None;
+        }
+        let builtin = self.builtin(&call_expr.callee)?;
+        let arguments = call_expr
+            .arguments
+            .iter()
+            .map(|argument| argument.as_expression().and_then(|argument| self.value(argument)))
+            .collect::<Option<std::vec::Vec<_>>>()?;
+        builtin.evaluate(&arguments)
+    }
+
+    /// The builtin which `callee` refers to, such as `Array.isArray`, `Math.floor` or the
+    /// global `isNaN`.
+    fn builtin(&self, callee: &Expression<'a>) -> Option<Builtin> {
+        match callee {
+            Expression::Identifier(ident) if self.is_global_reference(ident) => {
+                match ident.name.as_str() {
+                    "isFinite" => Some(Builtin::Predicate(Predicate::IsFinite)),
+                    "isNaN" => Some(Builtin::Predicate(Predicate::IsNaN)),
+                    _ => None,
+                }
+            }
+            Expression::StaticMemberExpression(member) => {
+                let predicate = match self.global_member(member)? {
+                    ("Array", "isArray") => Predicate::ArrayIsArray,
+                    ("Number", "isFinite") => Predicate::NumberIsFinite,
+                    ("Number", "isInteger") => Predicate::NumberIsInteger,
+                    ("Number", "isNaN") => Predicate::NumberIsNaN,
+                    ("Number", "isSafeInteger") => Predicate::NumberIsSafeInteger,
+                    ("Object", "is") => Predicate::ObjectIs,
+                    ("Math", name) => return Self::math_function(name).map(Builtin::Math),
+                    _ => return None,
+                };
+                Some(Builtin::Predicate(predicate))
+            }
+            _ => None,
+        }
+    }
+
+    fn math_function(name: &str) -> Option<MathFunction> {
+        let function = match name {
+            "abs" => MathFunction::Abs,
+            "ceil" => MathFunction::Ceil,
+            "floor" => MathFunction::Floor,
+            "fround" => MathFunction::Fround,
+            "max" => MathFunction::Max,
+            "min" => MathFunction::Min,
+            "pow" => MathFunction::Pow,
+            "round" => MathFunction::Round,
+            "sign" => MathFunction::Sign,
+            "sqrt" => MathFunction::Sqrt,
+            "trunc" => MathFunction::Trunc,
+            _ => return None,
+        };
+        Some(function)
+    }
+
+    /// The value of a constant of `Math` or `Number`, such as `Math.PI`.
+    fn constant(&self, member: &StaticMemberExpression<'a>) -> Option<f64> {
+        let value = match self.global_member(member)? {
+            ("Math", "E") => std::f64::consts::E,
+            ("Math", "LN10") => std::f64::consts::LN_10,
+            ("Math", "LN2") => std::f64::consts::LN_2,
+            ("Math", "LOG10E") => std::f64::consts::LOG10_E,
+            ("Math", "LOG2E") => std::f64::consts::LOG2_E,
+            ("Math", "PI") => std::f64::consts::PI,
+            ("Math", "SQRT1_2") => std::f64::consts::FRAC_1_SQRT_2,
+            ("Math", "SQRT2") => std::f64::consts::SQRT_2,
+            ("Number", "EPSILON") => f64::EPSILON,
+            ("Number", "MAX_SAFE_INTEGER") => MAX_SAFE_INTEGER,
+            ("Number", "MIN_SAFE_INTEGER") => -MAX_SAFE_INTEGER,
+            ("Number", "MAX_VALUE") => f64::MAX,
+            ("Number", "MIN_VALUE") => 5e-324,
+            ("Number", "NaN") => f64::NAN,
+            ("Number", "POSITIVE_INFINITY") => f64::INFINITY,
+            ("Number", "NEGATIVE_INFINITY") => f64::NEG_INFINITY,
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    /// The names of the object and of the property of `member`, if the object is a global,
+    /// like `Math` of `Math.floor`.
+    fn global_member<'b>(
+        &self,
+        member: &'b StaticMemberExpression<'a>,
+    ) -> Option<(&'b str, &'b str)> {
+        if member.optional {
+            return None;
+        }
+        let Expression::Identifier(object) = &member.object else { return None };
+        if !self.is_global_reference(object) {
+            return None;
+        }
+        Some((object.name.as_str(), member.property.name.as_str()))
+    }
+
+    /// The value of `expr`, if it is a literal without side effects.
+    fn value<'b>(&self, expr: &'b Expression<'a>) -> Option<Value<'b>> {
+        match expr {
+            Expression::NumericLiteral(lit) => Some(Value::Number(lit.value)),
+            Expression::StringLiteral(lit) => Some(Value::String(lit.value.as_str())),
+            Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+                lit.quasis[0].value.cooked.as_ref().map(|cooked| Value::String(cooked.as_str()))
+            }
+            Expression::BooleanLiteral(lit) => Some(Value::Boolean(lit.value)),
+            Expression::NullLiteral(_) => Some(Value::Null),
+            Expression::Identifier(ident) if self.is_global_reference(ident) => {
+                match ident.name.as_str() {
+                    "undefined" => Some(Value::Undefined),
+                    "NaN" => Some(Value::Number(f64::NAN)),
+                    "Infinity" => Some(Value::Number(f64::INFINITY)),
+                    _ => None,
+                }
+            }
+            Expression::UnaryExpression(unary) => match unary.operator {
+                UnaryOperator::Void if !unary.argument.may_have_side_effects() => {
+                    Some(Value::Undefined)
+                }
+                UnaryOperator::LogicalNot => {
+                    self.value(&unary.argument).map(|value| Value::Boolean(!value.to_boolean()))
+                }
+                UnaryOperator::UnaryNegation => {
+                    self.value(&unary.argument)?.as_number().map(|num| Value::Number(-num))
+                }
+                UnaryOperator::UnaryPlus => {
+                    self.value(&unary.argument)?.as_number().map(Value::Number)
+                }
+                _ => None,
+            },
+            Expression::StaticMemberExpression(member) => self.constant(member).map(Value::Number),
+            Expression::CallExpression(call_expr) => self.evaluate_call(call_expr),
+            // `0/0`, `1/0` and `Math.PI * 2`
+            Expression::BinaryExpression(binary) => {
+                let operate: fn(f64, f64) -> f64 = match binary.operator {
+                    BinaryOperator::Addition => |left, right| left + right,
+                    BinaryOperator::Subtraction => |left, right| left - right,
+                    BinaryOperator::Multiplication => |left, right| left * right,
+                    BinaryOperator::Division => |left, right| left / right,
+                    BinaryOperator::Remainder => |left, right| left % right,
+                    _ => return None,
+                };
+                let left = self.value(&binary.left)?.as_number()?;
+                let right = self.value(&binary.right)?.as_number()?;
+                Some(Value::Number(operate(left, right)))
+            }
+            Expression::ArrayExpression(_) if !expr.may_have_side_effects() => {
+                Some(Value::Object { array: true })
+            }
+            Expression::ObjectExpression(_) if !expr.may_have_side_effects() => {
+                Some(Value::Object { array: false })
+            }
+            Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::RegExpLiteral(_) => Some(Value::Object { array: false }),
+            _ => None,
+        }
+    }
+}
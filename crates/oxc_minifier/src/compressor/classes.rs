@@ -0,0 +1,298 @@
+//! Class Downgrade
+//!
+//! Rewrites local classes which only have a constructor and static methods into an object literal
+//! or a function, which are shorter:
+//!
+//! ```javascript
+//! class A { static f() {} static g() {} }
+//! class B { constructor(x) { this.x = x } static f() {} }
+//! ```
+//!
+//! is compressed to `let A = { f() {}, g() {} }; function B(x) { this.x = x } B.f = function () {}`.
+//!
+//! A class which is never constructed becomes an object literal with its static methods, and a
+//! class which is constructed becomes a function, with its static methods assigned to it, when
+//! that is shorter than the class.
+//!
+//! This is unsafe: the class can then be called without `new`, its methods become enumerable,
+//! and the static methods of a function can be constructed. So only classes whose every use is
+//! known are changed, which are declared in strict mode code, not exported or visible to other
+//! scripts, and not reachable by a direct `eval`. The class has no heritage, decorators, fields,
+//! accessors, computed keys or use of `new.target` and `super`, and is only referenced as
+//! `new A()`, `x instanceof A` or by a static member `A.f`, so that e.g. `A.prototype` or
+//! `A.name` are never read.
+
+use oxc_allocator::{Allocator, Box, Vec};
+use oxc_ast::visit::walk_mut::walk_statements_mut;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder, AstKind, Visit, VisitMut};
+use oxc_semantic::{Semantic, SemanticBuilder, SymbolId};
+use oxc_span::GetSpan;
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator};
+use rustc_hash::FxHashMap;
+
+use super::SPAN;
+
+/// Names of static methods which are own properties of functions, or set the prototype of
+/// object literals.
+const RESERVED_NAMES: [&str; 6] =
+    ["arguments", "caller", "length", "name", "prototype", "__proto__"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Downgrade {
+    Object,
+    Function,
+}
+
+pub struct ClassDowngrade<'a> {
+    ast: AstBuilder<'a>,
+    classes: FxHashMap<SymbolId, Downgrade>,
+}
+
+impl<'a> ClassDowngrade<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { ast: AstBuilder::new(allocator), classes: FxHashMap::default() }
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        let semantic = SemanticBuilder::new("", program.source_type).build(program).semantic;
+        self.classes = semantic
+            .symbols()
+            .iter()
+            .filter_map(|symbol_id| downgrade(&semantic, symbol_id).map(|kind| (symbol_id, kind)))
+            .collect();
+        if !self.classes.is_empty() {
+            self.visit_program(program);
+        }
+    }
+
+    fn downgrade_class(
+        &self,
+        mut class: Box<'a, Class<'a>>,
+        stmts: &mut Vec<'a, Statement<'a>>,
+    ) -> Option<Box<'a, Class<'a>>> {
+        let Some(kind) = class
+            .id
+            .as_ref()
+            .and_then(|id| id.symbol_id.get())
+            .and_then(|symbol_id| self.classes.get(&symbol_id).copied())
+        else {
+            return Some(class);
+        };
+        let id = class.id.take().unwrap();
+        let mut constructor = None;
+        let mut methods = std::vec::Vec::new();
+        for element in class.body.body.drain(..) {
+            let ClassElement::MethodDefinition(def) = element else { continue };
+            let def = def.unbox();
+            match (def.kind, def.key) {
+                (MethodDefinitionKind::Constructor, _) => constructor = Some(def.value),
+                (_, PropertyKey::StaticIdentifier(key)) => methods.push((key.unbox(), def.value)),
+                _ => unreachable!(),
+            }
+        }
+
+        match kind {
+            // `let A = { f() {} }`
+            Downgrade::Object => {
+                let mut properties = self.ast.new_vec_with_capacity(methods.len());
+                for (key, value) in methods {
+                    let property = self.ast.object_property(
+                        SPAN,
+                        PropertyKind::Init,
+                        self.ast.property_key_identifier(key),
+                        self.ast.function_expression(value),
+                        None,
+                        true,
+                        false,
+                        false,
+                    );
+                    properties.push(ObjectPropertyKind::ObjectProperty(property));
+                }
+                let object = self.ast.object_expression(SPAN, properties, None);
+                let declarator = self.ast.variable_declarator(
+                    SPAN,
+                    VariableDeclarationKind::Let,
+                    self.ast.binding_pattern(self.ast.binding_pattern_identifier(id), None, false),
+                    Some(object),
+                    false,
+                );
+                stmts.push(Statement::VariableDeclaration(self.ast.variable_declaration(
+                    class.span,
+                    VariableDeclarationKind::Let,
+                    self.ast.new_vec_single(declarator),
+                    Modifiers::empty(),
+                )));
+            }
+            // `function A() {} A.f = function () {}`
+            Downgrade::Function => {
+                let mut func = constructor.unwrap();
+                func.r#type = FunctionType::FunctionDeclaration;
+                func.span = class.span;
+                let name = id.name.clone();
+                func.id = Some(id);
+                stmts.push(self.ast.function_declaration(func));
+                for (key, value) in methods {
+                    let object = self.ast.identifier_reference_expression(
+                        IdentifierReference::new(SPAN, name.clone()),
+                    );
+                    let target = self.ast.simple_assignment_target_member_expression(
+                        self.ast.static_member(SPAN, object, key, false),
+                    );
+                    let assignment = self.ast.assignment_expression(
+                        SPAN,
+                        AssignmentOperator::Assign,
+                        target,
+                        self.ast.function_expression(value),
+                    );
+                    stmts.push(self.ast.expression_statement(SPAN, assignment));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<'a> VisitMut<'a> for ClassDowngrade<'a> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        walk_statements_mut(self, stmts);
+        let is_downgraded = |stmt: &Statement<'a>| {
+            matches!(stmt, Statement::ClassDeclaration(class)
+            if class.id.as_ref().and_then(|id| id.symbol_id.get()).is_some_and(|symbol_id| {
+                self.classes.contains_key(&symbol_id)
+            }))
+        };
+        if !stmts.iter().any(is_downgraded) {
+            return;
+        }
+
+        let mut new_stmts = self.ast.new_vec_with_capacity(stmts.len());
+        for stmt in stmts.drain(..) {
+            match stmt {
+                Statement::ClassDeclaration(class) => {
+                    if let Some(class) = self.downgrade_class(class, &mut new_stmts) {
+                        new_stmts.push(Statement::ClassDeclaration(class));
+                    }
+                }
+                stmt => new_stmts.push(stmt),
+            }
+        }
+        *stmts = new_stmts;
+    }
+}
+
+/// How the class bound to `symbol_id` is downgraded, if it is a class whose every use is known.
+fn downgrade(semantic: &Semantic, symbol_id: SymbolId) -> Option<Downgrade> {
+    let symbols = semantic.symbols();
+    let scopes = semantic.scopes();
+    let nodes = semantic.nodes();
+
+    let declaration = symbols.get_declaration(symbol_id);
+    let AstKind::Class(class) = nodes.kind(declaration) else { return None };
+    if !class.is_declaration()
+        || class.is_declare()
+        || class.super_class.is_some()
+        || !class.decorators.is_empty()
+        || !matches!(
+            nodes.parent_kind(declaration),
+            Some(
+                AstKind::Program(_)
+                    | AstKind::FunctionBody(_)
+                    | AstKind::BlockStatement(_)
+                    | AstKind::StaticBlock(_)
+                    | AstKind::SwitchCase(_)
+            )
+        )
+    {
+        return None;
+    }
+
+    let scope_id = symbols.get_scope_id(symbol_id);
+    let flags = scopes.get_flags(scope_id);
+    if !flags.is_strict_mode()
+        || flags.contains_direct_eval()
+        || symbols.get_flag(symbol_id).is_export()
+        // Top-level bindings of a script can be used by other scripts.
+        || (scopes.get_parent_id(scope_id).is_none() && !semantic.source_type().is_module())
+    {
+        return None;
+    }
+
+    let mut has_constructor = false;
+    let mut names = vec![];
+    for element in &class.body.body {
+        let ClassElement::MethodDefinition(def) = element else { return None };
+        if !def.decorators.is_empty() {
+            return None;
+        }
+        match def.kind {
+            MethodDefinitionKind::Constructor => has_constructor = true,
+            MethodDefinitionKind::Method if def.r#static && !def.computed => {
+                let PropertyKey::StaticIdentifier(key) = &def.key else { return None };
+                let name = key.name.as_str();
+                if RESERVED_NAMES.contains(&name) || names.contains(&name) {
+                    return None;
+                }
+                names.push(name);
+            }
+            _ => return None,
+        }
+    }
+
+    let mut finder = NewTargetOrSuperFinder::default();
+    finder.visit_class_body(&class.body);
+    if finder.found {
+        return None;
+    }
+
+    let mut is_constructed = has_constructor;
+    for reference_id in symbols.get_resolved_reference_ids(symbol_id) {
+        let reference = symbols.get_reference(reference_id);
+        if reference.is_write() {
+            return None;
+        }
+        match nodes.parent_kind(reference.node_id()) {
+            Some(AstKind::NewExpression(new_expr))
+                if new_expr.callee.span() == reference.span() =>
+            {
+                is_constructed = true;
+            }
+            Some(AstKind::BinaryExpression(binary_expr))
+                if binary_expr.operator == BinaryOperator::Instanceof
+                    && binary_expr.right.span() == reference.span() =>
+            {
+                is_constructed = true;
+            }
+            Some(AstKind::MemberExpression(MemberExpression::StaticMemberExpression(member)))
+                if member.object.span() == reference.span()
+                    && names.contains(&member.property.name.as_str()) => {}
+            _ => return None,
+        }
+    }
+
+    if !is_constructed {
+        return Some(Downgrade::Object);
+    }
+    // `class A{constructor(){}}` is 10 bytes longer than `function A(){}`, and each
+    // `A.f=function(){};` is 4 bytes plus the length of `A` longer than `static f(){}`.
+    let saved = if has_constructor { 10 } else { 0 };
+    let added = names.len() * (class.id.as_ref()?.name.len() + 4);
+    (has_constructor && added < saved).then_some(Downgrade::Function)
+}
+
+#[derive(Default)]
+struct NewTargetOrSuperFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for NewTargetOrSuperFinder {
+    fn visit_meta_property(&mut self, meta: &MetaProperty<'a>) {
+        if meta.meta.name == "new" {
+            self.found = true;
+        }
+    }
+
+    fn visit_super(&mut self, _super: &Super) {
+        self.found = true;
+    }
+}
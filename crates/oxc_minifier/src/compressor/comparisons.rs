@@ -0,0 +1,331 @@
+//! Comparisons
+//!
+//! Simplifies comparisons whose result is known from their shape rather than their values:
+//!
+//! * `a < b === true` -> `a < b` and `a < b === false` -> `!(a < b)`, when one side is a boolean.
+//! * `typeof a === typeof b` -> `typeof a == typeof b`, and `typeof a == "strnig"` -> `false`.
+//! * `x === x` -> `true` and `x < x` -> `false`, under `compress.unsafe_comparisons`.
+//! * `x > 1 && x > 5` -> `x > 5`, `x > 5 || x > 1` -> `x > 1` and `x > 5 && x < 3` -> `false`,
+//!   under `compress.unsafe_comparisons`.
+//!
+//! Every comparison with `NaN` is false, so a negated comparison is not the opposite comparison,
+//! `!(a < b)` is not `a >= b`, and checks which cover every number, e.g. `x > 1 || x < 3`, are not
+//! always true. Merged range checks are only ever false where one of the original checks is.
+//!
+//! Range checks compare an identifier with a numeric literal, which converts the value of the
+//! identifier to a number. They are only merged under `compress.unsafe_comparisons`, which assumes
+//! that the conversion gives the same number each time. With an object whose `valueOf` counts up,
+//! `x > 1 && x > 5` would otherwise skip a call to `valueOf` and compare the number of the first
+//! call with `5`. The identifier is also assumed not to be a symbol, where `x > 5 && x < 3`
+//! would throw.
+
+use std::cmp::Ordering;
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_span::{Atom, GetSpan};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+
+use super::{ast_util::MayHaveSideEffects, Compressor};
+
+/// Results of `typeof`.
+pub(super) const TYPEOF_RESULTS: [&str; 8] =
+    ["bigint", "boolean", "function", "number", "object", "string", "symbol", "undefined"];
+
+/// A bound which a range check sets on the checked value.
+#[derive(Debug, Clone, Copy)]
+struct Bound {
+    /// `x > v` and `x >= v` set a lower bound, `x < v` and `x <= v` an upper bound.
+    is_lower: bool,
+    value: f64,
+    inclusive: bool,
+}
+
+impl Bound {
+    /// Whether fewer values pass this bound than the `other` bound of the same kind.
+    fn is_stricter_than(self, other: Self) -> bool {
+        let ordering = if self.is_lower {
+            self.value.partial_cmp(&other.value)
+        } else {
+            other.value.partial_cmp(&self.value)
+        };
+        match ordering {
+            Some(Ordering::Greater) => true,
+            Some(Ordering::Equal) => !self.inclusive && other.inclusive,
+            _ => false,
+        }
+    }
+
+    /// Whether no value passes both the lower bound `self` and the upper bound `upper`.
+    fn excludes(self, upper: Self) -> bool {
+        match self.value.partial_cmp(&upper.value) {
+            Some(Ordering::Greater) => true,
+            Some(Ordering::Equal) => !(self.inclusive && upper.inclusive),
+            _ => false,
+        }
+    }
+}
+
+/// What two range checks on the same identifier merge into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Merged {
+    Left,
+    Right,
+    False,
+}
+
+impl<'a> Compressor<'a> {
+    pub(crate) fn fold_comparisons(&mut self, expr: &mut Expression<'a>) {
+        let folded = match expr {
+            Expression::BinaryExpression(binary_expr) => self
+                .fold_self_comparison(binary_expr)
+                .or_else(|| self.fold_boolean_comparison(binary_expr))
+                .or_else(|| self.fold_typeof_comparison(binary_expr)),
+            Expression::LogicalExpression(logical_expr) => self.fold_range_checks(logical_expr),
+            _ => None,
+        };
+        if let Some(folded) = folded {
+            *expr = folded;
+        }
+    }
+
+    /// Enabled by `compress.unsafe_comparisons`
+    ///
+    /// `x === x` -> `true`, `x !== x` -> `false` and `x < x` -> `false`
+    fn fold_self_comparison(&self, expr: &BinaryExpression<'a>) -> Option<Expression<'a>> {
+        if !self.options.unsafe_comparisons {
+            return None;
+        }
+        let (Expression::Identifier(left), Expression::Identifier(right)) =
+            (&expr.left, &expr.right)
+        else {
+            return None;
+        };
+        if left.name != right.name {
+            return None;
+        }
+        let value = match expr.operator {
+            BinaryOperator::Equality
+            | BinaryOperator::StrictEquality
+            | BinaryOperator::LessEqualThan
+            | BinaryOperator::GreaterEqualThan => true,
+            BinaryOperator::Inequality
+            | BinaryOperator::StrictInequality
+            | BinaryOperator::LessThan
+            | BinaryOperator::GreaterThan => false,
+            _ => return None,
+        };
+        Some(self.ast.literal_boolean_expression(self.ast.boolean_literal(expr.span, value)))
+    }
+
+    /// Enabled by `compress.comparisons`
+    ///
+    /// `a < b === true` -> `a < b` and `a < b !== true` -> `!(a < b)`
+    fn fold_boolean_comparison(
+        &mut self,
+        expr: &mut BinaryExpression<'a>,
+    ) -> Option<Expression<'a>> {
+        if !self.options.comparisons || !expr.operator.is_equality() {
+            return None;
+        }
+        let (value, is_left) =
+            match (boolean_literal_value(&expr.left), boolean_literal_value(&expr.right)) {
+                (Some(value), None) if is_boolean_valued(&expr.right) => (value, false),
+                (None, Some(value)) if is_boolean_valued(&expr.left) => (value, true),
+                _ => return None,
+            };
+        let is_equality =
+            matches!(expr.operator, BinaryOperator::Equality | BinaryOperator::StrictEquality);
+        let boolean =
+            self.ast.move_expression(if is_left { &mut expr.left } else { &mut expr.right });
+        if value == is_equality {
+            return Some(boolean);
+        }
+        Some(self.negate(boolean))
+    }
+
+    /// `!a` for a boolean `a`, which is not `a` with the opposite comparison because of `NaN`,
+    /// except for equality.
+    fn negate(&mut self, mut expr: Expression<'a>) -> Expression<'a> {
+        match &mut expr {
+            Expression::BinaryExpression(binary_expr) => {
+                if let Some(operator) = binary_expr.operator.equality_inverse_operator() {
+                    binary_expr.operator = operator;
+                    return expr;
+                }
+            }
+            Expression::UnaryExpression(unary_expr)
+                if unary_expr.operator == UnaryOperator::LogicalNot
+                    && is_boolean_valued(&unary_expr.argument) =>
+            {
+                return self.ast.move_expression(&mut unary_expr.argument);
+            }
+            _ => {}
+        }
+        self.ast.unary_expression(expr.span(), UnaryOperator::LogicalNot, expr)
+    }
+
+    /// Enabled by `compress.comparisons`
+    ///
+    /// `typeof a === typeof b` -> `typeof a == typeof b` and `typeof a === "strnig"` -> `false`
+    fn fold_typeof_comparison(
+        &mut self,
+        expr: &mut BinaryExpression<'a>,
+    ) -> Option<Expression<'a>> {
+        if !self.options.comparisons || !expr.operator.is_equality() {
+            return None;
+        }
+        match (&expr.left, &expr.right) {
+            (left, right) if is_typeof(left) && is_typeof(right) => {}
+            (Expression::StringLiteral(lit), other) | (other, Expression::StringLiteral(lit))
+                if is_typeof(other) =>
+            {
+                if !TYPEOF_RESULTS.contains(&lit.value.as_str()) && !other.may_have_side_effects() {
+                    let value = matches!(
+                        expr.operator,
+                        BinaryOperator::Inequality | BinaryOperator::StrictInequality
+                    );
+                    let boolean_literal = self.ast.boolean_literal(expr.span, value);
+                    return Some(self.ast.literal_boolean_expression(boolean_literal));
+                }
+            }
+            _ => return None,
+        }
+        // Both sides are strings.
+        expr.operator = match expr.operator {
+            BinaryOperator::StrictEquality => BinaryOperator::Equality,
+            BinaryOperator::StrictInequality => BinaryOperator::Inequality,
+            operator => operator,
+        };
+        None
+    }
+
+    /// Enabled by `compress.unsafe_comparisons`
+    ///
+    /// `x > 1 && x > 5` -> `x > 5` and `a && x > 5 && x < 3` -> `a && false`
+    fn fold_range_checks(&mut self, expr: &mut LogicalExpression<'a>) -> Option<Expression<'a>> {
+        if !self.options.unsafe_comparisons || expr.operator == LogicalOperator::Coalesce {
+            return None;
+        }
+        let operator = expr.operator;
+        let span = expr.span;
+        // `a && x > 1 && x > 5` is `(a && x > 1) && x > 5`.
+        if let Expression::LogicalExpression(left) = &mut expr.left {
+            if left.operator == operator {
+                match merge_range_checks(operator, &left.right, &expr.right)? {
+                    Merged::Left => {}
+                    Merged::Right => left.right = self.ast.move_expression(&mut expr.right),
+                    Merged::False => {
+                        let boolean_literal = self.ast.boolean_literal(span, false);
+                        left.right = self.ast.literal_boolean_expression(boolean_literal);
+                    }
+                }
+                left.span = span;
+                return Some(self.ast.move_expression(&mut expr.left));
+            }
+        }
+        match merge_range_checks(operator, &expr.left, &expr.right)? {
+            Merged::Left => Some(self.ast.move_expression(&mut expr.left)),
+            Merged::Right => Some(self.ast.move_expression(&mut expr.right)),
+            Merged::False => {
+                Some(self.ast.literal_boolean_expression(self.ast.boolean_literal(span, false)))
+            }
+        }
+    }
+}
+
+/// Whether `expr` always evaluates to a boolean.
+fn is_boolean_valued(expr: &Expression) -> bool {
+    match expr {
+        Expression::BinaryExpression(binary_expr) => {
+            let operator = binary_expr.operator;
+            operator.is_equality() || operator.is_compare() || operator.is_relational()
+        }
+        Expression::UnaryExpression(unary_expr) => unary_expr.operator == UnaryOperator::LogicalNot,
+        _ => false,
+    }
+}
+
+/// `true` and `false`, which may already be compressed to `!0` and `!1`.
+fn boolean_literal_value(expr: &Expression) -> Option<bool> {
+    match expr {
+        Expression::BooleanLiteral(lit) => Some(lit.value),
+        Expression::UnaryExpression(unary_expr)
+            if unary_expr.operator == UnaryOperator::LogicalNot =>
+        {
+            match &unary_expr.argument {
+                Expression::NumericLiteral(lit) => Some(lit.value == 0.0),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_typeof(expr: &Expression) -> bool {
+    matches!(expr, Expression::UnaryExpression(unary_expr) if unary_expr.operator == UnaryOperator::Typeof)
+}
+
+/// `1`, `-1.5` and other numbers written as literals.
+fn numeric_literal_value(expr: &Expression) -> Option<f64> {
+    match expr {
+        Expression::NumericLiteral(lit) => Some(lit.value),
+        Expression::UnaryExpression(unary_expr)
+            if unary_expr.operator == UnaryOperator::UnaryNegation =>
+        {
+            match &unary_expr.argument {
+                Expression::NumericLiteral(lit) => Some(-lit.value),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// `x < 1` and `1 > x`, as the name of `x` and the bound on it.
+fn range_check<'b, 'a>(expr: &'b Expression<'a>) -> Option<(&'b Atom<'a>, Bound)> {
+    let Expression::BinaryExpression(binary_expr) = expr else { return None };
+    let (ident, value, operator) = match (&binary_expr.left, &binary_expr.right) {
+        (Expression::Identifier(ident), other) => {
+            (ident, numeric_literal_value(other)?, binary_expr.operator)
+        }
+        (other, Expression::Identifier(ident)) => {
+            (ident, numeric_literal_value(other)?, binary_expr.operator.compare_inverse_operator()?)
+        }
+        _ => return None,
+    };
+    let (is_lower, inclusive) = match operator {
+        BinaryOperator::GreaterThan => (true, false),
+        BinaryOperator::GreaterEqualThan => (true, true),
+        BinaryOperator::LessThan => (false, false),
+        BinaryOperator::LessEqualThan => (false, true),
+        _ => return None,
+    };
+    Some((&ident.name, Bound { is_lower, value, inclusive }))
+}
+
+/// Merges `left && right` or `left || right`, if both are range checks on the same identifier.
+fn merge_range_checks(
+    operator: LogicalOperator,
+    left: &Expression,
+    right: &Expression,
+) -> Option<Merged> {
+    let (left_name, left_bound) = range_check(left)?;
+    let (right_name, right_bound) = range_check(right)?;
+    if left_name != right_name {
+        return None;
+    }
+    if left_bound.is_lower == right_bound.is_lower {
+        // `&&` keeps the stricter check and `||` the looser one.
+        let keep_left =
+            left_bound.is_stricter_than(right_bound) == (operator == LogicalOperator::And);
+        return Some(if keep_left { Merged::Left } else { Merged::Right });
+    }
+    if operator == LogicalOperator::Or {
+        // `x > 1 || x < 3` is false for `NaN`.
+        return None;
+    }
+    let (lower, upper) =
+        if left_bound.is_lower { (left_bound, right_bound) } else { (right_bound, left_bound) };
+    lower.excludes(upper).then_some(Merged::False)
+}
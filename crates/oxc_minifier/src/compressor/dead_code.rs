@@ -0,0 +1,212 @@
+//! Dead Branch Elimination
+//!
+//! Removes the branches which can never run because their test is a constant:
+//!
+//! * `if (true) a; else b` → `a` and `if (false) a` → ``
+//! * `true ? a : b` → `a`
+//!
+//! `false && a` and `true || a` are already folded by `fold_expression`.
+//!
+//! Together with `compress.node_env`, this removes the code for other environments,
+//! e.g. `if (process.env.NODE_ENV !== "production") devWarn()` in a production build.
+//!
+//! Only literal tests, and `!` of them, are treated as constants, so a test never has side
+//! effects. A removed `if` branch which declares a `var` or a function is kept, because these
+//! bindings are hoisted out of the branch.
+//!
+//! Calls whose result is unused are removed when the semantic analysis found that the callee
+//! has no side effects, so `function double(x) { return x * 2 } double(1)` keeps only the
+//! declaration, or when the callee is one of `compress.pure_funcs`, such as `console.log`,
+//! whatever it is bound to. The arguments must not have side effects either.
+//!
+//! Loading a module may run any code, so `require("m")` and `import("m")` are calls like any
+//! other, which are neither removed, reordered nor moved out of their condition, unless the module
+//! is one of `compress.pure_modules`. Their result is then dropped when it is unused.
+
+use oxc_allocator::Vec;
+use oxc_ast::visit::walk::walk_statement;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, match_expression, Visit};
+use oxc_semantic::ScopeFlags;
+use oxc_syntax::operator::UnaryOperator;
+
+use super::{ast_util::MayHaveSideEffects, Compressor};
+
+impl<'a> Compressor<'a> {
+    /// Enabled by `compress.dead_code`
+    ///
+    /// `if (true) a; else b` -> `a` and `if (false) a` -> ``
+    pub(crate) fn remove_dead_branch(&mut self, stmt: &mut Statement<'a>) {
+        if !self.options.dead_code {
+            return;
+        }
+        let Statement::IfStatement(if_stmt) = stmt else { return };
+        let Some(test) = constant_test(&if_stmt.test) else { return };
+        let if_stmt = &mut **if_stmt;
+        let span = if_stmt.span;
+        let (kept, removed) = if test {
+            (Some(&mut if_stmt.consequent), if_stmt.alternate.as_ref())
+        } else {
+            (if_stmt.alternate.as_mut(), Some(&if_stmt.consequent))
+        };
+        if removed.is_some_and(declares_hoisted_binding)
+            || kept.as_ref().is_some_and(|kept| kept.is_declaration())
+        {
+            return;
+        }
+        *stmt = match kept {
+            Some(kept) => self.ast.move_statement(kept),
+            None => self.ast.empty_statement(span),
+        };
+    }
+
+    /// Enabled by `compress.dead_code`
+    ///
+    /// `f(a)` -> `` when `f` has no side effects or is one of `compress.pure_funcs`, and
+    /// `require("m")` -> `` when `m` is one of `compress.pure_modules`.
+    pub(crate) fn drop_unused_pure_call(&mut self, stmt: &Statement<'a>) -> bool {
+        if !self.options.dead_code {
+            return false;
+        }
+        let Statement::ExpressionStatement(expr_stmt) = stmt else { return false };
+        if self.is_pure_module_load(&expr_stmt.expression) {
+            return true;
+        }
+        let Expression::CallExpression(call_expr) = &expr_stmt.expression else { return false };
+        (self.is_pure_func(&call_expr.callee) || self.has_no_side_effects(&call_expr.callee))
+            && call_expr.arguments.iter().all(|arg| match arg {
+                Argument::SpreadElement(_) => false,
+                arg @ match_expression!(Argument) => !arg.to_expression().may_have_side_effects(),
+            })
+    }
+
+    /// A function declared in the program which the semantic analysis found to have no side
+    /// effects.
+    fn has_no_side_effects(&self, callee: &Expression<'a>) -> bool {
+        // The function may be reassigned by a direct `eval`.
+        if self.dynamic_scope {
+            return false;
+        }
+        let Expression::Identifier(callee) = callee else { return false };
+        callee
+            .reference_id
+            .get()
+            .and_then(|reference_id| self.symbols.get_reference(reference_id).symbol_id())
+            .is_some_and(|symbol_id| self.symbols.has_no_side_effects(symbol_id))
+    }
+
+    /// One of `compress.pure_funcs`, e.g. `invariant` or `console.log`.
+    fn is_pure_func(&self, callee: &Expression<'a>) -> bool {
+        self.options.pure_funcs.iter().any(|name| is_function_named(callee, name))
+    }
+
+    /// `require("m")` or `import("m")` of a module in `compress.pure_modules`.
+    fn is_pure_module_load(&self, expr: &Expression<'a>) -> bool {
+        let source = match expr {
+            Expression::CallExpression(call_expr) => {
+                match (&call_expr.callee, call_expr.arguments.as_slice()) {
+                    (Expression::Identifier(callee), [Argument::StringLiteral(source)])
+                        if callee.name == "require" && self.is_global_reference(callee) =>
+                    {
+                        source
+                    }
+                    _ => return false,
+                }
+            }
+            Expression::ImportExpression(import_expr) => {
+                match (&import_expr.source, import_expr.arguments.as_slice()) {
+                    (Expression::StringLiteral(source), []) => source,
+                    _ => return false,
+                }
+            }
+            _ => return false,
+        };
+        self.options.is_pure_module(&source.value)
+    }
+
+    /// Remove the empty statements left behind by `remove_dead_branch`.
+    /// Enabled by `compress.dead_code`
+    pub(crate) fn remove_empty_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if self.options.dead_code {
+            stmts.retain(|stmt| !matches!(stmt, Statement::EmptyStatement(_)));
+        }
+    }
+
+    /// Enabled by `compress.dead_code`
+    ///
+    /// `true ? a : b` -> `a` and `!1 ? a : b` -> `b`
+    pub(crate) fn remove_dead_operand(&mut self, expr: &mut Expression<'a>) {
+        if !self.options.dead_code {
+            return;
+        }
+        let Expression::ConditionalExpression(cond_expr) = expr else { return };
+        *expr = match constant_test(&cond_expr.test) {
+            Some(true) => self.ast.move_expression(&mut cond_expr.consequent),
+            Some(false) => self.ast.move_expression(&mut cond_expr.alternate),
+            None => return,
+        };
+    }
+}
+
+/// The truthiness of a literal test, such as `false`, `!0` or `"production"`.
+fn constant_test(expr: &Expression) -> Option<bool> {
+    match expr {
+        Expression::BooleanLiteral(lit) => Some(lit.value),
+        Expression::NumericLiteral(lit) => Some(lit.value != 0.0 && !lit.value.is_nan()),
+        Expression::StringLiteral(lit) => Some(!lit.value.is_empty()),
+        Expression::NullLiteral(_) => Some(false),
+        Expression::UnaryExpression(unary_expr)
+            if unary_expr.operator == UnaryOperator::LogicalNot =>
+        {
+            constant_test(&unary_expr.argument).map(|value| !value)
+        }
+        Expression::ParenthesizedExpression(paren_expr) => constant_test(&paren_expr.expression),
+        _ => None,
+    }
+}
+
+/// Whether `expr` is the identifier `name`, or the path of static properties `name`,
+/// e.g. `console.log`.
+fn is_function_named(expr: &Expression, name: &str) -> bool {
+    match expr {
+        Expression::Identifier(ident) => ident.name == name,
+        Expression::StaticMemberExpression(member) => {
+            name.rsplit_once('.').is_some_and(|(object, property)| {
+                member.property.name == property && is_function_named(&member.object, object)
+            })
+        }
+        _ => false,
+    }
+}
+
+/// Whether `stmt` declares a `var` or a function, whose binding outlives the statement.
+pub(super) fn declares_hoisted_binding(stmt: &Statement) -> bool {
+    let mut finder = HoistedBindingFinder { found: false };
+    finder.visit_statement(stmt);
+    finder.found
+}
+
+struct HoistedBindingFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for HoistedBindingFinder {
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        if !self.found {
+            walk_statement(self, stmt);
+        }
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        self.found |= decl.kind.is_var();
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, _flags: Option<ScopeFlags>) {
+        self.found |= func.is_declaration();
+    }
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+
+    // Expressions can only declare bindings inside functions, which are not hoisted out of them.
+    fn visit_expression(&mut self, _expr: &Expression<'a>) {}
+}
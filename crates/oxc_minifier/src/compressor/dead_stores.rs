@@ -0,0 +1,333 @@
+//! Dead Store Elimination
+//!
+//! Removes assignments to local variables whose value is never read, because the variable is
+//! assigned again or goes out of scope first.
+//!
+//! ```javascript
+//! let x;
+//! x = 1;
+//! x = 2;
+//! ```
+//!
+//! is compressed to `let x; x = 2;`. The value is kept as an expression statement when it has
+//! side effects, `x = f(); x = 2` → `f(); x = 2`.
+//!
+//! Reads and writes are classified from the references resolved by the [`SemanticBuilder`].
+//! Only stores followed by a straight-line run of expression statements and variable declarations
+//! are considered, so that every statement between the store and the next write runs in order.
+//! A store is kept whenever it may be observed:
+//!
+//! * the variable is referenced in between, or by the value of the next write,
+//! * the variable is not declared with `var` or `let`, or its scope contains a direct `eval` /
+//!   `with`,
+//! * a statement in between has side effects, unless no one else can read the variable: it is not
+//!   captured by a closure, not exported nor visible to other scripts, and no `try` statement of the
+//!   function can catch an exception thrown in between.
+
+use oxc_allocator::{Allocator, Vec};
+use oxc_ast::visit::{
+    walk::{
+        walk_arrow_expression, walk_function, walk_statements, walk_static_block,
+        walk_try_statement,
+    },
+    walk_mut::walk_statements_mut,
+};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder, AstKind, Visit, VisitMut};
+use oxc_semantic::{
+    AstNode, AstNodeId, ReferenceId, ScopeFlags, Semantic, SemanticBuilder, SymbolId,
+};
+use oxc_span::Span;
+use oxc_syntax::operator::AssignmentOperator;
+use rustc_hash::FxHashSet;
+
+use super::ast_util::MayHaveSideEffects;
+
+pub struct DeadStores<'a> {
+    ast: AstBuilder<'a>,
+    /// References of the assigned identifiers of dead `x = value` statements.
+    dead_stores: FxHashSet<ReferenceId>,
+}
+
+impl<'a> DeadStores<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { ast: AstBuilder::new(allocator), dead_stores: FxHashSet::default() }
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        {
+            let semantic = SemanticBuilder::new("", program.source_type).build(program).semantic;
+            let mut finder = DeadStoreFinder::new(&semantic);
+            finder.visit_program(program);
+            self.dead_stores = finder.dead_stores;
+        }
+        if !self.dead_stores.is_empty() {
+            self.visit_program(program);
+        }
+    }
+
+    /// Returns `true` if the statement is a dead store without side effects, which can be removed.
+    fn remove_dead_store(&mut self, stmt: &mut Statement<'a>) -> bool {
+        let Statement::ExpressionStatement(expr_stmt) = stmt else { return false };
+        let Expression::AssignmentExpression(assign) = &mut expr_stmt.expression else {
+            return false;
+        };
+        let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+            return false;
+        };
+        if !ident.reference_id.get().is_some_and(|id| self.dead_stores.contains(&id)) {
+            return false;
+        }
+        let value = self.ast.move_expression(&mut assign.right);
+        if !value.may_have_side_effects() {
+            return true;
+        }
+        expr_stmt.expression = value;
+        false
+    }
+}
+
+impl<'a> VisitMut<'a> for DeadStores<'a> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let mut i = 0;
+        while i < stmts.len() {
+            if self.remove_dead_store(&mut stmts[i]) {
+                stmts.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+        walk_statements_mut(self, stmts);
+    }
+}
+
+struct DeadStoreFinder<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+    /// Number of `try` statements around the current statement, within the current function.
+    try_depth: usize,
+    /// The next statement list is the consequent of a `switch` case, which may fall through.
+    is_switch_case: bool,
+    dead_stores: FxHashSet<ReferenceId>,
+}
+
+impl<'s, 'a> DeadStoreFinder<'s, 'a> {
+    fn new(semantic: &'s Semantic<'a>) -> Self {
+        Self { semantic, try_depth: 0, is_switch_case: false, dead_stores: FxHashSet::default() }
+    }
+
+    /// `x = value;`
+    fn store<'b>(
+        stmt: &'b Statement<'a>,
+    ) -> Option<(&'b IdentifierReference<'a>, &'b Expression<'a>)> {
+        let Statement::ExpressionStatement(expr_stmt) = stmt else { return None };
+        let Expression::AssignmentExpression(assign) = &expr_stmt.expression else { return None };
+        if assign.operator != AssignmentOperator::Assign {
+            return None;
+        }
+        let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+            return None;
+        };
+        Some((ident, &assign.right))
+    }
+
+    fn symbol_id(&self, ident: &IdentifierReference<'a>) -> Option<SymbolId> {
+        let reference_id = ident.reference_id.get()?;
+        self.semantic.symbols().get_reference(reference_id).symbol_id()
+    }
+
+    fn find_dead_stores(&mut self, stmts: &[Statement<'a>], can_exit_scope: bool) {
+        for (i, stmt) in stmts.iter().enumerate() {
+            let Some((ident, _)) = Self::store(stmt) else { continue };
+            let Some(symbol_id) = self.symbol_id(ident) else { continue };
+            if self.is_in_temporal_dead_zone(symbol_id, ident.span) {
+                continue;
+            }
+            if self.is_dead_store(symbol_id, stmts, i, can_exit_scope) {
+                self.dead_stores.insert(ident.reference_id.get().unwrap());
+            }
+        }
+    }
+
+    /// Whether the store to `symbol_id` at `stmts[index]` is never read.
+    fn is_dead_store(
+        &self,
+        symbol_id: SymbolId,
+        stmts: &[Statement<'a>],
+        index: usize,
+        can_exit_scope: bool,
+    ) -> bool {
+        let Some(is_local) = self.is_local_variable(symbol_id) else { return false };
+        // Nothing can observe the variable if a statement in between throws.
+        let is_contained = is_local && self.try_depth == 0;
+
+        for stmt in &stmts[index + 1..] {
+            if let Some((ident, value)) = Self::store(stmt) {
+                if self.symbol_id(ident) == Some(symbol_id) {
+                    return !self.is_referenced_in(symbol_id, |finder| {
+                        finder.visit_expression(value);
+                    }) && (is_contained || !value.may_have_side_effects());
+                }
+            }
+            let may_have_side_effects = match stmt {
+                Statement::ExpressionStatement(expr_stmt) => {
+                    expr_stmt.expression.may_have_side_effects()
+                }
+                Statement::VariableDeclaration(decl) => {
+                    decl.declarations.iter().any(|declarator| {
+                        !declarator.id.kind.is_binding_identifier()
+                            || declarator
+                                .init
+                                .as_ref()
+                                .is_some_and(MayHaveSideEffects::may_have_side_effects)
+                    })
+                }
+                _ => return false,
+            };
+            if (may_have_side_effects && !is_contained)
+                || self.is_referenced_in(symbol_id, |finder| finder.visit_statement(stmt))
+            {
+                return false;
+            }
+        }
+
+        // `{ let x; use(x); x = 1; }`
+        can_exit_scope && is_local && self.is_let_declared_in(symbol_id, stmts)
+    }
+
+    /// Returns `None` if the symbol is not a `var` or `let` variable whose stores can be removed,
+    /// and whether the variable can only be read by the function declaring it otherwise.
+    fn is_local_variable(&self, symbol_id: SymbolId) -> Option<bool> {
+        let symbols = self.semantic.symbols();
+        let scopes = self.semantic.scopes();
+        let nodes = self.semantic.nodes();
+
+        let declaration = symbols.get_declaration(symbol_id);
+        let AstKind::VariableDeclarator(declarator) = nodes.kind(declaration) else { return None };
+        if declarator.kind.is_const() {
+            return None;
+        }
+        let scope_id = symbols.get_scope_id(symbol_id);
+        if scopes.get_flags(scope_id).contains_direct_eval() {
+            return None;
+        }
+
+        // Top-level variables of a script are shared with other scripts.
+        if scopes.get_parent_id(scope_id).is_none()
+            && (!self.semantic.source_type().is_module() || symbols.get_flag(symbol_id).is_export())
+        {
+            return Some(false);
+        }
+        let owner = self.function_owner(declaration);
+        Some(symbols.get_resolved_reference_ids(symbol_id).all(|reference_id| {
+            self.function_owner(symbols.get_reference(reference_id).node_id()) == owner
+        }))
+    }
+
+    /// `x = 1; let x;` throws a `ReferenceError`.
+    fn is_in_temporal_dead_zone(&self, symbol_id: SymbolId, span: Span) -> bool {
+        let declaration = self.semantic.symbols().get_declaration(symbol_id);
+        let AstKind::VariableDeclarator(declarator) = self.semantic.nodes().kind(declaration)
+        else {
+            return false;
+        };
+        declarator.kind == VariableDeclarationKind::Let && span.start < declarator.span.end
+    }
+
+    /// The closest function, class or static block around the node.
+    fn function_owner(&self, node_id: AstNodeId) -> Option<AstNodeId> {
+        self.semantic
+            .nodes()
+            .iter_parents(node_id)
+            .find(|node| {
+                matches!(
+                    node.kind(),
+                    AstKind::Function(_)
+                        | AstKind::ArrowFunctionExpression(_)
+                        | AstKind::Class(_)
+                        | AstKind::StaticBlock(_)
+                )
+            })
+            .map(AstNode::id)
+    }
+
+    /// Whether the variable is declared with `let` by one of the statements, and therefore goes
+    /// out of scope at the end of them.
+    fn is_let_declared_in(&self, symbol_id: SymbolId, stmts: &[Statement<'a>]) -> bool {
+        let declaration = self.semantic.symbols().get_declaration(symbol_id);
+        let Some(parent) = self.semantic.nodes().parent_node(declaration) else { return false };
+        let AstKind::VariableDeclaration(decl) = parent.kind() else { return false };
+        decl.kind == VariableDeclarationKind::Let
+            && stmts.iter().any(|stmt| {
+                matches!(stmt, Statement::VariableDeclaration(d) if std::ptr::eq(&**d, decl))
+            })
+    }
+
+    fn is_referenced_in<F: FnOnce(&mut ReferenceFinder<'s, 'a>)>(
+        &self,
+        symbol_id: SymbolId,
+        visit: F,
+    ) -> bool {
+        let mut finder = ReferenceFinder { semantic: self.semantic, symbol_id, found: false };
+        visit(&mut finder);
+        finder.found
+    }
+}
+
+impl<'s, 'a> Visit<'a> for DeadStoreFinder<'s, 'a> {
+    fn visit_statements(&mut self, stmts: &Vec<'a, Statement<'a>>) {
+        let is_switch_case = std::mem::take(&mut self.is_switch_case);
+        self.find_dead_stores(stmts, !is_switch_case);
+        walk_statements(self, stmts);
+    }
+
+    fn visit_switch_case(&mut self, case: &SwitchCase<'a>) {
+        if let Some(test) = &case.test {
+            self.visit_expression(test);
+        }
+        self.is_switch_case = true;
+        self.visit_statements(&case.consequent);
+    }
+
+    fn visit_try_statement(&mut self, stmt: &TryStatement<'a>) {
+        self.try_depth += 1;
+        walk_try_statement(self, stmt);
+        self.try_depth -= 1;
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, flags: Option<ScopeFlags>) {
+        let try_depth = std::mem::take(&mut self.try_depth);
+        walk_function(self, func, flags);
+        self.try_depth = try_depth;
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &ArrowFunctionExpression<'a>) {
+        let try_depth = std::mem::take(&mut self.try_depth);
+        walk_arrow_expression(self, expr);
+        self.try_depth = try_depth;
+    }
+
+    fn visit_static_block(&mut self, block: &StaticBlock<'a>) {
+        let try_depth = std::mem::take(&mut self.try_depth);
+        walk_static_block(self, block);
+        self.try_depth = try_depth;
+    }
+}
+
+/// Finds any reference to a symbol.
+struct ReferenceFinder<'s, 'a> {
+    semantic: &'s Semantic<'a>,
+    symbol_id: SymbolId,
+    found: bool,
+}
+
+impl<'s, 'a> Visit<'a> for ReferenceFinder<'s, 'a> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if let Some(reference_id) = ident.reference_id.get() {
+            if self.semantic.symbols().get_reference(reference_id).symbol_id()
+                == Some(self.symbol_id)
+            {
+                self.found = true;
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Debug Code Removal
+//!
+//! Removes `debugger` statements with `compress.drop_debugger`, and the calls of the methods of
+//! the global `console` with `compress.drop_console`, so that
+//!
+//! ```javascript
+//! if (DEBUG) console.log("state", state);
+//! init(), console.time("run"), run();
+//! ```
+//!
+//! becomes `DEBUG; init(), run()`. A call whose value is unused is removed with its arguments,
+//! including in the branches of conditional and logical expressions,
+//! `a ? console.log(a) : b()` → `a || b()`, and a call whose value is used becomes `void 0`.
+//! Calls on a `console` binding which shadows the global are kept.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_span::GetSpan;
+use oxc_syntax::operator::LogicalOperator;
+
+use super::{Compressor, SPAN};
+
+impl<'a> Compressor<'a> {
+    /// Enabled by `compress.drop_debugger` and `compress.drop_console`
+    ///
+    /// `debugger` -> `` and `a(), console.log(a)` -> `a()`
+    ///
+    /// Returns whether all of `stmt` is removed.
+    pub(crate) fn drop_debug_code(&mut self, stmt: &mut Statement<'a>) -> bool {
+        match stmt {
+            Statement::DebuggerStatement(_) => self.options.drop_debugger,
+            Statement::ExpressionStatement(expr_stmt) => {
+                self.options.drop_console
+                    && self.drop_unused_console_calls(&mut expr_stmt.expression)
+            }
+            _ => false,
+        }
+    }
+
+    /// Enabled by `compress.drop_debugger` and `compress.drop_console`
+    ///
+    /// `if (a) debugger` -> `if (a);`
+    pub(crate) fn drop_debug_statement(&mut self, stmt: &mut Statement<'a>) {
+        if self.drop_debug_code(stmt) {
+            *stmt = self.ast.empty_statement(stmt.span());
+        }
+    }
+
+    /// Enabled by `compress.drop_console`
+    ///
+    /// `(console.log(a), b)` -> `b` and `console.log(a)` -> `void 0`
+    pub(crate) fn drop_console_call(&mut self, expr: &mut Expression<'a>) {
+        if !self.options.drop_console {
+            return;
+        }
+        if self.is_console_call(expr) {
+            *expr = self.ast.void_0();
+            return;
+        }
+        let Expression::SequenceExpression(seq) = expr else { return };
+        // The value of the last expression is used.
+        let last = seq.expressions.len().saturating_sub(1);
+        let mut index = 0;
+        seq.expressions.retain_mut(|expr| {
+            let keep = index == last || !self.drop_unused_console_calls(expr);
+            index += 1;
+            keep
+        });
+        if seq.expressions.len() == 1 {
+            *expr = seq.expressions.pop().unwrap();
+        }
+    }
+
+    /// Removes the console calls from `expr`, whose value is unused.
+    /// Returns whether all of `expr` is removed.
+    fn drop_unused_console_calls(&mut self, expr: &mut Expression<'a>) -> bool {
+        match expr {
+            Expression::CallExpression(_) => self.is_console_call(expr),
+            Expression::SequenceExpression(seq) => {
+                seq.expressions.retain_mut(|expr| !self.drop_unused_console_calls(expr));
+                match seq.expressions.len() {
+                    0 => true,
+                    1 => {
+                        *expr = seq.expressions.pop().unwrap();
+                        false
+                    }
+                    _ => false,
+                }
+            }
+            // `a ? console.log(a) : b()` -> `a || b()`
+            Expression::ConditionalExpression(cond_expr) => {
+                let consequent = self.drop_unused_console_calls(&mut cond_expr.consequent);
+                let alternate = self.drop_unused_console_calls(&mut cond_expr.alternate);
+                if !consequent && !alternate {
+                    return false;
+                }
+                let test = self.ast.move_expression(&mut cond_expr.test);
+                if consequent && alternate {
+                    *expr = test;
+                    return self.drop_unused_console_calls(expr);
+                }
+                *expr = if consequent {
+                    let alternate = self.ast.move_expression(&mut cond_expr.alternate);
+                    self.ast.logical_expression(SPAN, test, LogicalOperator::Or, alternate)
+                } else {
+                    let consequent = self.ast.move_expression(&mut cond_expr.consequent);
+                    self.ast.logical_expression(SPAN, test, LogicalOperator::And, consequent)
+                };
+                false
+            }
+            // `a && console.log(a)` -> `a`
+            Expression::LogicalExpression(logical_expr) => {
+                if !self.drop_unused_console_calls(&mut logical_expr.right) {
+                    return false;
+                }
+                *expr = self.ast.move_expression(&mut logical_expr.left);
+                self.drop_unused_console_calls(expr)
+            }
+            Expression::ParenthesizedExpression(paren_expr) => {
+                self.drop_unused_console_calls(&mut paren_expr.expression)
+            }
+            _ => false,
+        }
+    }
+
+    /// `console.log(a)` or `console["log"](a)`, where `console` is the global.
+    fn is_console_call(&self, expr: &Expression<'a>) -> bool {
+        let Expression::CallExpression(call_expr) = expr else { return false };
+        let Some(member_expr) = call_expr.callee.as_member_expression() else { return false };
+        member_expr
+            .object()
+            .get_identifier_reference()
+            .is_some_and(|ident| ident.name == "console" && self.is_global_reference(ident))
+    }
+}
@@ -0,0 +1,274 @@
+//! Default Parameters
+//!
+//! Turns the checks for missing arguments at the start of a function, which older compilers
+//! lower default parameters to, back into default parameters:
+//!
+//! ```javascript
+//! function f(a, b) {
+//!     if (a === void 0) a = 1;
+//!     if (b === undefined) { b = a + 1; }
+//!     return a + b;
+//! }
+//! ```
+//!
+//! is compressed to `function f(a = 1, b = a + 1) { return a + b }`. The test may also be
+//! `typeof a === "undefined"`.
+//!
+//! A default value is evaluated before the body is entered, in the order of the parameters, so
+//! only the leading checks are converted, while the parameters are in order, and a default value
+//! may only reference the parameters before it or the bindings outside of the function.
+//!
+//! A function with default parameters can not have a `"use strict"` directive or duplicate
+//! parameters, and its `arguments` no longer follows the parameters in sloppy mode, so such
+//! functions are left alone. Default values which contain `yield` or `await` are left in the body.
+//!
+//! Unsafe because the `length` of the function no longer counts the parameter with the default
+//! value and the ones after it.
+
+use oxc_allocator::Allocator;
+use oxc_ast::visit::walk_mut::{walk_arrow_expression_mut, walk_function_mut};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder, Visit, VisitMut};
+use oxc_semantic::{ReferenceId, ScopeFlags, SemanticBuilder, SymbolId, SymbolTable};
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator};
+
+use super::SPAN;
+
+pub struct DefaultParams<'a> {
+    ast: AstBuilder<'a>,
+    symbols: SymbolTable,
+    /// The spans of the references to `arguments`.
+    arguments_spans: std::vec::Vec<Span>,
+}
+
+impl<'a> DefaultParams<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            symbols: SymbolTable::default(),
+            arguments_spans: vec![],
+        }
+    }
+
+    pub fn build(mut self, program: &mut Program<'a>) {
+        let semantic = SemanticBuilder::new("", program.source_type).build(program).semantic;
+        let symbols = semantic.symbols();
+        self.arguments_spans = semantic
+            .scopes()
+            .root_unresolved_references()
+            .get("arguments")
+            .map_or_else(std::vec::Vec::new, |reference_ids| {
+                reference_ids.iter().map(|&id| symbols.get_reference(id).span()).collect()
+            });
+        self.symbols = semantic.into_symbol_table_and_scope_tree().0;
+        self.visit_program(program);
+    }
+
+    fn symbol_of(&self, ident: &IdentifierReference<'a>) -> Option<SymbolId> {
+        ident.reference_id.get().and_then(|id| self.symbols.get_reference(id).symbol_id())
+    }
+
+    /// Move the leading checks of the `body` of the function at `span` into its `params`.
+    fn convert(&self, span: Span, params: &mut FormalParameters<'a>, body: &mut FunctionBody<'a>) {
+        if body.has_use_strict_directive()
+            || self.arguments_spans.iter().any(|arguments| contains(span, *arguments))
+            || params.items.iter().any(|param| self.is_redeclared(param))
+        {
+            return;
+        }
+        let mut indices = std::vec::Vec::new();
+        for stmt in &body.statements {
+            let Some((index, default)) = self.default_assignment(stmt, params) else { break };
+            if indices.last().is_some_and(|&last| last >= index)
+                || !self.is_valid_default(default, span, &params.items.as_slice()[..index])
+            {
+                break;
+            }
+            indices.push(index);
+        }
+        if indices.is_empty() {
+            return;
+        }
+        for (stmt, &index) in body.statements.iter_mut().zip(&indices) {
+            let Some(assign) = default_assignment_mut(stmt) else { continue };
+            let default = self.ast.move_expression(&mut assign.right);
+            let dummy = self.ast.binding_pattern(
+                self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, Atom::from(""))),
+                None,
+                false,
+            );
+            let param = &mut params.items[index];
+            let pattern = std::mem::replace(&mut param.pattern, dummy);
+            param.pattern = self.ast.assignment_pattern(SPAN, pattern, default);
+        }
+        body.statements.drain(..indices.len());
+    }
+
+    /// `if (a === void 0) a = d`, the index of the parameter `a` and `d`.
+    fn default_assignment<'s>(
+        &self,
+        stmt: &'s Statement<'a>,
+        params: &FormalParameters<'a>,
+    ) -> Option<(usize, &'s Expression<'a>)> {
+        let Statement::IfStatement(if_stmt) = stmt else { return None };
+        if if_stmt.alternate.is_some() {
+            return None;
+        }
+        let symbol_id = self.undefined_test(&if_stmt.test)?;
+        let assign = match &if_stmt.consequent {
+            Statement::BlockStatement(block) if block.body.len() == 1 => &block.body[0],
+            stmt => stmt,
+        };
+        let Statement::ExpressionStatement(expr_stmt) = assign else { return None };
+        let Expression::AssignmentExpression(assign) = &expr_stmt.expression else { return None };
+        let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+            return None;
+        };
+        if assign.operator != AssignmentOperator::Assign || self.symbol_of(ident) != Some(symbol_id)
+        {
+            return None;
+        }
+        let index = params.items.iter().position(|param| {
+            matches!(
+                &param.pattern.kind,
+                BindingPatternKind::BindingIdentifier(ident)
+                    if ident.symbol_id.get() == Some(symbol_id)
+            )
+        })?;
+        let param = &params.items[index];
+        (param.accessibility.is_none()
+            && !param.readonly
+            && param.decorators.is_empty()
+            && !param.pattern.optional)
+            .then_some((index, &assign.right))
+    }
+
+    /// `a === void 0`, `a === undefined` or `typeof a === "undefined"`, with the operands in
+    /// either order, and the binding of `a`.
+    fn undefined_test(&self, test: &Expression<'a>) -> Option<SymbolId> {
+        let Expression::BinaryExpression(binary) = test else { return None };
+        if binary.operator != BinaryOperator::StrictEquality {
+            return None;
+        }
+        [(&binary.left, &binary.right), (&binary.right, &binary.left)].into_iter().find_map(
+            |(subject, value)| match (subject, value) {
+                (Expression::Identifier(ident), value) if self.is_undefined(value) => {
+                    self.symbol_of(ident)
+                }
+                (Expression::UnaryExpression(unary), Expression::StringLiteral(lit))
+                    if unary.operator == UnaryOperator::Typeof && lit.value == "undefined" =>
+                {
+                    match &unary.argument {
+                        Expression::Identifier(ident) => self.symbol_of(ident),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            },
+        )
+    }
+
+    /// `void 0` or the global `undefined`.
+    fn is_undefined(&self, expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::Identifier(ident) => {
+                ident.name == "undefined" && self.symbol_of(ident).is_none()
+            }
+            expr => expr.is_void_0(),
+        }
+    }
+
+    /// Whether parameters with the same name are declared, which is only allowed without
+    /// default values.
+    fn is_redeclared(&self, param: &FormalParameter<'a>) -> bool {
+        matches!(
+            &param.pattern.kind,
+            BindingPatternKind::BindingIdentifier(ident)
+                if ident.symbol_id.get().map_or(true, |id| {
+                    !self.symbols.get_redeclare_variables(id).is_empty()
+                })
+        )
+    }
+
+    /// Whether `default` can be evaluated with the `earlier` parameters of the function at `span`
+    /// instead of in its body.
+    fn is_valid_default(
+        &self,
+        default: &Expression<'a>,
+        span: Span,
+        earlier: &[FormalParameter<'a>],
+    ) -> bool {
+        let mut finder = DefaultFinder::default();
+        finder.visit_expression(default);
+        !finder.suspends
+            && finder.references.iter().all(|&reference_id| {
+                let Some(symbol_id) = self.symbols.get_reference(reference_id).symbol_id() else {
+                    return true;
+                };
+                let declaration = self.symbols.get_span(symbol_id);
+                !contains(span, declaration)
+                    || contains(default.span(), declaration)
+                    || earlier.iter().any(|param| contains(param.span, declaration))
+            })
+    }
+}
+
+/// The assignment of `if (a === void 0) a = d` or `if (a === void 0) { a = d; }`.
+fn default_assignment_mut<'s, 'a>(
+    stmt: &'s mut Statement<'a>,
+) -> Option<&'s mut AssignmentExpression<'a>> {
+    let Statement::IfStatement(if_stmt) = stmt else { return None };
+    let stmt = match &mut if_stmt.consequent {
+        Statement::BlockStatement(block) => block.body.first_mut()?,
+        stmt => stmt,
+    };
+    match stmt {
+        Statement::ExpressionStatement(expr_stmt) => match &mut expr_stmt.expression {
+            Expression::AssignmentExpression(assign) => Some(assign),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// The references in a default value, and whether it contains `yield` or `await`.
+#[derive(Default)]
+struct DefaultFinder {
+    references: std::vec::Vec<ReferenceId>,
+    suspends: bool,
+}
+
+impl<'a> Visit<'a> for DefaultFinder {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.references.extend(ident.reference_id.get());
+    }
+
+    fn visit_yield_expression(&mut self, _expr: &YieldExpression<'a>) {
+        self.suspends = true;
+    }
+
+    fn visit_await_expression(&mut self, _expr: &AwaitExpression<'a>) {
+        self.suspends = true;
+    }
+}
+
+impl<'a> VisitMut<'a> for DefaultParams<'a> {
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: Option<ScopeFlags>) {
+        walk_function_mut(self, func, flags);
+        if let Some(body) = &mut func.body {
+            self.convert(func.span, &mut func.params, body);
+        }
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &mut ArrowFunctionExpression<'a>) {
+        walk_arrow_expression_mut(self, expr);
+        if !expr.expression {
+            self.convert(expr.span, &mut expr.params, &mut expr.body);
+        }
+    }
+}
@@ -0,0 +1,278 @@
+//! Destructuring of Literals
+//!
+//! Binds the values of an object or array literal, which is immediately destructured, directly:
+//!
+//! ```javascript
+//! const { a } = { a: 1, b: g() };
+//! const [x] = [1, 2];
+//! ```
+//!
+//! is compressed to `g(); const a = 1; const x = 1`, a pattern common in transpiled module
+//! interop code.
+//!
+//! The whole literal is evaluated before any of its values is bound, so the values which are not
+//! bound, but have side effects, are moved before the declaration. The bound values are then
+//! evaluated after them, which is only done when every bound value can be reordered with the side
+//! effects after it.
+//!
+//! * Object patterns and literals must only have data properties with identifier or string keys,
+//!   no duplicate, computed or `__proto__` keys, and no spread or rest elements. A pattern key
+//!   missing in the literal would be read from `Object.prototype`.
+//! * Array patterns and literals must have no spread, rest or hole in the literal, and the
+//!   literal must have a value for every pattern element.
+//! * Only identifiers without default values are bound.
+//! * An anonymous function or class keeps its inferred `name`, so it is only bound to an
+//!   identifier with the same name as its property key.
+
+use super::{ast_util::MayHaveSideEffects, motion::Effects, SPAN};
+use oxc_allocator::{Allocator, Box, Vec};
+use oxc_ast::visit::walk_mut::walk_statements_mut;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+
+pub struct Destructuring<'a> {
+    ast: AstBuilder<'a>,
+}
+
+impl<'a> Destructuring<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { ast: AstBuilder::new(allocator) }
+    }
+
+    pub fn build(mut self, program: &mut Program<'a>) {
+        self.visit_program(program);
+    }
+
+    fn fold_declarations(&self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let is_foldable = |stmt: &Statement<'a>| {
+            matches!(stmt, Statement::VariableDeclaration(decl)
+                if decl.modifiers.is_none()
+                    && decl.declarations.iter().any(|declarator| bound_indices(declarator).is_some()))
+        };
+        if !stmts.iter().any(is_foldable) {
+            return;
+        }
+
+        let mut new_stmts = self.ast.new_vec_with_capacity(stmts.len());
+        for stmt in stmts.drain(..) {
+            match stmt {
+                Statement::VariableDeclaration(decl) if decl.modifiers.is_none() => {
+                    self.fold_declaration(decl, &mut new_stmts);
+                }
+                stmt => new_stmts.push(stmt),
+            }
+        }
+        *stmts = new_stmts;
+    }
+
+    /// `const a = 1, { b } = { b: 2, c: f() }, d = 3` -> `const a = 1; f(); const b = 2, d = 3`
+    fn fold_declaration(
+        &self,
+        mut decl: Box<'a, VariableDeclaration<'a>>,
+        stmts: &mut Vec<'a, Statement<'a>>,
+    ) {
+        let (span, kind) = (decl.span, decl.kind);
+        let mut declarations = self.ast.new_vec();
+        for declarator in decl.declarations.drain(..) {
+            let Some(indices) = bound_indices(&declarator) else {
+                declarations.push(declarator);
+                continue;
+            };
+            let (mut effects, declarators) = self.fold_declarator(declarator, &indices);
+            if !effects.is_empty() {
+                if !declarations.is_empty() {
+                    let declarations = std::mem::replace(&mut declarations, self.ast.new_vec());
+                    stmts.push(Statement::VariableDeclaration(self.ast.variable_declaration(
+                        span,
+                        kind,
+                        declarations,
+                        Modifiers::empty(),
+                    )));
+                }
+                let expr = if effects.len() == 1 {
+                    effects.pop().unwrap()
+                } else {
+                    self.ast.sequence_expression(SPAN, effects)
+                };
+                stmts.push(self.ast.expression_statement(SPAN, expr));
+            }
+            declarations.extend(declarators);
+        }
+        if !declarations.is_empty() {
+            decl.declarations = declarations;
+            stmts.push(Statement::VariableDeclaration(decl));
+        }
+    }
+
+    /// Split the destructured literal of `declarator` into the unbound values with side effects,
+    /// and a declarator for each bound value.
+    fn fold_declarator(
+        &self,
+        declarator: VariableDeclarator<'a>,
+        indices: &[Option<usize>],
+    ) -> (Vec<'a, Expression<'a>>, std::vec::Vec<VariableDeclarator<'a>>) {
+        let (mut bindings, values): (std::vec::Vec<_>, std::vec::Vec<_>) =
+            match (declarator.id.kind, declarator.init) {
+                (
+                    BindingPatternKind::ObjectPattern(mut pattern),
+                    Some(Expression::ObjectExpression(mut object)),
+                ) => (
+                    pattern.properties.drain(..).map(|property| Some(property.value)).collect(),
+                    object
+                        .properties
+                        .iter_mut()
+                        .map(|property| match property {
+                            ObjectPropertyKind::ObjectProperty(property) => {
+                                self.ast.move_expression(&mut property.value)
+                            }
+                            ObjectPropertyKind::SpreadProperty(_) => unreachable!(),
+                        })
+                        .collect(),
+                ),
+                (
+                    BindingPatternKind::ArrayPattern(mut pattern),
+                    Some(Expression::ArrayExpression(mut array)),
+                ) => (
+                    pattern.elements.drain(..).collect(),
+                    array
+                        .elements
+                        .iter_mut()
+                        .map(|element| {
+                            let expr = element.as_expression_mut().unwrap();
+                            self.ast.move_expression(expr)
+                        })
+                        .collect(),
+                ),
+                _ => unreachable!(),
+            };
+
+        let mut effects = self.ast.new_vec();
+        let mut declarators = vec![];
+        for (value, index) in values.into_iter().zip(indices) {
+            match index.and_then(|index| bindings[index].take()) {
+                Some(id) => declarators.push(self.ast.variable_declarator(
+                    SPAN,
+                    declarator.kind,
+                    id,
+                    Some(value),
+                    false,
+                )),
+                None if value.may_have_side_effects() => effects.push(value),
+                None => {}
+            }
+        }
+        (effects, declarators)
+    }
+}
+
+impl<'a> VisitMut<'a> for Destructuring<'a> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        walk_statements_mut(self, stmts);
+        self.fold_declarations(stmts);
+    }
+}
+
+/// For each value of the literal destructured by `declarator`, the index of the pattern element
+/// it is bound to, if the destructuring can be evaluated at compile time.
+fn bound_indices(declarator: &VariableDeclarator) -> Option<std::vec::Vec<Option<usize>>> {
+    let mut values = vec![];
+    let mut indices = vec![];
+    match (&declarator.id.kind, declarator.init.as_ref()?) {
+        (BindingPatternKind::ObjectPattern(pattern), Expression::ObjectExpression(object)) => {
+            if pattern.rest.is_some() {
+                return None;
+            }
+            let mut keys = vec![];
+            for property in &pattern.properties {
+                let BindingPatternKind::BindingIdentifier(ident) = &property.value.kind else {
+                    return None;
+                };
+                let key = static_key(&property.key).filter(|_| !property.computed)?;
+                if keys.iter().any(|(other, _)| *other == key) {
+                    return None;
+                }
+                keys.push((key, ident.name.as_str()));
+            }
+            let mut literal_keys = vec![];
+            for property in &object.properties {
+                let ObjectPropertyKind::ObjectProperty(property) = property else { return None };
+                if property.kind != PropertyKind::Init || property.method || property.computed {
+                    return None;
+                }
+                let key = static_key(&property.key)?;
+                if key == "__proto__" || literal_keys.contains(&key) {
+                    return None;
+                }
+                literal_keys.push(key);
+                let index = keys.iter().position(|(other, _)| *other == key);
+                if let Some(index) = index {
+                    if is_anonymous_function_definition(&property.value) && keys[index].1 != key {
+                        return None;
+                    }
+                }
+                values.push(&property.value);
+                indices.push(index);
+            }
+            if keys.iter().any(|(key, _)| !literal_keys.contains(key)) {
+                return None;
+            }
+        }
+        (BindingPatternKind::ArrayPattern(pattern), Expression::ArrayExpression(array)) => {
+            if pattern.rest.is_some() || pattern.elements.len() > array.elements.len() {
+                return None;
+            }
+            for (i, element) in array.elements.iter().enumerate() {
+                let value = element.as_expression()?;
+                let index = match pattern.elements.get(i) {
+                    Some(Some(binding)) => {
+                        if !matches!(binding.kind, BindingPatternKind::BindingIdentifier(_))
+                            || is_anonymous_function_definition(value)
+                        {
+                            return None;
+                        }
+                        Some(i)
+                    }
+                    _ => None,
+                };
+                values.push(value);
+                indices.push(index);
+            }
+        }
+        _ => return None,
+    }
+
+    // The bound values are evaluated after the side effects of the unbound ones.
+    let effects = values.iter().map(|value| Effects::of(value)).collect::<std::vec::Vec<_>>();
+    for (i, index) in indices.iter().enumerate() {
+        if index.is_some()
+            && values.iter().zip(&indices).zip(&effects).skip(i + 1).any(
+                |((value, index), other)| {
+                    index.is_none()
+                        && value.may_have_side_effects()
+                        && !effects[i].can_reorder(other)
+                },
+            )
+        {
+            return None;
+        }
+    }
+    Some(indices)
+}
+
+fn static_key<'b>(key: &'b PropertyKey) -> Option<&'b str> {
+    match key {
+        PropertyKey::StaticIdentifier(ident) => Some(ident.name.as_str()),
+        PropertyKey::StringLiteral(lit) => Some(lit.value.as_str()),
+        _ => None,
+    }
+}
+
+/// Functions and classes whose `name` is inferred from the binding or property key.
+fn is_anonymous_function_definition(expr: &Expression) -> bool {
+    match expr {
+        Expression::FunctionExpression(func) => func.id.is_none(),
+        Expression::ClassExpression(class) => class.id.is_none(),
+        Expression::ArrowFunctionExpression(_) => true,
+        _ => false,
+    }
+}
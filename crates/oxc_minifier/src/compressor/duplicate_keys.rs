@@ -0,0 +1,164 @@
+//! Duplicate Key Removal
+//!
+//! A later definition of a key in an object literal replaces the earlier one, but the property
+//! keeps the position of the first definition:
+//!
+//! ```javascript
+//! ({ a: 1, b: 2, a: 3 })   // { a: 3, b: 2 }
+//! ```
+//!
+//! so the last definition is moved to the position of the first one, and the others are removed.
+//! This moves the evaluation of the last value before the properties in between, so it is only
+//! done when the properties in between have no side effects.
+//!
+//! * A data property replaces every earlier definition. When the replaced value of a data
+//!   property has side effects, it is kept in a sequence, `{ a: f(), a: 1 }` → `{ a: (f(), 1) }`,
+//!   and a warning is reported, because the duplicate is most likely a mistake.
+//! * A getter or setter only replaces an earlier definition of the same accessor, or an earlier
+//!   data property, so `{ get a() {}, set a(v) {} }` is kept.
+//! * `__proto__: x` sets the prototype instead of defining a property, and is never merged with
+//!   `["__proto__"]: x`, `__proto__() {}` or the shorthand `{ __proto__ }`, which do define a
+//!   property named `__proto__`.
+//! * Spread properties and computed keys which are not literals can define any key, so
+//!   definitions on both sides of them are not merged.
+//!
+//! Keys are compared by the names of [`PropertyShape`], like in the lint rules.
+
+use oxc_allocator::{Allocator, Vec};
+use oxc_ast::visit::walk_mut::walk_object_expression_mut;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{DefinedKey, PropertyShape};
+use oxc_span::{GetSpan, Span};
+
+use super::ast_util::MayHaveSideEffects;
+
+fn overwritten_side_effects(key: &str, overwritten: Span, last: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Duplicate key `{key}` in object literal, the overwritten value has side effects"
+    ))
+    .with_labels([overwritten.into(), last.into()])
+}
+
+pub struct DuplicateKeys<'a> {
+    ast: AstBuilder<'a>,
+    warnings: std::vec::Vec<OxcDiagnostic>,
+}
+
+impl<'a> DuplicateKeys<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { ast: AstBuilder::new(allocator), warnings: vec![] }
+    }
+
+    pub fn build(mut self, program: &mut Program<'a>) -> std::vec::Vec<OxcDiagnostic> {
+        self.visit_program(program);
+        self.warnings
+    }
+
+    fn remove_duplicates(&mut self, properties: &mut Vec<'a, ObjectPropertyKind<'a>>) {
+        let mut last = 0;
+        while last < properties.len() {
+            // The properties after `last` move back by the number of removed properties.
+            last = last + 1 - self.merge_into_first(properties, last);
+        }
+    }
+
+    /// Merge the property at `last` into the first definition of its key, returns the number of
+    /// removed properties.
+    fn merge_into_first(
+        &mut self,
+        properties: &mut Vec<'a, ObjectPropertyKind<'a>>,
+        last: usize,
+    ) -> usize {
+        let PropertyShape { key: DefinedKey::Name(name), kind, .. } =
+            PropertyShape::of(&properties[last])
+        else {
+            return 0;
+        };
+
+        // Earlier definitions of the same key, up to the nearest property with an unknown key.
+        let mut duplicates = vec![];
+        for i in (0..last).rev() {
+            match PropertyShape::of(&properties[i]).key {
+                DefinedKey::Unknown => break,
+                DefinedKey::Name(other) if other == name => duplicates.push(i),
+                _ => {}
+            }
+        }
+        let Some(&first) = duplicates.last() else { return 0 };
+        if properties.iter().take(last).skip(first + 1).any(has_side_effects) {
+            return 0;
+        }
+
+        let ObjectPropertyKind::ObjectProperty(first_property) = &properties[first] else {
+            unreachable!()
+        };
+        let ObjectPropertyKind::ObjectProperty(last_property) = &properties[last] else {
+            unreachable!()
+        };
+        let keep_first_value = first_property.kind == PropertyKind::Init
+            && first_property.value.may_have_side_effects();
+        match kind {
+            PropertyKind::Init => {
+                if keep_first_value
+                    && (last_property.method
+                        // `__proto__: (f(), __proto__)` would set the prototype.
+                        || (last_property.shorthand && name == "__proto__"))
+                {
+                    return 0;
+                }
+            }
+            // The other accessor of an accessor pair is not replaced.
+            PropertyKind::Get | PropertyKind::Set => {
+                if duplicates.len() > 1
+                    || keep_first_value
+                    || (first_property.kind != PropertyKind::Init && first_property.kind != kind)
+                {
+                    return 0;
+                }
+            }
+        }
+
+        if keep_first_value {
+            self.warnings.push(overwritten_side_effects(
+                &name,
+                first_property.span,
+                last_property.span,
+            ));
+        }
+        let ObjectPropertyKind::ObjectProperty(mut last_property) = properties.remove(last) else {
+            unreachable!()
+        };
+        for &i in &duplicates[..duplicates.len() - 1] {
+            properties.remove(i);
+        }
+        let ObjectPropertyKind::ObjectProperty(first_property) = &mut properties[first] else {
+            unreachable!()
+        };
+        if keep_first_value {
+            let span = last_property.value.span();
+            let first_value = self.ast.move_expression(&mut first_property.value);
+            let last_value = self.ast.move_expression(&mut last_property.value);
+            let expressions = self.ast.new_vec_from_iter([first_value, last_value]);
+            last_property.value = self.ast.sequence_expression(span, expressions);
+            last_property.shorthand = false;
+        }
+        *first_property = last_property;
+        duplicates.len()
+    }
+}
+
+impl<'a> VisitMut<'a> for DuplicateKeys<'a> {
+    fn visit_object_expression(&mut self, expr: &mut ObjectExpression<'a>) {
+        walk_object_expression_mut(self, expr);
+        self.remove_duplicates(&mut expr.properties);
+    }
+}
+
+fn has_side_effects(property: &ObjectPropertyKind) -> bool {
+    match property {
+        ObjectPropertyKind::ObjectProperty(property) => property.value.may_have_side_effects(),
+        ObjectPropertyKind::SpreadProperty(_) => true,
+    }
+}
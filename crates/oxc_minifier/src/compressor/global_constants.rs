@@ -0,0 +1,147 @@
+//! Global Constant Substitution
+//!
+//! Replaces references to the global `Infinity` and `NaN` with shorter expressions:
+//!
+//! * `Infinity` → `1/0` and `-Infinity` → `-1/0`,
+//! * `Number.POSITIVE_INFINITY` → `1/0`, `Number.NEGATIVE_INFINITY` → `-1/0` and
+//!   `Number.NaN` → `0/0`,
+//! * `NaN` → `0/0` when the program also contains `1/0`. Both have the same length, but `0/0`
+//!   compresses better next to `1/0`.
+//!
+//! This runs after the other passes, which evaluate `Infinity` and `NaN` by name.
+//! References to a binding which shadows one of these globals are kept.
+
+use oxc_allocator::Allocator;
+use oxc_ast::visit::walk_mut::{walk_expression_mut, walk_object_property_mut};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_semantic::SymbolTable;
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{BinaryOperator, UnaryOperator},
+};
+
+use super::{ast_util::NumberValue, SPAN};
+
+pub struct GlobalConstants<'a, 's> {
+    ast: AstBuilder<'a>,
+    symbols: &'s SymbolTable,
+    replace_nan: bool,
+    replaced_infinity: bool,
+}
+
+impl<'a, 's> GlobalConstants<'a, 's> {
+    pub fn new(allocator: &'a Allocator, symbols: &'s SymbolTable) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            symbols,
+            replace_nan: false,
+            replaced_infinity: false,
+        }
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        self.visit_program(program);
+        if self.replaced_infinity {
+            self.replace_nan = true;
+            self.visit_program(program);
+        }
+    }
+
+    fn is_global(&self, expr: &Expression<'a>, name: &str) -> bool {
+        let Expression::Identifier(ident) = expr else { return false };
+        ident.name == name
+            && ident.reference_id.get().is_some_and(|id| self.symbols.is_global_reference(id))
+    }
+
+    /// Value of a reference to one of the replaced globals.
+    fn constant_value(&self, expr: &Expression<'a>) -> Option<NumberValue> {
+        if self.is_global(expr, "Infinity") {
+            return Some(NumberValue::PositiveInfinity);
+        }
+        if self.replace_nan && self.is_global(expr, "NaN") {
+            return Some(NumberValue::NaN);
+        }
+        let Expression::StaticMemberExpression(member) = expr else { return None };
+        if !self.is_global(&member.object, "Number") {
+            return None;
+        }
+        match member.property.name.as_str() {
+            "POSITIVE_INFINITY" => Some(NumberValue::PositiveInfinity),
+            "NEGATIVE_INFINITY" => Some(NumberValue::NegativeInfinity),
+            "NaN" => Some(NumberValue::NaN),
+            _ => None,
+        }
+    }
+
+    fn value_expression(&mut self, value: &NumberValue) -> Expression<'a> {
+        match value {
+            NumberValue::PositiveInfinity => self.positive_infinity(),
+            NumberValue::NegativeInfinity => self.negative_infinity(),
+            _ => self.nan(),
+        }
+    }
+
+    fn number(&self, value: f64, raw: &'a str) -> Expression<'a> {
+        let literal = self.ast.number_literal(SPAN, value, raw, NumberBase::Decimal);
+        self.ast.literal_number_expression(literal)
+    }
+
+    /// `left/0`
+    fn divide_by_zero(&self, left: Expression<'a>) -> Expression<'a> {
+        let right = self.number(0.0, "0");
+        self.ast.binary_expression(SPAN, left, BinaryOperator::Division, right)
+    }
+
+    /// `1/0`
+    fn positive_infinity(&mut self) -> Expression<'a> {
+        self.replaced_infinity = true;
+        let one = self.number(1.0, "1");
+        self.divide_by_zero(one)
+    }
+
+    /// `-1/0`, which is printed without the parentheses of `-(1/0)`.
+    fn negative_infinity(&mut self) -> Expression<'a> {
+        self.replaced_infinity = true;
+        let one = self.number(1.0, "1");
+        let minus_one = self.ast.unary_expression(SPAN, UnaryOperator::UnaryNegation, one);
+        self.divide_by_zero(minus_one)
+    }
+
+    /// `0/0`
+    fn nan(&self) -> Expression<'a> {
+        let zero = self.number(0.0, "0");
+        self.divide_by_zero(zero)
+    }
+}
+
+impl<'a, 's> VisitMut<'a> for GlobalConstants<'a, 's> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::UnaryExpression(unary) = expr {
+            match unary.operator {
+                // `delete Infinity` is `false`, `delete (1/0)` is `true`.
+                UnaryOperator::Delete if self.constant_value(&unary.argument).is_some() => return,
+                UnaryOperator::UnaryNegation => {
+                    if let Some(value) = self.constant_value(&unary.argument) {
+                        *expr = self.value_expression(&value.not());
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let Some(value) = self.constant_value(expr) {
+            *expr = self.value_expression(&value);
+        } else {
+            walk_expression_mut(self, expr);
+        }
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        walk_object_property_mut(self, prop);
+        // `{ Infinity }` -> `{ Infinity: 1/0 }`
+        if prop.shorthand && !matches!(prop.value, Expression::Identifier(_)) {
+            prop.shorthand = false;
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Global Definitions
+//!
+//! Replaces the globals and the member expressions on globals given by `compress.global_defs`
+//! with constants, before the enclosing expressions are folded, so that
+//!
+//! ```javascript
+//! if (__DEV__) validate(props);
+//! if (process.env.NODE_ENV !== "production") devWarn();
+//! ```
+//!
+//! becomes `if (false) validate(props); if (false) devWarn()` with the definitions
+//! `__DEV__` → `false` and `process.env.NODE_ENV` → `"production"`, which `compress.dead_code`
+//! removes. Like `compress.node_env`, assignments to the definitions and references to
+//! bindings which shadow the global are kept.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{number::NumberBase, operator::UnaryOperator};
+
+use super::Compressor;
+
+/// The constant which replaces a global definition, see `compress.global_defs`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GlobalDefValue {
+    Boolean(bool),
+    /// A finite number.
+    Number(f64),
+    String(&'static str),
+    Null,
+    Undefined,
+}
+
+impl<'a> Compressor<'a> {
+    /// Enabled by `compress.global_defs`
+    ///
+    /// `__DEV__` -> `false`
+    pub(crate) fn replace_global_def(&mut self, expr: &mut Expression<'a>) {
+        let Some(value) = self
+            .options
+            .global_defs
+            .iter()
+            .find_map(|(name, value)| self.is_global_def(expr, name).then_some(*value))
+        else {
+            return;
+        };
+        *expr = self.global_def_value(expr.span(), value);
+    }
+
+    /// Whether `expr` is `name`, a global such as `__DEV__`, or a path of static properties on a
+    /// global such as `process.env.NODE_ENV`, where `process.env["NODE_ENV"]` also matches.
+    fn is_global_def(&self, expr: &Expression<'a>, name: &str) -> bool {
+        let Some((object_name, property_name)) = name.rsplit_once('.') else {
+            return matches!(expr, Expression::Identifier(ident)
+                if ident.name == name && self.is_global_reference(ident));
+        };
+        let (object, property) = match expr {
+            Expression::StaticMemberExpression(member) if !member.optional => {
+                (&member.object, member.property.name.as_str())
+            }
+            Expression::ComputedMemberExpression(member) if !member.optional => {
+                match &member.expression {
+                    Expression::StringLiteral(lit) => (&member.object, lit.value.as_str()),
+                    _ => return false,
+                }
+            }
+            _ => return false,
+        };
+        property == property_name && self.is_global_def(object, object_name)
+    }
+
+    fn global_def_value(&self, span: Span, value: GlobalDefValue) -> Expression<'a> {
+        match value {
+            GlobalDefValue::Boolean(value) => {
+                self.ast.literal_boolean_expression(self.ast.boolean_literal(span, value))
+            }
+            GlobalDefValue::Number(value) => {
+                let raw = self.ast.new_str(&value.abs().to_string());
+                let base =
+                    if value.fract() == 0.0 { NumberBase::Decimal } else { NumberBase::Float };
+                let number = self.ast.number_literal(span, value.abs(), raw, base);
+                let number = self.ast.literal_number_expression(number);
+                if value.is_sign_negative() {
+                    self.ast.unary_expression(span, UnaryOperator::UnaryNegation, number)
+                } else {
+                    number
+                }
+            }
+            GlobalDefValue::String(value) => {
+                self.ast.literal_string_expression(self.ast.string_literal(span, value))
+            }
+            GlobalDefValue::Null => self.ast.literal_null_expression(NullLiteral::new(span)),
+            GlobalDefValue::Undefined => self.ast.void_0(),
+        }
+    }
+}
@@ -0,0 +1,359 @@
+//! Helper Deduplication
+//!
+//! Replaces the helpers which TypeScript and Babel inline into their output with imports from the
+//! shared runtime, so a bundle of many such files carries one copy instead of one for each file:
+//!
+//! ```javascript
+//! var __assign = (this && this.__assign) || function () { /* .. */ };
+//! function _defineProperty(e, r, t) { /* .. */ Object.defineProperty(e, r, { /* .. */ }) }
+//! ```
+//!
+//! is compressed to
+//!
+//! ```javascript
+//! import { __assign } from "tslib";
+//! import _defineProperty from "@babel/runtime/helpers/defineProperty";
+//! ```
+//!
+//! with `compress.import_ts_helpers: Some("tslib")` and
+//! `compress.import_babel_helpers: Some("@babel/runtime")`.
+//!
+//! The helpers are recognized by their structure rather than by their name alone. A TypeScript
+//! helper is `var __name = (this && this.__name) || ..`, which is how `tsc` emits every helper, and
+//! a Babel helper is a function declaration with the name, the number of parameters and a property
+//! name or a string which are specific to the helper.
+//!
+//! Only modules are compressed, which can import. A helper is only replaced when every binding
+//! of its name in the program is a recognized copy of the helper, which is only assigned inside
+//! of its own declaration, e.g. by `_typeof = function (o) { .. }` picking an implementation
+//! the first time it is called, and no reference to the name is global. Every copy is then
+//! removed, and its references resolve to the one import.
+
+use oxc_allocator::{Allocator, Vec};
+use oxc_ast::visit::{walk::walk_statement, walk_mut::walk_statements_mut};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder, Visit, VisitMut};
+use oxc_semantic::{SemanticBuilder, SymbolId};
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::operator::LogicalOperator;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::SPAN;
+
+/// The helpers exported by `tslib`.
+const TS_HELPERS: [&str; 30] = [
+    "__addDisposableResource",
+    "__assign",
+    "__asyncDelegator",
+    "__asyncGenerator",
+    "__asyncValues",
+    "__await",
+    "__awaiter",
+    "__classPrivateFieldGet",
+    "__classPrivateFieldIn",
+    "__classPrivateFieldSet",
+    "__createBinding",
+    "__decorate",
+    "__disposeResources",
+    "__esDecorate",
+    "__exportStar",
+    "__extends",
+    "__generator",
+    "__importDefault",
+    "__importStar",
+    "__makeTemplateObject",
+    "__metadata",
+    "__param",
+    "__propKey",
+    "__read",
+    "__rest",
+    "__runInitializers",
+    "__setFunctionName",
+    "__spread",
+    "__spreadArray",
+    "__values",
+];
+
+/// A helper inlined by Babel.
+struct BabelHelper {
+    /// The name of the function declaration.
+    name: &'static str,
+    params: usize,
+    /// A property name, string or global which the body of the helper contains.
+    marker: &'static str,
+    /// The module of the helper in `@babel/runtime/helpers`.
+    module: &'static str,
+}
+
+const BABEL_HELPERS: [BabelHelper; 7] = [
+    BabelHelper {
+        name: "_asyncToGenerator",
+        params: 1,
+        marker: "Promise",
+        module: "asyncToGenerator",
+    },
+    BabelHelper {
+        name: "_classCallCheck",
+        params: 2,
+        marker: "Cannot call a class as a function",
+        module: "classCallCheck",
+    },
+    BabelHelper {
+        name: "_defineProperty",
+        params: 3,
+        marker: "defineProperty",
+        module: "defineProperty",
+    },
+    BabelHelper { name: "_extends", params: 0, marker: "assign", module: "extends" },
+    BabelHelper {
+        name: "_interopRequireDefault",
+        params: 1,
+        marker: "__esModule",
+        module: "interopRequireDefault",
+    },
+    BabelHelper {
+        name: "_objectSpread",
+        params: 1,
+        marker: "getOwnPropertyDescriptors",
+        module: "objectSpread2",
+    },
+    BabelHelper { name: "_typeof", params: 1, marker: "Symbol", module: "typeof" },
+];
+
+/// A copy of a helper found in the program.
+struct Helper<'a> {
+    name: Atom<'a>,
+    symbol_id: SymbolId,
+    /// The span of the declaring statement.
+    span: Span,
+    /// `None` for a TypeScript helper, which is imported by name.
+    babel_module: Option<&'static str>,
+}
+
+pub struct Helpers<'a> {
+    ast: AstBuilder<'a>,
+    ts_module: Option<&'static str>,
+    babel_module: Option<&'static str>,
+    /// The spans of the declarations which are replaced by imports.
+    removed: FxHashSet<Span>,
+}
+
+impl<'a> Helpers<'a> {
+    pub fn new(
+        allocator: &'a Allocator,
+        ts_module: Option<&'static str>,
+        babel_module: Option<&'static str>,
+    ) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            ts_module,
+            babel_module,
+            removed: FxHashSet::default(),
+        }
+    }
+
+    pub fn build(mut self, program: &mut Program<'a>) {
+        if !program.source_type.is_module() {
+            return;
+        }
+        let imports = {
+            let semantic = SemanticBuilder::new("", program.source_type).build(program).semantic;
+            let (symbols, scopes) = (semantic.symbols(), semantic.scopes());
+            if scopes.get_flags(scopes.root_scope_id()).contains_direct_eval() {
+                return;
+            }
+            let mut finder = HelperFinder {
+                ts: self.ts_module.is_some(),
+                babel: self.babel_module.is_some(),
+                helpers: vec![],
+            };
+            finder.visit_program(program);
+            let helpers = finder.helpers;
+
+            let mut copies = FxHashMap::<SymbolId, usize>::default();
+            for helper in &helpers {
+                *copies.entry(helper.symbol_id).or_default() += 1;
+            }
+            let mut imports: std::vec::Vec<(Atom<'a>, Option<&'static str>)> = vec![];
+            for helper in &helpers {
+                let name = helper.name.as_str();
+                if imports.iter().any(|(imported, _)| imported == name)
+                    || scopes.root_unresolved_references().contains_key(name)
+                {
+                    continue;
+                }
+                let all_copies =
+                    symbols.iter().filter(|&id| symbols.get_name(id) == name).all(|id| {
+                        // Every declaration of the binding is a copy of the helper, and it is only
+                        // assigned inside of them.
+                        copies.get(&id).copied()
+                            == Some(1 + symbols.get_redeclare_variables(id).len())
+                            && symbols.get_resolved_references(id).all(|reference| {
+                                !reference.is_write()
+                                    || helpers.iter().any(|helper| {
+                                        helper.symbol_id == id
+                                            && contains(helper.span, reference.span())
+                                    })
+                            })
+                    });
+                if !all_copies {
+                    continue;
+                }
+                imports.push((helper.name.clone(), helper.babel_module));
+                self.removed.extend(
+                    helpers.iter().filter(|other| other.name == name).map(|other| other.span),
+                );
+            }
+            imports
+        };
+        if imports.is_empty() {
+            return;
+        }
+
+        self.visit_program(program);
+        for (i, (name, babel_module)) in imports.into_iter().enumerate() {
+            let import = self.import_declaration(name, babel_module);
+            program.body.insert(i, import);
+        }
+    }
+
+    fn import_declaration(
+        &self,
+        name: Atom<'a>,
+        babel_module: Option<&'static str>,
+    ) -> Statement<'a> {
+        let local = BindingIdentifier::new(SPAN, name.clone());
+        let (specifier, source) = match (babel_module, self.babel_module, self.ts_module) {
+            (Some(module), Some(runtime), _) => {
+                let specifier = ImportDeclarationSpecifier::ImportDefaultSpecifier(
+                    self.ast.alloc(ImportDefaultSpecifier { span: SPAN, local }),
+                );
+                (specifier, format!("{runtime}/helpers/{module}"))
+            }
+            (None, _, Some(tslib)) => {
+                let specifier =
+                    ImportDeclarationSpecifier::ImportSpecifier(self.ast.alloc(ImportSpecifier {
+                        span: SPAN,
+                        imported: ModuleExportName::Identifier(IdentifierName::new(SPAN, name)),
+                        local,
+                        import_kind: ImportOrExportKind::Value,
+                    }));
+                (specifier, tslib.to_string())
+            }
+            _ => unreachable!(),
+        };
+        let import_decl = self.ast.import_declaration(
+            SPAN,
+            Some(self.ast.new_vec_single(specifier)),
+            self.ast.string_literal(SPAN, &source),
+            None,
+            ImportOrExportKind::Value,
+        );
+        self.ast.module_declaration(ModuleDeclaration::ImportDeclaration(import_decl))
+    }
+}
+
+impl<'a> VisitMut<'a> for Helpers<'a> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        stmts.retain(|stmt| {
+            !(matches!(stmt, Statement::VariableDeclaration(_) | Statement::FunctionDeclaration(_))
+                && self.removed.contains(&stmt.span()))
+        });
+        walk_statements_mut(self, stmts);
+    }
+}
+
+/// Collects the copies of the helpers in the program.
+struct HelperFinder<'a> {
+    ts: bool,
+    babel: bool,
+    helpers: std::vec::Vec<Helper<'a>>,
+}
+
+impl<'a> HelperFinder<'a> {
+    /// `var __name = (this && this.__name) || ..`
+    fn ts_helper<'b>(
+        &self,
+        decl: &'b VariableDeclaration<'a>,
+    ) -> Option<&'b BindingIdentifier<'a>> {
+        let [declarator] = decl.declarations.as_slice() else { return None };
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+            return None;
+        };
+        if !self.ts || !decl.kind.is_var() || !TS_HELPERS.contains(&ident.name.as_str()) {
+            return None;
+        }
+        let Some(Expression::LogicalExpression(or_expr)) = &declarator.init else { return None };
+        let Expression::LogicalExpression(and_expr) = &or_expr.left else { return None };
+        let is_this_property = matches!(&and_expr.right, Expression::StaticMemberExpression(member)
+            if matches!(member.object, Expression::ThisExpression(_))
+                && member.property.name == ident.name);
+        (or_expr.operator == LogicalOperator::Or
+            && and_expr.operator == LogicalOperator::And
+            && matches!(and_expr.left, Expression::ThisExpression(_))
+            && is_this_property)
+            .then_some(ident)
+    }
+
+    /// `function _name(..) { .. }` with the parameters and the marker of a Babel helper.
+    fn babel_helper<'b>(
+        &self,
+        func: &'b Function<'a>,
+    ) -> Option<(&'b BindingIdentifier<'a>, &'static str)> {
+        let ident = func.id.as_ref()?;
+        let helper = BABEL_HELPERS.iter().find(|helper| helper.name == ident.name)?;
+        if !self.babel || func.params.items.len() != helper.params || func.params.rest.is_some() {
+            return None;
+        }
+        let mut finder = MarkerFinder { marker: helper.marker, found: false };
+        finder.visit_function_body(func.body.as_ref()?);
+        finder.found.then_some((ident, helper.module))
+    }
+}
+
+impl<'a> Visit<'a> for HelperFinder<'a> {
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        let helper = match stmt {
+            Statement::VariableDeclaration(decl) => self.ts_helper(decl).map(|ident| (ident, None)),
+            Statement::FunctionDeclaration(func) => {
+                self.babel_helper(func).map(|(ident, module)| (ident, Some(module)))
+            }
+            _ => None,
+        };
+        if let Some((ident, babel_module)) = helper {
+            if let Some(symbol_id) = ident.symbol_id.get() {
+                self.helpers.push(Helper {
+                    name: ident.name.clone(),
+                    symbol_id,
+                    span: stmt.span(),
+                    babel_module,
+                });
+            }
+        }
+        walk_statement(self, stmt);
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// Finds a property name, string or reference equal to `marker`.
+struct MarkerFinder {
+    marker: &'static str,
+    found: bool,
+}
+
+impl<'a> Visit<'a> for MarkerFinder {
+    fn visit_identifier_name(&mut self, ident: &IdentifierName<'a>) {
+        self.found |= ident.name == self.marker;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.found |= ident.name == self.marker;
+    }
+
+    fn visit_string_literal(&mut self, lit: &StringLiteral<'a>) {
+        self.found |= lit.value == self.marker;
+    }
+}
@@ -0,0 +1,191 @@
+//! Block Function Hoisting
+//!
+//! Moves function declarations out of blocks to the enclosing function or module, where strict
+//! mode makes them block scoped:
+//!
+//! ```javascript
+//! "use strict";
+//! if (x) { function f() {} f() }
+//! ```
+//!
+//! is compressed to `"use strict"; function f() {} if (x) f()`, which lets the block itself be
+//! removed. Rewriting the declaration to `var f = function () {}` instead is never shorter.
+//!
+//! In sloppy mode, Annex B also binds these functions in the enclosing function (see `binder.rs`
+//! in `oxc_semantic`), so only the functions which the binder declared block scoped in strict
+//! code are moved, and only when the move can not change what any name refers to:
+//!
+//! * The enclosing function does not bind the same name, and every reference to the name inside
+//!   it already resolves to a binding declared inside it.
+//! * The function does not capture a binding of the blocks it is moved out of.
+//! * No loop or class static block is between the block and the enclosing function, as those
+//!   create a new function object each time the block runs.
+//! * No direct `eval` can observe the new binding.
+//! * Top-level functions of a script are global bindings, so script top-level blocks are kept.
+
+use oxc_ast::visit::walk_mut::walk_block_statement_mut;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstKind, VisitMut};
+use oxc_semantic::{ScopeId, Semantic, SemanticBuilder, SymbolFlags, SymbolId};
+use oxc_span::{CompactStr, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+#[derive(Default)]
+pub struct HoistFunctions<'a> {
+    /// Function declarations which can be moved out of their block.
+    candidates: FxHashSet<SymbolId>,
+    /// Function declarations moved out of blocks, inserted before the statement of the enclosing
+    /// function which contained them.
+    hoisted: std::vec::Vec<Statement<'a>>,
+}
+
+impl<'a> HoistFunctions<'a> {
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        let semantic = SemanticBuilder::new("", program.source_type).build(program).semantic;
+        let mut targets = FxHashMap::<(ScopeId, &CompactStr), std::vec::Vec<SymbolId>>::default();
+        for symbol_id in semantic.symbols().iter() {
+            if let Some(scope_id) = target_scope_id(&semantic, symbol_id) {
+                let name = &semantic.symbols().names[symbol_id];
+                targets.entry((scope_id, name)).or_default().push(symbol_id);
+            }
+        }
+        // Two functions of the same name can not both be moved into the same scope.
+        self.candidates = targets
+            .into_values()
+            .filter_map(|symbol_ids| match symbol_ids[..] {
+                [symbol_id] => Some(symbol_id),
+                _ => None,
+            })
+            .collect();
+        if self.candidates.is_empty() {
+            return;
+        }
+        self.visit_program(program);
+    }
+
+    fn is_candidate(&self, stmt: &Statement<'a>) -> bool {
+        matches!(
+            stmt,
+            Statement::FunctionDeclaration(func)
+                if func.id.as_ref().and_then(|id| id.symbol_id.get()).is_some_and(|id| self.candidates.contains(&id))
+        )
+    }
+
+    /// Visit `stmts`, and insert the functions moved out of each statement's blocks before it.
+    fn hoist_into(&mut self, stmts: &mut oxc_allocator::Vec<'a, Statement<'a>>) {
+        let outer = std::mem::take(&mut self.hoisted);
+        let mut i = 0;
+        while i < stmts.len() {
+            self.visit_statement(&mut stmts[i]);
+            for stmt in self.hoisted.drain(..) {
+                stmts.insert(i, stmt);
+                i += 1;
+            }
+            i += 1;
+        }
+        self.hoisted = outer;
+    }
+}
+
+impl<'a> VisitMut<'a> for HoistFunctions<'a> {
+    fn visit_program(&mut self, program: &mut Program<'a>) {
+        self.hoist_into(&mut program.body);
+    }
+
+    fn visit_function_body(&mut self, body: &mut FunctionBody<'a>) {
+        self.hoist_into(&mut body.statements);
+    }
+
+    fn visit_block_statement(&mut self, block: &mut BlockStatement<'a>) {
+        walk_block_statement_mut(self, block);
+        let mut i = 0;
+        while i < block.body.len() {
+            if self.is_candidate(&block.body[i]) {
+                self.hoisted.push(block.body.remove(i));
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+/// The scope to move the function declaration of `symbol_id` into, if it can be moved out of its
+/// block.
+fn target_scope_id(semantic: &Semantic, symbol_id: SymbolId) -> Option<ScopeId> {
+    let symbols = semantic.symbols();
+    let scopes = semantic.scopes();
+    let nodes = semantic.nodes();
+
+    // Matches the binder, which declares block functions as `var`s in sloppy mode by Annex B.
+    if !symbols
+        .get_flag(symbol_id)
+        .contains(SymbolFlags::Function | SymbolFlags::BlockScopedVariable)
+    {
+        return None;
+    }
+    let declaration = symbols.get_declaration(symbol_id);
+    let AstKind::Function(func) = nodes.kind(declaration) else { return None };
+    if !matches!(nodes.parent_kind(declaration), Some(AstKind::BlockStatement(_))) {
+        return None;
+    }
+
+    let mut target = None;
+    for ancestor in nodes.iter_parents(declaration).skip(1) {
+        match ancestor.kind() {
+            AstKind::Function(function) => {
+                target = function.scope_id.get().map(|scope_id| (scope_id, function.span));
+                break;
+            }
+            AstKind::ArrowFunctionExpression(arrow) => {
+                target = arrow.scope_id.get().map(|scope_id| (scope_id, arrow.span));
+                break;
+            }
+            // Top-level functions of a script are properties of the global object.
+            AstKind::Program(program) if program.source_type.is_module() => {
+                target = Some((scopes.root_scope_id(), program.span));
+                break;
+            }
+            kind if kind.is_iteration_statement() => return None,
+            AstKind::Program(_) | AstKind::StaticBlock(_) | AstKind::TSModuleBlock(_) => {
+                return None
+            }
+            _ => {}
+        }
+    }
+    let (target_scope_id, target_span) = target?;
+
+    let name = symbols.get_name(symbol_id);
+    let contains = |span: Span| target_span.start <= span.start && span.end <= target_span.end;
+    if !scopes.get_flags(target_scope_id).is_strict_mode()
+        || scopes.has_binding(target_scope_id, name)
+        || std::iter::once(target_scope_id)
+            .chain(scopes.descendants(target_scope_id))
+            .any(|scope_id| scopes.get_flags(scope_id).contains_direct_eval())
+    {
+        return None;
+    }
+
+    // The new binding must not shadow a binding of the same name declared outside the target.
+    let shadows = symbols.references.iter().any(|reference| {
+        reference.name().as_str() == name
+            && contains(reference.span())
+            && reference.symbol_id().map_or(true, |id| !contains(symbols.get_span(id)))
+    });
+    if shadows {
+        return None;
+    }
+
+    // The function must not capture a binding of the blocks it is moved out of.
+    let block_scope_ids = scopes
+        .ancestors(symbols.get_scope_id(symbol_id))
+        .take_while(|&scope_id| scope_id != target_scope_id)
+        .collect::<FxHashSet<_>>();
+    let captures = symbols.references.iter().any(|reference| {
+        func.span.start <= reference.span().start
+            && reference.span().end <= func.span.end
+            && reference.symbol_id().is_some_and(|id| {
+                id != symbol_id && block_scope_ids.contains(&symbols.get_scope_id(id))
+            })
+    });
+    (!captures).then_some(target_scope_id)
+}
@@ -0,0 +1,511 @@
+//! If Statement Minimization
+//!
+//! Rewrites `if` statements after their branches are minimized, so that empty branches and
+//! early exits left behind by the other passes are removed before the late sequence pass.
+//!
+//! An `if` statement whose branches are expressions becomes an expression, `if (a) b()` ->
+//! `a && b()` and `if (a) b(); else c()` -> `a ? b() : c()`, when it is shorter. A conditional
+//! expression whose branches assign to the same target becomes a single assignment,
+//! `if (a) x = b; else x = c` -> `x = a ? b : c`. The target is evaluated before the test
+//! afterwards, so a member target is only hoisted when the test cannot change its object or
+//! property.
+//!
+//! The `else` branch of an `if` statement whose consequent always exits is moved after it,
+//! `if (a) return b; else c()` -> `if (a) return b; c()`, and a `return` without an argument at
+//! the end of a function is removed, so that the statements around them can be joined.
+//!
+//! `if` statements with identical consequents are merged, `if (a) b(); else if (c) b()` ->
+//! `if (a || c) b()`. Consecutive `if` statements are only merged when the consequent always
+//! exits, `if (a) return b; if (c) return b` -> `if (a || c) return b`, as `c` is evaluated after
+//! the consequent otherwise, and the consequent would run twice when both tests are true.
+
+use oxc_allocator::Vec;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, ContentEq};
+use oxc_syntax::{
+    operator::{AssignmentOperator, LogicalOperator, UnaryOperator},
+    precedence::GetPrecedence,
+};
+
+use super::{motion::Effects, Compressor, SPAN};
+
+impl<'a> Compressor<'a> {
+    /// Enabled by `compress.conditionals`
+    ///
+    /// * `if (a) {} else b` -> `if (!a) b`
+    /// * `if (a) b; else {}` -> `if (a) b`
+    /// * `if (a) {}` -> `a`
+    /// * `if (a) if (b) c` -> `if (a && b) c`
+    /// * `if (a) b; else if (c) b; else d` -> `if (a || c) b; else d`
+    pub(crate) fn minimize_if_statement(&mut self, stmt: &mut Statement<'a>) {
+        if !self.options.conditionals {
+            return;
+        }
+        let Statement::IfStatement(if_stmt) = stmt else { return };
+        let if_stmt = &mut **if_stmt;
+        if if_stmt.alternate.as_ref().is_some_and(Self::is_empty_branch) {
+            if_stmt.alternate = None;
+        }
+        if Self::is_empty_branch(&if_stmt.consequent) {
+            let Some(alternate) = if_stmt.alternate.take() else {
+                let span = if_stmt.span;
+                let test = self.ast.move_expression(&mut if_stmt.test);
+                *stmt = self.ast.expression_statement(span, test);
+                return;
+            };
+            let test = self.ast.move_expression(&mut if_stmt.test);
+            if_stmt.test = self.negate_condition(test);
+            if_stmt.consequent = alternate;
+        }
+        if let Some(Statement::IfStatement(inner)) = &mut if_stmt.alternate {
+            if inner.consequent.content_eq(&if_stmt.consequent) {
+                let right = self.ast.move_expression(&mut inner.test);
+                let alternate = inner.alternate.take();
+                let left = self.ast.move_expression(&mut if_stmt.test);
+                if_stmt.test = self.join_logical(left, LogicalOperator::Or, right);
+                if_stmt.alternate = alternate;
+            }
+        }
+        if if_stmt.alternate.is_some() {
+            return;
+        }
+        let Statement::IfStatement(inner) = &mut if_stmt.consequent else { return };
+        if inner.alternate.is_some() {
+            return;
+        }
+        let right = self.ast.move_expression(&mut inner.test);
+        let consequent = self.ast.move_statement(&mut inner.consequent);
+        let left = self.ast.move_expression(&mut if_stmt.test);
+        if_stmt.test = self.ast.logical_expression(SPAN, left, LogicalOperator::And, right);
+        if_stmt.consequent = consequent;
+    }
+
+    /// Enabled by `compress.conditionals`
+    ///
+    /// * `if (a) b()` -> `a && b()`
+    /// * `if (!a) b()` -> `a || b()`
+    /// * `if (a) b(); else c()` -> `a ? b() : c()`
+    /// * `if (a) x = b; else x = c` -> `x = a ? b : c`
+    ///
+    /// `if (a) b = c` is kept, as `a && (b = c)` is not shorter, while a conditional expression
+    /// replaces `if()` and `;else` by `?:` and is always shorter. An `else if` is folded first.
+    pub(crate) fn fold_if_expression(&mut self, stmt: &mut Statement<'a>) {
+        if !self.options.conditionals {
+            return;
+        }
+        let Statement::IfStatement(if_stmt) = stmt else { return };
+        let if_stmt = &mut **if_stmt;
+        if let Some(alternate) = &mut if_stmt.alternate {
+            self.fold_if_expression(alternate);
+        }
+        let span = if_stmt.span;
+        let Statement::ExpressionStatement(consequent) = &mut if_stmt.consequent else { return };
+        let expr = match &mut if_stmt.alternate {
+            None => {
+                let (operator, test) = match &if_stmt.test {
+                    Expression::UnaryExpression(unary)
+                        if unary.operator == UnaryOperator::LogicalNot =>
+                    {
+                        (LogicalOperator::Or, &unary.argument)
+                    }
+                    test => (LogicalOperator::And, test),
+                };
+                if !Self::is_logical_operand(test, operator)
+                    || !Self::is_logical_operand(&consequent.expression, operator)
+                {
+                    return;
+                }
+                let test = match self.ast.move_expression(&mut if_stmt.test) {
+                    Expression::UnaryExpression(mut unary) if operator == LogicalOperator::Or => {
+                        self.ast.move_expression(&mut unary.argument)
+                    }
+                    test => test,
+                };
+                let right = self.ast.move_expression(&mut consequent.expression);
+                self.join_logical(test, operator, right)
+            }
+            Some(Statement::ExpressionStatement(alternate)) => {
+                let test = self.ast.move_expression(&mut if_stmt.test);
+                let consequent = self.ast.move_expression(&mut consequent.expression);
+                let alternate = self.ast.move_expression(&mut alternate.expression);
+                let mut expr = self.minimized_conditional(test, consequent, alternate);
+                self.hoist_conditional_assignment(&mut expr);
+                expr
+            }
+            Some(_) => return,
+        };
+        *stmt = self.ast.expression_statement(span, expr);
+    }
+
+    /// Whether `expr` is printed without parentheses as an operand of `operator`, once the
+    /// operands of the same operator are joined by [Self::join_logical].
+    fn is_logical_operand(expr: &Expression<'a>, operator: LogicalOperator) -> bool {
+        match expr {
+            Expression::SequenceExpression(_)
+            | Expression::AssignmentExpression(_)
+            | Expression::YieldExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::ConditionalExpression(_) => false,
+            Expression::LogicalExpression(logical) => {
+                logical.operator.precedence() >= operator.precedence()
+            }
+            _ => true,
+        }
+    }
+
+    /// Enabled by `compress.conditionals`
+    ///
+    /// `a ? x = b : x = c` -> `x = a ? b : c`
+    pub(crate) fn hoist_conditional_assignment(&mut self, expr: &mut Expression<'a>) {
+        if !self.options.conditionals {
+            return;
+        }
+        let Expression::ConditionalExpression(cond_expr) = expr else { return };
+        let cond_expr = &mut **cond_expr;
+        let span = cond_expr.span;
+        if !Self::is_same_assignment(&cond_expr.test, &cond_expr.consequent, &cond_expr.alternate) {
+            return;
+        }
+        let (
+            Expression::AssignmentExpression(consequent),
+            Expression::AssignmentExpression(alternate),
+        ) = (&mut cond_expr.consequent, &mut cond_expr.alternate)
+        else {
+            unreachable!()
+        };
+        let operator = consequent.operator;
+        let left = self.ast.move_assignment_target(&mut consequent.left);
+        let consequent = self.ast.move_expression(&mut consequent.right);
+        let alternate = self.ast.move_expression(&mut alternate.right);
+        let test = self.ast.move_expression(&mut cond_expr.test);
+        let right = self.minimized_conditional(test, consequent, alternate);
+        *expr = self.ast.assignment_expression(span, operator, left, right);
+    }
+
+    /// Build `test ? consequent : alternate`, without the negation of the test.
+    /// Enabled by `compress.conditionals`
+    ///
+    /// `!a ? b : c` -> `a ? c : b`
+    pub(crate) fn minimized_conditional(
+        &mut self,
+        test: Expression<'a>,
+        consequent: Expression<'a>,
+        alternate: Expression<'a>,
+    ) -> Expression<'a> {
+        match test {
+            Expression::UnaryExpression(mut unary)
+                if self.options.conditionals && unary.operator == UnaryOperator::LogicalNot =>
+            {
+                let test = self.ast.move_expression(&mut unary.argument);
+                self.ast.conditional_expression(SPAN, test, alternate, consequent)
+            }
+            test => self.ast.conditional_expression(SPAN, test, consequent, alternate),
+        }
+    }
+
+    /// Whether `consequent` and `alternate` assign to the same target with the same operator,
+    /// and evaluating the target before `test` does not change the result.
+    fn is_same_assignment(
+        test: &Expression<'a>,
+        consequent: &Expression<'a>,
+        alternate: &Expression<'a>,
+    ) -> bool {
+        let (
+            Expression::AssignmentExpression(consequent),
+            Expression::AssignmentExpression(alternate),
+        ) = (consequent, alternate)
+        else {
+            return false;
+        };
+        // `x ||= a ? b : c` would skip the test.
+        if consequent.operator != alternate.operator || consequent.operator.is_logical() {
+            return false;
+        }
+        let is_same_target = match (&consequent.left, &alternate.left) {
+            (
+                AssignmentTarget::AssignmentTargetIdentifier(a),
+                AssignmentTarget::AssignmentTargetIdentifier(b),
+            ) => a.name == b.name,
+            (a, b) => match (a.as_member_expression(), b.as_member_expression()) {
+                (Some(a), Some(b)) => Self::is_same_member(a, b),
+                _ => false,
+            },
+        };
+        // `x += a ? b : c` reads `x` before the test as well.
+        let reads_target = consequent.operator != AssignmentOperator::Assign;
+        is_same_target
+            && Effects::of_assignment_target(&consequent.left, reads_target)
+                .can_reorder(&Effects::of(test))
+    }
+
+    fn is_same_member(a: &MemberExpression<'a>, b: &MemberExpression<'a>) -> bool {
+        a.object().content_eq(b.object())
+            && match (a, b) {
+                (
+                    MemberExpression::ComputedMemberExpression(a),
+                    MemberExpression::ComputedMemberExpression(b),
+                ) => a.expression.content_eq(&b.expression),
+                (
+                    MemberExpression::StaticMemberExpression(a),
+                    MemberExpression::StaticMemberExpression(b),
+                ) => a.property.name == b.property.name,
+                (
+                    MemberExpression::PrivateFieldExpression(a),
+                    MemberExpression::PrivateFieldExpression(b),
+                ) => a.field.name == b.field.name,
+                _ => false,
+            }
+    }
+
+    /// Enabled by `compress.conditionals`
+    ///
+    /// * `if (a) return b; else { c(); d() }` -> `if (a) return b; c(); d()`
+    /// * `if (a) { b(); throw c } else d()` -> `if (a) { b(); throw c } d()`
+    pub(crate) fn fold_else_after_exit(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if !self.options.conditionals {
+            return;
+        }
+        let mut i = 0;
+        while i < stmts.len() {
+            i += 1;
+            let Statement::IfStatement(if_stmt) = &mut stmts[i - 1] else { continue };
+            // Block scoped declarations would change scope when moved out of the block.
+            let movable = match &if_stmt.alternate {
+                Some(Statement::BlockStatement(block)) => {
+                    !block.body.iter().any(Self::is_block_scoped_declaration)
+                }
+                Some(stmt) => !Self::is_block_scoped_declaration(stmt),
+                None => false,
+            };
+            if !movable || !Self::always_exits(&if_stmt.consequent) {
+                continue;
+            }
+            match if_stmt.alternate.take().unwrap() {
+                Statement::BlockStatement(mut block) => {
+                    stmts.splice(i..i, block.body.drain(..));
+                }
+                stmt => stmts.insert(i, stmt),
+            }
+        }
+    }
+
+    /// Enabled by `compress.conditionals`
+    ///
+    /// * `if (a) return b; if (c) return b` -> `if (a || c) return b`
+    /// * `if (a) throw b; if (c) throw b; else d()` -> `if (a || c) throw b; else d()`
+    pub(crate) fn merge_exiting_if_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if !self.options.conditionals {
+            return;
+        }
+        let mut i = 1;
+        while i < stmts.len() {
+            let mergeable = match (&stmts[i - 1], &stmts[i]) {
+                (Statement::IfStatement(prev), Statement::IfStatement(cur)) => {
+                    prev.alternate.is_none()
+                        && Self::always_exits(&prev.consequent)
+                        && prev.consequent.content_eq(&cur.consequent)
+                }
+                _ => false,
+            };
+            if !mergeable {
+                i += 1;
+                continue;
+            }
+            let Statement::IfStatement(mut prev) = stmts.remove(i - 1) else { unreachable!() };
+            let Statement::IfStatement(cur) = &mut stmts[i - 1] else { unreachable!() };
+            let left = self.ast.move_expression(&mut prev.test);
+            let right = self.ast.move_expression(&mut cur.test);
+            cur.test = self.join_logical(left, LogicalOperator::Or, right);
+        }
+    }
+
+    /// `left || right`, keeping the operands of `||` in `right` at the top level,
+    /// so that `a || (b || c)` is `a || b || c`. The same for `&&`.
+    fn join_logical(
+        &mut self,
+        left: Expression<'a>,
+        operator: LogicalOperator,
+        right: Expression<'a>,
+    ) -> Expression<'a> {
+        match right {
+            Expression::LogicalExpression(mut logical_expr)
+                if logical_expr.operator == operator =>
+            {
+                let inner = self.ast.move_expression(&mut logical_expr.left);
+                let left = self.join_logical(left, operator, inner);
+                let right = self.ast.move_expression(&mut logical_expr.right);
+                self.ast.logical_expression(SPAN, left, operator, right)
+            }
+            right => self.ast.logical_expression(SPAN, left, operator, right),
+        }
+    }
+
+    /// Remove the `return` without an argument at the end of a function body, also from the
+    /// branches of a trailing `if` statement. Returns `true` if `stmts` is changed.
+    /// Enabled by `compress.conditionals`
+    ///
+    /// * `a(); return` -> `a()`
+    /// * `if (a) { b(); return } else c()` -> `if (a) b(); else c()`
+    fn remove_trailing_return(&mut self, stmts: &mut Vec<'a, Statement<'a>>) -> bool {
+        if !self.options.conditionals {
+            return false;
+        }
+        let Some(last) = stmts.last_mut() else { return false };
+        if Self::is_return_without_argument(last) {
+            stmts.pop();
+            return true;
+        }
+        let Statement::IfStatement(if_stmt) = last else { return false };
+        let if_stmt = &mut **if_stmt;
+        let mut changed = self.remove_trailing_return_from_branch(&mut if_stmt.consequent);
+        if let Some(alternate) = &mut if_stmt.alternate {
+            changed |= self.remove_trailing_return_from_branch(alternate);
+        }
+        if changed {
+            self.minimize_if_statement(last);
+            self.fold_if_expression(last);
+        }
+        changed
+    }
+
+    fn remove_trailing_return_from_branch(&mut self, stmt: &mut Statement<'a>) -> bool {
+        if Self::is_return_without_argument(stmt) {
+            *stmt = self.ast.empty_statement(SPAN);
+            return true;
+        }
+        let Statement::BlockStatement(block) = stmt else { return false };
+        if !self.remove_trailing_return(&mut block.body) {
+            return false;
+        }
+        self.join_sequences(&mut block.body);
+        self.compress_block(stmt);
+        true
+    }
+
+    /// Invert the early exits at the top level of `stmts` into a conditional block.
+    /// Enabled by `compress.conditionals`
+    ///
+    /// * `if (a) return; b(); c()` -> `if (!a) { b(); c() }` for the body of a function
+    /// * `if (a) continue; b(); c()` -> `if (!a) { b(); c() }` for the body of a loop
+    ///
+    /// Only valid when `stmts` ends the function or the loop iteration, which `is_exit` decides
+    /// for the statement kinds it accepts. Returns `true` if `stmts` is changed.
+    fn fold_early_exit(
+        &mut self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        is_exit: fn(&Statement<'a>) -> bool,
+    ) -> bool {
+        if !self.options.conditionals {
+            return false;
+        }
+        // Block scoped declarations would change scope when moved into the block.
+        let Some(index) = (0..stmts.len()).find(|&i| {
+            matches!(&stmts[i], Statement::IfStatement(if_stmt) if if_stmt.alternate.is_none() && is_exit(&if_stmt.consequent))
+                && !stmts.iter().skip(i + 1).any(Self::is_block_scoped_declaration)
+        }) else {
+            return false;
+        };
+        let mut rest = self.ast.new_vec_with_capacity(stmts.len() - index - 1);
+        rest.extend(stmts.drain(index + 1..));
+        if self.fold_early_exit(&mut rest, is_exit) {
+            self.join_sequences(&mut rest);
+        }
+        let Statement::IfStatement(if_stmt) = &mut stmts[index] else { unreachable!() };
+        let test = self.ast.move_expression(&mut if_stmt.test);
+        if_stmt.test = self.negate_condition(test);
+        let mut consequent = Statement::BlockStatement(self.ast.block(SPAN, rest));
+        self.compress_block(&mut consequent);
+        if_stmt.consequent = consequent;
+        self.minimize_if_statement(&mut stmts[index]);
+        self.fold_if_expression(&mut stmts[index]);
+        true
+    }
+
+    /// Fold the early `return`s of a function body, see [Self::fold_early_exit].
+    /// Remove the trailing `return` first, see [Self::remove_trailing_return].
+    pub(crate) fn fold_function_body(&mut self, body: &mut FunctionBody<'a>) {
+        let removed = self.remove_trailing_return(&mut body.statements);
+        if self.fold_early_exit(&mut body.statements, Self::is_return_without_argument) || removed {
+            self.join_sequences(&mut body.statements);
+        }
+    }
+
+    /// Fold the early `continue`s of a loop body, see [Self::fold_early_exit].
+    ///
+    /// A body which is a single `if` statement was unwrapped from its block before it was
+    /// visited, so its `else` branch is moved after it here, see [Self::fold_else_after_exit]:
+    /// `for (;;) if (a) break; else b()` -> `for (;;) { if (a) break; b() }`
+    pub(crate) fn fold_loop_body(&mut self, body: &mut Statement<'a>) {
+        if matches!(body, Statement::IfStatement(if_stmt) if if_stmt.alternate.is_some()) {
+            let stmt = self.ast.move_statement(body);
+            *body = Statement::BlockStatement(self.ast.block(SPAN, self.ast.new_vec_single(stmt)));
+        }
+        let Statement::BlockStatement(block) = body else { return };
+        let len = block.body.len();
+        self.fold_else_after_exit(&mut block.body);
+        if block.body.len() != len {
+            self.join_sequences(&mut block.body);
+        }
+        if self.fold_early_exit(&mut block.body, Self::is_unlabeled_continue) {
+            self.join_sequences(&mut block.body);
+        }
+        self.compress_block(body);
+    }
+
+    /// Negate an expression in boolean context.
+    ///
+    /// * `!a` -> `a`
+    /// * `a === b` -> `a !== b`
+    /// * `a` -> `!a`
+    fn negate_condition(&mut self, expr: Expression<'a>) -> Expression<'a> {
+        match expr {
+            Expression::UnaryExpression(mut unary)
+                if unary.operator == UnaryOperator::LogicalNot =>
+            {
+                self.ast.move_expression(&mut unary.argument)
+            }
+            Expression::BinaryExpression(mut binary) if binary.operator.is_equality() => {
+                if let Some(operator) = binary.operator.equality_inverse_operator() {
+                    binary.operator = operator;
+                }
+                Expression::BinaryExpression(binary)
+            }
+            expr => self.ast.unary_expression(SPAN, UnaryOperator::LogicalNot, expr),
+        }
+    }
+
+    fn is_empty_branch(stmt: &Statement<'a>) -> bool {
+        match stmt {
+            Statement::EmptyStatement(_) => true,
+            Statement::BlockStatement(block) => block.body.is_empty(),
+            _ => false,
+        }
+    }
+
+    pub(crate) fn is_block_scoped_declaration(stmt: &Statement<'a>) -> bool {
+        match stmt {
+            Statement::VariableDeclaration(decl) => decl.kind.is_lexical(),
+            stmt => stmt.is_declaration(),
+        }
+    }
+
+    /// Whether `stmt` never completes normally, ignoring the exits inside of nested statements.
+    pub(crate) fn always_exits(stmt: &Statement<'a>) -> bool {
+        match stmt {
+            Statement::ReturnStatement(_)
+            | Statement::ThrowStatement(_)
+            | Statement::BreakStatement(_)
+            | Statement::ContinueStatement(_) => true,
+            Statement::BlockStatement(block) => block.body.last().is_some_and(Self::always_exits),
+            _ => false,
+        }
+    }
+
+    fn is_return_without_argument(stmt: &Statement<'a>) -> bool {
+        matches!(stmt, Statement::ReturnStatement(ret) if ret.argument.is_none())
+    }
+
+    fn is_unlabeled_continue(stmt: &Statement<'a>) -> bool {
+        matches!(stmt, Statement::ContinueStatement(stmt) if stmt.label.is_none())
+    }
+}
@@ -0,0 +1,108 @@
+//! Immediately Invoked Arrow Functions
+//!
+//! Collapses an arrow function without parameters which is called right away, and whose body
+//! is a decision tree, into an expression, like a `do` expression would be:
+//! `(() => { if (a) return b; return c })()` -> `a ? b : c`. Compilers of pattern matching
+//! emit such helpers for `match` and `switch` expressions.
+//!
+//! A decision tree is made of `if` statements, blocks and `return` statements only, and every
+//! path through it ends with a `return`, so it declares nothing and has no side effects other
+//! than those of its tests and results, which are evaluated in the same order afterwards. An
+//! arrow function has no `this` or `arguments` of its own, so its body means the same outside
+//! of it. Async arrow functions, which return a promise, and bodies with directives are kept.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+
+use super::Compressor;
+
+impl<'a> Compressor<'a> {
+    /// Enabled by `compress.iifes`
+    ///
+    /// * `(() => a)()` -> `a`
+    /// * `(() => { if (a) return b; return c })()` -> `a ? b : c`
+    /// * `(() => { if (a) { if (b) return c; return d } return e })()` -> `a ? b ? c : d : e`
+    pub(crate) fn collapse_iife(&mut self, expr: &mut Expression<'a>) {
+        if !self.options.iifes {
+            return;
+        }
+        let Expression::CallExpression(call_expr) = expr else { return };
+        if call_expr.optional || !call_expr.arguments.is_empty() {
+            return;
+        }
+        let Some(arrow) = Self::arrow_callee(&mut call_expr.callee) else { return };
+        if arrow.r#async
+            || !arrow.params.is_empty()
+            || arrow.params.rest.is_some()
+            || !arrow.body.directives.is_empty()
+        {
+            return;
+        }
+        let collapsed = if arrow.expression {
+            let Some(Statement::ExpressionStatement(expr_stmt)) = arrow.body.statements.first_mut()
+            else {
+                return;
+            };
+            self.ast.move_expression(&mut expr_stmt.expression)
+        } else {
+            if !Self::is_decision_tree(&arrow.body.statements) {
+                return;
+            }
+            self.lower_decision_tree(&mut arrow.body.statements)
+        };
+        *expr = collapsed;
+    }
+
+    /// The arrow function `callee`, which may be parenthesized.
+    fn arrow_callee<'b>(
+        callee: &'b mut Expression<'a>,
+    ) -> Option<&'b mut ArrowFunctionExpression<'a>> {
+        match callee {
+            Expression::ArrowFunctionExpression(arrow) => Some(&mut **arrow),
+            Expression::ParenthesizedExpression(paren) => Self::arrow_callee(&mut paren.expression),
+            _ => None,
+        }
+    }
+
+    /// Whether every path through `stmts` ends with a `return`, through `if` statements and
+    /// blocks only.
+    fn is_decision_tree(stmts: &[Statement<'a>]) -> bool {
+        match stmts {
+            [Statement::ReturnStatement(_)] => true,
+            [Statement::BlockStatement(block)] => Self::is_decision_tree(&block.body),
+            [Statement::IfStatement(if_stmt), rest @ ..] => {
+                Self::is_decision_tree(std::slice::from_ref(&if_stmt.consequent))
+                    && match &if_stmt.alternate {
+                        Some(alternate) => {
+                            rest.is_empty()
+                                && Self::is_decision_tree(std::slice::from_ref(alternate))
+                        }
+                        None => Self::is_decision_tree(rest),
+                    }
+            }
+            _ => false,
+        }
+    }
+
+    /// Lower the decision tree `stmts`, see [Self::is_decision_tree], to the expression of its
+    /// result, with a conditional expression for every `if` statement.
+    fn lower_decision_tree(&mut self, stmts: &mut [Statement<'a>]) -> Expression<'a> {
+        match stmts {
+            [Statement::ReturnStatement(ret)] => {
+                ret.argument.take().unwrap_or_else(|| self.ast.void_0())
+            }
+            [Statement::BlockStatement(block)] => self.lower_decision_tree(&mut block.body),
+            [Statement::IfStatement(if_stmt), rest @ ..] => {
+                let test = self.ast.move_expression(&mut if_stmt.test);
+                let consequent =
+                    self.lower_decision_tree(std::slice::from_mut(&mut if_stmt.consequent));
+                let alternate = match &mut if_stmt.alternate {
+                    Some(alternate) => self.lower_decision_tree(std::slice::from_mut(alternate)),
+                    None => self.lower_decision_tree(rest),
+                };
+                self.minimized_conditional(test, consequent, alternate)
+            }
+            _ => unreachable!(),
+        }
+    }
+}
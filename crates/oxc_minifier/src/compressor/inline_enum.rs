@@ -0,0 +1,437 @@
+//! Enum-like Object Inlining
+//!
+//! Inlines the members of frozen objects and lowered TypeScript enums at their use sites.
+//!
+//! ```javascript
+//! const E = Object.freeze({ A: 1, B: "b" });
+//! use(E.A, E["B"]);
+//! ```
+//!
+//! is compressed to `use(1, "b")` and the declaration is dropped.
+//!
+//! Only top-level declarations in modules are considered, since top-level bindings of a script
+//! are visible to other scripts. The object is kept as is whenever the binding escapes: any
+//! reference that is not a read of a known member (`E`, `E.A = 1`, `delete E.A`, `E.C`), a
+//! binding of the same name anywhere else in the program, or a direct `eval` / `with`.
+
+use oxc_allocator::Allocator;
+use oxc_ast::visit::{
+    walk::{
+        walk_call_expression, walk_export_named_declaration, walk_expression, walk_unary_expression,
+    },
+    walk_mut::walk_expression_mut,
+};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder, Visit, VisitMut};
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::{
+    number::NumberBase,
+    operator::{AssignmentOperator, LogicalOperator, UnaryOperator},
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// A member value that can be inlined.
+#[derive(Debug, Clone)]
+enum EnumValue<'a> {
+    Number { value: f64, raw: &'a str, base: NumberBase, negative: bool },
+    String(Atom<'a>),
+}
+
+#[derive(Debug)]
+struct EnumObject<'a> {
+    /// Index of the declaring statement in `Program::body`.
+    statement_index: usize,
+    members: FxHashMap<Atom<'a>, EnumValue<'a>>,
+    /// Reverse mapping of lowered TypeScript numeric enums, `E[0] === "A"`.
+    reverse_members: Vec<(f64, Atom<'a>)>,
+}
+
+impl<'a> EnumObject<'a> {
+    #[allow(clippy::float_cmp)]
+    fn get(&self, expr: &MemberExpression<'a>) -> Option<EnumValue<'a>> {
+        if let MemberExpression::ComputedMemberExpression(computed) = expr {
+            if let Expression::NumericLiteral(lit) = &computed.expression {
+                return self
+                    .reverse_members
+                    .iter()
+                    .find(|(value, _)| *value == lit.value)
+                    .map(|(_, name)| EnumValue::String(name.clone()));
+            }
+        }
+        let name = expr.static_property_name()?;
+        self.members.get(name).cloned()
+    }
+}
+
+pub struct InlineEnum<'a> {
+    ast: AstBuilder<'a>,
+    enums: FxHashMap<Atom<'a>, EnumObject<'a>>,
+}
+
+impl<'a> InlineEnum<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { ast: AstBuilder::new(allocator), enums: FxHashMap::default() }
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        if !program.source_type.is_module() {
+            return;
+        }
+
+        let mut duplicates = FxHashSet::default();
+        for (i, stmt) in program.body.iter().enumerate() {
+            if let Some((name, object)) = Self::collect_enum_object(i, stmt) {
+                // Candidates with the same name can not be told apart, skip all of them.
+                if duplicates.contains(&name) {
+                    continue;
+                }
+                if self.enums.insert(name.clone(), object).is_some() {
+                    self.enums.remove(&name);
+                    duplicates.insert(name);
+                }
+            }
+        }
+        if self.enums.is_empty() {
+            return;
+        }
+
+        let mut checker = EscapeChecker::new(&self.enums);
+        for (i, stmt) in program.body.iter().enumerate() {
+            if !self.enums.values().any(|object| object.statement_index == i) {
+                checker.visit_statement(stmt);
+            }
+        }
+        if checker.poisoned {
+            self.enums.clear();
+            return;
+        }
+        let escaped = checker.escaped;
+        self.enums.retain(|name, _| !escaped.contains(name));
+        if self.enums.is_empty() {
+            return;
+        }
+
+        let removed =
+            self.enums.values().map(|object| object.statement_index).collect::<FxHashSet<_>>();
+        let mut i = 0;
+        program.body.retain(|_| {
+            i += 1;
+            !removed.contains(&(i - 1))
+        });
+        self.visit_program(program);
+    }
+
+    /// `const E = Object.freeze({ A: 1 })` or the lowered TypeScript enum
+    /// `var E = function(E) { E[E["A"] = 0] = "A"; return E; }(E || {})`
+    fn collect_enum_object(
+        statement_index: usize,
+        stmt: &Statement<'a>,
+    ) -> Option<(Atom<'a>, EnumObject<'a>)> {
+        let Statement::VariableDeclaration(decl) = stmt else { return None };
+        if decl.declarations.len() != 1 {
+            return None;
+        }
+        let declarator = &decl.declarations[0];
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+            return None;
+        };
+        let Some(Expression::CallExpression(call_expr)) = &declarator.init else { return None };
+
+        let mut object =
+            EnumObject { statement_index, members: FxHashMap::default(), reverse_members: vec![] };
+        let collected = if decl.kind.is_const()
+            && call_expr.callee.is_specific_member_access("Object", "freeze")
+        {
+            Self::collect_frozen_object(call_expr, &mut object)
+        } else {
+            Self::collect_lowered_enum(&ident.name, call_expr, &mut object)
+        };
+        collected.then(|| (ident.name.clone(), object))
+    }
+
+    /// `Object.freeze({ A: 1, B: "b" })`
+    fn collect_frozen_object(call_expr: &CallExpression<'a>, object: &mut EnumObject<'a>) -> bool {
+        if call_expr.arguments.len() != 1 {
+            return false;
+        }
+        let Argument::ObjectExpression(object_expr) = &call_expr.arguments[0] else {
+            return false;
+        };
+        for property in &object_expr.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = property else { return false };
+            if property.kind != PropertyKind::Init || property.method || property.computed {
+                return false;
+            }
+            let name = match &property.key {
+                PropertyKey::StaticIdentifier(ident) => ident.name.clone(),
+                PropertyKey::StringLiteral(lit) => lit.value.clone(),
+                _ => return false,
+            };
+            // `__proto__: x` sets the prototype instead of defining a property.
+            if name == "__proto__" {
+                return false;
+            }
+            let Some(value) = Self::get_enum_value(&property.value) else { return false };
+            object.members.insert(name, value);
+        }
+        true
+    }
+
+    /// `function(E) { E[E["A"] = 0] = "A"; E["B"] = "b"; return E; }(E || {})`
+    fn collect_lowered_enum(
+        name: &Atom<'a>,
+        call_expr: &CallExpression<'a>,
+        object: &mut EnumObject<'a>,
+    ) -> bool {
+        let is_enum_argument = match call_expr.arguments.as_slice() {
+            [Argument::ObjectExpression(object_expr)] => object_expr.properties.is_empty(),
+            [Argument::LogicalExpression(logical_expr)] => {
+                logical_expr.operator == LogicalOperator::Or
+                    && logical_expr.left.is_specific_id(name.as_str())
+                    && matches!(
+                        &logical_expr.right,
+                        Expression::ObjectExpression(object_expr) if object_expr.properties.is_empty()
+                    )
+            }
+            _ => false,
+        };
+        if !is_enum_argument {
+            return false;
+        }
+
+        let (params, body) = match &call_expr.callee {
+            Expression::FunctionExpression(func) => {
+                if func.r#async || func.generator {
+                    return false;
+                }
+                let Some(body) = &func.body else { return false };
+                (&func.params, body)
+            }
+            Expression::ArrowFunctionExpression(func) if !func.expression && !func.r#async => {
+                (&func.params, &func.body)
+            }
+            _ => return false,
+        };
+        if params.items.len() != 1 || params.rest.is_some() {
+            return false;
+        }
+        let BindingPatternKind::BindingIdentifier(param) = &params.items[0].pattern.kind else {
+            return false;
+        };
+        let param = param.name.as_str();
+
+        let Some((last, members)) = body.statements.split_last() else { return false };
+        let Statement::ReturnStatement(ret) = last else { return false };
+        if !ret.argument.as_ref().is_some_and(|arg| arg.is_specific_id(param)) {
+            return false;
+        }
+
+        for stmt in members {
+            let Statement::ExpressionStatement(stmt) = stmt else { return false };
+            let Expression::AssignmentExpression(assign) = &stmt.expression else { return false };
+            match Self::get_enum_member_assignment(param, assign) {
+                // E["B"] = "b"
+                Some((EnumMemberKey::Name(name), value @ EnumValue::String(_))) => {
+                    object.members.insert(name, value);
+                }
+                // E[E["A"] = 0] = "A"
+                Some((EnumMemberKey::Reverse(name, value), EnumValue::String(reverse_name)))
+                    if name == reverse_name =>
+                {
+                    let EnumValue::Number { value: number, negative, .. } = &value else {
+                        return false;
+                    };
+                    let number = if *negative { -number } else { *number };
+                    object.reverse_members.push((number, name.clone()));
+                    object.members.insert(name, value);
+                }
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// Returns the key and the assigned value of `E["A"] = value` or `E[E["A"] = value] = "A"`.
+    fn get_enum_member_assignment(
+        param: &str,
+        assign: &AssignmentExpression<'a>,
+    ) -> Option<(EnumMemberKey<'a>, EnumValue<'a>)> {
+        if assign.operator != AssignmentOperator::Assign {
+            return None;
+        }
+        let AssignmentTarget::ComputedMemberExpression(member) = &assign.left else { return None };
+        if !member.object.is_specific_id(param) {
+            return None;
+        }
+        let value = Self::get_enum_value(&assign.right)?;
+        let key = match &member.expression {
+            Expression::StringLiteral(lit) => EnumMemberKey::Name(lit.value.clone()),
+            Expression::AssignmentExpression(inner) => {
+                let (EnumMemberKey::Name(name), inner_value @ EnumValue::Number { .. }) =
+                    Self::get_enum_member_assignment(param, inner)?
+                else {
+                    return None;
+                };
+                EnumMemberKey::Reverse(name, inner_value)
+            }
+            _ => return None,
+        };
+        Some((key, value))
+    }
+
+    fn get_enum_value(expr: &Expression<'a>) -> Option<EnumValue<'a>> {
+        match expr {
+            Expression::NumericLiteral(lit) => Some(EnumValue::Number {
+                value: lit.value,
+                raw: lit.raw,
+                base: lit.base,
+                negative: false,
+            }),
+            Expression::UnaryExpression(unary_expr)
+                if unary_expr.operator == UnaryOperator::UnaryNegation =>
+            {
+                let Expression::NumericLiteral(lit) = &unary_expr.argument else { return None };
+                Some(EnumValue::Number {
+                    value: lit.value,
+                    raw: lit.raw,
+                    base: lit.base,
+                    negative: true,
+                })
+            }
+            Expression::StringLiteral(lit) => Some(EnumValue::String(lit.value.clone())),
+            _ => None,
+        }
+    }
+
+    fn create_value(&self, span: Span, value: EnumValue<'a>) -> Expression<'a> {
+        match value {
+            EnumValue::Number { value, raw, base, negative } => {
+                let literal = self.ast.number_literal(span, value, raw, base);
+                let literal = self.ast.literal_number_expression(literal);
+                if negative {
+                    self.ast.unary_expression(span, UnaryOperator::UnaryNegation, literal)
+                } else {
+                    literal
+                }
+            }
+            EnumValue::String(value) => {
+                self.ast.literal_string_expression(StringLiteral::new(span, value))
+            }
+        }
+    }
+
+    fn get_inlined_value(&self, expr: &Expression<'a>) -> Option<EnumValue<'a>> {
+        let member_expr = expr.as_member_expression()?;
+        if member_expr.optional() {
+            return None;
+        }
+        let Expression::Identifier(ident) = member_expr.object() else { return None };
+        self.enums.get(&ident.name)?.get(member_expr)
+    }
+}
+
+impl<'a> VisitMut<'a> for InlineEnum<'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Some(value) = self.get_inlined_value(expr) {
+            *expr = self.create_value(expr.span(), value);
+            return;
+        }
+        walk_expression_mut(self, expr);
+    }
+}
+
+#[derive(Debug)]
+enum EnumMemberKey<'a> {
+    /// `E["A"]`
+    Name(Atom<'a>),
+    /// `E[E["A"] = 0]`
+    Reverse(Atom<'a>, EnumValue<'a>),
+}
+
+/// Finds the enum objects that escape, i.e. are referenced by anything other than a read of a
+/// known member.
+struct EscapeChecker<'a, 'b> {
+    enums: &'b FxHashMap<Atom<'a>, EnumObject<'a>>,
+    escaped: FxHashSet<Atom<'a>>,
+    /// Direct `eval` or `with` can reference any binding.
+    poisoned: bool,
+}
+
+impl<'a, 'b> EscapeChecker<'a, 'b> {
+    fn new(enums: &'b FxHashMap<Atom<'a>, EnumObject<'a>>) -> Self {
+        Self { enums, escaped: FxHashSet::default(), poisoned: false }
+    }
+
+    fn enum_member<'c>(&self, expr: &'c Expression<'a>) -> Option<&'c Atom<'a>> {
+        let member_expr = expr.as_member_expression()?;
+        let Expression::Identifier(ident) = member_expr.object() else { return None };
+        self.enums.contains_key(&ident.name).then_some(&ident.name)
+    }
+}
+
+impl<'a, 'b> Visit<'a> for EscapeChecker<'a, 'b> {
+    fn visit_expression(&mut self, expr: &Expression<'a>) {
+        if let Some(name) = self.enum_member(expr) {
+            let member_expr = expr.to_member_expression();
+            if member_expr.optional() || self.enums[name].get(member_expr).is_none() {
+                self.escaped.insert(name.clone());
+            }
+            // Visit the computed key, the object is a known read.
+            if let MemberExpression::ComputedMemberExpression(computed) = member_expr {
+                self.visit_expression(&computed.expression);
+            }
+            return;
+        }
+        walk_expression(self, expr);
+    }
+
+    fn visit_unary_expression(&mut self, expr: &UnaryExpression<'a>) {
+        if expr.operator == UnaryOperator::Delete {
+            if let Some(name) = self.enum_member(&expr.argument) {
+                self.escaped.insert(name.clone());
+            }
+        }
+        walk_unary_expression(self, expr);
+    }
+
+    fn visit_call_expression(&mut self, expr: &CallExpression<'a>) {
+        if expr.callee.is_specific_id("eval") {
+            self.poisoned = true;
+        }
+        walk_call_expression(self, expr);
+    }
+
+    /// `export { E }` exports the object itself.
+    fn visit_export_named_declaration(&mut self, decl: &ExportNamedDeclaration<'a>) {
+        if decl.source.is_none() {
+            for specifier in &decl.specifiers {
+                if let ModuleExportName::Identifier(local) = &specifier.local {
+                    if self.enums.contains_key(&local.name) {
+                        self.escaped.insert(local.name.clone());
+                    }
+                }
+            }
+        }
+        walk_export_named_declaration(self, decl);
+    }
+
+    fn visit_with_statement(&mut self, _stmt: &WithStatement<'a>) {
+        self.poisoned = true;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if self.enums.contains_key(&ident.name) {
+            self.escaped.insert(ident.name.clone());
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        if self.enums.contains_key(&ident.name) {
+            self.escaped.insert(ident.name.clone());
+        }
+        // `Object.freeze` must refer to the global.
+        if ident.name == "Object" {
+            self.poisoned = true;
+        }
+    }
+}
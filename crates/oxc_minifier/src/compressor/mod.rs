@@ -1,18 +1,56 @@
 #![allow(clippy::unused_self)]
 
+mod array;
 mod ast_util;
+mod binds;
+mod booleans;
+mod builtins;
+mod classes;
+mod comparisons;
+mod dead_code;
+mod dead_stores;
+mod debug_code;
+mod default_params;
+mod destructuring;
+mod duplicate_keys;
 mod fold;
+mod global_constants;
+mod global_defs;
+mod helpers;
+mod hoist_functions;
+mod if_statements;
+mod iifes;
+mod inline_enum;
+mod modules;
+mod motion;
+mod narrow_vars;
+mod node_env;
+mod nullish_coalescing;
+mod optional_chains;
 mod options;
 mod prepass;
+mod properties;
+#[cfg(feature = "regexp")]
+mod regexp;
+mod sequences;
+mod string_arrays;
+mod string_pool;
+mod switches;
+mod typeofs;
+mod unused_imports;
+mod unused_params;
 mod util;
 
 use oxc_allocator::{Allocator, Vec};
 use oxc_ast::visit::walk_mut::{
-    walk_binary_expression_mut, walk_expression_mut, walk_return_statement_mut, walk_statement_mut,
-    walk_statements_mut,
+    walk_binary_expression_mut, walk_class_element_mut, walk_expression_mut,
+    walk_function_body_mut, walk_member_expression_mut, walk_object_property_mut,
+    walk_return_statement_mut, walk_statement_mut, walk_statements_mut,
 };
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::{SemanticBuilder, SymbolTable};
 use oxc_span::Span;
 use oxc_syntax::{
     number::NumberBase,
@@ -20,38 +58,208 @@ use oxc_syntax::{
     precedence::GetPrecedence,
 };
 
-pub use self::options::CompressOptions;
-use self::prepass::Prepass;
+use crate::{
+    pass::{CustomPass, PassPhase, Passes},
+    snapshot::{PassSnapshot, Printer, Snapshots},
+};
+
+use self::{
+    classes::ClassDowngrade, dead_stores::DeadStores, default_params::DefaultParams,
+    destructuring::Destructuring, duplicate_keys::DuplicateKeys, global_constants::GlobalConstants,
+    helpers::Helpers, hoist_functions::HoistFunctions, inline_enum::InlineEnum,
+    narrow_vars::NarrowVars, nullish_coalescing::NullishCoalescing,
+    optional_chains::OptionalChains, prepass::Prepass, string_arrays::StringArrays,
+    string_pool::StringPool, unused_imports::UnusedImports, unused_params::UnusedParams,
+};
+pub use self::{global_defs::GlobalDefValue, options::CompressOptions};
 
 pub struct Compressor<'a> {
     ast: AstBuilder<'a>,
     options: CompressOptions,
 
     prepass: Prepass<'a>,
+
+    /// Resolves references to global bindings which may be shadowed, e.g. `undefined`.
+    symbols: SymbolTable,
+
+    /// The program contains a `with` statement or a direct `eval`, so a reference may resolve to
+    /// any binding at runtime, and no reference is known to be global.
+    dynamic_scope: bool,
+
+    /// The statement being visited is the `else if` of an `if` statement, which is folded into an
+    /// expression together with that `if` statement, after their identical branches are merged.
+    in_else_if: bool,
+
+    snapshots: Option<Snapshots>,
+
+    /// The passes registered with [Compressor::with_pass].
+    passes: Passes,
+}
+
+pub struct CompressorReturn {
+    /// Suspicious code found while compressing.
+    pub warnings: std::vec::Vec<OxcDiagnostic>,
+
+    /// The program after each pass, empty unless enabled by [Compressor::with_snapshots].
+    pub snapshots: std::vec::Vec<PassSnapshot>,
 }
 
 const SPAN: Span = Span::new(0, 0);
 
+fn dynamic_scope(kind: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "The program contains {kind}, the compressions which depend on the scope are disabled"
+    ))
+    .with_label(span)
+}
+
 impl<'a> Compressor<'a> {
     pub fn new(allocator: &'a Allocator, options: CompressOptions) -> Self {
-        Self { ast: AstBuilder::new(allocator), options, prepass: Prepass::new(allocator) }
+        Self {
+            ast: AstBuilder::new(allocator),
+            options,
+            prepass: Prepass::new(allocator),
+            symbols: SymbolTable::default(),
+            dynamic_scope: false,
+            in_else_if: false,
+            snapshots: None,
+            passes: Passes::default(),
+        }
+    }
+
+    /// Run `pass` in `phase`, after the passes registered before it. Its snapshot is recorded
+    /// as `name`.
+    #[must_use]
+    pub fn with_pass(mut self, phase: PassPhase, name: &'static str, pass: CustomPass) -> Self {
+        self.passes.add(phase, name, pass);
+        self
     }
 
-    pub fn build(mut self, program: &mut Program<'a>) {
+    /// Record a snapshot of the program printed by `printer` after each pass, which are returned
+    /// in [CompressorReturn::snapshots].
+    #[must_use]
+    pub fn with_snapshots(mut self, printer: Printer) -> Self {
+        self.snapshots = Some(Snapshots::new(printer));
+        self
+    }
+
+    /// Compress `program`, returns the warnings about suspicious code found along the way.
+    pub fn build(mut self, program: &mut Program<'a>) -> CompressorReturn {
+        let mut warnings = vec![];
+        if let Some(snapshots) = &mut self.snapshots {
+            snapshots.start();
+        }
         self.prepass.build(program);
+        self.snapshot("prepass", program);
+        if let Some((kind, span)) = self.prepass.dynamic_scope {
+            warnings.push(dynamic_scope(kind, span));
+            self.options = self.options.without_scope_analysis();
+            self.dynamic_scope = true;
+        }
+        if self.options.import_ts_helpers.is_some() || self.options.import_babel_helpers.is_some() {
+            Helpers::new(
+                self.ast.allocator,
+                self.options.import_ts_helpers,
+                self.options.import_babel_helpers,
+            )
+            .build(program);
+            self.snapshot("helpers", program);
+        }
+        if self.options.inline_enums {
+            InlineEnum::new(self.ast.allocator).build(program);
+            self.snapshot("inline_enums", program);
+        }
+        if self.options.dead_stores {
+            DeadStores::new(self.ast.allocator).build(program);
+            self.snapshot("dead_stores", program);
+        }
+        if self.options.optional_chaining {
+            OptionalChains::new(self.ast.allocator).build(program);
+            self.snapshot("optional_chaining", program);
+        }
+        if self.options.nullish_coalescing {
+            NullishCoalescing::new(self.ast.allocator).build(program);
+            self.snapshot("nullish_coalescing", program);
+        }
+        if self.options.unsafe_default_params {
+            DefaultParams::new(self.ast.allocator).build(program);
+            self.snapshot("unsafe_default_params", program);
+        }
+        if self.options.hoist_functions {
+            HoistFunctions::default().build(program);
+            self.snapshot("hoist_functions", program);
+        }
+        if self.options.unused_params {
+            UnusedParams::default().build(program);
+            self.snapshot("unused_params", program);
+        }
+        if self.options.narrow_vars {
+            NarrowVars::new(self.ast.allocator).build(program);
+            self.snapshot("narrow_vars", program);
+        }
+        if self.options.duplicate_keys {
+            warnings.extend(DuplicateKeys::new(self.ast.allocator).build(program));
+            self.snapshot("duplicate_keys", program);
+        }
+        if self.options.destructuring {
+            Destructuring::new(self.ast.allocator).build(program);
+            self.snapshot("destructuring", program);
+        }
+        if self.options.unsafe_classes {
+            ClassDowngrade::new(self.ast.allocator).build(program);
+            self.snapshot("unsafe_classes", program);
+        }
+        self.run_custom_passes(PassPhase::Early, program);
+        let semantic = SemanticBuilder::new("", program.source_type).build(program).semantic;
+        self.symbols = semantic.into_symbol_table_and_scope_tree().0;
         self.visit_program(program);
+        self.snapshot("peephole", program);
+        if self.options.unused_imports {
+            UnusedImports::new(self.ast.allocator, self.options).build(program);
+            self.snapshot("unused_imports", program);
+        }
+        self.compress_module_declarations(&mut program.body);
+        self.snapshot("module_declarations", program);
+        if self.options.global_constants {
+            GlobalConstants::new(self.ast.allocator, &self.symbols).build(program);
+            self.snapshot("global_constants", program);
+        }
+        if self.options.late {
+            StringArrays::new(self.ast.allocator).build(program);
+            self.snapshot("string_arrays", program);
+        }
+        if self.options.pool_strings {
+            StringPool::new(self.ast.allocator).build(program);
+            self.snapshot("pool_strings", program);
+        }
+        if self.options.late {
+            self.run_custom_passes(PassPhase::Late, program);
+        }
+        let snapshots = self.snapshots.map(Snapshots::into_snapshots).unwrap_or_default();
+        CompressorReturn { warnings, snapshots }
+    }
+
+    fn run_custom_passes(&mut self, phase: PassPhase, program: &mut Program<'a>) {
+        let mut passes = std::mem::take(&mut self.passes);
+        for (name, pass) in passes.phase(phase) {
+            pass(self.ast.allocator, program);
+            self.snapshot(name, program);
+        }
+        self.passes = passes;
+    }
+
+    fn snapshot(&mut self, pass: &'static str, program: &Program<'a>) {
+        if let Some(snapshots) = &mut self.snapshots {
+            snapshots.record(pass, program);
+        }
     }
 
     /* Utilities */
 
-    /// `1/0`
-    #[allow(unused)]
-    fn create_one_div_zero(&mut self) -> Expression<'a> {
-        let left = self.ast.number_literal(SPAN, 1.0, "1", NumberBase::Decimal);
-        let left = self.ast.literal_number_expression(left);
-        let right = self.ast.number_literal(SPAN, 0.0, "0", NumberBase::Decimal);
-        let right = self.ast.literal_number_expression(right);
-        self.ast.binary_expression(SPAN, left, BinaryOperator::Division, right)
+    /// Whether `ident` refers to a global, rather than a binding which shadows it.
+    fn is_global_reference(&self, ident: &IdentifierReference<'a>) -> bool {
+        !self.dynamic_scope
+            && ident.reference_id.get().is_some_and(|id| self.symbols.is_global_reference(id))
     }
 
     /* Statements */
@@ -70,28 +278,6 @@ impl<'a> Compressor<'a> {
         }
     }
 
-    /// Drop `drop_debugger` statement.
-    /// Enabled by `compress.drop_debugger`
-    fn drop_debugger(&mut self, stmt: &Statement<'a>) -> bool {
-        matches!(stmt, Statement::DebuggerStatement(_)) && self.options.drop_debugger
-    }
-
-    /// Drop `console.*` expressions.
-    /// Enabled by `compress.drop_console
-    fn drop_console(&mut self, stmt: &Statement<'a>) -> bool {
-        self.options.drop_console
-            && matches!(stmt, Statement::ExpressionStatement(expr) if util::is_console(&expr.expression))
-    }
-
-    fn compress_console(&mut self, expr: &mut Expression<'a>) -> bool {
-        if self.options.drop_console && util::is_console(expr) {
-            *expr = self.ast.void_0();
-            true
-        } else {
-            false
-        }
-    }
-
     /// Join consecutive var statements
     fn join_vars(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
         // Collect all the consecutive ranges that contain joinable vars.
@@ -158,28 +344,21 @@ impl<'a> Compressor<'a> {
     /// Transforms `undefined` => `void 0`
     fn compress_undefined(&self, expr: &mut Expression<'a>) -> bool {
         let Expression::Identifier(ident) = expr else { return false };
-        if ident.name == "undefined" {
-            // if let Some(reference_id) = ident.reference_id.get() {
-            // && self.semantic.symbols().is_global_reference(reference_id)
+        if ident.name == "undefined" && self.is_global_reference(ident) {
             *expr = self.ast.void_0();
             return true;
-            // }
         }
         false
     }
 
-    /// Transforms `Infinity` => `1/0`
-    #[allow(unused)]
-    fn compress_infinity(&mut self, expr: &mut Expression<'a>) -> bool {
-        let Expression::Identifier(ident) = expr else { return false };
-        if ident.name == "Infinity" {
-            // if let Some(reference_id) = ident.reference_id.get() {
-            //&& self.semantic.symbols().is_global_reference(reference_id)
-            *expr = self.create_one_div_zero();
-            return true;
-            // }
+    /// `void 0`, or `undefined` when it refers to the global.
+    fn is_undefined(&self, expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::Identifier(ident) => {
+                ident.name == "undefined" && self.is_global_reference(ident)
+            }
+            _ => expr.is_void_0(),
         }
-        false
     }
 
     /// Transforms boolean expression `true` => `!0` `false` => `!1`
@@ -200,44 +379,51 @@ impl<'a> Compressor<'a> {
         false
     }
 
-    /// Transforms `typeof foo == "undefined"` into `foo === void 0`
+    /// Transforms `typeof foo == "undefined"` into `foo === void 0`,
+    /// and `typeof foo != "undefined"` into `foo !== void 0`,
+    /// when `foo` is declared, so that reading it never throws
     /// Enabled by `compress.typeofs`
     fn compress_typeof_undefined(&self, expr: &mut BinaryExpression<'a>) {
         if !self.options.typeofs {
             return;
         }
-        match expr.operator {
+        let operator = match expr.operator {
             BinaryOperator::Equality | BinaryOperator::StrictEquality => {
-                let pair = self.commutative_pair(
-                    (&expr.left, &expr.right),
-                    |a| {
-                        if a.is_specific_string_literal("undefined") {
-                            return Some(());
-                        }
-                        None
-                    },
-                    |b| {
-                        if let Expression::UnaryExpression(op) = b {
-                            if op.operator == UnaryOperator::Typeof {
-                                if let Expression::Identifier(id) = &op.argument {
-                                    return Some((*id).clone());
-                                }
+                BinaryOperator::StrictEquality
+            }
+            BinaryOperator::Inequality | BinaryOperator::StrictInequality => {
+                BinaryOperator::StrictInequality
+            }
+            _ => return,
+        };
+        let pair = self.commutative_pair(
+            (&expr.left, &expr.right),
+            |a| {
+                if a.is_specific_string_literal("undefined") {
+                    return Some(());
+                }
+                None
+            },
+            |b| {
+                if let Expression::UnaryExpression(op) = b {
+                    if op.operator == UnaryOperator::Typeof {
+                        if let Expression::Identifier(id) = &op.argument {
+                            if !self.is_global_reference(id) {
+                                return Some((*id).clone());
                             }
                         }
-                        None
-                    },
-                );
-                if let Some((_void_exp, id_ref)) = pair {
-                    let span = expr.span;
-                    let left = self.ast.void_0();
-                    let operator = BinaryOperator::StrictEquality;
-                    let right = self.ast.identifier_reference_expression(id_ref);
-                    let cmp = BinaryExpression { span, left, operator, right };
-                    *expr = cmp;
+                    }
                 }
-            }
-            _ => {}
-        };
+                None
+            },
+        );
+        if let Some((_void_exp, id_ref)) = pair {
+            let span = expr.span;
+            let left = self.ast.void_0();
+            let right = self.ast.identifier_reference_expression(id_ref);
+            let cmp = BinaryExpression { span, left, operator, right };
+            *expr = cmp;
+        }
     }
 
     fn commutative_pair<A, F, G, RetF: 'a, RetG: 'a>(
@@ -267,7 +453,7 @@ impl<'a> Compressor<'a> {
     /// `return undefined` -> `return`
     /// `return void 0` -> `return`
     fn compress_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
-        if stmt.argument.as_ref().is_some_and(|expr| expr.is_undefined() || expr.is_void_0()) {
+        if stmt.argument.as_ref().is_some_and(|expr| self.is_undefined(expr)) {
             stmt.argument = None;
         }
     }
@@ -276,7 +462,7 @@ impl<'a> Compressor<'a> {
         if decl.kind.is_const() {
             return;
         }
-        if decl.init.as_ref().is_some_and(|init| init.is_undefined() || init.is_void_0()) {
+        if decl.init.as_ref().is_some_and(|init| self.is_undefined(init)) {
             decl.init = None;
         }
     }
@@ -309,11 +495,11 @@ impl<'a> Compressor<'a> {
 
 impl<'a> VisitMut<'a> for Compressor<'a> {
     fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
-        stmts.retain(|stmt| {
-            if self.drop_debugger(stmt) {
+        stmts.retain_mut(|stmt| {
+            if self.drop_debug_code(stmt) {
                 return false;
             }
-            if self.drop_console(stmt) {
+            if self.drop_unused_pure_call(stmt) {
                 return false;
             }
             true
@@ -322,13 +508,52 @@ impl<'a> VisitMut<'a> for Compressor<'a> {
         self.join_vars(stmts);
 
         walk_statements_mut(self, stmts);
+
+        self.remove_empty_statements(stmts);
+        self.fold_else_after_exit(stmts);
+        self.merge_exiting_if_statements(stmts);
+        self.join_sequences(stmts);
     }
 
     fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        let in_else_if = std::mem::take(&mut self.in_else_if);
+        self.drop_debug_statement(stmt);
         self.compress_block(stmt);
         self.compress_while(stmt);
         self.fold_condition(stmt);
+        self.fold_if_chain_to_switch(stmt);
         walk_statement_mut(self, stmt);
+        self.compress_statement_test(stmt);
+        self.remove_dead_branch(stmt);
+        self.remove_impossible_typeof_cases(stmt);
+        match stmt {
+            Statement::ForStatement(for_stmt) => self.fold_loop_body(&mut for_stmt.body),
+            Statement::ForInStatement(for_in_stmt) => self.fold_loop_body(&mut for_in_stmt.body),
+            Statement::ForOfStatement(for_of_stmt) => self.fold_loop_body(&mut for_of_stmt.body),
+            Statement::WhileStatement(while_stmt) => self.fold_loop_body(&mut while_stmt.body),
+            Statement::DoWhileStatement(do_stmt) => self.fold_loop_body(&mut do_stmt.body),
+            _ => {}
+        }
+        self.minimize_if_statement(stmt);
+        if !in_else_if {
+            self.fold_if_expression(stmt);
+        }
+        // Joining sequences may have reduced the block to a single statement.
+        self.compress_block(stmt);
+    }
+
+    fn visit_if_statement(&mut self, stmt: &mut IfStatement<'a>) {
+        self.visit_expression(&mut stmt.test);
+        self.visit_statement(&mut stmt.consequent);
+        if let Some(alternate) = &mut stmt.alternate {
+            self.in_else_if = matches!(alternate, Statement::IfStatement(_));
+            self.visit_statement(alternate);
+        }
+    }
+
+    fn visit_function_body(&mut self, body: &mut FunctionBody<'a>) {
+        walk_function_body_mut(self, body);
+        self.fold_function_body(body);
     }
 
     fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
@@ -345,9 +570,24 @@ impl<'a> VisitMut<'a> for Compressor<'a> {
     }
 
     fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        self.replace_node_env(expr);
+        self.replace_global_def(expr);
+        self.drop_console_call(expr);
+        self.fold_builtin_arithmetic(expr);
         walk_expression_mut(self, expr);
-        self.compress_console(expr);
+        self.collapse_iife(expr);
+        self.remove_dead_operand(expr);
+        self.hoist_conditional_assignment(expr);
+        self.fold_array_expression(expr);
+        #[cfg(feature = "regexp")]
+        self.fold_regexp_call(expr);
+        self.fold_boolean_call(expr);
+        self.fold_builtin_call(expr);
+        self.fold_bind_call(expr);
+        self.compress_boolean_operand(expr);
         self.fold_expression(expr);
+        self.fold_comparisons(expr);
+        self.fold_nullish_checks(expr);
         if !self.compress_undefined(expr) {
             self.compress_boolean(expr);
         }
@@ -357,4 +597,19 @@ impl<'a> VisitMut<'a> for Compressor<'a> {
         walk_binary_expression_mut(self, expr);
         self.compress_typeof_undefined(expr);
     }
+
+    fn visit_member_expression(&mut self, expr: &mut MemberExpression<'a>) {
+        walk_member_expression_mut(self, expr);
+        self.compress_member_expression(expr);
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        walk_object_property_mut(self, prop);
+        self.compress_object_property(prop);
+    }
+
+    fn visit_class_element(&mut self, elem: &mut ClassElement<'a>) {
+        walk_class_element_mut(self, elem);
+        self.compress_class_element(elem);
+    }
 }
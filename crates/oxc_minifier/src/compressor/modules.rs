@@ -0,0 +1,364 @@
+//! Module Declarations
+//!
+//! Rewrites the top-level import and export statements of a module into their shortest form.
+//!
+//! * `export { x as "x" }` -> `export { x }`
+//! * `function f() {} export default f` -> `export default function f() {}`
+//! * `import { a } from "m"; import { b } from "m"` -> `import { a, b } from "m"`
+//! * `export { a }; export { b }` -> `export { a, b }`
+//! * `export const a = 1; export const b = 2` -> `export const a = 1, b = 2`
+//!
+//! Module requests are evaluated in the order of their first appearance, and both imports and
+//! exports are hoisted, so merging a statement into an earlier one of the same kind does not
+//! change the semantics. Statements with import attributes and type-only statements are kept
+//! as is.
+
+use oxc_allocator::Vec;
+use oxc_ast::visit::walk::{
+    walk_assignment_target_property_identifier, walk_call_expression, walk_simple_assignment_target,
+};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, Visit};
+use oxc_span::{Atom, GetSpan};
+use oxc_syntax::identifier::is_identifier_name;
+use rustc_hash::FxHashMap;
+
+use super::{Compressor, SPAN};
+
+impl<'a> Compressor<'a> {
+    /// Shorten the import and export statements of a program.
+    /// Enabled by `compress.modules`
+    pub(crate) fn compress_module_declarations(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if !self.options.modules || !stmts.iter().any(Statement::is_module_declaration) {
+            return;
+        }
+        for stmt in stmts.iter_mut() {
+            Self::shorten_module_export_names(stmt);
+        }
+        self.inline_default_export(stmts);
+        self.merge_imports(stmts);
+        self.merge_exports(stmts);
+    }
+
+    /// `import { "a" as b } from "m"` -> `import { a as b } from "m"`
+    /// `export { x as "x" }` -> `export { x }`
+    fn shorten_module_export_names(stmt: &mut Statement<'a>) {
+        match stmt {
+            Statement::ImportDeclaration(decl) => {
+                let Some(specifiers) = &mut decl.specifiers else { return };
+                for specifier in specifiers.iter_mut() {
+                    if let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier {
+                        Self::shorten_module_export_name(&mut specifier.imported);
+                    }
+                }
+            }
+            Statement::ExportNamedDeclaration(decl) => {
+                for specifier in decl.specifiers.iter_mut() {
+                    Self::shorten_module_export_name(&mut specifier.local);
+                    Self::shorten_module_export_name(&mut specifier.exported);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn shorten_module_export_name(name: &mut ModuleExportName<'a>) {
+        if let ModuleExportName::StringLiteral(lit) = name {
+            if is_identifier_name(&lit.value) {
+                *name =
+                    ModuleExportName::Identifier(IdentifierName::new(lit.span, lit.value.clone()));
+            }
+        }
+    }
+
+    /// `function f() {} export default f` -> `export default function f() {}`
+    /// `class C {} export { C as default }` -> `export default class C {}`
+    ///
+    /// `export default function f() {}` exports the binding `f` instead of its current value,
+    /// so `f` must never be reassigned. Class declarations are only moved when they precede the
+    /// export, because they are not hoisted.
+    fn inline_default_export(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let Some((export_index, name)) = stmts.iter().enumerate().find_map(|(i, stmt)| {
+            Self::get_default_exported_name(stmt).map(|name| (i, name.clone()))
+        }) else {
+            return;
+        };
+
+        let Some(decl_index) = stmts.iter().position(|stmt| match stmt {
+            Statement::FunctionDeclaration(func) => {
+                func.body.is_some() && func.id.as_ref().is_some_and(|id| id.name == name)
+            }
+            Statement::ClassDeclaration(class) => {
+                !class.modifiers.is_contains_declare()
+                    && class.id.as_ref().is_some_and(|id| id.name == name)
+            }
+            _ => false,
+        }) else {
+            return;
+        };
+        if matches!(stmts[decl_index], Statement::ClassDeclaration(_)) && decl_index > export_index
+        {
+            return;
+        }
+
+        let mut finder = WriteFinder { name: &name, declarations: 0, written: false };
+        for stmt in stmts.iter() {
+            finder.visit_statement(stmt);
+        }
+        if finder.written || finder.declarations != 1 {
+            return;
+        }
+
+        // Drop the `export default f` statement, or the `f as default` specifier.
+        let removed = if let Statement::ExportNamedDeclaration(decl) = &mut stmts[export_index] {
+            decl.specifiers.retain(|specifier| specifier.exported.name() != "default");
+            decl.specifiers.is_empty()
+        } else {
+            true
+        };
+        let decl_index = if removed {
+            stmts.remove(export_index);
+            if decl_index > export_index {
+                decl_index - 1
+            } else {
+                decl_index
+            }
+        } else {
+            decl_index
+        };
+
+        let stmt = &mut stmts[decl_index];
+        let span = stmt.span();
+        let declaration = match self.ast.move_statement(stmt) {
+            Statement::FunctionDeclaration(func) => {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func)
+            }
+            Statement::ClassDeclaration(class) => {
+                ExportDefaultDeclarationKind::ClassDeclaration(class)
+            }
+            _ => unreachable!(),
+        };
+        let exported = ModuleExportName::Identifier(IdentifierName::new(SPAN, "default".into()));
+        *stmt = Statement::ExportDefaultDeclaration(self.ast.export_default_declaration(
+            span,
+            declaration,
+            exported,
+        ));
+    }
+
+    /// `f` in `export default f` or `export { f as default }`.
+    fn get_default_exported_name<'b>(stmt: &'b Statement<'a>) -> Option<&'b Atom<'a>> {
+        match stmt {
+            Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::Identifier(ident) => Some(&ident.name),
+                _ => None,
+            },
+            Statement::ExportNamedDeclaration(decl)
+                if decl.source.is_none() && decl.export_kind.is_value() =>
+            {
+                decl.specifiers
+                    .iter()
+                    .find(|specifier| {
+                        specifier.export_kind.is_value() && specifier.exported.name() == "default"
+                    })
+                    .map(|specifier| specifier.local.name())
+            }
+            _ => None,
+        }
+    }
+
+    /// `import { a } from "m"; import { b } from "m"` -> `import { a, b } from "m"`
+    /// `import "m"; import a from "m"` -> `import a from "m"`
+    fn merge_imports(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let is_mergeable = |decl: &ImportDeclaration<'a>| {
+            decl.import_kind.is_value() && decl.with_clause.is_none()
+        };
+        if stmts
+            .iter()
+            .filter(|stmt| matches!(stmt, Statement::ImportDeclaration(decl) if is_mergeable(decl)))
+            .nth(1)
+            .is_none()
+        {
+            return;
+        }
+
+        // Index of the first import of each source in `new_stmts`.
+        let mut first_imports: FxHashMap<Atom<'a>, usize> = FxHashMap::default();
+        let mut new_stmts = self.ast.new_vec_with_capacity(stmts.len());
+        for stmt in stmts.drain(..) {
+            let Statement::ImportDeclaration(mut decl) = stmt else {
+                new_stmts.push(stmt);
+                continue;
+            };
+            if is_mergeable(&decl) {
+                if let Some(&index) = first_imports.get(&decl.source.value) {
+                    let Statement::ImportDeclaration(first) = &mut new_stmts[index] else {
+                        unreachable!()
+                    };
+                    if Self::merge_import(first, &mut decl) {
+                        continue;
+                    }
+                } else {
+                    first_imports.insert(decl.source.value.clone(), new_stmts.len());
+                }
+            }
+            new_stmts.push(Statement::ImportDeclaration(decl));
+        }
+        *stmts = new_stmts;
+    }
+
+    /// Returns `false` if the specifiers cannot be combined into a single import,
+    /// e.g. `import * as a from "m"` and `import { b } from "m"`.
+    fn merge_import(first: &mut ImportDeclaration<'a>, other: &mut ImportDeclaration<'a>) -> bool {
+        let Some(other_specifiers) = &mut other.specifiers else {
+            // The module is already imported.
+            return true;
+        };
+        let Some(first_specifiers) = &mut first.specifiers else {
+            first.specifiers = other.specifiers.take();
+            return true;
+        };
+
+        let (mut defaults, mut namespaces, mut named) = (0, 0, 0);
+        for specifier in first_specifiers.iter().chain(other_specifiers.iter()) {
+            match specifier {
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => defaults += 1,
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => namespaces += 1,
+                ImportDeclarationSpecifier::ImportSpecifier(_) => named += 1,
+            }
+        }
+        if defaults > 1 || namespaces > 1 || (namespaces == 1 && named > 0) {
+            return false;
+        }
+
+        first_specifiers.append(other_specifiers);
+        // `import a, * as b` and `import a, { b }`: the default import comes first.
+        first_specifiers.sort_by_key(|specifier| match specifier {
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => 0,
+            ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => 1,
+            ImportDeclarationSpecifier::ImportSpecifier(_) => 2,
+        });
+        true
+    }
+
+    /// `export { a }; export { b }` -> `export { a, b }`
+    /// `export { a } from "m"; export { b } from "m"` -> `export { a, b } from "m"`
+    /// `export let a; export let b` -> `export let a, b`
+    fn merge_exports(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if stmts
+            .iter()
+            .filter(|stmt| matches!(stmt, Statement::ExportNamedDeclaration(_)))
+            .nth(1)
+            .is_none()
+        {
+            return;
+        }
+
+        // Index of the first `export { .. }` in `new_stmts`.
+        let mut first_local_export = None;
+        // Index of the first `export { .. } from` of each source in `new_stmts`.
+        let mut first_re_exports: FxHashMap<Atom<'a>, usize> = FxHashMap::default();
+        let mut new_stmts = self.ast.new_vec_with_capacity(stmts.len());
+        for stmt in stmts.drain(..) {
+            let Statement::ExportNamedDeclaration(mut decl) = stmt else {
+                new_stmts.push(stmt);
+                continue;
+            };
+            if !decl.export_kind.is_value() || decl.with_clause.is_some() {
+                new_stmts.push(Statement::ExportNamedDeclaration(decl));
+                continue;
+            }
+
+            let first_index = match (&decl.declaration, &decl.source) {
+                (None, None) => {
+                    let index = first_local_export;
+                    if index.is_none() {
+                        first_local_export = Some(new_stmts.len());
+                    }
+                    index
+                }
+                (None, Some(source)) => {
+                    let index = first_re_exports.get(&source.value).copied();
+                    if index.is_none() {
+                        first_re_exports.insert(source.value.clone(), new_stmts.len());
+                    }
+                    index
+                }
+                (Some(Declaration::VariableDeclaration(var_decl)), _) => {
+                    if let Some(Statement::ExportNamedDeclaration(prev)) = new_stmts.last_mut() {
+                        let prev = &mut **prev;
+                        if let Some(Declaration::VariableDeclaration(prev_decl)) =
+                            &mut prev.declaration
+                        {
+                            if prev.export_kind.is_value()
+                                && prev_decl.kind == var_decl.kind
+                                && !prev_decl.modifiers.is_contains_declare()
+                                && !var_decl.modifiers.is_contains_declare()
+                            {
+                                let Some(Declaration::VariableDeclaration(var_decl)) =
+                                    &mut decl.declaration
+                                else {
+                                    unreachable!()
+                                };
+                                prev_decl.declarations.append(&mut var_decl.declarations);
+                                continue;
+                            }
+                        }
+                    }
+                    None
+                }
+                _ => None,
+            };
+
+            if let Some(index) = first_index {
+                let Statement::ExportNamedDeclaration(first) = &mut new_stmts[index] else {
+                    unreachable!()
+                };
+                first.specifiers.append(&mut decl.specifiers);
+                continue;
+            }
+            new_stmts.push(Statement::ExportNamedDeclaration(decl));
+        }
+        *stmts = new_stmts;
+    }
+}
+
+/// Counts the declarations of `name` and finds any assignment to it.
+struct WriteFinder<'n> {
+    name: &'n str,
+    declarations: usize,
+    written: bool,
+}
+
+impl<'a, 'n> Visit<'a> for WriteFinder<'n> {
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        if ident.name == self.name {
+            self.declarations += 1;
+        }
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = target {
+            self.written |= ident.name == self.name;
+        }
+        walk_simple_assignment_target(self, target);
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        ident: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        self.written |= ident.binding.name == self.name;
+        walk_assignment_target_property_identifier(self, ident);
+    }
+
+    fn visit_call_expression(&mut self, expr: &CallExpression<'a>) {
+        // Direct `eval` can assign to any binding.
+        self.written |= expr.callee.is_specific_id("eval");
+        walk_call_expression(self, expr);
+    }
+
+    fn visit_with_statement(&mut self, _stmt: &WithStatement<'a>) {
+        self.written = true;
+    }
+}
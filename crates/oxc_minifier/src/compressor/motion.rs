@@ -0,0 +1,350 @@
+//! Expression Motion
+//!
+//! Decides whether two expressions can be evaluated in the opposite order, for the passes which
+//! move an expression across another one: destructuring binds values out of the order of the
+//! literal, and the hoisting of an assignment out of a conditional evaluates its target before the
+//! test. Passes which only drop expressions or join them in order check
+//! [`MayHaveSideEffects`](super::ast_util::MayHaveSideEffects) instead.
+//!
+//! The [`Effects`] of an expression summarize what its evaluation may observe or change:
+//!
+//! * the variables it reads and writes,
+//! * whether it may run arbitrary code, i.e. call a function, construct an object, spread an
+//!   iterable, or get, set or delete a property, which may run a getter, setter or proxy trap,
+//! * whether it may suspend with `await` or `yield`, which lets arbitrary code run while it is
+//!   suspended, and must stay in its function.
+//!
+//! Variables are compared by name, as an expression declares no bindings outside of the nested
+//! functions and classes. Code in nested functions is not evaluated with the expression, so a
+//! function expression has no effects. Like the rest of the compressor, conversions of operands to primitives are assumed not
+//! to run arbitrary code, and reads of variables are assumed not to throw.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{
+    ast::*,
+    visit::walk::{
+        walk_assignment_expression, walk_await_expression, walk_call_expression,
+        walk_import_expression, walk_member_expression, walk_new_expression, walk_spread_element,
+        walk_tagged_template_expression, walk_unary_expression, walk_update_expression,
+        walk_yield_expression,
+    },
+    Visit,
+};
+use oxc_semantic::ScopeFlags;
+use oxc_span::Atom;
+use oxc_syntax::operator::UnaryOperator;
+
+/// What the evaluation of an expression may observe or change.
+#[derive(Debug, Default)]
+pub struct Effects<'a> {
+    reads: Vec<Atom<'a>>,
+    writes: Vec<Atom<'a>>,
+    /// Calls, getters, setters and other code which may read and write anything.
+    runs_code: bool,
+    /// `await` or `yield` outside of nested functions, so the expression must also stay in its
+    /// function.
+    suspends: bool,
+}
+
+impl<'a> Effects<'a> {
+    pub fn of(expr: &Expression<'a>) -> Self {
+        let mut effects = Self::default();
+        effects.visit_expression(expr);
+        effects
+    }
+
+    /// What evaluating `target` before the assigned value may observe or change: the object and
+    /// the computed key of a member target, and the target itself when `reads_target`, as in
+    /// `x += 1`.
+    pub fn of_assignment_target(target: &AssignmentTarget<'a>, reads_target: bool) -> Self {
+        let mut effects = Self::default();
+        if reads_target {
+            effects.visit_assignment_target(target);
+        } else if let Some(member) = target.as_member_expression() {
+            effects.visit_expression(member.object());
+            if let MemberExpression::ComputedMemberExpression(member) = member {
+                effects.visit_expression(&member.expression);
+            }
+        }
+        effects
+    }
+
+    /// The value of the expression is the same whenever it is evaluated, and evaluating it changes
+    /// nothing, e.g. a literal or a function expression.
+    pub fn is_constant(&self) -> bool {
+        self.reads.is_empty() && !self.has_side_effects()
+    }
+
+    pub fn has_side_effects(&self) -> bool {
+        !self.writes.is_empty() || self.runs_code || self.suspends
+    }
+
+    /// Whether evaluating the expressions of `self` and `other` in either order has the same
+    /// result.
+    pub fn can_reorder(&self, other: &Self) -> bool {
+        // A suspension lets other code run, which may observe or change anything.
+        if self.runs_code || self.suspends {
+            return other.is_constant();
+        }
+        if other.runs_code || other.suspends {
+            return self.is_constant();
+        }
+        !self.writes.iter().any(|name| other.reads.contains(name) || other.writes.contains(name))
+            && !other.writes.iter().any(|name| self.reads.contains(name))
+    }
+
+    fn write(&mut self, target: &AssignmentTarget<'a>) {
+        match target {
+            AssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                self.writes.push(ident.name.clone());
+            }
+            // A setter, or the iterator and getters of a destructured value.
+            _ => self.runs_code = true,
+        }
+    }
+}
+
+impl<'a> Visit<'a> for Effects<'a> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.reads.push(ident.name.clone());
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        // The target of `x = 1` is visited as a read of `x` as well, which only conflicts with
+        // writes of `x`, as the write does already.
+        self.write(&expr.left);
+        walk_assignment_expression(self, expr);
+    }
+
+    fn visit_update_expression(&mut self, expr: &UpdateExpression<'a>) {
+        match &expr.argument {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                self.writes.push(ident.name.clone());
+            }
+            _ => self.runs_code = true,
+        }
+        walk_update_expression(self, expr);
+    }
+
+    fn visit_unary_expression(&mut self, expr: &UnaryExpression<'a>) {
+        if expr.operator == UnaryOperator::Delete {
+            self.runs_code = true;
+        }
+        walk_unary_expression(self, expr);
+    }
+
+    fn visit_member_expression(&mut self, expr: &MemberExpression<'a>) {
+        self.runs_code = true;
+        walk_member_expression(self, expr);
+    }
+
+    fn visit_call_expression(&mut self, expr: &CallExpression<'a>) {
+        self.runs_code = true;
+        walk_call_expression(self, expr);
+    }
+
+    fn visit_new_expression(&mut self, expr: &NewExpression<'a>) {
+        self.runs_code = true;
+        walk_new_expression(self, expr);
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &TaggedTemplateExpression<'a>) {
+        self.runs_code = true;
+        walk_tagged_template_expression(self, expr);
+    }
+
+    fn visit_import_expression(&mut self, expr: &ImportExpression<'a>) {
+        self.runs_code = true;
+        walk_import_expression(self, expr);
+    }
+
+    fn visit_spread_element(&mut self, elem: &SpreadElement<'a>) {
+        self.runs_code = true;
+        walk_spread_element(self, elem);
+    }
+
+    fn visit_await_expression(&mut self, expr: &AwaitExpression<'a>) {
+        self.suspends = true;
+        walk_await_expression(self, expr);
+    }
+
+    fn visit_yield_expression(&mut self, expr: &YieldExpression<'a>) {
+        self.suspends = true;
+        walk_yield_expression(self, expr);
+    }
+
+    fn visit_jsx_element(&mut self, _elem: &JSXElement<'a>) {
+        self.runs_code = true;
+    }
+
+    fn visit_jsx_fragment(&mut self, _elem: &JSXFragment<'a>) {
+        self.runs_code = true;
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {}
+
+    /// Only the heritage, computed keys and static initializers of a class are evaluated with it.
+    fn visit_class(&mut self, class: &Class<'a>) {
+        let runs_code = class.super_class.is_some()
+            || class.body.body.iter().any(|element| match element {
+                ClassElement::StaticBlock(_) => true,
+                ClassElement::MethodDefinition(def) => def.computed,
+                ClassElement::PropertyDefinition(def) => {
+                    def.computed || (def.r#static && def.value.is_some())
+                }
+                ClassElement::AccessorProperty(def) => {
+                    def.computed || (def.r#static && def.value.is_some())
+                }
+                ClassElement::TSIndexSignature(_) => false,
+            });
+        self.runs_code |= runs_code;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::Statement;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::Effects;
+
+    /// The kinds of expressions in the matrix, with examples.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Kind {
+        Constant,
+        ReadX,
+        ReadY,
+        WriteX,
+        WriteY,
+        /// Reads `y` and writes `x`.
+        CopyYToX,
+        RunsCode,
+        Suspends,
+    }
+
+    const EXAMPLES: &[(Kind, &[&str])] = &[
+        (
+            Kind::Constant,
+            &[
+                "1",
+                "'a' + `b`",
+                "!0",
+                "[1, [2]]",
+                "({ a: 1, get b() { return x } })",
+                "() => x = 1",
+                "async function () { await f() }",
+                "function* () { yield x }",
+                "class { f() { x = 1 } }",
+                "this",
+            ],
+        ),
+        (Kind::ReadX, &["x", "x + 1", "typeof x", "[x]", "{ a: x }", "`${x}`", "x ? 1 : 2"]),
+        (Kind::ReadY, &["y", "-y", "y || 0"]),
+        (Kind::WriteX, &["x = 1", "x++", "--x", "x += 1", "x ||= 1", "(x = 1, x)"]),
+        (Kind::WriteY, &["y = 2", "y--"]),
+        (Kind::CopyYToX, &["x = y", "x += y"]),
+        (
+            Kind::RunsCode,
+            &[
+                "f()",
+                "new F()",
+                "o.p",
+                "o[p]",
+                "o?.p",
+                "o.p = 1",
+                "o.p++",
+                "delete o.p",
+                "[...a]",
+                "({ ...o })",
+                "[x] = a",
+                "({ x } = o)",
+                "t``",
+                "import('m')",
+                "class extends B {}",
+                "class { static a = 1 }",
+                "class { [k]() {} }",
+                "class { static {} }",
+            ],
+        ),
+        (Kind::Suspends, &["await p", "yield", "yield x", "await 1", "(await p, 1)"]),
+    ];
+
+    /// Whether expressions of the two kinds can be reordered, listed exhaustively.
+    #[allow(clippy::match_same_arms)]
+    fn expected(a: Kind, b: Kind) -> bool {
+        use Kind as K;
+        match (a, b) {
+            (K::Constant, _) | (_, K::Constant) => true,
+            (K::RunsCode | K::Suspends, _) | (_, K::RunsCode | K::Suspends) => false,
+            (K::ReadX | K::ReadY, K::ReadX | K::ReadY)
+            | (K::ReadX | K::WriteX, K::WriteY)
+            | (K::WriteY, K::ReadX | K::WriteX)
+            | (K::ReadY, K::WriteX | K::CopyYToX)
+            | (K::WriteX | K::CopyYToX, K::ReadY) => true,
+            (K::ReadX | K::WriteX | K::CopyYToX, K::WriteX | K::CopyYToX)
+            | (K::WriteX | K::CopyYToX, K::ReadX)
+            | (K::ReadY | K::CopyYToX, K::WriteY)
+            | (K::WriteY, K::ReadY | K::WriteY | K::CopyYToX) => false,
+        }
+    }
+
+    fn with_effects(source_text: &str, f: impl FnOnce(&Effects)) {
+        let allocator = Allocator::default();
+        let source_text = format!("async function* g() {{ ({source_text}); }}");
+        let source_type = SourceType::default().with_module(true);
+        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        assert!(ret.errors.is_empty(), "{source_text}: {:?}", ret.errors);
+        let Some(Statement::FunctionDeclaration(func)) = ret.program.body.first() else {
+            unreachable!()
+        };
+        let body = func.body.as_ref().unwrap();
+        let Some(Statement::ExpressionStatement(stmt)) = body.statements.first() else {
+            unreachable!()
+        };
+        f(&Effects::of(&stmt.expression));
+    }
+
+    #[test]
+    fn kinds() {
+        for (kind, examples) in EXAMPLES {
+            for example in *examples {
+                with_effects(example, |effects| {
+                    assert_eq!(effects.is_constant(), *kind == Kind::Constant, "{example}");
+                    assert_eq!(
+                        effects.has_side_effects(),
+                        !matches!(kind, Kind::Constant | Kind::ReadX | Kind::ReadY),
+                        "{example}"
+                    );
+                    assert_eq!(effects.suspends, *kind == Kind::Suspends, "{example}");
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn matrix() {
+        for (a_kind, a_examples) in EXAMPLES {
+            for (b_kind, b_examples) in EXAMPLES {
+                let reorderable = expected(*a_kind, *b_kind);
+                assert_eq!(reorderable, expected(*b_kind, *a_kind));
+                for a in *a_examples {
+                    with_effects(a, |a_effects| {
+                        for b in *b_examples {
+                            with_effects(b, |b_effects| {
+                                assert_eq!(
+                                    a_effects.can_reorder(b_effects),
+                                    reorderable,
+                                    "`{a}` ({a_kind:?}) and `{b}` ({b_kind:?})"
+                                );
+                            });
+                        }
+                    });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,310 @@
+//! Narrowing of `var` Declarations
+//!
+//! Declares the `var`s which are only used inside of one block with `let` in that block:
+//!
+//! ```javascript
+//! function f() {
+//!     var x;
+//!     if (a) { x = g(); h(x) }
+//!     if (b) { var y = g(); h(y) }
+//! }
+//! ```
+//!
+//! is compressed to `function f() { if (a) { let x = g(); h(x) } if (b) { let y = g(); h(y) } }`.
+//! Moving `var x;` into the block declares `x` with its first assignment and saves a statement,
+//! while `var y` converted in place keeps its size. Either way the scopes of `x` and `y` no longer
+//! overlap, so the mangler can give both the same short name.
+//!
+//! `let` requires an ES2015 target. A `var` is only narrowed when the block scoped binding behaves
+//! the same:
+//!
+//! * The `var` is the only declaration of the name, and binds an identifier.
+//! * No loop or function is between the block and the function which declares the `var`. A `let`
+//!   in a loop is a new binding in every iteration, and in a function a new binding in every
+//!   call, which closures created along the way would observe.
+//! * Every reference comes after the declaration in source order, or after the first assignment
+//!   for a moved `var x;`, so that no reference is in the temporal dead zone of the `let`.
+//! * No reference is inside of a function declaration in the block, which is hoisted and may be
+//!   called before the `let` is initialized.
+//! * Top-level `var`s of a script are global bindings, so they are kept.
+
+use oxc_allocator::{Allocator, Box, Vec};
+use oxc_ast::visit::walk_mut::{walk_statements_mut, walk_variable_declaration_mut};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder, AstKind, VisitMut};
+use oxc_semantic::{
+    AstNode, AstNodeId, Reference, ReferenceId, Semantic, SemanticBuilder, SymbolFlags, SymbolId,
+};
+use oxc_span::Span;
+use oxc_syntax::operator::AssignmentOperator;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+pub struct NarrowVars<'a> {
+    ast: AstBuilder<'a>,
+    /// `var`s converted to `let` in place.
+    converted: FxHashSet<SymbolId>,
+    /// `var x;` declarations which are removed, as `x` is declared by its first assignment.
+    moved: FxHashSet<SymbolId>,
+    /// The first assignments of the moved `var`s, which become `let` declarations.
+    assignments: FxHashSet<ReferenceId>,
+}
+
+enum Narrowing {
+    /// Convert the `var` declaration, the given statement, to `let` in place.
+    InPlace(AstNodeId),
+    /// Remove `var x;` and declare `x` with its first assignment, the given reference.
+    Moved(ReferenceId),
+}
+
+impl<'a> NarrowVars<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            converted: FxHashSet::default(),
+            moved: FxHashSet::default(),
+            assignments: FxHashSet::default(),
+        }
+    }
+
+    pub fn build(mut self, program: &mut Program<'a>) {
+        let semantic = SemanticBuilder::new("", program.source_type).build(program).semantic;
+        let mut in_place = FxHashMap::<AstNodeId, std::vec::Vec<SymbolId>>::default();
+        for symbol_id in semantic.symbols().iter() {
+            match narrowing(&semantic, symbol_id) {
+                Some(Narrowing::InPlace(decl_id)) => {
+                    in_place.entry(decl_id).or_default().push(symbol_id);
+                }
+                Some(Narrowing::Moved(reference_id)) => {
+                    self.moved.insert(symbol_id);
+                    self.assignments.insert(reference_id);
+                }
+                None => {}
+            }
+        }
+        // A declaration is only converted when all of its declarators can be.
+        for (decl_id, symbol_ids) in in_place {
+            if let AstKind::VariableDeclaration(decl) = semantic.nodes().kind(decl_id) {
+                if decl.declarations.len() == symbol_ids.len() {
+                    self.converted.extend(symbol_ids);
+                }
+            }
+        }
+        if self.converted.is_empty() && self.moved.is_empty() {
+            return;
+        }
+        self.visit_program(program);
+    }
+
+    fn declares(set: &FxHashSet<SymbolId>, declarator: &VariableDeclarator<'a>) -> bool {
+        matches!(
+            &declarator.id.kind,
+            BindingPatternKind::BindingIdentifier(ident)
+                if ident.symbol_id.get().is_some_and(|id| set.contains(&id))
+        )
+    }
+
+    /// `let x = value` for the first assignment `x = value` of a moved `var x;`.
+    fn declare_assignment(
+        &self,
+        stmt: &mut Statement<'a>,
+    ) -> Option<Box<'a, VariableDeclaration<'a>>> {
+        let Statement::ExpressionStatement(expr_stmt) = stmt else { return None };
+        let Expression::AssignmentExpression(assign) = &expr_stmt.expression else { return None };
+        let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+            return None;
+        };
+        if !ident.reference_id.get().is_some_and(|id| self.assignments.contains(&id)) {
+            return None;
+        }
+        let Expression::AssignmentExpression(assign) =
+            self.ast.move_expression(&mut expr_stmt.expression)
+        else {
+            unreachable!()
+        };
+        let assign = assign.unbox();
+        let AssignmentTarget::AssignmentTargetIdentifier(ident) = assign.left else {
+            unreachable!()
+        };
+        let id = BindingIdentifier::new(ident.span, ident.name.clone());
+        let declarator = self.ast.variable_declarator(
+            assign.span,
+            VariableDeclarationKind::Let,
+            self.ast.binding_pattern(self.ast.binding_pattern_identifier(id), None, false),
+            Some(assign.right),
+            false,
+        );
+        Some(self.ast.variable_declaration(
+            expr_stmt.span,
+            VariableDeclarationKind::Let,
+            self.ast.new_vec_single(declarator),
+            Modifiers::empty(),
+        ))
+    }
+}
+
+impl<'a> VisitMut<'a> for NarrowVars<'a> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        walk_statements_mut(self, stmts);
+        stmts.retain(|stmt| {
+            !matches!(stmt, Statement::VariableDeclaration(decl)
+                if decl.declarations.iter().any(|declarator| Self::declares(&self.moved, declarator)))
+        });
+        for stmt in stmts.iter_mut() {
+            if let Some(decl) = self.declare_assignment(stmt) {
+                *stmt = Statement::VariableDeclaration(decl);
+            }
+        }
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        if decl.declarations.iter().any(|declarator| Self::declares(&self.converted, declarator)) {
+            decl.kind = VariableDeclarationKind::Let;
+            for declarator in decl.declarations.iter_mut() {
+                declarator.kind = VariableDeclarationKind::Let;
+            }
+        }
+        walk_variable_declaration_mut(self, decl);
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// How the `var` declaration of `symbol_id` can be narrowed, if it can be.
+fn narrowing(semantic: &Semantic, symbol_id: SymbolId) -> Option<Narrowing> {
+    let symbols = semantic.symbols();
+    let nodes = semantic.nodes();
+
+    if symbols.get_flag(symbol_id).bits() != SymbolFlags::FunctionScopedVariable.bits()
+        || !symbols.get_redeclare_variables(symbol_id).is_empty()
+    {
+        return None;
+    }
+    let declarator_id = symbols.get_declaration(symbol_id);
+    let AstKind::VariableDeclarator(declarator) = nodes.kind(declarator_id) else { return None };
+    if !declarator.kind.is_var() || !declarator.id.kind.is_binding_identifier() {
+        return None;
+    }
+    let decl_id = nodes.parent_id(declarator_id)?;
+    let AstKind::VariableDeclaration(decl) = nodes.kind(decl_id) else { return None };
+    if decl.modifiers.is_contains_declare() {
+        return None;
+    }
+
+    let mut references = symbols
+        .get_resolved_reference_ids(symbol_id)
+        .map(|reference_id| (reference_id, symbols.get_reference(reference_id)))
+        .collect::<std::vec::Vec<_>>();
+    references.sort_unstable_by_key(|(_, reference)| reference.span().start);
+    let &(first_id, first) = references.first()?;
+    let references =
+        references.into_iter().map(|(_, reference)| reference).collect::<std::vec::Vec<_>>();
+    let in_block = |block: &BlockStatement| {
+        references.iter().all(|reference| contains(block.span, reference.span()))
+    };
+
+    // A standalone `var x;` moves into the innermost block which contains every reference, when
+    // the block starts with assigning `x`.
+    let standalone = decl.declarations.len() == 1
+        && declarator.init.is_none()
+        && matches!(
+            nodes.parent_kind(decl_id),
+            Some(
+                AstKind::BlockStatement(_)
+                    | AstKind::FunctionBody(_)
+                    | AstKind::SwitchCase(_)
+                    | AstKind::Program(_)
+            )
+        );
+    if standalone {
+        let block = nodes.iter_parents(first.node_id()).find(
+            |node| matches!(node.kind(), AstKind::BlockStatement(block) if in_block(block)),
+        )?;
+        let stmt = nodes
+            .iter_parents(first.node_id())
+            .find(|node| matches!(node.kind(), AstKind::ExpressionStatement(_)))?;
+        let AstKind::ExpressionStatement(expr_stmt) = stmt.kind() else { return None };
+        let is_first_assignment = nodes.parent_id(stmt.id()) == Some(block.id())
+            && matches!(&expr_stmt.expression, Expression::AssignmentExpression(assign)
+                if assign.operator == AssignmentOperator::Assign
+                    && matches!(&assign.left, AssignmentTarget::AssignmentTargetIdentifier(ident)
+                        if ident.span == first.span()))
+            && references[1..].iter().all(|reference| reference.span().start >= expr_stmt.span.end);
+        if is_first_assignment && is_narrowable(semantic, symbol_id, block, &references) {
+            return Some(Narrowing::Moved(first_id));
+        }
+    }
+
+    // Any other `var` is converted in place, in the block which contains it.
+    let block = nodes.parent_node(decl_id)?;
+    let AstKind::BlockStatement(block_stmt) = block.kind() else { return None };
+    (in_block(block_stmt)
+        && references.iter().all(|reference| reference.span().start >= declarator.span.end)
+        && is_narrowable(semantic, symbol_id, block, &references))
+    .then_some(Narrowing::InPlace(decl_id))
+}
+
+/// Whether the `var` of `symbol_id` can be declared with `let` in `block`, which contains all of
+/// its `references` after the declaration.
+fn is_narrowable(
+    semantic: &Semantic,
+    symbol_id: SymbolId,
+    block: &AstNode,
+    references: &[&Reference],
+) -> bool {
+    let symbols = semantic.symbols();
+    let scopes = semantic.scopes();
+    let nodes = semantic.nodes();
+    let AstKind::BlockStatement(block_stmt) = block.kind() else { return false };
+
+    // The `var` is declared in the scope of its block, and also bound in every scope up to the
+    // function which declares it.
+    let Some(var_scope_id) = scopes
+        .ancestors(symbols.get_scope_id(symbol_id))
+        .find(|&scope_id| scopes.get_flags(scope_id).is_var())
+    else {
+        return false;
+    };
+
+    // A `var` in a catch clause may also assign its parameter of the same name.
+    let name = symbols.get_name(symbol_id);
+    let Some(block_scope_id) = block_stmt.scope_id.get() else { return false };
+    if scopes
+        .ancestors(block_scope_id)
+        .take_while(|&scope_id| scope_id != var_scope_id)
+        .any(|scope_id| scopes.get_binding(scope_id, name).is_some_and(|id| id != symbol_id))
+    {
+        return false;
+    }
+
+    // The block must be in the function which declares the `var`, outside of any loop.
+    let mut in_declaring_function = false;
+    for ancestor in nodes.iter_parents(block.id()).skip(1) {
+        let scope_id = match ancestor.kind() {
+            AstKind::Function(func) => func.scope_id.get(),
+            AstKind::ArrowFunctionExpression(arrow) => arrow.scope_id.get(),
+            AstKind::StaticBlock(block) => block.scope_id.get(),
+            // Top-level `var`s of a script are properties of the global object.
+            AstKind::Program(program) if program.source_type.is_module() => {
+                Some(scopes.root_scope_id())
+            }
+            kind if kind.is_iteration_statement() => return false,
+            AstKind::Program(_) | AstKind::TSModuleBlock(_) => return false,
+            _ => continue,
+        };
+        in_declaring_function = scope_id == Some(var_scope_id);
+        break;
+    }
+    if !in_declaring_function {
+        return false;
+    }
+
+    // A function declaration in the block is hoisted, and may read the binding before the `let`.
+    references.iter().all(|reference| {
+        !nodes
+            .iter_parents(reference.node_id())
+            .take_while(|node| node.id() != block.id())
+            .any(|node| matches!(node.kind(), AstKind::Function(func) if func.is_declaration()))
+    })
+}
@@ -0,0 +1,57 @@
+//! Environment Substitution
+//!
+//! Replaces `process.env.NODE_ENV` with the string given by `compress.node_env`, before the
+//! enclosing expressions are folded, so that
+//!
+//! ```javascript
+//! if (process.env.NODE_ENV !== "production") devWarn();
+//! ```
+//!
+//! becomes `if (false) devWarn()` in a production build, which `compress.dead_code` removes.
+//! Assignments to `process.env.NODE_ENV` and references to a `process` binding which shadows
+//! the global are kept.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_span::GetSpan;
+
+use super::Compressor;
+
+impl<'a> Compressor<'a> {
+    /// Enabled by `compress.node_env`
+    ///
+    /// `process.env.NODE_ENV` -> `"production"`
+    pub(crate) fn replace_node_env(&mut self, expr: &mut Expression<'a>) {
+        let Some(node_env) = self.options.node_env else { return };
+        if !self.is_node_env(expr) {
+            return;
+        }
+        let span = expr.span();
+        *expr = self.ast.literal_string_expression(self.ast.string_literal(span, node_env));
+    }
+
+    /// `process.env.NODE_ENV` or `process.env["NODE_ENV"]`
+    fn is_node_env(&self, expr: &Expression<'a>) -> bool {
+        let (object, property) = match expr {
+            Expression::StaticMemberExpression(member) if !member.optional => {
+                (&member.object, member.property.name.as_str())
+            }
+            Expression::ComputedMemberExpression(member) if !member.optional => {
+                match &member.expression {
+                    Expression::StringLiteral(lit) => (&member.object, lit.value.as_str()),
+                    _ => return false,
+                }
+            }
+            _ => return false,
+        };
+        if property != "NODE_ENV" {
+            return false;
+        }
+        let Expression::StaticMemberExpression(env) = object else { return false };
+        if env.property.name != "env" || env.optional {
+            return false;
+        }
+        let Expression::Identifier(process) = &env.object else { return false };
+        process.name == "process" && self.is_global_reference(process)
+    }
+}
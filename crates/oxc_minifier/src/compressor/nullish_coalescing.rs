@@ -0,0 +1,250 @@
+//! Nullish Coalescing
+//!
+//! Recovers `??` from the conditional expressions which older compilers lower it to, which
+//! requires an ES2020 target:
+//!
+//! * `x == null ? d : x` and `x != null ? x : d` -> `x ?? d`
+//! * `x === null || x === void 0 ? d : x` and `x !== null && x !== void 0 ? x : d` -> `x ?? d`,
+//!   with the comparisons in either order and `undefined` for `void 0`
+//! * `(_a = e) !== null && _a !== void 0 ? _a : d`, as emitted by TypeScript and Babel,
+//!   -> `e ?? d` when the temporary `_a` is not used elsewhere, and its `var _a;` is removed,
+//!   otherwise -> `(_a = e) ?? d`
+//!
+//! `x` must be a local binding, which is read once instead of twice. `x === void 0 ? d : x` alone
+//! is not `x ?? d`, which also replaces `null`. `document.all` is the only value which is
+//! `== null` without being `null` or `undefined`, and is ignored.
+//!
+//! The top-level `var`s of a script are properties of the global object, so they are never
+//! removed as temporaries.
+
+use oxc_allocator::{Allocator, Vec};
+use oxc_ast::visit::walk_mut::{walk_expression_mut, walk_statements_mut};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder, AstKind, VisitMut};
+use oxc_semantic::{Semantic, SemanticBuilder, SymbolFlags, SymbolId, SymbolTable};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator};
+use rustc_hash::FxHashSet;
+
+pub struct NullishCoalescing<'a> {
+    ast: AstBuilder<'a>,
+    symbols: SymbolTable,
+    /// The `var`s declared without a value, which may be the temporaries of a lowered `??`.
+    temporaries: FxHashSet<SymbolId>,
+    /// The temporaries which are no longer used, whose declarators are removed.
+    removed: FxHashSet<SymbolId>,
+}
+
+/// A test of a conditional expression whether a binding is `null` or `undefined`.
+struct NullishTest {
+    symbol_id: SymbolId,
+    /// The binding is assigned by the test, `(_a = e) == null`.
+    assigned: bool,
+    /// The test is true when the binding is not nullish, `x != null`.
+    negated: bool,
+    /// The number of references to the binding in the test.
+    references: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Nullish {
+    Null,
+    Undefined,
+}
+
+impl<'a> NullishCoalescing<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            symbols: SymbolTable::default(),
+            temporaries: FxHashSet::default(),
+            removed: FxHashSet::default(),
+        }
+    }
+
+    pub fn build(mut self, program: &mut Program<'a>) {
+        let semantic = SemanticBuilder::new("", program.source_type).build(program).semantic;
+        self.temporaries = semantic
+            .symbols()
+            .iter()
+            .filter(|&symbol_id| is_temporary(&semantic, symbol_id))
+            .collect();
+        self.symbols = semantic.into_symbol_table_and_scope_tree().0;
+        self.visit_program(program);
+    }
+
+    fn symbol_of(&self, ident: &IdentifierReference<'a>) -> Option<SymbolId> {
+        ident.reference_id.get().and_then(|id| self.symbols.get_reference(id).symbol_id())
+    }
+
+    /// The local binding read by `expr`.
+    fn binding(&self, expr: &Expression<'a>) -> Option<SymbolId> {
+        match expr.without_parenthesized() {
+            Expression::Identifier(ident) => self.symbol_of(ident),
+            _ => None,
+        }
+    }
+
+    /// The local binding read by `expr`, or assigned by `(_a = e)`, and whether it is assigned.
+    fn subject(&self, expr: &Expression<'a>) -> Option<(SymbolId, bool)> {
+        if let Expression::AssignmentExpression(assign) = expr.without_parenthesized() {
+            let AssignmentTarget::AssignmentTargetIdentifier(ident) = &assign.left else {
+                return None;
+            };
+            return (assign.operator == AssignmentOperator::Assign)
+                .then(|| self.symbol_of(ident).map(|symbol_id| (symbol_id, true)))
+                .flatten();
+        }
+        self.binding(expr).map(|symbol_id| (symbol_id, false))
+    }
+
+    /// `null`, or `void 0` and the global `undefined`.
+    fn nullish_value(&self, expr: &Expression<'a>) -> Option<Nullish> {
+        match expr.without_parenthesized() {
+            Expression::NullLiteral(_) => Some(Nullish::Null),
+            Expression::Identifier(ident)
+                if ident.name == "undefined" && self.symbol_of(ident).is_none() =>
+            {
+                Some(Nullish::Undefined)
+            }
+            expr if expr.is_void_0() => Some(Nullish::Undefined),
+            _ => None,
+        }
+    }
+
+    /// `x == null`, `x === null` or `x !== void 0`, with the operands in either order.
+    fn comparison(
+        &self,
+        expr: &Expression<'a>,
+    ) -> Option<((SymbolId, bool), Nullish, BinaryOperator)> {
+        let Expression::BinaryExpression(binary) = expr.without_parenthesized() else {
+            return None;
+        };
+        let (subject, value) = match self.nullish_value(&binary.right) {
+            Some(value) => (&binary.left, value),
+            None => (&binary.right, self.nullish_value(&binary.left)?),
+        };
+        Some((self.subject(subject)?, value, binary.operator))
+    }
+
+    fn nullish_test(&self, test: &Expression<'a>) -> Option<NullishTest> {
+        if let Some(((symbol_id, assigned), _, operator)) = self.comparison(test) {
+            let negated = match operator {
+                BinaryOperator::Equality => false,
+                BinaryOperator::Inequality => true,
+                _ => return None,
+            };
+            return Some(NullishTest { symbol_id, assigned, negated, references: 1 });
+        }
+        let Expression::LogicalExpression(logical) = test.without_parenthesized() else {
+            return None;
+        };
+        let (operator, negated) = match logical.operator {
+            LogicalOperator::Or => (BinaryOperator::StrictEquality, false),
+            LogicalOperator::And => (BinaryOperator::StrictInequality, true),
+            LogicalOperator::Coalesce => return None,
+        };
+        let ((symbol_id, assigned), left_value, left_operator) = self.comparison(&logical.left)?;
+        let ((right_symbol_id, right_assigned), right_value, right_operator) =
+            self.comparison(&logical.right)?;
+        // Only the first comparison may assign the binding.
+        (right_symbol_id == symbol_id
+            && !right_assigned
+            && left_value != right_value
+            && left_operator == operator
+            && right_operator == operator)
+            .then_some(NullishTest { symbol_id, assigned, negated, references: 2 })
+    }
+
+    /// `x == null ? d : x` -> `x ?? d`
+    fn fold_conditional(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ConditionalExpression(cond) = expr else { return };
+        let cond = &mut **cond;
+        let Some(test) = self.nullish_test(&cond.test) else { return };
+        let (value, default) = if test.negated {
+            (&mut cond.consequent, &mut cond.alternate)
+        } else {
+            (&mut cond.alternate, &mut cond.consequent)
+        };
+        if self.binding(value) != Some(test.symbol_id) {
+            return;
+        }
+        let default = self.ast.move_expression(default);
+        let left = if test.assigned {
+            let assign = first_comparison_subject(&mut cond.test);
+            // The test and the value are all of the references to an unused temporary.
+            let unused = self.temporaries.contains(&test.symbol_id)
+                && self.symbols.get_resolved_reference_count(test.symbol_id) == test.references + 1;
+            match assign {
+                Expression::AssignmentExpression(assign) if unused => {
+                    self.removed.insert(test.symbol_id);
+                    self.ast.move_expression(&mut assign.right)
+                }
+                _ => self.ast.move_expression(assign),
+            }
+        } else {
+            self.ast.move_expression(value)
+        };
+        *expr = self.ast.logical_expression(cond.span, left, LogicalOperator::Coalesce, default);
+    }
+
+    fn declares_removed(&self, declarator: &VariableDeclarator<'a>) -> bool {
+        matches!(
+            &declarator.id.kind,
+            BindingPatternKind::BindingIdentifier(ident)
+                if ident.symbol_id.get().is_some_and(|id| self.removed.contains(&id))
+        )
+    }
+}
+
+/// The `(_a = e)` of `(_a = e) == null` or `(_a = e) === null || _a === void 0`, whose
+/// parentheses are removed by the prepass.
+fn first_comparison_subject<'b, 'a>(mut test: &'b mut Expression<'a>) -> &'b mut Expression<'a> {
+    if let Expression::LogicalExpression(logical) = test {
+        test = &mut logical.left;
+    }
+    let Expression::BinaryExpression(binary) = test else { unreachable!() };
+    if matches!(binary.left, Expression::AssignmentExpression(_)) {
+        &mut binary.left
+    } else {
+        &mut binary.right
+    }
+}
+
+/// A `var` declared with only an identifier and without a value, which is not a global.
+fn is_temporary(semantic: &Semantic, symbol_id: SymbolId) -> bool {
+    let symbols = semantic.symbols();
+    if symbols.get_flag(symbol_id).bits() != SymbolFlags::FunctionScopedVariable.bits()
+        || !symbols.get_redeclare_variables(symbol_id).is_empty()
+        || (!semantic.source_type().is_module()
+            && symbols.get_scope_id(symbol_id) == semantic.scopes().root_scope_id())
+    {
+        return false;
+    }
+    matches!(
+        semantic.nodes().kind(symbols.get_declaration(symbol_id)),
+        AstKind::VariableDeclarator(declarator)
+            if declarator.init.is_none() && declarator.id.kind.is_binding_identifier()
+    )
+}
+
+impl<'a> VisitMut<'a> for NullishCoalescing<'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        walk_expression_mut(self, expr);
+        self.fold_conditional(expr);
+    }
+
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        walk_statements_mut(self, stmts);
+        if self.removed.is_empty() {
+            return;
+        }
+        for stmt in stmts.iter_mut() {
+            if let Statement::VariableDeclaration(decl) = stmt {
+                decl.declarations.retain(|declarator| !self.declares_removed(declarator));
+            }
+        }
+        stmts.retain(|stmt| {
+            !matches!(stmt, Statement::VariableDeclaration(decl) if decl.declarations.is_empty())
+        });
+    }
+}
@@ -0,0 +1,331 @@
+//! Optional Chaining Collapse
+//!
+//! Removes `?.` when the value it tests is known not to be `null` or `undefined`:
+//!
+//! * literals, object, array, function and class expressions, `new` expressions and operators
+//!   which always produce a value, e.g. `"a"?.length` → `"a".length`,
+//! * `this` in class constructors, static blocks and field initializers,
+//! * bindings which are never reassigned and hold such a value: function and class declarations,
+//!   and `let` / `const` declarations initialized with such a value,
+//! * a binding or `this` tested by the same expression right before the chain is evaluated,
+//!   e.g. `a && a?.b` → `a && a.b` and `a != null ? a?.b : c` → `a != null ? a.b : c`.
+//!
+//! The chain expression is unwrapped once no `?.` is left, `f?.()` → `f()`.
+
+use oxc_allocator::Allocator;
+use oxc_ast::visit::walk_mut::{
+    walk_conditional_expression_mut, walk_expression_mut, walk_function_mut,
+    walk_logical_expression_mut, walk_static_block_mut,
+};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder, AstKind, VisitMut};
+use oxc_semantic::{
+    Reference, ScopeFlags, Semantic, SemanticBuilder, SymbolFlags, SymbolId, SymbolTable,
+};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+use rustc_hash::FxHashSet;
+
+pub struct OptionalChains<'a> {
+    ast: AstBuilder<'a>,
+    symbols: SymbolTable,
+    /// Bindings which are never `null` or `undefined` when read.
+    non_nullish_symbols: FxHashSet<SymbolId>,
+    /// Whether `this` is known to be an object, for each enclosing function or class member.
+    this_is_object: std::vec::Vec<bool>,
+}
+
+/// A value known not to be nullish.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Guard {
+    This,
+    Symbol(SymbolId),
+}
+
+impl<'a> OptionalChains<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            symbols: SymbolTable::default(),
+            non_nullish_symbols: FxHashSet::default(),
+            this_is_object: vec![],
+        }
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        let semantic = SemanticBuilder::new("", program.source_type).build(program).semantic;
+        self.non_nullish_symbols = semantic
+            .symbols()
+            .iter()
+            .filter(|&symbol_id| is_non_nullish_symbol(&semantic, symbol_id))
+            .collect();
+        self.symbols = semantic.into_symbol_table_and_scope_tree().0;
+        self.visit_program(program);
+    }
+
+    fn symbol_of(&self, ident: &IdentifierReference<'a>) -> Option<SymbolId> {
+        ident.reference_id.get().and_then(|id| self.symbols.get_reference(id).symbol_id())
+    }
+
+    fn is_non_nullish(&self, expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::Identifier(ident) => {
+                self.symbol_of(ident).is_some_and(|id| self.non_nullish_symbols.contains(&id))
+            }
+            Expression::ThisExpression(_) => self.this_is_object.last() == Some(&true),
+            Expression::ParenthesizedExpression(paren) => self.is_non_nullish(&paren.expression),
+            _ => is_non_nullish_value(expr),
+        }
+    }
+
+    /// Remove `?.` from a link of a chain and the links evaluated before it.
+    fn collapse(&self, optional: &mut bool, object: &mut Expression<'a>) {
+        if let Some((inner_optional, inner_object)) = link_mut(object) {
+            self.collapse(inner_optional, inner_object);
+        }
+        if *optional && self.is_non_nullish(object) {
+            *optional = false;
+        }
+    }
+
+    /// Remove `?.` from the first link of the chain, if it tests one of `guards`.
+    fn collapse_guarded(&self, optional: &mut bool, object: &mut Expression<'a>, guards: &[Guard]) {
+        if let Some((inner_optional, inner_object)) = link_mut(object) {
+            self.collapse_guarded(inner_optional, inner_object, guards);
+            return;
+        }
+        if *optional && self.guard(object).is_some_and(|guard| guards.contains(&guard)) {
+            *optional = false;
+        }
+    }
+
+    fn guard(&self, expr: &Expression<'a>) -> Option<Guard> {
+        match expr {
+            Expression::Identifier(ident) => self.symbol_of(ident).map(Guard::Symbol),
+            Expression::ThisExpression(_) => Some(Guard::This),
+            _ => None,
+        }
+    }
+
+    /// Collect the values known not to be nullish when `expr` is truthy.
+    /// Returns `false` if evaluating `expr` may reassign them.
+    fn collect_guards(&self, expr: &Expression<'a>, guards: &mut std::vec::Vec<Guard>) -> bool {
+        match expr {
+            // Reading an unresolved reference may call a getter of the global object
+            Expression::Identifier(_) | Expression::ThisExpression(_) => {
+                let Some(guard) = self.guard(expr) else { return false };
+                guards.push(guard);
+                true
+            }
+            Expression::LogicalExpression(logical) if logical.operator == LogicalOperator::And => {
+                self.collect_guards(&logical.left, guards)
+                    && self.collect_guards(&logical.right, guards)
+            }
+            // `a != null`
+            Expression::BinaryExpression(binary)
+                if binary.operator == BinaryOperator::Inequality
+                    && matches!(binary.right, Expression::NullLiteral(_)) =>
+            {
+                self.collect_guards(&binary.left, guards)
+            }
+            _ => false,
+        }
+    }
+
+    /// Remove `?.` from `chain` where `test` is known to be truthy.
+    fn collapse_after_test(&mut self, test: &Expression<'a>, chain: &mut Expression<'a>) {
+        let Expression::ChainExpression(chain_expr) = chain else { return };
+        let mut guards = vec![];
+        if !self.collect_guards(test, &mut guards) || guards.is_empty() {
+            return;
+        }
+        let (optional, object) = chain_element_mut(&mut chain_expr.expression);
+        self.collapse_guarded(optional, object, &guards);
+        self.unwrap_chain(chain);
+    }
+
+    /// `a.b` without any `?.` left does not need to be a chain expression.
+    fn unwrap_chain(&mut self, expr: &mut Expression<'a>) {
+        let Expression::ChainExpression(chain) = expr else { return };
+        let (optional, object) = chain_element_mut(&mut chain.expression);
+        if *optional || has_optional(object) {
+            return;
+        }
+        let Expression::ChainExpression(chain) = self.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        *expr = match chain.unbox().expression {
+            ChainElement::CallExpression(call) => Expression::CallExpression(call),
+            ChainElement::ComputedMemberExpression(member) => {
+                Expression::ComputedMemberExpression(member)
+            }
+            ChainElement::StaticMemberExpression(member) => {
+                Expression::StaticMemberExpression(member)
+            }
+            ChainElement::PrivateFieldExpression(member) => {
+                Expression::PrivateFieldExpression(member)
+            }
+        };
+    }
+}
+
+impl<'a> VisitMut<'a> for OptionalChains<'a> {
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: Option<ScopeFlags>) {
+        let is_constructor = flags.is_some_and(|flags| flags.contains(ScopeFlags::Constructor));
+        self.this_is_object.push(is_constructor);
+        walk_function_mut(self, func, flags);
+        self.this_is_object.pop();
+    }
+
+    fn visit_static_block(&mut self, block: &mut StaticBlock<'a>) {
+        self.this_is_object.push(true);
+        walk_static_block_mut(self, block);
+        self.this_is_object.pop();
+    }
+
+    fn visit_property_definition(&mut self, def: &mut PropertyDefinition<'a>) {
+        // Decorators and computed keys are evaluated outside of the class
+        for decorator in def.decorators.iter_mut() {
+            self.visit_decorator(decorator);
+        }
+        self.visit_property_key(&mut def.key);
+        if let Some(value) = &mut def.value {
+            self.this_is_object.push(true);
+            self.visit_expression(value);
+            self.this_is_object.pop();
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        walk_expression_mut(self, expr);
+        if let Expression::ChainExpression(chain) = expr {
+            let (optional, object) = chain_element_mut(&mut chain.expression);
+            self.collapse(optional, object);
+            self.unwrap_chain(expr);
+        }
+    }
+
+    fn visit_logical_expression(&mut self, expr: &mut LogicalExpression<'a>) {
+        walk_logical_expression_mut(self, expr);
+        if expr.operator == LogicalOperator::And {
+            self.collapse_after_test(&expr.left, &mut expr.right);
+        }
+    }
+
+    fn visit_conditional_expression(&mut self, expr: &mut ConditionalExpression<'a>) {
+        walk_conditional_expression_mut(self, expr);
+        self.collapse_after_test(&expr.test, &mut expr.consequent);
+    }
+}
+
+/// The `?.` flag and the tested object of a link of a chain.
+fn link_mut<'e, 'a>(
+    expr: &'e mut Expression<'a>,
+) -> Option<(&'e mut bool, &'e mut Expression<'a>)> {
+    match expr {
+        Expression::CallExpression(call) => {
+            let CallExpression { optional, callee, .. } = &mut **call;
+            Some((optional, callee))
+        }
+        Expression::ComputedMemberExpression(member) => {
+            let ComputedMemberExpression { optional, object, .. } = &mut **member;
+            Some((optional, object))
+        }
+        Expression::StaticMemberExpression(member) => {
+            let StaticMemberExpression { optional, object, .. } = &mut **member;
+            Some((optional, object))
+        }
+        Expression::PrivateFieldExpression(member) => {
+            let PrivateFieldExpression { optional, object, .. } = &mut **member;
+            Some((optional, object))
+        }
+        _ => None,
+    }
+}
+
+fn chain_element_mut<'e, 'a>(
+    element: &'e mut ChainElement<'a>,
+) -> (&'e mut bool, &'e mut Expression<'a>) {
+    match element {
+        ChainElement::CallExpression(call) => {
+            let CallExpression { optional, callee, .. } = &mut **call;
+            (optional, callee)
+        }
+        ChainElement::ComputedMemberExpression(member) => {
+            let ComputedMemberExpression { optional, object, .. } = &mut **member;
+            (optional, object)
+        }
+        ChainElement::StaticMemberExpression(member) => {
+            let StaticMemberExpression { optional, object, .. } = &mut **member;
+            (optional, object)
+        }
+        ChainElement::PrivateFieldExpression(member) => {
+            let PrivateFieldExpression { optional, object, .. } = &mut **member;
+            (optional, object)
+        }
+    }
+}
+
+fn has_optional(expr: &mut Expression<'_>) -> bool {
+    link_mut(expr).is_some_and(|(optional, object)| *optional || has_optional(object))
+}
+
+/// Values which are never `null` or `undefined`.
+fn is_non_nullish_value(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::BooleanLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigintLiteral(_)
+        | Expression::RegExpLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::TemplateLiteral(_)
+        | Expression::ArrayExpression(_)
+        | Expression::ObjectExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ClassExpression(_)
+        | Expression::NewExpression(_)
+        | Expression::BinaryExpression(_)
+        | Expression::UpdateExpression(_)
+        | Expression::PrivateInExpression(_)
+        | Expression::ImportExpression(_) => true,
+        Expression::UnaryExpression(unary) => unary.operator != UnaryOperator::Void,
+        Expression::ParenthesizedExpression(paren) => is_non_nullish_value(&paren.expression),
+        Expression::SequenceExpression(seq) => {
+            seq.expressions.last().is_some_and(is_non_nullish_value)
+        }
+        _ => false,
+    }
+}
+
+fn is_non_nullish_symbol(semantic: &Semantic<'_>, symbol_id: SymbolId) -> bool {
+    let symbols = semantic.symbols();
+    let scope_id = symbols.get_scope_id(symbol_id);
+    let scope_flags = semantic.scopes().get_flags(scope_id);
+    if scope_flags.contains_direct_eval()
+        || symbols.get_resolved_references(symbol_id).any(Reference::is_write)
+    {
+        return false;
+    }
+
+    let flags = symbols.get_flag(symbol_id);
+    if flags.intersects(SymbolFlags::Ambient | SymbolFlags::ImportBinding) {
+        return false;
+    }
+    match semantic.nodes().kind(symbols.get_declaration(symbol_id)) {
+        // `var f = null; function f() {}` is reassigned by the initializer.
+        // Sloppy mode block functions are also assigned to a function scoped variable,
+        // and top level functions of scripts can be reassigned through the global object.
+        AstKind::Function(func) if func.is_declaration() => {
+            let is_global = scope_id == semantic.scopes().root_scope_id()
+                && !semantic.source_type().is_module();
+            symbols.get_redeclare_variables(symbol_id).is_empty()
+                && scope_flags.is_strict_mode()
+                && !is_global
+        }
+        AstKind::Class(class) => class.is_declaration(),
+        AstKind::VariableDeclarator(decl) if flags.contains(SymbolFlags::BlockScopedVariable) => {
+            decl.init.as_ref().is_some_and(is_non_nullish_value)
+        }
+        _ => false,
+    }
+}
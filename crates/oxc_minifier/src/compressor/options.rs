@@ -1,10 +1,75 @@
+use super::{global_defs::GlobalDefValue, util::matches_glob};
+
 #[derive(Debug, Clone, Copy)]
 pub struct CompressOptions {
-    /// Various optimizations for boolean context, for example `!!a ? b : c` → `a ? b : c`.
+    /// Fold pure array operations on array literals,
+    /// for example `[1, 2, 3].map(x => x * 2).length` → `3` and `Array.of(1, 2)` → `[1, 2]`.
+    ///
+    /// Default `true`
+    pub arrays: bool,
+
+    /// Call functions which are bound and called right away directly, for example
+    /// `(function () {}).bind(a)(b)` → `(function () {}).call(a, b)` and
+    /// `goog.partial(f, a)(b)` → `f(a, b)`.
+    ///
+    /// Default `true`
+    pub bind_calls: bool,
+
+    /// Various optimizations for boolean context, for example `!!a ? b : c` → `a ? b : c`
+    /// and `if (Boolean(a)) b` → `if (a) b`.
     ///
     /// Default `true`
     pub booleans: bool,
 
+    /// Evaluate the calls to the static predicates of the builtins whose arguments are literals,
+    /// for example `Array.isArray([])` → `true`, `Number.isNaN("a")` → `false` and
+    /// `Object.is(0, -0)` → `false`, and the calls to the functions of `Math` and the arithmetic
+    /// on the constants of `Math` and `Number`, for example `Math.floor(3.7)` → `3` and
+    /// `Math.PI * 2` → `6.283185307179586`.
+    ///
+    /// Default `true`
+    pub builtins: bool,
+
+    /// Simplify comparisons whose result follows from their shape, for example
+    /// `a < b === true` → `a < b` and `typeof a === typeof b` → `typeof a == typeof b`.
+    ///
+    /// Default `true`
+    pub comparisons: bool,
+
+    /// Rewrite `if` statements with empty branches, nested `if` statements and early exits,
+    /// for example `if (a) {} else b` → `if (!a) b` and `if (a) return; b()` → `if (!a) b()`,
+    /// fold `if` statements whose branches are expressions into expressions when it is shorter,
+    /// for example `if (a) b()` → `a && b()`, and remove `else` after an exit and `return` at the
+    /// end of a function.
+    ///
+    /// Default `true`
+    pub conditionals: bool,
+
+    /// Remove the branches of `if` statements and conditional expressions whose test is a literal,
+    /// for example `if (false) a(); else b()` → `b()` and `true ? a : b` → `a`.
+    ///
+    /// Default `true`
+    pub dead_code: bool,
+
+    /// Remove assignments to local variables which are never read before the next assignment or
+    /// the end of their scope, for example `x = 1; x = 2` → `x = 2`.
+    ///
+    /// Default `true`
+    pub dead_stores: bool,
+
+    /// Bind the values of object and array literals which are immediately destructured directly,
+    /// for example `const { a } = { a: 1, b: g() }` → `g(); const a = 1` and
+    /// `const [x] = [1, 2]` → `const x = 1`.
+    ///
+    /// Default `true`
+    pub destructuring: bool,
+
+    /// Remove duplicate keys in object literals, keeping the last definition, for example
+    /// `{ a: 1, b, a: 2 }` → `{ a: 2, b }`.
+    ///
+    /// Default `true`
+    pub duplicate_keys: bool,
+
     /// Remove `debugger;` statements.
     ///
     /// Default `true`
@@ -20,58 +85,388 @@ pub struct CompressOptions {
     /// Default `true`
     pub evaluate: bool,
 
+    /// Shorten references to the global `Infinity` and `NaN`,
+    /// for example `Infinity` → `1/0` and `Number.NEGATIVE_INFINITY` → `-1/0`.
+    ///
+    /// Default `true`
+    pub global_constants: bool,
+
+    /// Replace globals and the static properties of globals with constants, for example
+    /// `&[("__DEV__", GlobalDefValue::Boolean(false))]` for `__DEV__` → `false` and
+    /// `&[("process.env.NODE_ENV", GlobalDefValue::String("production"))]` for
+    /// `process.env.NODE_ENV` → `"production"`, so that the branches which test them can be
+    /// removed.
+    ///
+    /// Default `&[]`
+    pub global_defs: &'static [(&'static str, GlobalDefValue)],
+
+    /// Move function declarations out of blocks in strict mode code, where they are block scoped,
+    /// to the enclosing function, for example `if (a) { function f() {} f() }` →
+    /// `function f() {} if (a) f()`, so that the block can be removed.
+    ///
+    /// Default `true`
+    pub hoist_functions: bool,
+
+    /// Collapse arrow functions without parameters which are called right away, and whose body
+    /// only returns through `if` statements, into an expression, for example `(() => a)()` → `a`
+    /// and `(() => { if (a) return b; return c })()` → `a ? b : c`.
+    ///
+    /// Default `true`
+    pub iifes: bool,
+
+    /// Replace the helpers which Babel inlines, such as `function _defineProperty(e, r, t) { .. }`,
+    /// with default imports from the given runtime, for example `Some("@babel/runtime")` for
+    /// `import _defineProperty from "@babel/runtime/helpers/defineProperty"`. Modules only.
+    ///
+    /// Default `None`
+    pub import_babel_helpers: Option<&'static str>,
+
+    /// Replace the helpers which TypeScript inlines, such as
+    /// `var __assign = (this && this.__assign) || ..`, with named imports from the given runtime,
+    /// for example `Some("tslib")` for `import { __assign } from "tslib"`. Modules only.
+    ///
+    /// Default `None`
+    pub import_ts_helpers: Option<&'static str>,
+
+    /// Inline the members of enum-like objects, `Object.freeze({ .. })` and lowered TypeScript
+    /// enums, when the object never escapes.
+    ///
+    /// Default `true`
+    pub inline_enums: bool,
+
     /// Join consecutive var statements.
     ///
     /// Default `true`
     pub join_vars: bool,
 
+    /// Run the late transformations, which make the output smaller but harder to optimize again,
+    /// for example joining consecutive expression statements into sequence expressions with
+    /// `sequences`, `a(); b(); return c` → `return a(), b(), c`, and splitting a string for long
+    /// arrays of strings, `["a", "b", "c", "d", "e", "f"]` → `"abcdef".split("")`.
+    /// Disable it when the output is compressed again, for example when a bundle is minified.
+    /// The passes registered for [crate::PassPhase::Late] only run with it.
+    ///
+    /// Default `true`
+    pub late: bool,
+
     /// Optimizations for do, while and for loops when we can statically determine the condition
     ///
     /// Default `true`
     pub loops: bool,
 
-    /// Transforms `typeof foo == "undefined" into `foo === void 0`
+    /// Rewrite import and export statements into their shortest form,
+    /// for example `import { a } from "m"; import { b } from "m"` → `import { a, b } from "m"`
+    /// and `function f() {} export default f` → `export default function f() {}`.
+    ///
+    /// Default `true`
+    pub modules: bool,
+
+    /// Declare the `var`s which are only used inside of one block with `let` in that block,
+    /// for example `var x; if (a) { x = f(); g(x) }` → `if (a) { let x = f(); g(x) }`,
+    /// so that the mangler can reuse their names in sibling blocks. Requires an ES2015 target.
+    ///
+    /// Default `false`
+    pub narrow_vars: bool,
+
+    /// Replace `process.env.NODE_ENV` with the given string, for example `Some("production")`,
+    /// so that the branches for other environments can be removed.
+    ///
+    /// Default `None`
+    pub node_env: Option<&'static str>,
+
+    /// Recover `??` from the conditional expressions which older compilers lower it to,
+    /// for example `x == null ? d : x` → `x ?? d` and
+    /// `(_a = f()) !== null && _a !== void 0 ? _a : d` → `f() ?? d`. Requires an ES2020 target.
+    ///
+    /// Default `false`
+    pub nullish_coalescing: bool,
+
+    /// Remove `?.` when the tested value is known not to be `null` or `undefined`,
+    /// for example `a && a?.b` → `a && a.b` and `function f() {} f?.()` → `function f() {} f()`.
+    ///
+    /// Default `true`
+    pub optional_chaining: bool,
+
+    /// Experimental. Declare the string literals which are repeated in a module once in a `const`,
+    /// for example `f("pointerdown"); g("pointerdown")` →
+    /// `const a = "pointerdown"; f(a); g(a)`, when it is estimated to be smaller after gzip,
+    /// which already compresses repeated strings well. Modules only.
+    ///
+    /// Default `false`
+    pub pool_strings: bool,
+
+    /// Rewrite literal property keys into their shortest form, for example `a["b"]` → `a.b`,
+    /// `a["0"]` → `a[0]` and `{ ["c"]: 1 }` → `{ c: 1 }`.
+    ///
+    /// Default `true`
+    pub properties: bool,
+
+    /// The functions whose calls have no side effects, as identifiers or paths of static
+    /// properties, for example `&["invariant", "console.log"]`. A call whose result is unused
+    /// is removed by `dead_code` when its arguments have no side effects, whatever the callee is
+    /// bound to, for example `console.log("ready")` → ``.
+    ///
+    /// Default `&[]`
+    pub pure_funcs: &'static [&'static str],
+
+    /// The modules whose evaluation has no side effects, as globs of their specifiers where `*`
+    /// matches any characters, for example `&["lodash-es", "lodash-es/*"]`. Their imports are
+    /// removed when no binding is used, and `require("m")` and `import("m")` when their result
+    /// is unused. Any other module is assumed to have side effects, such as a polyfill, and is
+    /// always loaded, in the same order and under the same conditions.
+    ///
+    /// Default `&[]`
+    pub pure_modules: &'static [&'static str],
+
+    /// Join consecutive simple statements into sequence expressions,
+    /// for example `a(); if (b) return c; return d` → `return a(), b ? c : d`.
+    ///
+    /// Default `true`
+    pub sequences: bool,
+
+    /// Rewrite `else if` chains which compare the same variable with literals into a `switch`
+    /// statement when it is estimated to be shorter, for example
+    /// `if (x === 1) return a; else if (x === 2) return b; else return c` →
+    /// `switch (x) { case 1: return a; case 2: return b; default: return c }`.
+    /// The variable is read once instead of once for each comparison.
+    ///
+    /// Default `false`
+    pub switches: bool,
+
+    /// Transforms `typeof foo == "undefined"` into `foo === void 0` and
+    /// `typeof foo == "undefined" || foo === null` into `foo == null` for a declared `foo`,
+    /// and removes the `switch (typeof foo)` cases which can never match.
     ///
     /// Default `true`
     pub typeofs: bool,
+
+    /// Remove the import specifiers whose binding is never referenced, for example
+    /// `import { a, b } from "m"; a()` → `import { a } from "m"; a()`. A module whose
+    /// specifiers are all removed is still imported for its side effects, `import "m"`.
+    ///
+    /// Default `true`
+    pub unused_imports: bool,
+
+    /// Remove the unused trailing parameters of local functions which are only called directly,
+    /// and the arguments they ignore, for example `function f(a, b) { return a } f(1, 2)` →
+    /// `function f(a) { return a } f(1)`.
+    ///
+    /// Default `true`
+    pub unused_params: bool,
+
+    /// Fuse consecutive `map` calls,
+    /// for example `a.map(x => f(x)).map(y => g(y))` → `a.map((x, y) => (y = f(x), g(y)))`.
+    /// Unsafe when the callbacks have side effects or the second callback uses the array argument.
+    ///
+    /// Default `false`
+    pub unsafe_arrays: bool,
+
+    /// Downgrade local classes which only have a constructor and static methods,
+    /// for example `class A { static f() {} }` → `let A = { f() {} }` and
+    /// `class A { constructor(a) { this.a = a } }` → `function A(a) { this.a = a }`.
+    /// Unsafe because the class can then be called without `new`, and its methods are enumerable.
+    ///
+    /// Default `false`
+    pub unsafe_classes: bool,
+
+    /// Fold comparisons of an identifier with itself, for example `x === x` → `true` and
+    /// `x < x` → `false`, and merge range checks on the same identifier, for example
+    /// `x > 1 && x > 5` → `x > 5`.
+    /// Unsafe because they are different for `NaN`, where `x !== x` is the usual test for it,
+    /// and for objects which convert to a different value each time, as with `valueOf`.
+    ///
+    /// Default `false`
+    pub unsafe_comparisons: bool,
+
+    /// Turn the leading checks of a function for missing arguments into default parameters,
+    /// for example `function f(a) { if (a === void 0) a = 1; }` → `function f(a = 1) {}`.
+    /// Unsafe because the `length` of the function no longer counts the parameter and the
+    /// following ones. Requires an ES2015 target.
+    ///
+    /// Default `false`
+    pub unsafe_default_params: bool,
+
+    /// Remove the imports whose specifiers are all removed by `unused_imports`, instead of keeping
+    /// `import "m"`. Unsafe when the imported module has side effects.
+    ///
+    /// Default `false`
+    pub unsafe_imports: bool,
 }
 
 impl Default for CompressOptions {
     fn default() -> Self {
         Self {
+            arrays: true,
+            bind_calls: true,
             booleans: true,
+            builtins: true,
+            comparisons: true,
+            conditionals: true,
+            dead_code: true,
+            dead_stores: true,
+            destructuring: true,
+            duplicate_keys: true,
             drop_debugger: true,
             drop_console: false,
             evaluate: true,
+            global_constants: true,
+            global_defs: &[],
+            hoist_functions: true,
+            iifes: true,
+            import_babel_helpers: None,
+            import_ts_helpers: None,
+            inline_enums: true,
             join_vars: true,
+            late: true,
             loops: true,
+            modules: true,
+            narrow_vars: false,
+            node_env: None,
+            nullish_coalescing: false,
+            optional_chaining: true,
+            pool_strings: false,
+            properties: true,
+            pure_funcs: &[],
+            pure_modules: &[],
+            sequences: true,
+            switches: false,
             typeofs: true,
+            unsafe_arrays: false,
+            unsafe_classes: false,
+            unsafe_comparisons: false,
+            unsafe_default_params: false,
+            unsafe_imports: false,
+            unused_imports: true,
+            unused_params: true,
         }
     }
 }
 
 impl CompressOptions {
+    /// Disable the options which depend on the bindings that references resolve to, used when
+    /// the program contains a `with` statement or a direct `eval`, which can reference or
+    /// reassign any binding by name at runtime.
+    #[must_use]
+    pub fn without_scope_analysis(self) -> Self {
+        Self {
+            dead_stores: false,
+            global_constants: false,
+            global_defs: &[],
+            hoist_functions: false,
+            import_babel_helpers: None,
+            import_ts_helpers: None,
+            inline_enums: false,
+            narrow_vars: false,
+            node_env: None,
+            nullish_coalescing: false,
+            optional_chaining: false,
+            pool_strings: false,
+            switches: false,
+            typeofs: false,
+            unsafe_classes: false,
+            unsafe_default_params: false,
+            unused_imports: false,
+            unused_params: false,
+            ..self
+        }
+    }
+
+    /// Whether the module `specifier` is in `pure_modules`.
+    pub fn is_pure_module(&self, specifier: &str) -> bool {
+        self.pure_modules.iter().any(|pattern| matches_glob(pattern, specifier))
+    }
+
     pub fn all_true() -> Self {
         Self {
+            arrays: true,
+            bind_calls: true,
             booleans: true,
+            builtins: true,
+            comparisons: true,
+            conditionals: true,
+            dead_code: true,
+            dead_stores: true,
+            destructuring: true,
+            duplicate_keys: true,
             drop_debugger: true,
             drop_console: true,
             evaluate: true,
+            global_constants: true,
+            global_defs: &[],
+            hoist_functions: true,
+            iifes: true,
+            import_babel_helpers: None,
+            import_ts_helpers: None,
+            inline_enums: true,
             join_vars: true,
+            late: true,
             loops: true,
+            modules: true,
+            narrow_vars: true,
+            node_env: None,
+            nullish_coalescing: true,
+            optional_chaining: true,
+            pool_strings: true,
+            properties: true,
+            pure_funcs: &[],
+            pure_modules: &[],
+            sequences: true,
+            switches: true,
             typeofs: true,
+            unsafe_arrays: true,
+            unsafe_classes: true,
+            unsafe_comparisons: true,
+            unsafe_default_params: true,
+            unsafe_imports: true,
+            unused_imports: true,
+            unused_params: true,
         }
     }
 
     pub fn all_false() -> Self {
         Self {
+            arrays: false,
+            bind_calls: false,
             booleans: false,
+            builtins: false,
+            comparisons: false,
+            conditionals: false,
+            dead_code: false,
+            dead_stores: false,
+            destructuring: false,
+            duplicate_keys: false,
             drop_debugger: false,
             drop_console: false,
             evaluate: false,
+            global_constants: false,
+            global_defs: &[],
+            hoist_functions: false,
+            iifes: false,
+            import_babel_helpers: None,
+            import_ts_helpers: None,
+            inline_enums: false,
             join_vars: false,
+            late: false,
             loops: false,
+            modules: false,
+            narrow_vars: false,
+            node_env: None,
+            nullish_coalescing: false,
+            optional_chaining: false,
+            pool_strings: false,
+            properties: false,
+            pure_funcs: &[],
+            pure_modules: &[],
+            sequences: false,
+            switches: false,
             typeofs: false,
+            unsafe_arrays: false,
+            unsafe_classes: false,
+            unsafe_comparisons: false,
+            unsafe_default_params: false,
+            unsafe_imports: false,
+            unused_imports: false,
+            unused_params: false,
         }
     }
 }
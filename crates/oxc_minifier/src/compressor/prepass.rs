@@ -1,16 +1,22 @@
 use oxc_allocator::{Allocator, Vec};
 
-use oxc_ast::visit::walk_mut::{walk_expression_mut, walk_statements_mut};
+use oxc_ast::visit::walk_mut::{
+    walk_call_expression_mut, walk_expression_mut, walk_statements_mut, walk_with_statement_mut,
+};
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_span::Span;
 
 pub struct Prepass<'a> {
     ast: AstBuilder<'a>,
+    /// The first `with` statement or direct `eval` call, which can reference any binding by name
+    /// at runtime, and what it is.
+    pub dynamic_scope: Option<(&'static str, Span)>,
 }
 
 impl<'a> Prepass<'a> {
     pub fn new(allocator: &'a Allocator) -> Self {
-        Self { ast: AstBuilder::new(allocator) }
+        Self { ast: AstBuilder::new(allocator), dynamic_scope: None }
     }
 
     pub fn build(&mut self, program: &mut Program<'a>) {
@@ -35,4 +41,17 @@ impl<'a> VisitMut<'a> for Prepass<'a> {
         self.strip_parenthesized_expression(expr);
         walk_expression_mut(self, expr);
     }
+
+    fn visit_with_statement(&mut self, stmt: &mut WithStatement<'a>) {
+        self.dynamic_scope.get_or_insert(("a `with` statement", stmt.span));
+        walk_with_statement_mut(self, stmt);
+    }
+
+    fn visit_call_expression(&mut self, expr: &mut CallExpression<'a>) {
+        // `eval?.(x)` is an indirect `eval`.
+        if expr.callee.is_specific_id("eval") && !expr.optional {
+            self.dynamic_scope.get_or_insert(("a direct `eval`", expr.span));
+        }
+        walk_call_expression_mut(self, expr);
+    }
 }
@@ -0,0 +1,128 @@
+//! Property Keys and Accesses
+//!
+//! Rewrites literal property keys into their shortest form:
+//!
+//! * `a["b"]` -> `a.b` and `a["0"]` -> `a[0]`
+//! * `{ "b": 1, ["c"]: 2, ["0"]: 3 }` -> `{ b: 1, c: 2, 0: 3 }`
+//! * `class { ["b"]() {} static [0] = 1 }` -> `class { b() {} static 0 = 1 }`
+//!
+//! A computed key is not always equivalent to the same literal key, so some names stay computed
+//! in both object literals and classes:
+//!
+//! * `{ __proto__: a }` sets the prototype of the object, `{ ["__proto__"]: a }` defines a
+//!   property.
+//! * `class { constructor() {} }` is the constructor of the class, `class { ["constructor"]() {} }`
+//!   is a method, and a `constructor` field is an early error.
+//! * `class { static prototype = 1 }` is an early error, `class { static ["prototype"] = 1 }`
+//!   throws at runtime.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_syntax::{identifier::is_identifier_name, number::NumberBase};
+
+use super::Compressor;
+
+impl<'a> Compressor<'a> {
+    /// Enabled by `compress.properties`
+    ///
+    /// `a["b"]` -> `a.b` and `a["0"]` -> `a[0]`
+    pub(crate) fn compress_member_expression(&self, expr: &mut MemberExpression<'a>) {
+        if !self.options.properties {
+            return;
+        }
+        let MemberExpression::ComputedMemberExpression(computed) = expr else { return };
+        let Expression::StringLiteral(lit) = &computed.expression else { return };
+        if is_identifier_name(&lit.value) {
+            let property = IdentifierName::new(lit.span, lit.value.clone());
+            let (span, optional) = (computed.span, computed.optional);
+            let object = self.ast.move_expression(&mut computed.object);
+            *expr = self.ast.static_member(span, object, property, optional);
+        } else if let Some(index) = self.array_index(lit) {
+            computed.expression = self.ast.literal_number_expression(index);
+        }
+    }
+
+    /// Enabled by `compress.properties`
+    ///
+    /// `{ "b": 1, ["c"]: 2 }` -> `{ b: 1, c: 2 }`
+    pub(crate) fn compress_object_property(&self, prop: &mut ObjectProperty<'a>) {
+        if self.options.properties {
+            self.compress_property_key(&mut prop.key, &mut prop.computed, &["__proto__"]);
+        }
+    }
+
+    /// Enabled by `compress.properties`
+    ///
+    /// `class { ["b"]() {} static [0] = 1 }` -> `class { b() {} static 0 = 1 }`
+    pub(crate) fn compress_class_element(&self, elem: &mut ClassElement<'a>) {
+        if !self.options.properties {
+            return;
+        }
+        let (key, computed, r#static) = match elem {
+            ClassElement::MethodDefinition(def) => {
+                let MethodDefinition { key, computed, r#static, .. } = &mut **def;
+                (key, computed, *r#static)
+            }
+            ClassElement::PropertyDefinition(def) => {
+                let PropertyDefinition { key, computed, r#static, .. } = &mut **def;
+                (key, computed, *r#static)
+            }
+            ClassElement::AccessorProperty(def) => {
+                let AccessorProperty { key, computed, r#static, .. } = &mut **def;
+                (key, computed, *r#static)
+            }
+            ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => return,
+        };
+        let reserved: &[&str] =
+            if r#static { &["constructor", "prototype"] } else { &["constructor"] };
+        self.compress_property_key(key, computed, reserved);
+    }
+
+    /// Rewrite a string or numeric `key` into its shortest form, which is not computed unless it
+    /// is one of the `reserved` names.
+    fn compress_property_key(
+        &self,
+        key: &mut PropertyKey<'a>,
+        computed: &mut bool,
+        reserved: &[&str],
+    ) {
+        match key {
+            PropertyKey::StringLiteral(lit) => {
+                if *computed && reserved.contains(&lit.value.as_str()) {
+                    return;
+                }
+                if is_identifier_name(&lit.value) {
+                    let ident = IdentifierName::new(lit.span, lit.value.clone());
+                    *key = self.ast.property_key_identifier(ident);
+                } else if let Some(index) = self.array_index(lit) {
+                    *key =
+                        self.ast.property_key_expression(self.ast.literal_number_expression(index));
+                }
+                *computed = false;
+            }
+            PropertyKey::NumericLiteral(_) => *computed = false,
+            _ => {}
+        }
+    }
+
+    /// The number `lit` converts to, if it is the string of an integer which converts back to
+    /// the same string, e.g. `"10"` but not `"010"` or `"1e3"`.
+    fn array_index(&self, lit: &StringLiteral<'a>) -> Option<NumericLiteral<'a>> {
+        let value = lit.value.as_str();
+        // Integers of up to 15 digits are exact.
+        if value.is_empty()
+            || value.len() > 15
+            || !value.bytes().all(|b| b.is_ascii_digit())
+            || (value.len() > 1 && value.starts_with('0'))
+        {
+            return None;
+        }
+        let number = value.parse::<f64>().ok()?;
+        Some(self.ast.number_literal(
+            lit.span,
+            number,
+            self.ast.new_str(value),
+            NumberBase::Decimal,
+        ))
+    }
+}
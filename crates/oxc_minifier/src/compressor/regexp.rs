@@ -0,0 +1,41 @@
+//! Regular Expression Folding
+//!
+//! Evaluates calls on regular expression literals with a string literal subject.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_syntax::regexp::RegExp;
+
+use super::{Compressor, SPAN};
+
+impl<'a> Compressor<'a> {
+    /// Enabled by `compress.evaluate`
+    ///
+    /// * `/^a/.test("abc")` -> `true`
+    /// * `/^b/.exec("abc")` -> `null`
+    ///
+    /// Successful `exec` calls are kept, the match array is longer than the call.
+    pub(crate) fn fold_regexp_call(&mut self, expr: &mut Expression<'a>) {
+        if !self.options.evaluate {
+            return;
+        }
+        let Expression::CallExpression(call_expr) = expr else { return };
+        let Expression::StaticMemberExpression(member_expr) = &call_expr.callee else { return };
+        let Expression::RegExpLiteral(lit) = &member_expr.object else { return };
+        let [Argument::StringLiteral(subject)] = call_expr.arguments.as_slice() else { return };
+        let Ok(regexp) = RegExp::new(&lit.regex.pattern, &lit.regex.flags.to_string()) else {
+            return;
+        };
+        *expr = match member_expr.property.name.as_str() {
+            "test" => {
+                let Ok(matched) = regexp.test(&subject.value) else { return };
+                self.ast.literal_boolean_expression(self.ast.boolean_literal(SPAN, matched))
+            }
+            "exec" => match regexp.exec(&subject.value) {
+                Ok(None) => self.ast.literal_null_expression(NullLiteral::new(SPAN)),
+                _ => return,
+            },
+            _ => return,
+        };
+    }
+}
@@ -0,0 +1,173 @@
+//! Statement Joining
+//!
+//! Late phase which joins statements into sequence expressions.
+//!
+//! A sequence expression hides the statement boundaries from tools which compress the output
+//! again, so expression statements are only joined with `compress.late`, while `if` statements
+//! returning a value are folded into conditional expressions either way.
+//!
+//! <https://github.com/terser/terser/blob/v5.31.0/lib/compress/tighten-body.js>
+
+use oxc_allocator::Vec;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+
+use super::{Compressor, SPAN};
+
+impl<'a> Compressor<'a> {
+    /// Join statements of a statement list into sequence expressions.
+    /// Enabled by `compress.sequences`, expression statements are joined with `compress.late`
+    ///
+    /// * `if (a) return b; return c` -> `return a ? b : c`
+    /// * `a(); b(); return c` -> `return a(), b(), c`
+    /// * `a(); throw b` -> `throw a(), b`
+    /// * `a(); b()` -> `a(), b()`
+    pub(crate) fn join_sequences(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if !self.options.sequences || stmts.len() < 2 {
+            return;
+        }
+        self.fold_if_return(stmts);
+        if !self.options.late
+            || !stmts.iter().any(|stmt| matches!(stmt, Statement::ExpressionStatement(_)))
+        {
+            return;
+        }
+
+        let mut new_stmts = self.ast.new_vec_with_capacity(stmts.len());
+        // The pending run of consecutive expression statements.
+        let mut run = self.ast.new_vec();
+        for stmt in stmts.drain(..) {
+            match stmt {
+                Statement::ExpressionStatement(_) => run.push(stmt),
+                Statement::ReturnStatement(mut ret) if !run.is_empty() => {
+                    if let Some(argument) = ret.argument.take() {
+                        ret.argument = Some(self.join_run(&mut run, argument));
+                    } else {
+                        self.flush_run(&mut run, &mut new_stmts);
+                    }
+                    new_stmts.push(Statement::ReturnStatement(ret));
+                }
+                Statement::ThrowStatement(mut throw) if !run.is_empty() => {
+                    let argument = self.ast.move_expression(&mut throw.argument);
+                    throw.argument = self.join_run(&mut run, argument);
+                    new_stmts.push(Statement::ThrowStatement(throw));
+                }
+                stmt => {
+                    self.flush_run(&mut run, &mut new_stmts);
+                    new_stmts.push(stmt);
+                }
+            }
+        }
+        self.flush_run(&mut run, &mut new_stmts);
+        *stmts = new_stmts;
+    }
+
+    /// `if (a) return b; return c` -> `return a ? b : c`
+    /// `if (a) return b; else return c` -> `return a ? b : c`
+    /// `if (!a) return b; return c` -> `return a ? c : b`
+    ///
+    /// Always shorter: `if()` and the second `return` are replaced by `?:`,
+    /// while a sequence in a branch costs at most two parentheses.
+    fn fold_if_return(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let mut i = 0;
+        while i < stmts.len() {
+            let Statement::IfStatement(if_stmt) = &mut stmts[i] else {
+                i += 1;
+                continue;
+            };
+            if !Self::is_return_with_argument(&if_stmt.consequent) {
+                i += 1;
+                continue;
+            }
+            let has_else_return =
+                if_stmt.alternate.as_ref().is_some_and(Self::is_return_with_argument);
+            if has_else_return {
+                let alternate = if_stmt.alternate.take().unwrap();
+                stmts[i] = self.create_conditional_return(i, stmts, alternate);
+                continue;
+            }
+            if if_stmt.alternate.is_some()
+                || !stmts.get(i + 1).is_some_and(Self::is_return_with_argument)
+            {
+                i += 1;
+                continue;
+            }
+            let alternate = stmts.remove(i + 1);
+            stmts[i] = self.create_conditional_return(i, stmts, alternate);
+        }
+    }
+
+    /// Build `return test ? consequent : alternate` from the `if` statement at `index`
+    /// and the `return` statement `alternate`.
+    fn create_conditional_return(
+        &mut self,
+        index: usize,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        alternate: Statement<'a>,
+    ) -> Statement<'a> {
+        let Statement::IfStatement(if_stmt) = &mut stmts[index] else { unreachable!() };
+        let span = if_stmt.span;
+        let test = self.ast.move_expression(&mut if_stmt.test);
+        let Statement::ReturnStatement(consequent) = &mut if_stmt.consequent else {
+            unreachable!()
+        };
+        let consequent = consequent.argument.take().unwrap();
+        let Statement::ReturnStatement(mut alternate) = alternate else { unreachable!() };
+        let alternate = alternate.argument.take().unwrap();
+        let argument = self.minimized_conditional(test, consequent, alternate);
+        self.ast.return_statement(span, Some(argument))
+    }
+
+    /// Join the expressions of `run` and `last` into a sequence expression, emptying `run`.
+    ///
+    /// Never longer than the separate statements: every `;` is replaced by a `,`,
+    /// and a sequence needs no parentheses in the argument of a `return` or `throw`.
+    fn join_run(
+        &mut self,
+        run: &mut Vec<'a, Statement<'a>>,
+        last: Expression<'a>,
+    ) -> Expression<'a> {
+        let mut expressions = self.ast.new_vec_with_capacity(run.len() + 1);
+        for stmt in run.drain(..) {
+            let Statement::ExpressionStatement(mut expr_stmt) = stmt else { unreachable!() };
+            self.push_flattened(
+                &mut expressions,
+                self.ast.move_expression(&mut expr_stmt.expression),
+            );
+        }
+        self.push_flattened(&mut expressions, last);
+        self.ast.sequence_expression(SPAN, expressions)
+    }
+
+    /// Push the pending run of expression statements to `stmts` as a single statement.
+    fn flush_run(&mut self, run: &mut Vec<'a, Statement<'a>>, stmts: &mut Vec<'a, Statement<'a>>) {
+        match run.len() {
+            0 => {}
+            1 => stmts.push(run.pop().unwrap()),
+            _ => {
+                let Some(Statement::ExpressionStatement(mut last)) = run.pop() else {
+                    unreachable!()
+                };
+                let span = last.span;
+                let last = self.ast.move_expression(&mut last.expression);
+                let expr = self.join_run(run, last);
+                stmts.push(self.ast.expression_statement(span, expr));
+            }
+        }
+    }
+
+    fn push_flattened(&self, expressions: &mut Vec<'a, Expression<'a>>, expr: Expression<'a>) {
+        match expr {
+            Expression::SequenceExpression(mut seq) => expressions.append(&mut seq.expressions),
+            Expression::ParenthesizedExpression(mut paren) => {
+                let expr = self.ast.move_expression(&mut paren.expression);
+                self.push_flattened(expressions, expr);
+            }
+            expr => expressions.push(expr),
+        }
+    }
+
+    fn is_return_with_argument(stmt: &Statement<'a>) -> bool {
+        matches!(stmt, Statement::ReturnStatement(ret) if ret.argument.is_some())
+    }
+}
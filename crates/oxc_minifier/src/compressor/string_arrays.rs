@@ -0,0 +1,92 @@
+//! String Array Splitting
+//!
+//! Long arrays of string literals are shorter as a single string split by a delimiter:
+//!
+//! ```javascript
+//! x = ["a", "b", "c", "d", "e", "f"]
+//! ```
+//!
+//! is compressed to `x = "abcdef".split("")`. The empty delimiter is used when every string is a
+//! single UTF-16 code unit, which is what `split("")` splits into, otherwise the first of ` `,
+//! `;`, `,`, `{` and `}` which no string contains, and the array is kept when every delimiter is
+//! used. These characters are common in code, so they compress well.
+//!
+//! Every element saves its quotes and its comma but costs a delimiter, which leaves two
+//! characters, and `.split("")` costs 11. The array is a literal until the other passes are done,
+//! as they fold operations on array literals such as `[a, b].length`.
+//!
+//! <https://github.com/google/closure-compiler/blob/v20240317/src/com/google/javascript/jscomp/PeepholeSubstituteAlternateSyntax.java>
+
+use oxc_allocator::Allocator;
+use oxc_ast::visit::walk_mut::walk_expression_mut;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+
+use super::SPAN;
+
+/// The delimiters tried when some string is not a single character.
+const DELIMITERS: [&str; 5] = [" ", ";", ",", "{", "}"];
+
+/// The length of `.split("")`.
+const SPLIT_OVERHEAD: usize = 11;
+
+pub struct StringArrays<'a> {
+    ast: AstBuilder<'a>,
+}
+
+impl<'a> StringArrays<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { ast: AstBuilder::new(allocator) }
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        self.visit_program(program);
+    }
+
+    /// `["a", "b", "c"]` -> `"abc".split("")`
+    fn split_string_array(&self, expr: &mut Expression<'a>) {
+        let Expression::ArrayExpression(array_expr) = expr else { return };
+        if array_expr.elements.len() * 2 <= SPLIT_OVERHEAD {
+            return;
+        }
+        let Some(strings) = array_expr
+            .elements
+            .iter()
+            .map(|element| match element {
+                ArrayExpressionElement::StringLiteral(lit) => Some(lit.value.as_str()),
+                _ => None,
+            })
+            .collect::<Option<std::vec::Vec<_>>>()
+        else {
+            return;
+        };
+        let Some(delimiter) = pick_delimiter(&strings) else { return };
+
+        let span = array_expr.span;
+        let joined = self.ast.string_literal(SPAN, &strings.join(delimiter));
+        let callee = self.ast.static_member_expression(
+            SPAN,
+            self.ast.literal_string_expression(joined),
+            IdentifierName::new(SPAN, "split".into()),
+            false,
+        );
+        let delimiter = self.ast.alloc(self.ast.string_literal(SPAN, delimiter));
+        let arguments = self.ast.new_vec_single(Argument::StringLiteral(delimiter));
+        *expr = self.ast.call_expression(span, callee, arguments, false, None);
+    }
+}
+
+impl<'a> VisitMut<'a> for StringArrays<'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        walk_expression_mut(self, expr);
+        self.split_string_array(expr);
+    }
+}
+
+/// The delimiter which no string contains, if any.
+fn pick_delimiter(strings: &[&str]) -> Option<&'static str> {
+    if strings.iter().all(|s| s.encode_utf16().count() == 1) {
+        return Some("");
+    }
+    DELIMITERS.into_iter().find(|delimiter| strings.iter().all(|s| !s.contains(delimiter)))
+}
@@ -0,0 +1,275 @@
+//! String Pooling
+//!
+//! Experimental. Declares the string literals which are repeated in a module once, in a `const`
+//! at the start of the module, and refers to the `const` instead:
+//!
+//! ```javascript
+//! el.addEventListener("pointerdown", start);
+//! el.removeEventListener("pointerdown", start);
+//! ```
+//!
+//! becomes `const a = "pointerdown"; el.addEventListener(a, start); el.removeEventListener(a, start)`.
+//!
+//! The code is shorter, but gzip already encodes a repeated string as a back-reference of a few
+//! bytes, which the name of the `const` may not beat. So the strings are pooled one by one, from
+//! the largest saving of the code size, and a string is only pooled when the [GzipEstimator]
+//! estimates that the string literals of the module and the declaration compress to fewer bytes.
+//! Estimating the strings instead of the printed module is fast, but ignores the back-references
+//! between the strings and the rest of the code.
+//!
+//! Only modules are pooled, as a `const` in a script is a global which may collide with another
+//! script. A function may be called before the module body runs when the module is in an import
+//! cycle, so the strings in functions are only pooled in modules without imports. The specifiers
+//! of `require()` and `import()` are kept for bundlers.
+
+use std::fmt::Write;
+
+use oxc_allocator::Allocator;
+use oxc_ast::visit::walk_mut::{
+    walk_arrow_expression_mut, walk_class_mut, walk_expression_mut, walk_function_mut,
+    walk_property_key_mut,
+};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder, VisitMut};
+use oxc_semantic::ScopeFlags;
+use oxc_span::Atom;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::SPAN;
+use crate::{
+    gzip::GzipEstimator,
+    mangler::{base54, is_keyword},
+};
+
+/// The most strings which are estimated, from the largest saving of the code size.
+const MAX_CANDIDATES: usize = 128;
+
+pub struct StringPool<'a> {
+    ast: AstBuilder<'a>,
+
+    /// The strings in functions can be pooled, as the module has no imports.
+    pool_in_functions: bool,
+    /// The depth of the functions and classes being visited.
+    function_depth: usize,
+
+    /// The names in the module, which the `const`s must not shadow.
+    names: FxHashSet<Atom<'a>>,
+    /// The string literals which can be pooled, in the order of the module.
+    strings: std::vec::Vec<Atom<'a>>,
+    /// The `const`s of the pooled strings, in the order of their declaration.
+    pool: std::vec::Vec<(Atom<'a>, Atom<'a>)>,
+    /// The name of each pooled string.
+    pooled: FxHashMap<Atom<'a>, Atom<'a>>,
+    /// The next name which may be free.
+    next_name: usize,
+}
+
+impl<'a> StringPool<'a> {
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            pool_in_functions: false,
+            function_depth: 0,
+            names: FxHashSet::default(),
+            strings: vec![],
+            pool: vec![],
+            pooled: FxHashMap::default(),
+            next_name: 0,
+        }
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        if !program.source_type.is_module() {
+            return;
+        }
+        self.pool_in_functions = !program.body.iter().any(|stmt| match stmt {
+            Statement::ImportDeclaration(_) | Statement::ExportAllDeclaration(_) => true,
+            Statement::ExportNamedDeclaration(decl) => decl.source.is_some(),
+            _ => false,
+        });
+        // Collect the strings, nothing is pooled yet.
+        self.visit_program(program);
+        self.choose_pool();
+        if self.pool.is_empty() {
+            return;
+        }
+        // Replace the pooled strings.
+        self.visit_program(program);
+        self.declare_pool(program);
+    }
+
+    /// Pool the strings which make the code shorter and are estimated to compress better.
+    fn choose_pool(&mut self) {
+        let mut counts = FxHashMap::<&Atom<'a>, usize>::default();
+        for string in &self.strings {
+            *counts.entry(string).or_default() += 1;
+        }
+        let mut candidates = counts
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(string, count)| (string.clone(), count))
+            .collect::<std::vec::Vec<_>>();
+        // Sorted by the string too, so that the output does not depend on the hash map.
+        candidates.sort_unstable_by(|(a, a_count), (b, b_count)| {
+            raw_saving(b, *b_count)
+                .cmp(&raw_saving(a, *a_count))
+                .then_with(|| a.as_str().cmp(b.as_str()))
+        });
+
+        let mut estimator = GzipEstimator::default();
+        let mut size = estimator.estimate(&self.model());
+        for (string, count) in candidates.into_iter().take(MAX_CANDIDATES) {
+            let name = self.free_name();
+            if raw_saving(&string, count) <= pool_cost(&name, count) {
+                continue;
+            }
+            self.pool.push((string.clone(), name.clone()));
+            self.pooled.insert(string.clone(), name);
+            let pooled_size = estimator.estimate(&self.model());
+            if pooled_size < size {
+                size = pooled_size;
+                self.next_name += 1;
+            } else {
+                self.pool.pop();
+                self.pooled.remove(&string);
+            }
+        }
+    }
+
+    /// The string literals of the module and the declaration of the pool, separated by commas.
+    fn model(&self) -> String {
+        let mut text = String::new();
+        if !self.pool.is_empty() {
+            text.push_str("const ");
+            for (string, name) in &self.pool {
+                let _ = write!(text, "{name}={string:?},");
+            }
+        }
+        for string in &self.strings {
+            match self.pooled.get(string) {
+                Some(name) => text.push_str(name),
+                None => {
+                    let _ = write!(text, "{string:?}");
+                }
+            }
+            text.push(',');
+        }
+        text
+    }
+
+    /// The shortest name which is not used in the module, from `next_name`.
+    fn free_name(&mut self) -> Atom<'a> {
+        loop {
+            let name = base54(self.next_name);
+            if !is_keyword(&name) && !self.names.contains(name.as_str()) {
+                return self.ast.new_atom(&name);
+            }
+            self.next_name += 1;
+        }
+    }
+
+    /// `const a = "pointerdown", b = "pointerup"` after the imports.
+    fn declare_pool(&mut self, program: &mut Program<'a>) {
+        let mut declarations = self.ast.new_vec_with_capacity(self.pool.len());
+        for (string, name) in self.pool.drain(..) {
+            let id = self.ast.binding_pattern(
+                self.ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, name)),
+                None,
+                false,
+            );
+            let init = self.ast.literal_string_expression(self.ast.string_literal(SPAN, &string));
+            declarations.push(self.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Const,
+                id,
+                Some(init),
+                false,
+            ));
+        }
+        let decl = self.ast.variable_declaration(
+            SPAN,
+            VariableDeclarationKind::Const,
+            declarations,
+            Modifiers::empty(),
+        );
+        let index = program
+            .body
+            .iter()
+            .position(|stmt| !matches!(stmt, Statement::ImportDeclaration(_)))
+            .unwrap_or(program.body.len());
+        program.body.insert(index, Statement::VariableDeclaration(decl));
+    }
+}
+
+/// The bytes saved by writing the quoted `string` once instead of `count` times.
+fn raw_saving(string: &str, count: usize) -> usize {
+    (string.len() + 2) * (count - 1)
+}
+
+/// The bytes of `name` in the declaration, `name=` and `,`, and in place of the `count` strings.
+fn pool_cost(name: &str, count: usize) -> usize {
+    name.len() * (count + 1) + 2
+}
+
+impl<'a> VisitMut<'a> for StringPool<'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::StringLiteral(lit) => {
+                if self.function_depth > 0 && !self.pool_in_functions {
+                    return;
+                }
+                if self.pooled.is_empty() {
+                    self.strings.push(lit.value.clone());
+                } else if let Some(name) = self.pooled.get(&lit.value) {
+                    let ident = self.ast.identifier_reference(lit.span, name);
+                    *expr = self.ast.identifier_reference_expression(ident);
+                }
+            }
+            // `require("m")`
+            Expression::CallExpression(call_expr) if matches!(&call_expr.callee, Expression::Identifier(ident) if ident.name == "require") =>
+            {
+                self.visit_expression(&mut call_expr.callee);
+            }
+            // `import("m")`
+            Expression::ImportExpression(_) => {}
+            _ => walk_expression_mut(self, expr),
+        }
+    }
+
+    fn visit_property_key(&mut self, key: &mut PropertyKey<'a>) {
+        // `{ "a": 1 }` is a name, not a value.
+        if !matches!(key, PropertyKey::StringLiteral(_)) {
+            walk_property_key_mut(self, key);
+        }
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: Option<ScopeFlags>) {
+        self.function_depth += 1;
+        walk_function_mut(self, func, flags);
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &mut ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk_arrow_expression_mut(self, expr);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        self.function_depth += 1;
+        walk_class_mut(self, class);
+        self.function_depth -= 1;
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier<'a>) {
+        self.names.insert(ident.name.clone());
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference<'a>) {
+        self.names.insert(ident.name.clone());
+    }
+
+    fn visit_jsx_identifier(&mut self, ident: &mut JSXIdentifier<'a>) {
+        self.names.insert(ident.name.clone());
+    }
+}
@@ -0,0 +1,267 @@
+//! Switch Statements from `else if` Chains
+//!
+//! An `else if` chain which compares the same variable with literals becomes a `switch` statement:
+//!
+//! ```javascript
+//! if (x === 1) a(); else if (x === 2 || x === 3) b(); else c()
+//! ```
+//!
+//! is compressed to `switch (x) { case 1: a(); break; case 2: case 3: b(); break; default: c() }`.
+//! `switch` compares with `===` like the tests, and the literals are compared in the same order.
+//! The variable is read once instead of once for each test, so a getter on the global object
+//! would observe the difference, which is why this is opt-in.
+//!
+//! The `switch` statement needs a `break` for each branch which does not exit, so it is only
+//! shorter for long chains or branches which `return`, and the size of both forms is estimated
+//! to pick the shorter one. A branch with an unlabeled `break` is never moved into the `switch`,
+//! where the `break` would end the `switch` instead of the enclosing loop.
+//!
+//! A lookup object, `x = { a: 1, b: 2 }[y]`, is not used for chains of assignments, as it would
+//! also find the properties of `Object.prototype` such as `y = "toString"`.
+
+use oxc_allocator::Vec;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, visit::walk::walk_statement, Visit};
+use oxc_span::Atom;
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator};
+
+use super::{Compressor, SPAN};
+
+/// A branch of an `else if` chain.
+struct Branch<'b, 'a> {
+    /// The literals which the variable is compared with.
+    values: std::vec::Vec<&'b Expression<'a>>,
+    consequent: &'b Statement<'a>,
+}
+
+/// The `else if` chain of an `if` statement which can become a `switch` statement.
+struct IfChain<'b, 'a> {
+    name: &'b Atom<'a>,
+    branches: std::vec::Vec<Branch<'b, 'a>>,
+    /// The final `else` branch, or the rest of the chain which does not compare the variable.
+    default: Option<&'b Statement<'a>>,
+}
+
+impl<'a> Compressor<'a> {
+    /// Enabled by `compress.switches`
+    ///
+    /// `if (x === 1) a(); else if (x === 2) b(); else c()` ->
+    /// `switch (x) { case 1: a(); break; case 2: b(); break; default: c() }`
+    pub(crate) fn fold_if_chain_to_switch(&mut self, stmt: &mut Statement<'a>) {
+        if !self.options.switches {
+            return;
+        }
+        let Statement::IfStatement(if_stmt) = stmt else { return };
+        let Some(chain) = Self::collect_if_chain(if_stmt) else { return };
+        if !Self::is_switch_shorter(&chain) {
+            return;
+        }
+        let name = chain.name.clone();
+        let branch_count = chain.branches.len();
+
+        let span = if_stmt.span;
+        let mut cases = self.ast.new_vec();
+        let mut next = Some(self.ast.move_statement(stmt));
+        for _ in 0..branch_count {
+            let Some(Statement::IfStatement(mut if_stmt)) = next else { unreachable!() };
+            let mut values = vec![];
+            self.move_case_values(&mut if_stmt.test, &mut values);
+            let last = values.pop().unwrap();
+            for value in values {
+                cases.push(self.ast.switch_case(SPAN, Some(value), self.ast.new_vec()));
+            }
+            let consequent = self.ast.move_statement(&mut if_stmt.consequent);
+            let exits = Self::always_exits(&consequent);
+            let mut consequent = self.case_consequent(consequent);
+            if !exits {
+                consequent.push(self.ast.break_statement(SPAN, None));
+            }
+            cases.push(self.ast.switch_case(SPAN, Some(last), consequent));
+            next = if_stmt.alternate.take();
+        }
+        if let Some(default) = next {
+            let consequent = self.case_consequent(default);
+            cases.push(self.ast.switch_case(SPAN, None, consequent));
+        }
+        let discriminant =
+            self.ast.identifier_reference_expression(IdentifierReference::new(SPAN, name));
+        *stmt = self.ast.switch_statement(span, discriminant, cases);
+    }
+
+    /// The `else if` chain of `if_stmt`, if it compares a variable in at least two branches.
+    fn collect_if_chain<'b>(if_stmt: &'b IfStatement<'a>) -> Option<IfChain<'b, 'a>> {
+        let mut name: Option<&Atom> = None;
+        let mut branches = vec![];
+        let mut current = if_stmt;
+        // The statement of `current`, when it is the `else` branch of the previous branch.
+        let mut current_stmt = None;
+        let default = loop {
+            let mut values = vec![];
+            let Some(ident) = Self::collect_comparisons(&current.test, &mut values)
+                .filter(|ident| name.map_or(true, |name| *name == ident.name))
+            else {
+                break current_stmt;
+            };
+            if has_unlabeled_break(&current.consequent) {
+                return None;
+            }
+            name = Some(&ident.name);
+            branches.push(Branch { values, consequent: &current.consequent });
+            match &current.alternate {
+                Some(stmt @ Statement::IfStatement(alternate)) => {
+                    current = alternate;
+                    current_stmt = Some(stmt);
+                }
+                alternate => break alternate.as_ref(),
+            }
+        };
+        if branches.len() < 2 || default.is_some_and(has_unlabeled_break) {
+            return None;
+        }
+        Some(IfChain { name: name?, branches, default })
+    }
+
+    /// Collect the literals of `x === 1 || x === 2` into `values`, returns `x`.
+    fn collect_comparisons<'b>(
+        test: &'b Expression<'a>,
+        values: &mut std::vec::Vec<&'b Expression<'a>>,
+    ) -> Option<&'b IdentifierReference<'a>> {
+        match test {
+            Expression::LogicalExpression(logical_expr)
+                if logical_expr.operator == LogicalOperator::Or =>
+            {
+                let left = Self::collect_comparisons(&logical_expr.left, values)?;
+                let right = Self::collect_comparisons(&logical_expr.right, values)?;
+                (left.name == right.name).then_some(left)
+            }
+            Expression::BinaryExpression(binary_expr)
+                if binary_expr.operator == BinaryOperator::StrictEquality =>
+            {
+                match (&binary_expr.left, &binary_expr.right) {
+                    (Expression::Identifier(ident), value)
+                    | (value, Expression::Identifier(ident))
+                        if is_case_literal(value) =>
+                    {
+                        values.push(value);
+                        Some(ident)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Move the literals of a test accepted by [Self::collect_comparisons] into `values`.
+    fn move_case_values(
+        &mut self,
+        test: &mut Expression<'a>,
+        values: &mut std::vec::Vec<Expression<'a>>,
+    ) {
+        match test {
+            Expression::LogicalExpression(logical_expr) => {
+                self.move_case_values(&mut logical_expr.left, values);
+                self.move_case_values(&mut logical_expr.right, values);
+            }
+            Expression::BinaryExpression(binary_expr) => {
+                let value = if is_case_literal(&binary_expr.left) {
+                    &mut binary_expr.left
+                } else {
+                    &mut binary_expr.right
+                };
+                values.push(self.ast.move_expression(value));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// The statements of the case for `stmt`. The statements of a block are moved into the case,
+    /// unless they declare block scoped bindings, which would be shared with the other cases.
+    fn case_consequent(&mut self, stmt: Statement<'a>) -> Vec<'a, Statement<'a>> {
+        match stmt {
+            Statement::BlockStatement(mut block)
+                if !block.body.iter().any(Self::is_block_scoped_declaration) =>
+            {
+                let mut consequent = self.ast.new_vec_with_capacity(block.body.len() + 1);
+                consequent.extend(block.body.drain(..));
+                consequent
+            }
+            stmt => self.ast.new_vec_single(stmt),
+        }
+    }
+
+    /// Whether the `switch` statement is estimated to be shorter than the `else if` chain.
+    /// The consequents are the same in both, apart from the braces of blocks.
+    fn is_switch_shorter(chain: &IfChain<'_, 'a>) -> bool {
+        let name = chain.name.len();
+        let default = usize::from(chain.default.is_some());
+        // `switch(x){}` and `default:`
+        let mut switch_size = 10 + name + 8 * default;
+        // `else ` before every `if` but the first, and before the final `else` branch
+        let mut chain_size = 5 * (chain.branches.len() - 1 + default);
+        for branch in &chain.branches {
+            for value in &branch.values {
+                let value = literal_size(value);
+                // `case 1:`, and `x===1||`
+                switch_size += 6 + value;
+                chain_size += name + 3 + value + 2;
+            }
+            // `if()`, without the `||` after the last value
+            chain_size += 2;
+            if !Self::always_exits(branch.consequent) {
+                // `break;`
+                switch_size += 6;
+            }
+            if matches!(branch.consequent, Statement::BlockStatement(block) if block.body.len() > 1)
+            {
+                // `{}`
+                chain_size += 2;
+            }
+        }
+        switch_size < chain_size
+    }
+}
+
+/// A literal whose value is compared by `===` like by `switch`, without side effects.
+fn is_case_literal(expr: &Expression) -> bool {
+    matches!(expr, Expression::StringLiteral(_) | Expression::NumericLiteral(_))
+}
+
+/// The printed size of a literal accepted by [is_case_literal].
+fn literal_size(expr: &Expression) -> usize {
+    match expr {
+        Expression::StringLiteral(lit) => lit.value.len() + 2,
+        Expression::NumericLiteral(lit) => lit.raw.len(),
+        _ => unreachable!(),
+    }
+}
+
+/// Whether `stmt` contains a `break` without a label which is not inside of a nested loop or
+/// `switch` statement, and would end a `switch` statement around `stmt`.
+fn has_unlabeled_break(stmt: &Statement) -> bool {
+    let mut finder = BreakFinder::default();
+    finder.visit_statement(stmt);
+    finder.found
+}
+
+#[derive(Default)]
+struct BreakFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for BreakFinder {
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        match stmt {
+            Statement::BreakStatement(break_stmt) if break_stmt.label.is_none() => {
+                self.found = true;
+            }
+            Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_)
+            | Statement::SwitchStatement(_) => {}
+            stmt => walk_statement(self, stmt),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Typeof Checks
+//!
+//! Shortens the checks for `undefined` and `null` which `compress_typeof_undefined` leaves as two
+//! comparisons, after `typeof foo == "undefined"` became `void 0 === foo`:
+//!
+//! * `typeof foo == "undefined" || foo === null` -> `foo == null`
+//! * `typeof foo != "undefined" && foo !== null` -> `foo != null`
+//!
+//! These only apply to a declared `foo`, which is read once instead of twice. `document.all` is
+//! the only value which is `== null` without being `null` or `undefined`, and is ignored.
+//!
+//! The cases of `switch (typeof foo)` which can never match, such as `case "strnig":`, are
+//! removed like `typeof foo == "strnig"` is folded to `false`. The statements of a removed case
+//! are still reached by falling through from the previous case, so they are moved there.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_span::Span;
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+
+use super::{comparisons::TYPEOF_RESULTS, dead_code::declares_hoisted_binding, Compressor};
+
+/// A comparison of an identifier with `undefined` or `null`.
+struct NullishCheck<'b, 'a> {
+    span: Span,
+    ident: &'b IdentifierReference<'a>,
+    /// `== null` and `== undefined` match both `null` and `undefined`.
+    loose: bool,
+    null: bool,
+    negated: bool,
+}
+
+impl<'a> Compressor<'a> {
+    /// Enabled by `compress.typeofs`
+    ///
+    /// `void 0 === foo || foo === null` -> `foo == null`
+    pub(crate) fn fold_nullish_checks(&mut self, expr: &mut Expression<'a>) {
+        if !self.options.typeofs {
+            return;
+        }
+        let Expression::LogicalExpression(logical_expr) = expr else { return };
+        let logical_expr = &mut **logical_expr;
+        if let Some(folded) =
+            self.fold_nullish_pair(&logical_expr.left, &logical_expr.right, logical_expr.operator)
+        {
+            *expr = folded;
+            return;
+        }
+        // `a || void 0 === foo || foo === null` is `(a || void 0 === foo) || foo === null`
+        let operator = logical_expr.operator;
+        let Expression::LogicalExpression(left) = &mut logical_expr.left else { return };
+        if left.operator != operator {
+            return;
+        }
+        if let Some(folded) = self.fold_nullish_pair(&left.right, &logical_expr.right, operator) {
+            left.right = folded;
+            *expr = self.ast.move_expression(&mut logical_expr.left);
+        }
+    }
+
+    fn fold_nullish_pair(
+        &self,
+        left: &Expression<'a>,
+        right: &Expression<'a>,
+        operator: LogicalOperator,
+    ) -> Option<Expression<'a>> {
+        let negated = match operator {
+            LogicalOperator::Or => false,
+            LogicalOperator::And => true,
+            LogicalOperator::Coalesce => return None,
+        };
+        let left = self.nullish_check(left)?;
+        let right = self.nullish_check(right)?;
+        if left.ident.name != right.ident.name
+            || left.negated != negated
+            || right.negated != negated
+            || (left.null == right.null && !left.loose && !right.loose)
+        {
+            return None;
+        }
+        let span = left.span.merge(&right.span);
+        let ident = self.ast.identifier_reference_expression(left.ident.clone());
+        let operator = if negated { BinaryOperator::Inequality } else { BinaryOperator::Equality };
+        let null = self.ast.literal_null_expression(NullLiteral::new(span));
+        Some(self.ast.binary_expression(span, ident, operator, null))
+    }
+
+    /// `foo === null`, `void 0 !== foo` or `foo == void 0`, for a declared `foo`.
+    fn nullish_check<'b>(&self, expr: &'b Expression<'a>) -> Option<NullishCheck<'b, 'a>> {
+        let Expression::BinaryExpression(binary_expr) = expr else { return None };
+        let (loose, negated) = match binary_expr.operator {
+            BinaryOperator::Equality => (true, false),
+            BinaryOperator::Inequality => (true, true),
+            BinaryOperator::StrictEquality => (false, false),
+            BinaryOperator::StrictInequality => (false, true),
+            _ => return None,
+        };
+        let ((Expression::Identifier(ident), value) | (value, Expression::Identifier(ident))) =
+            (&binary_expr.left, &binary_expr.right)
+        else {
+            return None;
+        };
+        // `undefined` was already compressed to `void 0`.
+        let null = if value.is_null() {
+            true
+        } else if value.is_void_0() {
+            false
+        } else {
+            return None;
+        };
+        // Created identifiers are not resolved.
+        if ident.reference_id.get().is_none() || self.is_global_reference(ident) {
+            return None;
+        }
+        Some(NullishCheck { span: binary_expr.span, ident, loose, null, negated })
+    }
+
+    /// Enabled by `compress.typeofs`
+    ///
+    /// `switch (typeof foo) { case "strnig": a(); case "string": b() }` ->
+    /// `switch (typeof foo) { case "string": b() }`
+    pub(crate) fn remove_impossible_typeof_cases(&mut self, stmt: &mut Statement<'a>) {
+        if !self.options.typeofs {
+            return;
+        }
+        let Statement::SwitchStatement(switch_stmt) = stmt else { return };
+        let Expression::UnaryExpression(discriminant) = &switch_stmt.discriminant else { return };
+        if discriminant.operator != UnaryOperator::Typeof {
+            return;
+        }
+        let cases = &mut switch_stmt.cases;
+        let mut i = 0;
+        while i < cases.len() {
+            if !is_impossible_typeof_case(&cases[i]) {
+                i += 1;
+                continue;
+            }
+            if i == 0 {
+                // Nothing falls through to the first case, so its statements never run.
+                if has_declarations(&cases[0].consequent) {
+                    i += 1;
+                    continue;
+                }
+                cases.remove(0);
+                continue;
+            }
+            let case = cases.remove(i);
+            cases[i - 1].consequent.extend(case.consequent);
+        }
+    }
+}
+
+/// `case "strnig":`, which `typeof` never returns.
+fn is_impossible_typeof_case(case: &SwitchCase) -> bool {
+    let Some(Expression::StringLiteral(lit)) = &case.test else { return false };
+    !TYPEOF_RESULTS.contains(&lit.value.as_str())
+}
+
+/// Whether removing `stmts` removes a binding which other cases of the switch may reference.
+fn has_declarations(stmts: &[Statement]) -> bool {
+    stmts.iter().any(|stmt| stmt.is_declaration() || declares_hoisted_binding(stmt))
+}
@@ -0,0 +1,111 @@
+//! Unused Import Removal
+//!
+//! Removes the import specifiers whose binding is never referenced, which is common after
+//! `compress.dead_code` removed the branches using them:
+//!
+//! ```javascript
+//! import { devWarn, log } from "./debug";
+//! if (false) devWarn();
+//! log();
+//! ```
+//!
+//! is compressed to `import { log } from "./debug"; log()`. When every specifier of an import is
+//! removed, the module is still imported for its side effects, `import "./debug"`, unless it is
+//! one of `compress.pure_modules` or `compress.unsafe_imports` assumes it has none, and the whole
+//! import is removed. `import "m"` of a pure module is removed as well, while the same import of
+//! any other module, such as a polyfill, is kept.
+//!
+//! References are resolved by a new [`SemanticBuilder`] after the other passes, so that the
+//! module record and the references agree with the compressed program. Bindings which are
+//! exported, `import { a } from "m"; export { a }`, are kept, and nothing is removed when the
+//! program contains a direct `eval` or a `with` statement, which can reference any binding.
+
+use oxc_allocator::Allocator;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstBuilder};
+use oxc_semantic::{SemanticBuilder, SymbolId};
+use rustc_hash::FxHashSet;
+
+use super::CompressOptions;
+
+pub struct UnusedImports<'a> {
+    ast: AstBuilder<'a>,
+    options: CompressOptions,
+    /// Imported bindings which are never referenced.
+    unused: FxHashSet<SymbolId>,
+}
+
+impl<'a> UnusedImports<'a> {
+    pub fn new(allocator: &'a Allocator, options: CompressOptions) -> Self {
+        Self { ast: AstBuilder::new(allocator), options, unused: FxHashSet::default() }
+    }
+
+    pub fn build(&mut self, program: &mut Program<'a>) {
+        if !program.body.iter().any(|stmt| matches!(stmt, Statement::ImportDeclaration(_))) {
+            return;
+        }
+        {
+            let semantic = SemanticBuilder::new("", program.source_type).build(program).semantic;
+            let (symbols, scopes) = (semantic.symbols(), semantic.scopes());
+            if scopes.get_flags(scopes.root_scope_id()).contains_direct_eval() {
+                return;
+            }
+            self.unused = symbols
+                .iter()
+                .filter(|&symbol_id| {
+                    let flags = symbols.get_flag(symbol_id);
+                    flags.is_import_binding()
+                        && !flags.is_export()
+                        && symbols.get_resolved_reference_count(symbol_id) == 0
+                })
+                .collect();
+        }
+        if self.unused.is_empty() && self.options.pure_modules.is_empty() {
+            return;
+        }
+
+        let mut body = self.ast.new_vec_with_capacity(program.body.len());
+        for stmt in program.body.drain(..) {
+            if let Statement::ImportDeclaration(mut decl) = stmt {
+                if self.remove_unused_specifiers(&mut decl) {
+                    continue;
+                }
+                body.push(Statement::ImportDeclaration(decl));
+            } else {
+                body.push(stmt);
+            }
+        }
+        program.body = body;
+    }
+
+    /// Returns `true` if every specifier of `decl` is removed and the whole import can be removed.
+    fn remove_unused_specifiers(&self, decl: &mut ImportDeclaration<'a>) -> bool {
+        if !decl.import_kind.is_value() {
+            return false;
+        }
+        let is_pure = self.options.is_pure_module(&decl.source.value);
+        let Some(specifiers) = &mut decl.specifiers else { return is_pure };
+        if specifiers.is_empty() {
+            return is_pure;
+        }
+        specifiers.retain(|specifier| {
+            let local = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    if !specifier.import_kind.is_value() {
+                        return true;
+                    }
+                    &specifier.local
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => &specifier.local,
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => &specifier.local,
+            };
+            !local.symbol_id.get().is_some_and(|symbol_id| self.unused.contains(&symbol_id))
+        });
+        if !specifiers.is_empty() {
+            return false;
+        }
+        // Keep the module import, `import "m"`, for its side effects.
+        decl.specifiers = None;
+        is_pure || self.options.unsafe_imports
+    }
+}
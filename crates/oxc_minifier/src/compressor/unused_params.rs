@@ -0,0 +1,214 @@
+//! Parameter and Argument Pruning
+//!
+//! Removes the unused trailing parameters of local functions, and the arguments which are
+//! ignored by them:
+//!
+//! ```javascript
+//! function f(a, b) { return a }
+//! f(1, 2);
+//! f(3, void 0);
+//! ```
+//!
+//! is compressed to `function f(a) { return a } f(1); f(3);`.
+//!
+//! * A parameter is removed when it is never referenced and no parameter after it is kept.
+//! * An argument past the last parameter is removed unless it has side effects, and trailing
+//!   `undefined` arguments are removed because a missing argument is `undefined` as well.
+//!
+//! Only functions bound to a local binding, which is never reassigned and only ever called
+//! directly as `f(...)`, are changed, so that every call site is known. Functions which read
+//! `arguments`, have a rest or non-identifier parameter, or can be reached by a direct `eval`,
+//! are left alone.
+
+use oxc_allocator::Vec;
+use oxc_ast::visit::walk_mut::{
+    walk_call_expression_mut, walk_function_mut, walk_variable_declarator_mut,
+};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstKind, VisitMut};
+use oxc_semantic::{ScopeFlags, Semantic, SemanticBuilder, SymbolId, SymbolTable};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use super::ast_util::MayHaveSideEffects;
+
+#[derive(Default)]
+pub struct UnusedParams {
+    symbols: SymbolTable,
+    /// Number of parameters kept, for each function binding whose call sites are all known.
+    arities: FxHashMap<SymbolId, usize>,
+}
+
+impl UnusedParams {
+    pub fn build(&mut self, program: &mut Program<'_>) {
+        let semantic = SemanticBuilder::new("", program.source_type).build(program).semantic;
+        let arguments_spans = arguments_spans(&semantic);
+        self.arities = semantic
+            .symbols()
+            .iter()
+            .filter_map(|symbol_id| {
+                arity(&semantic, symbol_id, &arguments_spans).map(|arity| (symbol_id, arity))
+            })
+            .collect();
+        if self.arities.is_empty() {
+            return;
+        }
+        self.symbols = semantic.into_symbol_table_and_scope_tree().0;
+        self.visit_program(program);
+    }
+
+    fn binding_arity(&self, ident: Option<&BindingIdentifier>) -> Option<usize> {
+        let symbol_id = ident?.symbol_id.get()?;
+        self.arities.get(&symbol_id).copied()
+    }
+
+    fn callee_arity(&self, callee: &Expression) -> Option<usize> {
+        let Expression::Identifier(ident) = callee else { return None };
+        let symbol_id = self.symbols.get_reference(ident.reference_id.get()?).symbol_id()?;
+        self.arities.get(&symbol_id).copied()
+    }
+
+    fn is_undefined(&self, arg: &Argument) -> bool {
+        match arg {
+            Argument::Identifier(ident) if ident.name == "undefined" => {
+                ident.reference_id.get().is_some_and(|id| self.symbols.is_global_reference(id))
+            }
+            arg => arg.as_expression().is_some_and(Expression::is_void_0),
+        }
+    }
+
+    fn prune_arguments(&self, arguments: &mut Vec<Argument>, arity: usize) {
+        // A spread argument moves the arguments after it to unknown positions.
+        if arguments.iter().take(arity).any(Argument::is_spread) {
+            return;
+        }
+        let mut i = arity;
+        while i < arguments.len() {
+            let has_side_effects = match &arguments[i] {
+                Argument::SpreadElement(_) => true,
+                arg => arg.as_expression().is_some_and(MayHaveSideEffects::may_have_side_effects),
+            };
+            if has_side_effects {
+                i += 1;
+            } else {
+                arguments.remove(i);
+            }
+        }
+        while arguments.last().is_some_and(|arg| self.is_undefined(arg)) {
+            arguments.pop();
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for UnusedParams {
+    fn visit_function(&mut self, func: &mut Function<'a>, flags: Option<ScopeFlags>) {
+        if let Some(arity) = self.binding_arity(func.id.as_ref()) {
+            func.params.items.truncate(arity);
+        }
+        walk_function_mut(self, func, flags);
+    }
+
+    fn visit_variable_declarator(&mut self, declarator: &mut VariableDeclarator<'a>) {
+        if let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind {
+            if let Some(arity) = self.binding_arity(Some(ident)) {
+                match &mut declarator.init {
+                    Some(Expression::FunctionExpression(func)) => {
+                        func.params.items.truncate(arity);
+                    }
+                    Some(Expression::ArrowFunctionExpression(arrow)) => {
+                        arrow.params.items.truncate(arity);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        walk_variable_declarator_mut(self, declarator);
+    }
+
+    fn visit_call_expression(&mut self, expr: &mut CallExpression<'a>) {
+        if let Some(arity) = self.callee_arity(&expr.callee) {
+            self.prune_arguments(&mut expr.arguments, arity);
+        }
+        walk_call_expression_mut(self, expr);
+    }
+}
+
+/// Spans of the references to `arguments`.
+fn arguments_spans(semantic: &Semantic) -> std::vec::Vec<Span> {
+    let symbols = semantic.symbols();
+    semantic
+        .scopes()
+        .root_unresolved_references()
+        .get("arguments")
+        .map_or_else(std::vec::Vec::new, |reference_ids| {
+            reference_ids.iter().map(|&id| symbols.get_reference(id).span()).collect()
+        })
+}
+
+/// The number of parameters to keep, if `symbol_id` is a function binding whose call sites are
+/// all known.
+fn arity(semantic: &Semantic, symbol_id: SymbolId, arguments_spans: &[Span]) -> Option<usize> {
+    let symbols = semantic.symbols();
+    let scopes = semantic.scopes();
+    let nodes = semantic.nodes();
+
+    let declaration = symbols.get_declaration(symbol_id);
+    let (span, params) = match nodes.kind(declaration) {
+        // Function declarations in blocks are also bound in the enclosing function, by Annex B.
+        AstKind::Function(func)
+            if func.is_function_declaration()
+                && nodes.parent_kind(declaration).is_some_and(|parent| {
+                    matches!(parent, AstKind::Program(_) | AstKind::FunctionBody(_))
+                }) =>
+        {
+            (func.span, &func.params)
+        }
+        AstKind::VariableDeclarator(declarator) if !declarator.kind.is_var() => {
+            match &declarator.init {
+                Some(Expression::FunctionExpression(func)) if func.id.is_none() => {
+                    (func.span, &func.params)
+                }
+                Some(Expression::ArrowFunctionExpression(arrow)) => (arrow.span, &arrow.params),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+
+    let scope_id = symbols.get_scope_id(symbol_id);
+    if scopes.get_flags(scope_id).contains_direct_eval()
+        || symbols.get_flag(symbol_id).is_export()
+        // Top-level bindings of a script can be called by other scripts.
+        || (scopes.get_parent_id(scope_id).is_none() && !semantic.source_type().is_module())
+        || params.rest.is_some()
+        || arguments_spans.iter().any(|arguments| span.start <= arguments.start && arguments.end <= span.end)
+    {
+        return None;
+    }
+
+    let is_called_directly = symbols.get_resolved_reference_ids(symbol_id).all(|id| {
+        let reference = symbols.get_reference(id);
+        reference.is_read()
+            && !reference.is_write()
+            && matches!(
+                nodes.parent_kind(reference.node_id()),
+                Some(AstKind::CallExpression(call)) if call.callee.span() == reference.span()
+            )
+    });
+    if !is_called_directly {
+        return None;
+    }
+
+    let mut arity = 0;
+    for (i, param) in params.items.iter().enumerate() {
+        let BindingPatternKind::BindingIdentifier(ident) = &param.pattern.kind else {
+            return None;
+        };
+        let is_used =
+            ident.symbol_id.get().map_or(true, |id| symbols.get_resolved_reference_count(id) > 0);
+        if is_used {
+            arity = i + 1;
+        }
+    }
+    Some(arity)
+}
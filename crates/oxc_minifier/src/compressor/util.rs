@@ -1,10 +1,13 @@
-use oxc_ast::ast::Expression;
-
-pub(super) fn is_console(expr: &Expression<'_>) -> bool {
-    // let Statement::ExpressionStatement(expr) = stmt else { return false };
-    let Expression::CallExpression(call_expr) = &expr else { return false };
-    let Some(member_expr) = call_expr.callee.as_member_expression() else { return false };
-    let obj = member_expr.object();
-    let Some(ident) = obj.get_identifier_reference() else { return false };
-    ident.name == "console"
+/// Whether `text` matches the glob `pattern`, where `*` matches any characters.
+pub(super) fn matches_glob(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(mut rest) = text.strip_prefix(parts.next().unwrap_or_default()) else {
+        return false;
+    };
+    let Some(last) = parts.next_back() else { return rest.is_empty() };
+    for part in parts {
+        let Some(index) = rest.find(part) else { return false };
+        rest = &rest[index + part.len()..];
+    }
+    rest.ends_with(last)
 }
@@ -0,0 +1,116 @@
+//! Gzip Size Estimation
+//!
+//! Estimates the size of text after DEFLATE compression, as used by gzip, without compressing it,
+//! for the compressions which trade the raw size for the compressed size. For example, pooling a
+//! repeated string into a variable makes the code shorter, but gzip already encodes the repeated
+//! string as a back-reference of a few bytes, which the name of the variable may not beat.
+//!
+//! The text is parsed greedily into literal bytes and back-references of 3 to 258 bytes within
+//! the last 32 KiB like DEFLATE, with a hash table of the last position of each 3 bytes instead of
+//! hash chains. The literals cost their order-0 entropy, and the back-references the extra bits of
+//! their length and distance codes plus an average cost of their Huffman codes. The estimate is
+//! meant for comparing two versions of a text, rather than predicting the size of one.
+//!
+//! <https://www.rfc-editor.org/rfc/rfc1951#section-3.2.5>
+
+/// The maximum distance of a back-reference.
+const WINDOW: usize = 32 * 1024;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+const HASH_BITS: u32 = 15;
+/// The average bits of the Huffman codes of the length and distance of a back-reference.
+const MATCH_CODE_BITS: f64 = 12.0;
+
+/// Estimates the size of text after gzip compression, see the [module docs](self).
+///
+/// Reuse one for many estimates, as it keeps its hash table.
+#[derive(Debug, Clone)]
+pub struct GzipEstimator {
+    /// The position after the last occurrence of each hash of 3 bytes, 0 for none.
+    head: Vec<usize>,
+}
+
+impl Default for GzipEstimator {
+    fn default() -> Self {
+        Self { head: vec![0; 1 << HASH_BITS] }
+    }
+}
+
+impl GzipEstimator {
+    /// The estimated size of `text` after compression in bytes, without the gzip header.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn estimate(&mut self, text: &str) -> usize {
+        let bytes = text.as_bytes();
+        self.head.fill(0);
+        let mut literal_counts = [0usize; 256];
+        let mut match_bits = 0.0;
+        let mut pos = 0;
+        while pos < bytes.len() {
+            let Some((length, distance)) = self.longest_match(bytes, pos) else {
+                literal_counts[usize::from(bytes[pos])] += 1;
+                pos += 1;
+                continue;
+            };
+            match_bits += MATCH_CODE_BITS
+                + f64::from(length_extra_bits(length) + distance_extra_bits(distance));
+            for next in pos + 1..pos + length {
+                self.insert(bytes, next);
+            }
+            pos += length;
+        }
+        let bits = match_bits + entropy_bits(&literal_counts);
+        (bits / 8.0).ceil() as usize
+    }
+
+    /// The length and distance of the back-reference at `pos`, if any.
+    fn longest_match(&mut self, bytes: &[u8], pos: usize) -> Option<(usize, usize)> {
+        let candidate = self.insert(bytes, pos)?.checked_sub(1)?;
+        let distance = pos - candidate;
+        if distance > WINDOW {
+            return None;
+        }
+        let end = bytes.len().min(pos + MAX_MATCH);
+        let length =
+            bytes[pos..end].iter().zip(&bytes[candidate..]).take_while(|(a, b)| a == b).count();
+        (length >= MIN_MATCH).then_some((length, distance))
+    }
+
+    /// Records the 3 bytes at `pos`, returns the previous entry of their hash.
+    fn insert(&mut self, bytes: &[u8], pos: usize) -> Option<usize> {
+        let key = bytes.get(pos..pos + MIN_MATCH)?;
+        let value = (u32::from(key[0]) << 16) | (u32::from(key[1]) << 8) | u32::from(key[2]);
+        let hash = (value.wrapping_mul(0x9E37_79B1) >> (32 - HASH_BITS)) as usize;
+        Some(std::mem::replace(&mut self.head[hash], pos + 1))
+    }
+}
+
+/// The extra bits of the DEFLATE length code, e.g. 1 for the lengths 11 to 18.
+fn length_extra_bits(length: usize) -> u32 {
+    match length - MIN_MATCH {
+        0..=7 | 255 => 0,
+        n => n.ilog2() - 2,
+    }
+}
+
+/// The extra bits of the DEFLATE distance code, e.g. 1 for the distances 5 to 8.
+fn distance_extra_bits(distance: usize) -> u32 {
+    if distance <= 4 {
+        0
+    } else {
+        (distance - 1).ilog2() - 1
+    }
+}
+
+/// The bits of the literals coded with their order-0 entropy.
+#[allow(clippy::cast_precision_loss)]
+fn entropy_bits(counts: &[usize; 256]) -> f64 {
+    let total = counts.iter().sum::<usize>() as f64;
+    counts
+        .iter()
+        .filter(|count| **count > 0)
+        .map(|count| {
+            let count = *count as f64;
+            count * (total / count).log2()
+        })
+        .sum()
+}
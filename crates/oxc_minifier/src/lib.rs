@@ -1,14 +1,23 @@
 //! ECMAScript Minifier
 
 mod compressor;
+mod gzip;
 mod mangler;
+mod pass;
+mod snapshot;
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
+use oxc_diagnostics::OxcDiagnostic;
 
 pub use crate::{
-    compressor::{CompressOptions, Compressor},
-    mangler::ManglerBuilder,
+    compressor::{CompressOptions, Compressor, CompressorReturn, GlobalDefValue},
+    gzip::GzipEstimator,
+    mangler::{
+        base54, KeepNames, ManglerBuilder, NameCache, PropertyMangler, PropertyManglerOptions,
+    },
+    pass::{CustomPass, PassPhase},
+    snapshot::{PassSnapshot, Printer},
 };
 
 #[derive(Debug, Clone, Copy)]
@@ -23,20 +32,62 @@ impl Default for MinifierOptions {
     }
 }
 
+pub struct MinifierReturn {
+    /// Suspicious code found while minifying, e.g. duplicate object keys whose overwritten value
+    /// has side effects.
+    pub warnings: Vec<OxcDiagnostic>,
+
+    /// The program after each pass of the compressor, empty unless enabled by
+    /// [Minifier::with_snapshots].
+    pub snapshots: Vec<PassSnapshot>,
+}
+
 pub struct Minifier {
     options: MinifierOptions,
+    printer: Option<Printer>,
+    passes: Vec<(PassPhase, &'static str, CustomPass)>,
 }
 
 impl Minifier {
     pub fn new(options: MinifierOptions) -> Self {
-        Self { options }
+        Self { options, printer: None, passes: vec![] }
+    }
+
+    /// Record a snapshot of the program printed by `printer` after each pass of the compressor,
+    /// with the time the pass took, to find the pass which produced incorrect or larger output.
+    #[must_use]
+    pub fn with_snapshots<F>(mut self, printer: F) -> Self
+    where
+        F: for<'a> Fn(&Program<'a>) -> String + 'static,
+    {
+        self.printer = Some(Box::new(printer));
+        self
+    }
+
+    /// Run `pass` in `phase` of the compressor, after the passes registered before it, e.g. a
+    /// project specific fold in [PassPhase::Early]. Its snapshot is recorded as `name`.
+    #[must_use]
+    pub fn with_pass<F>(mut self, phase: PassPhase, name: &'static str, pass: F) -> Self
+    where
+        F: for<'a> FnMut(&'a Allocator, &mut Program<'a>) + 'static,
+    {
+        self.passes.push((phase, name, Box::new(pass)));
+        self
     }
 
-    pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) {
-        Compressor::new(allocator, self.options.compress).build(program);
+    pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) -> MinifierReturn {
+        let mut compressor = Compressor::new(allocator, self.options.compress);
+        if let Some(printer) = self.printer {
+            compressor = compressor.with_snapshots(printer);
+        }
+        for (phase, name, pass) in self.passes {
+            compressor = compressor.with_pass(phase, name, pass);
+        }
+        let CompressorReturn { warnings, snapshots } = compressor.build(program);
         // if self.options.mangle {
-        // let mangler = ManglerBuilder.build(program);
+        // let mangler = ManglerBuilder::default().build(program);
         // printer.with_mangler(mangler);
         // }
+        MinifierReturn { warnings, snapshots }
     }
 }
@@ -0,0 +1,152 @@
+//! Character Frequency
+//!
+//! Counts the characters of the text which is kept by the mangler, such as keywords, property
+//! names, strings and the names of the globals, so that the mangled names are made of the
+//! characters which are already the most frequent in the program, like esbuild does. gzip
+//! compresses repeated characters better than new ones.
+//!
+//! The names which are renamed are not counted, because they are replaced.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstKind, Visit};
+use oxc_semantic::{SymbolId, SymbolTable};
+use oxc_span::CompactStr;
+use rustc_hash::FxHashSet;
+
+use super::{encode, BASE54_CHARS};
+
+/// The number of occurrences of each of the characters of [BASE54_CHARS].
+pub(super) struct CharFreq<'s> {
+    symbol_table: &'s SymbolTable,
+    /// The symbols which are renamed.
+    renamed: &'s FxHashSet<SymbolId>,
+    counts: [usize; 64],
+}
+
+impl<'s> CharFreq<'s> {
+    pub(super) fn new(symbol_table: &'s SymbolTable, renamed: &'s FxHashSet<SymbolId>) -> Self {
+        Self { symbol_table, renamed, counts: [0; 64] }
+    }
+
+    /// The characters ordered by their frequency, from the highest to the lowest.
+    pub(super) fn build(mut self, program: &Program) -> Alphabet {
+        self.visit_program(program);
+        let mut chars = *BASE54_CHARS;
+        // The sort is stable, so characters with the same frequency keep the order of
+        // `BASE54_CHARS`, and a program without text is mangled as before.
+        chars.sort_by_key(|&c| std::cmp::Reverse(self.counts[char_index(c)]));
+        // A digit can not start an identifier.
+        let head = chars.iter().copied().filter(|c| !c.is_ascii_digit()).collect();
+        Alphabet { head, chars }
+    }
+
+    fn add(&mut self, text: &str) {
+        for c in text.bytes() {
+            if c.is_ascii_alphanumeric() || c == b'$' || c == b'_' {
+                self.counts[char_index(c)] += 1;
+            }
+        }
+    }
+
+    fn is_renamed(&self, symbol_id: Option<SymbolId>) -> bool {
+        symbol_id.is_some_and(|symbol_id| self.renamed.contains(&symbol_id))
+    }
+}
+
+/// The index of `c` in [BASE54_CHARS].
+fn char_index(c: u8) -> usize {
+    match c {
+        b'a'..=b'z' => (c - b'a') as usize,
+        b'A'..=b'Z' => (c - b'A') as usize + 26,
+        b'$' => 52,
+        b'_' => 53,
+        b'0'..=b'9' => (c - b'0') as usize + 54,
+        _ => unreachable!(),
+    }
+}
+
+/// The characters of the mangled names, ordered by their frequency in the program.
+pub(super) struct Alphabet {
+    /// The characters which can start a name.
+    head: Vec<u8>,
+    chars: [u8; 64],
+}
+
+impl Alphabet {
+    /// The `n`th shortest name, like [super::base54] with the characters of this alphabet.
+    pub(super) fn name(&self, n: usize) -> CompactStr {
+        encode(&self.head, &self.chars, n)
+    }
+}
+
+impl<'a, 's> Visit<'a> for CharFreq<'s> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let keyword = match kind {
+            AstKind::Function(_) => "function",
+            AstKind::Class(_) => "class",
+            AstKind::ReturnStatement(_) => "return",
+            AstKind::IfStatement(_) => "if",
+            AstKind::ForStatement(_) | AstKind::ForInStatement(_) | AstKind::ForOfStatement(_) => {
+                "for"
+            }
+            AstKind::WhileStatement(_) => "while",
+            AstKind::ThrowStatement(_) => "throw",
+            AstKind::TryStatement(_) => "try",
+            AstKind::VariableDeclaration(decl) => decl.kind.as_str(),
+            AstKind::ThisExpression(_) => "this",
+            AstKind::NewExpression(_) => "new",
+            AstKind::NullLiteral(_) => "null",
+            AstKind::UnaryExpression(expr) if expr.operator.is_keyword() => expr.operator.as_str(),
+            _ => return,
+        };
+        self.add(keyword);
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        if !self.is_renamed(ident.symbol_id.get()) {
+            self.add(&ident.name);
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let symbol_id = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| self.symbol_table.get_reference(reference_id).symbol_id());
+        if !self.is_renamed(symbol_id) {
+            self.add(&ident.name);
+        }
+    }
+
+    fn visit_identifier_name(&mut self, ident: &IdentifierName<'a>) {
+        self.add(&ident.name);
+    }
+
+    fn visit_private_identifier(&mut self, ident: &PrivateIdentifier<'a>) {
+        self.add(&ident.name);
+    }
+
+    fn visit_label_identifier(&mut self, ident: &LabelIdentifier<'a>) {
+        self.add(&ident.name);
+    }
+
+    fn visit_string_literal(&mut self, lit: &StringLiteral<'a>) {
+        self.add(&lit.value);
+    }
+
+    fn visit_template_element(&mut self, elem: &TemplateElement) {
+        self.add(&elem.value.raw);
+    }
+
+    fn visit_reg_expr_literal(&mut self, lit: &RegExpLiteral<'a>) {
+        self.add(&lit.regex.pattern);
+    }
+
+    fn visit_jsx_identifier(&mut self, ident: &JSXIdentifier<'a>) {
+        self.add(&ident.name);
+    }
+
+    fn visit_jsx_text(&mut self, child: &JSXText<'a>) {
+        self.add(&child.value);
+    }
+}
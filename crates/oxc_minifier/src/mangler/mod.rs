@@ -1,14 +1,32 @@
+mod char_freq;
+mod name_cache;
+mod properties;
+
 use itertools::Itertools;
-use oxc_ast::ast::Program;
+use oxc_ast::{
+    ast::{Expression, Program},
+    AstKind,
+};
 use oxc_index::{index_vec, IndexVec};
-use oxc_semantic::{ReferenceId, SemanticBuilder, SymbolId, SymbolTable};
+use oxc_semantic::{
+    ReferenceId, ScopeTree, Semantic, SemanticBuilder, SymbolFlags, SymbolId, SymbolTable,
+};
 use oxc_span::CompactStr;
+use regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use self::char_freq::CharFreq;
+pub use self::{
+    name_cache::NameCache,
+    properties::{PropertyMangler, PropertyManglerOptions},
+};
 
 type Slot = usize;
 
 #[derive(Debug)]
 pub struct Mangler {
     symbol_table: SymbolTable,
+    name_cache: Option<NameCache>,
 }
 
 impl Mangler {
@@ -20,6 +38,38 @@ impl Mangler {
         let symbol_id = self.symbol_table.get_reference(reference_id).symbol_id()?;
         Some(self.symbol_table.get_name(symbol_id))
     }
+
+    /// The name cache given to [ManglerBuilder::with_name_cache], with the names of the top-level
+    /// bindings of this program added.
+    pub fn name_cache(&self) -> Option<&NameCache> {
+        self.name_cache.as_ref()
+    }
+}
+
+/// The names of functions or classes which are kept by the mangler, for the code which reads
+/// them, e.g. with `Function.prototype.name`.
+///
+/// The name of a function or class is its binding, or the variable it is declared with, as in
+/// `const f = () => {}`. The names which are kept are not given to any other binding.
+#[derive(Debug, Clone, Default)]
+pub enum KeepNames {
+    /// Mangle every name.
+    #[default]
+    None,
+    /// Keep every name.
+    All,
+    /// Keep the names which match, e.g. `^[A-Z]` for the names of constructors.
+    Matching(Regex),
+}
+
+impl KeepNames {
+    fn keeps(&self, name: &str) -> bool {
+        match self {
+            Self::None => false,
+            Self::All => true,
+            Self::Matching(regex) => regex.is_match(name),
+        }
+    }
 }
 
 /// # Name Mangler / Symbol Minification
@@ -63,13 +113,46 @@ impl Mangler {
 ///     }
 /// }
 /// ```
-pub struct ManglerBuilder;
+///
+/// The names are made of the characters which are the most frequent in the text that is not
+/// renamed, such as keywords, property names and strings, instead of in alphabetical order, so
+/// that `a`, `b`, `c` above are the letters the program already repeats the most.
+#[derive(Default)]
+pub struct ManglerBuilder {
+    name_cache: Option<NameCache>,
+    keep_fnames: KeepNames,
+    keep_classnames: KeepNames,
+}
 
 impl ManglerBuilder {
+    /// Keep the mangled names of the top-level bindings which are in `name_cache`, from a previous
+    /// invocation, and add the names of the others to it, see [Mangler::name_cache]. The names in
+    /// the cache are not given to any other binding.
+    #[must_use]
+    pub fn with_name_cache(mut self, name_cache: NameCache) -> Self {
+        self.name_cache = Some(name_cache);
+        self
+    }
+
+    /// Keep the names of functions, like `keep_fnames` of terser.
+    #[must_use]
+    pub fn with_keep_fnames(mut self, keep_fnames: KeepNames) -> Self {
+        self.keep_fnames = keep_fnames;
+        self
+    }
+
+    /// Keep the names of classes, like `keep_classnames` of terser.
+    #[must_use]
+    pub fn with_keep_classnames(mut self, keep_classnames: KeepNames) -> Self {
+        self.keep_classnames = keep_classnames;
+        self
+    }
+
     #[must_use]
     pub fn build<'a>(self, program: &'a Program<'a>) -> Mangler {
         let semantic_ret = SemanticBuilder::new("", program.source_type).build(program);
         let semantic = semantic_ret.semantic;
+        let kept = self.kept_names(&semantic);
 
         // Mangle the symbol table by computing slots from the scope tree.
         // A slot is the occurrence index of a binding identifier inside a scope.
@@ -107,9 +190,6 @@ impl ManglerBuilder {
             }
         }
 
-        let frequencies =
-            Self::tally_slot_frequencies(&symbol_table, total_number_of_slots, &slots);
-
         let unresolved_references = scope_tree
             .root_unresolved_references()
             .keys()
@@ -117,15 +197,73 @@ impl ManglerBuilder {
             // .filter(|name| name.len() < 5)
             .collect::<Vec<_>>();
 
+        // Symbols visible to a direct `eval` or `with` and the kept names of functions and
+        // classes keep their names, so the mangled names must not shadow them.
+        let unrenamable_names = symbol_table
+            .iter()
+            .filter(|symbol_id| {
+                kept.contains(symbol_id)
+                    || Self::is_unrenamable(&symbol_table, &scope_tree, *symbol_id)
+            })
+            .map(|symbol_id| CompactStr::new(symbol_table.get_name(symbol_id)))
+            .collect::<Vec<_>>();
+
+        // The renamable top-level bindings with their original names, and the names from the name
+        // cache which they keep.
+        let top_level = scope_tree
+            .get_bindings(scope_tree.root_scope_id())
+            .values()
+            .copied()
+            .filter(|&symbol_id| {
+                Self::is_mangled_binding(symbol_table.get_flag(symbol_id))
+                    && !kept.contains(&symbol_id)
+                    && !Self::is_unrenamable(&symbol_table, &scope_tree, symbol_id)
+            })
+            .map(|symbol_id| (symbol_id, CompactStr::new(symbol_table.get_name(symbol_id))))
+            .collect::<Vec<_>>();
+        let mut cached = FxHashMap::<SymbolId, CompactStr>::default();
+        let mut cached_names = FxHashSet::<CompactStr>::default();
+        if let Some(name_cache) = &self.name_cache {
+            cached_names = name_cache.vars.values().map(|name| CompactStr::new(name)).collect();
+            for (symbol_id, original_name) in &top_level {
+                let Some(name) = name_cache.vars.get(original_name.as_str()) else { continue };
+                // A name which became a global reference or unrenamable is not kept.
+                if !unresolved_references.iter().any(|n| *n == name)
+                    && !unrenamable_names.iter().any(|n| n == name)
+                {
+                    cached.insert(*symbol_id, CompactStr::new(name));
+                }
+            }
+        }
+
+        let frequencies = Self::tally_slot_frequencies(
+            &symbol_table,
+            &scope_tree,
+            total_number_of_slots,
+            &slots,
+            &cached,
+            &kept,
+        );
+        let renamed = frequencies
+            .iter()
+            .flat_map(|frequency| frequency.symbol_ids.iter().copied())
+            .collect::<FxHashSet<_>>();
+        let alphabet = CharFreq::new(&symbol_table, &renamed).build(program);
+
         let mut names = Vec::with_capacity(total_number_of_slots);
 
         let mut count = 0;
         for _ in 0..total_number_of_slots {
             names.push(loop {
-                let name = base54(count);
+                let name = alphabet.name(count);
                 count += 1;
-                // Do not mangle keywords and unresolved references
-                if !is_keyword(&name) && !unresolved_references.iter().any(|n| **n == name) {
+                // Do not mangle keywords, unresolved references, unrenamable symbols and the
+                // names in the name cache
+                if !is_keyword(&name)
+                    && !unresolved_references.iter().any(|n| **n == name)
+                    && !unrenamable_names.contains(&name)
+                    && !cached_names.contains(&name)
+                {
                     break name;
                 }
             });
@@ -161,7 +299,7 @@ impl ManglerBuilder {
             // sorting by slot enables us to sort by the order at which the vars first appear in the source
             // (this is possible because the slots are discovered currently in a DFS method which is the same order
             //  as variables appear in the source code)
-            symbols_renamed_in_this_batch.sort_by(|a, b| a.slot.cmp(&b.slot.clone()));
+            symbols_renamed_in_this_batch.sort_unstable_by_key(|a| a.slot);
 
             // here we just zip the iterator of symbols to rename with the iterator of new names for the next for loop
             let symbols_to_rename_with_new_names =
@@ -175,26 +313,93 @@ impl ManglerBuilder {
             }
         }
 
-        Mangler { symbol_table }
+        for (symbol_id, name) in cached {
+            symbol_table.set_name(symbol_id, name);
+        }
+        let mut name_cache = self.name_cache;
+        if let Some(name_cache) = &mut name_cache {
+            for (symbol_id, original_name) in top_level {
+                name_cache
+                    .vars
+                    .entry(original_name.to_string())
+                    .or_insert_with(|| symbol_table.get_name(symbol_id).to_string());
+            }
+        }
+
+        Mangler { symbol_table, name_cache }
+    }
+
+    /// The functions and classes whose names are kept with `keep_fnames` and `keep_classnames`.
+    fn kept_names(&self, semantic: &Semantic) -> FxHashSet<SymbolId> {
+        let symbol_table = semantic.symbols();
+        symbol_table
+            .iter()
+            .filter(|&symbol_id| {
+                let keep = match semantic.nodes().kind(symbol_table.get_declaration(symbol_id)) {
+                    AstKind::Function(_) => &self.keep_fnames,
+                    AstKind::Class(_) => &self.keep_classnames,
+                    // `const f = () => {}` and `const C = class {}` are named after the variable.
+                    AstKind::VariableDeclarator(decl) => {
+                        match decl.init.as_ref().map(Expression::without_parenthesized) {
+                            Some(Expression::FunctionExpression(func)) if func.id.is_none() => {
+                                &self.keep_fnames
+                            }
+                            Some(Expression::ArrowFunctionExpression(_)) => &self.keep_fnames,
+                            Some(Expression::ClassExpression(class)) if class.id.is_none() => {
+                                &self.keep_classnames
+                            }
+                            _ => return false,
+                        }
+                    }
+                    _ => return false,
+                };
+                keep.keeps(symbol_table.get_name(symbol_id))
+            })
+            .collect()
+    }
+
+    /// Variables, and the names of functions and classes, which are also bound by the function
+    /// and class expressions that are not declared with a variable.
+    fn is_mangled_binding(flags: SymbolFlags) -> bool {
+        flags.intersects(SymbolFlags::Variable | SymbolFlags::Class | SymbolFlags::Function)
+            && !flags.contains(SymbolFlags::Ambient)
+    }
+
+    /// A symbol declared in a scope containing a direct `eval` or `with` can be referenced
+    /// dynamically by name, so it must not be renamed.
+    fn is_unrenamable(
+        symbol_table: &SymbolTable,
+        scope_tree: &ScopeTree,
+        symbol_id: SymbolId,
+    ) -> bool {
+        scope_tree.get_flags(symbol_table.get_scope_id(symbol_id)).contains_direct_eval()
     }
 
     fn tally_slot_frequencies(
         symbol_table: &SymbolTable,
+        scope_tree: &ScopeTree,
         total_number_of_slots: usize,
         slots: &IndexVec<SymbolId, Slot>,
+        cached: &FxHashMap<SymbolId, CompactStr>,
+        kept: &FxHashSet<SymbolId>,
     ) -> Vec<SlotFrequency> {
-        let mut frequencies = vec![SlotFrequency::default(); total_number_of_slots];
+        let mut frequencies = (0..total_number_of_slots)
+            .map(|slot| SlotFrequency { slot, ..SlotFrequency::default() })
+            .collect::<Vec<_>>();
         for (symbol_id, slot) in slots.iter_enumerated() {
-            if !symbol_table.get_flag(symbol_id).is_variable() {
+            if !Self::is_mangled_binding(symbol_table.get_flag(symbol_id))
+                || Self::is_unrenamable(symbol_table, scope_tree, symbol_id)
+                || cached.contains_key(&symbol_id)
+                || kept.contains(&symbol_id)
+            {
                 continue;
             }
             let index = *slot;
-            frequencies[index].slot = *slot;
-            frequencies[index].frequency +=
-                symbol_table.get_resolved_reference_ids(symbol_id).len();
+            frequencies[index].frequency += symbol_table.get_resolved_reference_count(symbol_id);
             frequencies[index].symbol_ids.push(symbol_id);
         }
-        frequencies.sort_by_key(|x| (std::cmp::Reverse(x.frequency)));
+        // Break ties by slot, so that the assigned names are the same on every run.
+        frequencies.sort_unstable_by_key(|x| (std::cmp::Reverse(x.frequency), x.slot));
         frequencies
     }
 }
@@ -207,7 +412,7 @@ struct SlotFrequency {
 }
 
 #[rustfmt::skip]
-fn is_keyword(s: &str) -> bool {
+pub(crate) fn is_keyword(s: &str) -> bool {
     matches!(s, "as" | "do" | "if" | "in" | "is" | "of" | "any" | "for" | "get"
             | "let" | "new" | "out" | "set" | "try" | "var" | "case" | "else"
             | "enum" | "from" | "meta" | "null" | "this" | "true" | "type"
@@ -218,20 +423,25 @@ const BASE54_CHARS: &[u8; 64] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRST
 
 /// Get the shortest mangled name for a given n.
 /// Code adapted from [terser](https://github.com/terser/terser/blob/8b966d687395ab493d2c6286cc9dd38650324c11/lib/scope.js#L1041-L1051)
-fn base54(n: usize) -> CompactStr {
+pub fn base54(n: usize) -> CompactStr {
+    encode(&BASE54_CHARS[..54], BASE54_CHARS, n)
+}
+
+/// The `n`th shortest name which starts with one of `head` and continues with `chars`.
+fn encode(head: &[u8], chars: &[u8], n: usize) -> CompactStr {
     let mut num = n;
     // Base 54 at first because these are the usable first characters in JavaScript identifiers
     // <https://tc39.es/ecma262/#prod-IdentifierStart>
-    let base = 54usize;
+    let base = head.len();
     let mut ret = String::new();
-    ret.push(BASE54_CHARS[num % base] as char);
+    ret.push(head[num % base] as char);
     num /= base;
     // Base 64 for the rest because after the first character we can also use 0-9 too
     // <https://tc39.es/ecma262/#prod-IdentifierPart>
-    let base = 64usize;
+    let base = chars.len();
     while num > 0 {
         num -= 1;
-        ret.push(BASE54_CHARS[num % base] as char);
+        ret.push(chars[num % base] as char);
         num /= base;
     }
     CompactStr::new(&ret)
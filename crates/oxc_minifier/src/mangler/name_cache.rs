@@ -0,0 +1,25 @@
+use std::collections::BTreeMap;
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// The names given by the manglers, like `nameCache` of terser.
+///
+/// A cache which is passed from one invocation of the minifier to the next, e.g. saved to a JSON
+/// file with the `serialize` feature, keeps the mangled names of the top-level bindings and the
+/// properties the same. The chunks of a split build then agree on the names they share, and a
+/// file whose source did not change keeps its output for long-term caching.
+///
+/// Used by [`ManglerBuilder::with_name_cache`](super::ManglerBuilder::with_name_cache) for the
+/// `vars`, and [`PropertyMangler::with_name_cache`](super::PropertyMangler::with_name_cache) for
+/// the `props`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct NameCache {
+    /// The mangled names of the top-level bindings, by their original name.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub vars: BTreeMap<String, String>,
+    /// The mangled names of the properties, by their original name.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    pub props: BTreeMap<String, String>,
+}
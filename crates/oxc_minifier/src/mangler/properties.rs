@@ -0,0 +1,339 @@
+use oxc_allocator::Allocator;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{
+    ast::*,
+    visit::walk_mut::{
+        walk_assignment_target_property_mut, walk_binary_expression_mut, walk_binding_property_mut,
+        walk_computed_member_expression_mut, walk_object_property_mut, walk_property_key_mut,
+        walk_static_member_expression_mut,
+    },
+    AstBuilder, VisitMut,
+};
+use oxc_span::{Atom, CompactStr};
+use oxc_syntax::operator::BinaryOperator;
+use regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::{base54, NameCache};
+
+#[derive(Debug, Clone, Default)]
+pub struct PropertyManglerOptions {
+    /// Only mangle the properties whose name matches, for example `^_` for the properties which
+    /// are private by convention.
+    ///
+    /// Default `None`, every property which is not reserved.
+    pub regex: Option<Regex>,
+
+    /// Properties which are never mangled, in addition to the properties of the builtins.
+    ///
+    /// Default empty
+    pub reserved: Vec<String>,
+
+    /// Never mangle the names which are quoted somewhere in the program, as in `{ "a": 1 }` and
+    /// `o["a"]`, so that quoting a property keeps it. Otherwise quoted names are mangled like
+    /// the others.
+    ///
+    /// Default `false`
+    pub keep_quoted: bool,
+}
+
+/// # Property Mangler
+///
+/// Renames the properties of objects and classes, like `mangle.properties` of terser:
+///
+/// ```javascript
+/// const o = { _count: 0, "_step": 1 };
+/// o._count += o["_step"];
+/// ```
+///
+/// is mangled with the regex `^_` to
+///
+/// ```javascript
+/// const o = { a: 0, "b": 1 };
+/// o.a += o["b"];
+/// ```
+///
+/// A property is renamed everywhere it is named: member expressions, keys of object literals,
+/// classes and destructuring patterns, and the left side of `in`. The names are kept by the
+/// mangler and shared by every program it mangles, so that the files of a bundle which are
+/// mangled separately still agree on the names. The most frequent properties get the shortest
+/// names, which never collide with a property kept in the same program.
+///
+/// A property which is accessed through a computed key other than a string literal, such as
+/// `o[key]`, cannot be found, so only properties known to be used by name may be mangled.
+pub struct PropertyMangler {
+    options: PropertyManglerOptions,
+    /// `options.reserved` and the properties of the builtins.
+    reserved: FxHashSet<CompactStr>,
+    /// The mangled names of the original names.
+    names: FxHashMap<CompactStr, CompactStr>,
+    /// The mangled names from a name cache, which are not given to other properties.
+    cached_names: FxHashSet<CompactStr>,
+    /// The index of the next name from [base54].
+    next_name: usize,
+}
+
+impl PropertyMangler {
+    pub fn new(options: PropertyManglerOptions) -> Self {
+        let reserved = BUILTIN_PROPERTIES
+            .iter()
+            .map(|name| CompactStr::new(name))
+            .chain(options.reserved.iter().map(|name| CompactStr::new(name)))
+            .collect();
+        Self {
+            options,
+            reserved,
+            names: FxHashMap::default(),
+            cached_names: FxHashSet::default(),
+            next_name: 0,
+        }
+    }
+
+    /// Keep the mangled names of the properties in `name_cache`, from a previous invocation.
+    #[must_use]
+    pub fn with_name_cache(mut self, name_cache: &NameCache) -> Self {
+        for (name, mangled) in &name_cache.props {
+            self.names.insert(CompactStr::new(name), CompactStr::new(mangled));
+            self.cached_names.insert(CompactStr::new(mangled));
+        }
+        self
+    }
+
+    /// Add the mangled names of the properties mangled so far to `name_cache`.
+    pub fn write_name_cache(&self, name_cache: &mut NameCache) {
+        for (name, mangled) in &self.names {
+            name_cache.props.insert(name.to_string(), mangled.to_string());
+        }
+    }
+
+    /// The mangled names of the properties mangled so far.
+    pub fn names(&self) -> &FxHashMap<CompactStr, CompactStr> {
+        &self.names
+    }
+
+    pub fn build<'a>(&mut self, allocator: &'a Allocator, program: &mut Program<'a>) {
+        let mut visitor = PropertyVisitor {
+            ast: AstBuilder::new(allocator),
+            mangler: self,
+            renaming: false,
+            counts: FxHashMap::default(),
+            kept: FxHashSet::default(),
+        };
+        visitor.visit_program(program);
+        let PropertyVisitor { counts, kept, .. } = visitor;
+
+        // The most frequent names get the shortest names, ties are broken by the first occurrence.
+        let mut new_names = counts
+            .into_iter()
+            .filter(|(name, _)| !self.names.contains_key(name) && !kept.contains(name))
+            .collect::<Vec<_>>();
+        new_names.sort_unstable_by_key(|(_, (count, first))| (std::cmp::Reverse(*count), *first));
+        for (name, _) in new_names {
+            let mangled = loop {
+                let mangled = base54(self.next_name);
+                self.next_name += 1;
+                if !kept.contains(&mangled)
+                    && !self.reserved.contains(&mangled)
+                    && !self.cached_names.contains(&mangled)
+                {
+                    break mangled;
+                }
+            };
+            self.names.insert(name, mangled);
+        }
+
+        let mut visitor = PropertyVisitor {
+            ast: AstBuilder::new(allocator),
+            mangler: self,
+            renaming: true,
+            counts: FxHashMap::default(),
+            kept,
+        };
+        visitor.visit_program(program);
+    }
+
+    fn is_mangled(&self, name: &str) -> bool {
+        !self.reserved.contains(name)
+            && self.options.regex.as_ref().map_or(true, |regex| regex.is_match(name))
+    }
+}
+
+/// Counts the names of the properties of a program, then renames them.
+struct PropertyVisitor<'m, 'a> {
+    ast: AstBuilder<'a>,
+    mangler: &'m PropertyMangler,
+    /// Whether the names are renamed, or counted.
+    renaming: bool,
+    /// The names to mangle, with the number of occurrences and the index of the first one.
+    counts: FxHashMap<CompactStr, (usize, usize)>,
+    /// The names which are not mangled in this program.
+    kept: FxHashSet<CompactStr>,
+}
+
+impl<'m, 'a> PropertyVisitor<'m, 'a> {
+    /// Counts `name`, or returns its mangled name.
+    fn property(&mut self, name: &str, quoted: bool) -> Option<Atom<'a>> {
+        if self.renaming {
+            if self.kept.contains(name) {
+                return None;
+            }
+            let mangled = self.mangler.names.get(name)?;
+            return Some(self.ast.new_atom(mangled));
+        }
+        if (quoted && self.mangler.options.keep_quoted) || !self.mangler.is_mangled(name) {
+            self.kept.insert(CompactStr::new(name));
+        } else {
+            let index = self.counts.len();
+            self.counts.entry(CompactStr::new(name)).or_insert((0, index)).0 += 1;
+        }
+        None
+    }
+}
+
+impl<'m, 'a> VisitMut<'a> for PropertyVisitor<'m, 'a> {
+    fn visit_static_member_expression(&mut self, expr: &mut StaticMemberExpression<'a>) {
+        walk_static_member_expression_mut(self, expr);
+        if let Some(name) = self.property(&expr.property.name, false) {
+            expr.property.name = name;
+        }
+    }
+
+    fn visit_computed_member_expression(&mut self, expr: &mut ComputedMemberExpression<'a>) {
+        walk_computed_member_expression_mut(self, expr);
+        if let Expression::StringLiteral(lit) = &mut expr.expression {
+            if let Some(name) = self.property(&lit.value, true) {
+                lit.value = name;
+            }
+        }
+    }
+
+    fn visit_property_key(&mut self, key: &mut PropertyKey<'a>) {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                if let Some(name) = self.property(&ident.name, false) {
+                    ident.name = name;
+                }
+            }
+            PropertyKey::StringLiteral(lit) => {
+                if let Some(name) = self.property(&lit.value, true) {
+                    lit.value = name;
+                }
+            }
+            _ => walk_property_key_mut(self, key),
+        }
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        walk_object_property_mut(self, prop);
+        // `{ a }` -> `{ b: a }`
+        if prop.shorthand {
+            prop.shorthand = matches!(
+                (&prop.key, &prop.value),
+                (PropertyKey::StaticIdentifier(key), Expression::Identifier(value))
+                    if key.name == value.name
+            );
+        }
+    }
+
+    fn visit_binding_property(&mut self, prop: &mut BindingProperty<'a>) {
+        walk_binding_property_mut(self, prop);
+        // `const { a = 1 } = o` -> `const { b: a = 1 } = o`
+        if prop.shorthand {
+            prop.shorthand = matches!(
+                (&prop.key, prop.value.get_identifier()),
+                (PropertyKey::StaticIdentifier(key), Some(value)) if key.name == value
+            );
+        }
+    }
+
+    fn visit_assignment_target_property(&mut self, property: &mut AssignmentTargetProperty<'a>) {
+        // `({ a = 1 } = o)` -> `({ b: a = 1 } = o)`
+        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) = property {
+            if let Some(name) = self.property(&ident.binding.name, false) {
+                let span = ident.span;
+                let key = PropertyKey::StaticIdentifier(
+                    self.ast.alloc(IdentifierName::new(ident.binding.span, name)),
+                );
+                let target = self.ast.alloc(ident.binding.clone());
+                let binding = match ident.init.take() {
+                    Some(mut init) => {
+                        self.visit_expression(&mut init);
+                        AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(self.ast.alloc(
+                            AssignmentTargetWithDefault {
+                                span,
+                                binding: AssignmentTarget::AssignmentTargetIdentifier(target),
+                                init,
+                            },
+                        ))
+                    }
+                    None => AssignmentTargetMaybeDefault::AssignmentTargetIdentifier(target),
+                };
+                *property = self.ast.assignment_target_property_property(span, key, binding);
+                return;
+            }
+        }
+        walk_assignment_target_property_mut(self, property);
+    }
+
+    fn visit_binary_expression(&mut self, expr: &mut BinaryExpression<'a>) {
+        walk_binary_expression_mut(self, expr);
+        // `"a" in o`
+        if expr.operator == BinaryOperator::In {
+            if let Expression::StringLiteral(lit) = &mut expr.left {
+                if let Some(name) = self.property(&lit.value, true) {
+                    lit.value = name;
+                }
+            }
+        }
+    }
+}
+
+/// The properties of the builtins which may be used by a program, which are never mangled.
+#[rustfmt::skip]
+const BUILTIN_PROPERTIES: &[&str] = &[
+    // Object
+    "__defineGetter__", "__defineSetter__", "__lookupGetter__", "__lookupSetter__", "__proto__",
+    "assign", "constructor", "create", "defineProperties", "defineProperty", "entries", "freeze",
+    "fromEntries", "getOwnPropertyDescriptor", "getOwnPropertyDescriptors", "getOwnPropertyNames",
+    "getOwnPropertySymbols", "getPrototypeOf", "groupBy", "hasOwn", "hasOwnProperty", "is",
+    "isExtensible", "isFrozen", "isPrototypeOf", "isSealed", "keys", "preventExtensions",
+    "propertyIsEnumerable", "prototype", "seal", "setPrototypeOf", "toLocaleString", "toString",
+    "valueOf", "values",
+    // Property descriptors
+    "configurable", "enumerable", "get", "set", "value", "writable",
+    // Function
+    "apply", "arguments", "bind", "call", "caller", "length", "name",
+    // Array
+    "at", "concat", "copyWithin", "every", "fill", "filter", "find", "findIndex", "findLast",
+    "findLastIndex", "flat", "flatMap", "forEach", "from", "includes", "indexOf", "isArray",
+    "join", "lastIndexOf", "map", "of", "pop", "push", "reduce", "reduceRight", "reverse", "shift",
+    "slice", "some", "sort", "splice", "toReversed", "toSorted", "toSpliced", "unshift", "with",
+    // String
+    "charAt", "charCodeAt", "codePointAt", "endsWith", "fromCharCode", "fromCodePoint",
+    "localeCompare", "match", "matchAll", "normalize", "padEnd", "padStart", "raw", "repeat",
+    "replace", "replaceAll", "search", "split", "startsWith", "substr", "substring",
+    "toLocaleLowerCase", "toLocaleUpperCase", "toLowerCase", "toUpperCase", "trim", "trimEnd",
+    "trimStart",
+    // Number and Math
+    "EPSILON", "MAX_SAFE_INTEGER", "MAX_VALUE", "MIN_SAFE_INTEGER", "MIN_VALUE", "NaN",
+    "NEGATIVE_INFINITY", "POSITIVE_INFINITY", "isFinite", "isInteger", "isNaN", "isSafeInteger",
+    "parseFloat", "parseInt", "toExponential", "toFixed", "toPrecision", "E", "PI", "abs", "ceil",
+    "floor", "max", "min", "pow", "random", "round", "sign", "sqrt", "trunc",
+    // RegExp
+    "exec", "flags", "global", "ignoreCase", "index", "input", "lastIndex", "multiline", "source",
+    "sticky", "test", "unicode",
+    // Promise
+    "all", "allSettled", "any", "catch", "finally", "race", "reject", "resolve", "then",
+    "withResolvers",
+    // Map, Set, WeakMap and WeakSet
+    "add", "clear", "delete", "has", "size",
+    // Symbol and iterators
+    "asyncIterator", "description", "done", "for", "hasInstance", "iterator", "next", "return",
+    "species", "throw", "toPrimitive", "toStringTag",
+    // Error
+    "cause", "errors", "message", "stack",
+    // JSON and Date
+    "now", "parse", "stringify", "toISOString", "toJSON",
+    // Modules
+    "__esModule", "default", "exports", "module", "require",
+];
@@ -0,0 +1,47 @@
+//! Passes registered by embedders, which run in one of the phases of the compressor.
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
+
+/// The phase of the compressor in which a pass runs.
+///
+/// The early phase normalizes the program for the peephole optimizations, for example by
+/// recovering `??` from lowered code. The late phase runs after them and denormalizes it, with
+/// code which is smaller but which the other passes would no longer optimize or rewrite back,
+/// for example `["a", "b", "c"]` → `"abc".split("")`. Every phase runs once, in this order, so
+/// a late pass and an early pass which undoes it can never run back and forth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PassPhase {
+    /// Run after the builtin passes which rewrite the program as a whole, such as
+    /// `dead_stores` and `destructuring`, and before the peephole optimizations.
+    Early,
+    /// Run after the builtin passes, only with `compress.late`, whose output is harder to
+    /// optimize again.
+    Late,
+}
+
+/// A pass registered with [crate::Minifier::with_pass], which mutates the program.
+pub type CustomPass = Box<dyn for<'a> FnMut(&'a Allocator, &mut Program<'a>)>;
+
+/// The passes registered for each phase, in the order they run.
+#[derive(Default)]
+pub(crate) struct Passes {
+    early: Vec<(&'static str, CustomPass)>,
+    late: Vec<(&'static str, CustomPass)>,
+}
+
+impl Passes {
+    pub(crate) fn add(&mut self, phase: PassPhase, name: &'static str, pass: CustomPass) {
+        match phase {
+            PassPhase::Early => self.early.push((name, pass)),
+            PassPhase::Late => self.late.push((name, pass)),
+        }
+    }
+
+    pub(crate) fn phase(&mut self, phase: PassPhase) -> &mut [(&'static str, CustomPass)] {
+        match phase {
+            PassPhase::Early => &mut self.early,
+            PassPhase::Late => &mut self.late,
+        }
+    }
+}
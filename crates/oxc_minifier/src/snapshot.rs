@@ -0,0 +1,49 @@
+//! Snapshots of the program after each pass of the compressor, to find the pass which produced
+//! incorrect or larger output without recompiling the minifier with prints.
+
+use std::time::{Duration, Instant};
+
+use oxc_ast::ast::Program;
+
+/// Prints a snapshot of the program, e.g. with `oxc_codegen`, or to JSON with `Program::to_json`.
+pub type Printer = Box<dyn for<'a> Fn(&Program<'a>) -> String>;
+
+/// The program after a pass.
+#[derive(Debug, Clone)]
+pub struct PassSnapshot {
+    /// The name of the pass, e.g. `"dead_stores"`, which is the option enabling it if there is one.
+    pub pass: &'static str,
+    /// The time the pass took, without printing the snapshot.
+    pub duration: Duration,
+    /// The program printed by the [Printer].
+    pub source_text: String,
+}
+
+pub(crate) struct Snapshots {
+    printer: Printer,
+    passes: Vec<PassSnapshot>,
+    /// The end of the previous pass.
+    last: Instant,
+}
+
+impl Snapshots {
+    pub(crate) fn new(printer: Printer) -> Self {
+        Self { printer, passes: vec![], last: Instant::now() }
+    }
+
+    /// Start timing the first pass.
+    pub(crate) fn start(&mut self) {
+        self.last = Instant::now();
+    }
+
+    pub(crate) fn record(&mut self, pass: &'static str, program: &Program) {
+        let duration = self.last.elapsed();
+        let source_text = (self.printer)(program);
+        self.passes.push(PassSnapshot { pass, duration, source_text });
+        self.last = Instant::now();
+    }
+
+    pub(crate) fn into_snapshots(self) -> Vec<PassSnapshot> {
+        self.passes
+    }
+}
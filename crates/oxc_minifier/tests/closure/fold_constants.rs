@@ -478,7 +478,7 @@ fn unary_ops() {
     test_same("a=!foo();");
     test("a=-0", "a=-0;");
     test("a=-(0)", "a=-0;");
-    test_same("a=-Infinity;");
+    test("a=-Infinity", "a=-1/0;");
     test("a=-NaN", "a=NaN;");
     test_same("a=-foo();");
     test("a=~~0", "a=0;");
@@ -492,7 +492,7 @@ fn unary_ops() {
     test_same("a=+f;");
     // test("a=+(f?true:false)", "a=+(f?1:0);"); // TODO(johnlenz): foldable
     test("a=+0", "a=0;");
-    test("a=+Infinity", "a=Infinity;");
+    test("a=+Infinity", "a=1/0;");
     test("a=+NaN", "a=NaN;");
     test("a=+-7", "a=-7;");
     test("a=+.5", "a=.5;");
@@ -6,10 +6,10 @@ use crate::test;
 fn fold_return_result() {
     test("function f(){return !1;}", "function f(){return !1}");
     test("function f(){return null;}", "function f(){return null}");
-    test("function f(){return void 0;}", "function f(){return}");
+    test("function f(){return void 0;}", "function f(){}");
     test("function f(){return void foo();}", "function f(){return void foo()}");
-    test("function f(){return undefined;}", "function f(){return}");
-    test("function f(){if(a()){return undefined;}}", "function f(){if(a())return}");
+    test("function f(){return undefined;}", "function f(){}");
+    test("function f(){if(a()){return undefined;}}", "function f(){a()}");
 }
 
 #[test]
@@ -25,3 +25,75 @@ fn undefined() {
     test("undefined++", "undefined++;");
     test("undefined += undefined;", "undefined+=void 0;");
 }
+
+#[test]
+fn bind_to_call1() {
+    test("(goog.bind(f))()", "f();");
+    test("(goog.bind(f,a))()", "f.call(a);");
+    test("(goog.bind(f,a,b))()", "f.call(a,b);");
+    test("(goog.bind(f))(a)", "f(a);");
+    test("(goog.bind(f,a))(b)", "f.call(a,b);");
+    test("(goog.bind(f,a,b))(c)", "f.call(a,b,c);");
+    test("(goog.partial(f))()", "f();");
+    test("(goog.partial(f,a))()", "f(a);");
+    test("(goog.partial(f,a,b))()", "f(a,b);");
+    test("(goog.partial(f))(a)", "f(a);");
+    test("(goog.partial(f,a))(b)", "f(a,b);");
+    test("(goog.partial(f,a,b))(c)", "f(a,b,c);");
+    test("((function(){}).bind())()", "(function(){})();");
+    test("((function(){}).bind(a))()", "(function(){}).call(a);");
+    test("((function(){}).bind(a,b))()", "(function(){}).call(a,b);");
+    test("((function(){}).bind())(a)", "(function(){})(a);");
+    test("((function(){}).bind(a))(b)", "(function(){}).call(a,b);");
+    test("((function(){}).bind(a,b))(c)", "(function(){}).call(a,b,c);");
+    test("((() => {}).bind(a))(b)", "(()=>{}).call(a,b);");
+    // Without using type information we don't know "f" is a function.
+    test("(f.bind())()", "f.bind()();");
+    test("(f.bind(a))()", "f.bind(a)();");
+    test("(f.bind())(a)", "f.bind()(a);");
+    test("(f.bind(a))(b)", "f.bind(a)(b);");
+    // Don't rewrite if the bind isn't the immediate call target
+    test("(goog.bind(f)).call(g)", "goog.bind(f).call(g);");
+    // `goog` is not the Closure Library
+    test("var goog;(goog.bind(f))()", "var goog;goog.bind(f)();");
+    test("(goog.bind(...f))()", "goog.bind(...f)();");
+    test("goog.bind(f,a)?.(b)", "goog.bind(f,a)?.(b);");
+}
+
+#[test]
+fn bind_to_call2() {
+    test("(goog.bind(f.m))()", "(0,f.m)();");
+    test("(goog.bind(f.m,a))()", "f.m.call(a);");
+    test("(goog.bind(f.m))(a)", "(0,f.m)(a);");
+    test("(goog.bind(f.m,a))(b)", "f.m.call(a,b);");
+    test("(goog.partial(f.m))()", "(0,f.m)();");
+    test("(goog.partial(f.m,a))()", "(0,f.m)(a);");
+    test("(goog.partial(f.m))(a)", "(0,f.m)(a);");
+    test("(goog.partial(f.m,a))(b)", "(0,f.m)(a,b);");
+    // Without using type information we don't know "f" is a function.
+    test("f.m.bind()()", "f.m.bind()();");
+    test("f.m.bind(a)()", "f.m.bind(a)();");
+    test("f.m.bind()(a)", "f.m.bind()(a);");
+    test("f.m.bind(a)(b)", "f.m.bind(a)(b);");
+    // Don't rewrite if the bind isn't the immediate call target
+    test("goog.bind(f.m).call(g)", "goog.bind(f.m).call(g);");
+}
+
+#[test]
+fn string_array_splitting() {
+    test("var x=['1','2','3','4']", "var x=['1','2','3','4'];");
+    test("var x=['1','2','3','4','5']", "var x=['1','2','3','4','5'];");
+    test("var x=['1','2','3','4','5','6']", "var x='123456'.split('');");
+    test("var x=['1','2','3','4','5','00']", "var x='1 2 3 4 5 00'.split(' ');");
+    test("var x=['1','2','3','4','5','6','7']", "var x='1234567'.split('');");
+    test("var x=['1','2','3','4','5','6','00']", "var x='1 2 3 4 5 6 00'.split(' ');");
+    test("var x=[' ,',',',',',',',',',',']", "var x=' ,;,;,;,;,;,'.split(';');");
+    test("var x=[',,',' ',',',',',',',',']", "var x=',,; ;,;,;,;,'.split(';');");
+    test("var x=['a,',' ',',',',',',',',']", "var x='a,; ;,;,;,;,'.split(';');");
+
+    // all possible delimiters used, leave it alone
+    test("var x=[',', ' ', ';', '{', '}', 'ab']", "var x=[',',' ',';','{','}','ab'];");
+    // `split('')` splits surrogate pairs
+    test("var x=['1','2','3','4','5','\u{1F600}']", "var x='1 2 3 4 5 \\u{1f600}'.split(' ');");
+    test("var x=['1','2','3','4','5',a]", "var x=['1','2','3','4','5',a];");
+}
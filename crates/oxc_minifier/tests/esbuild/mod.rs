@@ -224,7 +224,7 @@ fn r#for() {
 #[test]
 fn function() {
     test("function foo(a = (b, c), ...d) {}", "function foo(a=(b,c),...d){}");
-    test("function foo({[1 + 2]: a = 3} = {[1 + 2]: 3}) {}", "function foo({[3]:a=3}={[3]:3}){}");
+    test("function foo({[1 + 2]: a = 3} = {[1 + 2]: 3}) {}", "function foo({[3]:a=3}={3:3}){}");
     test(
         "function foo([a = (1, 2), ...[b, ...c]] = [1, [2, 3]]) {}",
         "function foo([a=(1,2),...[b,...c]]=[1,[2,3]]){}",
@@ -306,7 +306,7 @@ fn arrow() {
     test("x => (x, 0)", "x=>(x,0);");
     test("x => {y}", "x=>{y};");
     test("(a = (b, c), ...d) => {}", "(a=(b,c),...d)=>{};");
-    test("({[1 + 2]: a = 3} = {[1 + 2]: 3}) => {}", "({[3]:a=3}={[3]:3})=>{};");
+    test("({[1 + 2]: a = 3} = {[1 + 2]: 3}) => {}", "({[3]:a=3}={3:3})=>{};");
     test(
         "([a = (1, 2), ...[b, ...c]] = [1, [2, 3]]) => {}",
         "([a=(1,2),...[b,...c]]=[1,[2,3]])=>{};",
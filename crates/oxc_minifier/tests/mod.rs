@@ -37,6 +37,21 @@ pub(crate) fn test_same(source_text: &str) {
     test(source_text, source_text);
 }
 
+/// Compares `source_text` compressed with `compress` to `expected` compressed with
+/// `expected_compress`, which are the same options without the one being tested.
+pub(crate) fn test_with_compress_options(
+    source_text: &str,
+    expected: &str,
+    source_type: SourceType,
+    compress: CompressOptions,
+    expected_compress: CompressOptions,
+) {
+    let minified = minify(source_text, source_type, MinifierOptions { mangle: false, compress });
+    let options = MinifierOptions { mangle: false, compress: expected_compress };
+    let expected = minify(expected, source_type, options);
+    assert_eq!(expected, minified, "for source {source_text}");
+}
+
 pub(crate) fn test_reparse(source_text: &str) {
     let source_type = SourceType::default();
     let options = MinifierOptions { mangle: false, ..MinifierOptions::default() };
@@ -0,0 +1,67 @@
+use oxc_minifier::{CompressOptions, MinifierOptions};
+
+use crate::{test, test_with_options};
+
+#[test]
+fn array_of() {
+    test("Array.of(1, 2)", "[1,2];");
+    test("Array.of()", "[];");
+    test("Array.of(a, ...b)", "[a,...b];");
+    test("Array?.of(1)", "Array?.of(1);");
+    test("Foo.of(1)", "Foo.of(1);");
+}
+
+#[test]
+fn spread() {
+    test("[...'abc']", "['a','b','c'];");
+    test("[0, ...'ab', 3]", "[0,'a','b',3];");
+    test("[...[1, 2], 3]", "[1,2,3];");
+    test("[...[]]", "[];");
+    // Spreading a hole yields `undefined`
+    test("[...[1, , 2]]", "[...[1,,2]];");
+    test("[...a]", "[...a];");
+}
+
+#[test]
+fn length() {
+    test("[1, 2, 3].length", "3;");
+    test("[1, , 3].length", "3;");
+    test("[].length", "0;");
+    test("[...a].length", "[...a].length;");
+    test("[...'abc'].length", "3;");
+    test("Array.of(1, 2).length", "2;");
+    test("[1, 2, 3].map(x => x * 2).length", "3;");
+    test("[1, 2, 3].map(function (x) {}).length", "3;");
+    test("[1, 2, 3].reverse().slice().length", "3;");
+    test("[a(), 2].length", "[a(),2].length;");
+    test("[1, 2].map(f).length", "[1,2].map(f).length;");
+    test("[1, 2].map(x => f(x)).length", "[1,2].map(x=>f(x)).length;");
+    test("[1, 2].map(({ a }) => a).length", "[1,2].map(({a})=>a).length;");
+    test("[1, 2].slice(1).length", "[1,2].slice(1).length;");
+    test("[1, 2].filter(x => x).length", "[1,2].filter(x=>x).length;");
+}
+
+#[test]
+fn fuse_maps() {
+    let options = MinifierOptions {
+        mangle: false,
+        compress: CompressOptions { unsafe_arrays: true, ..CompressOptions::default() },
+    };
+    test_with_options("a.map(x => f(x)).map(x => g(x))", "a.map(x=>(x=f(x),g(x)));", options);
+    test_with_options("a.map(x => f(x)).map(y => g(y))", "a.map((x,y)=>(y=f(x),g(y)));", options);
+    // `y` is referenced by the first callback
+    test_with_options("a.map(x => f(y)).map(y => g(y))", "a.map(x=>f(y)).map(y=>g(y));", options);
+    test_with_options("a.map(f).map(y => g(y))", "a.map(f).map(y=>g(y));", options);
+
+    test("a.map(x => f(x)).map(x => g(x))", "a.map(x=>f(x)).map(x=>g(x));");
+}
+
+#[test]
+fn disabled() {
+    let options = MinifierOptions {
+        mangle: false,
+        compress: CompressOptions { arrays: false, ..CompressOptions::default() },
+    };
+    test_with_options("Array.of(1, 2).length", "Array.of(1,2).length;", options);
+    test_with_options("[...'ab']", "[...'ab'];", options);
+}
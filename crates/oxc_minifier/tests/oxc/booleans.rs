@@ -0,0 +1,62 @@
+use oxc_minifier::CompressOptions;
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+fn test(source_text: &str, expected: &str) {
+    let source_type = SourceType::default();
+    let compress = CompressOptions { booleans: true, ..CompressOptions::all_false() };
+    let expected_compress = CompressOptions::all_false();
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn boolean_calls() {
+    test("x = Boolean(a)", "x = !!a");
+    test("x = Boolean(a, b)", "x = Boolean(a, b)");
+    test("x = Boolean(a.b())", "x = !!a.b()");
+    test("x = Boolean()", "x = !1");
+    test_same("x = Boolean(...a)");
+    test_same("x = Boolean?.(a)");
+    test_same("x = new Boolean(a)");
+    test_same("x = a.Boolean(b)");
+    test_same("function f(Boolean) { return Boolean(a) }");
+}
+
+#[test]
+fn statement_tests() {
+    test("if (!!a) b()", "if (a) b()");
+    test("if (!!!!a) b()", "if (a) b()");
+    test("if (Boolean(a)) b()", "if (a) b()");
+    test("while (!!a) b()", "while (a) b()");
+    test("do b(); while (!!a)", "do b(); while (a)");
+    test("for (;!!a;) b()", "for (;a;) b()");
+    test("if (!!a && Boolean(b)) c()", "if (a && b) c()");
+    test("if (!!a || !!b) c()", "if (a || b) c()");
+    test("if (a ? !!b : Boolean(c)) d()", "if (a ? b : c) d()");
+    test("if ((a, !!b)) c()", "if ((a, b)) c()");
+    test("if (!!(a && !!b)) c()", "if (a && b) c()");
+    // `!!a` is never nullish.
+    test("if (!!a ?? b) c()", "if (!!a ?? b) c()");
+    test("if (a ?? !!b) c()", "if (a ?? !!b) c()");
+    test_same("if (!a) b()");
+    test_same("if (f(!!a)) b()");
+}
+
+#[test]
+fn expression_contexts() {
+    test("x = !!a ? b : c", "x = a ? b : c");
+    test("x = Boolean(a) ? b : c", "x = a ? b : c");
+    test("x = !!!a", "x = !a");
+    test("x = !Boolean(a)", "x = !a");
+    test("x = !(!!a || !!b)", "x = !(a || b)");
+    // The value of `&&` and `||` is one of the operands.
+    test_same("x = !!a && b");
+    test_same("x = a || !!b");
+    test_same("x = a ? !!b : c");
+    test_same("x = !!a");
+}
@@ -0,0 +1,142 @@
+use oxc_minifier::{CompressOptions, MinifierOptions};
+
+use crate::{test, test_with_options};
+
+#[test]
+fn array_is_array() {
+    test("x = Array.isArray([])", "x=!0;");
+    test("x = Array.isArray([1, [2]])", "x=!0;");
+    test("x = Array.isArray({})", "x=!1;");
+    test("x = Array.isArray('')", "x=!1;");
+    test("x = Array.isArray(function () {})", "x=!1;");
+    test("x = Array.isArray()", "x=!1;");
+    test("x = Array.isArray(a)", "x=Array.isArray(a);");
+    test("x = Array.isArray([f()])", "x=Array.isArray([f()]);");
+    test("x = Array.isArray(...a)", "x=Array.isArray(...a);");
+    test("x = Array?.isArray([])", "x=Array?.isArray([]);");
+}
+
+#[test]
+fn number_predicates() {
+    test("x = Number.isNaN(NaN)", "x=!0;");
+    test("x = Number.isNaN(0 / 0)", "x=!0;");
+    test("x = Number.isNaN(1)", "x=!1;");
+    test("x = Number.isFinite(1)", "x=!0;");
+    test("x = Number.isFinite(-1 / 0)", "x=!1;");
+    test("x = Number.isInteger(-0)", "x=!0;");
+    test("x = Number.isInteger(1.5)", "x=!1;");
+    test("x = Number.isInteger(Infinity)", "x=!1;");
+    test("x = Number.isSafeInteger(9007199254740991)", "x=!0;");
+    test("x = Number.isSafeInteger(9007199254740992)", "x=!1;");
+}
+
+#[test]
+fn number_predicates_do_not_convert() {
+    test("x = Number.isNaN('NaN')", "x=!1;");
+    test("x = Number.isNaN(undefined)", "x=!1;");
+    test("x = Number.isNaN()", "x=!1;");
+    test("x = Number.isFinite('1')", "x=!1;");
+    test("x = Number.isInteger(true)", "x=!1;");
+    test("x = Number.isSafeInteger(null)", "x=!1;");
+}
+
+#[test]
+fn global_predicates_convert() {
+    test("x = isNaN(undefined)", "x=!0;");
+    test("x = isNaN()", "x=!0;");
+    test("x = isNaN(null)", "x=!1;");
+    test("x = isNaN(true)", "x=!1;");
+    test("x = isFinite(null)", "x=!0;");
+    test("x = isFinite(NaN)", "x=!1;");
+    // Converting strings and objects to numbers is not evaluated.
+    test("x = isNaN('a')", "x=isNaN('a');");
+    test("x = isFinite([])", "x=isFinite([]);");
+}
+
+#[test]
+fn object_is() {
+    test("x = Object.is(1, 1)", "x=!0;");
+    test("x = Object.is(1, 2)", "x=!1;");
+    test("x = Object.is(NaN, NaN)", "x=!0;");
+    test("x = Object.is(NaN, 0 / 0)", "x=!0;");
+    test("x = Object.is(0, -0)", "x=!1;");
+    test("x = Object.is(-0, -0)", "x=!0;");
+    test("x = Object.is(0, +0)", "x=!0;");
+    test("x = Object.is('a', 'a')", "x=!0;");
+    test("x = Object.is(1, '1')", "x=!1;");
+    test("x = Object.is(null, undefined)", "x=!1;");
+    test("x = Object.is(undefined)", "x=!0;");
+    test("x = Object.is()", "x=!0;");
+    test("x = Object.is([], [])", "x=!1;");
+    test("x = Object.is(a, a)", "x=Object.is(a,a);");
+}
+
+#[test]
+fn shadowed_globals() {
+    test(
+        "function f(Array) { return Array.isArray([]) }",
+        "function f(Array){return Array.isArray([])}",
+    );
+    test("var Object; x = Object.is(1, 1)", "var Object;x=Object.is(1,1);");
+    test(
+        "function f(NaN) { return Number.isNaN(NaN) }",
+        "function f(NaN){return Number.isNaN(NaN)}",
+    );
+    test("function f(isNaN) { return isNaN(1) }", "function f(isNaN){return isNaN(1)}");
+}
+
+#[test]
+fn disabled() {
+    let compress = CompressOptions { builtins: false, ..CompressOptions::default() };
+    let options = MinifierOptions { mangle: false, compress };
+    test_with_options("x = Array.isArray([])", "x=Array.isArray([]);", options);
+}
+
+#[test]
+fn math_functions() {
+    test("x = Math.floor(3.7)", "x=3;");
+    test("x = Math.ceil(3.2)", "x=4;");
+    test("x = Math.trunc(-3.7)", "x=-3;");
+    test("x = Math.round(2.5)", "x=3;");
+    test("x = Math.round(-2.5)", "x=-2;");
+    test("x = Math.round(-0.2)", "x=-0;");
+    test("x = Math.abs(-5)", "x=5;");
+    test("x = Math.sign(-3)", "x=-1;");
+    test("x = Math.sqrt(16)", "x=4;");
+    test("x = Math.pow(2, 8)", "x=256;");
+    test("x = Math.max(1, 5, 3)", "x=5;");
+    test("x = Math.min(1, -5)", "x=-5;");
+    test("x = Math.floor(null)", "x=0;");
+    test("x = Math.floor(Math.PI * 100) / 100", "x=3.14;");
+}
+
+#[test]
+fn math_functions_kept() {
+    test("x = Math.floor(a)", "x=Math.floor(a);");
+    test("x = Math.floor('3')", "x=Math.floor('3');");
+    test("x = Math.floor(...a)", "x=Math.floor(...a);");
+    test("x = Math.floor?.(3.7)", "x=Math.floor?.(3.7);");
+    // The results which are not exact, `NaN` or infinite.
+    test("x = Math.sin(1)", "x=Math.sin(1);");
+    test("x = Math.pow(2, -1)", "x=Math.pow(2,-1);");
+    test("x = Math.pow(2, 60)", "x=Math.pow(2,60);");
+    test("x = Math.max()", "x=Math.max();");
+    test("x = Math.sqrt(-1)", "x=Math.sqrt(-1);");
+}
+
+#[test]
+fn builtin_constants() {
+    test("x = Math.PI * 2", "x=6.283185307179586;");
+    test("x = 2 * Math.PI / 4", "x=1.5707963267948966;");
+    test("x = Number.MAX_SAFE_INTEGER + 1", "x=9007199254740992;");
+    test("x = Number.isInteger(Math.PI)", "x=!1;");
+    test("x = Math.PI", "x=Math.PI;");
+    test("x = Math.PI * y", "x=Math.PI*y;");
+    test("x = 2 * 3", "x=2*3;");
+}
+
+#[test]
+fn shadowed_math() {
+    test("function f(Math) { return Math.floor(1.5) }", "function f(Math){return Math.floor(1.5)}");
+    test("function f(Math) { return Math.PI * 2 }", "function f(Math){return Math.PI*2}");
+}
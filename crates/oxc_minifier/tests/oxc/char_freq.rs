@@ -0,0 +1,60 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::BindingIdentifier, Visit};
+use oxc_minifier::ManglerBuilder;
+use oxc_parser::Parser;
+use oxc_semantic::SymbolId;
+use oxc_span::SourceType;
+
+/// The mangled names of the bindings, in the order they are declared.
+fn mangle(source_text: &str) -> Vec<String> {
+    #[derive(Default)]
+    struct Collector {
+        symbol_ids: Vec<SymbolId>,
+    }
+
+    impl<'a> Visit<'a> for Collector {
+        fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+            self.symbol_ids.extend(ident.symbol_id.get());
+        }
+    }
+
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let program = &*allocator.alloc(program);
+    let mangler = ManglerBuilder::default().build(program);
+    let mut collector = Collector::default();
+    collector.visit_program(program);
+    collector
+        .symbol_ids
+        .into_iter()
+        .map(|symbol_id| mangler.get_symbol_name(symbol_id).to_string())
+        .collect()
+}
+
+#[test]
+fn alphabetical_without_text() {
+    assert_eq!(mangle("(x, y) => x + x + y"), ["a", "b"]);
+}
+
+#[test]
+fn most_frequent_characters() {
+    // `z` is in the property name 8 times, and `n` in the keywords 3 times. The names of the same
+    // length are assigned in the order of the declarations.
+    assert_eq!(mangle("function f(x) { return x.zzzzzzzz }"), ["z", "n"]);
+    // The names of the globals are kept too.
+    assert_eq!(mangle("function f(x) { return $$$$$$(x) }"), ["$", "n"]);
+    assert_eq!(mangle("function f(x) { return '__________' + x }"), ["_", "n"]);
+}
+
+#[test]
+fn renamed_names_are_not_counted() {
+    // `n` is the most frequent in `function` and `return`, followed by `r`.
+    assert_eq!(mangle("function f(qqqqqqqq) { return qqqqqqqq }"), ["n", "r"]);
+}
+
+#[test]
+fn digits_do_not_start_names() {
+    let names = mangle("function f(x) { return x[0] + '00000000' }");
+    assert_eq!(names, ["n", "r"]);
+}
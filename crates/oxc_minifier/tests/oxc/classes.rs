@@ -0,0 +1,117 @@
+use oxc_minifier::CompressOptions;
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+fn test_with_source_type(source_text: &str, expected: &str, source_type: SourceType) {
+    let compress = CompressOptions { unsafe_classes: true, ..CompressOptions::all_false() };
+    let expected_compress = CompressOptions::all_false();
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test(source_text: &str, expected: &str) {
+    test_with_source_type(source_text, expected, SourceType::default().with_module(true));
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn object() {
+    test("class A { static f() { return 1 } } A.f()", "let A = { f() { return 1 } }; A.f()");
+    test(
+        "class A { static f() {} static g(x) { return this.f(x) } } A.g(A.f)",
+        "let A = { f() {}, g(x) { return this.f(x) } }; A.g(A.f)",
+    );
+    test("class A {}", "let A = {}");
+    test(
+        "function g() { class A { static f() {} } return A.f() }",
+        "function g() { let A = { f() {} }; return A.f() }",
+    );
+    test(
+        "{ class A { static *f() {} static async g() {} } }",
+        "{ let A = { *f() {}, async g() {} } }",
+    );
+}
+
+#[test]
+fn function() {
+    test(
+        "class A { constructor(a) { this.a = a } } new A(1)",
+        "function A(a) { this.a = a } new A(1)",
+    );
+    test("class A { constructor() {} } x instanceof A", "function A() {} x instanceof A");
+    test(
+        "class A { constructor() {} static f() {} } new A(A.f())",
+        "function A() {} A.f = function () {}; new A(A.f())",
+    );
+    // Assigning the static methods would be longer than the class.
+    test_same("class A { constructor() {} static f() {} static g() {} } new A");
+    test_same("class Abcdefg { constructor() {} static f() {} } new Abcdefg");
+    // Without a constructor, a constructed class stays a class.
+    test_same("class A { static f() {} } new A");
+}
+
+#[test]
+fn elements() {
+    test_same("class A { f() {} } A");
+    test_same("class A { get f() {} } A");
+    test_same("class A { static get f() {} } A.f");
+    test_same("class A { static a = 1 } A.a");
+    test_same("class A { static {} }");
+    test_same("class A { static ['f']() {} } A.f()");
+    test_same("class A { static #f() {} }");
+    test_same("class A { static 'f'() {} } A.f()");
+    test_same("class A { static f() {} static f() {} } A.f()");
+    test_same("class A { static name() {} } A.name()");
+    test_same("class A { static prototype() {} } A.prototype()");
+    test_same("class A { static __proto__() {} } A.__proto__()");
+}
+
+#[test]
+fn heritage_and_meta() {
+    test_same("class A extends B { static f() {} } A.f()");
+    test_same("class A { constructor() { if (!new.target) throw 0 } } new A");
+    test_same("class A { static f() { return super.toString() } } A.f()");
+    test_same("class A { static f() { return () => new.target } } A.f()");
+}
+
+#[test]
+fn references() {
+    test_same("class A { static f() {} } A.prototype");
+    test_same("class A { static f() {} } A.name");
+    test_same("class A { static f() {} } A.g()");
+    test_same("class A { static f() {} } A['f']()");
+    test_same("class A { static f() {} } g(A)");
+    test_same("class A { static f() {} } A = 1");
+    test_same("class A { static f() {} } typeof A");
+    test_same("class A { constructor() {} } A instanceof B");
+    test_same("class A { constructor() {} } new B(A)");
+}
+
+#[test]
+fn scope() {
+    test_same("export class A { static f() {} }");
+    test_same("export default class A { static f() {} }");
+    test_same("class A { static f() {} } export { A }");
+    test_same("class A { static f() {} } eval('A')");
+    // Top-level bindings of a script are visible to other scripts.
+    test_with_source_type(
+        "class A { static f() {} }",
+        "class A { static f() {} }",
+        SourceType::default(),
+    );
+    // Functions and object literals are sloppy in sloppy mode code.
+    test_with_source_type(
+        "function g() { class A { static f() { x = 1 } } return A.f() }",
+        "function g() { class A { static f() { x = 1 } } return A.f() }",
+        SourceType::default(),
+    );
+    test_with_source_type(
+        "function g() { 'use strict'; class A { static f() {} } return A.f() }",
+        "function g() { 'use strict'; let A = { f() {} }; return A.f() }",
+        SourceType::default(),
+    );
+    test_same("const A = class { static f() {} }; A.f()");
+}
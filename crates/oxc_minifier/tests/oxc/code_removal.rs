@@ -12,10 +12,10 @@ fn undefined_assignment() {
 
 #[test]
 fn undefined_return() {
-    test("function f(){return undefined;}", "function f(){return}");
-    test("function f(){return void 0;}", "function f(){return}");
+    test("function f(){return undefined;}", "function f(){}");
+    test("function f(){return void 0;}", "function f(){}");
     test("function f(){return void foo();}", "function f(){return void foo()}");
-    test("function f(){if(a()){return undefined;}}", "function f(){if(a())return}");
+    test("function f(){if(a()){return undefined;}}", "function f(){a()}");
 }
 
 #[test]
@@ -26,9 +26,15 @@ fn console_removal() {
     };
     test_with_options("console.log('hi')", "", options);
     test_with_options("let x = console.error('oops')", "let x;", options);
+    test_with_options("function f() { return console.warn('problem') }", "function f(){}", options);
+    test_with_options("if (a) console.log(a)", "a;", options);
+    test_with_options("a(), console.log(a), b()", "a(),b();", options);
+    test_with_options("a ? console.log(a) : b()", "a||b();", options);
+    test_with_options("a && console.log(a)", "a;", options);
+    test_with_options("x = (console.log(a), y)", "x=y;", options);
     test_with_options(
-        "function f() { return console.warn('problem') }",
-        "function f(){return}",
+        "function f(console) { console.log(a) }",
+        "function f(console){console.log(a)}",
         options,
     );
 
@@ -37,3 +43,15 @@ fn console_removal() {
     let options = MinifierOptions { mangle: false, ..MinifierOptions::default() };
     test_with_options("console.log('hi')", "console.log('hi');", options);
 }
+
+#[test]
+fn debugger_removal() {
+    test("if (a) debugger", "a;");
+    test("function f() { debugger }", "function f(){}");
+
+    let options = MinifierOptions {
+        mangle: false,
+        compress: CompressOptions { drop_debugger: false, ..CompressOptions::default() },
+    };
+    test_with_options("if (a) debugger", "if(a)debugger;", options);
+}
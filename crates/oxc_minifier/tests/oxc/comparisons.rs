@@ -0,0 +1,124 @@
+use oxc_minifier::CompressOptions;
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+fn test_with_options(source_text: &str, expected: &str, compress: CompressOptions) {
+    let source_type = SourceType::default();
+    let expected_compress = CompressOptions::all_false();
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test(source_text: &str, expected: &str) {
+    let compress = CompressOptions { comparisons: true, ..CompressOptions::all_false() };
+    test_with_options(source_text, expected, compress);
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+fn test_unsafe(source_text: &str, expected: &str) {
+    let compress = CompressOptions { unsafe_comparisons: true, ..CompressOptions::all_false() };
+    test_with_options(source_text, expected, compress);
+}
+
+#[test]
+fn boolean_comparisons() {
+    test("a < b === true", "a < b");
+    test("a < b == true", "a < b");
+    test("true === a < b", "a < b");
+    test("a in b !== false", "a in b");
+    test("a instanceof b != false", "a instanceof b");
+    test("a === b === true", "a === b");
+    test("a === b === false", "a !== b");
+    test("a != b == false", "a == b");
+    test("!a === false", "!!a");
+    test("!a === true", "!a");
+    test("!(a < b) === false", "a < b");
+    test("a.b() < c !== true", "!(a.b() < c)");
+    // `a < b` and `a >= b` are both false when either is `NaN`.
+    test("a < b === false", "!(a < b)");
+    test("a <= b !== true", "!(a <= b)");
+    test("false == a > b", "!(a > b)");
+    // `a` may not be a boolean.
+    test_same("a === true");
+    test_same("a == false");
+    test_same("a + b === true");
+    test_same("a < b === 1");
+}
+
+#[test]
+fn typeof_comparisons() {
+    test("typeof a === typeof b", "typeof a == typeof b");
+    test("typeof a !== typeof b", "typeof a != typeof b");
+    test("typeof a === 'string'", "typeof a == 'string'");
+    test("'function' !== typeof a", "'function' != typeof a");
+    test("typeof a === 'strnig'", "false");
+    test("typeof a != 'Object'", "true");
+    test("typeof a() === 'strnig'", "typeof a() == 'strnig'");
+    test_same("typeof a == 'string'");
+    test_same("typeof a === b");
+    test_same("typeof a < typeof b");
+}
+
+#[test]
+fn range_checks() {
+    // `x` may be an object whose `valueOf` returns a different number each time.
+    test_same("x > 1 && x > 5");
+    test_same("y > 1 || y > 5");
+
+    test_unsafe("x > 1 && x > 5", "x > 5");
+    test_unsafe("x > 5 && x > 1", "x > 5");
+    test_unsafe("x >= 5 && x > 5", "x > 5");
+    test_unsafe("x < 1 && x <= 5", "x < 1");
+    test_unsafe("x > 5 || x > 1", "x > 1");
+    test_unsafe("x < 1 || x < 5", "x < 5");
+    test_unsafe("x >= 5 || x > 5", "x >= 5");
+    test_unsafe("1 < x && x > 5", "x > 5");
+    test_unsafe("x > -1 && x > -5", "x > -1");
+    test_unsafe("x > 0.5 && 2 <= x", "2 <= x");
+    test_unsafe("a && x > 1 && x > 5", "a && x > 5");
+    test_unsafe("a || x > 1 || x > 5", "a || x > 1");
+    test_unsafe("x > 1 && x > 5 && x > 3", "x > 5");
+    test_unsafe("if (x > 1 && x > 5) a()", "if (x > 5) a()");
+    // No number passes both checks, and `NaN` passes neither.
+    test_unsafe("x > 5 && x < 3", "false");
+    test_unsafe("x >= 3 && x < 3", "false");
+    test_unsafe("3 > x && 5 < x", "false");
+    test_unsafe("a && x > 5 && x < 3", "a && false");
+    test_unsafe("x >= 3 && x <= 3", "x >= 3 && x <= 3");
+    test_unsafe("x > 1 && x < 3", "x > 1 && x < 3");
+    // Every number passes one of the checks, but `NaN` passes neither.
+    test_unsafe("x > 1 || x < 3", "x > 1 || x < 3");
+    test_unsafe("x >= 3 || x <= 3", "x >= 3 || x <= 3");
+    test_unsafe("x < 1 || x > 5", "x < 1 || x > 5");
+    // Not range checks on the same identifier.
+    test_unsafe("x > 1 && y > 5", "x > 1 && y > 5");
+    test_unsafe("x.y > 1 && x.y > 5", "x.y > 1 && x.y > 5");
+    test_unsafe("x > '1' && x > '5'", "x > '1' && x > '5'");
+    test_unsafe("x > a && x > 5", "x > a && x > 5");
+    test_unsafe("x > 1 ?? x > 5", "x > 1 ?? x > 5");
+    test_unsafe("(a || x > 1) && x > 5", "(a || x > 1) && x > 5");
+}
+
+#[test]
+fn self_comparisons() {
+    // `x !== x` is the test for `NaN`.
+    test_same("x === x");
+    test_same("x !== x");
+    test_same("x < x");
+    test_same("x <= x");
+
+    test_unsafe("x === x", "true");
+    test_unsafe("x == x", "true");
+    test_unsafe("x !== x", "false");
+    test_unsafe("x != x", "false");
+    test_unsafe("x < x", "false");
+    test_unsafe("x > x", "false");
+    test_unsafe("x <= x", "true");
+    test_unsafe("x >= x", "true");
+    test_unsafe("x === y", "x === y");
+    test_unsafe("x.y === x.y", "x.y === x.y");
+    test_unsafe("x + x", "x + x");
+}
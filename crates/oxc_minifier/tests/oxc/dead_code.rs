@@ -0,0 +1,106 @@
+use oxc_minifier::CompressOptions;
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+fn test_with_options(source_text: &str, expected: &str, compress: CompressOptions) {
+    let source_type = SourceType::default().with_module(true);
+    let expected_compress = CompressOptions::all_false();
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test(source_text: &str, expected: &str) {
+    test_with_options(source_text, expected, CompressOptions::default());
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+fn test_production(source_text: &str, expected: &str) {
+    let options = CompressOptions { node_env: Some("production"), ..CompressOptions::default() };
+    test_with_options(source_text, expected, options);
+}
+
+#[test]
+fn if_statements() {
+    test("if (true) a(); else b()", "a()");
+    test("if (false) a(); else b()", "b()");
+    test("if (false) a(); b()", "b()");
+    test("if (!0) a()", "a()");
+    test("if ('') a(); else b()", "b()");
+    test("if (null) a(); else { let x = b(); c(x) }", "{ let x = b(); c(x) }");
+    test("function f() { if (false) return 1; return 2 }", "function f() { return 2 }");
+}
+
+#[test]
+fn hoisted_bindings() {
+    // `var` declarations are visible outside of the removed branch.
+    test("if (false) { var x = a() } b(x)", "if (!1) { var x = a() } b(x)");
+    test("if (false) { let x = a() } b()", "b()");
+    test("if (false) { (function () { var x }) } b()", "b()");
+}
+
+#[test]
+fn conditional_expressions() {
+    test("x = true ? a : b", "x = a");
+    test("x = false ? a : b", "x = b");
+    test("x = !1 ? a : b", "x = b");
+    test_same("x = y ? a : b");
+}
+
+#[test]
+fn pure_calls() {
+    test("function f(x) { return x * 2 } f(1); b()", "function f(x) { return x * 2 } b()");
+    test(
+        "function f(x) { return g(x) } function g(x) { return x } f(1)",
+        "function f(x) { return g(x) } function g(x) { return x }",
+    );
+    // The arguments and the callee may have side effects.
+    test_same("function f(x) { return x } f(a())");
+    test_same("function f(x) { return x.y } f(1)");
+    test_same("function f(x) { a = x } f(1)");
+    test_same("function f(x) { return f(x) } f(1)");
+    test("function f(x) { return x } f = g; f(1)", "function f(x) { return x } f = g, f(1)");
+}
+
+#[test]
+fn pure_funcs() {
+    let options = CompressOptions {
+        pure_funcs: &["invariant", "console.log", "a.b.c"],
+        ..CompressOptions::default()
+    };
+    let test_pure = |source_text, expected| test_with_options(source_text, expected, options);
+    test_pure("invariant(x, 'message'); console.log(x); a.b.c(); d()", "d()");
+    test_pure("import invariant from 'm'; invariant(x)", "import 'm'");
+    // The result is used, or the arguments have side effects.
+    test_pure("x = invariant(y)", "x = invariant(y)");
+    test_pure("console.log(f())", "console.log(f())");
+    test_pure("console.log(...x)", "console.log(...x)");
+    // Other functions are kept.
+    test_pure("console.warn(x); a.c(); b.c()", "console.warn(x), a.c(), b.c()");
+    test_same("console.log(x)");
+}
+
+#[test]
+fn node_env() {
+    test_production("x = process.env.NODE_ENV", "x = 'production'");
+    test_production("x = process.env['NODE_ENV']", "x = 'production'");
+    test_production("if (process.env.NODE_ENV !== 'production') a(); b()", "b()");
+    test_production("if (process.env.NODE_ENV === 'production') a(); else b()", "a()");
+    test_production("x = process.env.NODE_ENV === 'development' ? a : b", "x = b");
+    // Assignments and shadowed `process` are kept.
+    test_production("process.env.NODE_ENV = 'test'", "process.env.NODE_ENV = 'test'");
+    test_production(
+        "function f(process) { return process.env.NODE_ENV }",
+        "function f(process) { return process.env.NODE_ENV }",
+    );
+    // Not replaced by default.
+    test_same("x = process.env.NODE_ENV");
+}
+
+#[test]
+fn disabled() {
+    let options = CompressOptions { dead_code: false, ..CompressOptions::default() };
+    test_with_options("x = true ? a : b", "x = !0 ? a : b", options);
+}
@@ -0,0 +1,78 @@
+use oxc_span::SourceType;
+
+use crate::{test_with_compress_options, CompressOptions};
+
+fn test_with_options(source_text: &str, expected: &str, compress: CompressOptions) {
+    let source_type = SourceType::default().with_module(true);
+    let expected_compress = CompressOptions { dead_stores: false, ..compress };
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test(source_text: &str, expected: &str) {
+    test_with_options(source_text, expected, CompressOptions::default());
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn overwritten() {
+    test("let x; x = 1; x = 2; f(x)", "let x; x = 2; f(x)");
+    test(
+        "function g() { var x; x = 1; x = 2; return x }",
+        "function g() { var x; x = 2; return x }",
+    );
+    test(
+        "function g() { let x; x = 1; var y = 2; x = y; return x }",
+        "function g() { let x; var y = 2; x = y; return x }",
+    );
+    // The value is kept for its side effects
+    test(
+        "function g() { let x; x = f(); x = 2; return x }",
+        "function g() { let x; f(); x = 2; return x }",
+    );
+    // Nothing but `g` can read `x`
+    test(
+        "function g() { let x; x = 1; f(); x = 2; return x }",
+        "function g() { let x; f(); x = 2; return x }",
+    );
+}
+
+#[test]
+fn out_of_scope() {
+    test("function g() { let x; x = 1; f(x); x = 2 }", "function g() { let x; x = 1; f(x) }");
+    test("function g() { { let x = 0; f(x); x = 1 } }", "function g() { { let x = 0; f(x) } }");
+    // `var` is visible to the whole function
+    test_same("function g() { { var x = 0; f(x); x = 1 } return x }");
+    // `case 1` can read `y`
+    test_same("function g(a) { switch (a) { case 0: let y; y = 1; case 1: f(y) } }");
+}
+
+#[test]
+fn observed() {
+    test_same("let x; x = 1; f(x); x = 2; f(x)");
+    test_same("function g() { let x; x = 1; x = x + 1; return x }");
+    // Unresolved and exported variables can be read by anyone
+    test_same("x = 1; f(); x = 2");
+    test_same("export let x; x = 1; f(); x = 2");
+    // Captured by a closure
+    test_same("function g() { let x; const h = () => x; x = 1; h(); x = 2; return h }");
+    // Read by `catch`
+    test_same("function g() { let x; try { x = 1; f(); x = 2 } catch { return x } }");
+    test_same("function g() { var x; x = 1; eval('x'); x = 2; return x }");
+    // `x = 1` throws in the temporal dead zone
+    test_same("function g() { x = 1; x = 2; let x; return x }");
+    // Control flow in between
+    test_same("function g() { let x; for (;;) { x = 1; if (f()) break; x = 2 } return x }");
+}
+
+#[test]
+fn disabled() {
+    let options = CompressOptions { dead_stores: false, ..CompressOptions::default() };
+    test_with_options(
+        "function g() { var x; x = 1; x = 2; return x }",
+        "function g() { var x; x = 1; x = 2; return x }",
+        options,
+    );
+}
@@ -0,0 +1,82 @@
+use oxc_minifier::CompressOptions;
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+fn test(source_text: &str, expected: &str) {
+    let source_type = SourceType::default();
+    let compress = CompressOptions { unsafe_default_params: true, ..CompressOptions::default() };
+    let expected_compress =
+        CompressOptions { unsafe_default_params: false, ..CompressOptions::default() };
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn leading_checks() {
+    test("function f(a) { if (a === void 0) a = 1; return a }", "function f(a = 1) { return a }");
+    test(
+        "function f(a) { if (a === undefined) { a = g(); } return a }",
+        "function f(a = g()) { return a }",
+    );
+    test(
+        "function f(a) { if (typeof a === 'undefined') a = 1; return a }",
+        "function f(a = 1) { return a }",
+    );
+    test(
+        "function f(a, b) { if (void 0 === a) a = 1; if (b === void 0) b = a + 1; return a + b }",
+        "function f(a = 1, b = a + 1) { return a + b }",
+    );
+    test(
+        "const f = (a) => { if (a === void 0) a = 1; return a }",
+        "const f = (a = 1) => { return a }",
+    );
+    test(
+        "class A { m(a) { if (a === void 0) a = {}; return a } }",
+        "class A { m(a = {}) { return a } }",
+    );
+    // Only the leading checks
+    test(
+        "function f(a, b) { if (a === void 0) a = 1; g(); if (b === void 0) b = 2; return a + b }",
+        "function f(a = 1, b) { g(); if (b === void 0) b = 2; return a + b }",
+    );
+}
+
+#[test]
+fn evaluation_order() {
+    // Out of order, only the leading check is moved
+    test(
+        "function f(a, b) { if (b === void 0) b = 1; if (a === void 0) a = b; return a + b }",
+        "function f(a, b = 1) { if (a === void 0) a = b; return a + b }",
+    );
+    // A later parameter or a binding of the body
+    test_same("function f(a, b) { if (a === void 0) a = b; return a }");
+    test_same("function f(a) { if (a === void 0) a = b; var b = 1; return a }");
+    test_same("function f(a) { if (a === void 0) a = g; function g() {} return a }");
+    test(
+        "function f(a) { if (a === void 0) a = b; return a } var b = 1",
+        "function f(a = b) { return a } var b = 1",
+    );
+    test(
+        "function f(a) { if (a === void 0) a = (x) => x + 1; return a }",
+        "function f(a = (x) => x + 1) { return a }",
+    );
+    test_same("function* f(a) { if (a === void 0) a = yield; return a }");
+    test_same("async function f(a) { if (a === void 0) a = await g(); return a }");
+}
+
+#[test]
+fn signatures() {
+    test_same("function f(a) { 'use strict'; if (a === void 0) a = 1; return a }");
+    test_same("function f(a) { if (a === void 0) a = 1; return arguments }");
+    test_same("function f(a, a) { if (a === void 0) a = 1; return a }");
+    test_same("function f(a) { if (a === null) a = 1; return a }");
+    test_same("function f(a) { if (a == void 0) a = 1; return a }");
+    test_same("function f(a) { if (a === void 0) a = 1; else g(); return a }");
+    test_same("function f(a) { if (a === void 0) a += 1; return a }");
+    test_same("function f({ a }) { if (a === void 0) a = 1; return a }");
+    test_same("function f(a, undefined) { if (a === undefined) a = 1; return a }");
+}
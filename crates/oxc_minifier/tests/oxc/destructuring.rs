@@ -0,0 +1,86 @@
+use oxc_minifier::CompressOptions;
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+fn test(source_text: &str, expected: &str) {
+    let source_type = SourceType::default();
+    let compress = CompressOptions { destructuring: true, ..CompressOptions::all_false() };
+    let expected_compress = CompressOptions::all_false();
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn object() {
+    test("const { a } = { a: 1, b: g() }", "g(); const a = 1");
+    test("const { a, b: c } = { a: 1, b: 2 }", "const a = 1, c = 2");
+    test("let { a } = { b: 1, a: x }", "let a = x");
+    test("var { a, 'b': c } = { 'a': f(), b: g() }", "var a = f(), c = g()");
+    test("const { a } = { a: 1, b: f(), c: g() }", "f(), g(); const a = 1");
+    test("const { a } = { b: f(), a: x }", "f(); const a = x");
+    test("const {} = { a: f(), b: 1 }", "f()");
+    test(
+        "function g() { const { a } = { a: 1 }; return a }",
+        "function g() { const a = 1; return a }",
+    );
+}
+
+#[test]
+fn array() {
+    test("const [x] = [1, 2]", "const x = 1");
+    test("const [x, y] = [a, b]", "const x = a, y = b");
+    test("const [, x] = [f(), 1]", "f(); const x = 1");
+    test("const [x, , y] = [1, f(), -2]", "f(); const x = 1, y = -2");
+    test("const [x] = [1, f()]", "f(); const x = 1");
+}
+
+#[test]
+fn declarations() {
+    test("const a = 1, { b } = { b: 2, c: f() }, d = 3", "const a = 1; f(); const b = 2, d = 3");
+    test("let [a] = [1], [b] = [2]", "let a = 1, b = 2");
+}
+
+#[test]
+fn evaluation_order() {
+    // `x` may be changed by `f()`, and `g()` must be called before `f()`.
+    test_same("const { a } = { a: x, b: f() };");
+    test_same("const [x] = [g(), f()];");
+    test("const [x, , y] = [g(), 1, f()]", "const x = g(), y = f()");
+    // `x` is read before `y` is assigned, and `await` lets other code change `x`.
+    test("let [a, , b] = [x, y = 1, 2]", "y = 1; let a = x, b = 2");
+    test_same("let [a, , b] = [y, y = 1, 2];");
+    test_same("async function g() { let [a, , b] = [x, await p, 2]; }");
+}
+
+#[test]
+fn function_names() {
+    test("const { a } = { a: () => {} }", "const a = () => {}");
+    test("const { a } = { a: function b() {} }", "const a = function b() {}");
+    test_same("const { a: b } = { a: function() {} };");
+    test_same("const [a] = [class {}];");
+}
+
+#[test]
+fn unknown_values() {
+    test_same("const { a } = { ...b };");
+    test_same("const { a } = { a: 1, ...b };");
+    test_same("const { toString } = {};");
+    test_same("const { a = 1 } = { a: 2 };");
+    test_same("const { a, ...b } = { a: 1 };");
+    test_same("const { a: { b } } = { a: { b: 1 } };");
+    test_same("const { [a]: b } = { a: 1 };");
+    test_same("const { a } = { [a]: 1 };");
+    test_same("const { a } = { get a() { return 1; } };");
+    test_same("const { a } = { a() {} };");
+    test_same("const { __proto__: a } = { __proto__: b };");
+    test_same("const { 1: a } = { 1: b };");
+    test_same("const [a] = [...b];");
+    test_same("const [a] = [, 1];");
+    test_same("const [a, b] = [1];");
+    test_same("const [a, ...b] = [1, 2];");
+    test_same("const [a = 1] = [2];");
+}
@@ -0,0 +1,78 @@
+//! The output must be byte-identical for identical inputs and options, regardless of hash map
+//! iteration order, the thread running the minifier, or how many run at the same time.
+
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::BindingIdentifier, Visit};
+use oxc_minifier::{CompressOptions, ManglerBuilder, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SymbolId;
+use oxc_span::SourceType;
+
+use crate::minify;
+
+const SOURCE: &str = "
+import { a } from 'm'; import 'n'; import b from 'm'; import { c } from 'o'; import { d } from 'm';
+export { a }; export { b };
+var E = function(E) { E[E['X'] = 0] = 'X'; E[E['Y'] = 1] = 'Y'; return E; }(E || {});
+const F = Object.freeze({ X: 'x', Y: 'y', Z: 'z' });
+const o = { p: 1, q: 2, p: f(), r: [1e21, 0.1, 1.5e-7, 123456789012345680000, 2 ** 53, -0.0] };
+function g(x, y, z) { if (x) { let u = y; return u + F.X } return z?.w ?? E.Y }
+export default function h(i, j) { const k = g(i, j, c, d); return [k, E[1], F.Z, o] }
+";
+
+fn mangled_names(source_text: &str) -> Vec<String> {
+    #[derive(Default)]
+    struct Collector {
+        symbol_ids: Vec<SymbolId>,
+    }
+
+    impl<'a> Visit<'a> for Collector {
+        fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+            self.symbol_ids.extend(ident.symbol_id.get());
+        }
+    }
+
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let program = &*allocator.alloc(program);
+    let mangler = ManglerBuilder::default().build(program);
+    let mut collector = Collector::default();
+    collector.visit_program(program);
+    collector
+        .symbol_ids
+        .into_iter()
+        .map(|symbol_id| mangler.get_symbol_name(symbol_id).to_string())
+        .collect()
+}
+
+#[test]
+fn repeated_runs() {
+    let source_type = SourceType::default().with_module(true);
+    for compress in [CompressOptions::default(), CompressOptions::all_true()] {
+        let options = MinifierOptions { mangle: false, compress };
+        let expected = minify(SOURCE, source_type, options);
+        for _ in 0..4 {
+            assert_eq!(expected, minify(SOURCE, source_type, options));
+        }
+    }
+    let expected = mangled_names(SOURCE);
+    for _ in 0..4 {
+        assert_eq!(expected, mangled_names(SOURCE));
+    }
+}
+
+#[test]
+fn threads() {
+    let source_type = SourceType::default().with_module(true);
+    let options = MinifierOptions { mangle: false, compress: CompressOptions::all_true() };
+    let expected = (minify(SOURCE, source_type, options), mangled_names(SOURCE));
+    std::thread::scope(|scope| {
+        let handles = (0..8)
+            .map(|_| scope.spawn(|| (minify(SOURCE, source_type, options), mangled_names(SOURCE))))
+            .collect::<Vec<_>>();
+        for handle in handles {
+            assert_eq!(expected, handle.join().unwrap());
+        }
+    });
+}
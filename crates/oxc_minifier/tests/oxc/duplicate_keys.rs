@@ -0,0 +1,70 @@
+use oxc_allocator::Allocator;
+use oxc_minifier::{Minifier, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+use crate::{test, test_same};
+
+#[test]
+fn duplicate_keys() {
+    test("x = { a: 1, b: 2, a: 3 }", "x={a:3,b:2};");
+    test("x = { a: 1, a: 2, a: 3 }", "x={a:3};");
+    test("x = { a: 1, 'a': 2 }", "x={a:2};");
+    test("x = { 1: a, '1': b, 1.0: c }", "x={1:c};");
+    test("x = { a: 1, a() {} }", "x={a(){}};");
+    test("x = { a, b, a: 1 }", "x={a:1,b};");
+    test("x = { a: { b: 1, b: 2 } }", "x={a:{b:2}};");
+    // Numbers are compared by their string in JavaScript.
+    test("x = { 1.5: a, '1.5': b }", "x={'1.5':b};");
+    test("x = { 1e21: a, '1e+21': b }", "x={'1e+21':b};");
+    test("x = { 10n: a, 10: b }", "x={10:b};");
+}
+
+#[test]
+fn accessors() {
+    test("x = { get a() {}, get a() { return 1 } }", "x={get a(){return 1}};");
+    test("x = { a: 1, set a(v) {} }", "x={set a(v){}};");
+    test("x = { get a() {}, set a(v) {}, a: 1 }", "x={a:1};");
+    test_same("x={get a(){},set a(v){}};");
+    test_same("x={set a(v){},get a(){}};");
+}
+
+#[test]
+fn barriers() {
+    // Spread properties and unknown computed keys can define any key.
+    test_same("x={a:1,...y,a:2};");
+    test_same("x={a:1,[k]:2,a:3};");
+    test("x = { a: 1, ['a']: 2 }", "x={a:2};");
+    // The last value is evaluated before the properties in between.
+    test_same("x={a:1,b:f(),a:2};");
+    test("x = { a: 1, b: 2, a: f() }", "x={a:f(),b:2};");
+}
+
+#[test]
+fn side_effects() {
+    test("x = { a: f(), a: 1 }", "x={a:(f(),1)};");
+    test("x = { a: f(), b: 2, a }", "x={a:(f(),a),b:2};");
+    test_same("x={a:f(),a(){}};");
+    test_same("x={a:f(),get a(){}};");
+}
+
+#[test]
+fn proto() {
+    // `__proto__: x` sets the prototype instead of defining a property.
+    test_same("x={__proto__:null,['__proto__']:1};");
+    test("x = { '__proto__': null, __proto__() {} }", "x={__proto__:null,__proto__(){}};");
+    test("x = { ['__proto__']: 1, ['__proto__']: 2 }", "x={['__proto__']:2};");
+    test("x = { __proto__() {}, __proto__ }", "x={__proto__};");
+    test_same("x={['__proto__']:f(),__proto__};");
+}
+
+#[test]
+fn warnings() {
+    let source_text = "x = { a: f(), a: 1, b: 1, b: 2 }";
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+    let program = allocator.alloc(program);
+    let ret = Minifier::new(MinifierOptions::default()).build(&allocator, program);
+    assert_eq!(ret.warnings.len(), 1);
+    assert!(ret.warnings[0].to_string().contains("Duplicate key `a`"));
+}
@@ -0,0 +1,52 @@
+use oxc_allocator::Allocator;
+use oxc_minifier::{Minifier, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+use crate::{test, test_same};
+
+#[test]
+fn with_statement() {
+    // `o.undefined` and `o.Infinity` may be found first.
+    test_same("with(o)x=undefined;");
+    test_same("with(o)x=Infinity;");
+    test_same("x=undefined;with(o)y=1;");
+}
+
+#[test]
+fn direct_eval() {
+    test(
+        "function f() { eval('var undefined = 1'); return undefined }",
+        "function f(){return eval('var undefined = 1'),undefined}",
+    );
+    test(
+        "function g() { function f(a, b) { return a } eval(s); return f(1, 2) }",
+        "function g(){function f(a,b){return a}return eval(s),f(1,2)}",
+    );
+    // `f` may be reassigned by `eval`.
+    test_same("function f(x){return x}f(1),eval('f = g');");
+}
+
+#[test]
+fn indirect_eval() {
+    // Indirect `eval` runs in the global scope and can not see the local bindings.
+    test("x = undefined; (0, eval)('x')", "x=void 0,(0,eval)('x');");
+    test("x = undefined; eval?.('x')", "x=void 0,eval?.('x');");
+}
+
+#[test]
+fn literal_folds() {
+    test("with (o) x = !0 ? a : b", "with(o)x=a;");
+    test("eval(s); if (false) f()", "eval(s);");
+}
+
+#[test]
+fn warnings() {
+    let source_text = "with (o) { eval(s) }";
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+    let program = allocator.alloc(program);
+    let ret = Minifier::new(MinifierOptions::default()).build(&allocator, program);
+    assert_eq!(ret.warnings.len(), 1);
+    assert!(ret.warnings[0].to_string().contains("a `with` statement"));
+}
@@ -10,8 +10,8 @@ fn addition_folding() {
 
 #[test]
 fn typeof_folding() {
-    test("typeof x === 'undefined'", "void 0===x;");
-    test("'undefined' === typeof x", "void 0===x;");
+    test("var x; typeof x === 'undefined'", "var x;void 0===x;");
+    test("var x; 'undefined' === typeof x", "var x;void 0===x;");
 }
 
 #[test]
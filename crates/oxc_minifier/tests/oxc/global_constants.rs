@@ -0,0 +1,40 @@
+use oxc_minifier::{CompressOptions, MinifierOptions};
+
+use crate::{test, test_with_options};
+
+#[test]
+fn infinity() {
+    test("x = Infinity", "x=1/0;");
+    test("x = -Infinity", "x=-1/0;");
+    test("x = Infinity.toString()", "x=(1/0).toString();");
+    test("x = Number.POSITIVE_INFINITY", "x=1/0;");
+    test("x = Number.NEGATIVE_INFINITY", "x=-1/0;");
+    test("x = -Number.NEGATIVE_INFINITY", "x=1/0;");
+    test("x = { Infinity }", "x={Infinity:1/0};");
+}
+
+#[test]
+fn nan() {
+    test("x = NaN", "x=NaN;");
+    test("x = Number.NaN", "x=0/0;");
+    // `0/0` only when `1/0` is there too.
+    test("x = [Infinity, NaN]", "x=[1/0,0/0];");
+    test("x = { NaN }", "x={NaN};");
+    test("x = { Infinity, NaN }", "x={Infinity:1/0,NaN:0/0};");
+}
+
+#[test]
+fn shadowed_globals() {
+    test("function f(Infinity) { g(Infinity) }", "function f(Infinity){g(Infinity)}");
+    test("var Number; x = Number.POSITIVE_INFINITY", "var Number;x=Number.POSITIVE_INFINITY;");
+    test("function f(NaN) { g(NaN, Infinity) }", "function f(NaN){g(NaN,1/0)}");
+    test("function f(undefined) { g(undefined) }", "function f(undefined){g(undefined)}");
+    test("delete Infinity", "delete Infinity;");
+}
+
+#[test]
+fn disabled() {
+    let compress = CompressOptions { global_constants: false, ..CompressOptions::default() };
+    let options = MinifierOptions { mangle: false, compress };
+    test_with_options("x = Infinity", "x=Infinity;", options);
+}
@@ -0,0 +1,55 @@
+use oxc_minifier::{CompressOptions, GlobalDefValue};
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+const GLOBAL_DEFS: &[(&str, GlobalDefValue)] = &[
+    ("__DEV__", GlobalDefValue::Boolean(false)),
+    ("process.env.NODE_ENV", GlobalDefValue::String("production")),
+    ("VERSION", GlobalDefValue::Number(3.0)),
+    ("OFFSET", GlobalDefValue::Number(-1.5)),
+    ("config.api.url", GlobalDefValue::Null),
+    ("LEGACY", GlobalDefValue::Undefined),
+];
+
+fn test_with_options(source_text: &str, expected: &str, compress: CompressOptions) {
+    let source_type = SourceType::default().with_module(true);
+    let expected_compress = CompressOptions::all_false();
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test(source_text: &str, expected: &str) {
+    let options = CompressOptions { global_defs: GLOBAL_DEFS, ..CompressOptions::default() };
+    test_with_options(source_text, expected, options);
+}
+
+#[test]
+fn replaced() {
+    test("x = process.env.NODE_ENV", "x = 'production'");
+    test("x = process.env['NODE_ENV']", "x = 'production'");
+    test("x = VERSION", "x = 3");
+    test("x = OFFSET", "x = -1.5");
+    test("x = config.api.url", "x = null");
+    test("x = LEGACY", "x = void 0");
+}
+
+#[test]
+fn dead_branches() {
+    test("if (__DEV__) a(); b()", "b()");
+    test("if (!__DEV__) a(); else b()", "a()");
+    test("x = __DEV__ ? a : b", "x = b");
+    test("if (process.env.NODE_ENV !== 'production') a(); b()", "b()");
+    test("if (VERSION < 2) a(); b()", "b()");
+}
+
+#[test]
+fn kept() {
+    // Assignments, shadowing bindings and other properties are kept.
+    test("__DEV__ = a", "__DEV__ = a");
+    test("function f(__DEV__) { return __DEV__ }", "function f(__DEV__) { return __DEV__ }");
+    test("x = config.api", "x = config.api");
+    test("x = config.api.key", "x = config.api.key");
+    test("x = process.env?.NODE_ENV", "x = process.env?.NODE_ENV");
+    // Not replaced by default.
+    test_with_options("x = VERSION", "x = VERSION", CompressOptions::default());
+}
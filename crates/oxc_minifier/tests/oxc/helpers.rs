@@ -0,0 +1,91 @@
+use oxc_minifier::CompressOptions;
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+fn test_with_source_type(source_text: &str, expected: &str, source_type: SourceType) {
+    let compress = CompressOptions {
+        import_babel_helpers: Some("@babel/runtime"),
+        import_ts_helpers: Some("tslib"),
+        ..CompressOptions::all_false()
+    };
+    let expected_compress = CompressOptions::all_false();
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test(source_text: &str, expected: &str) {
+    test_with_source_type(source_text, expected, SourceType::default().with_module(true));
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+const ASSIGN: &str = "var __assign = (this && this.__assign) || function () {
+    __assign = Object.assign || function (t) { return t };
+    return __assign.apply(this, arguments);
+};";
+
+const TYPEOF: &str = "function _typeof(o) {
+    return _typeof = 'function' == typeof Symbol ? function (o) { return typeof o } : function (o) { return o && o.constructor === Symbol ? 'symbol' : typeof o }, _typeof(o);
+}";
+
+#[test]
+fn ts_helpers() {
+    test(
+        &format!("{ASSIGN} __assign({{}}, a)"),
+        "import { __assign } from 'tslib'; __assign({}, a)",
+    );
+    test(
+        &format!("{ASSIGN} {ASSIGN} __assign({{}}, a)"),
+        "import { __assign } from 'tslib'; __assign({}, a)",
+    );
+    test(
+        &format!("{ASSIGN} function f() {{ {ASSIGN} return __assign({{}}, a) }}"),
+        "import { __assign } from 'tslib'; function f() { return __assign({}, a) }",
+    );
+    // Not the helper of `tslib`
+    test_same("var __assign = Object.assign; __assign({}, a)");
+    test_same("var __assign2 = (this && this.__assign2) || function () {}; __assign2({}, a)");
+}
+
+#[test]
+fn babel_helpers() {
+    test(
+        &format!("{TYPEOF} _typeof(a)"),
+        "import _typeof from '@babel/runtime/helpers/typeof'; _typeof(a)",
+    );
+    test(
+        "function _classCallCheck(a, n) { if (!(a instanceof n)) throw new TypeError('Cannot call a class as a function') }
+        function A() { _classCallCheck(this, A) }",
+        "import _classCallCheck from '@babel/runtime/helpers/classCallCheck';
+        function A() { _classCallCheck(this, A) }",
+    );
+    test(
+        &format!("{ASSIGN} {TYPEOF} __assign({{}}, _typeof(a))"),
+        "import { __assign } from 'tslib'; import _typeof from '@babel/runtime/helpers/typeof';
+        __assign({}, _typeof(a))",
+    );
+    // Not the helper of Babel
+    test_same("function _typeof(o) { return typeof o } _typeof(a)");
+    test_same("function _classCallCheck(a) { if (!a) throw new TypeError('Cannot call a class as a function') }");
+}
+
+#[test]
+fn other_bindings() {
+    // Reassigned outside of the helper
+    test_same(&format!("{ASSIGN} __assign = null"));
+    // Another binding of the name which is not the helper
+    test_same(&format!("{ASSIGN} function f(__assign) {{ return __assign }}"));
+    test_same(&format!("{TYPEOF} function f() {{ var _typeof = 1; return _typeof }}"));
+    test(
+        &format!("{ASSIGN} export {{ __assign }}"),
+        "import { __assign } from 'tslib'; export { __assign }",
+    );
+}
+
+#[test]
+fn scripts() {
+    let source_text = format!("{ASSIGN} __assign({{}}, a)");
+    test_with_source_type(&source_text, &source_text, SourceType::default());
+}
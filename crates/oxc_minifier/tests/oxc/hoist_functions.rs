@@ -0,0 +1,81 @@
+use oxc_minifier::CompressOptions;
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+fn test_with_source_type(source_text: &str, expected: &str, source_type: SourceType) {
+    let compress = CompressOptions { hoist_functions: true, ..CompressOptions::all_false() };
+    let expected_compress = CompressOptions::all_false();
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test(source_text: &str, expected: &str) {
+    test_with_source_type(source_text, expected, SourceType::default().with_module(true));
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn hoist_functions() {
+    test("if (x) { function f() {} f() }", "function f() {} if (x) f()");
+    test("g(); if (x) { function f() {} f() }", "g(); function f() {} if (x) f()");
+    test("if (x) { { function f() {} f() } }", "function f() {} if (x) f()");
+    test(
+        "function g() { if (x) { function f() { return 1 } h(f()) } }",
+        "function g() { function f() { return 1 } if (x) h(f()) }",
+    );
+    test(
+        "const g = () => { try { function f() {} f() } catch {} }",
+        "const g = () => { function f() {} try { f() } catch {} }",
+    );
+    // References to outer bindings do not change.
+    test("let y; if (x) { function f() { g(y) } f() }", "let y; function f() { g(y) } if (x) f()");
+    test("if (x) { function f() { return f } f() }", "function f() { return f } if (x) f()");
+}
+
+#[test]
+fn sloppy_mode() {
+    let source_type = SourceType::default();
+    // Annex B also binds the function in the enclosing function.
+    test_with_source_type(
+        "function g() { if (x) { function f() {} f() } }",
+        "function g() { if (x) { function f() {} f() } }",
+        source_type,
+    );
+    test_with_source_type(
+        "function g() { 'use strict'; if (x) { function f() {} f() } }",
+        "function g() { 'use strict'; function f() {} if (x) f() }",
+        source_type,
+    );
+    // Top-level functions of a script are global bindings.
+    test_with_source_type(
+        "'use strict'; if (x) { function f() {} f() }",
+        "'use strict'; if (x) { function f() {} f() }",
+        source_type,
+    );
+}
+
+#[test]
+fn unsafe_blocks() {
+    // A new function is created for each iteration.
+    test_same("for (const a of b) { function f() {} g(f) }");
+    test_same("for (const a in b) { if (a) { function f() {} g(f) } }");
+    test_same("class A { static { if (x) { function f() {} f() } } }");
+    // The function captures a binding of the block.
+    test_same("if (x) { let y = 1; function f() { return y } f() }");
+    test_same("try {} catch (e) { function f() { return e } f() }");
+    // Both functions would capture each other.
+    test_same("if (x) { function f() { g() } function g() { f() } f() }");
+}
+
+#[test]
+fn name_conflicts() {
+    test_same("let f; if (x) { function f() {} f() }");
+    test_same("if (x) { function f() {} f() } f()");
+    test_same("if (x) { function f() {} f() } else { function f() {} f() }");
+    test_same("function g() { if (x) { function f() {} f() } return f }");
+    test_same("let f; function g() { if (x) { function f() {} f() } return f }");
+    test_same("function g() { if (x) { function f() {} f() } eval('f') }");
+}
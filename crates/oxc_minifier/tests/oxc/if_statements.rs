@@ -0,0 +1,153 @@
+use oxc_minifier::{CompressOptions, MinifierOptions};
+
+use crate::{test, test_with_options};
+
+#[test]
+fn empty_branches() {
+    test("if (a) {} else { b() }", "a||b();");
+    test("if (a) {} else b()", "a||b();");
+    test("if (!a) {} else b()", "a&&b();");
+    test("if (a === b) {} else c()", "a!==b&&c();");
+    test("if (a) b(); else {}", "a&&b();");
+    test("if (a) {}", "a;");
+    test("if (a) {} else {}", "a;");
+}
+
+#[test]
+fn expressions() {
+    test("if (a) b()", "a&&b();");
+    test("if (!a) b()", "a||b();");
+    test("if (a) b(); else c()", "a?b():c();");
+    test("if (a) b(); else if (c) d()", "a?b():c&&d();");
+    // Parentheses would make them longer.
+    test("if (a) b = c", "if(a)b=c;");
+    test("if (a || b) c()", "if(a||b)c();");
+    test("if (a) { b(); c() }", "if(a)b(),c();");
+}
+
+#[test]
+fn nested_if() {
+    test("if (a) { if (b) c() }", "a&&b&&c();");
+    test("if (a) {} else if (b) c()", "!a&&b&&c();");
+}
+
+#[test]
+fn early_return() {
+    test("function f() { if (a) return; b(); c() }", "function f(){if(!a)b(),c()}");
+    test(
+        "function f() { if (a) return; b(); if (c) return; d() }",
+        "function f(){if(!a)b(),c||d()}",
+    );
+    test("function f() { a(); if (b) return; c() }", "function f(){a(),b||c()}");
+    test("function f() { a(); if (b) return }", "function f(){a(),b}");
+    test(
+        "function f() { if (a) return; let x = b(); c(x) }",
+        "function f(){if(a)return;let x=b();c(x)}",
+    );
+    test("function f() { if (a) return 1; b() }", "function f(){if(a)return 1;b()}");
+}
+
+#[test]
+fn trailing_return() {
+    test("function f() { a(); return }", "function f(){a()}");
+    test("function f() { return undefined }", "function f(){}");
+    test("function f() { if (a) { b(); return } }", "function f(){a&&b()}");
+    test("function f() { if (a) return; else { b(); return } }", "function f(){a||b()}");
+    test(
+        "function f() { if (a) return; else { let x = b(); c(x) } }",
+        "function f(){if(!a){let x=b();c(x)}}",
+    );
+    test("x = () => { a(); return }", "x=()=>{a()};");
+    test("function f() { return 1 }", "function f(){return 1}");
+}
+
+#[test]
+fn else_after_exit() {
+    test(
+        "function f() { if (a) return b; else { c(); d() } }",
+        "function f(){if(a)return b;c(),d()}",
+    );
+    test("function f() { if (a) throw b; else c() }", "function f(){if(a)throw b;c()}");
+    test("function f() { if (a) return; else b() }", "function f(){a||b()}");
+    test("for (;;) { if (a) break; else b() }", "for(;;){if(a)break;b()}");
+    test("for (;;) { if (a) { b(); continue } else c() }", "for(;;){if(a){b();continue}c()}");
+}
+
+#[test]
+fn early_continue() {
+    test("for (;;) { if (a) continue; b(); c() }", "for(;;)if(!a)b(),c();");
+    test("while (x) { if (a) continue; b() }", "for(;x;)a||b();");
+    test("a: for (;;) { if (x) continue a; b() }", "a:for(;;){if(x)continue a;b()}");
+}
+
+#[test]
+fn conditional_assignment() {
+    test("if (a) x = f(); else x = g();", "x=a?f():g();");
+    test("if (a) { x = 1 } else { x = 2 }", "x=a?1:2;");
+    test("if (!a) x = 1; else x = 2;", "x=a?2:1;");
+    test("a ? x = 1 : x = 2", "x=a?1:2;");
+    test("if (a) x += 1; else x += 2;", "x+=a?1:2;");
+    test("if (a()) x += 1; else x += 2;", "a()?x+=1:x+=2;");
+    test("if (y = a) x += 1; else x += 2;", "x+=(y=a)?1:2;");
+    test("if (x = a) x += 1; else x += 2;", "(x=a)?x+=1:x+=2;");
+    test("if (a) x ||= 1; else x ||= 2;", "a?x||=1:x||=2;");
+    test("if (a) x = 1; else y = 2;", "a?x=1:y=2;");
+    test("if (a) x = 1; else x -= 2;", "a?x=1:x-=2;");
+}
+
+#[test]
+fn conditional_member_assignment() {
+    test("if (a) o.p = 1; else o.p = 2;", "o.p=a?1:2;");
+    test("if (a) o[k] = 1; else o[k] = 2;", "o[k]=a?1:2;");
+    test("if (a()) this.p = 1; else this.p = 2;", "this.p=a()?1:2;");
+    // The test may reassign the object, which would be evaluated first after hoisting.
+    test("if (a()) o.p = 1; else o.p = 2;", "a()?o.p=1:o.p=2;");
+    test("if (a) f().p = 1; else f().p = 2;", "a?f().p=1:f().p=2;");
+    test("if (o = a) o.p = 1; else o.p = 2;", "(o=a)?o.p=1:o.p=2;");
+    test("if (k = a) o.p = 1; else o.p = 2;", "o.p=(k=a)?1:2;");
+    test("if (a) o.p = 1; else o.q = 2;", "a?o.p=1:o.q=2;");
+}
+
+#[test]
+fn conditional_return() {
+    test("function f() { if (!a) return b; return c }", "function f(){return a?c:b}");
+    test("function f() { if (!a) return b; else return c }", "function f(){return a?c:b}");
+}
+
+#[test]
+fn identical_consequents() {
+    test("if (a) b(); else if (c) b();", "if(a||c)b();");
+    test("if (a) b(); else if (c) b(); else d()", "a||c?b():d();");
+    test("if (a) b(); else if (c) b(); else if (d) b()", "if(a||c||d)b();");
+    test(
+        "function f() { if (a) return b; if (c) return b; d() }",
+        "function f(){if(a||c)return b;d()}",
+    );
+    test(
+        "function f() { if (a) return b; if (c) return b; return d }",
+        "function f(){return a||c?b:d}",
+    );
+    test("for (;;) { if (a) break; if (b) break; c() }", "for(;;){if(a||b)break;c()}");
+    // `c` is evaluated after `b()`, which would run twice, so they are not merged.
+    test("if (a) b(); if (c) b();", "a&&b(),c&&b();");
+    test(
+        "function f() { if (a) return b; if (c) return d }",
+        "function f(){if(a)return b;if(c)return d}",
+    );
+}
+
+#[test]
+fn disabled() {
+    let compress = CompressOptions { conditionals: false, ..CompressOptions::default() };
+    let options = MinifierOptions { mangle: false, compress };
+    test_with_options(
+        "function f() { if (a) return; b() }",
+        "function f(){if(a)return;b()}",
+        options,
+    );
+    test_with_options(
+        "function f() { if (a) return b; else c(); return }",
+        "function f(){if(a)return b;else c();return}",
+        options,
+    );
+}
@@ -0,0 +1,47 @@
+use oxc_minifier::{CompressOptions, MinifierOptions};
+
+use crate::{test, test_with_options};
+
+#[test]
+fn expression_bodies() {
+    test("x = (() => a)()", "x=a;");
+    test("x = (() => a + b)()", "x=a+b;");
+    test("x = ((() => a))()", "x=a;");
+}
+
+#[test]
+fn decision_trees() {
+    test("x = (() => { if (a) return b; return c; })()", "x=a?b:c;");
+    test("x = (() => { if (a) return b; else return c; })()", "x=a?b:c;");
+    test("x = (() => { if (!a) return b; return c; })()", "x=a?c:b;");
+    test("x = (() => { if (a) return b; else if (c) return d; else return e; })()", "x=a?b:c?d:e;");
+    test("x = (() => { if (a) { if (b) return c; return d; } return e; })()", "x=a?b?c:d:e;");
+}
+
+#[test]
+fn decision_trees_without_sequences() {
+    let compress = CompressOptions { sequences: false, ..CompressOptions::default() };
+    let options = MinifierOptions { mangle: false, compress };
+    test_with_options("x = (() => { if (a) return b; return c; })()", "x=a?b:c;", options);
+    test_with_options(
+        "x = (() => { if (a) { if (b) return c; return d; } return e; })()",
+        "x=a?b?c:d:e;",
+        options,
+    );
+}
+
+#[test]
+fn kept() {
+    test("x = ((a) => a ? b : c)(d)", "x=(a=>a?b:c)(d);");
+    test("x = (() => a)(b)", "x=(()=>a)(b);");
+    test("x = (async () => a)()", "x=(async()=>a)();");
+    // Falls through without a `return`.
+    test("x = (() => { if (a) return b; })()", "x=(()=>{if(a)return b})();");
+}
+
+#[test]
+fn disabled() {
+    let compress = CompressOptions { iifes: false, ..CompressOptions::default() };
+    let options = MinifierOptions { mangle: false, compress };
+    test_with_options("x = (() => a)()", "x=(()=>a)();", options);
+}
@@ -0,0 +1,56 @@
+use oxc_span::SourceType;
+
+use crate::{minify, test_with_compress_options, CompressOptions, MinifierOptions};
+
+fn test(source_text: &str, expected: &str) {
+    let source_type = SourceType::default().with_module(true);
+    let options = MinifierOptions { mangle: false, ..MinifierOptions::default() };
+    let minified = minify(source_text, source_type, options);
+    assert_eq!(expected, minified, "for source {source_text}");
+}
+
+/// The object must be kept as is, which is the same output as with `inline_enums` disabled.
+fn test_same(source_text: &str) {
+    test_with_compress_options(
+        source_text,
+        source_text,
+        SourceType::default().with_module(true),
+        CompressOptions::default(),
+        CompressOptions { inline_enums: false, ..CompressOptions::default() },
+    );
+}
+
+#[test]
+fn frozen_object() {
+    test("const E = Object.freeze({ A: 1, B: 'b' }); f(E.A, E['B'])", "f(1,'b');");
+    test("const E = Object.freeze({ 'A': -1 }); f(E.A)", "f(-1);");
+}
+
+#[test]
+fn lowered_typescript_enum() {
+    test(
+        "var E = function(E) { E[E['A'] = 0] = 'A'; E[E['B'] = 1] = 'B'; E['C'] = 'c'; return E; }(E || {}); f(E.A, E.C, E[1])",
+        "f(0,'c','B');",
+    );
+    test("let E = ((E) => { E[E['A'] = 0] = 'A'; return E; })({}); f(E.A)", "f(0);");
+}
+
+#[test]
+fn escaped() {
+    test_same("const E = Object.freeze({ A: 1 }); f(E)");
+    test_same("const E = Object.freeze({ A: 1 }); E.A = 2");
+    test_same("const E = Object.freeze({ A: 1 }); delete E.A");
+    test_same("const E = Object.freeze({ A: 1 }); f(E.B)");
+    test_same("const E = Object.freeze({ A: 1 }); f(E?.A)");
+    test_same("const E = Object.freeze({ A: 1 }); function g(E) { return E.A }");
+    test_same("const E = Object.freeze({ A: 1 }); eval('E'); f(E.A)");
+    test_same("const E = Object.freeze({ A: f() }); f(E.A)");
+    test_same("let E = Object.freeze({ A: 1 }); f(E.A)");
+    test_same("const E = Object.freeze({ A: 1 }); export { E }");
+    test_same("const E = Object.freeze({ A: 1 }); export { E as F }; f(E.A)");
+    test_same("const E = Object.freeze({ A: 1 }); export default E");
+    // Objects of the same name can not be told apart.
+    test_same(
+        "var E = function(E) { E[E['A'] = 0] = 'A'; return E; }(E || {}); var E = function(E) { E[E['A'] = 1] = 'A'; return E; }(E || {}); var E = function(E) { E[E['A'] = 2] = 'A'; return E; }(E || {}); f(E.A)",
+    );
+}
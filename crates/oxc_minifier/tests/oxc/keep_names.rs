@@ -0,0 +1,92 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::BindingIdentifier, Visit};
+use oxc_minifier::{KeepNames, ManglerBuilder};
+use oxc_parser::Parser;
+use oxc_semantic::SymbolId;
+use oxc_span::SourceType;
+use regex::Regex;
+
+/// The mangled names of the bindings, in the order they are declared.
+fn mangle(source_text: &str, keep_fnames: KeepNames, keep_classnames: KeepNames) -> Vec<String> {
+    #[derive(Default)]
+    struct Collector {
+        symbol_ids: Vec<SymbolId>,
+    }
+
+    impl<'a> Visit<'a> for Collector {
+        fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+            self.symbol_ids.extend(ident.symbol_id.get());
+        }
+    }
+
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let program = &*allocator.alloc(program);
+    let mangler = ManglerBuilder::default()
+        .with_keep_fnames(keep_fnames)
+        .with_keep_classnames(keep_classnames)
+        .build(program);
+    let mut collector = Collector::default();
+    collector.visit_program(program);
+    collector
+        .symbol_ids
+        .into_iter()
+        .map(|symbol_id| mangler.get_symbol_name(symbol_id).to_string())
+        .collect()
+}
+
+const SOURCE: &str = "
+function foo(x) { return x }
+const bar = () => foo(1), baz = function qux() {};
+class Foo {}
+const Bar = class {};
+export { foo, bar, baz, Foo, Bar };
+";
+
+#[test]
+fn mangle_every_name() {
+    let names = mangle(SOURCE, KeepNames::None, KeepNames::None);
+    assert!(names.iter().all(|name| name.len() == 1), "{names:?}");
+}
+
+#[test]
+fn keep_fnames() {
+    let names = mangle(SOURCE, KeepNames::All, KeepNames::None);
+    assert_eq!(names[0], "foo");
+    assert_eq!(names[2], "bar");
+    // `baz` is not the name of the function, which is `qux`.
+    assert_eq!(names[3].len(), 1);
+    assert_eq!(names[4], "qux");
+    assert_eq!(names[5].len(), 1);
+    assert_eq!(names[6].len(), 1);
+}
+
+#[test]
+fn keep_classnames() {
+    let names = mangle(SOURCE, KeepNames::None, KeepNames::All);
+    assert_eq!(names[0].len(), 1);
+    assert_eq!(names[5], "Foo");
+    assert_eq!(names[6], "Bar");
+}
+
+#[test]
+fn keep_matching_names() {
+    let keep = || KeepNames::Matching(Regex::new("^[A-Z]").unwrap());
+    let names = mangle(SOURCE, keep(), keep());
+    assert_eq!(names[0].len(), 1);
+    assert_eq!(names[5], "Foo");
+    assert_eq!(names[6], "Bar");
+}
+
+#[test]
+fn kept_names_are_not_shadowed() {
+    // The parameter must not be renamed to `a`, which would shadow the function.
+    let names = mangle(
+        "function a() {} export function f(x) { return a(x) }",
+        KeepNames::All,
+        KeepNames::None,
+    );
+    assert_eq!(names[0], "a");
+    assert_ne!(names[2], "a");
+}
@@ -0,0 +1,86 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_minifier::{PropertyMangler, PropertyManglerOptions};
+use oxc_parser::Parser;
+use oxc_span::{CompactStr, SourceType};
+use regex::Regex;
+
+fn mangle(mangler: &mut PropertyMangler, source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let program = allocator.alloc(program);
+    mangler.build(&allocator, program);
+    Codegen::<true>::new("", source_text, CodegenOptions::default()).build(program).source_text
+}
+
+fn test_with_options(source_text: &str, expected: &str, options: PropertyManglerOptions) {
+    let mangled = mangle(&mut PropertyMangler::new(options), source_text);
+    assert_eq!(mangled, expected, "for source {source_text}");
+}
+
+fn test(source_text: &str, expected: &str) {
+    let options =
+        PropertyManglerOptions { regex: Some(Regex::new("^_").unwrap()), ..Default::default() };
+    test_with_options(source_text, expected, options);
+}
+
+#[test]
+fn regex() {
+    test(
+        "const o = { _count: 0, '_step': 1, other: 2 }; o._count += o['_step'];",
+        "const o={a:0,'b':1,other:2};o.a+=o['b'];",
+    );
+    // The most frequent property gets the shortest name
+    test("x._a; x._b; x._b;", "x.b;x.a;x.a;");
+    test("class A { _b() { return this._a } }", "class A{a(){return this.b}}");
+    test("'_a' in o; o?._a; delete o['_a'];", "'a'in o;o?.a;delete o['a'];");
+}
+
+#[test]
+fn shorthand() {
+    test("const o = { _a };", "const o={a:_a};");
+    test("const { _a, _b = 1 } = o;", "const {a:_a,b:_b=1}=o;");
+    test("({ _a, _b = 1 } = o);", "({a:_a,b:_b=1}=o);");
+    test("({ _a: _a } = o);", "({a:_a}=o);");
+}
+
+#[test]
+fn reserved() {
+    // Without a regex, every property but the reserved ones and those of the builtins
+    // is mangled, the arguments of a call are visited before its callee
+    let options = PropertyManglerOptions { reserved: vec!["kept".into()], ..Default::default() };
+    test_with_options(
+        "o.foo(o.kept, o.length, [].map(x => x.bar));",
+        "o.b(o.kept,o.length,[].map(x=>x.a));",
+        options,
+    );
+    // A mangled name never collides with a property which is kept
+    test("o.a; o._x;", "o.a;o.b;");
+}
+
+#[test]
+fn keep_quoted() {
+    let options = PropertyManglerOptions {
+        regex: Some(Regex::new("^_").unwrap()),
+        keep_quoted: true,
+        ..Default::default()
+    };
+    test_with_options("o._a; o['_b']; o._b; o._c;", "o.a;o['_b'];o._b;o.b;", options);
+}
+
+#[test]
+fn shared_names() {
+    let options =
+        PropertyManglerOptions { regex: Some(Regex::new("^_").unwrap()), ..Default::default() };
+    let mut mangler = PropertyMangler::new(options);
+    assert_eq!(
+        mangle(&mut mangler, "export const o = { _a: 1, _b: 2 };"),
+        "export const o={a:1,b:2};"
+    );
+    assert_eq!(
+        mangle(&mut mangler, "import { o } from './o'; o._c = o._b;"),
+        "import {o} from './o';o.c=o.b;"
+    );
+    assert_eq!(mangler.names().get("_b").map(CompactStr::as_str), Some("b"));
+}
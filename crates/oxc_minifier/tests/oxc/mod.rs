@@ -1,3 +1,41 @@
+mod arrays;
+mod booleans;
+mod builtins;
+mod char_freq;
+mod classes;
 mod code_removal;
+mod comparisons;
+mod dead_code;
+mod dead_stores;
+mod default_params;
+mod destructuring;
+mod determinism;
+mod duplicate_keys;
+mod dynamic_scope;
 mod folding;
+mod global_constants;
+mod global_defs;
+mod helpers;
+mod hoist_functions;
+mod if_statements;
+mod iifes;
+mod inline_enum;
+mod keep_names;
+mod mangle_properties;
+mod modules;
+mod name_cache;
+mod narrow_vars;
+mod nullish_coalescing;
+mod optional_chains;
+mod passes;
 mod precedence;
+mod properties;
+#[cfg(feature = "regexp")]
+mod regexp;
+mod sequences;
+mod snapshots;
+mod string_pool;
+mod switches;
+mod typeofs;
+mod unused_imports;
+mod unused_params;
@@ -0,0 +1,90 @@
+use oxc_minifier::CompressOptions;
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+fn test_with_options(source_text: &str, expected: &str, compress: CompressOptions) {
+    let source_type = SourceType::default().with_module(true);
+    let expected_compress = CompressOptions::all_false();
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+// The imports of these tests are unused, keep them for `merge_imports`.
+fn test(source_text: &str, expected: &str) {
+    let options = CompressOptions { unused_imports: false, ..CompressOptions::default() };
+    test_with_options(source_text, expected, options);
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn export_names() {
+    test("let x; export { x as 'x' }", "let x; export { x }");
+    test("let x; export { x as 'y' }", "let x; export { x as y }");
+    test("export { 'a' as b } from 'm'", "export { a as b } from 'm'");
+    test("import { 'a' as b } from 'm'", "import { a as b } from 'm'");
+    test("import { 'a' as a } from 'm'", "import { a } from 'm'");
+    test_same("import { 'a-b' as c } from 'm'");
+    test_same("let x; export { x as 'a-b' }");
+}
+
+#[test]
+fn merge_imports() {
+    test("import { a } from 'm'; import { b } from 'm'", "import { a, b } from 'm'");
+    test(
+        "import { a } from 'm'; import 'n'; import { b } from 'm'",
+        "import { a, b } from 'm'; import 'n'",
+    );
+    test("import 'm'; import a from 'm'; import { b } from 'm'", "import a, { b } from 'm'");
+    test("import { b } from 'm'; import a from 'm'", "import a, { b } from 'm'");
+    test("import a from 'm'; import * as b from 'm'", "import a, * as b from 'm'");
+    test("import { a } from 'm'; import 'm'", "import { a } from 'm'");
+    test_same("import * as a from 'm'; import { b } from 'm'");
+    test_same("import a from 'm'; import b from 'm'");
+    test_same("import { a } from 'm' with { type: 'json' }; import { b } from 'm'");
+}
+
+#[test]
+fn merge_exports() {
+    test("let a, b; export { a }; export { b }", "let a, b; export { a, b }");
+    test("export { a } from 'm'; export { b } from 'm'", "export { a, b } from 'm'");
+    test_same("export { a } from 'm'; export { b } from 'n'");
+    test("export const a = 1; export const b = 2", "export const a = 1, b = 2");
+    test_same("export let a; export const b = 2");
+}
+
+#[test]
+fn default_export() {
+    test("function f() {} export default f", "export default function f() {}");
+    test("export default f; function f() {}", "export default function f() {}");
+    test("class C {} export { C as default }", "export default class C {}");
+    test(
+        "function f() {} export { f as default, f }",
+        "export default function f() {} export { f }",
+    );
+    // `C` is in its temporal dead zone
+    test_same("export default C; class C {}");
+    // `export default function f() {}` exports the binding
+    test_same("function f() {} f = 1; export default f");
+    test_same("function f() {} ({ f } = o); export default f");
+    // The bindings are found by name, a used parameter `f` is another binding
+    test_same("export default f; function f() {} function g(f) { return f }");
+}
+
+#[test]
+fn disabled() {
+    let options =
+        CompressOptions { modules: false, unused_imports: false, ..CompressOptions::default() };
+    test_with_options(
+        "import { a } from 'm'; import { b } from 'm'",
+        "import { a } from 'm'; import { b } from 'm'",
+        options,
+    );
+    test_with_options(
+        "function f() {} export default f",
+        "function f() {} export default f",
+        options,
+    );
+}
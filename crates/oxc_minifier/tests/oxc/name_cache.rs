@@ -0,0 +1,87 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::BindingIdentifier, Visit};
+use oxc_minifier::{ManglerBuilder, NameCache, PropertyMangler, PropertyManglerOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SymbolId;
+use oxc_span::SourceType;
+
+/// The mangled names of the bindings by their original name, and the updated name cache.
+fn mangle(source_text: &str, name_cache: NameCache) -> (Vec<(String, String)>, NameCache) {
+    #[derive(Default)]
+    struct Collector {
+        bindings: Vec<(String, SymbolId)>,
+    }
+
+    impl<'a> Visit<'a> for Collector {
+        fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+            if let Some(symbol_id) = ident.symbol_id.get() {
+                self.bindings.push((ident.name.to_string(), symbol_id));
+            }
+        }
+    }
+
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let program = &*allocator.alloc(program);
+    let mangler = ManglerBuilder::default().with_name_cache(name_cache).build(program);
+    let mut collector = Collector::default();
+    collector.visit_program(program);
+    let names = collector
+        .bindings
+        .into_iter()
+        .map(|(name, symbol_id)| (name, mangler.get_symbol_name(symbol_id).to_string()))
+        .collect();
+    (names, mangler.name_cache().cloned().unwrap())
+}
+
+fn name_of<'n>(names: &'n [(String, String)], original: &str) -> &'n str {
+    names.iter().find(|(name, _)| name == original).map(|(_, mangled)| mangled.as_str()).unwrap()
+}
+
+#[test]
+fn top_level_names() {
+    let (names, name_cache) =
+        mangle("function f(x) { return x } export const g = f(1);", NameCache::default());
+    assert_eq!(name_cache.vars.keys().collect::<Vec<_>>(), ["f", "g"]);
+
+    // A new binding and more references, which would change the names without the cache
+    let source_text =
+        "const h = 1; function f(y, z) { return y + z } export const g = f(h, h) + f(h, h);";
+    let (new_names, new_name_cache) = mangle(source_text, name_cache.clone());
+    assert_eq!(name_of(&new_names, "f"), name_of(&names, "f"));
+    assert_eq!(name_of(&new_names, "g"), name_of(&names, "g"));
+    // The other bindings never get a name from the cache
+    for original in ["h", "y", "z"] {
+        assert!(!name_cache.vars.values().any(|name| name == name_of(&new_names, original)));
+    }
+    assert_eq!(new_name_cache.vars.keys().collect::<Vec<_>>(), ["f", "g", "h"]);
+}
+
+#[test]
+fn global_references() {
+    // `a` is a global reference now, which the cached name would shadow
+    let mut name_cache = NameCache::default();
+    name_cache.vars.insert("f".into(), "a".into());
+    let (names, _) = mangle("function f() {} f(a);", name_cache);
+    assert_ne!(name_of(&names, "f"), "a");
+}
+
+#[test]
+fn properties() {
+    let mut name_cache = NameCache::default();
+    name_cache.props.insert("_b".into(), "a".into());
+    let options = PropertyManglerOptions {
+        regex: Some(regex::Regex::new("^_").unwrap()),
+        ..PropertyManglerOptions::default()
+    };
+    let mut mangler = PropertyMangler::new(options).with_name_cache(&name_cache);
+    let allocator = Allocator::default();
+    let source_text = "o._a; o._a; o._b;";
+    let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+    let program = allocator.alloc(program);
+    mangler.build(&allocator, program);
+    mangler.write_name_cache(&mut name_cache);
+    assert_eq!(name_cache.props.get("_b").map(String::as_str), Some("a"));
+    assert_eq!(name_cache.props.get("_a").map(String::as_str), Some("b"));
+}
@@ -0,0 +1,82 @@
+use oxc_minifier::{CompressOptions, MinifierOptions};
+use oxc_span::SourceType;
+
+use crate::{minify, test_with_compress_options};
+
+fn test_with_source_type(source_text: &str, expected: &str, source_type: SourceType) {
+    let compress = CompressOptions { narrow_vars: true, ..CompressOptions::default() };
+    let options = MinifierOptions { mangle: false, compress };
+    let minified = minify(source_text, source_type, options);
+    assert_eq!(expected, minified, "for source {source_text}");
+}
+
+fn test(source_text: &str, expected: &str) {
+    test_with_source_type(source_text, expected, SourceType::default());
+}
+
+/// The `var`s of `source_text` are kept.
+fn test_same(source_text: &str) {
+    test_with_compress_options(
+        source_text,
+        source_text,
+        SourceType::default(),
+        CompressOptions { narrow_vars: true, ..CompressOptions::default() },
+        CompressOptions::default(),
+    );
+}
+
+#[test]
+fn in_place() {
+    test("function f() { if (a) { var x = g(); h(x) } }", "function f(){if(a){let x=g();h(x)}}");
+    test(
+        "function f() { if (a) { var x = 1, y = x; h(y) } }",
+        "function f(){if(a){let x=1,y=x;h(y)}}",
+    );
+    test(
+        "const f = () => { try { var x = g(); h(x) } catch {} }",
+        "const f=()=>{try{let x=g();h(x)}catch{}};",
+    );
+}
+
+#[test]
+fn moved() {
+    test("function f() { var x; if (a) { x = g(); h(x) } }", "function f(){if(a){let x=g();h(x)}}");
+    test(
+        "function f() { var x; if (a) { x = g(); { h(x) } } }",
+        "function f(){if(a){let x=g();h(x)}}",
+    );
+    test_with_source_type(
+        "var x; if (a) { x = g(); h(x) }",
+        "if(a){let x=g();h(x)}",
+        SourceType::default().with_module(true),
+    );
+}
+
+#[test]
+fn kept() {
+    // Top-level `var`s of a script are global bindings.
+    test_same("if (a) { var x = g(); h(x) }");
+    // Referenced outside of the block, or before the declaration.
+    test_same("function f() { if (a) { var x = g(); h(x) } return x }");
+    test_same("function f() { if (a) { h(x); var x = g(); h(x) } }");
+    test_same("function f() { var x; if (a) { h(x); x = g() } }");
+    test_same("function f() { var x; if (a) { x = x + 1; h(x) } }");
+    // A new binding in every iteration or call would be observed by the closures.
+    test_same("function f() { for (;;) { if (a) { var x = g(); h(() => x) } } }");
+    test_same("function f() { var x; return () => { if (a) { x = g(); h(() => x) } } }");
+    // A hoisted function may read the binding before the declaration.
+    test_same("function f() { if (a) { g(); var x = 1; function g() { return x } } }");
+    // Redeclared, or assigning the catch parameter.
+    test_same("function f() { var x; if (a) { var x = g(); h(x) } }");
+    test_same("function f() { try {} catch (x) { var x = g(); h(x) } }");
+    // Not every declarator of the statement can be narrowed.
+    test_same("function f() { if (a) { var x = 1, y = 2; h(x) } return y }");
+}
+
+#[test]
+fn disabled() {
+    let source_text = "function f() { if (a) { var x = g(); h(x) } }";
+    let options = MinifierOptions { mangle: false, ..MinifierOptions::default() };
+    let minified = minify(source_text, SourceType::default(), options);
+    assert_eq!("function f(){if(a){var x=g();h(x)}}", minified);
+}
@@ -0,0 +1,86 @@
+use oxc_minifier::CompressOptions;
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+fn test(source_text: &str, expected: &str) {
+    let source_type = SourceType::default();
+    let compress = CompressOptions { nullish_coalescing: true, ..CompressOptions::default() };
+    let expected_compress =
+        CompressOptions { nullish_coalescing: false, ..CompressOptions::default() };
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn loose_comparisons() {
+    test("function f(x) { return x == null ? 1 : x }", "function f(x) { return x ?? 1 }");
+    test("function f(x) { return x != null ? x : 1 }", "function f(x) { return x ?? 1 }");
+    test("function f(x) { return null == x ? 1 : x }", "function f(x) { return x ?? 1 }");
+    test("function f(x) { return x == undefined ? 1 : x }", "function f(x) { return x ?? 1 }");
+    test("function f(x) { return x != void 0 ? x : g() }", "function f(x) { return x ?? g() }");
+    // Not the tested binding, or a global which may be a getter
+    test_same("function f(x, y) { return x == null ? 1 : y }");
+    test_same("function f(x) { return x == null ? x : 1 }");
+    test_same("x == null ? 1 : x");
+    test_same("function f(x) { return x.y == null ? 1 : x.y }");
+    test_same("function f(x, undefined) { return x == undefined ? 1 : x }");
+}
+
+#[test]
+fn strict_comparisons() {
+    test(
+        "function f(x) { return x === null || x === void 0 ? 1 : x }",
+        "function f(x) { return x ?? 1 }",
+    );
+    test(
+        "function f(x) { return x !== void 0 && x !== null ? x : 1 }",
+        "function f(x) { return x ?? 1 }",
+    );
+    test(
+        "function f(x) { return null !== x && undefined !== x ? x : 1 }",
+        "function f(x) { return x ?? 1 }",
+    );
+    // `??` also replaces `null`
+    test_same("function f(x) { return x === void 0 ? 1 : x }");
+    test_same("function f(x) { return x === null || x === null ? 1 : x }");
+    test_same("function f(x) { return x === null && x === void 0 ? 1 : x }");
+    test_same("function f(x, y) { return x === null || y === void 0 ? 1 : x }");
+}
+
+#[test]
+fn temporaries() {
+    test(
+        "function f() { var _a; return (_a = g()) !== null && _a !== void 0 ? _a : 1 }",
+        "function f() { return g() ?? 1 }",
+    );
+    test(
+        "function f() { var _a, b = 2; return (_a = g()) == null ? b : _a }",
+        "function f() { var b = 2; return g() ?? b }",
+    );
+    test(
+        "function f() { var _a, _b; \
+         return [(_a = g()) != null ? _a : 1, (_b = h()) != null ? _b : 2] }",
+        "function f() { return [g() ?? 1, h() ?? 2] }",
+    );
+    // The temporary is read elsewhere
+    test(
+        "function f() { var _a; g((_a = h()) != null ? _a : 1); return _a }",
+        "function f() { var _a; g((_a = h()) ?? 1); return _a }",
+    );
+    // A global, or not only a temporary
+    test("var _a; (_a = g()) != null ? _a : 1", "var _a; (_a = g()) ?? 1");
+    test(
+        "function f() { var _a = 1; return (_a = g()) != null ? _a : 1 }",
+        "function f() { var _a = 1; return (_a = g()) ?? 1 }",
+    );
+    test(
+        "function f() { let _a; return (_a = g()) != null ? _a : 1 }",
+        "function f() { let _a; return (_a = g()) ?? 1 }",
+    );
+    // Only the first comparison may assign
+    test_same("function f() { var _a; return _a !== null && (_a = g()) !== void 0 ? _a : 1 }");
+}
@@ -0,0 +1,89 @@
+use oxc_span::SourceType;
+
+use crate::{test_with_compress_options, CompressOptions};
+
+fn test_with_options(source_text: &str, expected: &str, compress: CompressOptions) {
+    let source_type = SourceType::default().with_module(true);
+    let expected_compress = CompressOptions { optional_chaining: false, ..compress };
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test(source_text: &str, expected: &str) {
+    test_with_options(source_text, expected, CompressOptions::default());
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn non_nullish_values() {
+    test("'a'?.length", "'a'.length");
+    test("[1, 2]?.map(f)", "[1, 2].map(f)");
+    test("({ a: 1 })?.a", "({ a: 1 }).a");
+    test("new Foo()?.bar", "new Foo().bar");
+    test("(a + b)?.toString()", "(a + b).toString()");
+    test("(() => {})?.()", "(() => {})()");
+    test_same("(void 0)?.a");
+    test_same("a?.b");
+    test_same("a.b?.c");
+}
+
+#[test]
+fn non_nullish_bindings() {
+    test("function f() {} g(f?.())", "function f() {} g(f())");
+    test("class A {} A?.name", "class A {} A.name");
+    test("const a = {}; a?.b", "const a = {}; a.b");
+    test("let a = []; g(a?.length)", "let a = []; g(a.length)");
+    test("const a = {}; a?.b?.c", "const a = {}; a.b?.c");
+    test("const a = {}; a?.b.c", "const a = {}; a.b.c");
+    // Reassigned or possibly nullish
+    test_same("function f() {} f = null; f?.()");
+    test_same("let a = {}; a = g(); a?.b");
+    test_same("const a = g(); a?.b");
+    test_same("let a; a?.b");
+    test_same("import a from 'a'; a?.b");
+    test_same("function g(a) { return a?.b }");
+    // `var a = null` is assigned after the function declaration is hoisted
+    test_same("var f = null; function f() {} f?.()");
+    // Direct eval may reassign the binding
+    test_same("function g() { let a = {}; eval('a = null'); return a?.b }");
+}
+
+#[test]
+fn non_nullish_this() {
+    test("class A { constructor() { this?.a() } }", "class A { constructor() { this.a() } }");
+    test("class A { static { this?.a() } }", "class A { static { this.a() } }");
+    test("class A { b = this?.a }", "class A { b = this.a }");
+    test(
+        "class A { constructor() { f(() => this?.a) } }",
+        "class A { constructor() { f(() => this.a) } }",
+    );
+    test_same("class A { m() { return this?.a } }");
+    test_same("function f() { return this?.a }");
+    test_same("this?.a");
+}
+
+#[test]
+fn guarded() {
+    test("let a = g(); a && a?.b", "let a = g(); a && a.b");
+    test("function f(a) { return a && a?.b?.c }", "function f(a) { return a && a.b?.c }");
+    test("function f(a, b) { return a && b && a?.c }", "function f(a, b) { return a && b && a.c }");
+    test(
+        "function f(a) { return a != null ? a?.b : 0 }",
+        "function f(a) { return a != null ? a.b : 0 }",
+    );
+    test("function f() { return this && this?.a }", "function f() { return this && this.a }");
+    // `a` may be reassigned by the getter, and unresolved references may be getters
+    test_same("function f(a) { return a.b && a?.c }");
+    test_same("a && a?.b");
+    test_same("function f(a, b) { return a || a?.b }");
+    test_same("function f(a, b) { return a && b?.c }");
+    test_same("function f(a) { return a ? 0 : a?.b }");
+}
+
+#[test]
+fn disabled() {
+    let compress = CompressOptions { optional_chaining: false, ..CompressOptions::default() };
+    test_with_options("const a = {}; a?.b", "const a = {}; a?.b", compress);
+}
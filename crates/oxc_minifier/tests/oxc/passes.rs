@@ -0,0 +1,58 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_minifier::{CompressOptions, Minifier, MinifierOptions, PassPhase};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn append<'a>(allocator: &'a Allocator, program: &mut Program<'a>, source_text: &'static str) {
+    let appended = Parser::new(allocator, source_text, SourceType::default()).parse().program;
+    program.body.extend(appended.body);
+}
+
+/// The snapshots of compressing `a()` with a pass in each phase, which append
+/// `x = [1, 2].length` in the early phase and `y = [1, 2].length` in the late phase.
+fn snapshots(compress: CompressOptions) -> Vec<(&'static str, String)> {
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, "a()", SourceType::default()).parse().program;
+    let program = allocator.alloc(program);
+    let ret = Minifier::new(MinifierOptions { mangle: false, compress })
+        .with_snapshots(|program| {
+            Codegen::<true>::new("", "", CodegenOptions::default()).build(program).source_text
+        })
+        .with_pass(PassPhase::Late, "late", |allocator, program| {
+            append(allocator, program, "y = [1, 2].length");
+        })
+        .with_pass(PassPhase::Early, "early", |allocator, program| {
+            append(allocator, program, "x = [1, 2].length");
+        })
+        .build(&allocator, program);
+    ret.snapshots.into_iter().map(|snapshot| (snapshot.pass, snapshot.source_text)).collect()
+}
+
+#[test]
+fn phases() {
+    let compress = CompressOptions { arrays: true, late: true, ..CompressOptions::all_false() };
+    let snapshots = snapshots(compress);
+    let snapshots = snapshots.iter().map(|(pass, code)| (*pass, code.as_str())).collect::<Vec<_>>();
+    // The early pass runs before the peephole optimizations, the late pass after every pass.
+    assert_eq!(
+        snapshots,
+        [
+            ("prepass", "a();"),
+            ("early", "a();x=[1,2].length;"),
+            ("peephole", "a();x=2;"),
+            ("module_declarations", "a();x=2;"),
+            ("string_arrays", "a();x=2;"),
+            ("late", "a();x=2;y=[1,2].length;"),
+        ]
+    );
+}
+
+#[test]
+fn not_late() {
+    let compress = CompressOptions { arrays: true, ..CompressOptions::all_false() };
+    let snapshots = snapshots(compress);
+    assert_eq!(snapshots.last().map(|(_, code)| code.as_str()), Some("a();x=2;"));
+    assert!(snapshots.iter().all(|(pass, _)| *pass != "late"));
+}
@@ -0,0 +1,73 @@
+use oxc_minifier::CompressOptions;
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+fn test(source_text: &str, expected: &str) {
+    let source_type = SourceType::default();
+    let compress = CompressOptions { properties: true, ..CompressOptions::all_false() };
+    let expected_compress = CompressOptions::all_false();
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn member_expressions() {
+    test("a['b']", "a.b");
+    test("a['length']", "a.length");
+    test("a?.['b']", "a?.b");
+    test("a['b']['c'] = 1", "a.b.c = 1");
+    test("[a['b']] = c", "[a.b] = c");
+    test("a['if']", "a.if");
+    test("a['__proto__']", "a.__proto__");
+    test("1['toString']", "1..toString");
+    test("a['0']", "a[0]");
+    test("a['123']", "a[123]");
+    test_same("a['b c']");
+    test_same("a['3b']");
+    test_same("a['']");
+    test_same("a['010']");
+    test_same("a['-1']");
+    test_same("a['1.5']");
+    test_same("a['1e3']");
+    test_same("a['1234567890123456']");
+    test_same("a[b]");
+}
+
+#[test]
+fn object_keys() {
+    test("x = { 'a': 1, ['b']: 2, ['c d']: 3 }", "x = { a: 1, b: 2, 'c d': 3 }");
+    test("x = { '0': 1, ['1']: 2, [2]: 3 }", "x = { 0: 1, 1: 2, 2: 3 }");
+    test(
+        "x = { ['a']() {}, get ['b']() {}, set ['c'](v) {} }",
+        "x = { a() {}, get b() {}, set c(v) {} }",
+    );
+    test("x = { [a]: 1, ['01']: 2, [a + 'b']: 3 }", "x = { [a]: 1, '01': 2, [a + 'b']: 3 }");
+}
+
+#[test]
+fn class_keys() {
+    test(
+        "class A { ['a']() {} static ['b'] = 1; get ['c']() {} [0] = 1; 'd' = 2 }",
+        "class A { a() {} static b = 1; get c() {} 0 = 1; d = 2 }",
+    );
+    test_same("class A { [a]() {} }");
+}
+
+#[test]
+fn reserved_keys() {
+    // A computed `__proto__` defines a property instead of setting the prototype.
+    test_same("x = { ['__proto__']: 1 }");
+    test("x = { '__proto__': 1 }", "x = { __proto__: 1 }");
+    // A computed `constructor` is a method instead of the constructor.
+    test_same("class A { ['constructor']() {} }");
+    test_same("class A { ['constructor'] = 1 }");
+    test_same("class A { static ['prototype'] = 1 }");
+    test(
+        "class A { ['prototype'] = 1; static ['constructor']() {} }",
+        "class A { prototype = 1; static ['constructor']() {} }",
+    );
+}
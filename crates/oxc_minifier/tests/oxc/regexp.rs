@@ -0,0 +1,22 @@
+use crate::test;
+
+#[test]
+fn fold_test() {
+    test("x = /^a/.test('abc')", "x=!0;");
+    test("x = /^b/.test('abc')", "x=!1;");
+    test("x = /B$/i.test('ab')", "x=!0;");
+    test("x = /(a+)+b/.test('aaa')", "x=!1;");
+}
+
+#[test]
+fn fold_exec() {
+    test("x = /^b/.exec('abc')", "x=null;");
+    test("x = /^a/.exec('abc')", "x=/^a/.exec('abc');");
+}
+
+#[test]
+fn unsupported() {
+    test("x = /(?<a>b)/.test('b')", "x=/(?<a>b)/.test('b');");
+    test("x = /a/i.test('é')", "x=/a/i.test('é');");
+    test("x = /a/.test(b)", "x=/a/.test(b);");
+}
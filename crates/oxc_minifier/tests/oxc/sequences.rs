@@ -0,0 +1,52 @@
+use oxc_minifier::{CompressOptions, MinifierOptions};
+
+use crate::{test, test_with_options};
+
+#[test]
+fn join_expression_statements() {
+    test("a(); b(); c()", "a(),b(),c();");
+    test("a(); var x = 1; b(); c()", "a();var x=1;b(),c();");
+    test("if (x) { a(); b() }", "if(x)a(),b();");
+    test("a(); (b(), c()); d()", "a(),b(),c(),d();");
+}
+
+#[test]
+fn join_into_return_and_throw() {
+    test("function f() { a(); b(); return c }", "function f(){return a(),b(),c}");
+    test("function f() { for (;;) { a(); return } }", "function f(){for(;;){a();return}}");
+    test("function f() { a(); throw b }", "function f(){throw a(),b}");
+}
+
+#[test]
+fn fold_if_return() {
+    test("function f() { if (a) return b; return c }", "function f(){return a?b:c}");
+    test("function f() { if (a) return b; else return c }", "function f(){return a?b:c}");
+    test("function f() { if (a) return; return c }", "function f(){if(!a)return c}");
+    test(
+        "function f() { x(); if (a) { y(); return b } return c }",
+        "function f(){return x(),a?(y(),b):c}",
+    );
+}
+
+#[test]
+fn disabled() {
+    let compress = CompressOptions { sequences: false, ..CompressOptions::default() };
+    let options = MinifierOptions { mangle: false, compress };
+    test_with_options(
+        "function f() { a(); if (b) return c; return d }",
+        "function f(){a();if(b)return c;return d}",
+        options,
+    );
+}
+
+#[test]
+fn not_late() {
+    let compress = CompressOptions { late: false, ..CompressOptions::default() };
+    let options = MinifierOptions { mangle: false, compress };
+    test_with_options("a(); b(); c()", "a();b();c();", options);
+    test_with_options(
+        "function f() { a(); if (b) return c; return d }",
+        "function f(){a();return b?c:d}",
+        options,
+    );
+}
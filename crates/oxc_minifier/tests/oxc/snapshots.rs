@@ -0,0 +1,28 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_minifier::{CompressOptions, Minifier, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+#[test]
+fn snapshots() {
+    let source_text = "a = [1, 2].length";
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
+    let program = allocator.alloc(program);
+    let compress = CompressOptions { arrays: true, ..CompressOptions::all_false() };
+    let ret = Minifier::new(MinifierOptions { mangle: false, compress })
+        .with_snapshots(|program| {
+            Codegen::<true>::new("", "", CodegenOptions::default()).build(program).source_text
+        })
+        .build(&allocator, program);
+    let snapshots = ret
+        .snapshots
+        .iter()
+        .map(|snapshot| (snapshot.pass, snapshot.source_text.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        snapshots,
+        [("prepass", "a=[1,2].length;"), ("peephole", "a=2;"), ("module_declarations", "a=2;")]
+    );
+}
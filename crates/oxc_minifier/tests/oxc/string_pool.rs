@@ -0,0 +1,57 @@
+use oxc_minifier::CompressOptions;
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+fn test_with_source_type(source_text: &str, expected: &str, source_type: SourceType) {
+    let compress = CompressOptions { pool_strings: true, ..CompressOptions::default() };
+    let expected_compress = CompressOptions::all_false();
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test(source_text: &str, expected: &str) {
+    test_with_source_type(source_text, expected, SourceType::default().with_module(true));
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+/// `f("pointerdown", "x0"), f("pointerdown", "x1"), ..`, where the repeated string is far apart.
+fn calls(string: &str) -> String {
+    (0..30).map(|i| format!("f({string}, 'x{i}')")).collect::<Vec<_>>().join(", ")
+}
+
+#[test]
+fn pooled() {
+    test(&calls("'pointerdown'"), &format!("const a = 'pointerdown'; {}", calls("a")));
+    test(
+        &format!("export const a = 1; {}", calls("'pointerdown'")),
+        &format!("const b = 'pointerdown'; export const a = 1; {}", calls("b")),
+    );
+    test(
+        &format!("export function h() {{ {} }}", calls("'pointerdown'")),
+        &format!("const a = 'pointerdown'; export function h() {{ {} }}", calls("a")),
+    );
+    // Specifiers are kept.
+    test(
+        &format!("require('pointerdown'), import('pointerdown'), {}", calls("'pointerdown'")),
+        &format!(
+            "const a = 'pointerdown'; require('pointerdown'), import('pointerdown'), {}",
+            calls("a")
+        ),
+    );
+}
+
+#[test]
+fn not_pooled() {
+    // Smaller after gzip as is.
+    test_same("f('pointerdown'), g('pointerdown')");
+    // Not shorter.
+    test_same("f('a', 'a', 'a')");
+    // A global in a script.
+    let calls = calls("'pointerdown'");
+    test_with_source_type(&calls, &calls, SourceType::default());
+    // The module may be in an import cycle.
+    test_same(&format!("import f from 'm'; export function h() {{ {calls} }}"));
+}
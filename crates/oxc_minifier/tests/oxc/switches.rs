@@ -0,0 +1,76 @@
+use oxc_minifier::CompressOptions;
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+fn test(source_text: &str, expected: &str) {
+    let source_type = SourceType::default();
+    let compress = CompressOptions { switches: true, ..CompressOptions::all_false() };
+    let expected_compress = CompressOptions::all_false();
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn if_chain() {
+    test(
+        "function f() {
+            if (x === 1) return a; else if (x === 2) return b;
+            else if (x === 3) return c; else if (x === 4) return d; else return e
+        }",
+        "function f() {
+            switch (x) { case 1: return a; case 2: return b; case 3: return c; case 4: return d; default: return e }
+        }",
+    );
+    test(
+        "function f() {
+            if (x === 'a' || x === 'b') return 1; else if ('c' === x) return 2; else if (x === 'd') return 3
+        }",
+        "function f() { switch (x) { case 'a': case 'b': return 1; case 'c': return 2; case 'd': return 3 } }",
+    );
+}
+
+#[test]
+fn rest_of_chain() {
+    test(
+        "function f() {
+            if (x === 1) return a; else if (x === 2) return b;
+            else if (x === 3) return c; else if (x === 4) return d; else if (y === 5) return e
+        }",
+        "function f() {
+            switch (x) { case 1: return a; case 2: return b; case 3: return c; case 4: return d; default: if (y === 5) return e }
+        }",
+    );
+}
+
+#[test]
+fn block_scoped() {
+    test(
+        "function f() {
+            if (x === 1) { let y = g(); return y } else if (x === 2) { h(); return b }
+            else if (x === 3) return c; else if (x === 4) return d; else return e
+        }",
+        "function f() {
+            switch (x) { case 1: { let y = g(); return y } case 2: h(); return b; case 3: return c; case 4: return d; default: return e }
+        }",
+    );
+}
+
+#[test]
+fn kept() {
+    // The `break` would end the `switch`.
+    test_same(
+        "function f() {
+            for (;;) if (x === 1) return a; else if (x === 2) return b;
+            else if (x === 3) return c; else if (x === 4) break; else return e
+        }",
+    );
+    // Longer as a `switch` statement.
+    test_same("if (x === 1) a(); else if (x === 2) b(); else c()");
+    test_same("function f() { if (x === 1) return a; else if (x === 2) return b; else return c }");
+    // `==` and comparisons with variables are not like `case`.
+    test_same("if (x === 1) a(); else if (x == 2) b(); else if (x === y) c(); else d()");
+}
@@ -0,0 +1,80 @@
+use oxc_minifier::CompressOptions;
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+fn test_with_options(source_text: &str, expected: &str, compress: CompressOptions) {
+    let source_type = SourceType::default();
+    let expected_compress = CompressOptions::all_false();
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test(source_text: &str, expected: &str) {
+    let compress = CompressOptions { typeofs: true, ..CompressOptions::all_false() };
+    test_with_options(source_text, expected, compress);
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn typeof_undefined() {
+    test("var x; typeof x === 'undefined'", "var x; void 0 === x");
+    test("var x; typeof x == 'undefined'", "var x; void 0 === x");
+    test("var x; typeof x !== 'undefined'", "var x; void 0 !== x");
+    test("var x; 'undefined' != typeof x", "var x; void 0 !== x");
+    test("var x; typeof x === 'undefined' ? a : b", "var x; void 0 === x ? a : b");
+    // Reading an undeclared global throws.
+    test_same("typeof x === 'undefined'");
+    test_same("typeof x !== 'undefined' ? a : b");
+}
+
+#[test]
+fn nullish_checks() {
+    test("var x; typeof x == 'undefined' || x === null", "var x; x == null");
+    test("var x; x === null || typeof x === 'undefined'", "var x; x == null");
+    test("var x; typeof x != 'undefined' && x !== null", "var x; x != null");
+    test("var x; x === undefined || x === null", "var x; x == null");
+    test("var x; x == null || x === void 0", "var x; x == null");
+    test("var x; a || typeof x == 'undefined' || x === null", "var x; a || x == null");
+    test("var x; (typeof x == 'undefined' || x === null) ? a : b", "var x; x == null ? a : b");
+    test_same("var x; x === null || x === null");
+    test_same("var x, y; x === null || y === void 0");
+    test_same("var x; x === null && x === void 0");
+    test_same("var x; x !== null || x !== void 0");
+    test_same("x === null || x === void 0");
+}
+
+#[test]
+fn typeof_switch() {
+    test(
+        "switch (typeof x) { case 'strnig': a(); case 'string': b() }",
+        "switch (typeof x) { case 'string': b() }",
+    );
+    test(
+        "switch (typeof x) { case 'string': a(); case 'strnig': b(); break; case 'number': c() }",
+        "switch (typeof x) { case 'string': a(); b(); break; case 'number': c() }",
+    );
+    test(
+        "switch (typeof x) { case 'string': case 'Object': a() }",
+        "switch (typeof x) { case 'string': a() }",
+    );
+    test_same("switch (typeof x) { case 'strnig': var y = a(); case 'string': b(y) }");
+    test_same("switch (x) { case 'strnig': a() }");
+}
+
+#[test]
+fn disabled() {
+    let compress = CompressOptions::all_false();
+    test_with_options(
+        "var x; typeof x !== 'undefined'",
+        "var x; typeof x !== 'undefined'",
+        compress,
+    );
+    test_with_options(
+        "switch (typeof x) { case 'strnig': a() }",
+        "switch (typeof x) { case 'strnig': a() }",
+        compress,
+    );
+}
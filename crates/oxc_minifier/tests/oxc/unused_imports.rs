@@ -0,0 +1,100 @@
+use oxc_minifier::CompressOptions;
+use oxc_span::SourceType;
+
+use crate::test_with_compress_options;
+
+fn test_with_options(source_text: &str, expected: &str, compress: CompressOptions) {
+    let source_type = SourceType::default().with_module(true);
+    let expected_compress = CompressOptions::all_false();
+    test_with_compress_options(source_text, expected, source_type, compress, expected_compress);
+}
+
+fn test(source_text: &str, expected: &str) {
+    test_with_options(source_text, expected, CompressOptions::default());
+}
+
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn unused_specifiers() {
+    test("import { a, b } from 'm'; a()", "import { a } from 'm'; a()");
+    test("import a, { b } from 'm'; b()", "import { b } from 'm'; b()");
+    test("import a, * as b from 'm'; a()", "import a from 'm'; a()");
+    test("import { a } from 'm'", "import 'm'");
+    test("import * as a from 'm'", "import 'm'");
+    test_same("import 'm'");
+    test_same("import { a } from 'm'; export { a }");
+    test_same("import a from 'm'; export default a");
+    test_same("import { a } from 'm'; function f() { return a }");
+}
+
+#[test]
+fn dead_branches() {
+    let options = CompressOptions { node_env: Some("production"), ..CompressOptions::default() };
+    test_with_options(
+        "import { devWarn, log } from './debug'; if (process.env.NODE_ENV !== 'production') devWarn(); log()",
+        "import { log } from './debug'; log()",
+        options,
+    );
+    test_with_options(
+        "import { devWarn } from './debug'; if (process.env.NODE_ENV !== 'production') devWarn()",
+        "import './debug'",
+        options,
+    );
+    let options = CompressOptions { unsafe_imports: true, ..options };
+    test_with_options(
+        "import { devWarn } from './debug'; if (process.env.NODE_ENV !== 'production') devWarn()",
+        "",
+        options,
+    );
+}
+
+#[test]
+fn unsafe_imports() {
+    let options = CompressOptions { unsafe_imports: true, ..CompressOptions::default() };
+    test_with_options("import { a } from 'm'; import 'n'", "import 'n'", options);
+    test_with_options("import { a, b } from 'm'; b()", "import { b } from 'm'; b()", options);
+}
+
+#[test]
+fn pure_modules() {
+    let options = CompressOptions {
+        pure_modules: &["lodash-es", "lodash-es/*"],
+        ..CompressOptions::default()
+    };
+    test_with_options(
+        "import { map } from 'lodash-es'; import 'core-js/stable'",
+        "import 'core-js/stable'",
+        options,
+    );
+    test_with_options(
+        "import 'lodash-es/map'; import { a } from 'lodash'",
+        "import 'lodash'",
+        options,
+    );
+    test_with_options(
+        "import { map } from 'lodash-es'; map()",
+        "import { map } from 'lodash-es'; map()",
+        options,
+    );
+    test_with_options("require('lodash-es'); require('core-js')", "require('core-js')", options);
+    test_with_options("import('lodash-es/map'); import('core-js')", "import('core-js')", options);
+    test_with_options(
+        "function f(require) { require('lodash-es') }",
+        "function f(require) { require('lodash-es') }",
+        options,
+    );
+}
+
+#[test]
+fn direct_eval() {
+    test_same("import { a } from 'm'; eval('a')");
+}
+
+#[test]
+fn disabled() {
+    let options = CompressOptions { unused_imports: false, ..CompressOptions::default() };
+    test_with_options("import { a } from 'm'", "import { a } from 'm'", options);
+}
@@ -0,0 +1,83 @@
+use oxc_minifier::{CompressOptions, MinifierOptions};
+
+use crate::{test, test_with_options};
+
+#[test]
+fn unused_params() {
+    test(
+        "function g() { function f(a, b) { return a } return f(1, 2) }",
+        "function g(){function f(a){return a}return f(1)}",
+    );
+    test(
+        "function g() { function f(a, b, c) { return b } return f(1, 2, 3) }",
+        "function g(){function f(a,b){return b}return f(1,2)}",
+    );
+    test(
+        "function g() { const f = (a, b) => a; return f(1, 2) }",
+        "function g(){const f=a=>a;return f(1)}",
+    );
+    test(
+        "function g() { let f = function(a, b) { return a }; return f(1, 2) }",
+        "function g(){let f=function(a){return a};return f(1)}",
+    );
+}
+
+#[test]
+fn ignored_arguments() {
+    // Arguments with side effects are still evaluated.
+    test(
+        "function g() { function f(a) { return a } return f(1, h()) }",
+        "function g(){function f(a){return a}return f(1,h())}",
+    );
+    test(
+        "function g() { function f(a, b) { return a + b } return f(1, undefined) }",
+        "function g(){function f(a,b){return a+b}return f(1)}",
+    );
+    test(
+        "function g() { function f(a, b) { return a + b } return f(void 0, void 0) }",
+        "function g(){function f(a,b){return a+b}return f()}",
+    );
+    test(
+        "function g() { function f(a) { return a } return f(...x, 1) }",
+        "function g(){function f(a){return a}return f(...x,1)}",
+    );
+}
+
+#[test]
+fn unknown_call_sites() {
+    test(
+        "function g() { function f(a, b) { return a } h(f); return f(1, 2) }",
+        "function g(){function f(a,b){return a}return h(f),f(1,2)}",
+    );
+    test(
+        "function g() { function f(a, b) { return arguments } return f(1, 2) }",
+        "function g(){function f(a,b){return arguments}return f(1,2)}",
+    );
+    test(
+        "function g() { function f(a, ...b) { return a } return f(1, 2) }",
+        "function g(){function f(a,...b){return a}return f(1,2)}",
+    );
+    test(
+        "function g() { function f(a, b) { return eval('b') } return f(1, 2) }",
+        "function g(){function f(a,b){return eval('b')}return f(1,2)}",
+    );
+    test(
+        "function g() { let f = (a, b) => a; f = h; return f(1, 2) }",
+        "function g(){let f=(a,b)=>a;return f=h,f(1,2)}",
+    );
+    // Top-level functions of a script can be called by other scripts.
+    test("function f(a, b) { return a } g(f(1, 2))", "function f(a,b){return a}g(f(1,2));");
+}
+
+#[test]
+fn options() {
+    let options = MinifierOptions {
+        mangle: false,
+        compress: CompressOptions { unused_params: false, ..CompressOptions::default() },
+    };
+    test_with_options(
+        "function g() { function f(a, b) { return a } return f(1, 2) }",
+        "function g(){function f(a,b){return a}return f(1,2)}",
+        options,
+    );
+}
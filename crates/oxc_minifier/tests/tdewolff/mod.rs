@@ -1,6 +1,6 @@
 //! <https://github.com/tdewolff/minify/blob/master/js/js_test.go>
 
-use crate::test;
+use crate::{test, test_with_options, CompressOptions, MinifierOptions};
 
 #[test]
 fn tdewolff() {
@@ -186,6 +186,10 @@ fn tdewolff() {
 #[test]
 #[ignore = "TODO"]
 fn ignore() {
+    let keep_debugger = MinifierOptions {
+        mangle: false,
+        compress: CompressOptions { drop_debugger: false, ..CompressOptions::default() },
+    };
     // if/else statements
     test("if(a){return b}", "if(a)return b;");
     test("if(a){b = 5;return b}", "if(a)return b=5,b;");
@@ -804,7 +808,7 @@ fn ignore() {
     test("for(var a;;)a();var b=5", "for(;;)a();var a,b=5;"); // #346
     test("if(e?0:n=1,o=2){o.a}", "(e?0:n=1,o=2)&&o.a;"); // #347
     test("const a=(a,b)=>({...a,b})", "const a=(a,b)=>({...a,b});"); // #369
-    test("if(!a)debugger;", "if(!a)debugger;"); // #370
+    test_with_options("if(!a)debugger;", "if(!a)debugger;", keep_debugger); // #370
     test("export function a(b){b}", "export function a(b){b};"); // #375
     test("switch(a){case 0:b=c;d=e}", "switch(a){case 0:b=c,d=e};"); // #426
     test("if(a){const b=0}", ";"); // #428
@@ -131,7 +131,6 @@ impl TestCase {
         Self { name, compress_options: options, input, expect }
     }
 
-    #[allow(clippy::single_match)]
     fn parse_options<'a>(object_expr: &'a ObjectExpression<'a>) -> CompressOptions {
         let mut options = CompressOptions::default();
         for object_property in &object_expr.properties {
@@ -139,6 +138,9 @@ impl TestCase {
                 if property.key.is_specific_static_name("drop_debugger") {
                     options.drop_debugger = Self::get_boolean(&property.value);
                 }
+                if property.key.is_specific_static_name("sequences") {
+                    options.sequences = Self::get_boolean(&property.value);
+                }
             }
         }
         options
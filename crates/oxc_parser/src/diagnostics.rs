@@ -3,93 +3,139 @@ use oxc_span::Span;
 
 #[cold]
 pub fn redeclaration(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Identifier `{x0}` has already been declared")).with_labels([
-        LabeledSpan::new_with_span(Some(format!("`{x0}` has already been declared here")), span1),
-        LabeledSpan::new_with_span(Some("It can not be redeclared here".to_string()), span2),
-    ])
+    OxcDiagnostic::error(format!("Identifier `{x0}` has already been declared"))
+        .with_labels([
+            LabeledSpan::new_with_span(
+                Some(format!("`{x0}` has already been declared here")),
+                span1,
+            ),
+            LabeledSpan::new_with_span(Some("It can not be redeclared here".to_string()), span2),
+        ])
+        .with_oxc_code(&REDECLARATION)
 }
 
 #[cold]
 pub fn overlong_source() -> OxcDiagnostic {
-    OxcDiagnostic::error("Source length exceeds 4 GiB limit")
+    OxcDiagnostic::error("Source length exceeds 4 GiB limit").with_oxc_code(&OVERLONG_SOURCE)
+}
+
+#[cold]
+pub fn invalid_utf8(x0: usize, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Invalid UTF-8 sequence at byte offset {x0}"))
+        .with_help("Invalid sequences are replaced with U+FFFD")
+        .with_labels([span1.into()])
+        .with_oxc_code(&INVALID_UTF8)
+}
+
+#[cold]
+pub fn lone_surrogate(x0: u32, x1: usize, span2: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Lone surrogate U+{x0:X} encoded at byte offset {x1}"))
+        .with_help("Surrogates are not valid in UTF-8 and are replaced with U+FFFD")
+        .with_labels([span2.into()])
+        .with_oxc_code(&LONE_SURROGATE)
 }
 
 #[cold]
 pub fn flow(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Flow is not supported").with_labels([span0.into()])
+    OxcDiagnostic::error("Flow is not supported").with_labels([span0.into()]).with_oxc_code(&FLOW)
 }
 
 #[cold]
 pub fn unexpected_token(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected token").with_labels([span0.into()])
+    OxcDiagnostic::error("Unexpected token")
+        .with_labels([span0.into()])
+        .with_oxc_code(&UNEXPECTED_TOKEN)
 }
 
 #[cold]
 pub fn expect_token(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Expected `{x0}` but found `{x1}`"))
         .with_labels([LabeledSpan::new_with_span(Some(format!("`{x0}` expected")), span2)])
+        .with_oxc_code(&EXPECT_TOKEN)
 }
 
 #[cold]
 pub fn invalid_escape_sequence(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid escape sequence").with_labels([span0.into()])
+    OxcDiagnostic::error("Invalid escape sequence")
+        .with_labels([span0.into()])
+        .with_oxc_code(&INVALID_ESCAPE_SEQUENCE)
 }
 
 #[cold]
 pub fn unicode_escape_sequence(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid Unicode escape sequence").with_labels([span0.into()])
+    OxcDiagnostic::error("Invalid Unicode escape sequence")
+        .with_labels([span0.into()])
+        .with_oxc_code(&UNICODE_ESCAPE_SEQUENCE)
 }
 
 #[cold]
 pub fn invalid_character(x0: char, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Invalid Character `{x0}`")).with_labels([span1.into()])
+    OxcDiagnostic::error(format!("Invalid Character `{x0}`"))
+        .with_labels([span1.into()])
+        .with_oxc_code(&INVALID_CHARACTER)
 }
 
 #[cold]
 pub fn invalid_number_end(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid characters after number").with_labels([span0.into()])
+    OxcDiagnostic::error("Invalid characters after number")
+        .with_labels([span0.into()])
+        .with_oxc_code(&INVALID_NUMBER_END)
 }
 
 #[cold]
 pub fn unterminated_multi_line_comment(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unterminated multiline comment").with_labels([span0.into()])
+    OxcDiagnostic::error("Unterminated multiline comment")
+        .with_labels([span0.into()])
+        .with_oxc_code(&UNTERMINATED_MULTI_LINE_COMMENT)
 }
 
 #[cold]
 pub fn unterminated_string(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unterminated string").with_labels([span0.into()])
+    OxcDiagnostic::error("Unterminated string")
+        .with_labels([span0.into()])
+        .with_oxc_code(&UNTERMINATED_STRING)
 }
 
 #[cold]
 pub fn reg_exp_flag(x0: char, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Unexpected flag {x0} in regular expression literal"))
         .with_labels([span1.into()])
+        .with_oxc_code(&REG_EXP_FLAG)
 }
 
 #[cold]
 pub fn reg_exp_flag_twice(x0: char, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Flag {x0} is mentioned twice in regular expression literal"))
         .with_labels([span1.into()])
+        .with_oxc_code(&REG_EXP_FLAG_TWICE)
 }
 
 #[cold]
 pub fn unexpected_end(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected end of file").with_labels([span0.into()])
+    OxcDiagnostic::error("Unexpected end of file")
+        .with_labels([span0.into()])
+        .with_oxc_code(&UNEXPECTED_END)
 }
 
 #[cold]
 pub fn unterminated_reg_exp(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unterminated regular expression").with_labels([span0.into()])
+    OxcDiagnostic::error("Unterminated regular expression")
+        .with_labels([span0.into()])
+        .with_oxc_code(&UNTERMINATED_REG_EXP)
 }
 
 #[cold]
 pub fn invalid_number(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Invalid Number {x0}")).with_labels([span1.into()])
+    OxcDiagnostic::error(format!("Invalid Number {x0}"))
+        .with_labels([span1.into()])
+        .with_oxc_code(&INVALID_NUMBER)
 }
 
 #[cold]
 pub fn escaped_keyword(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Keywords cannot contain escape characters").with_labels([span0.into()])
+    OxcDiagnostic::error("Keywords cannot contain escape characters")
+        .with_labels([span0.into()])
+        .with_oxc_code(&ESCAPED_KEYWORD)
 }
 
 #[cold]
@@ -99,22 +145,28 @@ pub fn auto_semicolon_insertion(span0: Span) -> OxcDiagnostic {
     )
     .with_help("Try insert a semicolon here")
     .with_labels([span0.into()])
+    .with_oxc_code(&AUTO_SEMICOLON_INSERTION)
 }
 
 #[cold]
 pub fn lineterminator_before_arrow(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Line terminator not permitted before arrow").with_labels([span0.into()])
+    OxcDiagnostic::error("Line terminator not permitted before arrow")
+        .with_labels([span0.into()])
+        .with_oxc_code(&LINE_TERMINATOR_BEFORE_ARROW)
 }
 
 #[cold]
 pub fn invalid_destrucuring_declaration(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Missing initializer in destructuring declaration")
         .with_labels([span0.into()])
+        .with_oxc_code(&INVALID_DESTRUCTURING_DECLARATION)
 }
 
 #[cold]
 pub fn missinginitializer_in_const(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Missing initializer in const declaration").with_labels([span0.into()])
+    OxcDiagnostic::error("Missing initializer in const declaration")
+        .with_labels([span0.into()])
+        .with_oxc_code(&MISSING_INITIALIZER_IN_CONST)
 }
 
 #[cold]
@@ -122,18 +174,21 @@ pub fn lexical_declaration_single_statement(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Lexical declaration cannot appear in a single-statement context")
         .with_help("Wrap this declaration in a block statement")
         .with_labels([span0.into()])
+        .with_oxc_code(&LEXICAL_DECLARATION_SINGLE_STATEMENT)
 }
 
 #[cold]
 pub fn async_function_declaration(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Async functions can only be declared at the top level or inside a block")
         .with_labels([span0.into()])
+        .with_oxc_code(&ASYNC_FUNCTION_DECLARATION)
 }
 
 #[cold]
 pub fn generator_function_declaration(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Generators can only be declared at the top level or inside a block")
         .with_labels([span0.into()])
+        .with_oxc_code(&GENERATOR_FUNCTION_DECLARATION)
 }
 
 #[cold]
@@ -142,12 +197,14 @@ pub fn await_expression(span0: Span) -> OxcDiagnostic {
         "`await` is only allowed within async functions and at the top levels of modules",
     )
     .with_labels([span0.into()])
+    .with_oxc_code(&AWAIT_EXPRESSION)
 }
 
 #[cold]
 pub fn yield_expression(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("A 'yield' expression is only allowed in a generator body.")
         .with_labels([span0.into()])
+        .with_oxc_code(&YIELD_EXPRESSION)
 }
 
 #[cold]
@@ -155,28 +212,35 @@ pub fn class_declaration(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Invalid class declaration")
         .with_help("Classes can only be declared at top level or inside a block")
         .with_labels([span0.into()])
+        .with_oxc_code(&CLASS_DECLARATION)
 }
 
 #[cold]
 pub fn binding_rest_element_last(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("A rest element must be last in a destructuring pattern")
         .with_labels([span0.into()])
+        .with_oxc_code(&BINDING_REST_ELEMENT_LAST)
 }
 
 #[cold]
 pub fn rest_parameter_last(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("A rest parameter must be last in a parameter list")
         .with_labels([span0.into()])
+        .with_oxc_code(&REST_PARAMETER_LAST)
 }
 
 #[cold]
 pub fn spread_last_element(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Spread must be last element").with_labels([span0.into()])
+    OxcDiagnostic::error("Spread must be last element")
+        .with_labels([span0.into()])
+        .with_oxc_code(&SPREAD_LAST_ELEMENT)
 }
 
 #[cold]
 pub fn binding_rest_element_trailing_comma(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected trailing comma after rest element").with_labels([span0.into()])
+    OxcDiagnostic::error("Unexpected trailing comma after rest element")
+        .with_labels([span0.into()])
+        .with_oxc_code(&BINDING_REST_ELEMENT_TRAILING_COMMA)
 }
 
 #[cold]
@@ -184,90 +248,112 @@ pub fn invalid_binding_rest_element(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Invalid rest element")
         .with_help("Expected identifier in rest element")
         .with_labels([span0.into()])
+        .with_oxc_code(&INVALID_BINDING_REST_ELEMENT)
 }
 
 #[cold]
 pub fn a_rest_parameter_cannot_be_optional(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A rest parameter cannot be optional").with_labels([span0.into()])
+    OxcDiagnostic::error("A rest parameter cannot be optional")
+        .with_labels([span0.into()])
+        .with_oxc_code(&OPTIONAL_REST_PARAMETER)
 }
 
 #[cold]
 pub fn invalid_assignment(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Cannot assign to this expression").with_labels([span0.into()])
+    OxcDiagnostic::error("Cannot assign to this expression")
+        .with_labels([span0.into()])
+        .with_oxc_code(&INVALID_ASSIGNMENT)
 }
 
 #[cold]
 pub fn new_optional_chain(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Optional chaining cannot appear in the callee of new expressions")
         .with_labels([span0.into()])
+        .with_oxc_code(&NEW_OPTIONAL_CHAIN)
 }
 
 #[cold]
 pub fn for_loop_async_of(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("The left-hand side of a `for...of` statement may not be `async`")
         .with_labels([span0.into()])
+        .with_oxc_code(&FOR_LOOP_ASYNC_OF)
 }
 
 #[cold]
 pub fn for_await(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("await can only be used in conjunction with `for...of` statements")
         .with_labels([span0.into()])
+        .with_oxc_code(&FOR_AWAIT)
 }
 
 #[cold]
 pub fn new_dynamic_import(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Cannot use new with dynamic import").with_labels([span0.into()])
+    OxcDiagnostic::error("Cannot use new with dynamic import")
+        .with_labels([span0.into()])
+        .with_oxc_code(&NEW_DYNAMIC_IMPORT)
 }
 
 #[cold]
 pub fn private_name_constructor(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Classes can't have an element named '#constructor'")
         .with_labels([span0.into()])
+        .with_oxc_code(&PRIVATE_NAME_CONSTRUCTOR)
 }
 
 #[cold]
 pub fn static_prototype(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Classes may not have a static property named prototype")
         .with_labels([span0.into()])
+        .with_oxc_code(&STATIC_PROTOTYPE)
 }
 
 #[cold]
 pub fn constructor_getter_setter(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Constructor can't have get/set modifier").with_labels([span0.into()])
+    OxcDiagnostic::error("Constructor can't have get/set modifier")
+        .with_labels([span0.into()])
+        .with_oxc_code(&CONSTRUCTOR_GETTER_SETTER)
 }
 
 #[cold]
 pub fn constructor_async(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Constructor can't be an async method").with_labels([span0.into()])
+    OxcDiagnostic::error("Constructor can't be an async method")
+        .with_labels([span0.into()])
+        .with_oxc_code(&CONSTRUCTOR_ASYNC)
 }
 
 #[cold]
 pub fn identifier_async(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Cannot use `{x0}` as an identifier in an async context"))
         .with_labels([span1.into()])
+        .with_oxc_code(&IDENTIFIER_ASYNC)
 }
 
 #[cold]
 pub fn identifier_generator(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Cannot use `{x0}` as an identifier in a generator context"))
         .with_labels([span1.into()])
+        .with_oxc_code(&IDENTIFIER_GENERATOR)
 }
 
 #[cold]
 pub fn constructor_generator(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Constructor can't be a generator").with_labels([span0.into()])
+    OxcDiagnostic::error("Constructor can't be a generator")
+        .with_labels([span0.into()])
+        .with_oxc_code(&CONSTRUCTOR_GENERATOR)
 }
 
 #[cold]
 pub fn field_constructor(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Classes can't have a field named 'constructor'")
         .with_labels([span0.into()])
+        .with_oxc_code(&FIELD_CONSTRUCTOR)
 }
 
 #[cold]
 pub fn export_lone_surrogate(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("An export name cannot include a unicode lone surrogate")
         .with_labels([span0.into()])
+        .with_oxc_code(&EXPORT_LONE_SURROGATE)
 }
 
 #[cold]
@@ -275,6 +361,7 @@ pub fn export_named_string(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("A string literal cannot be used as an exported binding without `from`")
         .with_help(format!("Did you mean `export {{ {x0} as {x1} }} from 'some-module'`?"))
         .with_labels([span2.into()])
+        .with_oxc_code(&EXPORT_NAMED_STRING)
 }
 
 #[cold]
@@ -282,43 +369,52 @@ pub fn export_reserved_word(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("A reserved word cannot be used as an exported binding without `from`")
         .with_help(format!("Did you mean `export {{ {x0} as {x1} }} from 'some-module'`?"))
         .with_labels([span2.into()])
+        .with_oxc_code(&EXPORT_RESERVED_WORD)
 }
 
 #[cold]
 pub fn template_literal(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Bad escape sequence in untagged template literal")
         .with_labels([span0.into()])
+        .with_oxc_code(&TEMPLATE_LITERAL)
 }
 
 #[cold]
 pub fn empty_parenthesized_expression(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Empty parenthesized expression").with_labels([span0.into()])
+    OxcDiagnostic::error("Empty parenthesized expression")
+        .with_labels([span0.into()])
+        .with_oxc_code(&EMPTY_PARENTHESIZED_EXPRESSION)
 }
 
 #[cold]
 pub fn illegal_newline(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Illegal newline after {x0}")).with_labels([
-        LabeledSpan::new_with_span(Some(format!("{x0} starts here")), span1),
-        LabeledSpan::new_with_span(Some("A newline is not expected here".to_string()), span2),
-    ])
+    OxcDiagnostic::error(format!("Illegal newline after {x0}"))
+        .with_labels([
+            LabeledSpan::new_with_span(Some(format!("{x0} starts here")), span1),
+            LabeledSpan::new_with_span(Some("A newline is not expected here".to_string()), span2),
+        ])
+        .with_oxc_code(&ILLEGAL_NEWLINE)
 }
 
 #[cold]
 pub fn optional_chain_tagged_template(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Tagged template expressions are not permitted in an optional chain")
         .with_labels([span0.into()])
+        .with_oxc_code(&OPTIONAL_CHAIN_TAGGED_TEMPLATE)
 }
 
 #[cold]
 pub fn ts_constructor_this_parameter(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("TS2681: A constructor cannot have a `this` parameter.")
         .with_labels([span0.into()])
+        .with_oxc_code(&TS_CONSTRUCTOR_THIS_PARAMETER)
 }
 
 #[cold]
 pub fn ts_arrow_function_this_parameter(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("TS2730: An arrow function cannot have a `this` parameter.")
         .with_labels([span0.into()])
+        .with_oxc_code(&TS_ARROW_FUNCTION_THIS_PARAMETER)
 }
 
 #[cold]
@@ -326,6 +422,7 @@ pub fn unexpected_super(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("'super' can only be used with function calls or in property accesses")
         .with_help("replace with `super()` or `super.prop` or `super[prop]`")
         .with_labels([span0.into()])
+        .with_oxc_code(&UNEXPECTED_SUPER)
 }
 
 #[cold]
@@ -333,23 +430,28 @@ pub fn expect_function_name(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Expected function name")
         .with_help("Function name is required in function declaration or named export")
         .with_labels([span0.into()])
+        .with_oxc_code(&EXPECT_FUNCTION_NAME)
 }
 
 #[cold]
 pub fn expect_catch_finally(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Missing catch or finally clause").with_labels([span0.into()])
+    OxcDiagnostic::error("Missing catch or finally clause")
+        .with_labels([span0.into()])
+        .with_oxc_code(&EXPECT_CATCH_FINALLY)
 }
 
 #[cold]
 pub fn a_set_accessor_cannot_have_a_return_type_annotation(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("TS1095: A 'set' accessor cannot have a return type annotation")
         .with_labels([span0.into()])
+        .with_oxc_code(&SETTER_RETURN_TYPE)
 }
 
 #[cold]
 pub fn return_statement_only_in_function_body(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("TS1108: A 'return' statement can only be used within a function body")
         .with_labels([span0.into()])
+        .with_oxc_code(&RETURN_OUTSIDE_FUNCTION)
 }
 
 #[cold]
@@ -357,23 +459,28 @@ pub fn jsx_expressions_may_not_use_the_comma_operator(span0: Span) -> OxcDiagnos
     OxcDiagnostic::error("TS18007: JSX expressions may not use the comma operator.")
         .with_help("Did you mean to write an array?")
         .with_labels([span0.into()])
+        .with_oxc_code(&JSX_COMMA_EXPRESSION)
 }
 
 #[cold]
 pub fn line_terminator_before_using_declaration(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Line terminator not permitted before using declaration.")
         .with_labels([span0.into()])
+        .with_oxc_code(&LINE_TERMINATOR_BEFORE_USING_DECLARATION)
 }
 
 #[cold]
 pub fn await_in_using_declaration(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Await is not allowed in using declarations.").with_labels([span0.into()])
+    OxcDiagnostic::error("Await is not allowed in using declarations.")
+        .with_labels([span0.into()])
+        .with_oxc_code(&AWAIT_IN_USING_DECLARATION)
 }
 
 #[cold]
 pub fn invalid_identifier_in_using_declaration(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Using declarations may not have binding patterns.")
         .with_labels([span0.into()])
+        .with_oxc_code(&USING_DECLARATION_PATTERN)
 }
 
 #[cold]
@@ -382,6 +489,7 @@ pub fn await_using_declaration_not_allowed_in_for_in_statement(span0: Span) -> O
         "The left-hand side of a for...in statement cannot be an await using declaration.",
     )
     .with_labels([span0.into()])
+    .with_oxc_code(&AWAIT_USING_DECLARATION_IN_FOR_IN)
 }
 
 #[cold]
@@ -390,15 +498,393 @@ pub fn using_declaration_not_allowed_in_for_in_statement(span0: Span) -> OxcDiag
         "The left-hand side of a for...in statement cannot be an using declaration.",
     )
     .with_labels([span0.into()])
+    .with_oxc_code(&USING_DECLARATION_IN_FOR_IN)
 }
 
 #[cold]
 pub fn using_declarations_must_be_initialized(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Using declarations must have an initializer.").with_labels([span0.into()])
+    OxcDiagnostic::error("Using declarations must have an initializer.")
+        .with_labels([span0.into()])
+        .with_oxc_code(&UNINITIALIZED_USING_DECLARATION)
 }
 
 #[cold]
 pub fn static_constructor(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("TS1089: `static` modifier cannot appear on a constructor declaration.")
         .with_labels([span0.into()])
+        .with_oxc_code(&STATIC_CONSTRUCTOR)
+}
+
+#[cold]
+pub fn ambient_initializer(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("TS1039: Initializers are not allowed in ambient contexts.")
+        .with_labels([span0.into()])
+        .with_oxc_code(&AMBIENT_INITIALIZER)
+}
+
+#[cold]
+pub fn ambient_const_initializer(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("TS1254: A 'const' initializer in an ambient context must be a string or numeric literal or literal enum reference.")
+        .with_labels([span0.into()])
+        .with_oxc_code(&AMBIENT_CONST_INITIALIZER)
+}
+
+#[cold]
+pub fn ambient_parameter_initializer(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(
+        "TS2371: A parameter initializer is only allowed in a function or constructor implementation.",
+    )
+    .with_labels([span0.into()])
+    .with_oxc_code(&AMBIENT_PARAMETER_INITIALIZER)
+}
+
+#[cold]
+pub fn ambient_implementation(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("TS1183: An implementation cannot be declared in ambient contexts.")
+        .with_labels([span0.into()])
+        .with_oxc_code(&AMBIENT_IMPLEMENTATION)
+}
+
+#[cold]
+pub fn ambient_statement(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("TS1036: Statements are not allowed in ambient contexts.")
+        .with_labels([span0.into()])
+        .with_oxc_code(&AMBIENT_STATEMENT)
+}
+
+#[cold]
+pub fn declaration_file_top_level_modifier(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("TS1046: Top-level declarations in .d.ts files must start with either a 'declare' or 'export' modifier.")
+        .with_labels([span0.into()])
+        .with_oxc_code(&DECLARATION_FILE_TOP_LEVEL_MODIFIER)
+}
+
+oxc_diagnostics::diagnostic_codes! {
+    scope: "parse";
+
+    /// An identifier is declared twice in the same scope, and one of the declarations is lexical,
+    /// such as `let`, `const` or `class`, which can not be redeclared.
+    ///
+    /// ```js
+    /// let a = 1;
+    /// let a = 2;
+    /// ```
+    REDECLARATION = "redeclaration";
+
+    /// The source text is longer than 4 GiB, the largest size whose offsets fit in a span.
+    OVERLONG_SOURCE = "overlong_source";
+
+    /// The source text is not valid UTF-8. The invalid bytes are replaced with U+FFFD, the
+    /// replacement character, and the rest of the file is parsed.
+    INVALID_UTF8 = "invalid_utf8";
+
+    /// The source text encodes a surrogate code point, U+D800 to U+DFFF, in UTF-8, which is only
+    /// valid in pairs in UTF-16. It is replaced with U+FFFD.
+    LONE_SURROGATE = "lone_surrogate";
+
+    /// The file has a `@flow` pragma. Flow type annotations are not supported; strip them before
+    /// parsing.
+    FLOW = "flow";
+
+    /// The parser found a token which can not appear at this position, for example an operator
+    /// without its operands or a keyword where an expression is expected.
+    ///
+    /// ```js
+    /// let x = * 2;
+    /// ```
+    UNEXPECTED_TOKEN = "unexpected_token";
+
+    /// A specific token, such as a closing bracket, is required at this position but another one
+    /// was found.
+    ///
+    /// ```js
+    /// if (a { b() }
+    /// ```
+    EXPECT_TOKEN = "expect_token";
+
+    /// An escape sequence in a string literal or a template is malformed, for example `\x` which is
+    /// not followed by two hexadecimal digits.
+    INVALID_ESCAPE_SEQUENCE = "invalid_escape_sequence";
+
+    /// A `\u` escape sequence is malformed or encodes a code point which is not allowed at this
+    /// position, for example in an identifier.
+    UNICODE_ESCAPE_SEQUENCE = "unicode_escape_sequence";
+
+    /// A character which is not part of the JavaScript grammar appears outside of a string,
+    /// template, regular expression or comment.
+    INVALID_CHARACTER = "invalid_character";
+
+    /// A numeric literal is immediately followed by an identifier or a digit, which is not allowed.
+    ///
+    /// ```js
+    /// let x = 3in [];
+    /// ```
+    INVALID_NUMBER_END = "invalid_number_end";
+
+    /// A `/*` comment is not closed by `*/` before the end of the file.
+    UNTERMINATED_MULTI_LINE_COMMENT = "unterminated_multi_line_comment";
+
+    /// A string literal is not closed before the end of the line or of the file. Use a template
+    /// literal or `\n` for strings which span several lines.
+    UNTERMINATED_STRING = "unterminated_string";
+
+    /// A regular expression literal has a flag which is not one of `d`, `g`, `i`, `m`, `s`, `u`,
+    /// `v` and `y`.
+    REG_EXP_FLAG = "reg_exp_flag";
+
+    /// A flag of a regular expression literal is repeated, for example `/a/gg`.
+    REG_EXP_FLAG_TWICE = "reg_exp_flag_twice";
+
+    /// The file ends in the middle of a statement or an expression, usually because of a missing
+    /// closing bracket.
+    UNEXPECTED_END = "unexpected_end";
+
+    /// A regular expression literal is not closed by `/` before the end of the line.
+    UNTERMINATED_REG_EXP = "unterminated_reg_exp";
+
+    /// A numeric literal is malformed, for example a separator `_` at the end or next to another
+    /// separator, or a binary literal with a digit other than `0` and `1`.
+    INVALID_NUMBER = "invalid_number";
+
+    /// A keyword is written with a unicode escape sequence, such as `\u0069f` for `if`, which is
+    /// not allowed where the keyword is expected.
+    ESCAPED_KEYWORD = "escaped_keyword";
+
+    /// Two statements are not separated by a semicolon or a line break, so no semicolon can be
+    /// inserted automatically between them.
+    ///
+    /// ```js
+    /// let a = 1 let b = 2;
+    /// ```
+    AUTO_SEMICOLON_INSERTION = "auto_semicolon_insertion";
+
+    /// The `=>` of an arrow function must be on the same line as its parameters.
+    LINE_TERMINATOR_BEFORE_ARROW = "line_terminator_before_arrow";
+
+    /// A variable declaration which destructures its value must have an initializer, except in the
+    /// head of a `for...in` or `for...of` statement.
+    ///
+    /// ```js
+    /// let { a };
+    /// ```
+    INVALID_DESTRUCTURING_DECLARATION = "invalid_destructuring_declaration";
+
+    /// A `const` declaration must have an initializer, since the binding can not be assigned later.
+    ///
+    /// ```js
+    /// const a;
+    /// ```
+    MISSING_INITIALIZER_IN_CONST = "missing_initializer_in_const";
+
+    /// A `let`, `const` or `using` declaration is the body of a statement which is not a block,
+    /// such as `if (a) let b = 1`, where the binding would be useless. Wrap it in a block.
+    LEXICAL_DECLARATION_SINGLE_STATEMENT = "lexical_declaration_single_statement";
+
+    /// An async function is declared as the body of a statement, such as
+    /// `if (a) async function f() {}`. Declare it in a block instead.
+    ASYNC_FUNCTION_DECLARATION = "async_function_declaration";
+
+    /// A generator function is declared as the body of a statement, such as
+    /// `if (a) function* f() {}`. Declare it in a block instead.
+    GENERATOR_FUNCTION_DECLARATION = "generator_function_declaration";
+
+    /// `await` is used outside of an async function and outside of the top level of a module.
+    AWAIT_EXPRESSION = "await_expression";
+
+    /// `yield` is used outside of the body of a generator function.
+    YIELD_EXPRESSION = "yield_expression";
+
+    /// A class is declared as the body of a statement, such as `if (a) class A {}`. Declare it in a
+    /// block instead.
+    CLASS_DECLARATION = "class_declaration";
+
+    /// A rest element `...rest` in a destructuring pattern is followed by other elements.
+    ///
+    /// ```js
+    /// let [...rest, last] = array;
+    /// ```
+    BINDING_REST_ELEMENT_LAST = "binding_rest_element_last";
+
+    /// A rest parameter `...args` is followed by other parameters.
+    ///
+    /// ```js
+    /// function f(...args, last) {}
+    /// ```
+    REST_PARAMETER_LAST = "rest_parameter_last";
+
+    /// A spread element in an array or object which is the target of an assignment is followed by
+    /// other elements.
+    SPREAD_LAST_ELEMENT = "spread_last_element";
+
+    /// A rest element `...rest` in a destructuring pattern is followed by a trailing comma.
+    BINDING_REST_ELEMENT_TRAILING_COMMA = "binding_rest_element_trailing_comma";
+
+    /// A rest element of an object pattern must be an identifier, not a nested pattern.
+    ///
+    /// ```js
+    /// let { ...{ a } } = object;
+    /// ```
+    INVALID_BINDING_REST_ELEMENT = "invalid_binding_rest_element";
+
+    /// A rest parameter is marked as optional with `?`. It is always defined, as an empty array
+    /// when no arguments are left.
+    OPTIONAL_REST_PARAMETER = "optional_rest_parameter";
+
+    /// The left-hand side of an assignment, or the operand of `++` or `--`, is not a variable, a
+    /// property access or a destructuring pattern.
+    ///
+    /// ```js
+    /// a + b = c;
+    /// ```
+    INVALID_ASSIGNMENT = "invalid_assignment";
+
+    /// An optional chain is the callee of a `new` expression, such as `new a?.b()`.
+    NEW_OPTIONAL_CHAIN = "new_optional_chain";
+
+    /// The left-hand side of a `for...of` statement starts with `async`, which is ambiguous with an
+    /// async arrow function. Wrap it in parentheses.
+    FOR_LOOP_ASYNC_OF = "for_loop_async_of";
+
+    /// `for await` can only be used with `for...of` statements.
+    FOR_AWAIT = "for_await";
+
+    /// `import()` is used as the callee of a `new` expression, which is not allowed.
+    NEW_DYNAMIC_IMPORT = "new_dynamic_import";
+
+    /// A class element is named `#constructor`, which is reserved.
+    PRIVATE_NAME_CONSTRUCTOR = "private_name_constructor";
+
+    /// A class has a static property or method named `prototype`, which would replace the prototype
+    /// of the class.
+    STATIC_PROTOTYPE = "static_prototype";
+
+    /// The constructor of a class is declared as a getter or a setter.
+    CONSTRUCTOR_GETTER_SETTER = "constructor_getter_setter";
+
+    /// The constructor of a class is declared as an async method.
+    CONSTRUCTOR_ASYNC = "constructor_async";
+
+    /// `await` is used as an identifier inside of an async function or a module.
+    IDENTIFIER_ASYNC = "identifier_async";
+
+    /// `yield` is used as an identifier inside of a generator function.
+    IDENTIFIER_GENERATOR = "identifier_generator";
+
+    /// The constructor of a class is declared as a generator method.
+    CONSTRUCTOR_GENERATOR = "constructor_generator";
+
+    /// A class has a field named `constructor`, which is reserved for the constructor method.
+    FIELD_CONSTRUCTOR = "field_constructor";
+
+    /// A string which is used as the name of an export contains a lone surrogate, which is not a
+    /// valid module export name.
+    EXPORT_LONE_SURROGATE = "export_lone_surrogate";
+
+    /// A string literal is exported as a local binding, which is only allowed when re-exporting
+    /// from another module.
+    ///
+    /// ```js
+    /// export { "a" as b };
+    /// ```
+    EXPORT_NAMED_STRING = "export_named_string";
+
+    /// A reserved word is exported as a local binding, which is only allowed when re-exporting from
+    /// another module.
+    ///
+    /// ```js
+    /// export { default as a };
+    /// ```
+    EXPORT_RESERVED_WORD = "export_reserved_word";
+
+    /// An untagged template literal contains an invalid escape sequence, which is only allowed in
+    /// tagged templates, whose functions can read the raw text.
+    TEMPLATE_LITERAL = "template_literal";
+
+    /// A pair of parentheses contains no expression, and is not the parameter list of an arrow
+    /// function.
+    EMPTY_PARENTHESIZED_EXPRESSION = "empty_parenthesized_expression";
+
+    /// A line break appears where it is not allowed, for example between `throw` and its argument,
+    /// where a semicolon would be inserted.
+    ILLEGAL_NEWLINE = "illegal_newline";
+
+    /// A tagged template is used in an optional chain, such as ``a?.b`c` ``, which is not allowed.
+    OPTIONAL_CHAIN_TAGGED_TEMPLATE = "optional_chain_tagged_template";
+
+    /// A constructor declares a `this` parameter. The type of `this` in a constructor is the class
+    /// itself.
+    TS_CONSTRUCTOR_THIS_PARAMETER = "ts_constructor_this_parameter";
+
+    /// An arrow function declares a `this` parameter. Arrow functions use the `this` of the
+    /// enclosing function.
+    TS_ARROW_FUNCTION_THIS_PARAMETER = "ts_arrow_function_this_parameter";
+
+    /// `super` is used on its own, instead of being called, `super()`, or accessed, `super.a` or
+    /// `super[a]`.
+    UNEXPECTED_SUPER = "unexpected_super";
+
+    /// A function declaration has no name. Only the default export can be an anonymous function
+    /// declaration.
+    EXPECT_FUNCTION_NAME = "expect_function_name";
+
+    /// A `try` statement has neither a `catch` nor a `finally` clause.
+    EXPECT_CATCH_FINALLY = "expect_catch_finally";
+
+    /// A setter has a return type annotation. The result of a setter is ignored.
+    SETTER_RETURN_TYPE = "setter_return_type";
+
+    /// A `return` statement is used outside of a function.
+    RETURN_OUTSIDE_FUNCTION = "return_outside_function";
+
+    /// A JSX expression container contains a sequence expression, such as `{a, b}`. Use an array
+    /// for several children.
+    JSX_COMMA_EXPRESSION = "jsx_comma_expression";
+
+    /// A line break appears between `await` and `using` in an `await using` declaration.
+    LINE_TERMINATOR_BEFORE_USING_DECLARATION = "line_terminator_before_using_declaration";
+
+    /// An `await using` declaration is used outside of an async function and outside of the top
+    /// level of a module.
+    AWAIT_IN_USING_DECLARATION = "await_in_using_declaration";
+
+    /// A `using` declaration destructures its value. It must bind a single identifier, whose value
+    /// is disposed at the end of the block.
+    USING_DECLARATION_PATTERN = "using_declaration_pattern";
+
+    /// The left-hand side of a `for...in` statement is an `await using` declaration, which can only
+    /// be used in `for...of` statements.
+    AWAIT_USING_DECLARATION_IN_FOR_IN = "await_using_declaration_in_for_in";
+
+    /// The left-hand side of a `for...in` statement is a `using` declaration, which can only be
+    /// used in `for...of` statements.
+    USING_DECLARATION_IN_FOR_IN = "using_declaration_in_for_in";
+
+    /// A `using` declaration has no initializer, so it has nothing to dispose.
+    UNINITIALIZED_USING_DECLARATION = "uninitialized_using_declaration";
+
+    /// The constructor of a class is declared as `static`.
+    STATIC_CONSTRUCTOR = "static_constructor";
+
+    /// A variable or a property in an ambient context, such as a `declare` statement or a `.d.ts`
+    /// file, has an initializer. Ambient declarations only describe types.
+    AMBIENT_INITIALIZER = "ambient_initializer";
+
+    /// A `const` in an ambient context is initialized with an expression other than a string,
+    /// numeric or bigint literal, or a reference to a member of an enum.
+    AMBIENT_CONST_INITIALIZER = "ambient_const_initializer";
+
+    /// A parameter of a function without a body, such as an overload signature or a declared
+    /// function, has an initializer.
+    AMBIENT_PARAMETER_INITIALIZER = "ambient_parameter_initializer";
+
+    /// A function or a method in an ambient context has a body. Ambient declarations only describe
+    /// types.
+    AMBIENT_IMPLEMENTATION = "ambient_implementation";
+
+    /// A statement which is not a declaration appears in an ambient context, such as a
+    /// `declare namespace` or a `.d.ts` file.
+    AMBIENT_STATEMENT = "ambient_statement";
+
+    /// A top-level declaration of a `.d.ts` file does not start with `declare` or `export`.
+    DECLARATION_FILE_TOP_LEVEL_MODIFIER = "declaration_file_top_level_modifier";
 }
@@ -0,0 +1,92 @@
+//! Lossy decoding of source text which is not valid UTF-8.
+
+use oxc_allocator::Allocator;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+
+use crate::diagnostics;
+
+const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
+
+/// Decode `bytes` as UTF-8, replacing each invalid sequence with U+FFFD.
+///
+/// Returns the decoded text and an error for each run of invalid sequences. The spans of the
+/// errors are offsets into the decoded text, as are the spans of all other parser errors, and the
+/// messages contain the offsets into `bytes`.
+///
+/// Surrogates encoded as UTF-8 (also known as WTF-8 or CESU-8) are replaced by a single U+FFFD
+/// each, and are reported with their code point.
+pub(crate) fn decode_utf8_lossy<'a>(
+    allocator: &'a Allocator,
+    bytes: &'a [u8],
+) -> (&'a str, Vec<OxcDiagnostic>) {
+    let mut error = match std::str::from_utf8(bytes) {
+        Ok(text) => return (text, vec![]),
+        Err(error) => error,
+    };
+
+    let mut text = String::with_capacity(bytes.len() + 2);
+    let mut errors = vec![];
+    // Start of the last run of invalid sequences, in `bytes` and in `text`.
+    let mut invalid_run: Option<(usize, u32)> = None;
+    let mut offset = 0;
+    loop {
+        let valid_up_to = offset + error.valid_up_to();
+        if valid_up_to > offset {
+            if let Some((byte_offset, start)) = invalid_run.take() {
+                errors.push(diagnostics::invalid_utf8(byte_offset, span_to(start, &text)));
+            }
+            push_valid(&mut text, &bytes[offset..valid_up_to]);
+        }
+        offset = valid_up_to;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let start = text.len() as u32;
+        if let Some(code_point) = encoded_surrogate(&bytes[offset..]) {
+            if let Some((byte_offset, start)) = invalid_run.take() {
+                errors.push(diagnostics::invalid_utf8(byte_offset, span_to(start, &text)));
+            }
+            text.push(REPLACEMENT_CHARACTER);
+            errors.push(diagnostics::lone_surrogate(code_point, offset, span_to(start, &text)));
+            offset += 3;
+        } else {
+            invalid_run.get_or_insert((offset, start));
+            text.push(REPLACEMENT_CHARACTER);
+            offset += error.error_len().unwrap_or(bytes.len() - offset);
+        }
+
+        match std::str::from_utf8(&bytes[offset..]) {
+            Ok(rest) => {
+                if let Some((byte_offset, start)) = invalid_run.take() {
+                    errors.push(diagnostics::invalid_utf8(byte_offset, span_to(start, &text)));
+                }
+                text.push_str(rest);
+                break;
+            }
+            Err(next_error) => error = next_error,
+        }
+    }
+
+    (allocator.alloc_str(&text), errors)
+}
+
+#[allow(unsafe_code)]
+fn push_valid(text: &mut String, valid: &[u8]) {
+    // SAFETY: `valid` is the part of the input before `Utf8Error::valid_up_to`.
+    text.push_str(unsafe { std::str::from_utf8_unchecked(valid) });
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn span_to(start: u32, text: &str) -> Span {
+    Span::new(start, text.len() as u32)
+}
+
+/// The code point of a surrogate encoded as UTF-8 at the start of `bytes`, `ED A0..=BF 80..=BF`.
+fn encoded_surrogate(bytes: &[u8]) -> Option<u32> {
+    match bytes {
+        [0xED, b1 @ 0xA0..=0xBF, b2 @ 0x80..=0xBF, ..] => {
+            Some(0xD000 | (u32::from(b1 & 0x3F) << 6) | u32::from(b2 & 0x3F))
+        }
+        _ => None,
+    }
+}
@@ -417,8 +417,11 @@ impl<'a> ParserImpl<'a> {
         let value = if self.eat(Kind::Eq) {
             // let current_flags = self.scope.current_flags();
             // self.scope.set_current_flags(self.scope.current_flags());
-            let expr = self.parse_expression()?;
+            let expr = self.context(Context::empty(), Context::Ambient, Self::parse_expression)?;
             // self.scope.set_current_flags(current_flags);
+            if self.ctx.has_ambient() {
+                self.check_ambient_initializer(&expr, readonly && type_annotation.is_none());
+            }
             Some(expr)
         } else {
             None
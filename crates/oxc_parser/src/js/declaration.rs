@@ -3,7 +3,7 @@ use oxc_ast::ast::*;
 use oxc_diagnostics::Result;
 use oxc_span::{GetSpan, Span};
 
-use crate::{diagnostics, lexer::Kind, ParserImpl, StatementContext};
+use crate::{diagnostics, lexer::Kind, Context, ParserImpl, StatementContext};
 
 use super::{VariableDeclarationContext, VariableDeclarationParent};
 
@@ -101,8 +101,19 @@ impl<'a> ParserImpl<'a> {
             (self.ast.binding_pattern(binding_kind, None, false), false)
         };
 
-        let init =
-            self.eat(Kind::Eq).then(|| self.parse_assignment_expression_or_higher()).transpose()?;
+        let init = self
+            .eat(Kind::Eq)
+            .then(|| {
+                self.context(Context::empty(), Context::Ambient, |p| {
+                    p.parse_assignment_expression_or_higher()
+                })
+            })
+            .transpose()?;
+        if let Some(init) = init.as_ref().filter(|_| self.ctx.has_ambient()) {
+            let literal_type =
+                kind == VariableDeclarationKind::Const && id.type_annotation.is_none();
+            self.check_ambient_initializer(init, literal_type);
+        }
 
         if init.is_none() && decl_ctx.parent == VariableDeclarationParent::Statement {
             // LexicalBinding[In, Yield, Await] :
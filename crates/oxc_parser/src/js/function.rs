@@ -70,7 +70,16 @@ impl<'a> ParserImpl<'a> {
 
         let return_type = self.parse_ts_return_type_annotation()?;
 
-        let body = if self.at(Kind::LCurly) { Some(self.parse_function_body()?) } else { None };
+        let body = if self.at(Kind::LCurly) {
+            let body =
+                self.context(Context::empty(), Context::Ambient, Self::parse_function_body)?;
+            if ctx.has_ambient() {
+                self.error(diagnostics::ambient_implementation(body.span));
+            }
+            Some(body)
+        } else {
+            None
+        };
 
         self.ctx =
             self.ctx.and_in(ctx.has_in()).and_await(ctx.has_await()).and_yield(ctx.has_yield());
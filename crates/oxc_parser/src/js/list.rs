@@ -264,6 +264,11 @@ impl<'a> SeparatedList<'a> for FormalParameterList<'a> {
             }
             _ => {
                 let pattern = p.parse_binding_pattern_with_initializer()?;
+                if let BindingPatternKind::AssignmentPattern(pattern) = &pattern.kind {
+                    if p.ctx.has_ambient() {
+                        p.error(diagnostics::ambient_parameter_initializer(pattern.span));
+                    }
+                }
                 let decorators = p.consume_decorators();
                 let formal_parameter = p.ast.formal_parameter(
                     p.end_span(span),
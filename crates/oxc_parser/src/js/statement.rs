@@ -80,6 +80,9 @@ impl<'a> ParserImpl<'a> {
                         expecting_directives = false;
                     }
 
+                    if self.ctx.has_ambient() {
+                        self.check_ambient_statement(&stmt, is_top_level);
+                    }
                     statements.push(stmt);
                 }
             };
@@ -71,6 +71,7 @@ mod jsx;
 mod ts;
 
 mod diagnostics;
+mod encoding;
 
 // Expose lexer only in benchmarks
 #[cfg(not(feature = "benchmarking"))]
@@ -79,6 +80,7 @@ mod lexer;
 #[doc(hidden)]
 pub mod lexer;
 
+pub use crate::diagnostics::DIAGNOSTIC_CODES;
 pub use crate::lexer::Kind; // re-export for codegen
 
 use context::{Context, StatementContext};
@@ -146,13 +148,34 @@ pub struct Parser<'a> {
     source_text: &'a str,
     source_type: SourceType,
     options: ParserOptions,
+    /// Errors found while decoding the source text, see [`Parser::from_utf8_lossy`].
+    encoding_errors: Vec<OxcDiagnostic>,
 }
 
 impl<'a> Parser<'a> {
     /// Create a new parser
     pub fn new(allocator: &'a Allocator, source_text: &'a str, source_type: SourceType) -> Self {
         let options = ParserOptions::default();
-        Self { allocator, source_text, source_type, options }
+        Self { allocator, source_text, source_type, options, encoding_errors: vec![] }
+    }
+
+    /// Create a new parser for source text which may not be valid UTF-8.
+    ///
+    /// Each invalid UTF-8 sequence, including surrogates encoded as UTF-8, is replaced with
+    /// U+FFFD and reported as an error, and the decoded text is parsed as usual.
+    /// The spans of all errors are offsets into the decoded text, see [`Parser::source_text`].
+    pub fn from_utf8_lossy(
+        allocator: &'a Allocator,
+        source_bytes: &'a [u8],
+        source_type: SourceType,
+    ) -> Self {
+        let (source_text, encoding_errors) = encoding::decode_utf8_lossy(allocator, source_bytes);
+        Self { encoding_errors, ..Self::new(allocator, source_text, source_type) }
+    }
+
+    /// The source text which is parsed.
+    pub fn source_text(&self) -> &'a str {
+        self.source_text
     }
 
     /// Allow return outside of function
@@ -226,7 +249,13 @@ mod parser_parse {
                 self.options,
                 unique,
             );
-            parser.parse()
+            let mut ret = parser.parse();
+            if !self.encoding_errors.is_empty() {
+                let mut errors = self.encoding_errors;
+                errors.append(&mut ret.errors);
+                ret.errors = errors;
+            }
+            ret
         }
     }
 }
@@ -407,7 +436,7 @@ impl<'a> ParserImpl<'a> {
 #[cfg(test)]
 mod test {
     use oxc_ast::CommentKind;
-    use std::path::Path;
+    use std::{collections::HashSet, path::Path};
 
     use super::*;
 
@@ -436,6 +465,53 @@ mod test {
         assert_eq!(ret.errors.first().unwrap().to_string(), "Flow is not supported");
     }
 
+    #[test]
+    fn diagnostic_codes() {
+        let allocator = Allocator::default();
+        let source = "// @flow\nasdf adsf";
+        let ret = Parser::new(&allocator, source, SourceType::default()).parse();
+        let code = ret.errors.first().unwrap().oxc_code.unwrap();
+        assert_eq!(code.to_string(), "oxc(parse::flow)");
+        assert!(code.explanation().starts_with("The file has a `@flow` pragma."));
+
+        let names = DIAGNOSTIC_CODES.iter().map(|code| code.name).collect::<HashSet<_>>();
+        assert_eq!(names.len(), DIAGNOSTIC_CODES.len());
+        assert!(DIAGNOSTIC_CODES.iter().all(|code| code.scope == "parse"));
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = b"let a = '\xFF\xFE';\nlet b = '\xED\xA0\x80';\nlet c = '\xE2\x82';";
+        let parser = Parser::from_utf8_lossy(&allocator, source, source_type);
+        assert_eq!(
+            parser.source_text(),
+            "let a = '\u{FFFD}\u{FFFD}';\nlet b = '\u{FFFD}';\nlet c = '\u{FFFD}';"
+        );
+        let ret = parser.parse();
+        assert_eq!(ret.program.body.len(), 3);
+        let errors = ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                "Invalid UTF-8 sequence at byte offset 9",
+                "Lone surrogate U+D800 encoded at byte offset 23",
+                "Invalid UTF-8 sequence at byte offset 38",
+            ]
+        );
+        let spans = ret
+            .errors
+            .iter()
+            .flat_map(|error| error.labels.clone().unwrap_or_default())
+            .map(|label| (label.offset(), label.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(spans, [(9, 6), (27, 3), (42, 3)]);
+
+        let ret = Parser::from_utf8_lossy(&allocator, b"let a = 1", source_type).parse();
+        assert!(ret.errors.is_empty());
+    }
+
     #[test]
     fn ts_module_declaration() {
         let allocator = Allocator::default();
@@ -445,6 +521,44 @@ mod test {
         assert_eq!(ret.errors.len(), 0);
     }
 
+    #[test]
+    fn ambient_context() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(Path::new("index.d.ts")).unwrap();
+        let sources = [
+            ("declare const a = 1; export const b = 'b', c = -1n, d = E.A;", vec![]),
+            ("export declare class A { static readonly a = 1; m(): void }", vec![]),
+            ("declare namespace N { function f(): void; let a: number }", vec![]),
+            ("interface A {} type B = A; import C = require('c');", vec![]),
+            ("export let a = 1;", vec!["TS1039"]),
+            ("declare const a: number = 1;", vec!["TS1039"]),
+            ("export const a = f();", vec!["TS1254"]),
+            ("declare class A { a = () => {} }", vec!["TS1039"]),
+            ("declare class A { readonly a = {} }", vec!["TS1254"]),
+            ("declare function f(a = 1): void;", vec!["TS2371"]),
+            ("export function f() { let a = 1 }", vec!["TS1183"]),
+            ("declare class A { constructor() {} }", vec!["TS1183"]),
+            ("f(); declare namespace N { if (a) {} }", vec!["TS1036", "TS1036"]),
+            ("const a: number; function f(): void; namespace N {}", vec!["TS1046"; 3]),
+        ];
+        for (source, expected) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(!ret.panicked, "{source}");
+            let codes = ret
+                .errors
+                .iter()
+                .map(|error| error.to_string().split(':').next().unwrap().to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(codes, expected, "{source}");
+        }
+
+        // Only `declare` is ambient in other files.
+        let source_type = SourceType::from_path(Path::new("index.ts")).unwrap();
+        let source = "const a = f(); function g(a = 1) {} declare const b = f();";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert_eq!(ret.errors.len(), 1);
+    }
+
     #[test]
     fn directives() {
         let allocator = Allocator::default();
@@ -485,6 +599,57 @@ mod test {
         }
     }
 
+    #[test]
+    fn ast_diff() {
+        use oxc_ast::{AstChange, AstDiff, ContentEq};
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let parse = |source| Parser::new(&allocator, source, source_type).parse().program;
+
+        let old = parse("a + b; foo(1, `x${y}z`);");
+        let new = parse("a+b\n\nfoo( 1, `x${ y }z` )");
+        assert!(old.content_eq(&new));
+        assert!(AstDiff::new(&old, &new).is_empty());
+
+        let new = parse("a - b; bar(); foo(1, `x${y}z`);");
+        assert!(!old.content_eq(&new));
+        let diff = AstDiff::new(&old, &new);
+        let changes = diff.changes();
+        assert_eq!(changes.len(), 2, "{diff}");
+        assert!(matches!(
+            &changes[0],
+            AstChange::Changed { old, new, .. }
+                if old == "BinaryExpression(+)" && new == "BinaryExpression(-)"
+        ));
+        assert!(matches!(
+            &changes[1],
+            AstChange::Added { node, span, .. } if node == "ExpressionStatement" && span.start == 7
+        ));
+    }
+
+    #[test]
+    fn clone_in() {
+        use oxc_ast::{CloneCtx, CloneIn, ContentEq};
+        use oxc_span::{GetSpan, SPAN};
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let program = Parser::new(&allocator, "let [a, b] = f(`x${y}`, /re/g, 1n);", source_type)
+            .parse()
+            .program;
+
+        let new_allocator = Allocator::default();
+        let cloned = program.clone_in(&new_allocator);
+        assert!(program.content_eq(&cloned));
+        assert_eq!(cloned.body[0].span(), program.body[0].span());
+
+        let mut ctx = CloneCtx::new(&new_allocator).with_reset_spans(true);
+        let cloned = program.clone_in_with(&mut ctx);
+        assert!(program.content_eq(&cloned));
+        assert_eq!(cloned.body[0].span(), SPAN);
+    }
+
     // Source with length MAX_LEN + 1 fails to parse.
     // Skip this test on 32-bit systems as impossible to allocate a string longer than `isize::MAX`.
     #[cfg(target_pointer_width = "64")]
@@ -0,0 +1,94 @@
+//! Ambient contexts
+//!
+//! `.d.ts` files, and declarations with the `declare` modifier, only describe the shape of code
+//! which exists elsewhere. They can not contain code which runs: statements, function bodies and
+//! most initializers are reported, and the parser continues as usual.
+//!
+//! Code which is parsed inside them anyway, such as an initializer or a function body, is not in
+//! the ambient context, so it is only reported once.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_span::GetSpan;
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{diagnostics, ParserImpl};
+
+impl<'a> ParserImpl<'a> {
+    /// A statement of a `.d.ts` file or a `declare namespace`, which must be a declaration.
+    pub(crate) fn check_ambient_statement(&mut self, stmt: &Statement<'a>, is_top_level: bool) {
+        match stmt {
+            match_module_declaration!(Statement) => {}
+            match_declaration!(Statement) => {
+                if is_top_level && self.source_type.is_typescript_definition() {
+                    self.check_declaration_file_top_level(stmt.to_declaration());
+                }
+            }
+            _ => self.error(diagnostics::ambient_statement(stmt.span())),
+        }
+    }
+
+    /// `function f() {}` at the top level of a `.d.ts` file, which declares nothing without
+    /// `declare` or `export`. Types and interfaces do not need either.
+    fn check_declaration_file_top_level(&mut self, decl: &Declaration<'a>) {
+        let needs_modifier = matches!(
+            decl,
+            Declaration::VariableDeclaration(_)
+                | Declaration::FunctionDeclaration(_)
+                | Declaration::ClassDeclaration(_)
+                | Declaration::TSEnumDeclaration(_)
+                | Declaration::TSModuleDeclaration(_)
+        );
+        let declare =
+            decl.modifiers().is_some_and(|modifiers| modifiers.contains(ModifierKind::Declare));
+        if needs_modifier && !declare {
+            self.error(diagnostics::declaration_file_top_level_modifier(decl.span()));
+        }
+    }
+
+    /// The initializer of a variable or a class property in an ambient context.
+    ///
+    /// A `const` variable or a `readonly` property without a type annotation can be initialized
+    /// with a literal, which is its type, e.g. `declare const version = "1.0"`.
+    pub(crate) fn check_ambient_initializer(&mut self, init: &Expression<'a>, literal_type: bool) {
+        if !literal_type {
+            self.error(diagnostics::ambient_initializer(init.span()));
+        } else if !is_literal_initializer(init) {
+            self.error(diagnostics::ambient_const_initializer(init.span()));
+        }
+    }
+}
+
+/// A string or numeric literal, or a literal enum reference.
+fn is_literal_initializer(expr: &Expression) -> bool {
+    match expr {
+        Expression::StringLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigintLiteral(_)
+        | Expression::BooleanLiteral(_) => true,
+        Expression::TemplateLiteral(lit) => lit.expressions.is_empty(),
+        Expression::UnaryExpression(unary_expr) => {
+            unary_expr.operator == UnaryOperator::UnaryNegation
+                && matches!(
+                    unary_expr.argument,
+                    Expression::NumericLiteral(_) | Expression::BigintLiteral(_)
+                )
+        }
+        // `E.A` and `E["A"]`
+        Expression::StaticMemberExpression(member_expr) => is_entity_name(&member_expr.object),
+        Expression::ComputedMemberExpression(member_expr) => {
+            matches!(member_expr.expression, Expression::StringLiteral(_))
+                && is_entity_name(&member_expr.object)
+        }
+        _ => false,
+    }
+}
+
+/// `A` or `A.B`
+fn is_entity_name(expr: &Expression) -> bool {
+    match expr {
+        Expression::Identifier(_) => true,
+        Expression::StaticMemberExpression(member_expr) => is_entity_name(&member_expr.object),
+        _ => false,
+    }
+}
@@ -1,3 +1,4 @@
+mod ambient;
 mod declaration;
 mod list;
 mod statement;
@@ -231,7 +231,11 @@ impl<'a> ParserImpl<'a> {
     }
 
     fn parse_ts_module_item(&mut self) -> Result<Statement<'a>> {
-        self.parse_statement_list_item(StatementContext::StatementList)
+        let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+        if self.ctx.has_ambient() {
+            self.check_ambient_statement(&stmt, /* is_top_level */ false);
+        }
+        Ok(stmt)
     }
 
     pub(crate) fn parse_ts_namespace_or_module_declaration_body(
@@ -0,0 +1,138 @@
+//! The JSON AST matches `estree_schema.json` of `oxc_ast`, which is generated by
+//! `crates/oxc_traverse/scripts/build.mjs` for tools in other languages.
+
+use std::collections::BTreeSet;
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use serde_json::Value;
+
+const SCHEMA: &str = include_str!("../../oxc_ast/estree_schema.json");
+
+const SOURCES: &[(&str, &str)] = &[
+    (
+        "script.js",
+        r#"
+        "use strict";
+        var { a, b: [c, , ...d] = [], ...e } = f;
+        function g(h, i = 1, ...j) { return /x/gu.test(`${h}!`) }
+        [a, ...b] = c;
+        ({ d, e: f = 1, ...g } = h);
+        label: for (const p of q) { if (r) continue label; else break }
+        x ??= y?.z(...w);
+        switch (a) { case 1: throw new Error(); default: }
+        try { debugger } catch ({ message }) {} finally {}
+        "#,
+    ),
+    (
+        "module.mjs",
+        r#"
+        import x, { y as z } from "m";
+        import * as ns from "n";
+        export * as all from "o";
+        export const [first] = import.meta.list;
+        export default class K extends L {
+            #m = 1;
+            static { this.n = 0n }
+            get o() { return this.#m }
+            async *p() { yield await 1 }
+        }
+        "#,
+    ),
+    (
+        "types.ts",
+        r#"
+        interface A<T> extends B { a?: string; [k: string]: number; new (): A<T> }
+        type C = A<number> | keyof D | `e${string}` | [f: 1, ...g: 2[]];
+        enum E { F = 1, G }
+        declare module "h" { export function i(this: Window, ...l: string[]): asserts l is string[]; }
+        abstract class H<T extends object = {}> implements I {
+            constructor(private readonly i: number) {}
+            abstract j(): void;
+            declare k?: T;
+        }
+        let m = n as unknown satisfies O, p = <Q>r!, s = t<U>;
+        "#,
+    ),
+    (
+        "component.tsx",
+        r#"
+        const a = <div className="b" {...c}>{d}<></><e.f g /></div>;
+        "#,
+    ),
+];
+
+/// The node `type`s of the schema, with the keys of each of their variants apart from `type`.
+fn node_keys(schema: &Value) -> Vec<(String, BTreeSet<String>)> {
+    let field_names = |entry: &Value| -> BTreeSet<String> {
+        entry["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| field["name"].as_str().unwrap().to_string())
+            .collect()
+    };
+    // A node may be flattened into an object like `BindingPattern`, which adds its own keys.
+    let flattening: Vec<BTreeSet<String>> = ["nodes", "objects"]
+        .iter()
+        .flat_map(|section| schema[section].as_object().unwrap().values())
+        .filter(|entry| entry.get("flatten").is_some())
+        .map(field_names)
+        .collect();
+
+    let mut keys = vec![];
+    for entry in schema["nodes"].as_object().unwrap().values() {
+        let tags: Vec<&str> = match &entry["type"] {
+            Value::Array(tags) => tags.iter().map(|tag| tag.as_str().unwrap()).collect(),
+            tag => vec![tag.as_str().unwrap()],
+        };
+        let fields = field_names(entry);
+        for tag in tags {
+            keys.push((tag.to_string(), fields.clone()));
+            for extra in &flattening {
+                keys.push((tag.to_string(), fields.union(extra).cloned().collect()));
+            }
+        }
+    }
+    keys
+}
+
+fn check(value: &Value, keys: &[(String, BTreeSet<String>)], path: &str) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::String(tag)) = object.get("type") {
+                let object_keys: BTreeSet<String> =
+                    object.keys().filter(|key| *key != "type").cloned().collect();
+                assert!(
+                    keys.iter()
+                        .any(|(node_tag, node_keys)| node_tag == tag && *node_keys == object_keys),
+                    "`{tag}` with keys {object_keys:?} is not in the schema, in {path}"
+                );
+            }
+            for value in object.values() {
+                check(value, keys, path);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                check(value, keys, path);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn estree_schema() {
+    let schema: Value = serde_json::from_str(SCHEMA).unwrap();
+    let keys = node_keys(&schema);
+    for (path, source_text) in SOURCES {
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(path).unwrap();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        assert!(ret.errors.is_empty(), "{path}: {:?}", ret.errors);
+        let json: Value = serde_json::from_str(&ret.program.to_json()).unwrap();
+        check(&json, &keys, path);
+    }
+}
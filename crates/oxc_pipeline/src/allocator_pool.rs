@@ -0,0 +1,56 @@
+use std::{
+    mem,
+    ops::Deref,
+    sync::{Mutex, PoisonError},
+};
+
+use oxc_allocator::Allocator;
+
+/// A pool of [Allocator]s shared by the worker threads.
+///
+/// Allocators are reset and returned to the pool once a file is done, so that the arena grown for
+/// a large file is reused by the next files instead of being freed and allocated again.
+#[derive(Default)]
+pub struct AllocatorPool {
+    allocators: Mutex<Vec<Allocator>>,
+}
+
+impl AllocatorPool {
+    /// Create a pool with `size` allocators, usually one per worker thread.
+    pub fn new(size: usize) -> Self {
+        Self { allocators: Mutex::new((0..size).map(|_| Allocator::default()).collect()) }
+    }
+
+    /// Take an allocator from the pool, or create one if the pool is empty.
+    pub fn get(&self) -> PooledAllocator<'_> {
+        let allocator = self
+            .allocators
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop()
+            .unwrap_or_default();
+        PooledAllocator { pool: self, allocator }
+    }
+}
+
+/// An [Allocator] which is reset and returned to its [AllocatorPool] when dropped.
+pub struct PooledAllocator<'p> {
+    pool: &'p AllocatorPool,
+    allocator: Allocator,
+}
+
+impl Deref for PooledAllocator<'_> {
+    type Target = Allocator;
+
+    fn deref(&self) -> &Self::Target {
+        &self.allocator
+    }
+}
+
+impl Drop for PooledAllocator<'_> {
+    fn drop(&mut self) {
+        let mut allocator = mem::take(&mut self.allocator);
+        allocator.reset();
+        self.pool.allocators.lock().unwrap_or_else(PoisonError::into_inner).push(allocator);
+    }
+}
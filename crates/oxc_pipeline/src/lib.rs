@@ -0,0 +1,289 @@
+//! Oxc Pipeline
+//!
+//! Runs parse → semantic → transform → minify → codegen on many files in parallel.
+//!
+//! * Files are scheduled on a dedicated rayon thread pool, which balances the work between threads
+//!   by work stealing.
+//! * Allocators are reused across files through an [AllocatorPool].
+//! * Outputs are handed to the caller through a bounded channel, workers wait when the caller is
+//!   slower than them instead of keeping every output in memory.
+
+mod allocator_pool;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{mpsc, Arc},
+    thread,
+    time::Instant,
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
+use oxc_codegen::{Codegen, CodegenOptions, CodegenReturn};
+use oxc_diagnostics::{Error, NamedSource, OxcDiagnostic};
+use oxc_minifier::{Minifier, MinifierOptions, PassSnapshot};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_sourcemap::SourceMap;
+use oxc_span::SourceType;
+use oxc_transformer::{TransformOptions, Transformer};
+use rayon::prelude::*;
+
+pub use crate::allocator_pool::{AllocatorPool, PooledAllocator};
+
+#[derive(Debug, Clone)]
+pub struct PipelineOptions {
+    /// Run the transformer with these options.
+    ///
+    /// Default `None`
+    pub transform: Option<TransformOptions>,
+
+    /// Run the minifier with these options, and print without whitespace.
+    ///
+    /// Default `None`
+    pub minify: Option<MinifierOptions>,
+
+    pub codegen: CodegenOptions,
+
+    /// Number of worker threads.
+    ///
+    /// Default `None`, the number of logical CPUs.
+    pub threads: Option<usize>,
+
+    /// Number of outputs waiting to be handled by the caller before the workers are paused.
+    ///
+    /// Default `64`
+    pub max_pending_outputs: usize,
+
+    /// Print the program after parsing, after transforming, and after each pass of the minifier
+    /// into [FileOutput::snapshots], to find the stage which produced incorrect or larger output.
+    ///
+    /// Default `false`
+    pub snapshots: bool,
+}
+
+impl Default for PipelineOptions {
+    fn default() -> Self {
+        Self {
+            transform: None,
+            minify: None,
+            codegen: CodegenOptions::default(),
+            threads: None,
+            max_pending_outputs: 64,
+            snapshots: false,
+        }
+    }
+}
+
+/// The result of running the pipeline on a file.
+pub struct FileOutput {
+    pub path: PathBuf,
+
+    /// Printed code, `None` if the file cannot be read, parsed or transformed.
+    pub source_text: Option<String>,
+
+    pub source_map: Option<SourceMap>,
+
+    /// Errors of all stages, with the source code attached.
+    pub errors: Vec<Error>,
+
+    /// The program after each stage, empty unless enabled by [PipelineOptions::snapshots]:
+    /// `"parse"`, `"transform"` and the passes of the minifier. The transformer is a single stage,
+    /// as its presets run in one traversal of the program.
+    pub snapshots: Vec<PassSnapshot>,
+}
+
+/// Reported after each file.
+#[derive(Debug, Clone, Copy)]
+pub struct Progress<'a> {
+    /// The file which is done.
+    pub path: &'a Path,
+    /// Number of files done, including this one.
+    pub completed: usize,
+    pub total: usize,
+}
+
+type ProgressCallback = Box<dyn Fn(Progress<'_>) + Send + Sync>;
+
+pub struct Pipeline {
+    options: PipelineOptions,
+    thread_pool: rayon::ThreadPool,
+    allocators: AllocatorPool,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl Pipeline {
+    /// # Errors
+    ///
+    /// * Returns `Err` if the thread pool cannot be created.
+    pub fn new(options: PipelineOptions) -> Result<Self, rayon::ThreadPoolBuildError> {
+        let mut builder =
+            rayon::ThreadPoolBuilder::new().thread_name(|index| format!("oxc-pipeline-{index}"));
+        if let Some(threads) = options.threads {
+            builder = builder.num_threads(threads);
+        }
+        let thread_pool = builder.build()?;
+        let allocators = AllocatorPool::new(thread_pool.current_num_threads());
+        Ok(Self { options, thread_pool, allocators, on_progress: None })
+    }
+
+    /// Call `on_progress` on the calling thread of [Pipeline::run] after each file.
+    #[must_use]
+    pub fn with_progress<F>(mut self, on_progress: F) -> Self
+    where
+        F: Fn(Progress<'_>) + Send + Sync + 'static,
+    {
+        self.on_progress = Some(Box::new(on_progress));
+        self
+    }
+
+    /// Run the pipeline on `paths`.
+    ///
+    /// `on_output` is called on the calling thread for each file, in the order files complete.
+    pub fn run<F>(&self, paths: &[PathBuf], mut on_output: F)
+    where
+        F: FnMut(FileOutput),
+    {
+        let total = paths.len();
+        let (sender, receiver) = mpsc::sync_channel(self.options.max_pending_outputs);
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                self.thread_pool.install(|| {
+                    paths.par_iter().for_each_with(sender, |sender, path| {
+                        // The receiver only hangs up if `on_output` panics.
+                        let _ = sender.send(self.run_file(path));
+                    });
+                });
+            });
+            for (index, output) in receiver.iter().enumerate() {
+                if let Some(on_progress) = &self.on_progress {
+                    on_progress(Progress { path: &output.path, completed: index + 1, total });
+                }
+                on_output(output);
+            }
+        });
+    }
+
+    /// Run the pipeline on a single file, on the current thread.
+    pub fn run_file(&self, path: &Path) -> FileOutput {
+        let allocator = self.allocators.get();
+        let source_text = match fs::read_to_string(path) {
+            Ok(source_text) => source_text,
+            Err(error) => {
+                let error =
+                    OxcDiagnostic::error(format!("Failed to read {}: {error}", path.display()));
+                return FileOutput::failed(path, vec![error.into()], vec![]);
+            }
+        };
+        let source_type = match SourceType::from_path(path) {
+            Ok(source_type) => source_type,
+            Err(error) => {
+                let errors = vec![OxcDiagnostic::error(error.0).into()];
+                return FileOutput::failed(path, errors, vec![]);
+            }
+        };
+        let mut snapshots = vec![];
+        let result = self.run_source(&allocator, path, &source_text, source_type, &mut snapshots);
+        let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text));
+        let (ret, errors) = match result {
+            Ok((ret, errors)) => (Some(ret), errors),
+            Err(errors) => (None, errors),
+        };
+        let errors =
+            errors.into_iter().map(|error| error.with_source_code(Arc::clone(&source))).collect();
+        match ret {
+            Some(CodegenReturn { source_text, source_map, .. }) => FileOutput {
+                path: path.to_path_buf(),
+                source_text: Some(source_text),
+                source_map,
+                errors,
+                snapshots,
+            },
+            None => FileOutput::failed(path, errors, snapshots),
+        }
+    }
+
+    /// Returns the printed code and the errors which do not prevent printing it.
+    /// The snapshots of the stages which ran are pushed to `snapshots` if enabled.
+    fn run_source(
+        &self,
+        allocator: &Allocator,
+        path: &Path,
+        source_text: &str,
+        source_type: SourceType,
+        snapshots: &mut Vec<PassSnapshot>,
+    ) -> Result<(CodegenReturn, Vec<Error>), Vec<Error>> {
+        let start = Instant::now();
+        let ret = Parser::new(allocator, source_text, source_type).parse();
+        if !ret.errors.is_empty() {
+            return Err(ret.errors.into_iter().map(Error::from).collect());
+        }
+        let program = allocator.alloc(ret.program);
+        if self.options.snapshots {
+            snapshots.push(snapshot("parse", start, program));
+        }
+
+        let errors = SemanticBuilder::new(source_text, source_type)
+            .with_check_syntax_error(true)
+            .build(program)
+            .errors;
+        if !errors.is_empty() {
+            return Err(errors.into_iter().map(Error::from).collect());
+        }
+
+        if let Some(options) = &self.options.transform {
+            let start = Instant::now();
+            Transformer::new(
+                allocator,
+                path,
+                source_type,
+                source_text,
+                &ret.trivias,
+                options.clone(),
+            )
+            .build(program)?;
+            if self.options.snapshots {
+                snapshots.push(snapshot("transform", start, program));
+            }
+        }
+
+        if let Some(options) = self.options.minify {
+            let mut minifier = Minifier::new(options);
+            if self.options.snapshots {
+                minifier = minifier.with_snapshots(print_snapshot);
+            }
+            snapshots.extend(minifier.build(allocator, program).snapshots);
+        }
+
+        let source_name = path.to_string_lossy();
+        let codegen_options = self.options.codegen.clone();
+        let mut ret = if self.options.minify.is_some() {
+            Codegen::<true>::new(&source_name, source_text, codegen_options).build(program)
+        } else {
+            Codegen::<false>::new(&source_name, source_text, codegen_options).build(program)
+        };
+        let errors = std::mem::take(&mut ret.errors).into_iter().map(Error::from).collect();
+        Ok((ret, errors))
+    }
+}
+
+impl FileOutput {
+    fn failed(path: &Path, errors: Vec<Error>, snapshots: Vec<PassSnapshot>) -> Self {
+        Self { path: path.to_path_buf(), source_text: None, source_map: None, errors, snapshots }
+    }
+}
+
+/// The snapshot of a stage which started at `start`.
+fn snapshot(stage: &'static str, start: Instant, program: &Program) -> PassSnapshot {
+    let duration = start.elapsed();
+    PassSnapshot { pass: stage, duration, source_text: print_snapshot(program) }
+}
+
+fn print_snapshot(program: &Program) -> String {
+    let options = CodegenOptions {
+        enable_typescript: program.source_type.is_typescript(),
+        ..CodegenOptions::default()
+    };
+    Codegen::<false>::new("", "", options).build(program).source_text
+}
@@ -0,0 +1,115 @@
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use oxc_minifier::MinifierOptions;
+use oxc_pipeline::{FileOutput, Pipeline, PipelineOptions};
+use oxc_transformer::TransformOptions;
+
+fn write_files(dir: &tempfile::TempDir, files: &[(&str, &str)]) -> Vec<PathBuf> {
+    files
+        .iter()
+        .map(|(name, source_text)| {
+            let path = dir.path().join(name);
+            fs::write(&path, source_text).unwrap();
+            path
+        })
+        .collect()
+}
+
+fn run(options: PipelineOptions, paths: &[PathBuf]) -> Vec<FileOutput> {
+    let pipeline = Pipeline::new(options).unwrap();
+    let mut outputs = vec![];
+    pipeline.run(paths, |output| outputs.push(output));
+    outputs.sort_by(|a, b| a.path.cmp(&b.path));
+    outputs
+}
+
+#[test]
+fn codegen() {
+    let dir = tempfile::tempdir().unwrap();
+    let paths = write_files(&dir, &[("a.js", "let a  =  1"), ("b.js", "let b = ("), ("c.txt", "")]);
+    let outputs = run(PipelineOptions::default(), &paths);
+    assert_eq!(outputs.len(), 3);
+    assert_eq!(outputs[0].source_text.as_deref(), Some("let a = 1;\n"));
+    assert!(outputs[0].errors.is_empty());
+    assert_eq!(outputs[1].source_text, None);
+    assert_eq!(outputs[1].errors.len(), 1);
+    assert_eq!(outputs[2].source_text, None);
+    assert_eq!(outputs[2].errors.len(), 1);
+}
+
+#[test]
+fn transform_and_minify() {
+    let dir = tempfile::tempdir().unwrap();
+    let paths = write_files(&dir, &[("a.ts", "let a: number = 1 + 2;\nexport { a };")]);
+    let options = PipelineOptions {
+        transform: Some(TransformOptions::default()),
+        minify: Some(MinifierOptions { mangle: false, ..MinifierOptions::default() }),
+        ..PipelineOptions::default()
+    };
+    let outputs = run(options, &paths);
+    let source_text = outputs[0].source_text.as_deref().unwrap();
+    // Types are stripped, and whitespace is removed
+    assert!(source_text.starts_with("let a=3;"), "{source_text}");
+    assert!(!source_text.contains("number"), "{source_text}");
+}
+
+#[test]
+fn snapshots() {
+    let dir = tempfile::tempdir().unwrap();
+    let paths = write_files(&dir, &[("a.ts", "let a: number = 1 + 2;\nexport { a };")]);
+    let options = PipelineOptions {
+        transform: Some(TransformOptions::default()),
+        minify: Some(MinifierOptions { mangle: false, ..MinifierOptions::default() }),
+        snapshots: true,
+        ..PipelineOptions::default()
+    };
+    let outputs = run(options, &paths);
+    let snapshots = &outputs[0].snapshots;
+    let passes = snapshots.iter().map(|snapshot| snapshot.pass).collect::<Vec<_>>();
+    assert_eq!(&passes[..3], ["parse", "transform", "prepass"]);
+    assert!(snapshots[0].source_text.starts_with("let a: number = 1 + 2;"));
+    assert!(snapshots[1].source_text.starts_with("let a = 1 + 2;"));
+    let peephole = snapshots.iter().find(|snapshot| snapshot.pass == "peephole").unwrap();
+    assert!(peephole.source_text.starts_with("let a = 3;"), "{}", peephole.source_text);
+
+    // Disabled by default
+    let outputs = run(PipelineOptions::default(), &paths);
+    assert!(outputs[0].snapshots.is_empty());
+}
+
+#[test]
+fn progress() {
+    let dir = tempfile::tempdir().unwrap();
+    let files = (0..20).map(|i| (format!("{i}.js"), format!("f({i})"))).collect::<Vec<_>>();
+    let files = files.iter().map(|(name, text)| (name.as_str(), text.as_str())).collect::<Vec<_>>();
+    let paths = write_files(&dir, &files);
+
+    let completed = Arc::new(AtomicUsize::new(0));
+    let pipeline = Pipeline::new(PipelineOptions {
+        threads: Some(4),
+        max_pending_outputs: 1,
+        ..PipelineOptions::default()
+    })
+    .unwrap()
+    .with_progress({
+        let completed = Arc::clone(&completed);
+        move |progress| {
+            assert_eq!(progress.total, 20);
+            assert_eq!(completed.fetch_add(1, Ordering::SeqCst) + 1, progress.completed);
+        }
+    });
+    let mut count = 0;
+    pipeline.run(&paths, |output| {
+        assert!(output.errors.is_empty());
+        count += 1;
+    });
+    assert_eq!(count, 20);
+    assert_eq!(completed.load(Ordering::SeqCst), 20);
+}
@@ -0,0 +1,909 @@
+//! Declaration bundling
+//!
+//! Bundles a `.d.ts` file and the declaration files it imports into a single `.d.ts` file, like
+//! `rollup-plugin-dts`:
+//!
+//! * Imports of bundled files are removed, and their references use the names of the imported
+//!   declarations. The namespace of a bundled file, `import * as ns`, is declared as
+//!   `declare namespace ns { export { ... } }`.
+//! * Declarations with the same name in different files are renamed, e.g. `Options$1`. The names
+//!   of the entry file are kept.
+//! * Imports of other modules, such as packages, are merged and hoisted, and so are
+//!   `/// <reference />` directives.
+//! * The exports of the entry file, including the ones it re-exports from the bundled files, are
+//!   exported at the end of the bundle, the other `export` keywords are removed.
+//! * `declare module "name"` and `declare global` blocks are kept.
+//!
+//! A module is bundled when it resolves to a `.d.ts` file outside of `node_modules`, the other
+//! modules are external.
+//!
+//! The module records of [ProjectIndex](crate::ProjectIndex) leave out type-only imports and
+//! exports, so the declaration files are linked by their own import and export statements.
+
+use std::{
+    fmt::Write,
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use oxc_allocator::Allocator;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_index::IndexVec;
+use oxc_parser::Parser;
+use oxc_resolver::Resolver;
+use oxc_semantic::{Reference, ScopeTree, SemanticBuilder, SymbolId, SymbolTable};
+use oxc_span::{CompactStr, GetSpan, SourceType, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::ModuleId;
+
+/// Bundles declaration files, see the [module docs](self).
+pub struct DeclarationBundler {
+    resolver: Resolver,
+}
+
+/// A `.d.ts` file which contains the declarations of several files.
+#[derive(Debug)]
+pub struct DeclarationBundle {
+    pub source_text: String,
+    /// The declarations which are renamed because another file declares the same name: the file,
+    /// the name and the new name.
+    pub renamed: Vec<(PathBuf, CompactStr, CompactStr)>,
+}
+
+/// What is imported from a module, or exported from another module.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Imported {
+    Name(CompactStr),
+    Default,
+    Namespace,
+}
+
+impl Imported {
+    fn new(name: &str) -> Self {
+        if name == "default" {
+            Self::Default
+        } else {
+            Self::Name(CompactStr::from(name))
+        }
+    }
+
+    fn export_name(&self) -> Option<&str> {
+        match self {
+            Self::Name(name) => Some(name),
+            Self::Default => Some("default"),
+            Self::Namespace => None,
+        }
+    }
+}
+
+/// What a name refers to in the bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Target {
+    /// A top level declaration of a bundled file.
+    Declaration(ModuleId, CompactStr),
+    /// The namespace of a bundled file.
+    Namespace(ModuleId),
+    /// An import of an external module.
+    External(CompactStr, Imported),
+}
+
+struct ImportBinding {
+    local: CompactStr,
+    specifier: CompactStr,
+    imported: Imported,
+}
+
+struct DeclarationFile {
+    path: PathBuf,
+    source_text: String,
+    symbols: SymbolTable,
+    scopes: ScopeTree,
+    imports: Vec<ImportBinding>,
+    /// `import "specifier"`
+    side_effect_imports: Vec<CompactStr>,
+    /// Export names and the local names they export.
+    local_exports: Vec<(CompactStr, CompactStr)>,
+    /// `export { imported as name } from "specifier"` and `export * as name from "specifier"`.
+    indirect_exports: Vec<(CompactStr, CompactStr, Imported)>,
+    /// `export * from "specifier"`
+    star_exports: Vec<CompactStr>,
+    /// `export as namespace Name`, which is only kept for the entry file.
+    namespace_export: Option<Span>,
+    /// `/// <reference />` directives.
+    references: Vec<CompactStr>,
+    /// The identifiers of the top level `namespace` declarations, whose symbols span the whole
+    /// declaration. `declare module "name"` and `declare global` have none, and keep their names.
+    module_declarations: FxHashMap<Span, Option<Span>>,
+    /// The edits which remove the import and export syntax.
+    edits: Vec<(Span, CompactStr)>,
+    /// The bundled files which the specifiers resolve to.
+    resolved: FxHashMap<CompactStr, ModuleId>,
+}
+
+impl DeclarationBundler {
+    pub fn new(resolver: Resolver) -> Self {
+        Self { resolver }
+    }
+
+    /// Bundle the declaration file at `entry` and the declaration files it imports.
+    ///
+    /// # Errors
+    ///
+    /// * A file cannot be read, or has syntax errors.
+    /// * A file uses `export =`, or has an anonymous default export.
+    /// * A file imports a name which the bundled file does not export.
+    pub fn bundle(&self, entry: &Path) -> Result<DeclarationBundle, Vec<OxcDiagnostic>> {
+        let mut files = IndexVec::<ModuleId, DeclarationFile>::new();
+        let mut module_ids = FxHashMap::default();
+        let entry_path = fs::canonicalize(entry).map_err(|error| read_error(entry, &error))?;
+        let entry_id = files.push(DeclarationFile::read(entry_path.clone())?);
+        module_ids.insert(entry_path, entry_id);
+
+        let mut pending = vec![entry_id];
+        while let Some(module_id) = pending.pop() {
+            let dir = files[module_id].path.parent().unwrap_or(Path::new("")).to_path_buf();
+            for specifier in files[module_id].specifiers() {
+                let Ok(resolution) = self.resolver.resolve(&dir, &specifier) else { continue };
+                let path = resolution.path().to_path_buf();
+                if !is_bundled(&path) {
+                    continue;
+                }
+                let target_id = if let Some(&target_id) = module_ids.get(&path) {
+                    target_id
+                } else {
+                    let target_id = files.push(DeclarationFile::read(path.clone())?);
+                    module_ids.insert(path, target_id);
+                    pending.push(target_id);
+                    target_id
+                };
+                files[module_id].resolved.insert(specifier, target_id);
+            }
+        }
+
+        let bundle = Bundle::new(files, entry_id);
+        bundle.check_imports()?;
+        Ok(bundle.render())
+    }
+}
+
+fn read_error(path: &Path, error: &std::io::Error) -> Vec<OxcDiagnostic> {
+    vec![OxcDiagnostic::error(format!("Failed to read {}: {error}", path.display()))]
+}
+
+fn is_bundled(path: &Path) -> bool {
+    path.to_string_lossy().ends_with(".d.ts")
+        && !path
+            .components()
+            .any(|component| component == Component::Normal("node_modules".as_ref()))
+}
+
+impl DeclarationFile {
+    fn read(path: PathBuf) -> Result<Self, Vec<OxcDiagnostic>> {
+        let source_text = fs::read_to_string(&path).map_err(|error| read_error(&path, &error))?;
+        let source_type =
+            SourceType::from_path(&path).map_err(|error| vec![OxcDiagnostic::error(error.0)])?;
+        // The AST borrows the source text, which is moved into the file afterwards.
+        let mut file = {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, &source_text, source_type).parse();
+            if !ret.errors.is_empty() {
+                return Err(ret.errors);
+            }
+            let program = allocator.alloc(ret.program);
+            let ret = SemanticBuilder::new(&source_text, source_type).build(program);
+            if !ret.errors.is_empty() {
+                return Err(ret.errors);
+            }
+            let (symbols, scopes) = ret.semantic.into_symbol_table_and_scope_tree();
+            let mut file = Self {
+                path,
+                source_text: String::new(),
+                symbols,
+                scopes,
+                imports: vec![],
+                side_effect_imports: vec![],
+                local_exports: vec![],
+                indirect_exports: vec![],
+                star_exports: vec![],
+                namespace_export: None,
+                references: vec![],
+                module_declarations: FxHashMap::default(),
+                edits: vec![],
+                resolved: FxHashMap::default(),
+            };
+            file.collect_references(&source_text);
+            for stmt in &program.body {
+                file.collect_statement(stmt, &source_text)?;
+            }
+            file
+        };
+        file.source_text = source_text;
+        Ok(file)
+    }
+
+    /// The `/// <reference />` directives before the first statement.
+    #[allow(clippy::cast_possible_truncation)]
+    fn collect_references(&mut self, source_text: &str) {
+        let mut start = 0;
+        for line in source_text.split_inclusive('\n') {
+            let text = line.trim();
+            if text.starts_with("/// <reference") {
+                self.references.push(CompactStr::from(text));
+                self.remove(Span::new(start, start + line.len() as u32));
+            } else if !text.is_empty() && !text.starts_with("//") {
+                break;
+            }
+            start += line.len() as u32;
+        }
+    }
+
+    fn collect_statement(
+        &mut self,
+        stmt: &Statement<'_>,
+        source_text: &str,
+    ) -> Result<(), Vec<OxcDiagnostic>> {
+        match stmt {
+            Statement::TSModuleDeclaration(decl) => self.collect_module_declaration(decl),
+            Statement::ImportDeclaration(decl) => {
+                let specifier = decl.source.value.to_compact_str();
+                let specifiers = decl.specifiers.as_ref().filter(|s| !s.is_empty());
+                let Some(specifiers) = specifiers else {
+                    self.side_effect_imports.push(specifier);
+                    self.remove_statement(stmt.span(), source_text);
+                    return Ok(());
+                };
+                for import in specifiers {
+                    let (local, imported) = match import {
+                        ImportDeclarationSpecifier::ImportSpecifier(import) => {
+                            (&import.local, Imported::new(import.imported.name()))
+                        }
+                        ImportDeclarationSpecifier::ImportDefaultSpecifier(import) => {
+                            (&import.local, Imported::Default)
+                        }
+                        ImportDeclarationSpecifier::ImportNamespaceSpecifier(import) => {
+                            (&import.local, Imported::Namespace)
+                        }
+                    };
+                    let local = local.name.to_compact_str();
+                    self.imports.push(ImportBinding {
+                        local,
+                        specifier: specifier.clone(),
+                        imported,
+                    });
+                }
+                self.remove_statement(stmt.span(), source_text);
+            }
+            Statement::ExportNamedDeclaration(decl) => {
+                if let Some(declaration) = &decl.declaration {
+                    if let Declaration::TSModuleDeclaration(decl) = declaration {
+                        self.collect_module_declaration(decl);
+                    }
+                    for name in declared_names(declaration) {
+                        self.local_exports.push((name.clone(), name));
+                    }
+                    self.remove_export_keyword(decl.span, declaration, source_text);
+                    return Ok(());
+                }
+                for specifier in &decl.specifiers {
+                    let export_name = specifier.exported.name().to_compact_str();
+                    let local = specifier.local.name();
+                    match &decl.source {
+                        Some(source) => self.indirect_exports.push((
+                            export_name,
+                            source.value.to_compact_str(),
+                            Imported::new(local),
+                        )),
+                        None => self.local_exports.push((export_name, local.to_compact_str())),
+                    }
+                }
+                self.remove_statement(stmt.span(), source_text);
+            }
+            Statement::ExportDefaultDeclaration(decl) => {
+                let default = CompactStr::from("default");
+                let (name, keyword) = match &decl.declaration {
+                    ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                        (func.id.as_ref().map(|id| id.name.to_compact_str()), "declare ")
+                    }
+                    ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                        (class.id.as_ref().map(|id| id.name.to_compact_str()), "declare ")
+                    }
+                    ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) => {
+                        (Some(decl.id.name.to_compact_str()), "")
+                    }
+                    ExportDefaultDeclarationKind::TSEnumDeclaration(decl) => {
+                        (Some(decl.id.name.to_compact_str()), "declare ")
+                    }
+                    ExportDefaultDeclarationKind::Identifier(ident) => {
+                        self.local_exports.push((default, ident.name.to_compact_str()));
+                        self.remove_statement(stmt.span(), source_text);
+                        return Ok(());
+                    }
+                    _ => (None, ""),
+                };
+                let Some(name) = name else {
+                    return Err(vec![OxcDiagnostic::error(format!(
+                        "Anonymous default exports are not supported in {}",
+                        self.path.display()
+                    ))
+                    .with_label(decl.span)]);
+                };
+                self.local_exports.push((default, name));
+                let span = Span::new(decl.span.start, decl.declaration.span().start);
+                self.edits.push((span, CompactStr::from(keyword)));
+            }
+            Statement::ExportAllDeclaration(decl) => {
+                let specifier = decl.source.value.to_compact_str();
+                match &decl.exported {
+                    Some(exported) => self.indirect_exports.push((
+                        exported.name().to_compact_str(),
+                        specifier,
+                        Imported::Namespace,
+                    )),
+                    None => self.star_exports.push(specifier),
+                }
+                self.remove_statement(stmt.span(), source_text);
+            }
+            Statement::TSExportAssignment(assignment) => {
+                return Err(vec![OxcDiagnostic::error(format!(
+                    "`export =` is not supported in {}",
+                    self.path.display()
+                ))
+                .with_label(assignment.span)]);
+            }
+            Statement::TSNamespaceExportDeclaration(decl) => {
+                self.namespace_export = Some(decl.span);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn collect_module_declaration(&mut self, decl: &TSModuleDeclaration) {
+        let id = match &decl.id {
+            TSModuleDeclarationName::Identifier(ident)
+                if !matches!(decl.kind, TSModuleDeclarationKind::Global) =>
+            {
+                Some(ident.span)
+            }
+            _ => None,
+        };
+        self.module_declarations.insert(decl.span, id);
+    }
+
+    /// The spans of the names which declare a top level binding, or `None` if it keeps its name.
+    fn declaration_spans(&self, symbol_id: SymbolId) -> Option<Vec<Span>> {
+        let spans = std::iter::once(self.symbols.get_span(symbol_id))
+            .chain(self.symbols.get_redeclare_variables(symbol_id).iter().copied());
+        spans
+            .map(|span| match self.module_declarations.get(&span) {
+                Some(id) => *id,
+                None => Some(span),
+            })
+            .collect()
+    }
+
+    /// `export interface A {}` -> `interface A {}`, and `export function f(): void` ->
+    /// `declare function f(): void`, which must be `declare` at the top level of a `.d.ts` file.
+    fn remove_export_keyword(&mut self, span: Span, declaration: &Declaration, source_text: &str) {
+        let start = span.start as usize + "export".len();
+        let rest = source_text[start..].trim_start();
+        let end = source_text.len() - rest.len();
+        let needs_declare = matches!(
+            declaration,
+            Declaration::VariableDeclaration(_)
+                | Declaration::FunctionDeclaration(_)
+                | Declaration::ClassDeclaration(_)
+                | Declaration::TSEnumDeclaration(_)
+                | Declaration::TSModuleDeclaration(_)
+        ) && !rest.starts_with("declare");
+        let keyword = if needs_declare { "declare " } else { "" };
+        #[allow(clippy::cast_possible_truncation)]
+        self.edits.push((Span::new(span.start, end as u32), CompactStr::from(keyword)));
+    }
+
+    /// Remove a statement, and the rest of its line when it is empty.
+    fn remove_statement(&mut self, span: Span, source_text: &str) {
+        let rest = &source_text[span.end as usize..];
+        let line_rest = rest.split_inclusive('\n').next().unwrap_or_default();
+        #[allow(clippy::cast_possible_truncation)]
+        let end =
+            if line_rest.trim().is_empty() { span.end + line_rest.len() as u32 } else { span.end };
+        self.remove(Span::new(span.start, end));
+    }
+
+    fn remove(&mut self, span: Span) {
+        self.edits.push((span, CompactStr::new("")));
+    }
+
+    /// Every module which the file imports or exports from.
+    fn specifiers(&self) -> Vec<CompactStr> {
+        let mut specifiers: Vec<CompactStr> = self
+            .imports
+            .iter()
+            .map(|import| import.specifier.clone())
+            .chain(self.side_effect_imports.iter().cloned())
+            .chain(self.indirect_exports.iter().map(|(_, specifier, _)| specifier.clone()))
+            .chain(self.star_exports.iter().cloned())
+            .collect();
+        specifiers.dedup();
+        specifiers
+    }
+}
+
+/// The names declared by a declaration.
+fn declared_names(declaration: &Declaration) -> Vec<CompactStr> {
+    let mut names = vec![];
+    match declaration {
+        Declaration::TSModuleDeclaration(decl) => {
+            if let TSModuleDeclarationName::Identifier(ident) = &decl.id {
+                names.push(ident.name.to_compact_str());
+            }
+        }
+        Declaration::VariableDeclaration(_)
+        | Declaration::FunctionDeclaration(_)
+        | Declaration::ClassDeclaration(_) => {
+            declaration.bound_names(&mut |ident| names.push(ident.name.to_compact_str()));
+        }
+        _ => names.extend(declaration.id().map(|id| id.name.to_compact_str())),
+    }
+    names
+}
+
+struct Bundle {
+    files: IndexVec<ModuleId, DeclarationFile>,
+    entry_id: ModuleId,
+    /// The bundled files, each after the files it imports.
+    order: Vec<ModuleId>,
+    names: FxHashMap<Target, CompactStr>,
+    used_names: FxHashSet<CompactStr>,
+}
+
+impl Bundle {
+    fn new(files: IndexVec<ModuleId, DeclarationFile>, entry_id: ModuleId) -> Self {
+        let mut bundle = Self {
+            files,
+            entry_id,
+            order: vec![],
+            names: FxHashMap::default(),
+            used_names: FxHashSet::default(),
+        };
+        bundle.sort(entry_id, &mut FxHashSet::default());
+        bundle.assign_names();
+        bundle
+    }
+
+    fn sort(&mut self, module_id: ModuleId, visited: &mut FxHashSet<ModuleId>) {
+        if !visited.insert(module_id) {
+            return;
+        }
+        let file = &self.files[module_id];
+        let dependencies: Vec<_> =
+            file.specifiers().iter().filter_map(|s| file.resolved.get(s).copied()).collect();
+        for dependency in dependencies {
+            self.sort(dependency, visited);
+        }
+        self.order.push(module_id);
+    }
+
+    /// Give every declaration, external import and namespace a unique name. Globals, which the
+    /// files refer to without declaring them, keep their names.
+    fn assign_names(&mut self) {
+        for file in &self.files {
+            self.used_names.extend(file.scopes.root_unresolved_references().keys().cloned());
+        }
+        let entry_first = std::iter::once(self.entry_id)
+            .chain(self.order.iter().copied().filter(|&id| id != self.entry_id))
+            .collect::<Vec<_>>();
+        for &module_id in &entry_first {
+            let file = &self.files[module_id];
+            let root_scope_id = file.scopes.root_scope_id();
+            let mut names = vec![];
+            for (name, &symbol_id) in file.scopes.get_bindings(root_scope_id) {
+                if file.symbols.get_flag(symbol_id).is_import_binding() {
+                    continue;
+                }
+                if file.declaration_spans(symbol_id).is_some() {
+                    names.push((file.symbols.get_span(symbol_id), name.clone()));
+                } else {
+                    self.used_names.insert(name.clone());
+                }
+            }
+            names.sort_unstable_by_key(|(span, _)| span.start);
+            for (_, name) in names {
+                self.assign_name(Target::Declaration(module_id, name.clone()), &name);
+            }
+        }
+        for &module_id in &entry_first {
+            let imports: Vec<_> = self.files[module_id]
+                .imports
+                .iter()
+                .filter_map(|import| Some((self.resolve_import(module_id, import)?, import)))
+                .map(|(target, import)| (target, import.local.clone()))
+                .collect();
+            for (target, local) in imports {
+                self.assign_name(target, &local);
+            }
+        }
+        for (name, target) in self.exports() {
+            if let Target::Namespace(_) = target {
+                self.assign_name(target, &name);
+            }
+        }
+    }
+
+    fn assign_name(&mut self, target: Target, preferred: &str) {
+        if self.names.contains_key(&target) {
+            return;
+        }
+        let mut name = CompactStr::from(preferred);
+        let mut i = 1;
+        while self.used_names.contains(&name) {
+            name = CompactStr::from(format!("{preferred}${i}"));
+            i += 1;
+        }
+        self.used_names.insert(name.clone());
+        // The members of a namespace may be namespaces themselves.
+        if let Target::Namespace(module_id) = target {
+            self.names.insert(target, name);
+            for (export_name, member) in self.module_exports(module_id) {
+                if let Target::Namespace(_) = member {
+                    self.assign_name(member, &export_name);
+                }
+            }
+        } else {
+            self.names.insert(target, name);
+        }
+    }
+
+    /// The name of a target in the bundle. An external name which is only re-exported keeps
+    /// its name.
+    fn name(&self, target: &Target) -> CompactStr {
+        match (self.names.get(target), target) {
+            (Some(name), _) | (None, Target::External(_, Imported::Name(name))) => name.clone(),
+            (None, _) => CompactStr::new(""),
+        }
+    }
+
+    fn check_imports(&self) -> Result<(), Vec<OxcDiagnostic>> {
+        let mut errors = vec![];
+        for (module_id, file) in self.files.iter_enumerated() {
+            for import in &file.imports {
+                if self.resolve_import(module_id, import).is_some() {
+                    continue;
+                }
+                let target_id = file.resolved[&import.specifier];
+                let name = import.imported.export_name().unwrap_or_default();
+                errors.push(OxcDiagnostic::error(format!(
+                    "{} does not export `{name}`, which is imported by {}",
+                    self.files[target_id].path.display(),
+                    file.path.display()
+                )));
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn resolve_import(&self, module_id: ModuleId, import: &ImportBinding) -> Option<Target> {
+        self.resolve_request(module_id, &import.specifier, &import.imported, &mut vec![])
+    }
+
+    fn resolve_request(
+        &self,
+        module_id: ModuleId,
+        specifier: &CompactStr,
+        imported: &Imported,
+        visited: &mut Vec<(ModuleId, CompactStr)>,
+    ) -> Option<Target> {
+        let Some(&target_id) = self.files[module_id].resolved.get(specifier) else {
+            return Some(Target::External(specifier.clone(), imported.clone()));
+        };
+        match imported.export_name() {
+            Some(name) => self.resolve_export(target_id, name, visited),
+            None => Some(Target::Namespace(target_id)),
+        }
+    }
+
+    /// What the file exports as `name`, following imports and re-exports.
+    fn resolve_export(
+        &self,
+        module_id: ModuleId,
+        name: &str,
+        visited: &mut Vec<(ModuleId, CompactStr)>,
+    ) -> Option<Target> {
+        let key = (module_id, CompactStr::from(name));
+        if visited.contains(&key) {
+            return None;
+        }
+        visited.push(key);
+        let file = &self.files[module_id];
+        if let Some((_, local)) = file.local_exports.iter().find(|(export, _)| *export == name) {
+            return match file.imports.iter().find(|import| import.local == *local) {
+                Some(import) => {
+                    self.resolve_request(module_id, &import.specifier, &import.imported, visited)
+                }
+                None => Some(Target::Declaration(module_id, local.clone())),
+            };
+        }
+        if let Some((_, specifier, imported)) =
+            file.indirect_exports.iter().find(|(export, _, _)| *export == name)
+        {
+            return self.resolve_request(module_id, specifier, imported, visited);
+        }
+        if name == "default" {
+            return None;
+        }
+        file.star_exports.iter().find_map(|specifier| {
+            let &target_id = file.resolved.get(specifier)?;
+            self.resolve_export(target_id, name, visited)
+        })
+    }
+
+    /// The exports of a bundled file, including the ones of `export * from` a bundled file.
+    fn module_exports(&self, module_id: ModuleId) -> Vec<(CompactStr, Target)> {
+        let mut names = vec![];
+        let mut visited = vec![];
+        let mut stack = vec![module_id];
+        while let Some(current_id) = stack.pop() {
+            if visited.contains(&current_id) {
+                continue;
+            }
+            visited.push(current_id);
+            let file = &self.files[current_id];
+            for (name, _) in &file.local_exports {
+                if current_id == module_id || *name != "default" {
+                    names.push(name.clone());
+                }
+            }
+            for (name, _, _) in &file.indirect_exports {
+                if current_id == module_id || *name != "default" {
+                    names.push(name.clone());
+                }
+            }
+            stack.extend(file.star_exports.iter().filter_map(|s| file.resolved.get(s).copied()));
+        }
+        names.dedup();
+        let mut seen = FxHashSet::default();
+        names
+            .into_iter()
+            .filter(|name| seen.insert(name.clone()))
+            .filter_map(|name| {
+                let target = self.resolve_export(module_id, &name, &mut vec![])?;
+                Some((name, target))
+            })
+            .collect()
+    }
+
+    fn exports(&self) -> Vec<(CompactStr, Target)> {
+        self.module_exports(self.entry_id)
+    }
+
+    /// `export * from "external"` of the entry file and the files it re-exports.
+    fn external_star_exports(&self) -> Vec<CompactStr> {
+        let mut specifiers = vec![];
+        let mut visited = vec![];
+        let mut stack = vec![self.entry_id];
+        while let Some(module_id) = stack.pop() {
+            if visited.contains(&module_id) {
+                continue;
+            }
+            visited.push(module_id);
+            let file = &self.files[module_id];
+            for specifier in &file.star_exports {
+                match file.resolved.get(specifier) {
+                    Some(&target_id) => stack.push(target_id),
+                    None if !specifiers.contains(specifier) => specifiers.push(specifier.clone()),
+                    None => {}
+                }
+            }
+        }
+        specifiers
+    }
+
+    fn render(&self) -> DeclarationBundle {
+        let mut source_text = String::new();
+        let mut references = vec![];
+        for &module_id in &self.order {
+            for reference in &self.files[module_id].references {
+                if !references.contains(reference) {
+                    references.push(reference.clone());
+                }
+            }
+        }
+        for reference in &references {
+            source_text.push_str(reference);
+            source_text.push('\n');
+        }
+        self.render_external_imports(&mut source_text);
+
+        let mut renamed = vec![];
+        for &module_id in &self.order {
+            let text = self.render_file(module_id, &mut renamed);
+            let text = text.trim();
+            if !text.is_empty() {
+                source_text.push_str(text);
+                source_text.push('\n');
+            }
+        }
+
+        renamed.sort_unstable_by(|a, b| {
+            (&a.0, a.1.as_str(), a.2.as_str()).cmp(&(&b.0, b.1.as_str(), b.2.as_str()))
+        });
+
+        let mut namespaces: Vec<_> = self
+            .names
+            .iter()
+            .filter_map(|(target, name)| match target {
+                Target::Namespace(module_id) => Some((*module_id, name)),
+                _ => None,
+            })
+            .collect();
+        namespaces.sort_unstable_by_key(|(module_id, name)| (*module_id, name.as_str()));
+        for (module_id, name) in namespaces {
+            let members = self.export_list(&self.module_exports(module_id));
+            let _ =
+                writeln!(source_text, "declare namespace {name} {{\n\texport {{ {members} }};\n}}");
+        }
+
+        self.render_exports(&mut source_text);
+        DeclarationBundle { source_text, renamed }
+    }
+
+    fn render_external_imports(&self, source_text: &mut String) {
+        let mut specifiers: Vec<&CompactStr> = vec![];
+        let mut imports: FxHashMap<&CompactStr, Vec<(&Imported, CompactStr)>> =
+            FxHashMap::default();
+        for &module_id in &self.order {
+            let file = &self.files[module_id];
+            for import in &file.imports {
+                if file.resolved.contains_key(&import.specifier) {
+                    continue;
+                }
+                let target = Target::External(import.specifier.clone(), import.imported.clone());
+                let entry = imports.entry(&import.specifier).or_default();
+                if !entry.iter().any(|(imported, _)| **imported == import.imported) {
+                    entry.push((&import.imported, self.name(&target)));
+                }
+                if !specifiers.contains(&&import.specifier) {
+                    specifiers.push(&import.specifier);
+                }
+            }
+        }
+        for &module_id in &self.order {
+            let file = &self.files[module_id];
+            for specifier in &file.side_effect_imports {
+                if !file.resolved.contains_key(specifier) && !specifiers.contains(&specifier) {
+                    specifiers.push(specifier);
+                }
+            }
+        }
+        for specifier in specifiers {
+            let Some(imports) = imports.get(specifier) else {
+                let _ = writeln!(source_text, "import \"{specifier}\";");
+                continue;
+            };
+            let mut named = vec![];
+            for (imported, local) in imports {
+                match imported {
+                    Imported::Default => {
+                        let _ = writeln!(source_text, "import {local} from \"{specifier}\";");
+                    }
+                    Imported::Namespace => {
+                        let _ = writeln!(source_text, "import * as {local} from \"{specifier}\";");
+                    }
+                    Imported::Name(name) if name == local => named.push(name.to_string()),
+                    Imported::Name(name) => named.push(format!("{name} as {local}")),
+                }
+            }
+            if !named.is_empty() {
+                let named = named.join(", ");
+                let _ = writeln!(source_text, "import {{ {named} }} from \"{specifier}\";");
+            }
+        }
+    }
+
+    /// The text of a file, without its import and export syntax, and with the bundle names.
+    fn render_file(
+        &self,
+        module_id: ModuleId,
+        renamed: &mut Vec<(PathBuf, CompactStr, CompactStr)>,
+    ) -> String {
+        let file = &self.files[module_id];
+        let mut edits = file.edits.clone();
+        if module_id != self.entry_id {
+            if let Some(span) = file.namespace_export {
+                edits.push((span, CompactStr::new("")));
+            }
+        }
+        let symbols = &file.symbols;
+        for (name, &symbol_id) in file.scopes.get_bindings(file.scopes.root_scope_id()) {
+            let new_name =
+                if let Some(import) = file.imports.iter().find(|import| import.local == *name) {
+                    let Some(target) = self.resolve_import(module_id, import) else { continue };
+                    self.name(&target)
+                } else {
+                    let Some(spans) = file.declaration_spans(symbol_id) else { continue };
+                    let new_name = self.name(&Target::Declaration(module_id, name.clone()));
+                    if new_name != *name {
+                        renamed.push((file.path.clone(), name.clone(), new_name.clone()));
+                        edits.extend(spans.into_iter().map(|span| (span, new_name.clone())));
+                    }
+                    new_name
+                };
+            if new_name != *name {
+                for span in symbols.get_resolved_references(symbol_id).map(Reference::span) {
+                    edits.push((span, new_name.clone()));
+                }
+            }
+        }
+
+        // Edits inside of a removed statement are skipped.
+        edits.sort_unstable_by_key(|(span, _)| (span.start, std::cmp::Reverse(span.end)));
+        let mut output = String::with_capacity(file.source_text.len());
+        let mut last = 0;
+        for (span, replacement) in edits {
+            if (span.start as usize) < last {
+                continue;
+            }
+            output.push_str(&file.source_text[last..span.start as usize]);
+            output.push_str(&replacement);
+            last = span.end as usize;
+        }
+        output.push_str(&file.source_text[last..]);
+        output
+    }
+
+    fn render_exports(&self, source_text: &mut String) {
+        let mut local = vec![];
+        let mut external = vec![];
+        for (name, target) in self.exports() {
+            match target {
+                Target::External(specifier, Imported::Namespace) => {
+                    external.push(format!("export * as {name} from \"{specifier}\";"));
+                }
+                Target::External(specifier, imported) => {
+                    let imported = imported.export_name().unwrap_or_default();
+                    let export = if imported == name {
+                        format!("export {{ {name} }} from \"{specifier}\";")
+                    } else {
+                        format!("export {{ {imported} as {name} }} from \"{specifier}\";")
+                    };
+                    external.push(export);
+                }
+                target => local.push((name, target)),
+            }
+        }
+        for specifier in self.external_star_exports() {
+            external.push(format!("export * from \"{specifier}\";"));
+        }
+        if !local.is_empty() || external.is_empty() {
+            let _ = writeln!(source_text, "export {{ {} }};", self.export_list(&local));
+        }
+        for export in external {
+            source_text.push_str(&export);
+            source_text.push('\n');
+        }
+    }
+
+    /// `a, b as c`
+    fn export_list(&self, exports: &[(CompactStr, Target)]) -> String {
+        exports
+            .iter()
+            .map(|(name, target)| {
+                let local = self.name(target);
+                if local == *name {
+                    local.to_string()
+                } else {
+                    format!("{local} as {name}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
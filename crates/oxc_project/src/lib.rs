@@ -0,0 +1,493 @@
+//! Oxc Project
+//!
+//! A symbol index across the modules of a project, for features which need to follow a binding
+//! from one file to another, such as find-all-references, rename and cross-module minification.
+//!
+//! * Each module is parsed and analyzed once, only the owned results are kept: the symbol table,
+//!   the scope tree and the module record.
+//! * Import requests are resolved to the indexed modules with [oxc_resolver], and every export,
+//!   keyed by module and export name, is resolved to the binding which declares it, following
+//!   re-exports and imports which are exported again.
+//! * Modules which are not indexed, such as packages, are external, and their exports are not
+//!   resolved.
+//!
+//! The [DeclarationBundler] bundles the `.d.ts` files of a project into one file.
+
+mod bundle_declarations;
+mod mangle_exports;
+mod rename;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{Expression, MemberExpression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_index::{define_index_type, IndexVec};
+use oxc_parser::Parser;
+use oxc_resolver::Resolver;
+use oxc_semantic::{ModuleRecord, Reference, ScopeTree, SemanticBuilder, SymbolId, SymbolTable};
+use oxc_span::{CompactStr, SourceType, Span};
+use oxc_syntax::module_record::{
+    ExportExportName, ExportImportName, ExportLocalName, ImportImportName, ResolvedBindingName,
+    ResolvedExport,
+};
+use rustc_hash::FxHashMap;
+
+pub use crate::{
+    bundle_declarations::{DeclarationBundle, DeclarationBundler},
+    mangle_exports::{ExportMangling, PreserveReason, PreservedExports},
+    rename::{Edit, RenamePlan},
+};
+
+define_index_type! {
+    pub struct ModuleId = u32;
+}
+
+/// A binding declared in a module of the project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProjectSymbol {
+    pub module_id: ModuleId,
+    pub symbol_id: SymbolId,
+}
+
+/// What an export, or an import of it, resolves to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExportTarget {
+    /// A binding declared by an indexed module, which is not itself an import.
+    Symbol(ProjectSymbol),
+    /// The namespace object of an indexed module, `import * as ns` or `export * as ns from`.
+    Namespace(ModuleId),
+    /// The value of `export default expression`, which has no binding.
+    DefaultExpression(ModuleId),
+}
+
+/// A location in a module of the project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Location {
+    pub module_id: ModuleId,
+    pub span: Span,
+}
+
+/// A module of the project.
+pub struct Module {
+    path: PathBuf,
+    source_text: String,
+    record: Arc<ModuleRecord>,
+    symbols: SymbolTable,
+    scopes: ScopeTree,
+    /// `ns.name`, where `ns` is an import binding.
+    member_accesses: Vec<MemberAccess>,
+    /// The specifiers of `import(specifier)`, `None` when it is not a string literal.
+    dynamic_imports: Vec<Option<CompactStr>>,
+}
+
+/// `object.property`, where `object` is an import binding, which accesses the export `property`
+/// when `object` is a namespace import.
+pub(crate) struct MemberAccess {
+    pub object: SymbolId,
+    pub property: CompactStr,
+    pub span: Span,
+}
+
+impl Module {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn source_text(&self) -> &str {
+        &self.source_text
+    }
+
+    pub fn record(&self) -> &ModuleRecord {
+        &self.record
+    }
+
+    pub fn symbols(&self) -> &SymbolTable {
+        &self.symbols
+    }
+
+    pub fn scopes(&self) -> &ScopeTree {
+        &self.scopes
+    }
+}
+
+/// Adds modules to the index, which are linked by [ProjectIndexBuilder::build].
+pub struct ProjectIndexBuilder {
+    resolver: Resolver,
+    modules: IndexVec<ModuleId, Module>,
+}
+
+impl ProjectIndexBuilder {
+    pub fn new(resolver: Resolver) -> Self {
+        Self { resolver, modules: IndexVec::new() }
+    }
+
+    /// Read, parse and analyze the module at `path`.
+    ///
+    /// # Errors
+    ///
+    /// * The file cannot be read, or its extension is not a JavaScript or TypeScript one.
+    /// * The module has syntax errors.
+    pub fn add_file(&mut self, path: &Path) -> Result<ModuleId, Vec<OxcDiagnostic>> {
+        let read_error = |error: std::io::Error| {
+            vec![OxcDiagnostic::error(format!("Failed to read {}: {error}", path.display()))]
+        };
+        // The resolver returns canonical paths.
+        let path = fs::canonicalize(path).map_err(read_error)?;
+        let source_text = fs::read_to_string(&path).map_err(read_error)?;
+        let source_type =
+            SourceType::from_path(&path).map_err(|error| vec![OxcDiagnostic::error(error.0)])?;
+        self.add_source(path, source_text, source_type)
+    }
+
+    /// Parse and analyze `source_text` as the module at `path`.
+    ///
+    /// # Errors
+    ///
+    /// * The module has syntax errors.
+    pub fn add_source(
+        &mut self,
+        path: PathBuf,
+        source_text: String,
+        source_type: SourceType,
+    ) -> Result<ModuleId, Vec<OxcDiagnostic>> {
+        // The AST borrows the source text, which is moved into the module afterwards.
+        let (record, symbols, scopes, member_accesses, dynamic_imports) = {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, &source_text, source_type).parse();
+            if !ret.errors.is_empty() {
+                return Err(ret.errors);
+            }
+            let program = allocator.alloc(ret.program);
+            let ret = SemanticBuilder::new(&source_text, source_type)
+                .with_check_syntax_error(true)
+                .build_module_record(path.clone(), program)
+                .build(program);
+            if !ret.errors.is_empty() {
+                return Err(ret.errors);
+            }
+            let semantic = ret.semantic;
+            let record = Arc::clone(semantic.module_record());
+
+            let member_accesses = semantic
+                .nodes()
+                .iter()
+                .filter_map(|node| {
+                    let AstKind::MemberExpression(MemberExpression::StaticMemberExpression(expr)) =
+                        node.kind()
+                    else {
+                        return None;
+                    };
+                    let Expression::Identifier(object) = &expr.object else { return None };
+                    let reference = semantic.symbols().get_reference(object.reference_id.get()?);
+                    let object = reference.symbol_id()?;
+                    semantic.symbols().get_flag(object).is_import_binding().then(|| MemberAccess {
+                        object,
+                        property: expr.property.name.to_compact_str(),
+                        span: expr.property.span,
+                    })
+                })
+                .collect();
+
+            let dynamic_imports = semantic
+                .nodes()
+                .iter()
+                .filter_map(|node| {
+                    let AstKind::ImportExpression(expr) = node.kind() else { return None };
+                    Some(match &expr.source {
+                        Expression::StringLiteral(lit) => Some(lit.value.to_compact_str()),
+                        _ => None,
+                    })
+                })
+                .collect();
+
+            let (symbols, scopes) = semantic.into_symbol_table_and_scope_tree();
+            (record, symbols, scopes, member_accesses, dynamic_imports)
+        };
+        Ok(self.modules.push(Module {
+            path,
+            source_text,
+            record,
+            symbols,
+            scopes,
+            member_accesses,
+            dynamic_imports,
+        }))
+    }
+
+    /// Resolve the imports of every module, and link them to the exports they import.
+    pub fn build(self) -> ProjectIndex {
+        let module_ids: FxHashMap<PathBuf, ModuleId> =
+            self.modules.iter_enumerated().map(|(id, module)| (module.path.clone(), id)).collect();
+
+        for module in &self.modules {
+            let dir = module.path.parent().unwrap_or(Path::new(""));
+            // `import("./a")` is resolved like `import "./a"`.
+            let dynamic_imports = module.dynamic_imports.iter().flatten();
+            for specifier in module.record.requested_modules.keys().chain(dynamic_imports) {
+                let Ok(resolution) = self.resolver.resolve(dir, specifier) else { continue };
+                let Some(&target_id) = module_ids.get(resolution.path()) else { continue };
+                module
+                    .record
+                    .loaded_modules
+                    .insert(specifier.clone(), Arc::clone(&self.modules[target_id].record));
+            }
+        }
+
+        let mut index = ProjectIndex {
+            modules: self.modules,
+            module_ids,
+            imports: FxHashMap::default(),
+            exports: FxHashMap::default(),
+        };
+        index.link();
+        index
+    }
+}
+
+/// The modules of a project, with their imports linked to the bindings they import.
+pub struct ProjectIndex {
+    modules: IndexVec<ModuleId, Module>,
+    module_ids: FxHashMap<PathBuf, ModuleId>,
+    /// Import bindings, resolved to the binding which declares them.
+    imports: FxHashMap<ProjectSymbol, ExportTarget>,
+    /// Exports, keyed by module and export name.
+    exports: FxHashMap<(ModuleId, CompactStr), ExportTarget>,
+}
+
+impl ProjectIndex {
+    pub fn module_id(&self, path: &Path) -> Option<ModuleId> {
+        self.module_ids.get(path).copied()
+    }
+
+    pub fn module(&self, module_id: ModuleId) -> &Module {
+        &self.modules[module_id]
+    }
+
+    pub fn modules(&self) -> impl Iterator<Item = (ModuleId, &Module)> + '_ {
+        self.modules.iter_enumerated()
+    }
+
+    /// The binding declared at the top level of the module as `name`.
+    pub fn root_symbol(&self, module_id: ModuleId, name: &str) -> Option<ProjectSymbol> {
+        let symbol_id = self.modules[module_id].scopes.get_root_binding(name)?;
+        Some(ProjectSymbol { module_id, symbol_id })
+    }
+
+    /// What the module exports as `export_name`, `None` if it is not exported or the export comes
+    /// from an external module.
+    pub fn resolve_export(&self, module_id: ModuleId, export_name: &str) -> Option<ExportTarget> {
+        self.exports.get(&(module_id, CompactStr::from(export_name))).copied()
+    }
+
+    /// What the import binding `symbol` imports, `None` if it is not an import binding or the
+    /// import comes from an external module.
+    pub fn resolve_import(&self, symbol: ProjectSymbol) -> Option<ExportTarget> {
+        self.imports.get(&symbol).copied()
+    }
+
+    /// The binding which declares `symbol`, following imports.
+    pub fn declaration(&self, symbol: ProjectSymbol) -> ProjectSymbol {
+        match self.resolve_import(symbol) {
+            Some(ExportTarget::Symbol(declaration)) => declaration,
+            _ => symbol,
+        }
+    }
+
+    /// Every location which refers to the binding of `symbol` in the project: its declaration and
+    /// references, the import bindings of it and their references, the names it is imported and
+    /// exported with, and `ns.name` accesses through namespace imports.
+    pub fn find_references(&self, symbol: ProjectSymbol) -> Vec<Location> {
+        let declaration = self.declaration(symbol);
+        let target = ExportTarget::Symbol(declaration);
+        let mut locations = self.binding_locations(declaration);
+        for (&import, _) in
+            self.imports.iter().filter(|(_, &import_target)| import_target == target)
+        {
+            locations.extend(self.binding_locations(import));
+        }
+        for (module_id, span, _) in self.export_name_locations(target) {
+            locations.push(Location { module_id, span });
+        }
+        locations.sort_unstable();
+        locations.dedup();
+        locations
+    }
+
+    /// The declaration and the references of a binding in its module.
+    fn binding_locations(&self, symbol: ProjectSymbol) -> Vec<Location> {
+        let symbols = &self.modules[symbol.module_id].symbols;
+        std::iter::once(symbols.get_span(symbol.symbol_id))
+            .chain(symbols.get_resolved_references(symbol.symbol_id).map(Reference::span))
+            .map(|span| Location { module_id: symbol.module_id, span })
+            .collect()
+    }
+
+    /// The names which import or export `target` from another module, with the name used:
+    /// `import { name }`, `export { name }`, `export { name } from` and `ns.name`.
+    fn export_name_locations(&self, target: ExportTarget) -> Vec<(ModuleId, Span, CompactStr)> {
+        let mut locations = vec![];
+        for (module_id, module) in self.modules.iter_enumerated() {
+            let record = &module.record;
+            for entry in &record.import_entries {
+                let ImportImportName::Name(import_name) = &entry.import_name else { continue };
+                let Some(symbol_id) = module.scopes.get_root_binding(entry.local_name.name())
+                else {
+                    continue;
+                };
+                if self.imports.get(&ProjectSymbol { module_id, symbol_id }) == Some(&target) {
+                    locations.push((module_id, import_name.span(), import_name.name().clone()));
+                }
+            }
+            for entry in record.local_export_entries.iter().chain(&record.indirect_export_entries) {
+                let ExportExportName::Name(export_name) = &entry.export_name else { continue };
+                if self.resolve_export(module_id, export_name.name()) != Some(target) {
+                    continue;
+                }
+                locations.push((module_id, export_name.span(), export_name.name().clone()));
+                if let ExportImportName::Name(import_name) = &entry.import_name {
+                    locations.push((module_id, import_name.span(), import_name.name().clone()));
+                }
+                // `a` in `export { a as b }` is not a reference of the binding.
+                if let ExportLocalName::Name(local_name) = &entry.local_name {
+                    locations.push((module_id, local_name.span(), local_name.name().clone()));
+                }
+            }
+            for access in &module.member_accesses {
+                let object = ProjectSymbol { module_id, symbol_id: access.object };
+                let Some(ExportTarget::Namespace(namespace)) = self.resolve_import(object) else {
+                    continue;
+                };
+                if self.resolve_export(namespace, &access.property) == Some(target) {
+                    locations.push((module_id, access.span, access.property.clone()));
+                }
+            }
+        }
+        locations
+    }
+
+    /// Resolve every import binding and every export.
+    fn link(&mut self) {
+        let mut imports = FxHashMap::default();
+        let mut exports = FxHashMap::default();
+        for (module_id, module) in self.modules.iter_enumerated() {
+            for entry in &module.record.import_entries {
+                let Some(symbol_id) = module.scopes.get_root_binding(entry.local_name.name())
+                else {
+                    continue;
+                };
+                let symbol = ProjectSymbol { module_id, symbol_id };
+                if let Some(target) = self.resolve_import_binding(symbol, &mut vec![]) {
+                    imports.insert(symbol, target);
+                }
+            }
+            for name in self.export_names(module_id) {
+                if let Some(target) = self.resolve_export_impl(module_id, &name, &mut vec![]) {
+                    exports.insert((module_id, name), target);
+                }
+            }
+        }
+        self.imports = imports;
+        self.exports = exports;
+    }
+
+    /// The names exported by the module, including the ones of `export * from`.
+    fn export_names(&self, module_id: ModuleId) -> Vec<CompactStr> {
+        let mut names = vec![];
+        let mut visited = vec![];
+        let mut stack = vec![module_id];
+        while let Some(current_id) = stack.pop() {
+            if visited.contains(&current_id) {
+                continue;
+            }
+            visited.push(current_id);
+            let record = &self.modules[current_id].record;
+            for entry in record.local_export_entries.iter().chain(&record.indirect_export_entries) {
+                match &entry.export_name {
+                    ExportExportName::Name(name) => names.push(name.name().clone()),
+                    // `export * from` does not export the default export.
+                    ExportExportName::Default(_) if current_id == module_id => {
+                        names.push(CompactStr::from("default"));
+                    }
+                    _ => {}
+                }
+            }
+            for entry in &record.star_export_entries {
+                let Some(request) = &entry.module_request else { continue };
+                if let Some(target_id) = self.loaded_module_id(current_id, request.name()) {
+                    stack.push(target_id);
+                }
+            }
+        }
+        names.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+        names.dedup();
+        names
+    }
+
+    fn resolve_export_impl(
+        &self,
+        module_id: ModuleId,
+        export_name: &str,
+        visited: &mut Vec<ProjectSymbol>,
+    ) -> Option<ExportTarget> {
+        let ResolvedExport::Found(binding) =
+            self.modules[module_id].record.resolve_export(export_name)
+        else {
+            return None;
+        };
+        let module_id = self.module_id(&binding.module)?;
+        match binding.binding_name {
+            ResolvedBindingName::Name(name) => {
+                let symbol = self.root_symbol(module_id, &name)?;
+                if !self.modules[module_id].symbols.get_flag(symbol.symbol_id).is_import_binding() {
+                    return Some(ExportTarget::Symbol(symbol));
+                }
+                // `import { a } from "./a"; export { a }`
+                self.resolve_import_binding(symbol, visited)
+            }
+            ResolvedBindingName::Default => Some(ExportTarget::DefaultExpression(module_id)),
+            ResolvedBindingName::Namespace => Some(ExportTarget::Namespace(module_id)),
+        }
+    }
+
+    fn resolve_import_binding(
+        &self,
+        symbol: ProjectSymbol,
+        visited: &mut Vec<ProjectSymbol>,
+    ) -> Option<ExportTarget> {
+        // Modules which import a binding from each other.
+        if visited.contains(&symbol) {
+            return None;
+        }
+        visited.push(symbol);
+        let module = &self.modules[symbol.module_id];
+        let name = module.symbols.get_name(symbol.symbol_id);
+        let entry = module
+            .record
+            .import_entries
+            .iter()
+            .find(|entry| entry.local_name.name().as_str() == name && !entry.is_type)?;
+        let target_id = self.loaded_module_id(symbol.module_id, entry.module_request.name())?;
+        match &entry.import_name {
+            ImportImportName::Name(import_name) => {
+                self.resolve_export_impl(target_id, import_name.name(), visited)
+            }
+            ImportImportName::Default(_) => self.resolve_export_impl(target_id, "default", visited),
+            ImportImportName::NamespaceObject => Some(ExportTarget::Namespace(target_id)),
+        }
+    }
+
+    /// The indexed module which `specifier` resolves to from the module.
+    fn loaded_module_id(&self, module_id: ModuleId, specifier: &str) -> Option<ModuleId> {
+        let loaded_modules = &self.modules[module_id].record.loaded_modules;
+        let record = loaded_modules.get(specifier)?;
+        self.module_id(&record.resolved_absolute_path)
+    }
+}
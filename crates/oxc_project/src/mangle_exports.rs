@@ -0,0 +1,404 @@
+//! Export Mangling
+//!
+//! Renames the exports which are only imported by other modules of the project to short names,
+//! and rewrites the imports of them, before each module is minified:
+//!
+//! ```javascript
+//! // utils.js
+//! export function formatDate() {}
+//! // app.js
+//! import { formatDate } from "./utils.js";
+//! ```
+//!
+//! becomes `function formatDate() {}` followed by `export { formatDate as a };`, and
+//! `import { a as formatDate } from "./utils.js"`. Local bindings keep their names, they are
+//! shortened by the mangler of each module.
+//!
+//! An export name is renamed to the same short name in every module, so that names which are the
+//! same stay the same, and `export * from` keeps exporting them. The exports of these modules
+//! keep their names, and are listed in the manifest:
+//!
+//! * The entry modules, whose exports are used outside of the project.
+//! * Modules which are loaded with `import()`, or whose namespace object is used other than by
+//!   `ns.name`, as it may be accessed with any name.
+//! * Modules which are re-exported by a preserved module with `export * from`.
+//! * Every module, when the project contains an `import()` whose specifier is not a string.
+//!
+//! `export default` is never renamed. Modules loaded with `require` are not followed, and must be
+//! entries.
+
+use std::collections::hash_map;
+
+use oxc_minifier::base54;
+use oxc_span::{CompactStr, Span};
+use oxc_syntax::{
+    keyword::is_reserved_keyword,
+    module_record::{ExportExportName, ExportImportName, ExportLocalName, ImportImportName},
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_json::{json, Map, Value};
+
+use crate::{Edit, ExportTarget, ModuleId, ProjectIndex, ProjectSymbol, RenamePlan};
+
+/// Why the exports of a module keep their names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreserveReason {
+    /// An entry module, whose exports are used outside of the project.
+    Entry,
+    /// Loaded with `import()`.
+    DynamicImport,
+    /// The namespace object of the module is exported, or used other than by `ns.name`.
+    Namespace,
+    /// Re-exported with `export * from` by a preserved module.
+    StarExport,
+    /// The project contains an `import()` whose specifier is not a string.
+    UnknownDynamicImport,
+}
+
+impl PreserveReason {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Entry => "entry",
+            Self::DynamicImport => "dynamic-import",
+            Self::Namespace => "namespace",
+            Self::StarExport => "star-export",
+            Self::UnknownDynamicImport => "unknown-dynamic-import",
+        }
+    }
+}
+
+/// The export names of a module which are not renamed.
+#[derive(Debug)]
+pub struct PreservedExports {
+    pub module_id: ModuleId,
+    pub reason: PreserveReason,
+    pub names: Vec<CompactStr>,
+}
+
+/// The result of [ProjectIndex::mangle_exports].
+#[derive(Debug, Default)]
+pub struct ExportMangling {
+    /// The edits which rename the exports and their imports.
+    pub plan: RenamePlan,
+    /// The export names and their short names, sorted by export name.
+    pub renamed: Vec<(CompactStr, CompactStr)>,
+    /// The modules whose exports keep their names, sorted by module.
+    pub preserved: Vec<PreservedExports>,
+}
+
+impl ExportMangling {
+    /// The preserved and renamed export names as JSON, to look up the original names later.
+    pub fn manifest(&self, index: &ProjectIndex) -> String {
+        let preserved = self
+            .preserved
+            .iter()
+            .map(|preserved| {
+                json!({
+                    "path": index.module(preserved.module_id).path().to_string_lossy(),
+                    "reason": preserved.reason.as_str(),
+                    "exports": preserved.names.iter().map(CompactStr::as_str).collect::<Vec<_>>(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let renamed = self
+            .renamed
+            .iter()
+            .map(|(name, short_name)| (name.to_string(), Value::from(short_name.as_str())))
+            .collect::<Map<_, _>>();
+        format!("{:#}", json!({ "preserved": preserved, "renamed": renamed }))
+    }
+}
+
+impl ProjectIndex {
+    /// Rename the exports which are not used outside of the project, with `entries` as the
+    /// modules whose exports are used outside of it. See the [module docs](self).
+    pub fn mangle_exports(&self, entries: &[ModuleId]) -> ExportMangling {
+        let preserved = self.preserved_modules(entries);
+        let preserved_names: FxHashSet<CompactStr> =
+            preserved.keys().flat_map(|&module_id| self.export_names(module_id)).collect();
+
+        let mut names: Vec<CompactStr> = self
+            .modules
+            .indices()
+            .filter(|module_id| !preserved.contains_key(module_id))
+            .flat_map(|module_id| self.own_export_names(module_id))
+            .filter(|name| *name != "default")
+            .collect();
+        names.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+        names.dedup();
+        let mut count = 0;
+        let renamed = names
+            .into_iter()
+            .map(|name| {
+                let short_name = loop {
+                    let short_name = base54(count);
+                    count += 1;
+                    if !is_reserved_keyword(&short_name) && !preserved_names.contains(&short_name) {
+                        break short_name;
+                    }
+                };
+                (name, short_name)
+            })
+            .collect::<Vec<_>>();
+
+        let mut mangler = ExportMangler {
+            index: self,
+            preserved: &preserved,
+            short_names: renamed.iter().cloned().collect(),
+            edits: vec![],
+        };
+        for module_id in self.modules.indices() {
+            mangler.rewrite_imports(module_id);
+            mangler.rewrite_exports(module_id);
+        }
+        let mut edits = mangler.edits;
+        edits.sort_by_key(|edit| (edit.module_id, edit.span.start));
+
+        let mut preserved = preserved
+            .into_iter()
+            .map(|(module_id, reason)| PreservedExports {
+                module_id,
+                reason,
+                names: self.export_names(module_id),
+            })
+            .collect::<Vec<_>>();
+        preserved.sort_unstable_by_key(|preserved| preserved.module_id);
+        ExportMangling { plan: RenamePlan { edits }, renamed, preserved }
+    }
+
+    /// The modules whose exports keep their names, with the first reason found.
+    fn preserved_modules(&self, entries: &[ModuleId]) -> FxHashMap<ModuleId, PreserveReason> {
+        let mut preserved = FxHashMap::default();
+        if self.modules.iter().any(|module| module.dynamic_imports.iter().any(Option::is_none)) {
+            for module_id in self.modules.indices() {
+                preserved.insert(module_id, PreserveReason::UnknownDynamicImport);
+            }
+            return preserved;
+        }
+        for &module_id in entries {
+            preserved.insert(module_id, PreserveReason::Entry);
+        }
+        for (module_id, module) in self.modules.iter_enumerated() {
+            for specifier in module.dynamic_imports.iter().flatten() {
+                if let Some(target_id) = self.loaded_module_id(module_id, specifier) {
+                    preserved.entry(target_id).or_insert(PreserveReason::DynamicImport);
+                }
+            }
+        }
+        // `export * as ns from "./a"`, or `import * as ns from "./a"; f(ns)`
+        for target in self.exports.values() {
+            if let ExportTarget::Namespace(target_id) = target {
+                preserved.entry(*target_id).or_insert(PreserveReason::Namespace);
+            }
+        }
+        for (import, target) in &self.imports {
+            let ExportTarget::Namespace(target_id) = target else { continue };
+            let module = &self.modules[import.module_id];
+            let accesses =
+                module.member_accesses.iter().filter(|access| access.object == import.symbol_id);
+            if module.symbols.get_resolved_reference_count(import.symbol_id) > accesses.count() {
+                preserved.entry(*target_id).or_insert(PreserveReason::Namespace);
+            }
+        }
+
+        let mut stack: Vec<ModuleId> = preserved.keys().copied().collect();
+        while let Some(module_id) = stack.pop() {
+            for entry in &self.modules[module_id].record.star_export_entries {
+                let Some(request) = &entry.module_request else { continue };
+                let Some(target_id) = self.loaded_module_id(module_id, request.name()) else {
+                    continue;
+                };
+                if let hash_map::Entry::Vacant(entry) = preserved.entry(target_id) {
+                    entry.insert(PreserveReason::StarExport);
+                    stack.push(target_id);
+                }
+            }
+        }
+        preserved
+    }
+
+    /// The names exported by the module itself, without the ones of `export * from`.
+    fn own_export_names(&self, module_id: ModuleId) -> impl Iterator<Item = CompactStr> + '_ {
+        let record = &self.modules[module_id].record;
+        record.local_export_entries.iter().chain(&record.indirect_export_entries).filter_map(
+            |entry| match &entry.export_name {
+                ExportExportName::Name(name) => Some(name.name().clone()),
+                _ => None,
+            },
+        )
+    }
+}
+
+struct ExportMangler<'i> {
+    index: &'i ProjectIndex,
+    preserved: &'i FxHashMap<ModuleId, PreserveReason>,
+    short_names: FxHashMap<CompactStr, CompactStr>,
+    edits: Vec<Edit>,
+}
+
+impl<'i> ExportMangler<'i> {
+    /// The name which the module exports `name` with after mangling, following `export * from`.
+    fn export_name(&self, module_id: ModuleId, name: &str) -> CompactStr {
+        let mut visited = vec![];
+        let mut stack = vec![module_id];
+        while let Some(current_id) = stack.pop() {
+            if visited.contains(&current_id) {
+                continue;
+            }
+            visited.push(current_id);
+            if self.index.own_export_names(current_id).any(|export_name| export_name == name) {
+                return self.own_export_name(current_id, name);
+            }
+            for entry in &self.index.modules[current_id].record.star_export_entries {
+                let Some(request) = &entry.module_request else { continue };
+                if let Some(target_id) = self.index.loaded_module_id(current_id, request.name()) {
+                    stack.push(target_id);
+                }
+            }
+        }
+        CompactStr::from(name)
+    }
+
+    /// The name which the module exports its own export `name` with after mangling.
+    fn own_export_name(&self, module_id: ModuleId, name: &str) -> CompactStr {
+        if self.preserved.contains_key(&module_id) {
+            return CompactStr::from(name);
+        }
+        self.short_names.get(name).cloned().unwrap_or_else(|| CompactStr::from(name))
+    }
+
+    /// `import { name } from` and `ns.name`
+    fn rewrite_imports(&mut self, module_id: ModuleId) {
+        let module = &self.index.modules[module_id];
+        for entry in &module.record.import_entries {
+            let ImportImportName::Name(import_name) = &entry.import_name else { continue };
+            if entry.is_type {
+                continue;
+            }
+            let Some(target_id) =
+                self.index.loaded_module_id(module_id, entry.module_request.name())
+            else {
+                continue;
+            };
+            let name = self.export_name(target_id, import_name.name());
+            if name == *import_name.name() {
+                continue;
+            }
+            if import_name.span() == entry.local_name.span() {
+                // `import { a }` -> `import { b as a }`
+                let replacement = format!("{name} as {}", entry.local_name.name());
+                self.push(module_id, import_name.span(), replacement);
+            } else {
+                self.edits.push(self.index.edit(module_id, import_name.span(), &name));
+            }
+        }
+        for access in &module.member_accesses {
+            let object = ProjectSymbol { module_id, symbol_id: access.object };
+            let Some(ExportTarget::Namespace(target_id)) = self.index.resolve_import(object) else {
+                continue;
+            };
+            let name = self.export_name(target_id, &access.property);
+            if name != access.property {
+                self.edits.push(Edit { module_id, span: access.span, replacement: name });
+            }
+        }
+    }
+
+    /// `export { name }`, `export { name } from` and `export const name`
+    fn rewrite_exports(&mut self, module_id: ModuleId) {
+        let index = self.index;
+        let module = &index.modules[module_id];
+        // The exported names of each `export` declaration, with their new export names.
+        let mut declarations: Vec<(Span, Vec<(CompactStr, CompactStr)>)> = vec![];
+        for entry in
+            module.record.local_export_entries.iter().chain(&module.record.indirect_export_entries)
+        {
+            let ExportExportName::Name(export_name) = &entry.export_name else { continue };
+            let old_name = export_name.name();
+            let new_name = self.own_export_name(module_id, old_name);
+            let span = export_name.span();
+            if entry.module_request.is_none() {
+                let shorthand = matches!(
+                    &entry.local_name,
+                    ExportLocalName::Name(local_name) if local_name.span() == span
+                );
+                if !shorthand {
+                    if new_name != *old_name {
+                        self.edits.push(index.edit(module_id, span, &new_name));
+                    }
+                } else if span != entry.span {
+                    // `export const a = 1`, the exported name is the declared binding.
+                    match declarations.iter_mut().find(|(decl_span, _)| *decl_span == entry.span) {
+                        Some((_, names)) => names.push((old_name.clone(), new_name)),
+                        None => declarations.push((entry.span, vec![(old_name.clone(), new_name)])),
+                    }
+                } else if new_name != *old_name {
+                    // `export { a }` -> `export { a as b }`
+                    self.push(module_id, span, format!("{old_name} as {new_name}"));
+                }
+                continue;
+            }
+            // `export { a } from "./a"` and `export * as a from "./a"`
+            let ExportImportName::Name(import_name) = &entry.import_name else {
+                if new_name != *old_name {
+                    self.edits.push(index.edit(module_id, span, &new_name));
+                }
+                continue;
+            };
+            let imported = entry
+                .module_request
+                .as_ref()
+                .and_then(|request| index.loaded_module_id(module_id, request.name()))
+                .map_or_else(
+                    || import_name.name().clone(),
+                    |target_id| self.export_name(target_id, import_name.name()),
+                );
+            if import_name.span() != span {
+                if imported != *import_name.name() {
+                    self.edits.push(index.edit(module_id, import_name.span(), &imported));
+                }
+                if new_name != *old_name {
+                    self.edits.push(index.edit(module_id, span, &new_name));
+                }
+            } else if imported != new_name {
+                // `export { a } from` -> `export { b as c } from`
+                let imported = index.edit(module_id, span, &imported).replacement;
+                let exported = index.edit(module_id, span, &new_name).replacement;
+                self.push(module_id, span, format!("{imported} as {exported}"));
+            } else if new_name != *old_name {
+                self.edits.push(index.edit(module_id, span, &new_name));
+            }
+        }
+
+        for (decl_span, names) in declarations {
+            if names.iter().all(|(old_name, new_name)| old_name == new_name) {
+                continue;
+            }
+            // `export const a = 1` -> `const a = 1;\nexport { a as b };`
+            let Some(export_start) = module.source_text[..decl_span.start as usize].rfind("export")
+            else {
+                continue;
+            };
+            #[allow(clippy::cast_possible_truncation)]
+            let export_start = export_start as u32;
+            self.push(module_id, Span::new(export_start, decl_span.start), String::new());
+            let specifiers = names
+                .iter()
+                .map(|(old_name, new_name)| {
+                    if old_name == new_name {
+                        old_name.to_string()
+                    } else {
+                        format!("{old_name} as {new_name}")
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            let end = decl_span.end;
+            self.push(module_id, Span::new(end, end), format!("\nexport {{ {specifiers} }};"));
+        }
+    }
+
+    fn push(&mut self, module_id: ModuleId, span: Span, replacement: String) {
+        self.edits.push(Edit { module_id, span, replacement: CompactStr::from(replacement) });
+    }
+}
@@ -0,0 +1,136 @@
+//! Project-wide rename
+//!
+//! Renaming a binding also renames the bindings which import it under the same name with
+//! `import { name }`, and the names it is exported and imported with when they are the same as
+//! the binding, so that `export { name }` and its imports keep working. Names which differ from
+//! the binding, such as `b` in `export { a as b }`, are kept.
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{CompactStr, Span};
+use oxc_syntax::identifier::is_identifier_name;
+use rustc_hash::FxHashSet;
+
+use crate::{ExportTarget, ModuleId, ProjectIndex, ProjectSymbol};
+
+/// Replace the text at `span` of a module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    pub module_id: ModuleId,
+    pub span: Span,
+    pub replacement: CompactStr,
+}
+
+/// The edits for a rename, sorted by module and position.
+#[derive(Debug, Default)]
+pub struct RenamePlan {
+    pub edits: Vec<Edit>,
+}
+
+impl RenamePlan {
+    /// The modules which are changed by the rename.
+    pub fn module_ids(&self) -> Vec<ModuleId> {
+        let mut module_ids: Vec<_> = self.edits.iter().map(|edit| edit.module_id).collect();
+        module_ids.dedup();
+        module_ids
+    }
+
+    /// The source text of the module after the rename.
+    pub fn apply(&self, index: &ProjectIndex, module_id: ModuleId) -> String {
+        let source_text = index.module(module_id).source_text();
+        let mut output = String::with_capacity(source_text.len());
+        let mut last = 0;
+        for edit in self.edits.iter().filter(|edit| edit.module_id == module_id) {
+            output.push_str(&source_text[last..edit.span.start as usize]);
+            output.push_str(&edit.replacement);
+            last = edit.span.end as usize;
+        }
+        output.push_str(&source_text[last..]);
+        output
+    }
+}
+
+impl ProjectIndex {
+    /// Plan renaming the binding of `symbol` to `new_name` across the project.
+    ///
+    /// # Errors
+    ///
+    /// * `new_name` is not an identifier.
+    /// * `new_name` would shadow or be shadowed by another binding, or is already exported by a
+    ///   module which would export the renamed binding.
+    pub fn rename(
+        &self,
+        symbol: ProjectSymbol,
+        new_name: &str,
+    ) -> Result<RenamePlan, OxcDiagnostic> {
+        if !is_identifier_name(new_name) {
+            return Err(OxcDiagnostic::error(format!("`{new_name}` is not a valid identifier")));
+        }
+        let declaration = self.declaration(symbol);
+        let module = self.module(declaration.module_id);
+        let old_name = module.symbols().get_name(declaration.symbol_id);
+        let target = ExportTarget::Symbol(declaration);
+
+        let mut edits = vec![];
+        let mut bindings = vec![declaration];
+        let mut export_spans = FxHashSet::default();
+        for (module_id, span, name) in self.export_name_locations(target) {
+            if name != old_name {
+                continue;
+            }
+            export_spans.insert((module_id, span));
+            edits.push(self.edit(module_id, span, new_name));
+            let Some(existing) = self.resolve_export(module_id, new_name) else { continue };
+            if existing != target {
+                let path = self.module(module_id).path().display();
+                return Err(OxcDiagnostic::error(format!("{path} already exports `{new_name}`")));
+            }
+        }
+        // `import { name }`, where the imported name is also the local binding.
+        for (&import, &import_target) in &self.imports {
+            let span = self.module(import.module_id).symbols().get_span(import.symbol_id);
+            if import_target == target && export_spans.contains(&(import.module_id, span)) {
+                bindings.push(import);
+            }
+        }
+        for binding in bindings {
+            self.check_conflict(binding, new_name)?;
+            for location in self.binding_locations(binding) {
+                edits.push(self.edit(location.module_id, location.span, new_name));
+            }
+        }
+
+        edits.sort_unstable_by_key(|edit| (edit.module_id, edit.span.start));
+        edits.dedup_by_key(|edit| (edit.module_id, edit.span.start));
+        Ok(RenamePlan { edits })
+    }
+
+    /// Module export names can be strings, `export { a as "a" }`, which stay strings.
+    pub(crate) fn edit(&self, module_id: ModuleId, span: Span, new_name: &str) -> Edit {
+        let text = span.source_text(self.module(module_id).source_text());
+        let replacement = match text.chars().next() {
+            Some(quote @ ('"' | '\'')) => CompactStr::from(format!("{quote}{new_name}{quote}")),
+            _ => CompactStr::from(new_name),
+        };
+        Edit { module_id, span, replacement }
+    }
+
+    /// Whether renaming `binding` to `new_name` changes which binding a name refers to: another
+    /// binding named `new_name` is declared in its scope or a scope inside it, or `new_name` is a
+    /// global which is referenced in the module.
+    fn check_conflict(&self, binding: ProjectSymbol, new_name: &str) -> Result<(), OxcDiagnostic> {
+        let module = self.module(binding.module_id);
+        let scopes = module.scopes();
+        let scope_id = module.symbols().get_scope_id(binding.symbol_id);
+        let conflict = std::iter::once(scope_id)
+            .chain(scopes.descendants(scope_id))
+            .any(|scope_id| scopes.has_binding(scope_id, new_name))
+            || scopes.root_unresolved_references().contains_key(new_name);
+        if conflict {
+            let path = module.path().display();
+            return Err(OxcDiagnostic::error(format!(
+                "Renaming to `{new_name}` conflicts with another `{new_name}` in {path}"
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,385 @@
+use std::{fs, path::PathBuf};
+
+use oxc_project::{
+    DeclarationBundle, DeclarationBundler, ExportMangling, ExportTarget, PreserveReason,
+    ProjectIndex, ProjectIndexBuilder, ProjectSymbol,
+};
+use oxc_resolver::{ResolveOptions, Resolver};
+
+struct Project {
+    _dir: tempfile::TempDir,
+    index: ProjectIndex,
+    paths: Vec<PathBuf>,
+}
+
+impl Project {
+    fn new(files: &[(&str, &str)]) -> Self {
+        let dir = tempfile::tempdir().unwrap();
+        let mut builder = ProjectIndexBuilder::new(Resolver::new(ResolveOptions::default()));
+        let paths = files
+            .iter()
+            .map(|(name, source_text)| {
+                let path = dir.path().join(name);
+                fs::write(&path, source_text).unwrap();
+                builder.add_file(&path).unwrap();
+                fs::canonicalize(path).unwrap()
+            })
+            .collect();
+        Self { _dir: dir, index: builder.build(), paths }
+    }
+
+    /// The top level binding `name` of the file at `file`.
+    fn symbol(&self, file: usize, name: &str) -> ProjectSymbol {
+        let module_id = self.index.module_id(&self.paths[file]).unwrap();
+        self.index.root_symbol(module_id, name).unwrap()
+    }
+
+    /// The text of every reference to the binding `name` of the file at `file`, as `file:text`.
+    fn references(&self, file: usize, name: &str) -> Vec<String> {
+        self.index
+            .find_references(self.symbol(file, name))
+            .into_iter()
+            .map(|location| {
+                let module = self.index.module(location.module_id);
+                let file = self.paths.iter().position(|path| path == module.path()).unwrap();
+                format!("{file}:{}", location.span.source_text(module.source_text()))
+            })
+            .collect()
+    }
+
+    /// The source text of every file after renaming the binding `name` of the file at `file`.
+    fn rename(&self, file: usize, name: &str, new_name: &str) -> Result<Vec<String>, String> {
+        let plan =
+            self.index.rename(self.symbol(file, name), new_name).map_err(|e| e.to_string())?;
+        Ok(self
+            .paths
+            .iter()
+            .map(|path| plan.apply(&self.index, self.index.module_id(path).unwrap()))
+            .collect())
+    }
+
+    /// The source text of every file after mangling the exports, with the files at `entries` as
+    /// the entry modules.
+    fn mangle_exports(&self, entries: &[usize]) -> (Vec<String>, ExportMangling) {
+        let module_id = |path| self.index.module_id(path).unwrap();
+        let entries: Vec<_> = entries.iter().map(|&file| module_id(&self.paths[file])).collect();
+        let mangling = self.index.mangle_exports(&entries);
+        let sources = self
+            .paths
+            .iter()
+            .map(|path| mangling.plan.apply(&self.index, module_id(path)))
+            .collect();
+        (sources, mangling)
+    }
+}
+
+#[test]
+fn imports() {
+    let project = Project::new(&[
+        ("a.js", "export const a = 1; export default function f() {}"),
+        ("b.js", "import { a } from './a.js'; import f from './a.js'; a; f()"),
+        ("c.js", "import * as ns from './a.js'; import { b } from 'external'; ns.a"),
+    ]);
+    let a = project.symbol(0, "a");
+    assert_eq!(project.index.resolve_import(project.symbol(1, "a")), Some(ExportTarget::Symbol(a)));
+    assert_eq!(project.index.declaration(project.symbol(1, "f")), project.symbol(0, "f"));
+    let namespace = project.index.module_id(&project.paths[0]).unwrap();
+    assert_eq!(
+        project.index.resolve_import(project.symbol(2, "ns")),
+        Some(ExportTarget::Namespace(namespace))
+    );
+    assert_eq!(project.index.resolve_import(project.symbol(2, "b")), None);
+}
+
+#[test]
+fn reexports() {
+    let project = Project::new(&[
+        ("a.js", "export const a = 1; export default 2"),
+        ("b.js", "export { a as b } from './a.js'; export * from './a.js'"),
+        ("c.js", "import { b, a } from './b.js'; export { b as c }"),
+        ("d.js", "import { c } from './c.js'; import d from './a.js'"),
+    ]);
+    let a = ExportTarget::Symbol(project.symbol(0, "a"));
+    let b = project.index.module_id(&project.paths[1]).unwrap();
+    let c = project.index.module_id(&project.paths[2]).unwrap();
+    assert_eq!(project.index.resolve_export(b, "b"), Some(a));
+    assert_eq!(project.index.resolve_export(b, "a"), Some(a));
+    assert_eq!(project.index.resolve_export(b, "default"), None);
+    assert_eq!(project.index.resolve_export(c, "c"), Some(a));
+    assert_eq!(project.index.resolve_import(project.symbol(3, "c")), Some(a));
+    let default = project.index.module_id(&project.paths[0]).unwrap();
+    assert_eq!(
+        project.index.resolve_import(project.symbol(3, "d")),
+        Some(ExportTarget::DefaultExpression(default))
+    );
+}
+
+#[test]
+fn circular_imports() {
+    let project = Project::new(&[
+        ("a.js", "import { x } from './b.js'; export { x }"),
+        ("b.js", "import { x } from './a.js'; export { x }"),
+    ]);
+    assert_eq!(project.index.resolve_import(project.symbol(0, "x")), None);
+}
+
+#[test]
+fn find_references() {
+    let project = Project::new(&[
+        ("a.js", "export const a = 1; a + 1"),
+        ("b.js", "import { a } from './a.js'; import { a as x } from './a.js'; a; x"),
+        ("c.js", "import * as ns from './a.js'; ns.a; ns.b"),
+        ("d.js", "export { a as b } from './a.js'"),
+    ]);
+    let references = ["0:a", "0:a", "1:a", "1:a", "1:x", "1:a", "1:x", "2:a", "3:a", "3:b"];
+    assert_eq!(project.references(0, "a"), references);
+    // From an import binding
+    assert_eq!(project.references(1, "x"), references);
+}
+
+#[test]
+fn rename() {
+    let project = Project::new(&[
+        ("a.js", "export const a = 1; export { a as b }; a + 1"),
+        ("b.js", "import { a, b } from './a.js'; import { a as x } from './a.js'; a; x; b"),
+        ("c.js", "import * as ns from './a.js'; export { a } from './a.js'; ns.a"),
+    ]);
+    assert_eq!(
+        project.rename(0, "a", "value").unwrap(),
+        [
+            "export const value = 1; export { value as b }; value + 1",
+            "import { value, b } from './a.js'; import { value as x } from './a.js'; value; x; b",
+            "import * as ns from './a.js'; export { value } from './a.js'; ns.value",
+        ]
+    );
+}
+
+#[test]
+fn rename_conflicts() {
+    let project = Project::new(&[
+        ("a.js", "export const a = 1; export const b = 2"),
+        ("b.js", "import { a } from './a.js'; function f() { let c; a }"),
+        ("c.js", "import { a as x } from './a.js'; d"),
+    ]);
+    assert!(project.rename(0, "a", "b").is_err());
+    assert!(project.rename(0, "a", "c").is_err());
+    assert!(project.rename(0, "a", "d").is_ok());
+    assert!(project.rename(0, "a", "1d").is_err());
+    assert!(project.rename(0, "a", "e").is_ok());
+}
+
+#[test]
+fn mangle_exports() {
+    let project = Project::new(&[
+        (
+            "a.js",
+            "import { formatDate, VERSION } from './utils.js'; import * as math from './math.js';\n\
+             export const main = () => formatDate(VERSION) + math.add(1, 2);",
+        ),
+        ("utils.js", "export function formatDate(date) { return date }\nexport const VERSION = 1, NAME = 'x';"),
+        ("math.js", "const add = (a, b) => a + b;\nexport { add };\nexport { add as sum };"),
+    ]);
+    let (sources, mangling) = project.mangle_exports(&[0]);
+    assert_eq!(
+        sources,
+        [
+            "import { d as formatDate, b as VERSION } from './utils.js'; import * as math from './math.js';\n\
+             export const main = () => formatDate(VERSION) + math.c(1, 2);",
+            "function formatDate(date) { return date }\nexport { formatDate as d };\n\
+             const VERSION = 1, NAME = 'x';\nexport { VERSION as b, NAME as a };",
+            "const add = (a, b) => a + b;\nexport { add as c };\nexport { add as e };",
+        ]
+    );
+    let renamed: Vec<_> =
+        mangling.renamed.iter().map(|(name, short_name)| format!("{name}:{short_name}")).collect();
+    assert_eq!(renamed, ["NAME:a", "VERSION:b", "add:c", "formatDate:d", "sum:e"]);
+    assert_eq!(mangling.preserved.len(), 1);
+    assert_eq!(mangling.preserved[0].names, ["main"]);
+}
+
+#[test]
+fn mangle_exports_preserved() {
+    let project = Project::new(&[
+        (
+            "entry.js",
+            "export * from './lib.js'; import { helper } from './internal.js'; helper(); import('./lazy.js')",
+        ),
+        ("lib.js", "export const api = 1"),
+        ("internal.js", "export { helper } from './helper.js'; export const unused = 2;"),
+        ("helper.js", "export function helper() {}"),
+        ("lazy.js", "export const chunk = 1"),
+    ]);
+    let (sources, mangling) = project.mangle_exports(&[0]);
+    assert_eq!(
+        sources,
+        [
+            "export * from './lib.js'; import { a as helper } from './internal.js'; helper(); import('./lazy.js')",
+            "export const api = 1",
+            "export { a } from './helper.js'; const unused = 2;\nexport { unused as b };",
+            "function helper() {}\nexport { helper as a };",
+            "export const chunk = 1",
+        ]
+    );
+    let reasons: Vec<_> = mangling.preserved.iter().map(|preserved| preserved.reason).collect();
+    assert_eq!(
+        reasons,
+        [PreserveReason::Entry, PreserveReason::StarExport, PreserveReason::DynamicImport]
+    );
+    let manifest = mangling.manifest(&project.index);
+    assert!(manifest.contains(r#""reason": "star-export""#));
+    assert!(manifest.contains(r#""helper": "a""#));
+
+    // The namespace object may be accessed with any name.
+    let project = Project::new(&[
+        ("a.js", "import * as ns from './b.js'; console.log(ns)"),
+        ("b.js", "export const b = 1"),
+    ]);
+    let (sources, mangling) = project.mangle_exports(&[0]);
+    assert_eq!(sources[1], "export const b = 1");
+    assert_eq!(mangling.preserved[1].reason, PreserveReason::Namespace);
+
+    // Any module may be loaded.
+    let project = Project::new(&[
+        ("a.js", "import { b } from './b.js'; import(b)"),
+        ("b.js", "export const b = './c.js'"),
+    ]);
+    let (_, mangling) = project.mangle_exports(&[0]);
+    assert!(mangling.plan.edits.is_empty());
+}
+
+/// Bundle the declaration files, with the first file as the entry.
+fn bundle_declarations(files: &[(&str, &str)]) -> Result<DeclarationBundle, Vec<String>> {
+    let dir = tempfile::tempdir().unwrap();
+    for (name, source_text) in files {
+        fs::write(dir.path().join(name), source_text).unwrap();
+    }
+    let resolver = Resolver::new(ResolveOptions {
+        extensions: vec![".d.ts".into()],
+        ..ResolveOptions::default()
+    });
+    DeclarationBundler::new(resolver)
+        .bundle(&dir.path().join(files[0].0))
+        .map_err(|errors| errors.iter().map(ToString::to_string).collect())
+}
+
+#[test]
+fn bundle_declarations_conflicts() {
+    let bundle = bundle_declarations(&[
+        (
+            "index.d.ts",
+            "import { Options as BaseOptions } from './base';
+import type { Readable } from 'stream';
+export interface Options extends BaseOptions {
+    stream: Readable;
+}
+export declare function run(options: Options): void;
+",
+        ),
+        (
+            "base.d.ts",
+            "import { Writable } from 'stream';
+export interface Options {
+    output: Writable;
+}
+export declare function run(options: Options): void;
+export declare function run(options: Options, output: Writable): void;
+",
+        ),
+    ])
+    .unwrap();
+    assert_eq!(
+        bundle.source_text,
+        "import { Writable, Readable } from \"stream\";
+interface Options$1 {
+    output: Writable;
+}
+declare function run$1(options: Options$1): void;
+declare function run$1(options: Options$1, output: Writable): void;
+interface Options extends Options$1 {
+    stream: Readable;
+}
+declare function run(options: Options): void;
+export { Options, run };
+"
+    );
+    let renamed: Vec<_> = bundle
+        .renamed
+        .iter()
+        .map(|(path, name, new_name)| {
+            (path.file_name().unwrap().to_str().unwrap(), name.as_str(), new_name.as_str())
+        })
+        .collect();
+    assert_eq!(renamed, [("base.d.ts", "Options", "Options$1"), ("base.d.ts", "run", "run$1")]);
+}
+
+#[test]
+fn bundle_declarations_namespaces() {
+    let bundle = bundle_declarations(&[
+        (
+            "index.d.ts",
+            "/// <reference types=\"node\" />
+import * as shapes from './shapes';
+export * from './utils';
+export declare function area(shape: shapes.Shape): number;
+declare module 'canvas' {
+    interface Context {
+        shape: shapes.Shape;
+    }
+}
+",
+        ),
+        (
+            "shapes.d.ts",
+            "export interface Shape {
+    kind: string;
+}
+export declare const unit: Shape;
+",
+        ),
+        (
+            "utils.d.ts",
+            "/// <reference types=\"node\" />
+export type Point = [number, number];
+export * from 'events';
+",
+        ),
+    ])
+    .unwrap();
+    assert_eq!(
+        bundle.source_text,
+        "/// <reference types=\"node\" />
+interface Shape {
+    kind: string;
+}
+declare const unit: Shape;
+type Point = [number, number];
+declare function area(shape: shapes.Shape): number;
+declare module 'canvas' {
+    interface Context {
+        shape: shapes.Shape;
+    }
+}
+declare namespace shapes {
+\texport { Shape, unit };
+}
+export { area, Point };
+export * from \"events\";
+"
+    );
+    assert!(bundle.renamed.is_empty());
+}
+
+#[test]
+fn bundle_declarations_errors() {
+    let errors = bundle_declarations(&[
+        ("index.d.ts", "import { Missing } from './a';\nexport declare const a: Missing;"),
+        ("a.d.ts", "export interface Present {}"),
+    ])
+    .unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("does not export `Missing`"));
+
+    let errors = bundle_declarations(&[("index.d.ts", "declare const a: number;\nexport = a;")])
+        .unwrap_err();
+    assert!(errors[0].contains("`export =` is not supported"));
+}
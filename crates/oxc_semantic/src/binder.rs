@@ -10,7 +10,10 @@ use oxc_ast::{
 };
 use oxc_span::SourceType;
 
-use crate::{scope::ScopeFlags, symbol::SymbolFlags, SemanticBuilder};
+use crate::{
+    diagnostics::duplicate_function_implementation, scope::ScopeFlags, symbol::SymbolFlags,
+    SemanticBuilder,
+};
 
 pub trait Binder {
     fn bind(&self, _builder: &mut SemanticBuilder) {}
@@ -58,7 +61,7 @@ impl<'a> Binder for VariableDeclarator<'a> {
 
             for scope_id in &var_scope_ids {
                 if let Some(symbol_id) =
-                    builder.check_redeclaration(*scope_id, span, name, excludes, true)
+                    builder.check_redeclaration(*scope_id, span, name, includes, excludes, true)
                 {
                     ident.symbol_id.set(Some(symbol_id));
                     builder.add_redeclare_variable(symbol_id, ident.span);
@@ -79,15 +82,14 @@ impl<'a> Binder for VariableDeclarator<'a> {
 impl<'a> Binder for Class<'a> {
     fn bind(&self, builder: &mut SemanticBuilder) {
         let Some(ident) = &self.id else { return };
-        if !self.modifiers.contains(ModifierKind::Declare) {
-            let symbol_id = builder.declare_symbol(
-                ident.span,
-                &ident.name,
-                SymbolFlags::Class,
-                SymbolFlags::ClassExcludes,
-            );
-            ident.symbol_id.set(Some(symbol_id));
-        }
+        let includes = if self.modifiers.contains(ModifierKind::Declare) {
+            SymbolFlags::Class | SymbolFlags::Ambient
+        } else {
+            SymbolFlags::Class
+        };
+        let symbol_id =
+            builder.declare_symbol(ident.span, &ident.name, includes, SymbolFlags::ClassExcludes);
+        ident.symbol_id.set(Some(symbol_id));
     }
 }
 
@@ -111,35 +113,71 @@ impl<'a> Binder for Function<'a> {
             {
                 // Do not declare in if single statements,
                 // if (false) function f() {} else function g() { }
-            } else if self.r#type == FunctionType::FunctionDeclaration {
+            } else if self.r#type == FunctionType::FunctionDeclaration
+                || (self.r#type == FunctionType::TSDeclareFunction
+                    && builder.source_type.is_typescript_definition())
+            {
                 // The visitor is already inside the function scope,
                 // retrieve the parent scope for the function id to bind to.
                 let parent_scope_id = builder.scope.get_parent_id(current_scope_id).unwrap();
                 let parent_flags = builder.scope.get_flags(parent_scope_id);
 
-                let (includes, excludes) =
-                    if (parent_flags.is_strict_mode() || self.r#async || self.generator)
-                        && !function_as_var(parent_flags, builder.source_type)
-                    {
-                        (
-                            SymbolFlags::Function | SymbolFlags::BlockScopedVariable,
-                            SymbolFlags::BlockScopedVariableExcludes,
-                        )
-                    } else {
-                        (
-                            SymbolFlags::FunctionScopedVariable,
-                            SymbolFlags::FunctionScopedVariableExcludes,
-                        )
-                    };
-
-                let symbol_id = builder.declare_symbol_on_scope(
-                    ident.span,
-                    &ident.name,
-                    parent_scope_id,
-                    includes,
-                    excludes,
-                );
-                ident.symbol_id.set(Some(symbol_id));
+                // Declaration files only have functions without a body,
+                // the overloads of `declare function f(): void` are redeclarations.
+                let overloaded =
+                    builder.scope.get_binding(parent_scope_id, &ident.name).filter(|&symbol_id| {
+                        self.r#type == FunctionType::TSDeclareFunction
+                            && builder.symbols.get_flag(symbol_id).contains(SymbolFlags::Function)
+                    });
+                if let Some(symbol_id) = overloaded {
+                    builder.add_redeclare_variable(symbol_id, ident.span);
+                    ident.symbol_id.set(Some(symbol_id));
+                } else {
+                    let (includes, excludes) =
+                        if (parent_flags.is_strict_mode() || self.r#async || self.generator)
+                            && !function_as_var(parent_flags, builder.source_type)
+                        {
+                            (
+                                SymbolFlags::Function | SymbolFlags::BlockScopedVariable,
+                                SymbolFlags::BlockScopedVariableExcludes,
+                            )
+                        } else {
+                            (
+                                SymbolFlags::FunctionScopedVariable,
+                                SymbolFlags::FunctionScopedVariableExcludes,
+                            )
+                        };
+
+                    // `function f() {} function f() {}` is allowed in JavaScript, but not in TypeScript.
+                    // Conflicting declarations are reported by `declare_symbol_on_scope`.
+                    if builder.source_type.is_typescript() {
+                        if let Some(symbol_id) =
+                            builder.scope.get_binding(parent_scope_id, &ident.name)
+                        {
+                            if !builder.symbols.get_flag(symbol_id).intersects(excludes)
+                                && builder.is_function_implementation(
+                                    builder.symbols.get_declaration(symbol_id),
+                                )
+                                && builder.is_function_implementation(builder.current_node_id)
+                            {
+                                builder.error(duplicate_function_implementation(
+                                    &ident.name,
+                                    builder.symbols.get_span(symbol_id),
+                                    ident.span,
+                                ));
+                            }
+                        }
+                    }
+
+                    let symbol_id = builder.declare_symbol_on_scope(
+                        ident.span,
+                        &ident.name,
+                        parent_scope_id,
+                        includes,
+                        excludes,
+                    );
+                    ident.symbol_id.set(Some(symbol_id));
+                }
             } else if self.r#type == FunctionType::FunctionExpression {
                 // https://tc39.es/ecma262/#sec-runtime-semantics-instantiateordinaryfunctionexpression
                 // 5. Perform ! funcEnv.CreateImmutableBinding(name, false).
@@ -7,8 +7,7 @@ use oxc_ast::{ast::*, AstKind, Trivias, Visit};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{CompactStr, SourceType, Span};
 use oxc_syntax::{
-    identifier::is_identifier_name,
-    module_record::{ExportImportName, ExportLocalName, ModuleRecord},
+    module_record::{ExportImportName, ModuleRecord},
     operator::AssignmentOperator,
 };
 
@@ -19,17 +18,27 @@ use crate::{
     control_flow::{
         AssignmentValue, ControlFlowGraphBuilder, EdgeType, Register, StatementControlFlowType,
     },
-    diagnostics::redeclaration,
+    diagnostics::{
+        block_scoped_redeclaration, duplicate_function_implementation, duplicate_identifier,
+        redeclaration,
+    },
     jsdoc::JSDocBuilder,
     label::LabelBuilder,
     module_record::ModuleRecordBuilder,
     node::{AstNode, AstNodeId, AstNodes, NodeFlags},
+    purity,
     reference::{Reference, ReferenceFlag, ReferenceId},
     scope::{ScopeFlags, ScopeId, ScopeTree},
     symbol::{SymbolFlags, SymbolId, SymbolTable},
     Semantic,
 };
 
+/// [NodeFlags] which describe the enclosing function rather than the Node itself.
+const FUNCTION_CONTEXT_FLAGS: NodeFlags = NodeFlags::Async
+    .union(NodeFlags::Generator)
+    .union(NodeFlags::Loop)
+    .union(NodeFlags::TryFinally);
+
 pub struct SemanticBuilder<'a> {
     pub source_text: &'a str,
 
@@ -142,15 +151,17 @@ impl<'a> SemanticBuilder<'a> {
     }
 
     pub fn build(mut self, program: &Program<'a>) -> SemanticBuilderReturn<'a> {
-        if self.source_type.is_typescript_definition() {
-            self.scope.add_scope(None, ScopeFlags::Top);
-        } else {
-            self.visit_program(program);
+        self.visit_program(program);
+        self.symbols.no_side_effects = purity::find_no_side_effects(
+            &self.nodes,
+            &self.symbols,
+            &self.trivias,
+            self.source_text,
+        );
 
-            // Checking syntax error on module record requires scope information from the previous AST pass
-            if self.check_syntax_error {
-                EarlyErrorJavaScript::check_module_record(&self);
-            }
+        // Checking syntax error on module record requires scope information from the previous AST pass
+        if self.check_syntax_error {
+            EarlyErrorJavaScript::check_module_record(&self);
         }
 
         let semantic = Semantic {
@@ -225,12 +236,43 @@ impl<'a> SemanticBuilder<'a> {
             || self.current_node_flags.contains(NodeFlags::Class)
     }
 
+    /// Mark the current scope and its ancestors as containing a direct `eval` or `with`.
+    ///
+    /// Every binding visible from the current scope can then be referenced by name at runtime,
+    /// which makes renaming or removing them unsafe.
+    fn mark_scope_contains_direct_eval(&mut self) {
+        let mut scope_id = Some(self.current_scope_id);
+        while let Some(id) = scope_id {
+            let flags = self.scope.get_flags_mut(id);
+            if flags.contains_direct_eval() {
+                // Ancestors have been marked already.
+                break;
+            }
+            *flags |= ScopeFlags::ContainsDirectEval;
+            scope_id = self.scope.get_parent_id(id);
+        }
+    }
+
     pub fn set_function_node_flag(&mut self, flag: NodeFlags) {
         if let Some(current_function) = self.function_stack.last() {
             *self.nodes.get_node_mut(*current_function).flags_mut() |= flag;
         }
     }
 
+    /// Enter a function or a class static block, where `await`, `yield`, loops and `finally`
+    /// blocks of the enclosing function no longer apply.
+    ///
+    /// Returns the flags to restore with [Self::leave_function_context].
+    fn enter_function_context(&mut self, flags: NodeFlags) -> NodeFlags {
+        let saved = self.current_node_flags & FUNCTION_CONTEXT_FLAGS;
+        self.current_node_flags = (self.current_node_flags - FUNCTION_CONTEXT_FLAGS) | flags;
+        saved
+    }
+
+    fn leave_function_context(&mut self, saved: NodeFlags) {
+        self.current_node_flags = (self.current_node_flags - FUNCTION_CONTEXT_FLAGS) | saved;
+    }
+
     /// Declares a `Symbol` for the node, adds it to symbol table, and binds it to the scope.
     ///
     /// includes: the `SymbolFlags` that node has in addition to its declaration type (eg: export, ambient, etc.)
@@ -245,7 +287,9 @@ impl<'a> SemanticBuilder<'a> {
         includes: SymbolFlags,
         excludes: SymbolFlags,
     ) -> SymbolId {
-        if let Some(symbol_id) = self.check_redeclaration(scope_id, span, name, excludes, true) {
+        if let Some(symbol_id) =
+            self.check_redeclaration(scope_id, span, name, includes, excludes, true)
+        {
             self.symbols.union_flag(symbol_id, includes);
             self.add_redeclare_variable(symbol_id, span);
             return symbol_id;
@@ -273,17 +317,55 @@ impl<'a> SemanticBuilder<'a> {
         scope_id: ScopeId,
         span: Span,
         name: &str,
+        includes: SymbolFlags,
         excludes: SymbolFlags,
         report_error: bool,
     ) -> Option<SymbolId> {
         let symbol_id = self.scope.get_binding(scope_id, name)?;
         if report_error && self.symbols.get_flag(symbol_id).intersects(excludes) {
-            let symbol_span = self.symbols.get_span(symbol_id);
-            self.error(redeclaration(name, symbol_span, span));
+            self.error(self.redeclaration_error(symbol_id, span, name, includes));
         }
         Some(symbol_id)
     }
 
+    /// TypeScript files report the same categories as `tsc`, with their error codes:
+    /// TS2451 if either declaration is `let` or `const`, TS2393 for two function implementations,
+    /// and TS2300 for everything else.
+    fn redeclaration_error(
+        &self,
+        symbol_id: SymbolId,
+        span: Span,
+        name: &str,
+        includes: SymbolFlags,
+    ) -> OxcDiagnostic {
+        let symbol_span = self.symbols.get_span(symbol_id);
+        if !self.source_type.is_typescript() {
+            return redeclaration(name, symbol_span, span);
+        }
+        let is_block_scoped_variable = |flags: SymbolFlags| {
+            flags.contains(SymbolFlags::BlockScopedVariable) && !flags.is_function()
+        };
+        if is_block_scoped_variable(self.symbols.get_flag(symbol_id))
+            || is_block_scoped_variable(includes)
+        {
+            block_scoped_redeclaration(name, symbol_span, span)
+        } else if self.is_function_implementation(self.symbols.get_declaration(symbol_id))
+            && self.is_function_implementation(self.current_node_id)
+        {
+            duplicate_function_implementation(name, symbol_span, span)
+        } else {
+            duplicate_identifier(name, symbol_span, span)
+        }
+    }
+
+    /// Whether the node is a function declaration with a body, i.e. not an overload signature.
+    pub(crate) fn is_function_implementation(&self, node_id: AstNodeId) -> bool {
+        matches!(
+            self.nodes.kind(node_id),
+            AstKind::Function(func) if func.is_function_declaration() && func.body.is_some()
+        )
+    }
+
     pub fn declare_reference(
         &mut self,
         reference: Reference,
@@ -335,10 +417,10 @@ impl<'a> SemanticBuilder<'a> {
             self.scope.get_parent_id(self.current_scope_id).unwrap_or(self.current_scope_id);
 
         if let Some(symbol_id) = self.scope.get_binding(self.current_scope_id, &name) {
-            for reference_id in &reference_ids {
-                self.symbols.references[*reference_id].set_symbol_id(symbol_id);
+            for reference_id in reference_ids {
+                self.symbols.references[reference_id].set_symbol_id(symbol_id);
+                self.symbols.add_resolved_reference(symbol_id, reference_id);
             }
-            self.symbols.resolved_references[symbol_id].extend(reference_ids);
         } else {
             self.scope.extend_unresolved_reference(parent_scope_id, name, reference_ids);
         }
@@ -348,7 +430,7 @@ impl<'a> SemanticBuilder<'a> {
         self.symbols.add_redeclare_variable(symbol_id, span);
     }
 
-    fn add_export_flag_for_export_identifier(&mut self) {
+    fn add_export_flag_for_export_identifier(&mut self, program: &Program<'a>) {
         self.module_record.indirect_export_entries.iter().for_each(|entry| {
             if let ExportImportName::Name(name) = &entry.import_name {
                 if let Some(symbol_id) = self.symbols.get_symbol_id_from_name(name.name()) {
@@ -357,26 +439,34 @@ impl<'a> SemanticBuilder<'a> {
             }
         });
 
-        self.module_record.local_export_entries.iter().for_each(|entry| {
-            match &entry.local_name {
-                ExportLocalName::Name(name_span) => {
-                    if let Some(symbol_id) = self.scope.get_root_binding(name_span.name()) {
-                        self.symbols.union_flag(symbol_id, SymbolFlags::Export);
+        // The local exports are read from the program instead of the module record, which is
+        // only built with `build_module_record`.
+        for stmt in &program.body {
+            match stmt {
+                // export { a, b as c }
+                Statement::ExportNamedDeclaration(decl) if decl.source.is_none() => {
+                    for specifier in &decl.specifiers {
+                        if let ModuleExportName::Identifier(local) = &specifier.local {
+                            self.add_export_flag_for_root_binding(&local.name);
+                        }
                     }
                 }
-                ExportLocalName::Default(_) => {
-                    // export default identifier
-                    //                ^^^^^^^^^^
-                    let identifier = entry.span.source_text(self.source_text);
-                    if is_identifier_name(identifier) {
-                        if let Some(symbol_id) = self.scope.get_root_binding(identifier) {
-                            self.symbols.union_flag(symbol_id, SymbolFlags::Export);
-                        }
+                // export default identifier
+                //                ^^^^^^^^^^
+                Statement::ExportDefaultDeclaration(decl) => {
+                    if let ExportDefaultDeclarationKind::Identifier(ident) = &decl.declaration {
+                        self.add_export_flag_for_root_binding(&ident.name);
                     }
                 }
-                ExportLocalName::Null => {}
+                _ => {}
             }
-        });
+        }
+    }
+
+    fn add_export_flag_for_root_binding(&mut self, name: &str) {
+        if let Some(symbol_id) = self.scope.get_root_binding(name) {
+            self.symbols.union_flag(symbol_id, SymbolFlags::Export);
+        }
     }
 }
 
@@ -527,11 +617,14 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         }
         /* cfg */
         else {
-            self.cfg
-                .basic_blocks_with_breaks
-                .last_mut()
-                .expect("expected there to be a stack of control flows that a break can belong to")
-                .push(self.cfg.current_node_ix);
+            // an unlabeled break jumps out of the innermost loop or switch statement
+            let break_ = self
+                .cfg
+                .unlabeled_break_targets
+                .last()
+                .copied()
+                .unwrap_or(self.cfg.basic_blocks_with_breaks.len() - 1);
+            self.cfg.basic_blocks_with_breaks[break_].push(self.cfg.current_node_ix);
         }
         self.cfg.put_unreachable();
 
@@ -595,11 +688,12 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
                 .expect("expected there to be a stack of control flows that a break can belong to")
                 .push(self.cfg.current_node_ix);
         }
+        // the continue jumps from the basic block it is in
+        let current_node_ix = self.cfg.current_node_ix;
         self.cfg.put_unreachable();
         /* cfg */
 
         /* cfg */
-        let current_node_ix = self.cfg.current_node_ix;
         // todo: assert on this instead when continues which
         // aren't in iterations are nonrecoverable errors
         if let Some(continues) = self.cfg.basic_blocks_with_continues.last_mut() {
@@ -634,6 +728,8 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
     fn visit_do_while_statement(&mut self, stmt: &DoWhileStatement<'a>) {
         let kind = AstKind::DoWhileStatement(self.alloc(stmt));
         self.enter_node(kind);
+        let in_loop = self.current_node_flags.has_loop();
+        self.current_node_flags |= NodeFlags::Loop;
 
         /* cfg */
         let before_do_while_stmt_graph_ix = self.cfg.current_node_ix;
@@ -655,16 +751,14 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
 
         let end_do_while_graph_ix = self.cfg.new_basic_block();
 
-        // before do while to start of condition basic block
-        self.cfg.add_edge(
-            before_do_while_stmt_graph_ix,
-            start_of_condition_graph_ix,
-            EdgeType::Normal,
-        );
+        // before do while to start of body basic block
+        self.cfg.add_edge(before_do_while_stmt_graph_ix, start_body_graph_ix, EdgeType::Normal);
         // body of do-while to start of condition
         self.cfg.add_edge(start_body_graph_ix, start_of_condition_graph_ix, EdgeType::Backedge);
-        // end of condition to after do while
-        self.cfg.add_edge(end_of_condition_graph_ix, end_do_while_graph_ix, EdgeType::Normal);
+        // end of condition to after do while, unless it is `do {} while (true)`
+        if stmt.test.get_boolean_value() != Some(true) {
+            self.cfg.add_edge(end_of_condition_graph_ix, end_do_while_graph_ix, EdgeType::Normal);
+        }
         // end of condition to after start of body
         self.cfg.add_edge(end_of_condition_graph_ix, start_body_graph_ix, EdgeType::Normal);
 
@@ -680,6 +774,7 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         );
         /* cfg */
 
+        self.current_node_flags.set(NodeFlags::Loop, in_loop);
         self.leave_node(kind);
     }
 
@@ -833,6 +928,8 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         if let Some(init) = &stmt.init {
             self.visit_for_statement_init(init);
         }
+        let in_loop = self.current_node_flags.has_loop();
+        self.current_node_flags |= NodeFlags::Loop;
         /* cfg */
         let before_for_graph_ix = self.cfg.current_node_ix;
         let test_graph_ix = self.cfg.new_basic_block();
@@ -864,7 +961,10 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         self.cfg.add_edge(after_test_graph_ix, body_graph_ix, EdgeType::Normal);
         self.cfg.add_edge(body_graph_ix, update_graph_ix, EdgeType::Backedge);
         self.cfg.add_edge(update_graph_ix, test_graph_ix, EdgeType::Backedge);
-        self.cfg.add_edge(after_test_graph_ix, after_for_stmt, EdgeType::Normal);
+        // `for (;;)` and `for (; true;)` are only left by a break
+        if !stmt.test.as_ref().map_or(true, |test| test.get_boolean_value() == Some(true)) {
+            self.cfg.add_edge(after_test_graph_ix, after_for_stmt, EdgeType::Normal);
+        }
 
         self.cfg.after_statement(
             &statement_state,
@@ -878,6 +978,7 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         );
 
         /* cfg */
+        self.current_node_flags.set(NodeFlags::Loop, in_loop);
         self.leave_node(kind);
         if is_lexical_declaration {
             self.leave_scope();
@@ -925,7 +1026,10 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
             self.cfg.before_statement(self.current_node_id, StatementControlFlowType::UsesContinue);
         /* cfg */
 
+        let in_loop = self.current_node_flags.has_loop();
+        self.current_node_flags |= NodeFlags::Loop;
         self.visit_statement(&stmt.body);
+        self.current_node_flags.set(NodeFlags::Loop, in_loop);
 
         /* cfg */
         let end_of_body_graph_ix = self.cfg.current_node_ix;
@@ -995,7 +1099,10 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
             self.cfg.before_statement(self.current_node_id, StatementControlFlowType::UsesContinue);
         /* cfg */
 
+        let in_loop = self.current_node_flags.has_loop();
+        self.current_node_flags |= NodeFlags::Loop;
         self.visit_statement(&stmt.body);
+        self.current_node_flags.set(NodeFlags::Loop, in_loop);
 
         /* cfg */
         let end_of_body_graph_ix = self.cfg.current_node_ix;
@@ -1185,9 +1292,8 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         /* cfg */
         let discriminant_graph_ix = self.cfg.current_node_ix;
         self.cfg.switch_case_conditions.push(vec![]);
-        let statement_state = self
-            .cfg
-            .before_statement(self.current_node_id, StatementControlFlowType::DoesNotUseContinue);
+        let statement_state =
+            self.cfg.before_statement(self.current_node_id, StatementControlFlowType::UsesBreak);
         let mut ends_of_switch_cases = vec![];
         /* cfg */
 
@@ -1231,14 +1337,23 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
             );
         }
 
-        if let Some(last) = switch_case_conditions.last() {
-            self.cfg.add_edge(*last, self.cfg.current_node_ix, EdgeType::Normal);
+        let end_of_switch_cases_graph_ix = self.cfg.current_node_ix;
+        let after_switch_graph_ix = self.cfg.new_basic_block();
+        self.cfg.add_edge(end_of_switch_cases_graph_ix, after_switch_graph_ix, EdgeType::Normal);
+
+        // without a default case, the switch statement is skipped when no case matches
+        if !stmt.cases.iter().any(SwitchCase::is_default_case) {
+            let no_match_graph_ix =
+                switch_case_conditions.last().copied().unwrap_or(discriminant_graph_ix);
+            self.cfg.add_edge(no_match_graph_ix, after_switch_graph_ix, EdgeType::Normal);
         }
 
         self.cfg.after_statement(
             &statement_state,
             self.current_node_id,
-            self.cfg.current_node_ix,
+            // all basic blocks are break here so we connect them to the
+            // basic block after the switch statement
+            after_switch_graph_ix,
             None,
         );
         /* cfg */
@@ -1289,10 +1404,10 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         /* cfg */
 
         self.visit_expression(&stmt.argument);
-        // todo - put unreachable after throw statement
 
         /* cfg */
         self.cfg.put_throw(throw_expr);
+        self.cfg.put_unreachable();
         self.cfg.after_statement(
             &statement_state,
             self.current_node_id,
@@ -1307,6 +1422,10 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
     fn visit_try_statement(&mut self, stmt: &TryStatement<'a>) {
         let kind = AstKind::TryStatement(self.alloc(stmt));
         self.enter_node(kind);
+        let in_try_finally = self.current_node_flags.has_try_finally();
+        if stmt.finalizer.is_some() {
+            self.current_node_flags |= NodeFlags::TryFinally;
+        }
 
         // There are 3 possible kinds of Try Statements (See
         //    <https://tc39.es/ecma262/#sec-try-statement>):
@@ -1447,6 +1566,7 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
 
         // Restore the after_throw_block
         self.cfg.after_throw_block = parent_after_throw_block_ix;
+        self.current_node_flags.set(NodeFlags::TryFinally, in_try_finally);
 
         if let Some(finalizer) = &stmt.finalizer {
             /* cfg */
@@ -1530,6 +1650,8 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
     fn visit_while_statement(&mut self, stmt: &WhileStatement<'a>) {
         let kind = AstKind::WhileStatement(self.alloc(stmt));
         self.enter_node(kind);
+        let in_loop = self.current_node_flags.has_loop();
+        self.current_node_flags |= NodeFlags::Loop;
 
         /* cfg - condition basic block */
         let before_while_stmt_graph_ix = self.cfg.current_node_ix;
@@ -1549,11 +1671,18 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         /* cfg - after body basic block */
         let after_body_graph_ix = self.cfg.new_basic_block();
 
+        // `while (true)` is only left by a break
+        let is_infinite = stmt.test.get_boolean_value() == Some(true);
+
         self.cfg.add_edge(before_while_stmt_graph_ix, condition_graph_ix, EdgeType::Normal);
         self.cfg.add_edge(condition_graph_ix, body_graph_ix, EdgeType::Normal);
-        self.cfg.add_edge(body_graph_ix, after_body_graph_ix, EdgeType::Normal);
+        if !is_infinite {
+            self.cfg.add_edge(body_graph_ix, after_body_graph_ix, EdgeType::Normal);
+        }
         self.cfg.add_edge(body_graph_ix, condition_graph_ix, EdgeType::Backedge);
-        self.cfg.add_edge(condition_graph_ix, after_body_graph_ix, EdgeType::Normal);
+        if !is_infinite {
+            self.cfg.add_edge(condition_graph_ix, after_body_graph_ix, EdgeType::Normal);
+        }
 
         self.cfg.after_statement(
             &statement_state,
@@ -1566,6 +1695,7 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
             Some(condition_graph_ix),
         );
         /* cfg */
+        self.current_node_flags.set(NodeFlags::Loop, in_loop);
         self.leave_node(kind);
     }
 
@@ -1629,6 +1759,10 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         // We add a new basic block to the cfg before entering the node
         // so that the correct cfg_ix is associated with the ast node.
         self.enter_node(kind);
+        let mut context_flags = NodeFlags::empty();
+        context_flags.set(NodeFlags::Async, func.r#async);
+        context_flags.set(NodeFlags::Generator, func.generator);
+        let context_flags = self.enter_function_context(context_flags);
 
         /* cfg */
         self.cfg.add_edge(before_function_graph_ix, function_graph_ix, EdgeType::NewFunction);
@@ -1640,6 +1774,9 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         self.visit_formal_parameters(&func.params);
         if let Some(body) = &func.body {
             self.visit_function_body(body);
+            /* cfg */
+            self.cfg.function_ends.insert(self.current_node_id, self.cfg.current_node_ix);
+            /* cfg */
         }
 
         /* cfg */
@@ -1655,6 +1792,7 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         if let Some(annotation) = &func.return_type {
             self.visit_ts_type_annotation(annotation);
         }
+        self.leave_function_context(context_flags);
         self.leave_node(kind);
         self.leave_scope();
     }
@@ -1719,7 +1857,9 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         self.enter_scope(ScopeFlags::ClassStaticBlock);
         block.scope_id.set(Some(self.current_scope_id));
         self.enter_node(kind);
+        let context_flags = self.enter_function_context(NodeFlags::empty());
         self.visit_statements(&block.body);
+        self.leave_function_context(context_flags);
         self.leave_node(kind);
         self.leave_scope();
     }
@@ -1738,6 +1878,11 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         // We add a new basic block to the cfg before entering the node
         // so that the correct cfg_ix is associated with the ast node.
         self.enter_node(kind);
+        let context_flags = self.enter_function_context(if expr.r#async {
+            NodeFlags::Async
+        } else {
+            NodeFlags::empty()
+        });
 
         self.visit_formal_parameters(&expr.params);
 
@@ -1751,6 +1896,7 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         self.visit_function_body(&expr.body);
 
         /* cfg */
+        self.cfg.function_ends.insert(self.current_node_id, self.cfg.current_node_ix);
         self.cfg.restore_expression_state(preserved);
         self.cfg.current_node_ix = current_node_ix;
         // self.cfg.put_x_in_register(AssignmentValue::Function(self.current_node_id));
@@ -1758,6 +1904,7 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         if let Some(parameters) = &expr.type_parameters {
             self.visit_ts_type_parameter_declaration(parameters);
         }
+        self.leave_function_context(context_flags);
         self.leave_node(kind);
         self.leave_scope();
     }
@@ -1937,6 +2084,12 @@ impl<'a> SemanticBuilder<'a> {
             AstKind::YieldExpression(_) => {
                 self.set_function_node_flag(NodeFlags::HasYield);
             }
+            AstKind::CallExpression(call_expr) if call_expr.callee.is_specific_id("eval") => {
+                self.mark_scope_contains_direct_eval();
+            }
+            AstKind::WithStatement(_) => {
+                self.mark_scope_contains_direct_eval();
+            }
             _ => {}
         }
     }
@@ -1944,8 +2097,8 @@ impl<'a> SemanticBuilder<'a> {
     #[allow(clippy::single_match)]
     fn leave_kind(&mut self, kind: AstKind<'a>) {
         match kind {
-            AstKind::Program(_) => {
-                self.add_export_flag_for_export_identifier();
+            AstKind::Program(program) => {
+                self.add_export_flag_for_export_identifier(program);
             }
             AstKind::Class(_) => {
                 self.current_node_flags -= NodeFlags::Class;
@@ -14,7 +14,12 @@ use oxc_syntax::{
 use phf::{phf_set, Set};
 use rustc_hash::FxHashMap;
 
-use crate::{builder::SemanticBuilder, diagnostics::redeclaration, scope::ScopeFlags, AstNode};
+use crate::{
+    builder::SemanticBuilder,
+    diagnostics::{self, redeclaration},
+    scope::ScopeFlags,
+    AstNode,
+};
 
 pub struct EarlyErrorJavaScript;
 
@@ -139,14 +144,18 @@ fn check_duplicate_class_elements(ctx: &SemanticBuilder<'_>) {
 }
 
 fn undefined_export(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Export '{x0}' is not defined")).with_labels([span1.into()])
+    OxcDiagnostic::error(format!("Export '{x0}' is not defined"))
+        .with_labels([span1.into()])
+        .with_oxc_code(&diagnostics::UNDEFINED_EXPORT)
 }
 
 fn duplicate_export(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Duplicated export '{x0}'")).with_labels([
-        LabeledSpan::new_with_span(Some("Export has already been declared here".into()), span1),
-        LabeledSpan::new_with_span(Some("It cannot be redeclared here".into()), span2),
-    ])
+    OxcDiagnostic::error(format!("Duplicated export '{x0}'"))
+        .with_labels([
+            LabeledSpan::new_with_span(Some("Export has already been declared here".into()), span1),
+            LabeledSpan::new_with_span(Some("It cannot be redeclared here".into()), span2),
+        ])
+        .with_oxc_code(&diagnostics::DUPLICATE_EXPORT)
 }
 
 fn check_module_record(ctx: &SemanticBuilder<'_>) {
@@ -196,10 +205,13 @@ fn check_module_record(ctx: &SemanticBuilder<'_>) {
 fn class_static_block_await(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Cannot use await in class static initialization block")
         .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::CLASS_STATIC_BLOCK_AWAIT)
 }
 
 fn reserved_keyword(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("The keyword '{x0}' is reserved")).with_labels([span1.into()])
+    OxcDiagnostic::error(format!("The keyword '{x0}' is reserved"))
+        .with_labels([span1.into()])
+        .with_oxc_code(&diagnostics::RESERVED_KEYWORD)
 }
 
 pub const STRICT_MODE_NAMES: Set<&'static str> = phf_set! {
@@ -239,6 +251,7 @@ fn check_identifier<'a>(name: &Atom, span: Span, node: &AstNode<'a>, ctx: &Seman
 fn unexpected_identifier_assign(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Cannot assign to '{x0}' in strict mode"))
         .with_labels([span1.into()])
+        .with_oxc_code(&diagnostics::STRICT_MODE_ASSIGNMENT)
 }
 
 fn invalid_let_declaration(x0: &str, span1: Span) -> OxcDiagnostic {
@@ -246,6 +259,7 @@ fn invalid_let_declaration(x0: &str, span1: Span) -> OxcDiagnostic {
         "`let` cannot be declared as a variable name inside of a `{x0}` declaration"
     ))
     .with_labels([span1.into()])
+    .with_oxc_code(&diagnostics::INVALID_LET_DECLARATION)
 }
 
 fn check_binding_identifier<'a>(
@@ -277,7 +291,9 @@ fn check_binding_identifier<'a>(
 }
 
 fn unexpected_arguments(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("'arguments' is not allowed in {x0}")).with_labels([span1.into()])
+    OxcDiagnostic::error(format!("'arguments' is not allowed in {x0}"))
+        .with_labels([span1.into()])
+        .with_oxc_code(&diagnostics::UNEXPECTED_ARGUMENTS)
 }
 
 fn check_identifier_reference<'a>(
@@ -324,6 +340,7 @@ fn check_identifier_reference<'a>(
 fn private_not_in_class(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Private identifier '#{x0}' is not allowed outside class bodies"))
         .with_labels([span1.into()])
+        .with_oxc_code(&diagnostics::PRIVATE_NOT_IN_CLASS)
 }
 
 fn check_private_identifier_outside_class(ident: &PrivateIdentifier, ctx: &SemanticBuilder<'_>) {
@@ -335,6 +352,7 @@ fn check_private_identifier_outside_class(ident: &PrivateIdentifier, ctx: &Seman
 fn private_field_undeclared(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Private field '{x0}' must be declared in an enclosing class"))
         .with_labels([span1.into()])
+        .with_oxc_code(&diagnostics::PRIVATE_FIELD_UNDECLARED)
 }
 
 fn check_private_identifier(ctx: &SemanticBuilder<'_>) {
@@ -357,12 +375,14 @@ fn legacy_octal(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("'0'-prefixed octal literals and octal escape sequences are deprecated")
         .with_help("for octal literals use the '0o' prefix instead")
         .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::LEGACY_OCTAL)
 }
 
 fn leading_zero_decimal(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Decimals with leading zeros are not allowed in strict mode")
         .with_help("remove the leading zero")
         .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::LEADING_ZERO_DECIMAL)
 }
 
 fn check_number_literal(lit: &NumericLiteral, ctx: &SemanticBuilder<'_>) {
@@ -396,6 +416,7 @@ fn non_octal_decimal_escape_sequence(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Invalid escape sequence")
         .with_help("\\8 and \\9 are not allowed in strict mode")
         .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::NON_OCTAL_DECIMAL_ESCAPE_SEQUENCE)
 }
 
 fn check_string_literal(lit: &StringLiteral, ctx: &SemanticBuilder<'_>) {
@@ -433,6 +454,7 @@ fn illegal_use_strict(span0: Span) -> OxcDiagnostic {
         "Illegal 'use strict' directive in function with non-simple parameter list",
     )
     .with_labels([span0.into()])
+    .with_oxc_code(&diagnostics::ILLEGAL_USE_STRICT)
 }
 
 // It is a Syntax Error if FunctionBodyContainsUseStrict of AsyncFunctionBody is true and IsSimpleParameterList of FormalParameters is false.
@@ -460,10 +482,13 @@ fn top_level(x0: &str, span1: Span) -> OxcDiagnostic {
         "'{x0}' declaration can only be used at the top level of a module"
     ))
     .with_labels([span1.into()])
+    .with_oxc_code(&diagnostics::TOP_LEVEL)
 }
 
 fn module_code(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Cannot use {x0} outside a module")).with_labels([span1.into()])
+    OxcDiagnostic::error(format!("Cannot use {x0} outside a module"))
+        .with_labels([span1.into()])
+        .with_oxc_code(&diagnostics::MODULE_CODE)
 }
 
 fn check_module_declaration<'a>(
@@ -505,22 +530,26 @@ fn new_target(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Unexpected new.target expression")
 .with_help("new.target is only allowed in constructors and functions invoked using thew `new` operator")
 .with_labels([span0.into()])
+.with_oxc_code(&diagnostics::NEW_TARGET)
 }
 
 fn new_target_property(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("The only valid meta property for new is new.target")
         .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::NEW_TARGET_PROPERTY)
 }
 
 fn import_meta(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Unexpected import.meta expression")
         .with_help("import.meta is only allowed in module code")
         .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::IMPORT_META)
 }
 
 fn import_meta_property(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("The only valid meta property for import is import.meta")
         .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::IMPORT_META_PROPERTY)
 }
 
 fn check_meta_property<'a>(prop: &MetaProperty, node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
@@ -565,12 +594,14 @@ fn function_declaration_strict(span0: Span) -> OxcDiagnostic {
             "In strict mode code, functions can only be declared at top level or inside a block",
         )
         .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::FUNCTION_DECLARATION_STRICT)
 }
 
 fn function_declaration_non_strict(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Invalid function declaration")
 .with_help("In non-strict mode code, functions can only be declared at top level, inside a block, or as the body of an if statement")
 .with_labels([span0.into()])
+.with_oxc_code(&diagnostics::FUNCTION_DECLARATION_NON_STRICT)
 }
 
 fn check_function_declaration<'a>(
@@ -593,6 +624,7 @@ fn reg_exp_flag_u_and_v(span0: Span) -> OxcDiagnostic {
         "The 'u' and 'v' regular expression flags cannot be enabled at the same time",
     )
     .with_labels([span0.into()])
+    .with_oxc_code(&diagnostics::REG_EXP_FLAG_U_AND_V)
 }
 
 fn check_regexp_literal(lit: &RegExpLiteral, ctx: &SemanticBuilder<'_>) {
@@ -603,7 +635,9 @@ fn check_regexp_literal(lit: &RegExpLiteral, ctx: &SemanticBuilder<'_>) {
 }
 
 fn with_statement(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("'with' statements are not allowed").with_labels([span0.into()])
+    OxcDiagnostic::error("'with' statements are not allowed")
+        .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::WITH_STATEMENT)
 }
 
 fn check_with_statement(stmt: &WithStatement, ctx: &SemanticBuilder<'_>) {
@@ -626,16 +660,21 @@ fn check_switch_statement<'a>(stmt: &SwitchStatement<'a>, ctx: &SemanticBuilder<
 }
 
 fn invalid_label_jump_target(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Jump target cannot cross function boundary.").with_labels([span0.into()])
+    OxcDiagnostic::error("Jump target cannot cross function boundary.")
+        .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::INVALID_LABEL_JUMP_TARGET)
 }
 
 fn invalid_label_target(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Use of undefined label").with_labels([span0.into()])
+    OxcDiagnostic::error("Use of undefined label")
+        .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::INVALID_LABEL_TARGET)
 }
 
 fn invalid_label_non_iteration(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("A `{x0}` statement can only jump to a label of an enclosing `for`, `while` or `do while` statement."))
 .with_labels([LabeledSpan::new_with_span(Some("This is an non-iteration statement".into()), span1), LabeledSpan::new_with_span(Some("for this label".into()), span2)])
+.with_oxc_code(&diagnostics::INVALID_LABEL_NON_ITERATION)
 }
 
 fn check_label(label: &LabelIdentifier, ctx: &SemanticBuilder, is_continue: bool) {
@@ -667,6 +706,7 @@ fn invalid_break(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Illegal break statement")
 .with_help("A `break` statement can only be used within an enclosing iteration or switch statement.")
 .with_labels([span0.into()])
+.with_oxc_code(&diagnostics::INVALID_BREAK)
 }
 
 fn check_break_statement<'a>(stmt: &BreakStatement, node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
@@ -695,6 +735,7 @@ fn invalid_continue(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Illegal continue statement: no surrounding iteration statement")
 .with_help("A `continue` statement can only be used within an enclosing `for`, `while` or `do while` ")
 .with_labels([span0.into()])
+.with_oxc_code(&diagnostics::INVALID_CONTINUE)
 }
 
 fn check_continue_statement<'a>(
@@ -737,11 +778,13 @@ fn multiple_declaration_in_for_loop_head(x0: &str, span1: Span) -> OxcDiagnostic
         "Only a single declaration is allowed in a `for...{x0}` statement"
     ))
     .with_labels([span1.into()])
+    .with_oxc_code(&diagnostics::MULTIPLE_DECLARATION_IN_FOR_LOOP_HEAD)
 }
 
 fn unexpected_initializer_in_for_loop_head(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("{x0} loop variable declaration may not have an initializer"))
         .with_labels([span1.into()])
+        .with_oxc_code(&diagnostics::UNEXPECTED_INITIALIZER_IN_FOR_LOOP_HEAD)
 }
 
 fn check_for_statement_left<'a>(
@@ -777,17 +820,21 @@ fn check_for_statement_left<'a>(
 }
 
 fn duplicate_constructor(span0: Span, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Multiple constructor implementations are not allowed.").with_labels([
-        LabeledSpan::new_with_span(
-            Some("constructor has already been declared here".into()),
-            span0,
-        ),
-        LabeledSpan::new_with_span(Some("it cannot be redeclared here".into()), span1),
-    ])
+    OxcDiagnostic::error("Multiple constructor implementations are not allowed.")
+        .with_labels([
+            LabeledSpan::new_with_span(
+                Some("constructor has already been declared here".into()),
+                span0,
+            ),
+            LabeledSpan::new_with_span(Some("it cannot be redeclared here".into()), span1),
+        ])
+        .with_oxc_code(&diagnostics::DUPLICATE_CONSTRUCTOR)
 }
 
 fn require_class_name(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A class name is required.").with_labels([span0.into()])
+    OxcDiagnostic::error("A class name is required.")
+        .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::REQUIRE_CLASS_NAME)
 }
 
 fn check_class(class: &Class, node: &AstNode<'_>, ctx: &SemanticBuilder<'_>) {
@@ -825,10 +872,13 @@ fn check_class(class: &Class, node: &AstNode<'_>, ctx: &SemanticBuilder<'_>) {
 fn setter_with_parameters(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("A 'set' accessor must have exactly one parameter.")
         .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::SETTER_WITH_PARAMETERS)
 }
 
 fn setter_with_rest_parameter(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A 'set' accessor cannot have rest parameter.").with_labels([span0.into()])
+    OxcDiagnostic::error("A 'set' accessor cannot have rest parameter.")
+        .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::SETTER_WITH_REST_PARAMETER)
 }
 
 fn check_setter(function: &Function<'_>, ctx: &SemanticBuilder<'_>) {
@@ -847,6 +897,7 @@ fn check_setter(function: &Function<'_>, ctx: &SemanticBuilder<'_>) {
 fn getter_parameters(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("A 'get' accessor must not have any formal parameters.")
         .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::GETTER_PARAMETERS)
 }
 
 fn check_getter(function: &Function<'_>, ctx: &SemanticBuilder<'_>) {
@@ -870,16 +921,19 @@ fn super_without_derived_class(span0: Span, span1: Span) -> OxcDiagnostic {
             span0.into(),
             LabeledSpan::new_with_span(Some("class does not have `extends`".into()), span1),
         ])
+        .with_oxc_code(&diagnostics::SUPER_WITHOUT_DERIVED_CLASS)
 }
 
 fn unexpected_super_call(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Super calls are not permitted outside constructors or in nested functions inside constructors.")
 .with_labels([span0.into()])
+.with_oxc_code(&diagnostics::UNEXPECTED_SUPER_CALL)
 }
 
 fn unexpected_super_reference(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("'super' can only be referenced in members of derived classes or object literal expressions.")
 .with_labels([span0.into()])
+.with_oxc_code(&diagnostics::UNEXPECTED_SUPER_REFERENCE)
 }
 
 fn check_super<'a>(sup: &Super, node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) {
@@ -971,6 +1025,7 @@ fn cover_initialized_name(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Invalid assignment in object literal")
 .with_help("Did you mean to use a ':'? An '=' can only follow a property name when the containing object literal is part of a destructuring pattern.")
 .with_labels([span0.into()])
+.with_oxc_code(&diagnostics::COVER_INITIALIZED_NAME)
 }
 
 fn check_object_property(prop: &ObjectProperty, ctx: &SemanticBuilder<'_>) {
@@ -990,7 +1045,9 @@ fn check_object_property(prop: &ObjectProperty, ctx: &SemanticBuilder<'_>) {
 }
 
 fn a_rest_parameter_cannot_have_an_initializer(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("A rest parameter cannot have an initializer").with_labels([span0.into()])
+    OxcDiagnostic::error("A rest parameter cannot have an initializer")
+        .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::REST_PARAMETER_INITIALIZER)
 }
 
 fn check_formal_parameters<'a>(
@@ -1016,7 +1073,9 @@ fn check_array_pattern(pattern: &ArrayPattern, ctx: &SemanticBuilder<'_>) {
 }
 
 fn assignment_is_not_simple(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Invalid left-hand side in assignment").with_labels([span0.into()])
+    OxcDiagnostic::error("Invalid left-hand side in assignment")
+        .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::ASSIGNMENT_IS_NOT_SIMPLE)
 }
 
 fn check_assignment_expression(assign_expr: &AssignmentExpression, ctx: &SemanticBuilder<'_>) {
@@ -1053,6 +1112,7 @@ fn unexpected_exponential(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Unexpected exponentiation expression")
         .with_help(format!("Wrap {x0} expression in parentheses to enforce operator precedence"))
         .with_labels([span1.into()])
+        .with_oxc_code(&diagnostics::UNEXPECTED_EXPONENTIAL)
 }
 
 fn check_binary_expression(binary_expr: &BinaryExpression, ctx: &SemanticBuilder<'_>) {
@@ -1076,6 +1136,7 @@ fn mixed_coalesce(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Logical expressions and coalesce expressions cannot be mixed")
         .with_help("Wrap either expression by parentheses")
         .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::MIXED_COALESCE)
 }
 
 fn check_logical_expression(logical_expr: &LogicalExpression, ctx: &SemanticBuilder<'_>) {
@@ -1100,7 +1161,9 @@ fn check_logical_expression(logical_expr: &LogicalExpression, ctx: &SemanticBuil
 }
 
 fn super_private(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Private fields cannot be accessed on super").with_labels([span0.into()])
+    OxcDiagnostic::error("Private fields cannot be accessed on super")
+        .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::SUPER_PRIVATE)
 }
 
 fn check_member_expression(member_expr: &MemberExpression, ctx: &SemanticBuilder<'_>) {
@@ -1115,10 +1178,13 @@ fn check_member_expression(member_expr: &MemberExpression, ctx: &SemanticBuilder
 fn delete_of_unqualified(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Delete of an unqualified identifier in strict mode.")
         .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::DELETE_OF_UNQUALIFIED)
 }
 
 fn delete_private_field(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Private fields can not be deleted").with_labels([span0.into()])
+    OxcDiagnostic::error("Private fields can not be deleted")
+        .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::DELETE_PRIVATE_FIELD)
 }
 
 fn check_unary_expression<'a>(
@@ -1154,12 +1220,12 @@ fn is_in_formal_parameters<'a>(node: &AstNode<'a>, ctx: &SemanticBuilder<'a>) ->
 }
 
 fn await_or_yield_in_parameter(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("{x0} expression not allowed in formal parameter")).with_labels([
-        LabeledSpan::new_with_span(
+    OxcDiagnostic::error(format!("{x0} expression not allowed in formal parameter"))
+        .with_labels([LabeledSpan::new_with_span(
             Some(format!("{x0} expression not allowed in formal parameter")),
             span1,
-        ),
-    ])
+        )])
+        .with_oxc_code(&diagnostics::AWAIT_OR_YIELD_IN_PARAMETER)
 }
 
 fn check_await_expression<'a>(
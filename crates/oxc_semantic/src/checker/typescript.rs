@@ -5,7 +5,11 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{Atom, GetSpan, Span};
 use rustc_hash::FxHashMap;
 
-use crate::{builder::SemanticBuilder, diagnostics::redeclaration, AstNode};
+use crate::{
+    builder::SemanticBuilder,
+    diagnostics::{self, duplicate_identifier},
+    AstNode,
+};
 
 pub struct EarlyErrorTypeScript;
 
@@ -23,13 +27,17 @@ impl EarlyErrorTypeScript {
             }
             AstKind::TSModuleDeclaration(decl) => check_ts_module_declaration(decl, ctx),
             AstKind::TSEnumDeclaration(decl) => check_ts_enum_declaration(decl, ctx),
+            AstKind::Program(program) => check_function_overloads(&program.body, ctx),
+            AstKind::TSModuleBlock(block) => check_function_overloads(&block.body, ctx),
             _ => {}
         }
     }
 }
 
 fn empty_type_parameter_list(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Type parameter list cannot be empty.").with_labels([span0.into()])
+    OxcDiagnostic::error("Type parameter list cannot be empty.")
+        .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::EMPTY_TYPE_PARAMETER_LIST)
 }
 
 fn check_ts_type_parameter_declaration(
@@ -42,7 +50,9 @@ fn check_ts_type_parameter_declaration(
 }
 
 fn unexpected_optional(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected `?` operator").with_labels([span0.into()])
+    OxcDiagnostic::error("Unexpected `?` operator")
+        .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::UNEXPECTED_OPTIONAL)
 }
 
 #[allow(clippy::cast_possible_truncation)]
@@ -58,11 +68,13 @@ fn check_variable_declarator(decl: &VariableDeclarator, ctx: &SemanticBuilder<'_
 fn required_parameter_after_optional_parameter(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("A required parameter cannot follow an optional parameter.")
         .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::REQUIRED_PARAMETER_AFTER_OPTIONAL_PARAMETER)
 }
 
 fn parameter_property_outside_constructor(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("A parameter property is only allowed in a constructor implementation.")
         .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::PARAMETER_PROPERTY_OUTSIDE_CONSTRUCTOR)
 }
 
 fn check_formal_parameters(params: &FormalParameters, ctx: &SemanticBuilder<'_>) {
@@ -93,7 +105,7 @@ fn check_duplicate_bound_names<'a, T: BoundNames<'a>>(bound_names: &T, ctx: &Sem
     let mut idents: FxHashMap<Atom<'a>, Span> = FxHashMap::default();
     bound_names.bound_names(&mut |ident| {
         if let Some(old_span) = idents.insert(ident.name.clone(), ident.span) {
-            ctx.error(redeclaration(&ident.name, old_span, ident.span));
+            ctx.error(duplicate_identifier(&ident.name, old_span, ident.span));
         }
     });
 }
@@ -103,6 +115,7 @@ fn unexpected_assignment(span0: Span) -> OxcDiagnostic {
         "The left-hand side of an assignment expression must be a variable or a property access.",
     )
     .with_labels([span0.into()])
+    .with_oxc_code(&diagnostics::UNEXPECTED_ASSIGNMENT)
 }
 
 fn check_simple_assignment_target<'a>(
@@ -122,7 +135,9 @@ fn check_simple_assignment_target<'a>(
 }
 
 fn unexpected_type_annotation(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Unexpected type annotation").with_labels([span0.into()])
+    OxcDiagnostic::error("Unexpected type annotation")
+        .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::UNEXPECTED_TYPE_ANNOTATION)
 }
 
 fn check_array_pattern<'a>(pattern: &ArrayPattern<'a>, ctx: &SemanticBuilder<'a>) {
@@ -140,6 +155,7 @@ fn not_allowed_namespace_declaration(span0: Span) -> OxcDiagnostic {
         "A namespace declaration is only allowed at the top level of a namespace or module.",
     )
     .with_labels([span0.into()])
+    .with_oxc_code(&diagnostics::NOT_ALLOWED_NAMESPACE_DECLARATION)
 }
 
 fn check_ts_module_declaration<'a>(decl: &TSModuleDeclaration<'a>, ctx: &SemanticBuilder<'a>) {
@@ -162,7 +178,9 @@ fn check_ts_module_declaration<'a>(decl: &TSModuleDeclaration<'a>, ctx: &Semanti
 }
 
 fn enum_member_must_have_initializer(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("Enum member must have initializer.").with_labels([span0.into()])
+    OxcDiagnostic::error("Enum member must have initializer.")
+        .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::ENUM_MEMBER_MUST_HAVE_INITIALIZER)
 }
 
 fn check_ts_enum_declaration(decl: &TSEnumDeclaration<'_>, ctx: &SemanticBuilder<'_>) {
@@ -189,3 +207,145 @@ fn check_ts_enum_declaration(decl: &TSEnumDeclaration<'_>, ctx: &SemanticBuilder
         }
     });
 }
+
+fn function_implementation_missing(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(
+        "Function implementation is missing or not immediately following the declaration.",
+    )
+    .with_error_code("TS2391")
+    .with_labels([span0.into()])
+    .with_oxc_code(&diagnostics::FUNCTION_IMPLEMENTATION_MISSING)
+}
+
+fn function_implementation_name_mismatch(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Function implementation name must be '{x0}'."))
+        .with_error_code("TS2389")
+        .with_labels([span1.into()])
+        .with_oxc_code(&diagnostics::FUNCTION_IMPLEMENTATION_NAME_MISMATCH)
+}
+
+fn overload_export_mismatch(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Overload signatures must all be exported or non-exported.")
+        .with_error_code("TS2383")
+        .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::OVERLOAD_EXPORT_MISMATCH)
+}
+
+fn overload_ambient_mismatch(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Overload signatures must all be ambient or non-ambient.")
+        .with_error_code("TS2384")
+        .with_labels([span0.into()])
+        .with_oxc_code(&diagnostics::OVERLOAD_AMBIENT_MISMATCH)
+}
+
+/// A function declaration of a statement list, and whether it is exported.
+fn function_declaration<'a, 'b>(stmt: &'b Statement<'a>) -> Option<(&'b Function<'a>, bool)> {
+    match stmt {
+        Statement::FunctionDeclaration(func) => Some((&**func, false)),
+        Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+            Some(Declaration::FunctionDeclaration(func)) => Some((&**func, true)),
+            _ => None,
+        },
+        Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(func) => Some((&**func, true)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn function_name<'a, 'b>(func: &'b Function<'a>) -> Option<&'b Atom<'a>> {
+    func.id.as_ref().map(|id| &id.name)
+}
+
+fn function_name_span(func: &Function<'_>) -> Span {
+    func.id.as_ref().map_or(func.span, |id| id.span)
+}
+
+/// Whether the statement list is inside a `.d.ts` file or a `declare namespace`.
+fn is_ambient_context(ctx: &SemanticBuilder<'_>) -> bool {
+    ctx.source_type.is_typescript_definition()
+        || ctx.nodes.iter_parents(ctx.current_node_id).any(|node| {
+            matches!(node.kind(), AstKind::TSModuleDeclaration(decl) if decl.modifiers.contains(ModifierKind::Declare))
+        })
+}
+
+/// Check the overload signatures of each function in a statement list.
+///
+/// ```ts
+/// function f(a: string): void;
+/// function f(a: number): void;
+/// function f(a: any) {}
+/// ```
+fn check_function_overloads<'a>(stmts: &[Statement<'a>], ctx: &SemanticBuilder<'a>) {
+    let is_ambient_context = is_ambient_context(ctx);
+    let is_ambient =
+        |func: &Function<'a>| is_ambient_context || func.modifiers.contains(ModifierKind::Declare);
+
+    // Consecutive overload signatures with the same name
+    let mut overloads: Vec<(&Function<'a>, bool)> = vec![];
+    for stmt in stmts {
+        let Some((func, exported)) = function_declaration(stmt) else {
+            check_unimplemented_overloads(&overloads, &is_ambient, ctx);
+            overloads.clear();
+            continue;
+        };
+        let is_same_name =
+            overloads.last().map_or(true, |(last, _)| function_name(last) == function_name(func));
+        if func.body.is_none() {
+            if !is_same_name {
+                check_unimplemented_overloads(&overloads, &is_ambient, ctx);
+                overloads.clear();
+            }
+            overloads.push((func, exported));
+            continue;
+        }
+        if is_same_name {
+            check_overload_agreement(&overloads, Some((func, exported)), &is_ambient, ctx);
+        } else {
+            // function f(): void; function g() {}
+            let (last, _) = overloads[overloads.len() - 1];
+            if let Some(name) = function_name(last).filter(|_| !is_ambient(last)) {
+                ctx.error(function_implementation_name_mismatch(name, function_name_span(func)));
+            }
+            check_overload_agreement(&overloads, None, &is_ambient, ctx);
+        }
+        overloads.clear();
+    }
+    check_unimplemented_overloads(&overloads, &is_ambient, ctx);
+}
+
+fn check_unimplemented_overloads<'a>(
+    overloads: &[(&Function<'a>, bool)],
+    is_ambient: &dyn Fn(&Function<'a>) -> bool,
+    ctx: &SemanticBuilder<'a>,
+) {
+    let Some(&(last, _)) = overloads.last() else { return };
+    if !is_ambient(last) {
+        ctx.error(function_implementation_missing(function_name_span(last)));
+    }
+    check_overload_agreement(overloads, None, is_ambient, ctx);
+}
+
+/// Overload signatures must agree with their implementation, or with the first signature if
+/// there is none, on being exported and ambient.
+fn check_overload_agreement<'a>(
+    overloads: &[(&Function<'a>, bool)],
+    implementation: Option<(&Function<'a>, bool)>,
+    is_ambient: &dyn Fn(&Function<'a>) -> bool,
+    ctx: &SemanticBuilder<'a>,
+) {
+    let Some((canonical, canonical_exported)) = implementation.or(overloads.first().copied())
+    else {
+        return;
+    };
+    let canonical_ambient = is_ambient(canonical);
+    for &(func, exported) in overloads {
+        if exported != canonical_exported {
+            ctx.error(overload_export_mismatch(function_name_span(func)));
+        }
+        if is_ambient(func) != canonical_ambient {
+            ctx.error(overload_ambient_mismatch(function_name_span(func)));
+        }
+    }
+}
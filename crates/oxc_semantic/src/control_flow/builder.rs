@@ -1,3 +1,5 @@
+use rustc_hash::FxHashMap;
+
 use super::{
     AssignmentValue, AstNodeId, BasicBlockElement, BasicBlockId, CompactStr, ControlFlowGraph,
     EdgeType, Graph, PreservedExpressionState, PreservedStatementState, Register,
@@ -25,6 +27,9 @@ pub struct ControlFlowGraphBuilder {
     pub saved_stores: Vec<(Vec<BasicBlockElement>, Option<Register>)>,
     pub saved_store: Option<usize>,
     pub basic_blocks_with_breaks: Vec<Vec<BasicBlockId>>,
+    // indexes into basic_blocks_with_breaks of the loops and switch statements,
+    // which an unlabeled break jumps out of
+    pub unlabeled_break_targets: Vec<usize>,
     pub basic_blocks_with_continues: Vec<Vec<BasicBlockId>>,
     // node indexes of the basic blocks of switch case conditions
     pub switch_case_conditions: Vec<Vec<BasicBlockId>>,
@@ -32,11 +37,17 @@ pub struct ControlFlowGraphBuilder {
     pub label_to_ast_node_ix: Vec<(CompactStr, AstNodeId)>,
     pub ast_node_to_break_continue: Vec<(AstNodeId, usize, Option<usize>)>,
     pub after_throw_block: Option<BasicBlockId>,
+    // the basic block at the end of the body of each function
+    pub function_ends: FxHashMap<AstNodeId, BasicBlockId>,
 }
 
 impl ControlFlowGraphBuilder {
     pub fn build(self) -> ControlFlowGraph {
-        ControlFlowGraph { graph: self.graph, basic_blocks: self.basic_blocks }
+        ControlFlowGraph {
+            graph: self.graph,
+            basic_blocks: self.basic_blocks,
+            function_ends: self.function_ends,
+        }
     }
 
     /// # Panics
@@ -167,8 +178,11 @@ impl ControlFlowGraphBuilder {
         let mut pss = PreservedStatementState { put_label: false };
 
         match control_flow_type {
-            StatementControlFlowType::DoesNotUseContinue => {
+            StatementControlFlowType::DoesNotUseContinue | StatementControlFlowType::UsesBreak => {
                 self.basic_blocks_with_breaks.push(vec![]);
+                if matches!(control_flow_type, StatementControlFlowType::UsesBreak) {
+                    self.unlabeled_break_targets.push(self.basic_blocks_with_breaks.len() - 1);
+                }
                 if let Some(next_label) = &self.next_label.take() {
                     self.label_to_ast_node_ix.push((next_label.clone(), id));
                     pss.put_label = true;
@@ -181,6 +195,7 @@ impl ControlFlowGraphBuilder {
             }
             StatementControlFlowType::UsesContinue => {
                 self.basic_blocks_with_breaks.push(vec![]);
+                self.unlabeled_break_targets.push(self.basic_blocks_with_breaks.len() - 1);
                 self.basic_blocks_with_continues.push(vec![]);
                 if let Some(next_label) = &self.next_label.take() {
                     self.label_to_ast_node_ix.push((next_label.clone(), id));
@@ -205,11 +220,31 @@ impl ControlFlowGraphBuilder {
         break_jump_position: BasicBlockId,
         continue_jump_position: Option<BasicBlockId>,
     ) {
+        if self.unlabeled_break_targets.last() == Some(&(self.basic_blocks_with_breaks.len() - 1)) {
+            self.unlabeled_break_targets.pop();
+        }
         let basic_blocks_with_breaks = self
             .basic_blocks_with_breaks
             .pop()
             .expect("expected there to be a breaks array for this statement");
 
+        // A labeled statement which ends in unreachable code, e.g. `a: { break a; }`,
+        // is continued after in a new basic block that is only reached by its breaks.
+        let break_jump_position = if preserved_state.put_label
+            && !basic_blocks_with_breaks.is_empty()
+            && break_jump_position == self.current_node_ix
+            && self
+                .current_basic_block()
+                .iter()
+                .any(|element| matches!(element, BasicBlockElement::Unreachable))
+        {
+            let after_statement_ix = self.new_basic_block();
+            self.add_edge(break_jump_position, after_statement_ix, EdgeType::Normal);
+            after_statement_ix
+        } else {
+            break_jump_position
+        };
+
         for break_ in basic_blocks_with_breaks {
             // can this always be self.current_node_ix?
             self.add_edge(break_, break_jump_position, EdgeType::Normal);
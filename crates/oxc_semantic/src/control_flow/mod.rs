@@ -4,7 +4,8 @@ use oxc_span::CompactStr;
 use oxc_syntax::operator::{
     AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator, UpdateOperator,
 };
-use petgraph::{stable_graph::NodeIndex, Graph};
+use petgraph::{stable_graph::NodeIndex, visit::EdgeRef, Direction, Graph};
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::AstNodeId;
 
@@ -126,6 +127,9 @@ pub enum EdgeType {
 pub struct ControlFlowGraph {
     pub graph: Graph<usize, EdgeType>,
     pub basic_blocks: Vec<Vec<BasicBlockElement>>,
+    /// The basic block at the end of the body of each function,
+    /// which is reached when the function completes without a `return` or `throw`.
+    pub function_ends: FxHashMap<AstNodeId, BasicBlockId>,
 }
 
 impl ControlFlowGraph {
@@ -140,10 +144,58 @@ impl ControlFlowGraph {
         let ix = *self.graph.node_weight(id).expect("expected a valid node id in self.graph");
         self.basic_blocks.get_mut(ix).expect("expected a valid node id in self.basic_blocks")
     }
+
+    /// The basic blocks which are executed on some path from `roots`.
+    ///
+    /// A path ends at a `return` or `throw`, does not enter a block marked as
+    /// [BasicBlockElement::Unreachable], e.g. after a `break`, and does not enter
+    /// the functions created on the way, see [EdgeType::NewFunction].
+    pub fn reachable_basic_blocks<I: IntoIterator<Item = BasicBlockId>>(
+        &self,
+        roots: I,
+    ) -> FxHashSet<BasicBlockId> {
+        let mut reachable = FxHashSet::default();
+        let mut stack = roots.into_iter().collect::<Vec<_>>();
+        while let Some(id) = stack.pop() {
+            let basic_block = self.basic_block(id);
+            if basic_block.iter().any(|element| matches!(element, BasicBlockElement::Unreachable))
+                || !reachable.insert(id)
+            {
+                continue;
+            }
+            if basic_block.iter().any(|element| {
+                matches!(
+                    element,
+                    BasicBlockElement::Throw(_)
+                        | BasicBlockElement::Assignment(Register::Return, _)
+                )
+            }) {
+                continue;
+            }
+            stack.extend(
+                self.graph
+                    .edges_directed(id, Direction::Outgoing)
+                    .filter(|edge| !matches!(edge.weight(), EdgeType::NewFunction))
+                    .map(|edge| edge.target()),
+            );
+        }
+        reachable
+    }
+
+    /// Whether the end of the body of the function `function_id` is reachable,
+    /// i.e. the function can return `undefined` without a `return` statement.
+    pub fn is_function_end_reachable(&self, function_id: AstNodeId, entry: BasicBlockId) -> bool {
+        self.function_ends
+            .get(&function_id)
+            .is_some_and(|end| self.reachable_basic_blocks([entry]).contains(end))
+    }
 }
 
 pub enum StatementControlFlowType {
     DoesNotUseContinue,
+    /// A `switch` statement, which an unlabeled `break` jumps out of.
+    UsesBreak,
+    /// A loop, which an unlabeled `break` jumps out of.
     UsesContinue,
 }
 
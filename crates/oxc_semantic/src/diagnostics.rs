@@ -2,8 +2,289 @@ use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 use oxc_span::Span;
 
 pub fn redeclaration(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Identifier `{x0}` has already been declared")).with_labels([
+    OxcDiagnostic::error(format!("Identifier `{x0}` has already been declared"))
+        .with_labels(redeclaration_labels(x0, span1, span2))
+        .with_oxc_code(&REDECLARATION)
+}
+
+fn redeclaration_labels(x0: &str, span1: Span, span2: Span) -> [LabeledSpan; 2] {
+    [
         LabeledSpan::new_with_span(Some(format!("`{x0}` has already been declared here")), span1),
         LabeledSpan::new_with_span(Some("It can not be redeclared here".into()), span2),
-    ])
+    ]
+}
+
+/// TS2300
+pub fn duplicate_identifier(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Duplicate identifier '{x0}'."))
+        .with_error_code("TS2300")
+        .with_labels(redeclaration_labels(x0, span1, span2))
+        .with_oxc_code(&DUPLICATE_IDENTIFIER)
+}
+
+/// TS2451
+pub fn block_scoped_redeclaration(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Cannot redeclare block-scoped variable '{x0}'."))
+        .with_error_code("TS2451")
+        .with_labels(redeclaration_labels(x0, span1, span2))
+        .with_oxc_code(&BLOCK_SCOPED_REDECLARATION)
+}
+
+/// TS2393
+pub fn duplicate_function_implementation(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Duplicate function implementation.")
+        .with_error_code("TS2393")
+        .with_labels(redeclaration_labels(x0, span1, span2))
+        .with_oxc_code(&DUPLICATE_FUNCTION_IMPLEMENTATION)
+}
+
+oxc_diagnostics::diagnostic_codes! {
+    scope: "semantic";
+
+    /// A name is declared twice in the same scope, and one of the declarations is lexical, such as
+    /// `let`, `const`, `class` or a function in a block, which can not be redeclared.
+    ///
+    /// ```js
+    /// let a;
+    /// var a;
+    /// ```
+    REDECLARATION = "redeclaration";
+
+    /// A TypeScript name is declared twice in the same scope, for example two parameters of a
+    /// signature or a type and an interface with the same name, which can not be merged.
+    DUPLICATE_IDENTIFIER = "duplicate_identifier";
+
+    /// A `let` or `const` variable in TypeScript is declared twice in the same scope.
+    BLOCK_SCOPED_REDECLARATION = "block_scoped_redeclaration";
+
+    /// A function with overload signatures has two implementations, that is two declarations with a
+    /// body.
+    DUPLICATE_FUNCTION_IMPLEMENTATION = "duplicate_function_implementation";
+
+    /// An `export { name }` statement exports a name which is not declared in the module.
+    UNDEFINED_EXPORT = "undefined_export";
+
+    /// A module exports the same name twice, for example with two `export default` declarations.
+    DUPLICATE_EXPORT = "duplicate_export";
+
+    /// `await` is used in a class static initialization block, as an expression or as an
+    /// identifier. Static blocks run synchronously.
+    CLASS_STATIC_BLOCK_AWAIT = "class_static_block_await";
+
+    /// A reserved word is used as an identifier, such as `await` in a module, or `implements`,
+    /// `interface`, `let`, `package`, `private`, `protected`, `public`, `static` or `yield` in
+    /// strict mode code.
+    RESERVED_KEYWORD = "reserved_keyword";
+
+    /// `eval` or `arguments` is assigned or declared in strict mode code.
+    ///
+    /// ```js
+    /// "use strict";
+    /// arguments = [];
+    /// ```
+    STRICT_MODE_ASSIGNMENT = "strict_mode_assignment";
+
+    /// `let` is used as the name of a variable of a `let` or `const` declaration.
+    INVALID_LET_DECLARATION = "invalid_let_declaration";
+
+    /// `arguments` is used in a class field initializer or in a class static initialization block,
+    /// which have no arguments of their own.
+    UNEXPECTED_ARGUMENTS = "unexpected_arguments";
+
+    /// A private name such as `#a` is used outside of the body of a class.
+    PRIVATE_NOT_IN_CLASS = "private_not_in_class";
+
+    /// A private name such as `#a` is used in a class which, like its enclosing classes, does not
+    /// declare it.
+    PRIVATE_FIELD_UNDECLARED = "private_field_undeclared";
+
+    /// A legacy octal literal such as `010`, or an octal escape sequence such as `\01`, is used in
+    /// strict mode code. Use the `0o` prefix, `0o10`, or a hexadecimal escape sequence instead.
+    LEGACY_OCTAL = "legacy_octal";
+
+    /// A decimal literal with a leading zero such as `09` is used in strict mode code.
+    LEADING_ZERO_DECIMAL = "leading_zero_decimal";
+
+    /// The escape sequence `\8` or `\9` is used in a string in strict mode code.
+    NON_OCTAL_DECIMAL_ESCAPE_SEQUENCE = "non_octal_decimal_escape_sequence";
+
+    /// A function whose parameters have default values, destructuring or a rest parameter has a
+    /// `"use strict"` directive. Move the directive to the enclosing scope.
+    ILLEGAL_USE_STRICT = "illegal_use_strict";
+
+    /// An `import` or `export` declaration appears inside of a block or a function. They can only
+    /// be used at the top level of a module.
+    TOP_LEVEL = "top_level";
+
+    /// An `import` or `export` declaration appears in a script. Parse the file as a module, for
+    /// example with the `.mjs` extension or `"type": "module"` in `package.json`.
+    MODULE_CODE = "module_code";
+
+    /// `new.target` is used outside of a function, where it has no meaning.
+    NEW_TARGET = "new_target";
+
+    /// A meta property of `new` other than `new.target` is used.
+    NEW_TARGET_PROPERTY = "new_target_property";
+
+    /// `import.meta` is used in a script. It is only defined in modules.
+    IMPORT_META = "import_meta";
+
+    /// A meta property of `import` other than `import.meta` is used.
+    IMPORT_META_PROPERTY = "import_meta_property";
+
+    /// A function is declared as the body of a statement in strict mode code, such as
+    /// `if (a) function f() {}`. Declare it in a block instead.
+    FUNCTION_DECLARATION_STRICT = "function_declaration_strict";
+
+    /// A function is declared as the body of a statement other than `if`, such as
+    /// `while (a) function f() {}`. Declare it in a block instead.
+    FUNCTION_DECLARATION_NON_STRICT = "function_declaration_non_strict";
+
+    /// A regular expression literal has both the `u` and the `v` flags. The `v` flag is an
+    /// extension of the `u` flag, use only one of them.
+    REG_EXP_FLAG_U_AND_V = "reg_exp_flag_u_and_v";
+
+    /// A `with` statement is used in strict mode code.
+    WITH_STATEMENT = "with_statement";
+
+    /// A `break` or `continue` statement refers to a label outside of the enclosing function.
+    INVALID_LABEL_JUMP_TARGET = "invalid_label_jump_target";
+
+    /// A `break` or `continue` statement refers to a label which is not declared by an enclosing
+    /// statement.
+    INVALID_LABEL_TARGET = "invalid_label_target";
+
+    /// A `continue` statement refers to the label of a statement which is not a loop.
+    ///
+    /// ```js
+    /// a: {
+    ///   continue a;
+    /// }
+    /// ```
+    INVALID_LABEL_NON_ITERATION = "invalid_label_non_iteration";
+
+    /// A `break` statement without a label is used outside of a loop and of a `switch` statement.
+    INVALID_BREAK = "invalid_break";
+
+    /// A `continue` statement is used outside of a loop.
+    INVALID_CONTINUE = "invalid_continue";
+
+    /// The head of a `for...in` or `for...of` statement declares more than one variable.
+    MULTIPLE_DECLARATION_IN_FOR_LOOP_HEAD = "multiple_declaration_in_for_loop_head";
+
+    /// The variable of a `for...of` statement, or of a `for...in` statement in strict mode code or
+    /// with `let` or `const`, has an initializer.
+    UNEXPECTED_INITIALIZER_IN_FOR_LOOP_HEAD = "unexpected_initializer_in_for_loop_head";
+
+    /// A class has more than one `constructor` method.
+    DUPLICATE_CONSTRUCTOR = "duplicate_constructor";
+
+    /// A class declaration has no name. Only the default export can be an anonymous class
+    /// declaration.
+    REQUIRE_CLASS_NAME = "require_class_name";
+
+    /// A setter does not have exactly one parameter, the assigned value.
+    SETTER_WITH_PARAMETERS = "setter_with_parameters";
+
+    /// The parameter of a setter is a rest parameter.
+    SETTER_WITH_REST_PARAMETER = "setter_with_rest_parameter";
+
+    /// A getter has parameters. Getters are called without arguments.
+    GETTER_PARAMETERS = "getter_parameters";
+
+    /// `super()` is called in the constructor of a class which does not extend another class.
+    SUPER_WITHOUT_DERIVED_CLASS = "super_without_derived_class";
+
+    /// `super()` is called outside of the constructor of a class, or in a function other than an
+    /// arrow function inside of it.
+    UNEXPECTED_SUPER_CALL = "unexpected_super_call";
+
+    /// A property of `super` is accessed outside of the methods of classes and object literals.
+    UNEXPECTED_SUPER_REFERENCE = "unexpected_super_reference";
+
+    /// A shorthand property of an object literal has a default value, such as `({ a = 1 })`, which
+    /// is only allowed when the object is a destructuring pattern.
+    COVER_INITIALIZED_NAME = "cover_initialized_name";
+
+    /// A rest parameter has a default value. It is always defined, as an empty array when no
+    /// arguments are left.
+    REST_PARAMETER_INITIALIZER = "rest_parameter_initializer";
+
+    /// The left-hand side of a compound assignment such as `+=` is not a variable or a property
+    /// access.
+    ///
+    /// ```js
+    /// [a] += 1;
+    /// ```
+    ASSIGNMENT_IS_NOT_SIMPLE = "assignment_is_not_simple";
+
+    /// The left operand of `**` is a unary expression, such as `-a ** 2`, whose precedence is
+    /// ambiguous. Wrap it in parentheses, `(-a) ** 2`, or wrap the exponentiation, `-(a ** 2)`.
+    UNEXPECTED_EXPONENTIAL = "unexpected_exponential";
+
+    /// `??` is mixed with `&&` or `||` without parentheses, such as `a ?? b || c`. Wrap either
+    /// expression in parentheses.
+    MIXED_COALESCE = "mixed_coalesce";
+
+    /// A private field is accessed on `super`, such as `super.#a`. Private fields are not
+    /// inherited.
+    SUPER_PRIVATE = "super_private";
+
+    /// A variable is deleted in strict mode code, such as `delete a`. Only properties can be
+    /// deleted.
+    DELETE_OF_UNQUALIFIED = "delete_of_unqualified";
+
+    /// A private field is deleted, such as `delete this.#a`. Private fields can not be deleted.
+    DELETE_PRIVATE_FIELD = "delete_private_field";
+
+    /// An `await` or `yield` expression is used in the default value of a parameter.
+    AWAIT_OR_YIELD_IN_PARAMETER = "await_or_yield_in_parameter";
+
+    /// A list of type parameters is empty, such as `function f<>() {}`.
+    EMPTY_TYPE_PARAMETER_LIST = "empty_type_parameter_list";
+
+    /// A variable is marked as optional with `?`, such as `let a?: number`. Only parameters and
+    /// properties can be optional.
+    UNEXPECTED_OPTIONAL = "unexpected_optional";
+
+    /// A required parameter follows an optional parameter.
+    ///
+    /// ```ts
+    /// function f(a?: number, b: number) {}
+    /// ```
+    REQUIRED_PARAMETER_AFTER_OPTIONAL_PARAMETER = "required_parameter_after_optional_parameter";
+
+    /// A parameter has an accessibility modifier, such as `public a`, outside of a constructor.
+    /// Only the parameters of a constructor can declare properties.
+    PARAMETER_PROPERTY_OUTSIDE_CONSTRUCTOR = "parameter_property_outside_constructor";
+
+    /// The left-hand side of an assignment is a TypeScript expression, such as a type assertion or
+    /// a non-null assertion, which does not wrap a variable or a property access.
+    UNEXPECTED_ASSIGNMENT = "unexpected_assignment";
+
+    /// An element of an array destructuring pattern has a type annotation. Annotate the type of the
+    /// whole pattern instead.
+    UNEXPECTED_TYPE_ANNOTATION = "unexpected_type_annotation";
+
+    /// A namespace is declared inside of a block or a function. Namespaces can only be declared at
+    /// the top level of a module or of another namespace.
+    NOT_ALLOWED_NAMESPACE_DECLARATION = "not_allowed_namespace_declaration";
+
+    /// A member of an enum has no initializer, and follows a member whose value is not a number
+    /// literal, so its value can not be computed by incrementing the previous one.
+    ENUM_MEMBER_MUST_HAVE_INITIALIZER = "enum_member_must_have_initializer";
+
+    /// An overload signature of a function is not followed by another signature or by the
+    /// implementation of the function.
+    FUNCTION_IMPLEMENTATION_MISSING = "function_implementation_missing";
+
+    /// The implementation which follows the overload signatures of a function has another name.
+    FUNCTION_IMPLEMENTATION_NAME_MISMATCH = "function_implementation_name_mismatch";
+
+    /// Some overload signatures and the implementation of a function are exported, and some are
+    /// not.
+    OVERLOAD_EXPORT_MISMATCH = "overload_export_mismatch";
+
+    /// Some overload signatures of a function are declared with `declare`, and some are not.
+    OVERLOAD_AMBIENT_MISMATCH = "overload_ambient_mismatch";
 }
@@ -0,0 +1,252 @@
+//! Initialization order of binding patterns.
+//!
+//! Parameters, catch parameters and destructuring patterns initialize their bindings from left
+//! to right, and evaluate default values and computed keys in between. An expression of the
+//! pattern sees the bindings initialized before it, while the later bindings of the same pattern
+//! are in their temporal dead zone:
+//!
+//! * `function f(a = b, b) {}` throws, `function f(a, b = a) {}` does not.
+//! * `const { [a]: b, a } = obj` throws, because the key `a` is evaluated before `a` is bound.
+//! * `function f(a = () => b, b) {}` does not throw, the arrow function reads `b` when it is
+//!   called, so its body is deferred.
+//!
+//! Transforms which split a parameter list, e.g. into the body of an async function, or
+//! rewrite it as a sequence of declarations, can check these facts instead of re-deriving them.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, visit::walk::walk_property_definition, Visit};
+use oxc_span::{CompactStr, GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::{symbol::SymbolTable, ReferenceId, SymbolId};
+
+/// A binding of the pattern, in initialization order.
+#[derive(Debug, Clone)]
+pub struct InitializedBinding {
+    pub name: CompactStr,
+    /// `None` when semantic analysis did not run on the pattern.
+    pub symbol_id: Option<SymbolId>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvaluationKind {
+    /// The default value of `a = value`, or of a parameter.
+    Default,
+    /// The computed key of `{ [key]: a }`.
+    ComputedKey,
+}
+
+/// An expression which is evaluated while the pattern is initialized.
+#[derive(Debug, Clone)]
+pub struct Evaluation {
+    pub kind: EvaluationKind,
+    pub span: Span,
+    /// The number of bindings initialized before the expression is evaluated, which are visible
+    /// to it. The rest of the bindings are in their temporal dead zone.
+    pub initialized_before: usize,
+    /// The functions and instance fields in the expression, which are not evaluated with it.
+    pub deferred: Vec<Span>,
+    /// The identifiers read by the expression, outside of its deferred bodies.
+    references: Vec<(CompactStr, Option<ReferenceId>, Span)>,
+}
+
+impl Evaluation {
+    /// Whether `span` is evaluated with the expression, rather than in a deferred body.
+    pub fn evaluates(&self, span: Span) -> bool {
+        contains(self.span, span) && !self.deferred.iter().any(|&deferred| contains(deferred, span))
+    }
+}
+
+/// A reference which reads a binding of the pattern before it is initialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TdzReference {
+    /// Index into [InitializationOrder::evaluations].
+    pub evaluation: usize,
+    /// Index into [InitializationOrder::bindings].
+    pub binding: usize,
+    pub span: Span,
+}
+
+/// The order in which a pattern initializes its bindings and evaluates its expressions.
+#[derive(Debug, Default)]
+pub struct InitializationOrder {
+    bindings: Vec<InitializedBinding>,
+    evaluations: Vec<Evaluation>,
+}
+
+impl InitializationOrder {
+    pub fn of_parameters(params: &FormalParameters) -> Self {
+        let mut order = Self::default();
+        for param in &params.items {
+            order.add_pattern(&param.pattern);
+        }
+        if let Some(rest) = &params.rest {
+            order.add_pattern(&rest.argument);
+        }
+        order
+    }
+
+    pub fn of_catch_parameter(param: &CatchParameter) -> Self {
+        Self::of_pattern(&param.pattern)
+    }
+
+    pub fn of_pattern(pattern: &BindingPattern) -> Self {
+        let mut order = Self::default();
+        order.add_pattern(pattern);
+        order
+    }
+
+    pub fn bindings(&self) -> &[InitializedBinding] {
+        &self.bindings
+    }
+
+    pub fn evaluations(&self) -> &[Evaluation] {
+        &self.evaluations
+    }
+
+    /// The bindings of the pattern which `evaluation` may read.
+    pub fn visible_bindings(&self, evaluation: &Evaluation) -> &[InitializedBinding] {
+        &self.bindings[..evaluation.initialized_before]
+    }
+
+    /// The bindings of the pattern which are in their temporal dead zone during `evaluation`.
+    pub fn tdz_bindings(&self, evaluation: &Evaluation) -> &[InitializedBinding] {
+        &self.bindings[evaluation.initialized_before..]
+    }
+
+    /// The references which read a binding of the pattern before it is initialized, and throw a
+    /// `ReferenceError` when they are evaluated.
+    ///
+    /// The references in parameter initializers are resolved before the later parameters are
+    /// declared, so a reference is matched by name unless it resolves to a binding declared in
+    /// the expression itself, e.g. in a class static block.
+    pub fn tdz_references(&self, symbols: &SymbolTable) -> Vec<TdzReference> {
+        let mut references = vec![];
+        for (evaluation_index, evaluation) in self.evaluations.iter().enumerate() {
+            for (name, reference_id, span) in &evaluation.references {
+                let symbol_id = reference_id
+                    .and_then(|reference_id| symbols.references[reference_id].symbol_id());
+                if symbol_id
+                    .is_some_and(|symbol_id| contains(evaluation.span, symbols.get_span(symbol_id)))
+                {
+                    continue;
+                }
+                let binding_index = self
+                    .bindings
+                    .iter()
+                    .enumerate()
+                    .skip(evaluation.initialized_before)
+                    .find(|(_, binding)| binding.name == *name)
+                    .map(|(binding_index, _)| binding_index);
+                if let Some(binding_index) = binding_index {
+                    references.push(TdzReference {
+                        evaluation: evaluation_index,
+                        binding: binding_index,
+                        span: *span,
+                    });
+                }
+            }
+        }
+        references.sort_unstable_by_key(|reference| reference.span.start);
+        references
+    }
+
+    /// Whether every expression of the pattern only reads the bindings initialized before it,
+    /// so the bindings may be initialized one by one in separate declarations.
+    pub fn is_sequential(&self, symbols: &SymbolTable) -> bool {
+        self.tdz_references(symbols).is_empty()
+    }
+
+    fn add_pattern(&mut self, pattern: &BindingPattern) {
+        match &pattern.kind {
+            BindingPatternKind::BindingIdentifier(ident) => {
+                self.bindings.push(InitializedBinding {
+                    name: ident.name.to_compact_str(),
+                    symbol_id: ident.symbol_id.get(),
+                    span: ident.span,
+                });
+            }
+            BindingPatternKind::ObjectPattern(pattern) => {
+                for property in &pattern.properties {
+                    if property.computed {
+                        if let Some(key) = property.key.as_expression() {
+                            self.add_evaluation(EvaluationKind::ComputedKey, key);
+                        }
+                    }
+                    self.add_pattern(&property.value);
+                }
+                if let Some(rest) = &pattern.rest {
+                    self.add_pattern(&rest.argument);
+                }
+            }
+            BindingPatternKind::ArrayPattern(pattern) => {
+                for element in pattern.elements.iter().flatten() {
+                    self.add_pattern(element);
+                }
+                if let Some(rest) = &pattern.rest {
+                    self.add_pattern(&rest.argument);
+                }
+            }
+            // The default value is evaluated before the target is initialized.
+            BindingPatternKind::AssignmentPattern(pattern) => {
+                self.add_evaluation(EvaluationKind::Default, &pattern.right);
+                self.add_pattern(&pattern.left);
+            }
+        }
+    }
+
+    fn add_evaluation(&mut self, kind: EvaluationKind, expr: &Expression) {
+        let mut finder = DeferredFinder::default();
+        finder.visit_expression(expr);
+        self.evaluations.push(Evaluation {
+            kind,
+            span: expr.span(),
+            initialized_before: self.bindings.len(),
+            deferred: finder.deferred,
+            references: finder.references,
+        });
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// Finds the outermost bodies in an expression which are not evaluated with it, and the
+/// identifiers read outside of them.
+#[derive(Default)]
+struct DeferredFinder {
+    deferred: Vec<Span>,
+    references: Vec<(CompactStr, Option<ReferenceId>, Span)>,
+}
+
+impl<'a> Visit<'a> for DeferredFinder {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.references.push((ident.name.to_compact_str(), ident.reference_id.get(), ident.span));
+    }
+
+    fn visit_function(&mut self, func: &Function<'a>, _flags: Option<ScopeFlags>) {
+        self.deferred.push(func.span);
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &ArrowFunctionExpression<'a>) {
+        self.deferred.push(expr.span);
+    }
+
+    // The heritage, the computed keys and the static members of a class are evaluated with the
+    // class, the instance fields when it is constructed.
+    fn visit_property_definition(&mut self, def: &PropertyDefinition<'a>) {
+        match &def.value {
+            Some(value) if !def.r#static => {
+                if def.computed {
+                    if let Some(key) = def.key.as_expression() {
+                        self.visit_expression(key);
+                    }
+                }
+                self.deferred.push(value.span());
+            }
+            _ => walk_property_definition(self, def),
+        }
+    }
+}
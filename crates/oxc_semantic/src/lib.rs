@@ -4,14 +4,19 @@ mod checker;
 mod class;
 mod control_flow;
 mod diagnostics;
+mod initialization_order;
 mod jsdoc;
 mod label;
 mod module_record;
 mod node;
+mod object_shape;
 pub mod pg;
+mod post_transform_checker;
+mod purity;
 mod reference;
 mod scope;
 mod symbol;
+mod usage;
 
 use std::{rc::Rc, sync::Arc};
 
@@ -36,10 +41,21 @@ pub use crate::{
         ControlFlowGraph, EdgeType, ObjectPropertyAccessAssignmentValue, Register,
         UnaryExpressioneAssignmentValue, UpdateAssignmentValue,
     },
-    node::{AstNode, AstNodeId, AstNodes},
+    diagnostics::DIAGNOSTIC_CODES,
+    initialization_order::{
+        Evaluation, EvaluationKind, InitializationOrder, InitializedBinding, TdzReference,
+    },
+    node::{AstNode, AstNodeId, AstNodes, NodeFlags},
+    object_shape::{
+        property_key_name, AccessorPair, DefinedKey, Duplicate, ObjectShape, PropertyShape,
+    },
+    post_transform_checker::{
+        check_semantic_after_transform, SemanticMismatch, SemanticMismatchKind,
+    },
     reference::{Reference, ReferenceFlag, ReferenceId},
     scope::ScopeTree,
-    symbol::SymbolTable,
+    symbol::{ResolvedReferenceIds, SymbolTable},
+    usage::{ExportMode, SymbolUsage},
 };
 
 pub struct Semantic<'a> {
@@ -141,6 +157,12 @@ impl<'a> Semantic<'a> {
         self.nodes.get_node(self.symbols.get_declaration(symbol_id))
     }
 
+    /// Count the references to each symbol, where the exports which are used in `mode` count as
+    /// references from outside of the module, to find the unused symbols.
+    pub fn symbol_usage(&self, mode: &ExportMode) -> SymbolUsage {
+        SymbolUsage::new(self, mode)
+    }
+
     pub fn is_reference_to_global_variable(&self, ident: &IdentifierReference) -> bool {
         self.scopes().root_unresolved_references().contains_key(ident.name.as_str())
     }
@@ -238,6 +260,25 @@ mod tests {
         assert!(semantic.symbols().references.len() == 1);
     }
 
+    #[test]
+    fn diagnostic_codes() {
+        let source = "'use strict'; delete a;";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let program = oxc_parser::Parser::new(&allocator, source, source_type).parse().program;
+        let program = allocator.alloc(program);
+        let errors = SemanticBuilder::new(source, source_type)
+            .with_check_syntax_error(true)
+            .build(program)
+            .errors;
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].oxc_code.unwrap().to_string(), "oxc(semantic::delete_of_unqualified)");
+
+        let mut names = FxHashSet::default();
+        assert!(DIAGNOSTIC_CODES.iter().all(|code| names.insert(code.name)));
+        assert!(DIAGNOSTIC_CODES.iter().all(|code| code.scope == "semantic"));
+    }
+
     #[test]
     fn test_reference_resolutions_simple_read_write() {
         let alloc = Allocator::default();
@@ -1,10 +1,15 @@
 use std::path::PathBuf;
 
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::{ast::*, syntax_directed_operations::BoundNames};
+use oxc_ast::{
+    ast::*,
+    syntax_directed_operations::BoundNames,
+    visit::walk::{walk_for_of_statement, walk_statement},
+    Visit,
+};
 use oxc_span::{CompactStr, GetSpan, Span};
 #[allow(clippy::wildcard_imports)]
-use oxc_syntax::module_record::*;
+use oxc_syntax::{module_record::*, scope::ScopeFlags};
 
 #[derive(Default)]
 pub struct ModuleRecordBuilder {
@@ -31,18 +36,40 @@ impl ModuleRecordBuilder {
         // The `ParseModule` algorithm requires `importedBoundNames` (import entries) to be
         // resolved before resolving export entries.
         self.resolve_export_entries();
+
+        if program.source_type.is_module() {
+            let mut finder = TopLevelAwaitFinder::default();
+            finder.visit_statements(&program.body);
+            self.module_record.top_level_await = finder.span;
+        }
     }
 
     pub fn build(self) -> ModuleRecord {
         self.module_record
     }
 
-    fn add_module_request(&mut self, name_span: &NameSpan, is_type: bool, is_import: bool) {
+    fn add_module_request(
+        &mut self,
+        name_span: &NameSpan,
+        is_type: bool,
+        is_import: bool,
+        with_clause: Option<&WithClause>,
+    ) {
+        let import_attributes = with_clause.map_or_else(Vec::new, |with_clause| {
+            with_clause
+                .with_entries
+                .iter()
+                .map(|attr| ImportAttributeRecord {
+                    key: NameSpan::new(attr.key.as_atom().to_compact_str(), attr.key.span()),
+                    value: NameSpan::new(attr.value.value.to_compact_str(), attr.value.span),
+                })
+                .collect()
+        });
         self.module_record
             .requested_modules
             .entry(name_span.name().clone())
             .or_default()
-            .push(RequestedModule::new(name_span.span(), is_type, is_import));
+            .push(RequestedModule::new(name_span.span(), is_type, is_import, import_attributes));
     }
 
     fn add_import_entry(&mut self, entry: ImportEntry) {
@@ -206,6 +233,7 @@ impl ModuleRecordBuilder {
             &module_request,
             decl.import_kind.is_type(),
             /* is_import */ true,
+            decl.with_clause.as_ref(),
         );
     }
 
@@ -234,6 +262,7 @@ impl ModuleRecordBuilder {
             &module_request,
             decl.export_kind.is_type(),
             /* is_import */ false,
+            decl.with_clause.as_ref(),
         );
     }
 
@@ -285,6 +314,7 @@ impl ModuleRecordBuilder {
                 module_request,
                 decl.export_kind.is_type(),
                 /* is_import */ false,
+                decl.with_clause.as_ref(),
             );
         }
 
@@ -342,3 +372,33 @@ impl ModuleRecordBuilder {
         }
     }
 }
+
+/// Finds the first `await` which is not inside a function.
+#[derive(Default)]
+struct TopLevelAwaitFinder {
+    span: Option<Span>,
+}
+
+impl<'a> Visit<'a> for TopLevelAwaitFinder {
+    fn visit_statement(&mut self, stmt: &Statement<'a>) {
+        if self.span.is_none() {
+            walk_statement(self, stmt);
+        }
+    }
+
+    fn visit_await_expression(&mut self, expr: &AwaitExpression<'a>) {
+        self.span.get_or_insert(expr.span);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        if stmt.r#await {
+            self.span.get_or_insert(stmt.span);
+        } else {
+            walk_for_of_statement(self, stmt);
+        }
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {}
+}
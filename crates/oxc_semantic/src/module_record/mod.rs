@@ -6,7 +6,7 @@ pub use builder::ModuleRecordBuilder;
 mod module_record_tests {
     use oxc_allocator::Allocator;
     use oxc_parser::Parser;
-    use oxc_span::{SourceType, Span};
+    use oxc_span::{CompactStr, SourceType, Span};
     #[allow(clippy::wildcard_imports)]
     use oxc_syntax::module_record::*;
     use std::{path::PathBuf, sync::Arc};
@@ -14,13 +14,17 @@ mod module_record_tests {
     use crate::SemanticBuilder;
 
     fn build(source_text: &str) -> Arc<ModuleRecord> {
+        build_with_path(source_text, PathBuf::new())
+    }
+
+    fn build_with_path(source_text: &str, path: PathBuf) -> Arc<ModuleRecord> {
         let source_type = SourceType::default().with_module(true);
         let allocator = Allocator::default();
         let ret = Parser::new(&allocator, source_text, source_type).parse();
         let program = allocator.alloc(ret.program);
         let semantic_ret = SemanticBuilder::new(source_text, source_type)
             .with_trivias(ret.trivias)
-            .build_module_record(PathBuf::new(), program)
+            .build_module_record(path, program)
             .build(program);
         Arc::clone(&semantic_ret.semantic.module_record)
     }
@@ -277,4 +281,71 @@ mod module_record_tests {
             }
         );
     }
+
+    #[test]
+    fn import_attributes() {
+        let module_record = build(
+            "import a from 'a.json' with { type: 'json' };export * from 'b' with { 'type': 'css' };import 'c';",
+        );
+        let a = &module_record.requested_modules["a.json"][0];
+        assert_eq!(
+            a.import_attributes(),
+            [ImportAttributeRecord {
+                key: NameSpan::new("type".into(), Span::new(30, 34)),
+                value: NameSpan::new("json".into(), Span::new(36, 42)),
+            }]
+        );
+        assert_eq!(a.import_type().map(CompactStr::as_str), Some("json"));
+        let b = &module_record.requested_modules["b"][0];
+        assert_eq!(b.import_type().map(CompactStr::as_str), Some("css"));
+        let c = &module_record.requested_modules["c"][0];
+        assert!(c.import_attributes().is_empty());
+    }
+
+    #[test]
+    fn top_level_await() {
+        assert_eq!(build("await x;").top_level_await, Some(Span::new(0, 7)));
+        assert_eq!(build("for await (const x of y);").top_level_await, Some(Span::new(0, 25)));
+        assert_eq!(build("if (x) { foo(await y) }").top_level_await, Some(Span::new(13, 20)));
+        assert_eq!(build("async function f() { await x }").top_level_await, None);
+        assert_eq!(build("const f = async () => { await x }").top_level_await, None);
+
+        let dep = build_with_path("await x;", PathBuf::from("dep"));
+        let module_record = build_with_path("import 'dep';", PathBuf::from("main"));
+        assert!(!module_record.is_async());
+        module_record.loaded_modules.insert("dep".into(), dep);
+        assert!(module_record.is_async());
+    }
+
+    #[test]
+    fn resolve_star_exports() {
+        let a = build_with_path("export const x = 1, y = 2;", PathBuf::from("a"));
+        let b = build_with_path("export const x = 1;export { y } from 'a';", PathBuf::from("b"));
+        b.loaded_modules.insert("a".into(), Arc::clone(&a));
+        let module_record = build_with_path(
+            "export * from 'a';export * from 'b';export const z = 3;",
+            PathBuf::from("main"),
+        );
+        module_record.loaded_modules.insert("a".into(), a);
+        module_record.loaded_modules.insert("b".into(), b);
+
+        // `x` is declared by both `a` and `b`, `y` of `b` is re-exported from `a`.
+        assert_eq!(module_record.resolve_export("x"), ResolvedExport::Ambiguous);
+        assert_eq!(
+            module_record.resolve_export("y"),
+            ResolvedExport::Found(ResolvedBinding {
+                module: PathBuf::from("a"),
+                binding_name: ResolvedBindingName::Name("y".into()),
+            })
+        );
+        assert_eq!(
+            module_record.resolve_export("z"),
+            ResolvedExport::Found(ResolvedBinding {
+                module: PathBuf::from("main"),
+                binding_name: ResolvedBindingName::Name("z".into()),
+            })
+        );
+        assert_eq!(module_record.resolve_export("default"), ResolvedExport::NotFound);
+        assert_eq!(module_record.ambiguous_star_exports(), vec![CompactStr::from("x")]);
+    }
 }
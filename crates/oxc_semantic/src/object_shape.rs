@@ -0,0 +1,196 @@
+//! Property shape of object literals.
+//!
+//! Which properties of an object literal define the same key, which accessors form a getter and
+//! setter pair, and where spread properties are, shared by lint rules and the minifier so they
+//! agree on the names of keys:
+//!
+//! * Identifiers, strings, numbers, bigints, `null`, regular expressions and template literals
+//!   without expressions have a known name, e.g. `{ 0x1: a }` and `{ ["1"]: a }` both define `1`.
+//! * `__proto__: x` sets the prototype instead of defining a property, unlike
+//!   `["__proto__"]: x`, `__proto__() {}` or the shorthand `{ __proto__ }`.
+//! * Spread properties and other computed keys can define any key.
+
+use oxc_ast::ast::{ObjectExpression, ObjectPropertyKind, PropertyKey, PropertyKind};
+use oxc_span::{Atom, CompactStr, GetSpan, Span};
+use oxc_syntax::number::{BigintBase, ToJsString};
+use rustc_hash::FxHashMap;
+
+/// What a property of an object literal defines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DefinedKey {
+    /// A property with a known name.
+    Name(CompactStr),
+    /// Defines no property, `__proto__: x`.
+    Prototype,
+    /// Spread properties and unknown computed keys.
+    Unknown,
+}
+
+#[derive(Debug, Clone)]
+pub struct PropertyShape {
+    pub key: DefinedKey,
+    /// `PropertyKind::Init` for spread properties.
+    pub kind: PropertyKind,
+    pub is_spread: bool,
+    /// Span of the key, or of the spread property.
+    pub span: Span,
+}
+
+impl PropertyShape {
+    pub fn of(property: &ObjectPropertyKind) -> Self {
+        let property = match property {
+            ObjectPropertyKind::ObjectProperty(property) => property,
+            ObjectPropertyKind::SpreadProperty(spread) => {
+                return Self {
+                    key: DefinedKey::Unknown,
+                    kind: PropertyKind::Init,
+                    is_spread: true,
+                    span: spread.span,
+                };
+            }
+        };
+        let key = match property_key_name(&property.key) {
+            Some(name)
+                if name == "__proto__"
+                    && property.kind == PropertyKind::Init
+                    && !property.computed
+                    && !property.method
+                    && !property.shorthand =>
+            {
+                DefinedKey::Prototype
+            }
+            Some(name) => DefinedKey::Name(name),
+            None => DefinedKey::Unknown,
+        };
+        Self { key, kind: property.kind, is_spread: false, span: property.key.span() }
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        match &self.key {
+            DefinedKey::Name(name) => Some(name.as_str()),
+            DefinedKey::Prototype | DefinedKey::Unknown => None,
+        }
+    }
+
+    pub fn is_accessor(&self) -> bool {
+        matches!(self.kind, PropertyKind::Get | PropertyKind::Set)
+    }
+
+    /// Whether this property replaces the `earlier` definition. A getter or setter only replaces
+    /// an earlier definition of the same accessor, or an earlier data property.
+    pub fn replaces(&self, earlier: &Self) -> bool {
+        self.name().is_some_and(|name| earlier.name() == Some(name))
+            && (self.kind == PropertyKind::Init
+                || earlier.kind == PropertyKind::Init
+                || self.kind == earlier.kind)
+    }
+}
+
+/// The name of the property defined by `key`, if it is known without evaluating code.
+pub fn property_key_name(key: &PropertyKey) -> Option<CompactStr> {
+    match key {
+        PropertyKey::StaticIdentifier(ident) => Some(ident.name.to_compact_str()),
+        PropertyKey::StringLiteral(lit) => Some(lit.value.to_compact_str()),
+        PropertyKey::NumericLiteral(lit) => Some(lit.value.to_js_string().into()),
+        PropertyKey::BigintLiteral(lit) if lit.base == BigintBase::Decimal => {
+            let digits = lit.raw.trim_end_matches('n').replace('_', "");
+            Some(digits.into())
+        }
+        PropertyKey::NullLiteral(_) => Some("null".into()),
+        PropertyKey::RegExpLiteral(lit) => Some(lit.regex.to_string().into()),
+        PropertyKey::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+            lit.quasi().map(Atom::to_compact_str)
+        }
+        _ => None,
+    }
+}
+
+/// A getter and setter of the same name in an object literal, either of which may be missing.
+#[derive(Debug, Clone)]
+pub struct AccessorPair {
+    pub name: CompactStr,
+    /// Index of the last getter.
+    pub getter: Option<usize>,
+    /// Index of the last setter.
+    pub setter: Option<usize>,
+}
+
+/// A property which replaces an earlier property of the same object literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Duplicate {
+    /// Index of the nearest earlier property it replaces.
+    pub earlier: usize,
+    pub later: usize,
+}
+
+/// The shapes of the properties of an object literal, indexed like its properties.
+#[derive(Debug, Clone)]
+pub struct ObjectShape {
+    properties: Vec<PropertyShape>,
+}
+
+impl ObjectShape {
+    pub fn new(object: &ObjectExpression) -> Self {
+        Self { properties: object.properties.iter().map(PropertyShape::of).collect() }
+    }
+
+    pub fn properties(&self) -> &[PropertyShape] {
+        &self.properties
+    }
+
+    /// Indices of the spread properties.
+    pub fn spreads(&self) -> impl Iterator<Item = usize> + '_ {
+        self.properties.iter().enumerate().filter(|(_, shape)| shape.is_spread).map(|(i, _)| i)
+    }
+
+    /// The getters and setters with known names, in the order of their first accessor. A data
+    /// property replaces the accessors before it, so they end a pair.
+    pub fn accessor_pairs(&self) -> Vec<AccessorPair> {
+        let mut pairs: Vec<AccessorPair> = vec![];
+        // The index in `pairs` of the current pair of each name.
+        let mut current = FxHashMap::<&str, usize>::default();
+        for (i, shape) in self.properties.iter().enumerate() {
+            let Some(name) = shape.name() else { continue };
+            if !shape.is_accessor() {
+                current.remove(name);
+                continue;
+            }
+            let index = *current.entry(name).or_insert_with(|| {
+                pairs.push(AccessorPair { name: name.into(), getter: None, setter: None });
+                pairs.len() - 1
+            });
+            let pair = &mut pairs[index];
+            if shape.kind == PropertyKind::Get {
+                pair.getter = Some(i);
+            } else {
+                pair.setter = Some(i);
+            }
+        }
+        pairs
+    }
+
+    /// The properties which replace an earlier definition of the same key, anywhere before them.
+    pub fn duplicates(&self) -> Vec<Duplicate> {
+        let mut duplicates = vec![];
+        // The last data property, getter and setter of each name.
+        let mut last = FxHashMap::<&str, [Option<usize>; 3]>::default();
+        for (later, shape) in self.properties.iter().enumerate() {
+            let Some(name) = shape.name() else { continue };
+            let [init, get, set] = last.entry(name).or_default();
+            let earlier = match shape.kind {
+                PropertyKind::Init => (*init).max(*get).max(*set),
+                PropertyKind::Get => (*init).max(*get),
+                PropertyKind::Set => (*init).max(*set),
+            };
+            if let Some(earlier) = earlier {
+                duplicates.push(Duplicate { earlier, later });
+            }
+            match shape.kind {
+                PropertyKind::Init => *init = Some(later),
+                PropertyKind::Get => *get = Some(later),
+                PropertyKind::Set => *set = Some(later),
+            }
+        }
+        duplicates
+    }
+}
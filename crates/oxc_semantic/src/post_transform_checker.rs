@@ -0,0 +1,393 @@
+//! Check that the symbol table and scope tree maintained by a transform match the transformed AST.
+//!
+//! Transforms which add, move or remove bindings are expected to keep the ids in the AST
+//! (`scope_id`, `symbol_id` and `reference_id`) and the [SymbolTable] and [ScopeTree] in sync.
+//! [check_semantic_after_transform] rebuilds the semantic model from the transformed AST and
+//! compares it with the provided one, so that transform authors can validate their passes in tests.
+
+use std::{cell::Cell, fmt};
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstKind, Visit};
+use oxc_span::{CompactStr, GetSpan, Span};
+use oxc_syntax::{scope::ScopeId, symbol::SymbolId};
+use rustc_hash::FxHashMap;
+
+use crate::{reference::ReferenceId, ScopeTree, SemanticBuilder, SymbolTable};
+
+/// What is out of sync between the AST and the provided [SymbolTable] or [ScopeTree].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SemanticMismatchKind {
+    /// A node which creates a scope has no `scope_id`, or an id which is not in the scope tree.
+    MissingScopeId,
+    /// The parent scope differs.
+    ScopeParent,
+    /// The scope flags differ.
+    ScopeFlags,
+    /// A binding identifier has no `symbol_id`, or an id which is not in the symbol table.
+    MissingSymbolId,
+    /// The symbol name differs from the binding identifier name.
+    SymbolName,
+    /// The symbol flags differ.
+    SymbolFlags,
+    /// The symbol is declared in a different scope.
+    SymbolScope,
+    /// An identifier reference has no `reference_id`, or an id which is not in the symbol table.
+    MissingReferenceId,
+    /// The reference resolves to a different symbol.
+    ReferenceSymbol,
+}
+
+/// A difference between the provided semantic model and the one rebuilt from the AST.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SemanticMismatch {
+    pub kind: SemanticMismatchKind,
+    /// Span of the AST node the mismatch is found on.
+    pub span: Span,
+    /// Symbol of the node, for symbol and reference mismatches.
+    pub symbol_id: Option<SymbolId>,
+    /// Scope of the node, for scope mismatches.
+    pub scope_id: Option<ScopeId>,
+    /// Value rebuilt from the AST, expressed with the ids of the provided tables.
+    pub expected: String,
+    /// Value found in the provided tables.
+    pub actual: String,
+}
+
+impl fmt::Display for SemanticMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} at {}..{}: expected {}, found {}",
+            self.kind, self.span.start, self.span.end, self.expected, self.actual
+        )
+    }
+}
+
+/// Compare `symbols` and `scopes` with a semantic model rebuilt from `program`.
+///
+/// Ids are matched by node, so the provided tables may be numbered differently than a fresh
+/// [SemanticBuilder] would. The ids in the AST are left untouched.
+///
+/// Returns an empty `Vec` if the provided tables are consistent with the AST.
+pub fn check_semantic_after_transform(
+    symbols: &SymbolTable,
+    scopes: &ScopeTree,
+    program: &Program<'_>,
+) -> Vec<SemanticMismatch> {
+    let mut provided = SemanticIds::default();
+    provided.visit_program(program);
+
+    // Clear the ids so that nodes skipped by the builder are not read as fresh ids.
+    SemanticIdsWriter::new(&SemanticIds::empty_like(&provided)).visit_program(program);
+    let (fresh_symbols, fresh_scopes) = SemanticBuilder::new("", program.source_type)
+        .build(program)
+        .semantic
+        .into_symbol_table_and_scope_tree();
+    let mut fresh = SemanticIds::default();
+    fresh.visit_program(program);
+    SemanticIdsWriter::new(&provided).visit_program(program);
+
+    let mut checker = PostTransformChecker {
+        symbols,
+        scopes,
+        fresh_symbols: &fresh_symbols,
+        fresh_scopes: &fresh_scopes,
+        scope_map: FxHashMap::default(),
+        symbol_map: FxHashMap::default(),
+        mismatches: vec![],
+    };
+    checker.check_scopes(&provided.scopes, &fresh.scopes);
+    checker.check_symbols(&provided.symbols, &fresh.symbols);
+    checker.check_references(&provided.references, &fresh.references);
+    checker.mismatches
+}
+
+struct PostTransformChecker<'s> {
+    symbols: &'s SymbolTable,
+    scopes: &'s ScopeTree,
+    fresh_symbols: &'s SymbolTable,
+    fresh_scopes: &'s ScopeTree,
+    /// Fresh scope id -> provided scope id
+    scope_map: FxHashMap<ScopeId, ScopeId>,
+    /// Fresh symbol id -> provided symbol id
+    symbol_map: FxHashMap<SymbolId, SymbolId>,
+    mismatches: Vec<SemanticMismatch>,
+}
+
+impl PostTransformChecker<'_> {
+    fn report(
+        &mut self,
+        kind: SemanticMismatchKind,
+        span: Span,
+        symbol_id: Option<SymbolId>,
+        scope_id: Option<ScopeId>,
+        expected: impl fmt::Debug,
+        actual: impl fmt::Debug,
+    ) {
+        self.mismatches.push(SemanticMismatch {
+            kind,
+            span,
+            symbol_id,
+            scope_id,
+            expected: format!("{expected:?}"),
+            actual: format!("{actual:?}"),
+        });
+    }
+
+    fn check_scopes(
+        &mut self,
+        provided: &[(Option<ScopeId>, Span)],
+        fresh: &[(Option<ScopeId>, Span)],
+    ) {
+        let mut pairs = vec![];
+        for (&(actual, span), &(expected, _)) in provided.iter().zip(fresh) {
+            // The node does not create a scope
+            let Some(expected) = expected else { continue };
+            match actual {
+                Some(actual) if actual.index() < self.scopes.len() => {
+                    self.scope_map.insert(expected, actual);
+                    pairs.push((expected, actual, span));
+                }
+                _ => {
+                    self.report(
+                        SemanticMismatchKind::MissingScopeId,
+                        span,
+                        None,
+                        None,
+                        "a scope id",
+                        actual,
+                    );
+                }
+            }
+        }
+
+        for (expected, actual, span) in pairs {
+            let fresh_parent = self.fresh_scopes.get_parent_id(expected);
+            let expected_parent = fresh_parent.and_then(|id| self.scope_map.get(&id).copied());
+            // An unmapped parent is already reported as missing
+            if fresh_parent.is_none() || expected_parent.is_some() {
+                let actual_parent = self.scopes.get_parent_id(actual);
+                if expected_parent != actual_parent {
+                    self.report(
+                        SemanticMismatchKind::ScopeParent,
+                        span,
+                        None,
+                        Some(actual),
+                        expected_parent,
+                        actual_parent,
+                    );
+                }
+            }
+
+            let expected_flags = self.fresh_scopes.get_flags(expected);
+            let actual_flags = self.scopes.get_flags(actual);
+            if expected_flags != actual_flags {
+                self.report(
+                    SemanticMismatchKind::ScopeFlags,
+                    span,
+                    None,
+                    Some(actual),
+                    expected_flags,
+                    actual_flags,
+                );
+            }
+        }
+    }
+
+    fn check_symbols(
+        &mut self,
+        provided: &[(Option<SymbolId>, Span, CompactStr)],
+        fresh: &[(Option<SymbolId>, Span, CompactStr)],
+    ) {
+        for ((actual, span, name), (expected, _, _)) in provided.iter().zip(fresh) {
+            let (actual, span) = (*actual, *span);
+            // The identifier does not declare a symbol, e.g. a TypeScript type parameter
+            let Some(expected) = *expected else { continue };
+            let Some(actual) = actual.filter(|id| id.index() < self.symbols.len()) else {
+                self.report(
+                    SemanticMismatchKind::MissingSymbolId,
+                    span,
+                    None,
+                    None,
+                    "a symbol id",
+                    actual,
+                );
+                continue;
+            };
+            self.symbol_map.insert(expected, actual);
+
+            let actual_name = self.symbols.get_name(actual);
+            if actual_name != name.as_str() {
+                self.report(
+                    SemanticMismatchKind::SymbolName,
+                    span,
+                    Some(actual),
+                    None,
+                    name.as_str(),
+                    actual_name,
+                );
+            }
+
+            let expected_flags = self.fresh_symbols.get_flag(expected);
+            let actual_flags = self.symbols.get_flag(actual);
+            if expected_flags.bits() != actual_flags.bits() {
+                self.report(
+                    SemanticMismatchKind::SymbolFlags,
+                    span,
+                    Some(actual),
+                    None,
+                    expected_flags,
+                    actual_flags,
+                );
+            }
+
+            let fresh_scope = self.fresh_symbols.get_scope_id(expected);
+            // An unmapped scope is already reported as missing
+            if let Some(&expected_scope) = self.scope_map.get(&fresh_scope) {
+                let actual_scope = self.symbols.get_scope_id(actual);
+                if expected_scope != actual_scope {
+                    self.report(
+                        SemanticMismatchKind::SymbolScope,
+                        span,
+                        Some(actual),
+                        Some(actual_scope),
+                        expected_scope,
+                        actual_scope,
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_references(
+        &mut self,
+        provided: &[(Option<ReferenceId>, Span)],
+        fresh: &[(Option<ReferenceId>, Span)],
+    ) {
+        for (&(actual, span), &(expected, _)) in provided.iter().zip(fresh) {
+            let Some(expected) = expected else { continue };
+            let Some(actual) = actual.filter(|id| id.index() < self.symbols.references.len())
+            else {
+                self.report(
+                    SemanticMismatchKind::MissingReferenceId,
+                    span,
+                    None,
+                    None,
+                    "a reference id",
+                    actual,
+                );
+                continue;
+            };
+
+            let fresh_symbol = self.fresh_symbols.get_reference(expected).symbol_id();
+            let expected_symbol = fresh_symbol.and_then(|id| self.symbol_map.get(&id).copied());
+            // An unmapped symbol is already reported as missing
+            if fresh_symbol.is_some() && expected_symbol.is_none() {
+                continue;
+            }
+            let actual_symbol = self.symbols.get_reference(actual).symbol_id();
+            if expected_symbol != actual_symbol {
+                self.report(
+                    SemanticMismatchKind::ReferenceSymbol,
+                    span,
+                    actual_symbol,
+                    None,
+                    expected_symbol,
+                    actual_symbol,
+                );
+            }
+        }
+    }
+}
+
+/// Ids stored in the AST, in visitation order.
+#[derive(Default)]
+struct SemanticIds {
+    scopes: Vec<(Option<ScopeId>, Span)>,
+    symbols: Vec<(Option<SymbolId>, Span, CompactStr)>,
+    references: Vec<(Option<ReferenceId>, Span)>,
+}
+
+impl SemanticIds {
+    fn empty_like(other: &Self) -> Self {
+        Self {
+            scopes: other.scopes.iter().map(|&(_, span)| (None, span)).collect(),
+            symbols: other
+                .symbols
+                .iter()
+                .map(|(_, span, name)| (None, *span, name.clone()))
+                .collect(),
+            references: other.references.iter().map(|&(_, span)| (None, span)).collect(),
+        }
+    }
+}
+
+fn scope_id_cell(kind: AstKind<'_>) -> Option<&Cell<Option<ScopeId>>> {
+    let cell = match kind {
+        AstKind::Program(node) => &node.scope_id,
+        AstKind::BlockStatement(node) => &node.scope_id,
+        AstKind::ForStatement(node) => &node.scope_id,
+        AstKind::ForInStatement(node) => &node.scope_id,
+        AstKind::ForOfStatement(node) => &node.scope_id,
+        AstKind::SwitchStatement(node) => &node.scope_id,
+        AstKind::CatchClause(node) => &node.scope_id,
+        AstKind::Function(node) => &node.scope_id,
+        AstKind::ArrowFunctionExpression(node) => &node.scope_id,
+        AstKind::Class(node) => &node.scope_id,
+        AstKind::StaticBlock(node) => &node.scope_id,
+        AstKind::TSEnumDeclaration(node) => &node.scope_id,
+        AstKind::TSModuleBlock(node) => &node.scope_id,
+        _ => return None,
+    };
+    Some(cell)
+}
+
+impl<'a> Visit<'a> for SemanticIds {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        if let Some(cell) = scope_id_cell(kind) {
+            self.scopes.push((cell.get(), kind.span()));
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        self.symbols.push((ident.symbol_id.get(), ident.span, ident.name.to_compact_str()));
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.references.push((ident.reference_id.get(), ident.span));
+    }
+}
+
+/// Writes ids collected by [SemanticIds] back into the same AST.
+struct SemanticIdsWriter<'s> {
+    ids: &'s SemanticIds,
+    scope_index: usize,
+    symbol_index: usize,
+    reference_index: usize,
+}
+
+impl<'s> SemanticIdsWriter<'s> {
+    fn new(ids: &'s SemanticIds) -> Self {
+        Self { ids, scope_index: 0, symbol_index: 0, reference_index: 0 }
+    }
+}
+
+impl<'a> Visit<'a> for SemanticIdsWriter<'_> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        if let Some(cell) = scope_id_cell(kind) {
+            cell.set(self.ids.scopes[self.scope_index].0);
+            self.scope_index += 1;
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        ident.symbol_id.set(self.ids.symbols[self.symbol_index].0);
+        self.symbol_index += 1;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        ident.reference_id.set(self.ids.references[self.reference_index].0);
+        self.reference_index += 1;
+    }
+}
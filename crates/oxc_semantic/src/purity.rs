@@ -0,0 +1,318 @@
+//! Function Purity
+//!
+//! Finds the functions whose calls have no side effects, so that a call whose result is unused
+//! can be removed:
+//!
+//! * Functions annotated with `/* @__NO_SIDE_EFFECTS__ */` or `/* #__NO_SIDE_EFFECTS__ */`,
+//!   before the function or the declaration it is assigned to.
+//! * Small functions which are inferred to be pure, when every statement of their body is
+//!   a `return`, an `if`, a block, an expression statement or a variable declaration, and
+//!   every expression only reads bindings which are declared or global constants, writes
+//!   bindings declared in the function, and calls other functions of the set. Member accesses,
+//!   `new`, `throw`, loops, spreads and destructuring are rejected, as they may run unknown code
+//!   or throw. Recursive functions are rejected, as they may never return.
+//!
+//! Only `function f() {}`, `const f = () => {}` and `const f = function () {}` bindings which
+//! are never reassigned are considered, so every call through the binding calls the analyzed
+//! function. Async functions and generators are rejected.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, match_expression, AstKind, Trivias};
+use oxc_span::GetSpan;
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use rustc_hash::FxHashSet;
+
+use crate::{node::AstNodes, reference::Reference, symbol::SymbolTable, SymbolId};
+
+/// Functions with more statements than this are not analyzed.
+const MAX_STATEMENTS: usize = 8;
+
+/// A function which may be added to the `no_side_effects` set.
+struct Candidate {
+    symbol_id: SymbolId,
+    /// Functions called by this function, which must have no side effects themselves.
+    calls: Vec<SymbolId>,
+}
+
+pub(crate) fn find_no_side_effects(
+    nodes: &AstNodes,
+    symbols: &SymbolTable,
+    trivias: &Trivias,
+    source_text: &str,
+) -> FxHashSet<SymbolId> {
+    let mut no_side_effects = FxHashSet::default();
+    let mut candidates = vec![];
+    for node in nodes.iter() {
+        let (symbol_id, params, body, annotated) = match node.kind() {
+            AstKind::Function(func) if func.is_declaration() => {
+                let Some(symbol_id) = func.id.as_ref().and_then(|id| id.symbol_id.get()) else {
+                    continue;
+                };
+                let Some(body) = &func.body else { continue };
+                if func.r#async || func.generator {
+                    continue;
+                }
+                let annotated =
+                    has_no_side_effects_annotation(trivias, source_text, func.span.start);
+                (symbol_id, &func.params, body, annotated)
+            }
+            AstKind::VariableDeclarator(decl) if decl.kind.is_const() => {
+                let BindingPatternKind::BindingIdentifier(id) = &decl.id.kind else { continue };
+                let Some(symbol_id) = id.symbol_id.get() else { continue };
+                let (params, body) = match &decl.init {
+                    Some(Expression::ArrowFunctionExpression(arrow)) if !arrow.r#async => {
+                        (&arrow.params, &arrow.body)
+                    }
+                    Some(Expression::FunctionExpression(func))
+                        if !func.r#async && !func.generator =>
+                    {
+                        let Some(body) = &func.body else { continue };
+                        (&func.params, body)
+                    }
+                    _ => continue,
+                };
+                // `/* @__NO_SIDE_EFFECTS__ */ const f = () => {}` or `const f = /* ... */ () => {}`
+                let decl_start = match nodes.parent_kind(node.id()) {
+                    Some(AstKind::VariableDeclaration(var_decl))
+                        if var_decl.declarations.len() == 1 =>
+                    {
+                        var_decl.span.start
+                    }
+                    _ => decl.span.start,
+                };
+                let annotated =
+                    [decl_start, decl.init.as_ref().map_or(0, |init| init.span().start)]
+                        .into_iter()
+                        .any(|start| has_no_side_effects_annotation(trivias, source_text, start));
+                (symbol_id, params, body, annotated)
+            }
+            _ => continue,
+        };
+        // A reassigned binding may call another function.
+        if symbols.get_resolved_references(symbol_id).any(Reference::is_write)
+            || !symbols.get_redeclare_variables(symbol_id).is_empty()
+        {
+            continue;
+        }
+        if annotated {
+            no_side_effects.insert(symbol_id);
+            continue;
+        }
+        let mut checker = PurityChecker::new(symbols);
+        if checker.check_function(params, body) {
+            candidates.push(Candidate { symbol_id, calls: checker.calls });
+        }
+    }
+
+    // Add the functions which only call functions of the set, until none can be added.
+    loop {
+        let len = no_side_effects.len();
+        candidates.retain(|candidate| {
+            if candidate.calls.iter().all(|symbol_id| no_side_effects.contains(symbol_id)) {
+                no_side_effects.insert(candidate.symbol_id);
+                return false;
+            }
+            true
+        });
+        if no_side_effects.len() == len {
+            break;
+        }
+    }
+    no_side_effects
+}
+
+/// `/* @__NO_SIDE_EFFECTS__ */` right before `start`, or before the `export` keywords there.
+fn has_no_side_effects_annotation(trivias: &Trivias, source_text: &str, start: u32) -> bool {
+    let Some((comment_start, comment)) = trivias.comments_range(..start).next_back() else {
+        return false;
+    };
+    let Some(text) = source_text.get(*comment_start as usize..comment.end as usize) else {
+        return false;
+    };
+    if !comment.kind.is_multi_line()
+        || !matches!(text.trim(), "@__NO_SIDE_EFFECTS__" | "#__NO_SIDE_EFFECTS__")
+    {
+        return false;
+    }
+    // Trivias exclude the comment delimiters.
+    let Some(between) = source_text.get(comment.end as usize + 2..start as usize) else {
+        return false;
+    };
+    matches!(
+        between.split_whitespace().collect::<Vec<_>>()[..],
+        [] | ["export"] | ["export", "default"]
+    )
+}
+
+struct PurityChecker<'s> {
+    symbols: &'s SymbolTable,
+    /// Bindings declared in the function, which it may write.
+    declared: FxHashSet<SymbolId>,
+    /// Bindings written by the function.
+    written: Vec<SymbolId>,
+    /// Functions called by the function.
+    calls: Vec<SymbolId>,
+}
+
+impl<'s> PurityChecker<'s> {
+    fn new(symbols: &'s SymbolTable) -> Self {
+        Self { symbols, declared: FxHashSet::default(), written: vec![], calls: vec![] }
+    }
+
+    fn check_function(&mut self, params: &FormalParameters, body: &FunctionBody) -> bool {
+        if params.rest.is_some() || body.statements.len() > MAX_STATEMENTS {
+            return false;
+        }
+        // Destructuring and default values may run unknown code.
+        for param in &params.items {
+            let BindingPatternKind::BindingIdentifier(id) = &param.pattern.kind else {
+                return false;
+            };
+            self.declare(id);
+        }
+        body.statements.iter().all(|stmt| self.check_statement(stmt))
+            && self.written.iter().all(|symbol_id| self.declared.contains(symbol_id))
+    }
+
+    fn declare(&mut self, id: &BindingIdentifier) {
+        if let Some(symbol_id) = id.symbol_id.get() {
+            self.declared.insert(symbol_id);
+        }
+    }
+
+    fn check_statement(&mut self, stmt: &Statement) -> bool {
+        match stmt {
+            Statement::EmptyStatement(_) => true,
+            Statement::ExpressionStatement(stmt) => self.check_expression(&stmt.expression),
+            Statement::ReturnStatement(stmt) => {
+                stmt.argument.as_ref().map_or(true, |arg| self.check_expression(arg))
+            }
+            Statement::IfStatement(stmt) => {
+                self.check_expression(&stmt.test)
+                    && self.check_statement(&stmt.consequent)
+                    && stmt.alternate.as_ref().map_or(true, |alt| self.check_statement(alt))
+            }
+            Statement::BlockStatement(block) => {
+                block.body.iter().all(|stmt| self.check_statement(stmt))
+            }
+            Statement::VariableDeclaration(decl) => decl.declarations.iter().all(|declarator| {
+                let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else {
+                    return false;
+                };
+                self.declare(id);
+                declarator.init.as_ref().map_or(true, |init| self.check_expression(init))
+            }),
+            // Declaring a function has no side effects, calling it is checked at the call.
+            Statement::FunctionDeclaration(func) => {
+                if let Some(id) = &func.id {
+                    self.declare(id);
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn check_expression(&mut self, expr: &Expression) -> bool {
+        match expr {
+            Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BigintLiteral(_)
+            | Expression::RegExpLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::ThisExpression(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ArrowFunctionExpression(_) => true,
+            Expression::TemplateLiteral(lit) => {
+                lit.expressions.iter().all(|expr| self.check_expression(expr))
+            }
+            Expression::Identifier(ident) => self.check_identifier_read(ident),
+            Expression::ArrayExpression(array) => {
+                array.elements.iter().all(|element| match element {
+                    ArrayExpressionElement::SpreadElement(_) => false,
+                    ArrayExpressionElement::Elision(_) => true,
+                    match_expression!(ArrayExpressionElement) => {
+                        self.check_expression(element.to_expression())
+                    }
+                })
+            }
+            Expression::ObjectExpression(object) => {
+                object.properties.iter().all(|property| match property {
+                    ObjectPropertyKind::ObjectProperty(property) => {
+                        let key = match &property.key {
+                            PropertyKey::StaticIdentifier(_)
+                            | PropertyKey::PrivateIdentifier(_) => true,
+                            key @ match_expression!(PropertyKey) => {
+                                self.check_expression(key.to_expression())
+                            }
+                        };
+                        key && self.check_expression(&property.value)
+                    }
+                    ObjectPropertyKind::SpreadProperty(_) => false,
+                })
+            }
+            Expression::UnaryExpression(expr) => {
+                expr.operator != UnaryOperator::Delete && self.check_expression(&expr.argument)
+            }
+            // `in` and `instanceof` throw when the right side is not an object.
+            Expression::BinaryExpression(expr) => {
+                !matches!(expr.operator, BinaryOperator::In | BinaryOperator::Instanceof)
+                    && self.check_expression(&expr.left)
+                    && self.check_expression(&expr.right)
+            }
+            Expression::LogicalExpression(expr) => {
+                self.check_expression(&expr.left) && self.check_expression(&expr.right)
+            }
+            Expression::ConditionalExpression(expr) => {
+                self.check_expression(&expr.test)
+                    && self.check_expression(&expr.consequent)
+                    && self.check_expression(&expr.alternate)
+            }
+            Expression::SequenceExpression(expr) => {
+                expr.expressions.iter().all(|expr| self.check_expression(expr))
+            }
+            Expression::ParenthesizedExpression(expr) => self.check_expression(&expr.expression),
+            Expression::AssignmentExpression(expr) => {
+                let AssignmentTarget::AssignmentTargetIdentifier(ident) = &expr.left else {
+                    return false;
+                };
+                self.check_identifier_write(ident) && self.check_expression(&expr.right)
+            }
+            Expression::UpdateExpression(expr) => {
+                let SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) = &expr.argument
+                else {
+                    return false;
+                };
+                self.check_identifier_write(ident)
+            }
+            Expression::CallExpression(call) => {
+                let Expression::Identifier(callee) = &call.callee else { return false };
+                let Some(symbol_id) = self.resolve(callee) else { return false };
+                self.calls.push(symbol_id);
+                call.arguments.iter().all(|arg| match arg {
+                    Argument::SpreadElement(_) => false,
+                    arg @ match_expression!(Argument) => self.check_expression(arg.to_expression()),
+                })
+            }
+            _ => false,
+        }
+    }
+
+    fn resolve(&self, ident: &IdentifierReference) -> Option<SymbolId> {
+        let reference_id = ident.reference_id.get()?;
+        self.symbols.get_reference(reference_id).symbol_id()
+    }
+
+    /// Reading an unresolved global throws when it is not defined.
+    fn check_identifier_read(&self, ident: &IdentifierReference) -> bool {
+        self.resolve(ident).is_some()
+            || matches!(ident.name.as_str(), "undefined" | "NaN" | "Infinity")
+    }
+
+    fn check_identifier_write(&mut self, ident: &IdentifierReference) -> bool {
+        let Some(symbol_id) = self.resolve(ident) else { return false };
+        self.written.push(symbol_id);
+        true
+    }
+}
@@ -130,7 +130,7 @@ impl ScopeTree {
         &mut self.bindings[scope_id]
     }
 
-    pub(crate) fn add_scope(&mut self, parent_id: Option<ScopeId>, flags: ScopeFlags) -> ScopeId {
+    pub fn add_scope(&mut self, parent_id: Option<ScopeId>, flags: ScopeFlags) -> ScopeId {
         let scope_id = self.parent_ids.push(parent_id);
         _ = self.flags.push(flags);
         _ = self.bindings.push(Bindings::default());
@@ -143,7 +143,17 @@ impl ScopeTree {
         scope_id
     }
 
-    pub(crate) fn add_node_id(&mut self, scope_id: ScopeId, node_id: AstNodeId) {
+    /// Remove `scope_id` from the children of its parent, when its node is removed from the AST.
+    /// The scope itself is kept, so that the ids of the other scopes do not change.
+    pub fn detach_scope(&mut self, scope_id: ScopeId) {
+        if let Some(parent_id) = self.parent_ids[scope_id] {
+            if let Some(child_ids) = self.child_ids.get_mut(&parent_id) {
+                child_ids.retain(|&child_id| child_id != scope_id);
+            }
+        }
+    }
+
+    pub fn add_node_id(&mut self, scope_id: ScopeId, node_id: AstNodeId) {
         self.node_ids.insert(scope_id, node_id);
     }
 
@@ -155,7 +165,7 @@ impl ScopeTree {
         self.bindings[scope_id].shift_remove(name);
     }
 
-    pub(crate) fn add_unresolved_reference(
+    pub fn add_unresolved_reference(
         &mut self,
         scope_id: ScopeId,
         name: CompactStr,
@@ -164,6 +174,21 @@ impl ScopeTree {
         self.unresolved_references[scope_id].entry(name).or_default().push(reference_id);
     }
 
+    pub fn remove_unresolved_reference(
+        &mut self,
+        scope_id: ScopeId,
+        name: &str,
+        reference_id: ReferenceId,
+    ) {
+        let references = &mut self.unresolved_references[scope_id];
+        if let Some(reference_ids) = references.get_mut(name) {
+            reference_ids.retain(|&id| id != reference_id);
+            if reference_ids.is_empty() {
+                references.remove(name);
+            }
+        }
+    }
+
     pub(crate) fn extend_unresolved_reference(
         &mut self,
         scope_id: ScopeId,
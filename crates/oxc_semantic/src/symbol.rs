@@ -7,6 +7,7 @@ pub use oxc_syntax::{
     scope::ScopeId,
     symbol::{SymbolFlags, SymbolId},
 };
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     node::AstNodeId,
@@ -26,7 +27,12 @@ export type IndexVec<I, T> = Array<T>;
 
 /// Symbol Table
 ///
-/// `SoA` (Struct of Arrays) for memory efficiency.
+/// `SoA` (Struct of Arrays) for memory efficiency. Each column is an array indexed by the `u32`
+/// [SymbolId] or [ReferenceId], and no column allocates for each symbol: the references resolved
+/// to a symbol are linked through `next_resolved_references` and `previous_resolved_references`
+/// instead of being kept in a `Vec` for each symbol, so that a reference is removed in constant
+/// time, and the few redeclared symbols are kept in a map. Names are not interned, they are
+/// inline [CompactStr]s, which only allocate for names longer than 24 bytes.
 #[derive(Debug, Default)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Tsify), serde(rename_all = "camelCase"))]
 pub struct SymbolTable {
@@ -36,9 +42,26 @@ pub struct SymbolTable {
     pub scope_ids: IndexVec<SymbolId, ScopeId>,
     /// Pointer to the AST Node where this symbol is declared
     pub declarations: IndexVec<SymbolId, AstNodeId>,
-    pub resolved_references: IndexVec<SymbolId, Vec<ReferenceId>>,
+    /// The first and the last of the references resolved to a symbol, in the order they were
+    /// resolved. The symbol has no resolved references when it is `None`.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    resolved_reference_ends: IndexVec<SymbolId, Option<(ReferenceId, ReferenceId)>>,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    resolved_reference_counts: IndexVec<SymbolId, u32>,
     pub references: IndexVec<ReferenceId, Reference>,
-    pub redeclare_variables: IndexVec<SymbolId, Vec<Span>>,
+    /// The reference resolved to the same symbol after a reference.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    next_resolved_references: IndexVec<ReferenceId, Option<ReferenceId>>,
+    /// The reference resolved to the same symbol before a reference.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    previous_resolved_references: IndexVec<ReferenceId, Option<ReferenceId>>,
+    /// The spans of the redeclarations of a symbol, e.g. the second `a` in `var a; var a`.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    redeclare_variables: FxHashMap<SymbolId, Vec<Span>>,
+    /// Functions whose calls have no side effects, annotated with `@__NO_SIDE_EFFECTS__` or
+    /// inferred from their body.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub no_side_effects: FxHashSet<SymbolId>,
 }
 
 impl SymbolTable {
@@ -90,8 +113,8 @@ impl SymbolTable {
         self.flags[symbol_id]
     }
 
-    pub fn get_redeclare_variables(&self, symbol_id: SymbolId) -> &Vec<Span> {
-        &self.redeclare_variables[symbol_id]
+    pub fn get_redeclare_variables(&self, symbol_id: SymbolId) -> &[Span] {
+        self.redeclare_variables.get(&symbol_id).map_or(&[], Vec::as_slice)
     }
 
     pub fn union_flag(&mut self, symbol_id: SymbolId, includes: SymbolFlags) {
@@ -125,8 +148,8 @@ impl SymbolTable {
         _ = self.names.push(CompactStr::from(name));
         _ = self.flags.push(flag);
         _ = self.scope_ids.push(scope_id);
-        _ = self.resolved_references.push(vec![]);
-        self.redeclare_variables.push(vec![])
+        _ = self.resolved_reference_ends.push(None);
+        self.resolved_reference_counts.push(0)
     }
 
     pub fn add_declaration(&mut self, node_id: AstNodeId) {
@@ -134,13 +157,54 @@ impl SymbolTable {
     }
 
     pub fn add_redeclare_variable(&mut self, symbol_id: SymbolId, span: Span) {
-        self.redeclare_variables[symbol_id].push(span);
+        self.redeclare_variables.entry(symbol_id).or_default().push(span);
     }
 
     pub fn create_reference(&mut self, reference: Reference) -> ReferenceId {
+        _ = self.next_resolved_references.push(None);
+        _ = self.previous_resolved_references.push(None);
         self.references.push(reference)
     }
 
+    /// Add `reference_id` to the end of the references resolved to `symbol_id`.
+    pub fn add_resolved_reference(&mut self, symbol_id: SymbolId, reference_id: ReferenceId) {
+        let ends = &mut self.resolved_reference_ends[symbol_id];
+        *ends = match *ends {
+            Some((first, last)) => {
+                self.next_resolved_references[last] = Some(reference_id);
+                self.previous_resolved_references[reference_id] = Some(last);
+                Some((first, reference_id))
+            }
+            None => Some((reference_id, reference_id)),
+        };
+        self.resolved_reference_counts[symbol_id] += 1;
+    }
+
+    /// Remove `reference_id` from the references resolved to `symbol_id`.
+    pub fn remove_resolved_reference(&mut self, symbol_id: SymbolId, reference_id: ReferenceId) {
+        let Some((first, last)) = self.resolved_reference_ends[symbol_id] else { return };
+        let previous = self.previous_resolved_references[reference_id];
+        // Only the first reference has no previous reference.
+        if previous.is_none() && first != reference_id {
+            return;
+        }
+        let next = self.next_resolved_references[reference_id].take();
+        self.previous_resolved_references[reference_id] = None;
+        if let Some(previous) = previous {
+            self.next_resolved_references[previous] = next;
+        }
+        if let Some(next) = next {
+            self.previous_resolved_references[next] = previous;
+        }
+        self.resolved_reference_ends[symbol_id] = match (previous, next) {
+            (None, None) => None,
+            (None, Some(next)) => Some((next, last)),
+            (Some(previous), None) => Some((first, previous)),
+            (Some(_), Some(_)) => Some((first, last)),
+        };
+        self.resolved_reference_counts[symbol_id] -= 1;
+    }
+
     pub fn get_reference(&self, reference_id: ReferenceId) -> &Reference {
         &self.references[reference_id]
     }
@@ -153,17 +217,30 @@ impl SymbolTable {
         self.references[reference_id].symbol_id().is_none()
     }
 
-    pub fn get_resolved_reference_ids(&self, symbol_id: SymbolId) -> &Vec<ReferenceId> {
-        &self.resolved_references[symbol_id]
+    /// Whether calling the function declared by `symbol_id` has no side effects.
+    pub fn has_no_side_effects(&self, symbol_id: SymbolId) -> bool {
+        self.no_side_effects.contains(&symbol_id)
+    }
+
+    /// The references resolved to `symbol_id`, in the order they were resolved.
+    pub fn get_resolved_reference_ids(&self, symbol_id: SymbolId) -> ResolvedReferenceIds<'_> {
+        ResolvedReferenceIds {
+            next: self.resolved_reference_ends[symbol_id].map(|(first, _)| first),
+            remaining: self.resolved_reference_counts[symbol_id] as usize,
+            next_resolved_references: &self.next_resolved_references,
+        }
+    }
+
+    pub fn get_resolved_reference_count(&self, symbol_id: SymbolId) -> usize {
+        self.resolved_reference_counts[symbol_id] as usize
     }
 
     pub fn get_resolved_references(
         &self,
         symbol_id: SymbolId,
     ) -> impl Iterator<Item = &Reference> + '_ {
-        self.resolved_references[symbol_id]
-            .iter()
-            .map(|reference_id| &self.references[*reference_id])
+        self.get_resolved_reference_ids(symbol_id)
+            .map(|reference_id| &self.references[reference_id])
     }
 
     /// Determine whether evaluating the specific input `node` is a consequenceless reference. ie.
@@ -191,3 +268,29 @@ impl SymbolTable {
         }
     }
 }
+
+/// Iterator over the references resolved to a symbol, see
+/// [SymbolTable::get_resolved_reference_ids].
+#[derive(Debug, Clone)]
+pub struct ResolvedReferenceIds<'s> {
+    next: Option<ReferenceId>,
+    remaining: usize,
+    next_resolved_references: &'s IndexVec<ReferenceId, Option<ReferenceId>>,
+}
+
+impl Iterator for ResolvedReferenceIds<'_> {
+    type Item = ReferenceId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let reference_id = self.next?;
+        self.next = self.next_resolved_references[reference_id];
+        self.remaining -= 1;
+        Some(reference_id)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for ResolvedReferenceIds<'_> {}
@@ -0,0 +1,123 @@
+//! Export-aware Reference Counting
+//!
+//! Counts the references which read each symbol, where the exports of a module count as
+//! references from outside of it, depending on how the module is used:
+//!
+//! * [ExportMode::Library]: a package, whose consumers may import any of its exports, so the
+//!   exported symbols are never unused.
+//! * [ExportMode::Application]: the entry of an app, where only the given exports are used, e.g.
+//!   by the host page, so that the other exports are unused unless the module reads them itself.
+//!
+//! `export default x` is an export of `x`, not a reference in the module, and the symbols which
+//! are only written, as in `x = 1`, are unused.
+
+use oxc_ast::AstKind;
+use oxc_index::IndexVec;
+use oxc_span::CompactStr;
+use oxc_syntax::{
+    identifier::is_identifier_name,
+    module_record::{ExportExportName, ExportLocalName},
+};
+use rustc_hash::FxHashSet;
+
+use crate::{Semantic, SymbolFlags, SymbolId};
+
+/// How the exports of a module are used, see [Semantic::symbol_usage].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum ExportMode {
+    /// All exports are used.
+    #[default]
+    Library,
+    /// Only the exports with these names are used, e.g. `"default"` and `"render"`.
+    Application(Vec<CompactStr>),
+}
+
+impl ExportMode {
+    fn is_used(&self, export_name: &ExportExportName) -> bool {
+        match self {
+            Self::Library => !export_name.is_null(),
+            Self::Application(names) => names.iter().any(|name| export_name.matches(name)),
+        }
+    }
+}
+
+/// The number of references to each symbol, including the references from outside of the module
+/// given by the [ExportMode].
+#[derive(Debug)]
+pub struct SymbolUsage {
+    reference_counts: IndexVec<SymbolId, u32>,
+}
+
+impl SymbolUsage {
+    pub(crate) fn new(semantic: &Semantic, mode: &ExportMode) -> Self {
+        let symbols = semantic.symbols();
+        let nodes = semantic.nodes();
+        let mut reference_counts = symbols
+            .iter()
+            .map(|symbol_id| {
+                let references = symbols.get_resolved_references(symbol_id).filter(|reference| {
+                    (reference.is_read() || reference.is_type())
+                        && !matches!(
+                            nodes.parent_kind(reference.node_id()),
+                            Some(AstKind::ExportDefaultDeclaration(_))
+                        )
+                });
+                u32::try_from(references.count()).unwrap_or(u32::MAX)
+            })
+            .collect::<IndexVec<SymbolId, u32>>();
+
+        let module_record = semantic.module_record();
+        let scopes = semantic.scopes();
+        let mut exported = FxHashSet::default();
+        for entry in &module_record.local_export_entries {
+            if !mode.is_used(&entry.export_name) {
+                continue;
+            }
+            let symbol_id = match &entry.local_name {
+                ExportLocalName::Name(name) => scopes.get_root_binding(name.name()),
+                // `export default x`
+                ExportLocalName::Default(_) => {
+                    let identifier = entry.span.source_text(semantic.source_text());
+                    is_identifier_name(identifier)
+                        .then(|| scopes.get_root_binding(identifier))
+                        .flatten()
+                }
+                ExportLocalName::Null => None,
+            };
+            if let Some(symbol_id) = symbol_id {
+                reference_counts[symbol_id] = reference_counts[symbol_id].saturating_add(1);
+                exported.insert(symbol_id);
+            }
+        }
+        // TypeScript declarations, e.g. `export interface Foo {}`, are not in the module record.
+        if *mode == ExportMode::Library {
+            for symbol_id in symbols.iter() {
+                if symbols.get_flag(symbol_id).contains(SymbolFlags::Export)
+                    && symbols.get_scope_id(symbol_id) == scopes.root_scope_id()
+                    && !exported.contains(&symbol_id)
+                {
+                    reference_counts[symbol_id] = reference_counts[symbol_id].saturating_add(1);
+                }
+            }
+        }
+        Self { reference_counts }
+    }
+
+    /// The references which read `symbol_id` or use it as a type, plus one for each of its
+    /// exports which is used.
+    pub fn reference_count(&self, symbol_id: SymbolId) -> u32 {
+        self.reference_counts[symbol_id]
+    }
+
+    pub fn is_used(&self, symbol_id: SymbolId) -> bool {
+        self.reference_count(symbol_id) > 0
+    }
+
+    /// The symbols which are never read, neither in the module nor through a used export.
+    pub fn unused_symbols(&self) -> impl Iterator<Item = SymbolId> + '_ {
+        self.reference_counts
+            .iter_enumerated()
+            .filter(|(_, count)| **count == 0)
+            .map(|(symbol_id, _)| symbol_id)
+    }
+}
@@ -0,0 +1,84 @@
+use oxc_ast::AstKind;
+use oxc_semantic::{EvaluationKind, InitializationOrder};
+
+use crate::util::SemanticTester;
+
+/// The initialization order of the first parameter list, catch parameter or declared pattern in
+/// `source_text`, and the names of the bindings read before they are initialized.
+fn check(source_text: &'static str, f: impl FnOnce(&InitializationOrder, Vec<&str>)) {
+    let tester = SemanticTester::js(source_text);
+    let semantic = tester.build();
+    let order = semantic
+        .nodes()
+        .iter()
+        .find_map(|node| match node.kind() {
+            AstKind::FormalParameters(params) => Some(InitializationOrder::of_parameters(params)),
+            AstKind::CatchParameter(param) => Some(InitializationOrder::of_catch_parameter(param)),
+            AstKind::VariableDeclarator(decl) => Some(InitializationOrder::of_pattern(&decl.id)),
+            _ => None,
+        })
+        .expect("expected a pattern");
+    let tdz = order
+        .tdz_references(semantic.symbols())
+        .iter()
+        .map(|reference| order.bindings()[reference.binding].name.as_str())
+        .collect();
+    f(&order, tdz);
+}
+
+fn tdz_references(source_text: &'static str) -> Vec<String> {
+    let mut names = vec![];
+    check(source_text, |_, tdz| names = tdz.into_iter().map(String::from).collect());
+    names
+}
+
+#[test]
+fn test_parameters() {
+    assert_eq!(tdz_references("function f(a = b, b) {}"), ["b"]);
+    assert_eq!(tdz_references("function f({ a = a }) {}"), ["a"]);
+    assert_eq!(tdz_references("(a = b + c, b, ...c) => {}"), ["b", "c"]);
+    assert!(tdz_references("function f(a, b = a) {}").is_empty());
+    assert!(tdz_references("function f([a, b = a], c = b) {}").is_empty());
+
+    check("function f(a, { b = a, c }, d = c) {}", |order, tdz| {
+        assert!(tdz.is_empty());
+        let names: Vec<_> = order.bindings().iter().map(|binding| binding.name.as_str()).collect();
+        assert_eq!(names, ["a", "b", "c", "d"]);
+        let visible: Vec<_> = order
+            .evaluations()
+            .iter()
+            .map(|evaluation| order.visible_bindings(evaluation).len())
+            .collect();
+        assert_eq!(visible, [1, 3]);
+        assert_eq!(order.tdz_bindings(&order.evaluations()[0]).len(), 3);
+    });
+}
+
+#[test]
+fn test_deferred() {
+    assert!(tdz_references("function f(a = () => b, b) {}").is_empty());
+    assert!(tdz_references("function f(a = function () { return b }, b) {}").is_empty());
+    assert!(tdz_references("function f(a = class { x = b }, b) {}").is_empty());
+    assert_eq!(tdz_references("function f(a = class { static x = b }, b) {}"), ["b"]);
+    assert_eq!(tdz_references("function f(a = class extends b {}, b) {}"), ["b"]);
+    assert_eq!(tdz_references("function f(a = class { [b]() {} }, b) {}"), ["b"]);
+}
+
+#[test]
+fn test_destructuring() {
+    assert_eq!(tdz_references("const { [a]: b, a } = obj"), ["a"]);
+    assert!(tdz_references("const { a, [a]: b } = obj").is_empty());
+    assert_eq!(tdz_references("try {} catch ({ a = b, b }) {}"), ["b"]);
+    assert!(tdz_references("try {} catch ([a, b = a]) {}").is_empty());
+
+    check("let [a = 1, { [a]: b } = {}] = c", |order, _| {
+        let kinds: Vec<_> = order.evaluations().iter().map(|evaluation| evaluation.kind).collect();
+        assert_eq!(
+            kinds,
+            [EvaluationKind::Default, EvaluationKind::Default, EvaluationKind::ComputedKey]
+        );
+        let initialized: Vec<_> =
+            order.evaluations().iter().map(|evaluation| evaluation.initialized_before).collect();
+        assert_eq!(initialized, [0, 1, 1]);
+    });
+}
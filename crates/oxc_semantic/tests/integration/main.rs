@@ -2,7 +2,14 @@
 
 pub mod cfg;
 pub mod classes;
+pub mod initialization_order;
 pub mod modules;
+pub mod node_flags;
+pub mod object_shape;
+pub mod post_transform;
+pub mod purity;
+pub mod redeclarations;
 pub mod scopes;
 pub mod symbols;
+pub mod usage;
 pub mod util;
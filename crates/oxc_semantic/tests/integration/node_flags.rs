@@ -0,0 +1,69 @@
+use oxc_ast::AstKind;
+use oxc_semantic::NodeFlags;
+
+use crate::util::SemanticTester;
+
+/// Flags of the reference to `x` in `source_text`.
+fn flags_of_x(source_text: &'static str) -> NodeFlags {
+    let tester = SemanticTester::js(source_text);
+    let semantic = tester.build();
+    let node = semantic
+        .nodes()
+        .iter()
+        .find(
+            |node| matches!(node.kind(), AstKind::IdentifierReference(ident) if ident.name == "x"),
+        )
+        .expect("expected a reference to `x`");
+    node.flags()
+}
+
+#[test]
+fn test_async_and_generator_context() {
+    assert!(flags_of_x("async function f() { await x }").has_async());
+    assert!(flags_of_x("async () => x").has_async());
+    assert!(flags_of_x("function* f() { yield x }").has_generator());
+    assert!(!flags_of_x("x").has_async());
+
+    // Nested functions start a new context.
+    let flags = flags_of_x("async function* f() { function g() { x } }");
+    assert!(!flags.has_async());
+    assert!(!flags.has_generator());
+    assert!(!flags_of_x("function* f() { async () => x }").has_generator());
+    assert!(!flags_of_x("async function f() { class A { static { x } } }").has_async());
+}
+
+#[test]
+fn test_loop_context() {
+    assert!(flags_of_x("while (a) { x }").has_loop());
+    assert!(flags_of_x("while (x) {}").has_loop());
+    assert!(flags_of_x("do {} while (x)").has_loop());
+    assert!(flags_of_x("for (;;) x").has_loop());
+    assert!(flags_of_x("for (; x;) {}").has_loop());
+    assert!(flags_of_x("for (;; x++) {}").has_loop());
+    assert!(flags_of_x("for (a of b) x").has_loop());
+    assert!(flags_of_x("for (a in b) { if (a) x }").has_loop());
+
+    // Evaluated once
+    assert!(!flags_of_x("for (let a = x;;) {}").has_loop());
+    assert!(!flags_of_x("for (a of x) {}").has_loop());
+    assert!(!flags_of_x("for (a in x) {}").has_loop());
+
+    // Function boundary
+    assert!(!flags_of_x("while (a) { function f() { x } }").has_loop());
+    assert!(!flags_of_x("while (a) { () => x }").has_loop());
+    assert!(flags_of_x("function f() { for (;;) { x } }").has_loop());
+
+    // The flag of an outer loop is kept after an inner loop.
+    assert!(flags_of_x("while (a) { while (b) {} x }").has_loop());
+    assert!(!flags_of_x("while (a) {} x").has_loop());
+}
+
+#[test]
+fn test_try_finally_context() {
+    assert!(flags_of_x("try { x } finally {}").has_try_finally());
+    assert!(flags_of_x("try {} catch { x } finally {}").has_try_finally());
+    assert!(!flags_of_x("try {} finally { x }").has_try_finally());
+    assert!(!flags_of_x("try { x } catch {}").has_try_finally());
+    assert!(!flags_of_x("try { function f() { x } } finally {}").has_try_finally());
+    assert!(flags_of_x("try { try { } catch {} x } finally {}").has_try_finally());
+}
@@ -0,0 +1,118 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Expression, Statement};
+use oxc_parser::Parser;
+use oxc_semantic::{DefinedKey, Duplicate, ObjectShape};
+use oxc_span::SourceType;
+
+fn with_shape(source_text: &str, f: impl FnOnce(&ObjectShape)) {
+    let allocator = Allocator::default();
+    let source_text = format!("({source_text})");
+    let ret = Parser::new(&allocator, &source_text, SourceType::default()).parse();
+    assert!(ret.errors.is_empty(), "{source_text}: {:?}", ret.errors);
+    let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+        unreachable!()
+    };
+    let Expression::ObjectExpression(object) = stmt.expression.get_inner_expression() else {
+        unreachable!()
+    };
+    f(&ObjectShape::new(object));
+}
+
+fn names(source_text: &str) -> Vec<DefinedKey> {
+    let mut names = vec![];
+    with_shape(source_text, |shape| {
+        names = shape.properties().iter().map(|property| property.key.clone()).collect();
+    });
+    names
+}
+
+fn duplicates(source_text: &str) -> Vec<(usize, usize)> {
+    let mut duplicates = vec![];
+    with_shape(source_text, |shape| {
+        duplicates = shape
+            .duplicates()
+            .into_iter()
+            .map(|Duplicate { earlier, later }| (earlier, later))
+            .collect();
+    });
+    duplicates
+}
+
+fn accessor_pairs(source_text: &str) -> Vec<(String, Option<usize>, Option<usize>)> {
+    let mut pairs = vec![];
+    with_shape(source_text, |shape| {
+        pairs = shape
+            .accessor_pairs()
+            .into_iter()
+            .map(|pair| (pair.name.to_string(), pair.getter, pair.setter))
+            .collect();
+    });
+    pairs
+}
+
+#[test]
+fn test_key_names() {
+    let name = |name: &str| DefinedKey::Name(name.into());
+    assert_eq!(
+        names("{ a, b: 1, 'c': 1, ['d']: 1, [`e`]: 1, f() {}, get g() {}, set h(v) {} }"),
+        ["a", "b", "c", "d", "e", "f", "g", "h"].map(name)
+    );
+    assert_eq!(
+        names("{ 1: 1, 0x10: 1, 1.50: 1, 1e21: 1, 1_0: 1, 10n: 1, 0x10n: 1, [null]: 1 }"),
+        [
+            name("1"),
+            name("16"),
+            name("1.5"),
+            name("1e+21"),
+            name("10"),
+            name("10"),
+            DefinedKey::Unknown,
+            name("null")
+        ]
+    );
+    assert_eq!(
+        names("{ __proto__: a, ['__proto__']: a, __proto__() {}, [a]: 1, [`${a}`]: 1, ...a }"),
+        [
+            DefinedKey::Prototype,
+            name("__proto__"),
+            name("__proto__"),
+            DefinedKey::Unknown,
+            DefinedKey::Unknown,
+            DefinedKey::Unknown
+        ]
+    );
+}
+
+#[test]
+fn test_duplicates() {
+    assert_eq!(duplicates("{ a: 1, b: 2, a: 3 }"), [(0, 2)]);
+    assert_eq!(duplicates("{ a: 1, a: 2, a: 3 }"), [(0, 1), (1, 2)]);
+    assert_eq!(duplicates("{ 1: 1, '1': 2, [1.0]: 3 }"), [(0, 1), (1, 2)]);
+    assert_eq!(duplicates("{ a: 1, ...b, [c]: 1, a: 2 }"), [(0, 3)]);
+    assert!(duplicates("{ get a() {}, set a(v) {} }").is_empty());
+    assert_eq!(duplicates("{ get a() {}, set a(v) {}, get a() {} }"), [(0, 2)]);
+    assert_eq!(duplicates("{ get a() {}, set a(v) {}, a: 1 }"), [(1, 2)]);
+    assert_eq!(duplicates("{ a: 1, set a(v) {} }"), [(0, 1)]);
+    assert!(duplicates("{ __proto__: a, ['__proto__']: b }").is_empty());
+    assert_eq!(duplicates("{ ['__proto__']: a, __proto__() {} }"), [(0, 1)]);
+}
+
+#[test]
+fn test_accessor_pairs() {
+    assert_eq!(
+        accessor_pairs("{ get a() {}, b: 1, set a(v) {}, set c(v) {} }"),
+        [("a".to_string(), Some(0), Some(2)), ("c".to_string(), None, Some(3))]
+    );
+    assert_eq!(
+        accessor_pairs("{ get a() {}, a: 1, set a(v) {} }"),
+        [("a".to_string(), Some(0), None), ("a".to_string(), None, Some(2))]
+    );
+    assert!(accessor_pairs("{ get [a]() {}, set [a](v) {}, ...b }").is_empty());
+}
+
+#[test]
+fn test_spreads() {
+    with_shape("{ ...a, b, ...c }", |shape| {
+        assert_eq!(shape.spreads().collect::<Vec<_>>(), [0, 2]);
+    });
+}
@@ -0,0 +1,93 @@
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{BindingPatternKind, Program, Statement};
+use oxc_parser::Parser;
+use oxc_semantic::{
+    check_semantic_after_transform, ScopeFlags, ScopeTree, SemanticBuilder, SemanticMismatchKind,
+    SymbolTable,
+};
+use oxc_span::{CompactStr, SourceType};
+
+/// Build the semantic model of `source_text`, let `transform` modify the AST or the tables,
+/// and return the kinds of the mismatches found.
+fn check(
+    source_text: &str,
+    transform: impl FnOnce(&Program, &mut SymbolTable, &mut ScopeTree),
+) -> Vec<SemanticMismatchKind> {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let program = allocator.alloc(program);
+    let (mut symbols, mut scopes) = SemanticBuilder::new(source_text, source_type)
+        .build(program)
+        .semantic
+        .into_symbol_table_and_scope_tree();
+    transform(program, &mut symbols, &mut scopes);
+    check_semantic_after_transform(&symbols, &scopes, program)
+        .into_iter()
+        .map(|mismatch| mismatch.kind)
+        .collect()
+}
+
+#[test]
+fn test_consistent() {
+    let source_text = "let a = 1; function f(b) { { let c = a + b; } } class A { static {} }";
+    assert_eq!(check(source_text, |_, _, _| {}), vec![]);
+}
+
+#[test]
+fn test_ids_are_preserved() {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true);
+    let source_text = "let a = 1; { let b = a; }";
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let program = allocator.alloc(program);
+    let (symbols, scopes) = SemanticBuilder::new(source_text, source_type)
+        .build(program)
+        .semantic
+        .into_symbol_table_and_scope_tree();
+    let Statement::BlockStatement(block) = &program.body[1] else { unreachable!() };
+    let before = (program.scope_id.get(), block.scope_id.get());
+    assert!(check_semantic_after_transform(&symbols, &scopes, program).is_empty());
+    assert_eq!(before, (program.scope_id.get(), block.scope_id.get()));
+}
+
+#[test]
+fn test_symbol_mismatches() {
+    let renamed = check("let a = 1; a;", |_, symbols, _| {
+        let symbol_id = symbols.get_symbol_id_from_name("a").unwrap();
+        symbols.set_name(symbol_id, CompactStr::from("b"));
+    });
+    assert_eq!(renamed, vec![SemanticMismatchKind::SymbolName]);
+
+    let moved = check("let a = 1; { let b = 2; }", |_, symbols, _| {
+        let a = symbols.get_symbol_id_from_name("a").unwrap();
+        let b = symbols.get_symbol_id_from_name("b").unwrap();
+        symbols.scope_ids[b] = symbols.get_scope_id(a);
+    });
+    assert_eq!(moved, vec![SemanticMismatchKind::SymbolScope]);
+
+    let missing = check("let a = 1; a;", |program, _, _| {
+        let Statement::VariableDeclaration(decl) = &program.body[0] else { unreachable!() };
+        let BindingPatternKind::BindingIdentifier(ident) = &decl.declarations[0].id.kind else {
+            unreachable!()
+        };
+        ident.symbol_id.set(None);
+    });
+    assert_eq!(missing, vec![SemanticMismatchKind::MissingSymbolId]);
+}
+
+#[test]
+fn test_scope_mismatches() {
+    let flags = check("function f() {}", |program, _, scopes| {
+        let Statement::FunctionDeclaration(func) = &program.body[0] else { unreachable!() };
+        *scopes.get_flags_mut(func.scope_id.get().unwrap()) |= ScopeFlags::Arrow;
+    });
+    assert_eq!(flags, vec![SemanticMismatchKind::ScopeFlags]);
+
+    let missing = check("{ let a; }", |program, _, _| {
+        let Statement::BlockStatement(block) = &program.body[0] else { unreachable!() };
+        block.scope_id.set(None);
+    });
+    // `a` is declared in a scope which cannot be mapped anymore, and is not reported again
+    assert_eq!(missing, vec![SemanticMismatchKind::MissingScopeId]);
+}
@@ -0,0 +1,64 @@
+use crate::util::SemanticTester;
+
+/// Whether calling the function `f` declared in `source_text` has no side effects.
+fn is_pure(source_text: &'static str) -> bool {
+    let tester = SemanticTester::js(source_text).with_module(true);
+    let semantic = tester.build();
+    let symbols = semantic.symbols();
+    let symbol_id = symbols
+        .iter()
+        .find(|&symbol_id| symbols.get_name(symbol_id) == "f")
+        .expect("expected a function `f`");
+    symbols.has_no_side_effects(symbol_id)
+}
+
+#[test]
+fn test_annotations() {
+    assert!(is_pure("/* @__NO_SIDE_EFFECTS__ */ function f() { a.b() }"));
+    assert!(is_pure("/* #__NO_SIDE_EFFECTS__ */ function f() { a.b() }"));
+    assert!(is_pure("/* @__NO_SIDE_EFFECTS__ */ export function f() { a.b() }"));
+    assert!(is_pure("/* @__NO_SIDE_EFFECTS__ */ export default function f() { a.b() }"));
+    assert!(is_pure("/* @__NO_SIDE_EFFECTS__ */ const f = () => a.b()"));
+    assert!(is_pure("const f = /* @__NO_SIDE_EFFECTS__ */ function () { a.b() }"));
+    assert!(is_pure("/* @__NO_SIDE_EFFECTS__ */ export const f = () => a.b()"));
+
+    assert!(!is_pure("// @__NO_SIDE_EFFECTS__\nfunction f() { a.b() }"));
+    assert!(!is_pure("/* @__NO_SIDE_EFFECTS__ */ g(); function f() { a.b() }"));
+    assert!(!is_pure("/* @__NO_SIDE_EFFECTS__ */ function f() {} f = g"));
+}
+
+#[test]
+fn test_inference() {
+    assert!(is_pure("function f() {}"));
+    assert!(is_pure("function f(a, b) { return a + b }"));
+    assert!(is_pure("function f(a) { let b = a * 2; if (b > 1) { b = 1 } return [b, { a }] }"));
+    assert!(is_pure("const f = (a) => a ? `${a}` : undefined"));
+    assert!(is_pure("const f = function (a) { return typeof a === 'string' }"));
+    assert!(is_pure("const g = (a) => a; function f(a) { return g(a) + h(a) } function h() {}"));
+    assert!(is_pure("function f() { return () => a.b() }"));
+
+    // Writes to outer bindings
+    assert!(!is_pure("let a; function f() { a = 1 }"));
+    assert!(!is_pure("let a; function f() { a++ }"));
+    assert!(!is_pure("function f(a) { a.b = 1 }"));
+    // Calls to unknown functions
+    assert!(!is_pure("function f(a) { a() }"));
+    assert!(!is_pure("function f() { g() }"));
+    assert!(!is_pure("function f() { return f() }"));
+    assert!(!is_pure("function f() { return g() } function g() { return f() }"));
+    assert!(!is_pure("let g = () => {}; function f() { g() } g = h"));
+    // Operations which may throw
+    assert!(!is_pure("function f(a) { return a.b }"));
+    assert!(!is_pure("function f(a) { throw a }"));
+    assert!(!is_pure("function f() { return a }"));
+    assert!(!is_pure("function f(a) { return 'b' in a }"));
+    assert!(!is_pure("function f({ a }) {}"));
+    assert!(!is_pure("function f(a) { return [...a] }"));
+    assert!(!is_pure("function f() { while (true) {} }"));
+    // Functions which do not run their body
+    assert!(!is_pure("async function f() {}"));
+    assert!(!is_pure("function* f() {}"));
+    // Reassigned bindings
+    assert!(!is_pure("function f() {} f = g"));
+    assert!(!is_pure("let f = () => {}"));
+}
@@ -0,0 +1,59 @@
+use crate::util::SemanticTester;
+
+fn error_codes(source_text: &'static str) -> Vec<&'static str> {
+    SemanticTester::ts(source_text)
+        .errors()
+        .iter()
+        .map(|error| error.code.expect("TypeScript diagnostics should have an error code"))
+        .collect()
+}
+
+#[test]
+fn test_redeclaration_error_codes() {
+    assert_eq!(error_codes("let a; let a;"), vec!["TS2451"]);
+    assert_eq!(error_codes("var a; let a;"), vec!["TS2451"]);
+    assert_eq!(error_codes("declare let a: number; declare const a: number;"), vec!["TS2451"]);
+    assert_eq!(error_codes("class A {} class A {}"), vec!["TS2300"]);
+    assert_eq!(error_codes("declare class A {} class A {}"), vec!["TS2300"]);
+    assert_eq!(error_codes("type A = {}; interface A {}"), vec!["TS2300"]);
+    assert_eq!(error_codes("type F = (a: number, a: string) => void;"), vec!["TS2300"]);
+    assert_eq!(error_codes("function f() {} function f() {}"), vec!["TS2393"]);
+
+    // Script functions are function scoped, but still cannot be implemented twice
+    let errors = SemanticTester::ts("function f() {} function f() {}").with_module(false).errors();
+    assert_eq!(errors.iter().map(|error| error.code).collect::<Vec<_>>(), vec![Some("TS2393")]);
+
+    // JavaScript keeps reporting without an error code
+    let errors = SemanticTester::js("let a; let a;").errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code, None);
+    assert_eq!(errors[0].message, "Identifier `a` has already been declared");
+}
+
+#[test]
+fn test_valid_declaration_merging() {
+    for source_text in [
+        "var a; var a;",
+        "interface A {} interface A {}",
+        "declare class A {} interface A {}",
+        "class A {} namespace A {}",
+        "enum A { X } enum A { Y = 1 }",
+        "function f(a: string): void; function f(a: number): void; function f(a: any) {}",
+        "export function f(): void; export function f() {}",
+        "declare function f(): void; declare function f(a: number): void;",
+        "declare namespace N { function f(): void; }",
+        "declare module 'm' { export function f(): void; }",
+    ] {
+        assert_eq!(error_codes(source_text), Vec::<&str>::new(), "{source_text}");
+    }
+}
+
+#[test]
+fn test_function_overload_error_codes() {
+    assert_eq!(error_codes("function f(): void;"), vec!["TS2391"]);
+    assert_eq!(error_codes("function f(): void; let x; function f() {}"), vec!["TS2391"]);
+    assert_eq!(error_codes("namespace N { function f(): void; }"), vec!["TS2391"]);
+    assert_eq!(error_codes("function f(): void; function g() {}"), vec!["TS2389"]);
+    assert_eq!(error_codes("export function f(): void; function f() {}"), vec!["TS2383"]);
+    assert_eq!(error_codes("declare function f(): void; function f() {}"), vec!["TS2384"]);
+}
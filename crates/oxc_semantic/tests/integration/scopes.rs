@@ -136,3 +136,38 @@ fn test_catch_clause_parameters() {
     .has_number_of_references(1)
     .test();
 }
+
+#[test]
+fn test_contains_direct_eval() {
+    let tester = SemanticTester::js(
+        "
+            const a = 0;
+            function foo() {
+                let b = 1;
+                { eval('b'); }
+            }
+            function bar() {
+                let c = 2;
+            }
+        ",
+    );
+    tester.has_root_symbol("a").is_in_scope(ScopeFlags::ContainsDirectEval).test();
+    tester.has_some_symbol("b").is_in_scope(ScopeFlags::ContainsDirectEval).test();
+    tester.has_some_symbol("c").is_not_in_scope(ScopeFlags::ContainsDirectEval).test();
+
+    let tester = SemanticTester::js(
+        "
+            function foo(obj) {
+                let d = 1;
+                with (obj) { d; }
+            }
+            function bar() {
+                let e = 2;
+                window.eval('e');
+            }
+        ",
+    )
+    .with_module(false);
+    tester.has_some_symbol("d").is_in_scope(ScopeFlags::ContainsDirectEval).test();
+    tester.has_some_symbol("e").is_not_in_scope(ScopeFlags::ContainsDirectEval).test();
+}
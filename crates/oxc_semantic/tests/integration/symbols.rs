@@ -1,4 +1,7 @@
-use oxc_semantic::SymbolFlags;
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::{SemanticBuilder, SymbolFlags};
+use oxc_span::SourceType;
 
 use crate::util::SemanticTester;
 
@@ -37,6 +40,22 @@ fn test_function_expressions() {
         .test();
 }
 
+#[test]
+fn test_declaration_file() {
+    let source_type = SourceType::default().with_module(true).with_typescript_definition(true);
+    SemanticTester::new(
+        "export declare function f(a: string): void; export declare function f(a: number): void;",
+        source_type,
+    )
+    .has_root_symbol("f")
+    .contains_flags(SymbolFlags::Function)
+    .test();
+    SemanticTester::new("interface A {} interface B extends A {}", source_type)
+        .has_root_symbol("A")
+        .has_number_of_references(1)
+        .test();
+}
+
 #[test]
 fn test_var_simple() {
     SemanticTester::js("let x; { let y; }")
@@ -114,3 +133,39 @@ fn test_export_flag() {
     tester.has_root_symbol("b").contains_flags(SymbolFlags::Export).test();
     tester.has_root_symbol("c").contains_flags(SymbolFlags::Export).test();
 }
+
+#[test]
+fn test_remove_resolved_reference() {
+    let allocator = Allocator::default();
+    let source_text = "let x; x; x; x; x;";
+    let source_type = SourceType::default();
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let program = allocator.alloc(program);
+    let (mut symbols, _) = SemanticBuilder::new(source_text, source_type)
+        .build(program)
+        .semantic
+        .into_symbol_table_and_scope_tree();
+    let symbol_id = symbols.get_symbol_id_from_name("x").unwrap();
+    let ids = symbols.get_resolved_reference_ids(symbol_id).collect::<Vec<_>>();
+    assert_eq!(ids.len(), 4);
+
+    // The middle, first and last references are unlinked without walking the list.
+    for (removed, remaining) in [
+        (ids[2], vec![ids[0], ids[1], ids[3]]),
+        (ids[0], vec![ids[1], ids[3]]),
+        (ids[3], vec![ids[1]]),
+        (ids[1], vec![]),
+    ] {
+        symbols.remove_resolved_reference(symbol_id, removed);
+        assert_eq!(symbols.get_resolved_reference_ids(symbol_id).collect::<Vec<_>>(), remaining);
+        assert_eq!(symbols.get_resolved_reference_count(symbol_id), remaining.len());
+    }
+
+    // A reference which is not resolved to the symbol is ignored.
+    symbols.remove_resolved_reference(symbol_id, ids[0]);
+    assert_eq!(symbols.get_resolved_reference_count(symbol_id), 0);
+    symbols.add_resolved_reference(symbol_id, ids[2]);
+    symbols.add_resolved_reference(symbol_id, ids[0]);
+    symbols.remove_resolved_reference(symbol_id, ids[1]);
+    assert_eq!(symbols.get_resolved_reference_ids(symbol_id).collect::<Vec<_>>(), [ids[2], ids[0]]);
+}
@@ -0,0 +1,63 @@
+use oxc_semantic::ExportMode;
+
+use crate::util::SemanticTester;
+
+/// The names of the unused symbols declared in `source_text`.
+fn unused(source_text: &'static str, mode: &ExportMode) -> Vec<String> {
+    let tester = SemanticTester::ts(source_text);
+    let semantic = tester.build();
+    let symbols = semantic.symbols();
+    semantic
+        .symbol_usage(mode)
+        .unused_symbols()
+        .map(|symbol_id| symbols.get_name(symbol_id).to_string())
+        .collect()
+}
+
+fn application(exports: &[&str]) -> ExportMode {
+    ExportMode::Application(exports.iter().map(|&name| name.into()).collect())
+}
+
+#[test]
+fn test_library() {
+    let mode = ExportMode::Library;
+    assert!(unused("export function f() {}", &mode).is_empty());
+    assert!(unused("function f() {} export { f as g }", &mode).is_empty());
+    assert!(unused("const a = 1; export default a", &mode).is_empty());
+    assert!(unused("export default function f() {}", &mode).is_empty());
+    assert!(unused("export class A {} export interface B {} export type C = B", &mode).is_empty());
+    assert!(unused("import { a } from 'm'; export { a }", &mode).is_empty());
+    assert_eq!(unused("function f() {} function g() {} export { g }", &mode), ["f"]);
+    assert_eq!(unused("let a; a = 1; export let b = 2", &mode), ["a"]);
+    assert_eq!(unused("export function f(a, b) { return b }", &mode), ["a"]);
+}
+
+#[test]
+fn test_application() {
+    let mode = application(&["default"]);
+    assert!(unused("export default function f() {}", &mode).is_empty());
+    assert!(unused("const a = 1; export default a", &mode).is_empty());
+    assert!(unused("function f() {} export { f as default }", &mode).is_empty());
+    assert_eq!(unused("export function f() {} export default 1", &mode), ["f"]);
+    assert_eq!(unused("function f() {} function g() {} export { f, g as default }", &mode), ["f"]);
+    // The exports which are read in the module are used.
+    assert!(unused("export function f() {} export default () => f()", &mode).is_empty());
+
+    let mode = application(&["render"]);
+    assert_eq!(unused("const a = 1; export default a; export function render() {}", &mode), ["a"]);
+    assert!(unused("export function render() {}", &mode).is_empty());
+    assert_eq!(unused("export interface Props {}", &mode), ["Props"]);
+}
+
+#[test]
+fn test_reference_count() {
+    let tester = SemanticTester::js("export function f() {} f(); f(); function g() { f() }");
+    let semantic = tester.build();
+    let f = semantic.scopes().get_root_binding("f").unwrap();
+    let g = semantic.scopes().get_root_binding("g").unwrap();
+    let library = semantic.symbol_usage(&ExportMode::Library);
+    assert_eq!(library.reference_count(f), 4);
+    assert!(!library.is_used(g));
+    let app = semantic.symbol_usage(&application(&[]));
+    assert_eq!(app.reference_count(f), 3);
+}
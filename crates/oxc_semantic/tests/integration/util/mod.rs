@@ -6,7 +6,7 @@ use std::{path::PathBuf, sync::Arc};
 use itertools::Itertools;
 use oxc_allocator::Allocator;
 use oxc_diagnostics::{Error, NamedSource, OxcDiagnostic};
-use oxc_semantic::{print_basic_block, Semantic, SemanticBuilder};
+use oxc_semantic::{print_basic_block, Semantic, SemanticBuilder, SemanticBuilderReturn};
 use oxc_span::SourceType;
 
 pub use class_tester::ClassTester;
@@ -63,6 +63,31 @@ impl<'a> SemanticTester<'a> {
     /// # Panics
     #[allow(unstable_name_collisions)]
     pub fn build(&self) -> Semantic<'_> {
+        let semantic_ret = self.build_with_errors();
+
+        if !semantic_ret.errors.is_empty() {
+            let report = self.wrap_diagnostics(semantic_ret.errors);
+            panic!(
+                "Semantic analysis failed:\n\n{}",
+                report
+                    .iter()
+                    .map(ToString::to_string)
+                    .intersperse("\n\n".to_owned())
+                    .collect::<String>()
+            );
+        };
+
+        semantic_ret.semantic
+    }
+
+    /// Parse the source text and return the errors found during semantic analysis.
+    /// # Panics
+    pub fn errors(&self) -> Vec<OxcDiagnostic> {
+        self.build_with_errors().errors
+    }
+
+    #[allow(unstable_name_collisions)]
+    fn build_with_errors(&self) -> SemanticBuilderReturn<'_> {
         let parse =
             oxc_parser::Parser::new(&self.allocator, self.source_text, self.source_type).parse();
 
@@ -79,25 +104,11 @@ impl<'a> SemanticTester<'a> {
         );
 
         let program = self.allocator.alloc(parse.program);
-        let semantic_ret = SemanticBuilder::new(self.source_text, self.source_type)
+        SemanticBuilder::new(self.source_text, self.source_type)
             .with_check_syntax_error(true)
             .with_trivias(parse.trivias)
             .build_module_record(PathBuf::new(), program)
-            .build(program);
-
-        if !semantic_ret.errors.is_empty() {
-            let report = self.wrap_diagnostics(semantic_ret.errors);
-            panic!(
-                "Semantic analysis failed:\n\n{}",
-                report
-                    .iter()
-                    .map(ToString::to_string)
-                    .intersperse("\n\n".to_owned())
-                    .collect::<String>()
-            );
-        };
-
-        semantic_ret.semantic
+            .build(program)
     }
 
     pub fn basic_blocks_count(&self) -> usize {
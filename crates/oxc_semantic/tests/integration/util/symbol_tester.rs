@@ -128,13 +128,7 @@ impl<'a> SymbolTester<'a> {
     {
         self.test_result = match self.test_result {
             Ok(symbol_id) => {
-                let refs = {
-                    self.semantic
-                        .symbols()
-                        .get_resolved_reference_ids(symbol_id)
-                        .iter()
-                        .map(|r_id| self.semantic.symbols().get_reference(*r_id).clone())
-                };
+                let refs = { self.semantic.symbols().get_resolved_references(symbol_id).cloned() };
                 let num_accepted = refs.filter(filter).count();
                 if num_accepted == ref_count {
                     Ok(symbol_id)
@@ -21,15 +21,18 @@ impl ConcatSourceMapBuilder {
 
         // Add `token_chunks`, See `TokenChunk`.
         if let Some(last_token) = self.tokens.last() {
+            // The source position is only updated by tokens with a source, see `encode`.
+            let last_source_token =
+                self.tokens.iter().rev().find(|token| token.get_source_id().is_some());
             self.token_chunks.push(TokenChunk::new(
                 self.tokens.len() as u32,
                 self.tokens.len() as u32 + sourcemap.tokens.len() as u32,
                 last_token.get_dst_line(),
                 last_token.get_dst_col(),
-                last_token.get_src_line(),
-                last_token.get_src_col(),
+                last_source_token.map_or(0, Token::get_src_line),
+                last_source_token.map_or(0, Token::get_src_col),
                 self.token_chunk_prev_name_id,
-                source_offset - 1,
+                last_source_token.and_then(Token::get_source_id).unwrap_or_default(),
             ));
         } else {
             self.token_chunks.push(TokenChunk::new(
@@ -136,3 +139,49 @@ fn test_concat_sourcemap_builder() {
 
     assert_eq!(sm.to_json_string().unwrap(), sm.to_json_string().unwrap());
 }
+
+#[test]
+fn test_concat_sourcemap_builder_encode() {
+    // The chunks are encoded like the same tokens without chunks, also when a sourcemap starts
+    // on the last line of the previous one, or its last token does not use the last source.
+    let sm1 = SourceMap::new(
+        None,
+        vec!["foo".into()],
+        None,
+        vec!["a.js".into(), "b.js".into()],
+        None,
+        vec![Token::new(0, 0, 0, 0, Some(1), Some(0)), Token::new(0, 5, 1, 0, Some(0), None)],
+        None,
+    );
+    let sm2 = SourceMap::new(
+        None,
+        vec!["bar".into()],
+        None,
+        vec!["c.js".into()],
+        None,
+        vec![Token::new(0, 10, 0, 0, Some(0), None), Token::new(1, 0, 2, 3, Some(0), Some(0))],
+        None,
+    );
+
+    let mut builder = ConcatSourceMapBuilder::default();
+    builder.add_sourcemap(&sm1, 0);
+    builder.add_sourcemap(&sm2, 0);
+    let concat_sm = builder.into_sourcemap();
+
+    let sm = SourceMap::new(
+        None,
+        vec!["foo".into(), "bar".into()],
+        None,
+        vec!["a.js".into(), "b.js".into(), "c.js".into()],
+        Some(vec!["".into(), "".into(), "".into()]),
+        vec![
+            Token::new(0, 0, 0, 0, Some(1), Some(0)),
+            Token::new(0, 5, 1, 0, Some(0), None),
+            Token::new(0, 10, 0, 0, Some(2), None),
+            Token::new(1, 0, 2, 3, Some(2), Some(1)),
+        ],
+        None,
+    );
+    assert_eq!(concat_sm.tokens, sm.tokens);
+    assert_eq!(concat_sm.to_json_string().unwrap(), sm.to_json_string().unwrap());
+}
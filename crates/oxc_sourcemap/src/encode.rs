@@ -112,7 +112,9 @@ fn serialize_mappings(tokens: &[Token], token_chunk: &TokenChunk) -> String {
         mut prev_source_id,
     } = *token_chunk;
 
-    for (idx, token) in tokens[start as usize..end as usize].iter().enumerate() {
+    // `idx` indexes all `tokens`, so that a chunk is serialized exactly like the same tokens
+    // without chunks, regardless of where it starts.
+    for (idx, token) in tokens.iter().enumerate().take(end as usize).skip(start as usize) {
         if token.get_dst_line() != prev_dst_line {
             prev_dst_col = 0;
             while token.get_dst_line() != prev_dst_line {
@@ -32,6 +32,11 @@ impl Span {
         Self { start, end }
     }
 
+    /// Whether this is [SPAN], the span of a node which was created instead of parsed.
+    pub fn is_unspanned(&self) -> bool {
+        *self == SPAN
+    }
+
     pub fn size(&self) -> u32 {
         debug_assert!(self.start <= self.end);
         self.end - self.start
@@ -10,6 +10,8 @@ pub mod number;
 pub mod operator;
 pub mod precedence;
 pub mod reference;
+#[cfg(feature = "regexp")]
+pub mod regexp;
 pub mod scope;
 pub mod symbol;
 pub mod xml_entities;
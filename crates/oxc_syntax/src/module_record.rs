@@ -80,12 +80,177 @@ pub struct ModuleRecord {
 
     /// Duplicated span of `export default` for diagnostics
     pub export_default_duplicated: Vec<Span>,
+
+    /// `[[HasTLA]]`
+    ///
+    /// Span of the first `await` or `for await` outside of functions.
+    pub top_level_await: Option<Span>,
 }
 
 impl ModuleRecord {
     pub fn new(resolved_absolute_path: PathBuf) -> Self {
         Self { resolved_absolute_path, ..Self::default() }
     }
+
+    /// Whether evaluating this module is asynchronous, because this module or one of the loaded
+    /// modules it depends on uses top-level await.
+    ///
+    /// A bundler must not evaluate a chunk containing such a module synchronously.
+    pub fn is_async(&self) -> bool {
+        fn visit(module: &ModuleRecord, visited: &mut Vec<PathBuf>) -> bool {
+            if visited.contains(&module.resolved_absolute_path) {
+                return false;
+            }
+            visited.push(module.resolved_absolute_path.clone());
+            module.top_level_await.is_some()
+                || module.loaded_modules.iter().any(|entry| visit(entry.value(), visited))
+        }
+        visit(self, &mut vec![])
+    }
+
+    /// [ResolveExport](https://tc39.es/ecma262/#sec-resolveexport)
+    ///
+    /// Finds the binding exported as `export_name` through the loaded modules. Modules which are not
+    /// loaded export nothing.
+    pub fn resolve_export(&self, export_name: &str) -> ResolvedExport {
+        self.resolve_export_impl(export_name, &mut vec![])
+    }
+
+    fn resolve_export_impl(
+        &self,
+        export_name: &str,
+        resolve_set: &mut Vec<(PathBuf, CompactStr)>,
+    ) -> ResolvedExport {
+        // 2. For each Record { [[Module]], [[ExportName]] } r of resolveSet, do
+        //   a. If module and r.[[Module]] are the same Module Record and exportName is r.[[ExportName]], then
+        //     i. Assert: This is a circular import request.
+        //     ii. Return null.
+        let key = (self.resolved_absolute_path.clone(), CompactStr::from(export_name));
+        if resolve_set.contains(&key) {
+            return ResolvedExport::NotFound;
+        }
+        // 3. Append the Record { [[Module]]: module, [[ExportName]]: exportName } to resolveSet.
+        resolve_set.push(key);
+
+        // 4. For each ExportEntry Record e of module.[[LocalExportEntries]], do
+        for e in &self.local_export_entries {
+            // a. If exportName is e.[[ExportName]], then
+            if e.export_name.matches(export_name) {
+                // i. Return ResolvedBinding Record { [[Module]]: module, [[BindingName]]: e.[[LocalName]] }.
+                let binding_name = match &e.local_name {
+                    ExportLocalName::Name(name) => ResolvedBindingName::Name(name.name().clone()),
+                    ExportLocalName::Default(_) | ExportLocalName::Null => {
+                        ResolvedBindingName::Default
+                    }
+                };
+                return ResolvedExport::Found(ResolvedBinding {
+                    module: self.resolved_absolute_path.clone(),
+                    binding_name,
+                });
+            }
+        }
+
+        // 5. For each ExportEntry Record e of module.[[IndirectExportEntries]], do
+        for e in &self.indirect_export_entries {
+            // a. If exportName is e.[[ExportName]], then
+            if e.export_name.matches(export_name) {
+                let Some(imported_module) = self.imported_module(e) else {
+                    return ResolvedExport::NotFound;
+                };
+                return match &e.import_name {
+                    // ii. If e.[[ImportName]] is all, then
+                    //   2. Return ResolvedBinding Record { [[Module]]: importedModule, [[BindingName]]: namespace }.
+                    ExportImportName::All => ResolvedExport::Found(ResolvedBinding {
+                        module: imported_module.resolved_absolute_path.clone(),
+                        binding_name: ResolvedBindingName::Namespace,
+                    }),
+                    // iii. Else,
+                    //   2. Return importedModule.ResolveExport(e.[[ImportName]], resolveSet).
+                    ExportImportName::Name(name) => {
+                        imported_module.resolve_export_impl(name.name(), resolve_set)
+                    }
+                    ExportImportName::AllButDefault | ExportImportName::Null => {
+                        ResolvedExport::NotFound
+                    }
+                };
+            }
+        }
+
+        // 6. If exportName is "default", then
+        if export_name == "default" {
+            // c. Return null.
+            return ResolvedExport::NotFound;
+        }
+
+        // 7. Let starResolution be null.
+        let mut star_resolution: Option<ResolvedBinding> = None;
+        // 8. For each ExportEntry Record e of module.[[StarExportEntries]], do
+        for e in &self.star_export_entries {
+            let Some(imported_module) = self.imported_module(e) else { continue };
+            // b. Let resolution be importedModule.ResolveExport(exportName, resolveSet).
+            match imported_module.resolve_export_impl(export_name, resolve_set) {
+                // c. If resolution is ambiguous, return ambiguous.
+                ResolvedExport::Ambiguous => return ResolvedExport::Ambiguous,
+                // d. If resolution is not null, then
+                ResolvedExport::Found(resolution) => match &star_resolution {
+                    // ii. If starResolution is null, set starResolution to resolution.
+                    None => star_resolution = Some(resolution),
+                    // iii. Else,
+                    //   1. Assert: There is more than one * import that includes the requested name.
+                    //   2-3. If resolution.[[Module]] and starResolution.[[Module]] are not the same
+                    //        Module Record, or the [[BindingName]]s differ, return ambiguous.
+                    Some(star_resolution) if *star_resolution != resolution => {
+                        return ResolvedExport::Ambiguous
+                    }
+                    Some(_) => {}
+                },
+                ResolvedExport::NotFound => {}
+            }
+        }
+        // 9. Return starResolution.
+        star_resolution.map_or(ResolvedExport::NotFound, ResolvedExport::Found)
+    }
+
+    /// Names exported by `export * from` declarations which resolve to different bindings, and are
+    /// therefore left out of the namespace object of this module.
+    ///
+    /// Names which are also exported by this module itself are not ambiguous.
+    pub fn ambiguous_star_exports(&self) -> Vec<CompactStr> {
+        let mut names = vec![];
+        let mut visited = vec![self.resolved_absolute_path.clone()];
+        for e in &self.star_export_entries {
+            if let Some(imported_module) = self.imported_module(e) {
+                imported_module.exported_names(&mut visited, &mut names);
+            }
+        }
+        names.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+        names.dedup();
+        names.retain(|name| self.resolve_export(name) == ResolvedExport::Ambiguous);
+        names
+    }
+
+    /// [GetExportedNames](https://tc39.es/ecma262/#sec-getexportednames), without `default`.
+    fn exported_names(&self, visited: &mut Vec<PathBuf>, names: &mut Vec<CompactStr>) {
+        if visited.contains(&self.resolved_absolute_path) {
+            return;
+        }
+        visited.push(self.resolved_absolute_path.clone());
+        for e in self.local_export_entries.iter().chain(&self.indirect_export_entries) {
+            if let ExportExportName::Name(name) = &e.export_name {
+                names.push(name.name().clone());
+            }
+        }
+        for e in &self.star_export_entries {
+            if let Some(imported_module) = self.imported_module(e) {
+                imported_module.exported_names(visited, names);
+            }
+        }
+    }
+
+    fn imported_module(&self, entry: &ExportEntry) -> Option<Arc<ModuleRecord>> {
+        let module_request = entry.module_request.as_ref()?;
+        self.loaded_modules.get(module_request.name()).map(|module| Arc::clone(module.value()))
+    }
 }
 
 impl fmt::Debug for ModuleRecord {
@@ -112,6 +277,7 @@ impl fmt::Debug for ModuleRecord {
             .field("exported_bindings_from_star_export", &self.exported_bindings_from_star_export)
             .field("export_default", &self.export_default)
             .field("export_default_duplicated", &self.export_default_duplicated)
+            .field("top_level_await", &self.top_level_await)
             .finish()
     }
 }
@@ -230,6 +396,15 @@ impl ExportExportName {
         matches!(self, Self::Default(_))
     }
 
+    /// Whether this is the export named `name`, which is "default" for default exports.
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            Self::Name(export_name) => *export_name.name() == name,
+            Self::Default(_) => name == "default",
+            Self::Null => false,
+        }
+    }
+
     pub fn is_null(&self) -> bool {
         matches!(self, Self::Null)
     }
@@ -269,6 +444,34 @@ impl ExportLocalName {
     }
 }
 
+/// [ResolvedBinding Record](https://tc39.es/ecma262/#resolvedbinding-record), or the null and
+/// ambiguous results of [ResolveExport](https://tc39.es/ecma262/#sec-resolveexport).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedExport {
+    Found(ResolvedBinding),
+    /// The name is not exported, or only through a circular re-export.
+    NotFound,
+    /// The name is exported by more than one `export * from` declaration, with different bindings.
+    Ambiguous,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedBinding {
+    /// Resolved absolute path of the module which declares the binding
+    pub module: PathBuf,
+    pub binding_name: ResolvedBindingName,
+}
+
+/// `[[BindingName]]` for `ResolvedBinding`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedBindingName {
+    Name(CompactStr),
+    /// The anonymous binding of `export default expression`
+    Default,
+    /// The namespace object of the module, for `export * as ns from "mod"`
+    Namespace,
+}
+
 pub struct FunctionMeta {
     pub deprecated: bool,
 }
@@ -279,11 +482,18 @@ pub struct RequestedModule {
     is_type: bool,
     /// is_import is true if the module is requested by an import statement.
     is_import: bool,
+    /// `with { type: "json" }`
+    import_attributes: Vec<ImportAttributeRecord>,
 }
 
 impl RequestedModule {
-    pub fn new(span: Span, is_type: bool, is_import: bool) -> Self {
-        Self { span, is_type, is_import }
+    pub fn new(
+        span: Span,
+        is_type: bool,
+        is_import: bool,
+        import_attributes: Vec<ImportAttributeRecord>,
+    ) -> Self {
+        Self { span, is_type, is_import, import_attributes }
     }
 
     pub fn span(&self) -> Span {
@@ -297,6 +507,25 @@ impl RequestedModule {
     pub fn is_import(&self) -> bool {
         self.is_import
     }
+
+    pub fn import_attributes(&self) -> &[ImportAttributeRecord] {
+        &self.import_attributes
+    }
+
+    /// The value of the `type` import attribute, e.g. "json" for JSON modules.
+    pub fn import_type(&self) -> Option<&CompactStr> {
+        self.import_attributes
+            .iter()
+            .find(|attr| attr.key.name().as_str() == "type")
+            .map(|attr| attr.value.name())
+    }
+}
+
+/// [ImportAttribute Record](https://tc39.es/ecma262/#table-importattribute-fields)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportAttributeRecord {
+    pub key: NameSpan,
+    pub value: NameSpan,
 }
 
 #[cfg(test)]
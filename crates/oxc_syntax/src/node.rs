@@ -12,18 +12,26 @@ export type AstNodeId = number;
 export type NodeFlags = {
     JSDoc: 1,
     Class: 2,
-    HasYield: 4
-    Parameter: 8
+    HasYield: 4,
+    Parameter: 8,
+    Async: 16,
+    Generator: 32,
+    Loop: 64,
+    TryFinally: 128,
 };
 "#;
 
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct NodeFlags: u8 {
-        const JSDoc     = 1 << 0; // If the Node has a JSDoc comment attached
-        const Class     = 1 << 1; // If Node is inside a class
-        const HasYield  = 1 << 2; // If function has yield statement
-        const Parameter = 1 << 3; // If Node is inside a parameter
+        const JSDoc      = 1 << 0; // If the Node has a JSDoc comment attached
+        const Class      = 1 << 1; // If Node is inside a class
+        const HasYield   = 1 << 2; // If function has yield statement
+        const Parameter  = 1 << 3; // If Node is inside a parameter
+        const Async      = 1 << 4; // If Node is inside an async function
+        const Generator  = 1 << 5; // If Node is inside a generator function
+        const Loop       = 1 << 6; // If Node is evaluated on each iteration of a loop of the enclosing function
+        const TryFinally = 1 << 7; // If Node is inside the `try` or `catch` block of a try statement with a `finally` block, in the enclosing function
     }
 }
 
@@ -47,4 +55,30 @@ impl NodeFlags {
     pub fn has_parameter(&self) -> bool {
         self.contains(Self::Parameter)
     }
+
+    /// The Node is inside an async function or arrow function, including its parameters.
+    #[inline]
+    pub fn has_async(&self) -> bool {
+        self.contains(Self::Async)
+    }
+
+    /// The Node is inside a generator function, including its parameters.
+    #[inline]
+    pub fn has_generator(&self) -> bool {
+        self.contains(Self::Generator)
+    }
+
+    /// The Node is in the body, test or update of a loop, without a function boundary in between.
+    /// The iterated expression of `for-in` and `for-of`, and the init of `for`, are evaluated once.
+    #[inline]
+    pub fn has_loop(&self) -> bool {
+        self.contains(Self::Loop)
+    }
+
+    /// The Node is inside the `try` or `catch` block of a try statement with a `finally` block,
+    /// so returning from it runs the `finally` block first.
+    #[inline]
+    pub fn has_try_finally(&self) -> bool {
+        self.contains(Self::TryFinally)
+    }
 }
@@ -0,0 +1,1101 @@
+//! Regular Expression Evaluation
+//!
+//! A backtracking matcher for regular expressions with a literal pattern, following the
+//! ECMAScript semantics, so that `/^a/.test("abc")` can be evaluated at compile time.
+//!
+//! Only a subset of the pattern syntax is supported. Patterns outside of it are rejected instead of
+//! being evaluated with different semantics, these include Annex B extensions, named groups,
+//! lookbehind assertions, unicode property escapes, the `v` flag and case insensitive matching of
+//! non-ASCII text.
+
+use std::{fmt, ops::Range};
+
+/// Maximum number of instructions of a compiled pattern.
+const MAX_PROGRAM_SIZE: usize = 10_000;
+
+/// Maximum number of steps of a match, to bail out of catastrophic backtracking.
+const MAX_STEPS: usize = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegExpError(&'static str);
+
+impl fmt::Display for RegExpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl std::error::Error for RegExpError {}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Flags {
+    ignore_case: bool,
+    multiline: bool,
+    dot_all: bool,
+    unicode: bool,
+    sticky: bool,
+}
+
+impl Flags {
+    fn parse(flags: &str) -> Result<Self, RegExpError> {
+        let mut result = Self::default();
+        let mut seen = String::new();
+        for c in flags.chars() {
+            if seen.contains(c) {
+                return Err(RegExpError("Duplicated regular expression flag"));
+            }
+            seen.push(c);
+            match c {
+                'g' | 'd' => {}
+                'i' => result.ignore_case = true,
+                'm' => result.multiline = true,
+                's' => result.dot_all = true,
+                'u' => result.unicode = true,
+                'y' => result.sticky = true,
+                'v' => return Err(RegExpError("The `v` flag is not supported")),
+                _ => return Err(RegExpError("Invalid regular expression flag")),
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// A compiled regular expression.
+#[derive(Debug)]
+pub struct RegExp {
+    program: Vec<Inst>,
+    /// Number of capturing groups, including the whole match.
+    groups: usize,
+    registers: usize,
+    flags: Flags,
+}
+
+/// The captures of a successful match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    captures: Vec<Option<Range<usize>>>,
+}
+
+impl Match {
+    /// Range of the capturing group `index` in UTF-16 code units, `0` is the whole match.
+    /// Returns `None` if the group did not participate in the match.
+    pub fn get(&self, index: usize) -> Option<Range<usize>> {
+        self.captures.get(index).cloned().flatten()
+    }
+
+    /// Number of capturing groups, including the whole match.
+    pub fn len(&self) -> usize {
+        self.captures.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.captures.is_empty()
+    }
+}
+
+impl RegExp {
+    /// Compile `/pattern/flags`.
+    ///
+    /// # Errors
+    ///
+    /// * The pattern or flags are invalid, or use syntax which is not supported.
+    pub fn new(pattern: &str, flags: &str) -> Result<Self, RegExpError> {
+        let flags = Flags::parse(flags)?;
+        let mut parser = Parser::new(pattern, flags);
+        let node = parser.parse()?;
+        let mut compiler = Compiler { program: vec![], registers: 0 };
+        compiler.compile(&node)?;
+        compiler.emit(Inst::Match)?;
+        Ok(Self {
+            program: compiler.program,
+            groups: parser.group_count + 1,
+            registers: compiler.registers,
+            flags,
+        })
+    }
+
+    /// Evaluate `RegExp.prototype.test` on a newly created regular expression.
+    ///
+    /// # Errors
+    ///
+    /// * See [RegExp::exec].
+    pub fn test(&self, subject: &str) -> Result<bool, RegExpError> {
+        Ok(self.exec(subject)?.is_some())
+    }
+
+    /// Evaluate `RegExp.prototype.exec` on a newly created regular expression,
+    /// whose `lastIndex` is `0`.
+    ///
+    /// # Errors
+    ///
+    /// * The match takes too many steps.
+    /// * The subject contains non-ASCII characters and the `i` flag is set.
+    pub fn exec(&self, subject: &str) -> Result<Option<Match>, RegExpError> {
+        if self.flags.ignore_case && !subject.is_ascii() {
+            return Err(RegExpError(
+                "Case insensitive matching of non-ASCII text is not supported",
+            ));
+        }
+        let input: Vec<u32> = if self.flags.unicode {
+            subject.chars().map(u32::from).collect()
+        } else {
+            subject.encode_utf16().map(u32::from).collect()
+        };
+        let mut matcher = Matcher {
+            regexp: self,
+            input: &input,
+            captures: vec![None; self.groups * 2],
+            registers: vec![0; self.registers],
+            undo: vec![],
+            steps: 0,
+        };
+        let last_start = if self.flags.sticky { 0 } else { input.len() };
+        for start in 0..=last_start {
+            matcher.captures.fill(None);
+            if let Some(end) = matcher.run(0, start)? {
+                matcher.captures[0] = Some(start);
+                matcher.captures[1] = Some(end);
+                return Ok(Some(self.create_match(subject, &matcher.captures)));
+            }
+        }
+        Ok(None)
+    }
+
+    fn create_match(&self, subject: &str, captures: &[Option<usize>]) -> Match {
+        // Offsets are in code points in unicode mode, convert them to UTF-16 code units.
+        let offsets: Option<Vec<usize>> = self.flags.unicode.then(|| {
+            let mut offsets = vec![0];
+            for c in subject.chars() {
+                offsets.push(offsets.last().unwrap() + c.len_utf16());
+            }
+            offsets
+        });
+        let offset = |index: usize| offsets.as_ref().map_or(index, |offsets| offsets[index]);
+        let captures = captures
+            .chunks(2)
+            .map(|pair| match pair {
+                [Some(start), Some(end)] => Some(offset(*start)..offset(*end)),
+                _ => None,
+            })
+            .collect();
+        Match { captures }
+    }
+}
+
+/* Parser */
+
+#[derive(Debug)]
+enum Node {
+    Empty,
+    Char(u32),
+    Dot,
+    Class(Class),
+    Assertion(Assertion),
+    /// A group, with the index of the capturing group.
+    Group(Option<usize>, Box<Node>),
+    Lookahead {
+        negate: bool,
+        node: Box<Node>,
+    },
+    BackReference(usize),
+    Sequence(Vec<Node>),
+    Alternation(Vec<Node>),
+    Repeat(Box<Repeat>),
+}
+
+#[derive(Debug)]
+struct Repeat {
+    node: Node,
+    min: u32,
+    max: Option<u32>,
+    greedy: bool,
+    /// Capture slots of the groups inside of the repeated node, which are reset on each iteration.
+    slots: Range<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Assertion {
+    Start,
+    End,
+    WordBoundary,
+    NotWordBoundary,
+}
+
+#[derive(Debug, Clone)]
+struct Class {
+    negated: bool,
+    items: Vec<ClassItem>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ClassItem {
+    Range(u32, u32),
+    /// `\d`, or `\D` if negated
+    Digit {
+        negated: bool,
+    },
+    /// `\w`, or `\W` if negated
+    Word {
+        negated: bool,
+    },
+    /// `\s`, or `\S` if negated
+    Space {
+        negated: bool,
+    },
+}
+
+/// A character or a character class escape in a character class.
+enum ClassAtom {
+    Char(u32),
+    Escape(ClassItem),
+}
+
+struct Parser {
+    chars: Vec<char>,
+    index: usize,
+    flags: Flags,
+    /// Number of capturing groups in the whole pattern.
+    group_count: usize,
+    /// Number of capturing groups opened so far.
+    groups_opened: usize,
+}
+
+const UNSUPPORTED: RegExpError = RegExpError("Unsupported regular expression syntax");
+const INVALID: RegExpError = RegExpError("Invalid regular expression");
+
+impl Parser {
+    fn new(pattern: &str, flags: Flags) -> Self {
+        let chars: Vec<char> = pattern.chars().collect();
+        let group_count = Self::count_groups(&chars);
+        Self { chars, index: 0, flags, group_count, groups_opened: 0 }
+    }
+
+    /// Count capturing groups ahead of parsing, to tell backreferences from octal escapes.
+    fn count_groups(chars: &[char]) -> usize {
+        let mut count = 0;
+        let mut in_class = false;
+        let mut i = 0;
+        while i < chars.len() {
+            match chars[i] {
+                '\\' => i += 1,
+                '[' => in_class = true,
+                ']' => in_class = false,
+                '(' if !in_class => {
+                    let next = chars.get(i + 1..i + 3);
+                    let is_capturing = match next {
+                        Some(['?', '<']) => !matches!(chars.get(i + 3), Some('=' | '!')),
+                        Some(['?', _]) => false,
+                        _ => chars.get(i + 1) != Some(&'?'),
+                    };
+                    if is_capturing {
+                        count += 1;
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        count
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.index).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.index + offset).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn next_char(&mut self) -> Result<char, RegExpError> {
+        let c = self.peek().ok_or(INVALID)?;
+        self.index += 1;
+        Ok(c)
+    }
+
+    fn parse(&mut self) -> Result<Node, RegExpError> {
+        let node = self.parse_disjunction()?;
+        if self.index < self.chars.len() {
+            // Unmatched `)`
+            return Err(INVALID);
+        }
+        Ok(node)
+    }
+
+    fn parse_disjunction(&mut self) -> Result<Node, RegExpError> {
+        let mut alternatives = vec![self.parse_alternative()?];
+        while self.eat('|') {
+            alternatives.push(self.parse_alternative()?);
+        }
+        Ok(if alternatives.len() == 1 {
+            alternatives.pop().unwrap()
+        } else {
+            Node::Alternation(alternatives)
+        })
+    }
+
+    fn parse_alternative(&mut self) -> Result<Node, RegExpError> {
+        let mut terms = vec![];
+        while !matches!(self.peek(), None | Some('|' | ')')) {
+            terms.push(self.parse_term()?);
+        }
+        Ok(match terms.len() {
+            0 => Node::Empty,
+            1 => terms.pop().unwrap(),
+            _ => Node::Sequence(terms),
+        })
+    }
+
+    fn parse_term(&mut self) -> Result<Node, RegExpError> {
+        let groups_before = self.groups_opened;
+        let atom = match self.next_char()? {
+            '^' => return Ok(Node::Assertion(Assertion::Start)),
+            '$' => return Ok(Node::Assertion(Assertion::End)),
+            '\\' if self.eat('b') => return Ok(Node::Assertion(Assertion::WordBoundary)),
+            '\\' if self.eat('B') => return Ok(Node::Assertion(Assertion::NotWordBoundary)),
+            '(' if self.peek() == Some('?') => {
+                self.index += 1;
+                match self.next_char()? {
+                    ':' => {
+                        let node = self.parse_group_body()?;
+                        Node::Group(None, Box::new(node))
+                    }
+                    c @ ('=' | '!') => {
+                        let node = self.parse_group_body()?;
+                        let node = Node::Lookahead { negate: c == '!', node: Box::new(node) };
+                        if self.is_quantifier() {
+                            // Quantified lookaheads are an Annex B extension.
+                            return Err(UNSUPPORTED);
+                        }
+                        return Ok(node);
+                    }
+                    // Named groups and lookbehinds
+                    '<' => return Err(UNSUPPORTED),
+                    _ => return Err(INVALID),
+                }
+            }
+            '(' => {
+                self.groups_opened += 1;
+                let index = self.groups_opened;
+                let node = self.parse_group_body()?;
+                Node::Group(Some(index), Box::new(node))
+            }
+            '.' => Node::Dot,
+            '[' => Node::Class(self.parse_class()?),
+            '\\' => self.parse_atom_escape()?,
+            '*' | '+' | '?' => return Err(INVALID),
+            // Lone `{`, `}` and `]` are an Annex B extension.
+            '{' | '}' | ']' => return Err(UNSUPPORTED),
+            c => {
+                let c = self.check_char(u32::from(c))?;
+                if c > 0xFFFF && !self.flags.unicode {
+                    if self.is_quantifier() {
+                        // Only the trailing surrogate would be repeated.
+                        return Err(UNSUPPORTED);
+                    }
+                    let (high, low) = split_surrogates(c);
+                    return Ok(Node::Sequence(vec![Node::Char(high), Node::Char(low)]));
+                }
+                Node::Char(canonicalize(c, self.flags))
+            }
+        };
+        self.parse_quantifier(atom, (groups_before + 1) * 2..(self.groups_opened + 1) * 2)
+    }
+
+    fn parse_group_body(&mut self) -> Result<Node, RegExpError> {
+        let node = self.parse_disjunction()?;
+        if !self.eat(')') {
+            return Err(INVALID);
+        }
+        Ok(node)
+    }
+
+    fn is_quantifier(&mut self) -> bool {
+        match self.peek() {
+            Some('*' | '+' | '?') => true,
+            Some('{') => {
+                let start = self.index;
+                let is_quantifier = self.parse_braces().is_ok();
+                self.index = start;
+                is_quantifier
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_quantifier(&mut self, atom: Node, slots: Range<usize>) -> Result<Node, RegExpError> {
+        let (min, max) = if self.eat('*') {
+            (0, None)
+        } else if self.eat('+') {
+            (1, None)
+        } else if self.eat('?') {
+            (0, Some(1))
+        } else if self.peek() == Some('{') {
+            // Literal `{` is an Annex B extension.
+            self.parse_braces().map_err(|_| UNSUPPORTED)?
+        } else {
+            return Ok(atom);
+        };
+        if max.is_some_and(|max| max < min) {
+            return Err(INVALID);
+        }
+        let greedy = !self.eat('?');
+        if self.is_quantifier() {
+            return Err(INVALID);
+        }
+        Ok(Node::Repeat(Box::new(Repeat { node: atom, min, max, greedy, slots })))
+    }
+
+    /// Parse `{n}`, `{n,}` or `{n,m}`.
+    fn parse_braces(&mut self) -> Result<(u32, Option<u32>), RegExpError> {
+        if !self.eat('{') {
+            return Err(INVALID);
+        }
+        let min = self.parse_decimal().ok_or(INVALID)?;
+        let max = if self.eat(',') {
+            if self.peek() == Some('}') {
+                None
+            } else {
+                Some(self.parse_decimal().ok_or(INVALID)?)
+            }
+        } else {
+            Some(min)
+        };
+        if !self.eat('}') {
+            return Err(INVALID);
+        }
+        Ok((min, max))
+    }
+
+    fn parse_decimal(&mut self) -> Option<u32> {
+        let start = self.index;
+        let mut value: u32 = 0;
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(10)) {
+            value = value.saturating_mul(10).saturating_add(digit);
+            self.index += 1;
+        }
+        (self.index > start).then_some(value)
+    }
+
+    fn parse_atom_escape(&mut self) -> Result<Node, RegExpError> {
+        let c = self.peek().ok_or(INVALID)?;
+        if c.is_ascii_digit() && c != '0' {
+            let value = self.parse_decimal().ok_or(INVALID)?;
+            let index = usize::try_from(value).map_err(|_| INVALID)?;
+            if index > self.group_count {
+                // Octal escapes are an Annex B extension.
+                return Err(if self.flags.unicode { INVALID } else { UNSUPPORTED });
+            }
+            return Ok(Node::BackReference(index));
+        }
+        match self.parse_class_escape()? {
+            ClassAtom::Char(c) => Ok(Node::Char(canonicalize(c, self.flags))),
+            ClassAtom::Escape(item) => Ok(Node::Class(Class { negated: false, items: vec![item] })),
+        }
+    }
+
+    /// Parse the escape after `\`, which is shared by atoms and character classes.
+    fn parse_class_escape(&mut self) -> Result<ClassAtom, RegExpError> {
+        let c = match self.next_char()? {
+            'd' => return Ok(ClassAtom::Escape(ClassItem::Digit { negated: false })),
+            'D' => return Ok(ClassAtom::Escape(ClassItem::Digit { negated: true })),
+            'w' => return Ok(ClassAtom::Escape(ClassItem::Word { negated: false })),
+            'W' => return Ok(ClassAtom::Escape(ClassItem::Word { negated: true })),
+            's' => return Ok(ClassAtom::Escape(ClassItem::Space { negated: false })),
+            'S' => return Ok(ClassAtom::Escape(ClassItem::Space { negated: true })),
+            'f' => 0x0C,
+            'n' => 0x0A,
+            'r' => 0x0D,
+            't' => 0x09,
+            'v' => 0x0B,
+            '0' => {
+                if self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    return Err(if self.flags.unicode { INVALID } else { UNSUPPORTED });
+                }
+                0
+            }
+            'c' => match self.peek() {
+                Some(letter) if letter.is_ascii_alphabetic() => {
+                    self.index += 1;
+                    u32::from(letter) % 32
+                }
+                _ => return Err(if self.flags.unicode { INVALID } else { UNSUPPORTED }),
+            },
+            'x' => {
+                let value = self.parse_hex(2).ok_or(UNSUPPORTED)?;
+                self.check_char(value)?
+            }
+            'u' => {
+                let value = self.parse_unicode_escape()?;
+                self.check_char(value)?
+            }
+            c if "^$\\.*+?()[]{}|/".contains(c) => u32::from(c),
+            '-' if !self.flags.unicode => u32::from('-'),
+            c if !self.flags.unicode && !c.is_alphanumeric() && c != '_' => {
+                self.check_char(u32::from(c))?
+            }
+            _ => return Err(if self.flags.unicode { INVALID } else { UNSUPPORTED }),
+        };
+        Ok(ClassAtom::Char(c))
+    }
+
+    fn parse_hex(&mut self, len: usize) -> Option<u32> {
+        let digits: String = self.chars.get(self.index..self.index + len)?.iter().collect();
+        let value = u32::from_str_radix(&digits, 16).ok()?;
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        self.index += len;
+        Some(value)
+    }
+
+    /// Parse the escape after `\u`.
+    fn parse_unicode_escape(&mut self) -> Result<u32, RegExpError> {
+        if self.flags.unicode && self.eat('{') {
+            let start = self.index;
+            while self.peek().is_some_and(|c| c.is_ascii_hexdigit()) {
+                self.index += 1;
+            }
+            let digits: String = self.chars[start..self.index].iter().collect();
+            let value = u32::from_str_radix(&digits, 16).map_err(|_| INVALID)?;
+            if value > 0x0010_FFFF || !self.eat('}') {
+                return Err(INVALID);
+            }
+            return Ok(value);
+        }
+        let invalid = if self.flags.unicode { INVALID } else { UNSUPPORTED };
+        let value = self.parse_hex(4).ok_or(invalid)?;
+        // Surrogate pairs are combined in unicode mode.
+        if self.flags.unicode
+            && (0xD800..0xDC00).contains(&value)
+            && self.peek() == Some('\\')
+            && self.peek_at(1) == Some('u')
+        {
+            let start = self.index;
+            self.index += 2;
+            match self.parse_hex(4) {
+                Some(low) if (0xDC00..0xE000).contains(&low) => {
+                    return Ok(0x10000 + ((value - 0xD800) << 10) + (low - 0xDC00));
+                }
+                _ => self.index = start,
+            }
+        }
+        Ok(value)
+    }
+
+    fn parse_class(&mut self) -> Result<Class, RegExpError> {
+        let negated = self.eat('^');
+        let mut items = vec![];
+        loop {
+            if self.eat(']') {
+                return Ok(Class { negated, items });
+            }
+            let start = self.parse_class_atom()?;
+            if self.peek() == Some('-') && !matches!(self.peek_at(1), Some(']') | None) {
+                self.index += 1;
+                let end = self.parse_class_atom()?;
+                let (ClassAtom::Char(start), ClassAtom::Char(end)) = (start, end) else {
+                    // Ranges of character class escapes are an Annex B extension.
+                    return Err(if self.flags.unicode { INVALID } else { UNSUPPORTED });
+                };
+                if start > end {
+                    return Err(INVALID);
+                }
+                items.push(ClassItem::Range(start, end));
+            } else {
+                items.push(match start {
+                    ClassAtom::Char(c) => ClassItem::Range(c, c),
+                    ClassAtom::Escape(item) => item,
+                });
+            }
+        }
+    }
+
+    fn parse_class_atom(&mut self) -> Result<ClassAtom, RegExpError> {
+        let c = match self.next_char()? {
+            '\\' => match self.peek() {
+                Some('b') => {
+                    self.index += 1;
+                    0x08
+                }
+                Some('-') if self.flags.unicode => {
+                    self.index += 1;
+                    u32::from('-')
+                }
+                Some(c) if c.is_ascii_digit() && c != '0' => {
+                    return Err(if self.flags.unicode { INVALID } else { UNSUPPORTED })
+                }
+                _ => return self.parse_class_escape(),
+            },
+            // Non-BMP characters in character classes are matched per surrogate.
+            c if u32::from(c) > 0xFFFF && !self.flags.unicode => return Err(UNSUPPORTED),
+            c => self.check_char(u32::from(c))?,
+        };
+        Ok(ClassAtom::Char(c))
+    }
+
+    /// Reject characters which would be matched with unsupported semantics.
+    fn check_char(&self, c: u32) -> Result<u32, RegExpError> {
+        if self.flags.ignore_case && c > 0x7F {
+            return Err(UNSUPPORTED);
+        }
+        Ok(c)
+    }
+}
+
+fn split_surrogates(c: u32) -> (u32, u32) {
+    let c = c - 0x10000;
+    (0xD800 + (c >> 10), 0xDC00 + (c & 0x3FF))
+}
+
+/* Compiler */
+
+#[derive(Debug)]
+enum Inst {
+    Char(u32),
+    Dot,
+    Class(Box<Class>),
+    Assertion(Assertion),
+    BackReference(usize),
+    /// Continue at the first target, backtrack to the second one.
+    Split(usize, usize),
+    Jump(usize),
+    /// Save the position into a capture slot.
+    Save(usize),
+    /// Reset the capture slots in the range.
+    ResetCaptures(Range<usize>),
+    /// Save the position into a register.
+    SetRegister(usize),
+    /// Fail if the position is unchanged since the register was set,
+    /// iterations of a quantifier beyond its minimum must not match the empty string.
+    CheckProgress(usize),
+    /// Run the lookahead starting at the next instruction, then continue at `end`.
+    Lookahead {
+        negate: bool,
+        end: usize,
+    },
+    Match,
+}
+
+struct Compiler {
+    program: Vec<Inst>,
+    registers: usize,
+}
+
+impl Compiler {
+    fn emit(&mut self, inst: Inst) -> Result<usize, RegExpError> {
+        if self.program.len() >= MAX_PROGRAM_SIZE {
+            return Err(RegExpError("Regular expression is too large"));
+        }
+        self.program.push(inst);
+        Ok(self.program.len() - 1)
+    }
+
+    fn pc(&self) -> usize {
+        self.program.len()
+    }
+
+    fn compile(&mut self, node: &Node) -> Result<(), RegExpError> {
+        match node {
+            Node::Empty => {}
+            Node::Char(c) => {
+                self.emit(Inst::Char(*c))?;
+            }
+            Node::Dot => {
+                self.emit(Inst::Dot)?;
+            }
+            Node::Class(class) => {
+                self.emit(Inst::Class(Box::new(class.clone())))?;
+            }
+            Node::Assertion(assertion) => {
+                self.emit(Inst::Assertion(*assertion))?;
+            }
+            Node::BackReference(index) => {
+                self.emit(Inst::BackReference(*index))?;
+            }
+            Node::Group(index, node) => {
+                if let Some(index) = index {
+                    self.emit(Inst::Save(index * 2))?;
+                }
+                self.compile(node)?;
+                if let Some(index) = index {
+                    self.emit(Inst::Save(index * 2 + 1))?;
+                }
+            }
+            Node::Lookahead { negate, node } => {
+                let start = self.emit(Inst::Lookahead { negate: *negate, end: 0 })?;
+                self.compile(node)?;
+                self.emit(Inst::Match)?;
+                self.program[start] = Inst::Lookahead { negate: *negate, end: self.pc() };
+            }
+            Node::Sequence(nodes) => {
+                for node in nodes {
+                    self.compile(node)?;
+                }
+            }
+            Node::Alternation(nodes) => {
+                let mut jumps = vec![];
+                for (i, node) in nodes.iter().enumerate() {
+                    if i == nodes.len() - 1 {
+                        self.compile(node)?;
+                    } else {
+                        let split = self.emit(Inst::Split(0, 0))?;
+                        self.compile(node)?;
+                        jumps.push(self.emit(Inst::Jump(0))?);
+                        self.program[split] = Inst::Split(split + 1, self.pc());
+                    }
+                }
+                let end = self.pc();
+                for jump in jumps {
+                    self.program[jump] = Inst::Jump(end);
+                }
+            }
+            Node::Repeat(repeat) => self.compile_repeat(repeat)?,
+        }
+        Ok(())
+    }
+
+    fn compile_repeat(&mut self, repeat: &Repeat) -> Result<(), RegExpError> {
+        for _ in 0..repeat.min {
+            self.compile_iteration(repeat, None)?;
+        }
+        let split = |greedy: bool, body: usize, end: usize| {
+            if greedy {
+                Inst::Split(body, end)
+            } else {
+                Inst::Split(end, body)
+            }
+        };
+        if let Some(max) = repeat.max {
+            let mut splits = vec![];
+            for _ in repeat.min..max {
+                let register = self.new_register();
+                splits.push(self.emit(Inst::Split(0, 0))?);
+                self.compile_iteration(repeat, Some(register))?;
+            }
+            let end = self.pc();
+            for start in splits {
+                self.program[start] = split(repeat.greedy, start + 1, end);
+            }
+        } else {
+            let register = self.new_register();
+            let start = self.emit(Inst::Split(0, 0))?;
+            self.compile_iteration(repeat, Some(register))?;
+            self.emit(Inst::Jump(start))?;
+            self.program[start] = split(repeat.greedy, start + 1, self.pc());
+        }
+        Ok(())
+    }
+
+    /// Compile an iteration of a quantifier, checking progress with `register` if it is optional.
+    fn compile_iteration(
+        &mut self,
+        repeat: &Repeat,
+        register: Option<usize>,
+    ) -> Result<(), RegExpError> {
+        if let Some(register) = register {
+            self.emit(Inst::SetRegister(register))?;
+        }
+        if !repeat.slots.is_empty() {
+            self.emit(Inst::ResetCaptures(repeat.slots.clone()))?;
+        }
+        self.compile(&repeat.node)?;
+        if let Some(register) = register {
+            self.emit(Inst::CheckProgress(register))?;
+        }
+        Ok(())
+    }
+
+    fn new_register(&mut self) -> usize {
+        self.registers += 1;
+        self.registers - 1
+    }
+}
+
+/* Matcher */
+
+enum Undo {
+    Capture(usize, Option<usize>),
+    Register(usize, usize),
+}
+
+struct Matcher<'r> {
+    regexp: &'r RegExp,
+    input: &'r [u32],
+    captures: Vec<Option<usize>>,
+    registers: Vec<usize>,
+    /// Changes to undo when backtracking.
+    undo: Vec<Undo>,
+    steps: usize,
+}
+
+impl<'r> Matcher<'r> {
+    /// Run the program from `pc` at `pos`, returning the end position of the match.
+    fn run(&mut self, mut pc: usize, mut pos: usize) -> Result<Option<usize>, RegExpError> {
+        let regexp = self.regexp;
+        let flags = regexp.flags;
+        let base = self.undo.len();
+        let mut backtrack: Vec<(usize, usize, usize)> = vec![];
+        loop {
+            self.steps += 1;
+            if self.steps > MAX_STEPS {
+                return Err(RegExpError("Regular expression match is too expensive"));
+            }
+            let matched = match &regexp.program[pc] {
+                Inst::Char(c) => self.consume(&mut pos, |unit| canonicalize(unit, flags) == *c),
+                Inst::Dot => {
+                    self.consume(&mut pos, |unit| flags.dot_all || !is_line_terminator(unit))
+                }
+                Inst::Class(class) => {
+                    self.consume(&mut pos, |unit| class_matches(class, unit, flags))
+                }
+                Inst::Assertion(assertion) => self.assert(*assertion, pos),
+                Inst::BackReference(index) => self.back_reference(*index, &mut pos),
+                Inst::Split(next, alternative) => {
+                    backtrack.push((*alternative, pos, self.undo.len()));
+                    pc = *next;
+                    continue;
+                }
+                Inst::Jump(target) => {
+                    pc = *target;
+                    continue;
+                }
+                Inst::Save(slot) => {
+                    self.set_capture(*slot, Some(pos));
+                    true
+                }
+                Inst::ResetCaptures(slots) => {
+                    for slot in slots.clone() {
+                        self.set_capture(slot, None);
+                    }
+                    true
+                }
+                Inst::SetRegister(register) => {
+                    self.undo.push(Undo::Register(*register, self.registers[*register]));
+                    self.registers[*register] = pos;
+                    true
+                }
+                Inst::CheckProgress(register) => self.registers[*register] != pos,
+                Inst::Lookahead { negate, end } => {
+                    let (negate, end) = (*negate, *end);
+                    let undo = self.undo.len();
+                    let matched = self.run(pc + 1, pos)?.is_some();
+                    if negate {
+                        self.undo_to(undo);
+                    }
+                    if matched == negate {
+                        false
+                    } else {
+                        pc = end;
+                        continue;
+                    }
+                }
+                Inst::Match => return Ok(Some(pos)),
+            };
+            if matched {
+                pc += 1;
+            } else if let Some((next, next_pos, undo)) = backtrack.pop() {
+                self.undo_to(undo);
+                pc = next;
+                pos = next_pos;
+            } else {
+                self.undo_to(base);
+                return Ok(None);
+            }
+        }
+    }
+
+    fn consume<F: FnOnce(u32) -> bool>(&self, pos: &mut usize, f: F) -> bool {
+        match self.input.get(*pos) {
+            Some(&unit) if f(unit) => {
+                *pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn assert(&self, assertion: Assertion, pos: usize) -> bool {
+        let multiline = self.regexp.flags.multiline;
+        match assertion {
+            Assertion::Start => pos == 0 || (multiline && is_line_terminator(self.input[pos - 1])),
+            Assertion::End => {
+                pos == self.input.len() || (multiline && is_line_terminator(self.input[pos]))
+            }
+            Assertion::WordBoundary | Assertion::NotWordBoundary => {
+                let before = pos > 0 && is_word(self.input[pos - 1]);
+                let after = self.input.get(pos).is_some_and(|&unit| is_word(unit));
+                (before != after) == matches!(assertion, Assertion::WordBoundary)
+            }
+        }
+    }
+
+    fn back_reference(&self, index: usize, pos: &mut usize) -> bool {
+        let (Some(start), Some(end)) = (self.captures[index * 2], self.captures[index * 2 + 1])
+        else {
+            // Backreferences to groups which did not participate match the empty string.
+            return true;
+        };
+        let len = end - start;
+        let Some(subject) = self.input.get(*pos..*pos + len) else { return false };
+        let flags = self.regexp.flags;
+        let matched = self.input[start..end]
+            .iter()
+            .zip(subject)
+            .all(|(&a, &b)| canonicalize(a, flags) == canonicalize(b, flags));
+        if matched {
+            *pos += len;
+        }
+        matched
+    }
+
+    fn set_capture(&mut self, slot: usize, value: Option<usize>) {
+        self.undo.push(Undo::Capture(slot, self.captures[slot]));
+        self.captures[slot] = value;
+    }
+
+    fn undo_to(&mut self, len: usize) {
+        while self.undo.len() > len {
+            match self.undo.pop().unwrap() {
+                Undo::Capture(slot, value) => self.captures[slot] = value,
+                Undo::Register(register, value) => self.registers[register] = value,
+            }
+        }
+    }
+}
+
+/// Case insensitive matching is only supported for ASCII, where it is the same in all modes.
+fn canonicalize(unit: u32, flags: Flags) -> u32 {
+    if flags.ignore_case && (u32::from('A')..=u32::from('Z')).contains(&unit) {
+        unit + 32
+    } else {
+        unit
+    }
+}
+
+fn class_matches(class: &Class, unit: u32, flags: Flags) -> bool {
+    let contains = |unit: u32| class.items.iter().any(|item| item_matches(*item, unit));
+    let mut matched = contains(unit);
+    if !matched && flags.ignore_case {
+        let c = char::from_u32(unit).unwrap_or_default();
+        matched = contains(u32::from(c.to_ascii_lowercase()))
+            || contains(u32::from(c.to_ascii_uppercase()));
+    }
+    matched != class.negated
+}
+
+fn item_matches(item: ClassItem, unit: u32) -> bool {
+    match item {
+        ClassItem::Range(start, end) => (start..=end).contains(&unit),
+        ClassItem::Digit { negated } => {
+            (u32::from('0')..=u32::from('9')).contains(&unit) != negated
+        }
+        ClassItem::Word { negated } => is_word(unit) != negated,
+        ClassItem::Space { negated } => is_space(unit) != negated,
+    }
+}
+
+fn is_word(unit: u32) -> bool {
+    char::from_u32(unit).is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn is_line_terminator(unit: u32) -> bool {
+    matches!(unit, 0x0A | 0x0D | 0x2028 | 0x2029)
+}
+
+/// `WhiteSpace` and `LineTerminator`
+fn is_space(unit: u32) -> bool {
+    matches!(
+        unit,
+        0x09..=0x0D
+            | 0x20
+            | 0xA0
+            | 0x1680
+            | 0x2000..=0x200A
+            | 0x2028
+            | 0x2029
+            | 0x202F
+            | 0x205F
+            | 0x3000
+            | 0xFEFF
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::RegExp;
+
+    fn test(pattern: &str, flags: &str, subject: &str) -> bool {
+        RegExp::new(pattern, flags).unwrap().test(subject).unwrap()
+    }
+
+    #[test]
+    fn matches() {
+        assert!(test("^a", "", "abc"));
+        assert!(!test("^b", "", "abc"));
+        assert!(test("b$", "m", "ab\nc"));
+        assert!(test("a.c", "", "abc"));
+        assert!(!test("a.c", "", "a\nc"));
+        assert!(test("a.c", "s", "a\nc"));
+        assert!(test("^[a-c]+\\d{2,3}$", "", "abc12"));
+        assert!(!test("^[^a-c]", "", "abc"));
+        assert!(test("\\bfoo\\b", "", "a foo b"));
+        assert!(!test("\\bfoo\\b", "", "afoob"));
+        assert!(test("^(a|ab)c$", "", "abc"));
+        assert!(test("^(a)\\1$", "", "aa"));
+        assert!(test("^ABC$", "i", "abc"));
+        assert!(test("^[A-Z]+$", "i", "abc"));
+        assert!(test("^a(?=b)", "", "ab"));
+        assert!(!test("^a(?!b)", "", "ab"));
+        assert!(test("^(a*)*$", "", "aaa"));
+        assert!(test("^.$", "u", "😀"));
+        assert!(!test("^.$", "", "😀"));
+        assert!(test("^\\u{1F600}$", "u", "😀"));
+        assert!(!test("^a", "y", "ba"));
+    }
+
+    #[test]
+    fn captures() {
+        let regexp = RegExp::new("(a)|(b)", "").unwrap();
+        let m = regexp.exec("xb").unwrap().unwrap();
+        assert_eq!(m.get(0), Some(1..2));
+        assert_eq!(m.get(1), None);
+        assert_eq!(m.get(2), Some(1..2));
+
+        // Captures are reset on each iteration.
+        let regexp = RegExp::new("^(?:(a)|b)*$", "").unwrap();
+        let m = regexp.exec("ab").unwrap().unwrap();
+        assert_eq!(m.get(1), None);
+
+        let regexp = RegExp::new("b", "u").unwrap();
+        let m = regexp.exec("😀b").unwrap().unwrap();
+        assert_eq!(m.get(0), Some(2..3));
+    }
+
+    #[test]
+    fn unsupported() {
+        for (pattern, flags) in [
+            ("(?<name>a)", ""),
+            ("(?<=a)b", ""),
+            ("\\p{L}", "u"),
+            ("a{", ""),
+            ("\\1", ""),
+            ("é", "i"),
+            ("a", "v"),
+            ("(", ""),
+            ("a**", ""),
+        ] {
+            assert!(RegExp::new(pattern, flags).is_err(), "{pattern}");
+        }
+        assert!(RegExp::new("a", "i").unwrap().test("é").is_err());
+        assert!(RegExp::new("(a*)*b", "").unwrap().test(&"a".repeat(30)).is_err());
+    }
+}
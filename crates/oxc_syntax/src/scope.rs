@@ -23,6 +23,9 @@ bitflags! {
         const Constructor      = 1 << 6;
         const GetAccessor      = 1 << 7;
         const SetAccessor      = 1 << 8;
+        /// The scope or one of its descendants contains a direct `eval` call or a `with`
+        /// statement, so bindings visible from this scope can be looked up dynamically.
+        const ContainsDirectEval = 1 << 9;
         const Var = Self::Top.bits() | Self::Function.bits() | Self::ClassStaticBlock.bits() | Self::TsModuleBlock.bits();
         const Modifiers = Self::Constructor.bits() | Self::GetAccessor.bits() | Self::SetAccessor.bits();
     }
@@ -81,4 +84,8 @@ impl ScopeFlags {
     pub fn is_set_or_get_accessor(&self) -> bool {
         self.intersects(Self::SetAccessor | Self::GetAccessor)
     }
+
+    pub fn contains_direct_eval(&self) -> bool {
+        self.contains(Self::ContainsDirectEval)
+    }
 }
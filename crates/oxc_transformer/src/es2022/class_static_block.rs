@@ -0,0 +1,135 @@
+use std::rc::Rc;
+
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_span::{Atom, SPAN};
+use rustc_hash::FxHashSet;
+
+use crate::context::Ctx;
+
+/// [plugin-transform-class-static-block](https://babeljs.io/docs/babel-plugin-transform-class-static-block)
+///
+/// This plugin transforms class static blocks (`class C { static { foo } }`) to an equivalent
+/// static private property initializer.
+///
+/// This plugin is included in `preset-env`, in ES2022.
+///
+/// ## Example
+///
+/// Input:
+/// ```js
+/// class C {
+///   static {
+///     foo();
+///   }
+///   static {
+///     bar();
+///     baz();
+///   }
+/// }
+/// ```
+///
+/// Output:
+/// ```js
+/// class C {
+///   static #_ = foo();
+///   static #_2 = (() => {
+///     bar();
+///     baz();
+///   })();
+/// }
+/// ```
+///
+/// Static property initializers run in class element order with `this` bound to the class,
+/// so the evaluation order and `this` of the static blocks are preserved. The arrow function
+/// keeps `this` and scopes the `var` declarations of the block.
+///
+/// The output still contains static private properties, which are transformed by the
+/// class properties transform when the target does not support them.
+///
+/// References:
+/// * <https://babeljs.io/docs/babel-plugin-transform-class-static-block>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-class-static-block>
+/// * <https://tc39.es/proposal-class-static-block/>
+pub struct ClassStaticBlock<'a> {
+    ctx: Ctx<'a>,
+}
+
+impl<'a> ClassStaticBlock<'a> {
+    pub fn new(ctx: &Ctx<'a>) -> Self {
+        Self { ctx: Rc::clone(ctx) }
+    }
+
+    pub fn transform_class_body(&mut self, body: &mut ClassBody<'a>) {
+        if !body.body.iter().any(|element| matches!(element, ClassElement::StaticBlock(_))) {
+            return;
+        }
+
+        // `static {}` does nothing
+        body.body.retain(
+            |element| !matches!(element, ClassElement::StaticBlock(block) if block.body.is_empty()),
+        );
+
+        let mut private_names = body
+            .body
+            .iter()
+            .filter_map(ClassElement::property_key)
+            .filter_map(PropertyKey::private_name)
+            .cloned()
+            .collect::<FxHashSet<_>>();
+
+        for element in body.body.iter_mut() {
+            let ClassElement::StaticBlock(block) = element else { continue };
+            let span = block.span;
+            let value = self.convert_static_block_body(&mut block.body);
+            let name = self.generate_private_name(&mut private_names);
+            let key = PropertyKey::PrivateIdentifier(
+                self.ctx.ast.alloc(PrivateIdentifier::new(SPAN, name)),
+            );
+            *element = self.ctx.ast.class_property(
+                PropertyDefinitionType::PropertyDefinition,
+                span,
+                key,
+                Some(value),
+                false,
+                true,
+                self.ctx.ast.new_vec(),
+            );
+        }
+    }
+
+    /// `foo();` -> `foo()`
+    /// `foo(); bar();` -> `(() => { foo(); bar(); })()`
+    fn convert_static_block_body(&self, stmts: &mut Vec<'a, Statement<'a>>) -> Expression<'a> {
+        if stmts.len() == 1 {
+            if let Statement::ExpressionStatement(stmt) = &mut stmts[0] {
+                return self.ctx.ast.move_expression(&mut stmt.expression);
+            }
+        }
+
+        let stmts = self.ctx.ast.move_statement_vec(stmts);
+        let params = self.ctx.ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            self.ctx.ast.new_vec(),
+            None,
+        );
+        let body = self.ctx.ast.function_body(SPAN, self.ctx.ast.new_vec(), stmts);
+        let callee =
+            self.ctx.ast.arrow_function_expression(SPAN, false, false, params, body, None, None);
+        self.ctx.ast.call_expression(SPAN, callee, self.ctx.ast.new_vec(), false, None)
+    }
+
+    /// `#_`, `#_2`, `#_3`, ... whichever is not declared in the class yet.
+    fn generate_private_name(&self, private_names: &mut FxHashSet<Atom<'a>>) -> Atom<'a> {
+        let mut uid = 1;
+        loop {
+            let name = if uid == 1 { "_".to_string() } else { format!("_{uid}") };
+            let name = self.ctx.ast.new_atom(&name);
+            if private_names.insert(name.clone()) {
+                return name;
+            }
+            uid += 1;
+        }
+    }
+}
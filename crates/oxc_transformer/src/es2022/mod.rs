@@ -0,0 +1,57 @@
+mod class_static_block;
+mod options;
+mod private_property_in_object;
+
+pub use class_static_block::ClassStaticBlock;
+pub use options::ES2022Options;
+pub use private_property_in_object::PrivatePropertyInObject;
+
+use oxc_ast::ast::*;
+use std::rc::Rc;
+
+use crate::context::Ctx;
+
+#[allow(dead_code)]
+pub struct ES2022<'a> {
+    ctx: Ctx<'a>,
+    options: ES2022Options,
+
+    // Plugins
+    class_static_block: ClassStaticBlock<'a>,
+    private_property_in_object: PrivatePropertyInObject<'a>,
+}
+
+impl<'a> ES2022<'a> {
+    pub fn new(options: ES2022Options, ctx: &Ctx<'a>) -> Self {
+        Self {
+            class_static_block: ClassStaticBlock::new(ctx),
+            private_property_in_object: PrivatePropertyInObject::new(ctx),
+            ctx: Rc::clone(ctx),
+            options,
+        }
+    }
+
+    pub fn transform_class(&mut self, class: &mut Class<'a>) {
+        if self.options.private_property_in_object {
+            self.private_property_in_object.transform_class(class);
+        }
+    }
+
+    pub fn transform_class_on_exit(&mut self, class: &mut Class<'a>) {
+        if self.options.private_property_in_object {
+            self.private_property_in_object.transform_class_on_exit(class);
+        }
+    }
+
+    pub fn transform_class_body(&mut self, body: &mut ClassBody<'a>) {
+        if self.options.class_static_block {
+            self.class_static_block.transform_class_body(body);
+        }
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        if self.options.private_property_in_object {
+            self.private_property_in_object.transform_expression(expr);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ES2022Options {
+    #[serde(skip)]
+    pub class_static_block: bool,
+
+    #[serde(skip)]
+    pub private_property_in_object: bool,
+}
+
+impl ES2022Options {
+    #[must_use]
+    pub fn with_class_static_block(mut self, enable: bool) -> Self {
+        self.class_static_block = enable;
+        self
+    }
+
+    #[must_use]
+    pub fn with_private_property_in_object(mut self, enable: bool) -> Self {
+        self.private_property_in_object = enable;
+        self
+    }
+}
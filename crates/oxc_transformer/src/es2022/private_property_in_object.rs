@@ -0,0 +1,154 @@
+use std::rc::Rc;
+
+use oxc_ast::ast::*;
+use oxc_span::{Atom, Span, SPAN};
+use rustc_hash::FxHashMap;
+
+use crate::context::Ctx;
+
+/// [plugin-transform-private-property-in-object](https://babeljs.io/docs/babel-plugin-transform-private-property-in-object)
+///
+/// This plugin transforms ergonomic brand checks (`#x in obj`) to a read of the private
+/// member, which throws a `TypeError` when `obj` does not have it.
+///
+/// This plugin is included in `preset-env`, in ES2022.
+///
+/// ## Example
+///
+/// Input:
+/// ```js
+/// class Foo {
+///   #bar = "bar";
+///   test(obj) {
+///     return #bar in obj;
+///   }
+/// }
+/// ```
+///
+/// Output:
+/// ```js
+/// class Foo {
+///   #bar = "bar";
+///   test(obj) {
+///     return ((_obj) => {
+///       try {
+///         _obj.#bar;
+///         return true;
+///       } catch (_unused) {
+///         return false;
+///       }
+///     })(obj);
+///   }
+/// }
+/// ```
+///
+/// Private fields, methods and auto-accessors are transformed. Private getters and setters are
+/// left as is, because reading them runs user code or throws for a setter without a getter.
+///
+/// Unlike `#x in obj`, the output returns `false` instead of throwing when `obj` is a primitive.
+///
+/// References:
+/// * <https://babeljs.io/docs/babel-plugin-transform-private-property-in-object>
+/// * <https://github.com/babel/babel/blob/main/packages/babel-plugin-transform-private-property-in-object>
+/// * <https://tc39.es/proposal-private-fields-in-in/>
+pub struct PrivatePropertyInObject<'a> {
+    ctx: Ctx<'a>,
+    /// Private names declared by the enclosing classes, innermost last.
+    /// The value is `true` for getters and setters, which cannot be checked by reading them.
+    classes: std::vec::Vec<FxHashMap<Atom<'a>, bool>>,
+}
+
+impl<'a> PrivatePropertyInObject<'a> {
+    pub fn new(ctx: &Ctx<'a>) -> Self {
+        Self { ctx: Rc::clone(ctx), classes: vec![] }
+    }
+
+    pub fn transform_class(&mut self, class: &mut Class<'a>) {
+        let mut private_names = FxHashMap::default();
+        for element in &class.body.body {
+            let Some(name) = element.property_key().and_then(PropertyKey::private_name) else {
+                continue;
+            };
+            let is_accessor = matches!(
+                element,
+                ClassElement::MethodDefinition(def)
+                    if matches!(def.kind, MethodDefinitionKind::Get | MethodDefinitionKind::Set)
+            );
+            *private_names.entry(name.clone()).or_insert(false) |= is_accessor;
+        }
+        self.classes.push(private_names);
+    }
+
+    pub fn transform_class_on_exit(&mut self, _class: &mut Class<'a>) {
+        self.classes.pop();
+    }
+
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let Expression::PrivateInExpression(private_in) = expr else { return };
+
+        let name = &private_in.left.name;
+        let Some(is_accessor) =
+            self.classes.iter().rev().find_map(|private_names| private_names.get(name))
+        else {
+            return;
+        };
+        if *is_accessor {
+            return;
+        }
+
+        let Expression::PrivateInExpression(private_in) = self.ctx.ast.move_expression(expr) else {
+            unreachable!()
+        };
+        let PrivateInExpression { span, left, right, .. } = private_in.unbox();
+        *expr = self.create_brand_check(span, left, right);
+    }
+
+    /// `((_obj) => { try { _obj.#x; return true; } catch (_unused) { return false; } })(obj)`
+    fn create_brand_check(
+        &self,
+        span: Span,
+        private_name: PrivateIdentifier<'a>,
+        object: Expression<'a>,
+    ) -> Expression<'a> {
+        let ast = &self.ctx.ast;
+        let obj_name = ast.new_atom("_obj");
+
+        // (_obj) =>
+        let binding =
+            ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, obj_name.clone()));
+        let param = ast.plain_formal_parameter(SPAN, ast.binding_pattern(binding, None, false));
+        let params = ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            ast.new_vec_single(param),
+            None,
+        );
+
+        // try { _obj.#x; return true; }
+        let obj = ast.identifier_reference_expression(IdentifierReference::new(SPAN, obj_name));
+        let read = ast.private_field_expression(SPAN, obj, private_name, false);
+        let mut try_stmts = ast.new_vec_with_capacity(2);
+        try_stmts.push(ast.expression_statement(SPAN, read));
+        try_stmts.push(self.create_return_boolean(true));
+        let block = ast.block(SPAN, try_stmts);
+
+        // catch (_unused) { return false; }
+        let unused =
+            ast.binding_pattern_identifier(BindingIdentifier::new(SPAN, ast.new_atom("_unused")));
+        let param = ast.catch_parameter(SPAN, ast.binding_pattern(unused, None, false));
+        let catch_body = ast.block(SPAN, ast.new_vec_single(self.create_return_boolean(false)));
+        let handler = ast.catch_clause(SPAN, Some(param), catch_body);
+
+        let try_stmt = ast.try_statement(SPAN, block, Some(handler), None);
+        let body = ast.function_body(SPAN, ast.new_vec(), ast.new_vec_single(try_stmt));
+        let callee = ast.arrow_function_expression(SPAN, false, false, params, body, None, None);
+        let arguments = ast.new_vec_single(Argument::from(object));
+        ast.call_expression(span, callee, arguments, false, None)
+    }
+
+    fn create_return_boolean(&self, value: bool) -> Statement<'a> {
+        let ast = &self.ctx.ast;
+        let value = ast.literal_boolean_expression(ast.boolean_literal(SPAN, value));
+        ast.return_statement(SPAN, Some(value))
+    }
+}
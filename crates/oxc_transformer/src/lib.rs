@@ -15,6 +15,7 @@ mod options;
 // Presets: <https://babel.dev/docs/presets>
 mod env;
 mod es2015;
+mod es2022;
 mod react;
 mod typescript;
 
@@ -25,6 +26,7 @@ mod helpers {
 use std::{path::Path, rc::Rc};
 
 use es2015::ES2015;
+use es2022::ES2022;
 use oxc_allocator::{Allocator, Vec};
 use oxc_ast::{ast::*, AstBuilder, Trivias};
 use oxc_diagnostics::Error;
@@ -33,7 +35,7 @@ use oxc_traverse::{traverse_mut, Traverse, TraverseCtx};
 
 pub use crate::{
     compiler_assumptions::CompilerAssumptions, env::EnvOptions, es2015::ES2015Options,
-    options::BabelOptions, options::TransformOptions, react::ReactOptions,
+    es2022::ES2022Options, options::BabelOptions, options::TransformOptions, react::ReactOptions,
     typescript::TypeScriptOptions,
 };
 
@@ -48,6 +50,7 @@ pub struct Transformer<'a> {
     // NOTE: all callbacks must run in order.
     x0_typescript: TypeScript<'a>,
     x1_react: React<'a>,
+    x2_es2022: ES2022<'a>,
     x3_es2015: ES2015<'a>,
 }
 
@@ -72,6 +75,7 @@ impl<'a> Transformer<'a> {
             ctx: Rc::clone(&ctx),
             x0_typescript: TypeScript::new(options.typescript, &ctx),
             x1_react: React::new(options.react, &ctx),
+            x2_es2022: ES2022::new(options.es2022, &ctx),
             x3_es2015: ES2015::new(options.es2015, &ctx),
         }
     }
@@ -124,15 +128,18 @@ impl<'a> Traverse<'a> for Transformer<'a> {
 
     fn enter_class(&mut self, class: &mut Class<'a>, _ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.transform_class(class);
+        self.x2_es2022.transform_class(class);
         self.x3_es2015.transform_class(class);
     }
 
     fn exit_class(&mut self, class: &mut Class<'a>, _ctx: &mut TraverseCtx<'a>) {
+        self.x2_es2022.transform_class_on_exit(class);
         self.x3_es2015.transform_class_on_exit(class);
     }
 
     fn enter_class_body(&mut self, body: &mut ClassBody<'a>, _ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.transform_class_body(body);
+        self.x2_es2022.transform_class_body(body);
     }
 
     fn enter_export_named_declaration(
@@ -146,6 +153,7 @@ impl<'a> Traverse<'a> for Transformer<'a> {
     fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.transform_expression(expr);
         self.x1_react.transform_expression(expr, ctx);
+        self.x2_es2022.transform_expression(expr);
         self.x3_es2015.transform_expression(expr);
     }
 
@@ -191,14 +199,6 @@ impl<'a> Traverse<'a> for Transformer<'a> {
         self.x0_typescript.transform_method_definition(def);
     }
 
-    fn exit_method_definition(
-        &mut self,
-        def: &mut MethodDefinition<'a>,
-        _ctx: &mut TraverseCtx<'a>,
-    ) {
-        self.x0_typescript.transform_method_definition_on_exit(def);
-    }
-
     fn enter_new_expression(&mut self, expr: &mut NewExpression<'a>, _ctx: &mut TraverseCtx<'a>) {
         self.x0_typescript.transform_new_expression(expr);
     }
@@ -248,16 +248,4 @@ impl<'a> Traverse<'a> for Transformer<'a> {
     fn exit_declaration(&mut self, decl: &mut Declaration<'a>, _ctx: &mut TraverseCtx<'a>) {
         self.x3_es2015.transform_declaration_on_exit(decl);
     }
-
-    fn enter_if_statement(&mut self, stmt: &mut IfStatement<'a>, _ctx: &mut TraverseCtx<'a>) {
-        self.x0_typescript.transform_if_statement(stmt);
-    }
-
-    fn enter_module_declaration(
-        &mut self,
-        decl: &mut ModuleDeclaration<'a>,
-        _ctx: &mut TraverseCtx<'a>,
-    ) {
-        self.x0_typescript.transform_module_declaration(decl);
-    }
 }
@@ -7,6 +7,7 @@ use crate::{
     compiler_assumptions::CompilerAssumptions,
     env::{can_enable_plugin, EnvOptions, Versions},
     es2015::{ArrowFunctionsOptions, ES2015Options},
+    es2022::ES2022Options,
     options::babel::BabelOptions,
     react::ReactOptions,
     typescript::TypeScriptOptions,
@@ -34,6 +35,8 @@ pub struct TransformOptions {
     pub react: ReactOptions,
 
     pub es2015: ES2015Options,
+
+    pub es2022: ES2022Options,
 }
 
 impl TransformOptions {
@@ -104,6 +107,16 @@ impl TransformOptions {
             })
         });
 
+        let es2022 = ES2022Options::default()
+            .with_class_static_block({
+                let plugin_name = "transform-class-static-block";
+                enable_plugin(plugin_name, options, &env_options, &targets).is_some()
+            })
+            .with_private_property_in_object({
+                let plugin_name = "transform-private-property-in-object";
+                enable_plugin(plugin_name, options, &env_options, &targets).is_some()
+            });
+
         let typescript = {
             let plugin_name = "transform-typescript";
             from_value::<TypeScriptOptions>(get_plugin_options(plugin_name, options))
@@ -135,6 +148,7 @@ impl TransformOptions {
             typescript,
             react,
             es2015,
+            es2022,
         })
     }
 }
@@ -184,3 +198,16 @@ fn test_deny_unknown_fields() {
         result.err().unwrap().iter().map(ToString::to_string).collect::<Vec<_>>().join("\n");
     assert!(err_message.contains("transform-react-jsx: unknown field `filter`"));
 }
+
+#[test]
+fn test_es2022_plugins() {
+    // Both are supported by the target, only the listed plugin is enabled.
+    let options = serde_json::json!({
+      "presets": [["env", { "targets": { "chrome": "94" } }]],
+      "plugins": ["transform-class-static-block"],
+    });
+    let babel_options = serde_json::from_value::<BabelOptions>(options).unwrap();
+    let options = TransformOptions::from_babel_options(&babel_options).unwrap();
+    assert!(options.es2022.class_static_block);
+    assert!(!options.es2022.private_property_in_object);
+}
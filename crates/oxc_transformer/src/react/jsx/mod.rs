@@ -1,4 +1,6 @@
 mod diagnostics;
+mod optimize;
+mod solid;
 
 use std::rc::Rc;
 
@@ -46,24 +48,40 @@ pub struct ReactJsx<'a> {
     import_jsxs: bool,
     import_fragment: bool,
     import_create_element: bool,
+
+    /// `const _el = _jsx(...)` declarations of the constant elements hoisted by `optimize`.
+    hoisted_elements: Vec<'a, Statement<'a>>,
+    /// Whether a constant element which is going to be hoisted is being transformed.
+    in_constant_element: bool,
+
+    /// The helpers imported from the Solid runtime.
+    solid_imports: std::vec::Vec<&'static str>,
+    /// The events whose handlers are delegated by the Solid runtime.
+    delegated_events: std::vec::Vec<&'static str>,
 }
 
 // Transforms
 impl<'a> ReactJsx<'a> {
     pub fn new(options: &Rc<ReactOptions>, ctx: &Ctx<'a>) -> Self {
         let default_runtime = options.runtime;
-        let jsx_runtime_importer =
-            if options.import_source == "react" || default_runtime.is_classic() {
-                let source =
-                    if options.development { "react/jsx-dev-runtime" } else { "react/jsx-runtime" };
-                CompactStr::from(source)
+        let jsx_runtime_importer = if default_runtime.is_solid() {
+            let source = if options.import_source == "react" {
+                "solid-js/web"
             } else {
-                CompactStr::from(format!(
-                    "{}/jsx-{}runtime",
-                    options.import_source,
-                    if options.development { "dev-" } else { "" }
-                ))
+                options.import_source.as_ref()
             };
+            CompactStr::from(source)
+        } else if options.import_source == "react" || default_runtime.is_classic() {
+            let source =
+                if options.development { "react/jsx-dev-runtime" } else { "react/jsx-runtime" };
+            CompactStr::from(source)
+        } else {
+            CompactStr::from(format!(
+                "{}/jsx-{}runtime",
+                options.import_source,
+                if options.development { "dev-" } else { "" }
+            ))
+        };
 
         Self {
             options: Rc::clone(options),
@@ -76,6 +94,10 @@ impl<'a> ReactJsx<'a> {
             import_jsxs: false,
             import_fragment: false,
             import_create_element: false,
+            hoisted_elements: ctx.ast.new_vec(),
+            in_constant_element: false,
+            solid_imports: vec![],
+            delegated_events: vec![],
         }
     }
 
@@ -86,7 +108,7 @@ impl<'a> ReactJsx<'a> {
     pub fn transform_jsx_element(
         &mut self,
         e: &JSXElement<'a>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
         self.transform_jsx(&JSXElementOrFragment::Element(e), ctx)
     }
@@ -94,7 +116,7 @@ impl<'a> ReactJsx<'a> {
     pub fn transform_jsx_fragment(
         &mut self,
         e: &JSXFragment<'a>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
         self.transform_jsx(&JSXElementOrFragment::Fragment(e), ctx)
     }
@@ -123,6 +145,23 @@ impl<'a> ReactJsx<'a> {
             return;
         }
 
+        if self.options.runtime.is_solid() {
+            // Imports `delegateEvents` before the import statements are created.
+            let delegate_events = self.get_delegate_events_statement();
+            let imports = self.ctx.module_imports.get_import_statements();
+            let index = program
+                .body
+                .iter()
+                .rposition(|stmt| matches!(stmt, Statement::ImportDeclaration(_)))
+                .map_or(0, |i| i + 1);
+            let imports_end = index + imports.len();
+            program.body.splice(index..index, imports);
+            // Templates are created with the imported `template`
+            program.body.splice(imports_end..imports_end, self.hoisted_elements.drain(..));
+            program.body.extend(delegate_events);
+            return;
+        }
+
         if self.options.pragma != "React.createElement"
             || self.options.pragma_frag != "React.Fragment"
         {
@@ -146,7 +185,10 @@ impl<'a> ReactJsx<'a> {
             }
         }
 
+        let imports_end = index + imports.len();
         program.body.splice(index..index, imports);
+        // Hoisted elements call the imported functions
+        program.body.splice(imports_end..imports_end, self.hoisted_elements.drain(..));
     }
 
     fn add_import<'b>(
@@ -316,11 +358,17 @@ impl<'a> ReactJsx<'a> {
     /// ### Fragment
     /// React.createElement(React.Fragment, null, ...children)
     ///
+    /// ## Solid
+    /// See [`solid`](self::solid).
     fn transform_jsx<'b>(
         &mut self,
         e: &JSXElementOrFragment<'a, 'b>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
+        if self.options.runtime.is_solid() {
+            return self.transform_solid(e, ctx);
+        }
+
         let is_fragment = e.is_fragment();
         let has_key_after_props_spread = e.has_key_after_props_spread();
         // If has_key_after_props_spread is true, we need to fallback to `createElement` same behavior as classic runtime
@@ -328,6 +376,11 @@ impl<'a> ReactJsx<'a> {
         let is_automatic = !is_classic;
         let is_development = self.options.development;
 
+        let hoist = is_automatic && self.can_hoist(e, ctx);
+        if hoist {
+            self.in_constant_element = true;
+        }
+
         let mut arguments = self.ast().new_vec();
         arguments.push(Argument::from(match e {
             JSXElementOrFragment::Element(e) => {
@@ -400,6 +453,9 @@ impl<'a> ReactJsx<'a> {
                 children.iter().filter_map(|child| self.transform_jsx_child(child, ctx)),
                 allocator,
             );
+            if self.is_optimize_enabled() {
+                self.merge_static_children(&mut children);
+            }
             let children_len = children.len();
             if children_len != 0 {
                 let value = if children_len == 1 {
@@ -512,7 +568,12 @@ impl<'a> ReactJsx<'a> {
         }
 
         let callee = self.get_create_element(has_key_after_props_spread, need_jsxs);
-        self.ast().call_expression(SPAN, callee, arguments, false, None)
+        let call_expr = self.ast().call_expression(SPAN, callee, arguments, false, None);
+        if hoist {
+            self.in_constant_element = false;
+            return self.hoist_constant_element(call_expr, ctx);
+        }
+        call_expr
     }
 
     fn transform_element_name(&self, name: &JSXElementName<'a>) -> Expression<'a> {
@@ -553,7 +614,7 @@ impl<'a> ReactJsx<'a> {
                     self.get_call_expression_callee(self.options.pragma_frag.as_ref())
                 }
             }
-            ReactJsxRuntime::Automatic => {
+            ReactJsxRuntime::Automatic | ReactJsxRuntime::Solid => {
                 if self.is_script() {
                     let object_name = if self.options.development {
                         "_reactJsxDevRuntime"
@@ -580,7 +641,7 @@ impl<'a> ReactJsx<'a> {
                     self.get_call_expression_callee(self.options.pragma.as_ref())
                 }
             }
-            ReactJsxRuntime::Automatic => {
+            ReactJsxRuntime::Automatic | ReactJsxRuntime::Solid => {
                 let name = if self.is_script() {
                     if has_key_after_props_spread {
                         "createElement"
@@ -669,7 +730,7 @@ impl<'a> ReactJsx<'a> {
         &mut self,
         properties: &mut Vec<'a, ObjectPropertyKind<'a>>,
         attribute: &JSXAttributeItem<'a>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) {
         match attribute {
             JSXAttributeItem::Attribute(attr) => {
@@ -698,7 +759,7 @@ impl<'a> ReactJsx<'a> {
     fn transform_jsx_attribute_value(
         &mut self,
         value: Option<&JSXAttributeValue<'a>>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
         match value {
             Some(JSXAttributeValue::StringLiteral(s)) => {
@@ -725,7 +786,7 @@ impl<'a> ReactJsx<'a> {
     fn transform_jsx_child(
         &mut self,
         child: &JSXChild<'a>,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Option<Expression<'a>> {
         match child {
             JSXChild::Text(text) => self.transform_jsx_text(text.value.as_str()),
@@ -0,0 +1,169 @@
+//! JSX optimizations enabled by [ReactOptions::optimize].
+//!
+//! * Constant elements inside functions are hoisted to the top level, so they are created once
+//!   instead of on every render, like
+//!   [plugin-transform-react-constant-elements](https://babeljs.io/docs/babel-plugin-transform-react-constant-elements).
+//!
+//!   ```jsx
+//!   function App() { return <div className="a">Hello</div> }
+//!   ```
+//!   ```js
+//!   const _el = _jsx("div", { className: "a", children: "Hello" });
+//!   function App() { return _el }
+//!   ```
+//!
+//! * Adjacent static text children are merged, `<p>Hello {"world"}</p>` passes
+//!   `children: "Hello world"` to `jsx` instead of `children: ["Hello ", "world"]` to `jsxs`.
+
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_span::SPAN;
+use oxc_syntax::symbol::SymbolFlags;
+use oxc_traverse::{FinderRet, TraverseCtx};
+
+use super::{JSXElementOrFragment, ReactJsx, ReactOptions};
+
+impl<'a> ReactJsx<'a> {
+    /// Only in production mode of the automatic runtime. The development runtime receives the
+    /// source location of each element, and `this` for `__self`.
+    pub(super) fn is_optimize_enabled(&self) -> bool {
+        self.options.optimize && !is_development_runtime(&self.options)
+    }
+
+    /// Whether `e` is a constant element inside a function, which is not part of a constant
+    /// element being hoisted already.
+    pub(super) fn can_hoist<'b>(
+        &self,
+        e: &JSXElementOrFragment<'a, 'b>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        self.is_optimize_enabled()
+            && !self.in_constant_element
+            && is_constant_element(e)
+            && ctx
+                .find_scope_by_flags(|flags| {
+                    if flags.is_function() {
+                        FinderRet::Found(())
+                    } else {
+                        FinderRet::Continue
+                    }
+                })
+                .is_some()
+    }
+
+    /// Declare `const _el = call_expr` at the top level, and return a reference to `_el`.
+    pub(super) fn hoist_constant_element(
+        &mut self,
+        call_expr: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let root_scope_id = ctx.scopes().root_scope_id();
+        let name = ctx.generate_uid(
+            "el",
+            root_scope_id,
+            SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable,
+        );
+        let name = self.ast().new_atom(&name);
+
+        let kind = VariableDeclarationKind::Const;
+        let id = {
+            let ident = BindingIdentifier::new(SPAN, name.clone());
+            let ident = self.ast().binding_pattern_identifier(ident);
+            self.ast().binding_pattern(ident, None, false)
+        };
+        let decl = self.ast().variable_declarator(SPAN, kind, id, Some(call_expr), false);
+        let decl = self.ast().new_vec_single(decl);
+        let var_decl = self.ast().variable_declaration(SPAN, kind, decl, Modifiers::empty());
+        self.hoisted_elements.push(Statement::VariableDeclaration(var_decl));
+
+        let ident = IdentifierReference::new(SPAN, name);
+        self.ast().identifier_reference_expression(ident)
+    }
+
+    /// Merge adjacent string literals of transformed children.
+    pub(super) fn merge_static_children(&self, children: &mut Vec<'a, Expression<'a>>) {
+        let mut merged = self.ast().new_vec_with_capacity(children.len());
+        for child in children.drain(..) {
+            if let (Some(Expression::StringLiteral(last)), Expression::StringLiteral(string)) =
+                (merged.last_mut(), &child)
+            {
+                let value = format!("{}{}", last.value, string.value);
+                last.value = self.ast().new_atom(&value);
+                continue;
+            }
+            merged.push(child);
+        }
+        *children = merged;
+    }
+}
+
+fn is_development_runtime(options: &ReactOptions) -> bool {
+    options.development
+        || options.is_jsx_self_plugin_enabled()
+        || options.is_jsx_source_plugin_enabled()
+}
+
+/// A host element or fragment which only contains literals and other constant elements.
+/// Evaluating it twice creates two equal elements.
+fn is_constant_element(e: &JSXElementOrFragment) -> bool {
+    match e {
+        JSXElementOrFragment::Element(e) => {
+            is_host_element_name(&e.opening_element.name)
+                && e.opening_element.attributes.iter().all(is_constant_attribute)
+                && e.children.iter().all(is_constant_child)
+        }
+        JSXElementOrFragment::Fragment(e) => e.children.iter().all(is_constant_child),
+    }
+}
+
+/// `<div>`, but not `<Component>`, `<this>`, `<a.b>` or `<a:b>`.
+pub(super) fn is_host_element_name(name: &JSXElementName) -> bool {
+    matches!(name, JSXElementName::Identifier(ident)
+        if ident.name != "this" && ident.name.starts_with(|c: char| c.is_ascii_lowercase()))
+}
+
+fn is_constant_attribute(attribute: &JSXAttributeItem) -> bool {
+    let JSXAttributeItem::Attribute(attr) = attribute else { return false };
+    // The same ref object would be attached to every rendered copy.
+    if attr.is_identifier("ref") {
+        return false;
+    }
+    match &attr.value {
+        None | Some(JSXAttributeValue::StringLiteral(_)) => true,
+        Some(JSXAttributeValue::ExpressionContainer(container)) => {
+            is_constant_jsx_expression(&container.expression)
+        }
+        Some(JSXAttributeValue::Element(e)) => {
+            is_constant_element(&JSXElementOrFragment::Element(e))
+        }
+        Some(JSXAttributeValue::Fragment(e)) => {
+            is_constant_element(&JSXElementOrFragment::Fragment(e))
+        }
+    }
+}
+
+fn is_constant_child(child: &JSXChild) -> bool {
+    match child {
+        JSXChild::Text(_) => true,
+        JSXChild::ExpressionContainer(container) => {
+            is_constant_jsx_expression(&container.expression)
+        }
+        JSXChild::Element(e) => is_constant_element(&JSXElementOrFragment::Element(e)),
+        JSXChild::Fragment(e) => is_constant_element(&JSXElementOrFragment::Fragment(e)),
+        JSXChild::Spread(_) => false,
+    }
+}
+
+/// Primitive literals. Object, array and regular expression literals create a new object each
+/// time they are evaluated.
+fn is_constant_jsx_expression(expr: &JSXExpression) -> bool {
+    match expr {
+        JSXExpression::EmptyExpression(_)
+        | JSXExpression::StringLiteral(_)
+        | JSXExpression::NumericLiteral(_)
+        | JSXExpression::BooleanLiteral(_)
+        | JSXExpression::NullLiteral(_) => true,
+        JSXExpression::TemplateLiteral(lit) => lit.expressions.is_empty(),
+        _ => false,
+    }
+}
@@ -0,0 +1,880 @@
+//! Solid runtime, enabled by `runtime: "solid"`.
+//!
+//! Compiles JSX for [Solid](https://www.solidjs.com), compatible with
+//! [dom-expressions](https://github.com/ryansolid/dom-expressions):
+//!
+//! * Native elements are cloned from a template, which is created once at the top level.
+//!   The dynamic parts are filled in after cloning, and reactive ones are wrapped in `effect`.
+//!
+//!   ```jsx
+//!   const App = () => <div class="a" title={props.title}>Hello {name()}</div>
+//!   ```
+//!   ```js
+//!   import { template as _$template, insert as _$insert, effect as _$effect, setAttribute as _$setAttribute } from "solid-js/web";
+//!   const _tmpl$ = _$template("<div class=\"a\">Hello </div>");
+//!   const App = () => (() => {
+//!     const _el$ = _tmpl$();
+//!     _$effect(() => _$setAttribute(_el$, "title", props.title));
+//!     _$insert(_el$, name, null);
+//!     return _el$;
+//!   })();
+//!   ```
+//!
+//! * Handlers of common events are delegated to the document, e.g. `onClick={f}` is compiled to
+//!   `_el$.$$click = f`, and the events are registered with `delegateEvents` at the end of the
+//!   module. Other events are added with `addEventListener`.
+//!
+//! * Components are called with `createComponent`, and reactive props are passed as getters, so
+//!   they are only read when the component uses them.
+//!
+//! An expression is reactive if it calls a function or reads a property outside of a nested
+//! function, as either may read a signal.
+
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, Visit};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, UnaryOperator},
+    scope::ScopeFlags,
+    symbol::SymbolFlags,
+};
+use oxc_traverse::TraverseCtx;
+
+use super::{diagnostics, optimize::is_host_element_name, JSXElementOrFragment, ReactJsx};
+
+/// Events which are handled by a single listener on the document.
+const DELEGATED_EVENTS: &[&str] = &[
+    "beforeinput",
+    "click",
+    "contextmenu",
+    "dblclick",
+    "focusin",
+    "focusout",
+    "input",
+    "keydown",
+    "keyup",
+    "mousedown",
+    "mousemove",
+    "mouseout",
+    "mouseover",
+    "mouseup",
+    "pointerdown",
+    "pointermove",
+    "pointerout",
+    "pointerover",
+    "pointerup",
+    "touchend",
+    "touchmove",
+    "touchstart",
+];
+
+/// Elements without a closing tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Attributes which are set as properties of the element, as the attribute only holds the
+/// initial value.
+const PROPERTIES: &[&str] = &[
+    "checked",
+    "indeterminate",
+    "innerHTML",
+    "innerText",
+    "muted",
+    "selected",
+    "textContent",
+    "value",
+];
+
+/// The template of a native element, and the code which fills in its dynamic parts.
+struct Template<'a> {
+    html: String,
+    /// `_el$ = _tmpl$(), _el$2 = _el$.firstChild, ...`
+    declarators: Vec<'a, VariableDeclarator<'a>>,
+    statements: Vec<'a, Statement<'a>>,
+}
+
+/// A child of a native element.
+enum SolidChild<'a, 'b> {
+    /// Static text, which is part of the template.
+    Text(String),
+    /// A native element, which is part of the template.
+    Element(&'b JSXElement<'a>),
+    /// An expression, component or fragment, which is inserted after cloning the template.
+    Dynamic(&'b JSXChild<'a>),
+}
+
+/// A DOM node in the template, which is created for a child.
+enum TemplateNode<'a, 'b> {
+    Text(&'b str),
+    Element(&'b JSXElement<'a>),
+    /// `<!>`, which marks where a dynamic child is inserted.
+    Marker,
+}
+
+/// Where a dynamic child is inserted.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum InsertMarker {
+    /// Replaces all children of the element, `insert(el, value)`.
+    All,
+    /// After the other children, `insert(el, value, null)`.
+    End,
+    /// Before the template node with this index, `insert(el, value, marker)`.
+    Before(usize),
+}
+
+impl<'a> ReactJsx<'a> {
+    pub(super) fn transform_solid<'b>(
+        &mut self,
+        e: &JSXElementOrFragment<'a, 'b>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match e {
+            JSXElementOrFragment::Element(e) if is_host_element_name(&e.opening_element.name) => {
+                self.transform_solid_native_element(e, ctx)
+            }
+            JSXElementOrFragment::Element(e) => self.transform_solid_component(e, ctx),
+            JSXElementOrFragment::Fragment(e) => {
+                let allocator = self.ast().allocator;
+                let mut children = Vec::from_iter_in(
+                    e.children.iter().filter_map(|child| self.transform_solid_child(child, ctx)),
+                    allocator,
+                );
+                if children.len() == 1 {
+                    return children.pop().unwrap();
+                }
+                let elements = Vec::from_iter_in(
+                    children.into_iter().map(ArrayExpressionElement::from),
+                    allocator,
+                );
+                self.ast().array_expression(SPAN, elements, None)
+            }
+        }
+    }
+
+    /// `_$delegateEvents(["click", ...])` for the delegated events handled in this module.
+    pub(super) fn get_delegate_events_statement(&mut self) -> Option<Statement<'a>> {
+        if self.delegated_events.is_empty() {
+            return None;
+        }
+        let elements = self.ast().new_vec_from_iter(self.delegated_events.iter().map(|event| {
+            let event = StringLiteral::new(SPAN, (*event).into());
+            ArrayExpressionElement::from(self.ast().literal_string_expression(event))
+        }));
+        let events = self.ast().array_expression(SPAN, elements, None);
+        let call = self.call_solid_helper("delegateEvents", [events]);
+        Some(self.ast().expression_statement(SPAN, call))
+    }
+
+    /// `const _tmpl$ = _$template("<div>...</div>")` at the top level, and
+    /// `_tmpl$()` or `(() => { const _el$ = _tmpl$(); ...; return _el$; })()`
+    fn transform_solid_native_element(
+        &mut self,
+        e: &JSXElement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let flags = SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable;
+        let root_scope_id = ctx.scopes().root_scope_id();
+        let template_name = ctx.generate_uid("tmpl$", root_scope_id, flags);
+        let template_name = self.ast().new_atom(&template_name);
+
+        let mut template = Template {
+            html: String::new(),
+            declarators: self.ast().new_vec(),
+            statements: self.ast().new_vec(),
+        };
+        let root = needs_reference(e).then(|| {
+            let name = ctx.generate_uid_in_current_scope("el$", flags);
+            let name = self.ast().new_atom(&name);
+            let init = self.clone_template(&template_name);
+            self.declare_element(&mut template, name.clone(), init);
+            name
+        });
+        self.build_template(e, root.as_ref(), &mut template, ctx);
+
+        let html = self.ast().new_atom(&template.html);
+        let html = self.ast().literal_string_expression(StringLiteral::new(SPAN, html));
+        let init = self.call_solid_helper("template", [html]);
+        let kind = VariableDeclarationKind::Const;
+        let id = {
+            let ident = BindingIdentifier::new(SPAN, template_name.clone());
+            let ident = self.ast().binding_pattern_identifier(ident);
+            self.ast().binding_pattern(ident, None, false)
+        };
+        let decl = self.ast().variable_declarator(SPAN, kind, id, Some(init), false);
+        let decl = self.ast().new_vec_single(decl);
+        let var_decl = self.ast().variable_declaration(SPAN, kind, decl, Modifiers::empty());
+        self.hoisted_elements.push(Statement::VariableDeclaration(var_decl));
+
+        let Some(root) = root else { return self.clone_template(&template_name) };
+        let mut statements = self.ast().new_vec_with_capacity(template.statements.len() + 2);
+        let var_decl =
+            self.ast().variable_declaration(SPAN, kind, template.declarators, Modifiers::empty());
+        statements.push(Statement::VariableDeclaration(var_decl));
+        statements.extend(template.statements);
+        statements.push(self.ast().return_statement(SPAN, Some(self.element_reference(&root))));
+        let params = self.ast().formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            self.ast().new_vec(),
+            None,
+        );
+        let body = self.ast().function_body(SPAN, self.ast().new_vec(), statements);
+        let callee =
+            self.ast().arrow_function_expression(SPAN, false, false, params, body, None, None);
+        self.ast().call_expression(SPAN, callee, self.ast().new_vec(), false, None)
+    }
+
+    /// Append the HTML of `e` to the template, and the code which fills in its dynamic parts.
+    /// `element` references `e` in the cloned template if `e` has dynamic parts.
+    fn build_template(
+        &mut self,
+        e: &JSXElement<'a>,
+        element: Option<&Atom<'a>>,
+        template: &mut Template<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let JSXElementName::Identifier(tag) = &e.opening_element.name else {
+            unreachable!("native elements are named by an identifier")
+        };
+        template.html.push('<');
+        template.html.push_str(&tag.name);
+        for attribute in &e.opening_element.attributes {
+            match attribute {
+                JSXAttributeItem::Attribute(attr) if is_static_attribute(attr) => {
+                    template.html.push(' ');
+                    template.html.push_str(template_attribute_name(&attr.name).as_str());
+                    if let Some(JSXAttributeValue::StringLiteral(s)) = &attr.value {
+                        let value = Self::decode_entities(s.value.as_str());
+                        template.html.push_str("=\"");
+                        template.html.push_str(&escape_html(&value, true));
+                        template.html.push('"');
+                    }
+                }
+                JSXAttributeItem::Attribute(attr) => {
+                    let element = element.expect("elements with dynamic attributes are referenced");
+                    let expr = self.transform_solid_attribute(element, attr, ctx);
+                    template.statements.push(self.ast().expression_statement(SPAN, expr));
+                }
+                JSXAttributeItem::SpreadAttribute(spread) => {
+                    let element = element.expect("elements with dynamic attributes are referenced");
+                    let has_children = !e.children.is_empty();
+                    let arguments = [
+                        self.element_reference(element),
+                        self.ast().copy(&spread.argument),
+                        self.ast().literal_boolean_expression(BooleanLiteral::new(SPAN, false)),
+                        self.ast()
+                            .literal_boolean_expression(BooleanLiteral::new(SPAN, has_children)),
+                    ];
+                    let call = self.call_solid_helper("spread", arguments);
+                    template.statements.push(self.ast().expression_statement(SPAN, call));
+                }
+            }
+        }
+        template.html.push('>');
+        if VOID_ELEMENTS.contains(&tag.name.as_str()) {
+            return;
+        }
+
+        let children = self.collect_solid_children(&e.children);
+        let (nodes, inserts) = layout_children(&children);
+        // The references to the nodes, which are either elements with dynamic parts or markers.
+        let mut references = std::vec::Vec::with_capacity(nodes.len());
+        let mut previous = None;
+        for (index, node) in nodes.iter().enumerate() {
+            let is_marker =
+                inserts.iter().any(|(_, marker)| *marker == InsertMarker::Before(index));
+            let is_referenced = match node {
+                TemplateNode::Element(e) => is_marker || needs_reference(e),
+                TemplateNode::Text(_) | TemplateNode::Marker => is_marker,
+            };
+            let reference = is_referenced.then(|| {
+                let parent = element.expect("elements with dynamic children are referenced");
+                let init = self.sibling_reference(parent, previous.as_ref(), index);
+                let name = ctx.generate_uid_in_current_scope(
+                    "el$",
+                    SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable,
+                );
+                let name = self.ast().new_atom(&name);
+                self.declare_element(template, name.clone(), init);
+                previous = Some((name.clone(), index));
+                name
+            });
+            match node {
+                TemplateNode::Text(text) => template.html.push_str(&escape_html(text, false)),
+                TemplateNode::Element(e) => {
+                    self.build_template(e, reference.as_ref(), template, ctx);
+                }
+                TemplateNode::Marker => template.html.push_str("<!>"),
+            }
+            references.push(reference);
+        }
+
+        for (child, marker) in inserts {
+            let element = element.expect("elements with dynamic children are referenced");
+            let Some(value) = self.transform_solid_child(child, ctx) else { continue };
+            let mut arguments = vec![self.element_reference(element), value];
+            match marker {
+                InsertMarker::All => {}
+                InsertMarker::End => {
+                    arguments.push(self.ast().literal_null_expression(NullLiteral::new(SPAN)));
+                }
+                InsertMarker::Before(index) => {
+                    let marker = references[index].as_ref().expect("markers are referenced");
+                    arguments.push(self.element_reference(marker));
+                }
+            }
+            let call = self.call_solid_helper("insert", arguments);
+            template.statements.push(self.ast().expression_statement(SPAN, call));
+        }
+
+        template.html.push_str("</");
+        template.html.push_str(&tag.name);
+        template.html.push('>');
+    }
+
+    /// Static text and native elements are part of the template, everything else is dynamic.
+    fn collect_solid_children<'b>(
+        &self,
+        children: &'b Vec<'a, JSXChild<'a>>,
+    ) -> std::vec::Vec<SolidChild<'a, 'b>> {
+        let mut collected: std::vec::Vec<SolidChild> = vec![];
+        for child in children {
+            let child = match child {
+                JSXChild::Text(text) => {
+                    let Some(text) =
+                        Self::fixup_whitespace_and_decode_entities(text.value.as_str())
+                    else {
+                        continue;
+                    };
+                    SolidChild::Text(text)
+                }
+                JSXChild::ExpressionContainer(container) => match &container.expression {
+                    JSXExpression::EmptyExpression(_) => continue,
+                    JSXExpression::StringLiteral(s) => SolidChild::Text(s.value.to_string()),
+                    _ => SolidChild::Dynamic(child),
+                },
+                JSXChild::Element(e) if is_host_element_name(&e.opening_element.name) => {
+                    SolidChild::Element(e)
+                }
+                JSXChild::Element(_) | JSXChild::Fragment(_) => SolidChild::Dynamic(child),
+                JSXChild::Spread(e) => {
+                    self.ctx.error(diagnostics::spread_children_are_not_supported(e.span));
+                    continue;
+                }
+            };
+            // Adjacent text is a single text node in the template.
+            if let (Some(SolidChild::Text(last)), SolidChild::Text(text)) =
+                (collected.last_mut(), &child)
+            {
+                last.push_str(text);
+                continue;
+            }
+            collected.push(child);
+        }
+        collected
+    }
+
+    /// A child of a component, a fragment or an `insert`. Reactive expressions are wrapped in a
+    /// function, so they are tracked where they are inserted.
+    fn transform_solid_child(
+        &mut self,
+        child: &JSXChild<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let JSXChild::ExpressionContainer(container) = child else {
+            return self.transform_jsx_child(child, ctx);
+        };
+        let expr = match &container.expression {
+            e @ match_expression!(JSXExpression) => e.to_expression(),
+            JSXExpression::EmptyExpression(_) => return None,
+        };
+        // `{count()}` -> `count`
+        if let Expression::CallExpression(call) = expr {
+            if !call.optional
+                && call.arguments.is_empty()
+                && matches!(call.callee, Expression::Identifier(_))
+            {
+                return Some(self.ast().copy(&call.callee));
+            }
+        }
+        let expr = self.ast().copy(expr);
+        if is_reactive(&expr) {
+            return Some(self.solid_arrow(None, expr));
+        }
+        Some(expr)
+    }
+
+    /// `_$createComponent(Component, { a: 1, get b() { return c.d } })`
+    fn transform_solid_component(
+        &mut self,
+        e: &JSXElement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let component = self.transform_element_name(&e.opening_element.name);
+        // The arguments of `mergeProps` if there are spread attributes
+        let mut sources = vec![];
+        let mut properties = self.ast().new_vec();
+        for attribute in &e.opening_element.attributes {
+            match attribute {
+                JSXAttributeItem::Attribute(attr) => {
+                    let key = self.get_attribute_name(&attr.name);
+                    let (value, is_getter) = match &attr.value {
+                        Some(JSXAttributeValue::ExpressionContainer(container)) => {
+                            match &container.expression {
+                                e @ match_expression!(JSXExpression) => {
+                                    let expr = self.ast().copy(e.to_expression());
+                                    let is_getter = is_reactive(&expr);
+                                    (expr, is_getter)
+                                }
+                                JSXExpression::EmptyExpression(_) => {
+                                    let literal = BooleanLiteral::new(SPAN, true);
+                                    (self.ast().literal_boolean_expression(literal), false)
+                                }
+                            }
+                        }
+                        value @ Some(
+                            JSXAttributeValue::Element(_) | JSXAttributeValue::Fragment(_),
+                        ) => (self.transform_jsx_attribute_value(value.as_ref(), ctx), true),
+                        value => (self.transform_jsx_attribute_value(value.as_ref(), ctx), false),
+                    };
+                    properties.push(self.solid_property(key, value, is_getter));
+                }
+                JSXAttributeItem::SpreadAttribute(spread) => {
+                    if !properties.is_empty() {
+                        let properties = std::mem::replace(&mut properties, self.ast().new_vec());
+                        sources.push(self.ast().object_expression(SPAN, properties, None));
+                    }
+                    sources.push(self.ast().copy(&spread.argument));
+                }
+            }
+        }
+
+        let children = e
+            .children
+            .iter()
+            .filter(|child| match child {
+                JSXChild::Text(text) => {
+                    Self::fixup_whitespace_and_decode_entities(text.value.as_str()).is_some()
+                }
+                JSXChild::ExpressionContainer(container) => {
+                    !matches!(container.expression, JSXExpression::EmptyExpression(_))
+                }
+                _ => true,
+            })
+            .collect::<std::vec::Vec<_>>();
+        let children = match children.as_slice() {
+            [] => None,
+            [JSXChild::ExpressionContainer(container)] => {
+                let expr = self.ast().copy(container.expression.to_expression());
+                let is_getter = is_reactive(&expr);
+                Some((expr, is_getter))
+            }
+            [child] => self
+                .transform_solid_child(child, ctx)
+                .map(|expr| (expr, !matches!(child, JSXChild::Text(_)))),
+            children => {
+                let allocator = self.ast().allocator;
+                let elements = Vec::from_iter_in(
+                    children
+                        .iter()
+                        .filter_map(|child| self.transform_solid_child(child, ctx))
+                        .map(ArrayExpressionElement::from),
+                    allocator,
+                );
+                Some((self.ast().array_expression(SPAN, elements, None), true))
+            }
+        };
+        if let Some((value, is_getter)) = children {
+            let key =
+                self.ast().property_key_identifier(IdentifierName::new(SPAN, "children".into()));
+            properties.push(self.solid_property(key, value, is_getter));
+        }
+
+        if !properties.is_empty() || sources.is_empty() {
+            sources.push(self.ast().object_expression(SPAN, properties, None));
+        }
+        let props = if sources.len() == 1 {
+            sources.pop().unwrap()
+        } else {
+            self.call_solid_helper("mergeProps", sources)
+        };
+        self.call_solid_helper("createComponent", [component, props])
+    }
+
+    /// The code which sets a dynamic attribute of a native element.
+    fn transform_solid_attribute(
+        &mut self,
+        element: &Atom<'a>,
+        attr: &JSXAttribute<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let value = self.transform_jsx_attribute_value(attr.value.as_ref(), ctx);
+        let name = match &attr.name {
+            JSXAttributeName::Identifier(ident) if ident.name == "ref" => {
+                return self.solid_ref(element, value);
+            }
+            JSXAttributeName::Identifier(ident) if is_event_handler(&ident.name) => {
+                let event = ident.name[2..].to_ascii_lowercase();
+                let Some(event) = DELEGATED_EVENTS.iter().find(|e| **e == event) else {
+                    return self.add_event_listener(element, &event, value);
+                };
+                if !self.delegated_events.contains(event) {
+                    self.delegated_events.push(*event);
+                }
+                // `_el$.$$click = handler`
+                let property = self.ast().new_atom(&format!("$${event}"));
+                let target = self.ast().static_member(
+                    SPAN,
+                    self.element_reference(element),
+                    IdentifierName::new(SPAN, property),
+                    false,
+                );
+                let target = self.ast().simple_assignment_target_member_expression(target);
+                return self.ast().assignment_expression(
+                    SPAN,
+                    AssignmentOperator::Assign,
+                    target,
+                    value,
+                );
+            }
+            JSXAttributeName::NamespacedName(name) if name.namespace.name == "on" => {
+                return self.add_event_listener(element, &name.property.name, value);
+            }
+            name => template_attribute_name(name),
+        };
+
+        if !is_reactive(&value) {
+            return self.set_solid_attribute(element, &name, value, None);
+        }
+        // `style` and `classList` are diffed against the previous value.
+        let (params, previous) = if matches!(name.as_str(), "style" | "classList") {
+            let previous =
+                ctx.generate_uid_in_current_scope("p$", SymbolFlags::FunctionScopedVariable);
+            let previous = self.ast().new_atom(&previous);
+            let param = {
+                let ident = BindingIdentifier::new(SPAN, previous.clone());
+                let ident = self.ast().binding_pattern_identifier(ident);
+                let pattern = self.ast().binding_pattern(ident, None, false);
+                self.ast().plain_formal_parameter(SPAN, pattern)
+            };
+            (Some(param), Some(self.element_reference(&previous)))
+        } else {
+            (None, None)
+        };
+        let update = self.set_solid_attribute(element, &name, value, previous);
+        let effect = self.solid_arrow(params, update);
+        self.call_solid_helper("effect", [effect])
+    }
+
+    /// `_$className(_el$, value)`, `_el$.value = value` or `_$setAttribute(_el$, "name", value)`
+    fn set_solid_attribute(
+        &mut self,
+        element: &Atom<'a>,
+        name: &str,
+        value: Expression<'a>,
+        previous: Option<Expression<'a>>,
+    ) -> Expression<'a> {
+        let element = self.element_reference(element);
+        match name {
+            "class" => self.call_solid_helper("className", [element, value]),
+            "style" | "classList" => {
+                let helper = if name == "style" { "style" } else { "classList" };
+                let arguments = [element, value].into_iter().chain(previous);
+                self.call_solid_helper(helper, arguments)
+            }
+            name if PROPERTIES.contains(&name) => {
+                let property = IdentifierName::new(SPAN, self.ast().new_atom(name));
+                let target = self.ast().static_member(SPAN, element, property, false);
+                let target = self.ast().simple_assignment_target_member_expression(target);
+                self.ast().assignment_expression(SPAN, AssignmentOperator::Assign, target, value)
+            }
+            name => {
+                let name = StringLiteral::new(SPAN, self.ast().new_atom(name));
+                let name = self.ast().literal_string_expression(name);
+                self.call_solid_helper("setAttribute", [element, name, value])
+            }
+        }
+    }
+
+    /// `ref={el}` assigns the element to `el`, unless `el` is a function which is called with it.
+    ///
+    /// `typeof el === "function" ? _$use(el, _el$) : el = _el$`
+    fn solid_ref(&mut self, element: &Atom<'a>, value: Expression<'a>) -> Expression<'a> {
+        let Expression::Identifier(ident) = &value else {
+            return self.call_solid_helper("use", [value, self.element_reference(element)]);
+        };
+        let ident = IdentifierReference::new(SPAN, ident.name.clone());
+        let test = {
+            let argument = self.ast().identifier_reference_expression(ident.clone());
+            let typeof_expr = self.ast().unary_expression(SPAN, UnaryOperator::Typeof, argument);
+            let function = StringLiteral::new(SPAN, "function".into());
+            let function = self.ast().literal_string_expression(function);
+            self.ast().binary_expression(
+                SPAN,
+                typeof_expr,
+                BinaryOperator::StrictEquality,
+                function,
+            )
+        };
+        let consequent = self.call_solid_helper("use", [value, self.element_reference(element)]);
+        let alternate = {
+            let target = self.ast().simple_assignment_target_identifier(ident);
+            let element = self.element_reference(element);
+            self.ast().assignment_expression(SPAN, AssignmentOperator::Assign, target, element)
+        };
+        self.ast().conditional_expression(SPAN, test, consequent, alternate)
+    }
+
+    /// `_el$.addEventListener("event", handler)`
+    fn add_event_listener(
+        &self,
+        element: &Atom<'a>,
+        event: &str,
+        handler: Expression<'a>,
+    ) -> Expression<'a> {
+        let callee = {
+            let property = IdentifierName::new(SPAN, "addEventListener".into());
+            self.ast().static_member_expression(
+                SPAN,
+                self.element_reference(element),
+                property,
+                false,
+            )
+        };
+        let event = StringLiteral::new(SPAN, self.ast().new_atom(event));
+        let arguments = self.ast().new_vec_from_iter([
+            Argument::from(self.ast().literal_string_expression(event)),
+            Argument::from(handler),
+        ]);
+        self.ast().call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    /// `key: value` or `get key() { return value }`
+    fn solid_property(
+        &self,
+        key: PropertyKey<'a>,
+        value: Expression<'a>,
+        is_getter: bool,
+    ) -> ObjectPropertyKind<'a> {
+        let (kind, value) = if is_getter {
+            let params = self.ast().formal_parameters(
+                SPAN,
+                FormalParameterKind::FormalParameter,
+                self.ast().new_vec(),
+                None,
+            );
+            let statements =
+                self.ast().new_vec_single(self.ast().return_statement(SPAN, Some(value)));
+            let body = self.ast().function_body(SPAN, self.ast().new_vec(), statements);
+            let function = self.ast().plain_function(
+                FunctionType::FunctionExpression,
+                SPAN,
+                None,
+                params,
+                Some(body),
+            );
+            (PropertyKind::Get, self.ast().function_expression(function))
+        } else {
+            (PropertyKind::Init, value)
+        };
+        let property =
+            self.ast().object_property(SPAN, kind, key, value, None, false, false, false);
+        ObjectPropertyKind::ObjectProperty(property)
+    }
+
+    /// `() => expr` or `(param) => expr`
+    fn solid_arrow(
+        &self,
+        param: Option<FormalParameter<'a>>,
+        expr: Expression<'a>,
+    ) -> Expression<'a> {
+        let params = self.ast().formal_parameters(
+            SPAN,
+            FormalParameterKind::ArrowFormalParameters,
+            self.ast().new_vec_from_iter(param),
+            None,
+        );
+        let statements = self.ast().new_vec_single(self.ast().expression_statement(SPAN, expr));
+        let body = self.ast().function_body(SPAN, self.ast().new_vec(), statements);
+        self.ast().arrow_function_expression(SPAN, true, false, params, body, None, None)
+    }
+
+    /// `_$name(...arguments)`, importing `name` from the Solid runtime.
+    fn call_solid_helper(
+        &mut self,
+        name: &'static str,
+        arguments: impl IntoIterator<Item = Expression<'a>>,
+    ) -> Expression<'a> {
+        let local = format!("_${name}");
+        if !self.solid_imports.contains(&name) {
+            self.solid_imports.push(name);
+            self.add_import_statement(name, &local, self.jsx_runtime_importer.clone());
+        }
+        let ident = IdentifierReference::new(SPAN, self.ast().new_atom(&local));
+        let callee = self.ast().identifier_reference_expression(ident);
+        let arguments = self.ast().new_vec_from_iter(arguments.into_iter().map(Argument::from));
+        self.ast().call_expression(SPAN, callee, arguments, false, None)
+    }
+
+    /// `_tmpl$()`
+    fn clone_template(&self, template_name: &Atom<'a>) -> Expression<'a> {
+        let callee = self.element_reference(template_name);
+        self.ast().call_expression(SPAN, callee, self.ast().new_vec(), false, None)
+    }
+
+    fn declare_element(&self, template: &mut Template<'a>, name: Atom<'a>, init: Expression<'a>) {
+        let id = {
+            let ident = BindingIdentifier::new(SPAN, name);
+            let ident = self.ast().binding_pattern_identifier(ident);
+            self.ast().binding_pattern(ident, None, false)
+        };
+        let kind = VariableDeclarationKind::Const;
+        let decl = self.ast().variable_declarator(SPAN, kind, id, Some(init), false);
+        template.declarators.push(decl);
+    }
+
+    /// `_el$.firstChild.nextSibling`, or `_el$2.nextSibling` from the previous referenced sibling.
+    fn sibling_reference(
+        &self,
+        parent: &Atom<'a>,
+        previous: Option<&(Atom<'a>, usize)>,
+        index: usize,
+    ) -> Expression<'a> {
+        let (mut expr, steps) = if let Some((name, previous_index)) = previous {
+            (self.element_reference(name), index - previous_index)
+        } else {
+            let property = IdentifierName::new(SPAN, "firstChild".into());
+            let parent = self.element_reference(parent);
+            (self.ast().static_member_expression(SPAN, parent, property, false), index)
+        };
+        for _ in 0..steps {
+            let property = IdentifierName::new(SPAN, "nextSibling".into());
+            expr = self.ast().static_member_expression(SPAN, expr, property, false);
+        }
+        expr
+    }
+
+    fn element_reference(&self, name: &Atom<'a>) -> Expression<'a> {
+        let ident = IdentifierReference::new(SPAN, name.clone());
+        self.ast().identifier_reference_expression(ident)
+    }
+}
+
+/// The nodes of the children in the template, and where the dynamic children are inserted.
+/// A `<!>` marker is added before children which are followed by another dynamic child, or which
+/// separate two texts.
+fn layout_children<'a, 'b>(
+    children: &'b [SolidChild<'a, 'b>],
+) -> (std::vec::Vec<TemplateNode<'a, 'b>>, std::vec::Vec<(&'b JSXChild<'a>, InsertMarker)>) {
+    let mut nodes = vec![];
+    let mut inserts = vec![];
+    for (index, child) in children.iter().enumerate() {
+        match child {
+            SolidChild::Text(text) => nodes.push(TemplateNode::Text(text)),
+            SolidChild::Element(e) => nodes.push(TemplateNode::Element(e)),
+            SolidChild::Dynamic(child) => {
+                let marker = match children.get(index + 1) {
+                    _ if children.len() == 1 => InsertMarker::All,
+                    None => InsertMarker::End,
+                    Some(SolidChild::Element(_)) => InsertMarker::Before(nodes.len()),
+                    Some(SolidChild::Text(_))
+                        if !matches!(nodes.last(), Some(TemplateNode::Text(_))) =>
+                    {
+                        InsertMarker::Before(nodes.len())
+                    }
+                    Some(_) => {
+                        nodes.push(TemplateNode::Marker);
+                        InsertMarker::Before(nodes.len() - 1)
+                    }
+                };
+                inserts.push((*child, marker));
+            }
+        }
+    }
+    (nodes, inserts)
+}
+
+/// Whether `e` has dynamic attributes or children, which are filled in after cloning the template.
+fn needs_reference(e: &JSXElement) -> bool {
+    let has_dynamic_attribute = e.opening_element.attributes.iter().any(|attribute| {
+        !matches!(attribute, JSXAttributeItem::Attribute(attr) if is_static_attribute(attr))
+    });
+    has_dynamic_attribute
+        || e.children.iter().any(|child| match child {
+            JSXChild::Text(_) => false,
+            JSXChild::ExpressionContainer(container) => !matches!(
+                container.expression,
+                JSXExpression::EmptyExpression(_) | JSXExpression::StringLiteral(_)
+            ),
+            JSXChild::Element(e) => {
+                !is_host_element_name(&e.opening_element.name) || needs_reference(e)
+            }
+            JSXChild::Fragment(_) | JSXChild::Spread(_) => true,
+        })
+}
+
+/// `<div a>` and `<div a="b">`, which are part of the template.
+fn is_static_attribute(attr: &JSXAttribute) -> bool {
+    matches!(attr.value, None | Some(JSXAttributeValue::StringLiteral(_)))
+}
+
+/// `onClick`, but not `on:click` or `one`.
+fn is_event_handler(name: &str) -> bool {
+    name.len() > 2 && name.starts_with("on") && name.as_bytes()[2].is_ascii_uppercase()
+}
+
+/// The name of the HTML attribute, `className` is `class` and `htmlFor` is `for`.
+fn template_attribute_name(name: &JSXAttributeName) -> String {
+    match name {
+        JSXAttributeName::Identifier(ident) => match ident.name.as_str() {
+            "className" => "class".to_string(),
+            "htmlFor" => "for".to_string(),
+            name => name.to_string(),
+        },
+        JSXAttributeName::NamespacedName(name) => name.to_string(),
+    }
+}
+
+fn escape_html(text: &str, is_attribute: bool) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '"' if is_attribute => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Whether `expr` calls a function or reads a property outside of a nested function.
+fn is_reactive(expr: &Expression) -> bool {
+    let mut finder = ReactiveFinder { is_reactive: false };
+    finder.visit_expression(expr);
+    finder.is_reactive
+}
+
+struct ReactiveFinder {
+    is_reactive: bool,
+}
+
+impl<'a> Visit<'a> for ReactiveFinder {
+    fn visit_call_expression(&mut self, _expr: &CallExpression<'a>) {
+        self.is_reactive = true;
+    }
+
+    fn visit_tagged_template_expression(&mut self, _expr: &TaggedTemplateExpression<'a>) {
+        self.is_reactive = true;
+    }
+
+    fn visit_member_expression(&mut self, _expr: &MemberExpression<'a>) {
+        self.is_reactive = true;
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {}
+}
@@ -52,7 +52,7 @@ impl<'a> React<'a> {
         }
     }
 
-    pub fn transform_expression(&mut self, expr: &mut Expression<'a>, ctx: &TraverseCtx<'a>) {
+    pub fn transform_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         match expr {
             Expression::JSXElement(e) => {
                 if self.options.is_jsx_plugin_enabled() {
@@ -83,6 +83,10 @@ impl<'a> React<'a> {
         elem: &mut JSXOpeningElement<'a>,
         ctx: &TraverseCtx<'a>,
     ) {
+        // Elements compiled to templates have no props for `__self` and `__source`.
+        if self.options.runtime.is_solid() {
+            return;
+        }
         if self.options.is_jsx_self_plugin_enabled()
             && self.jsx.jsx_self.can_add_self_attribute(ctx)
         {
@@ -26,6 +26,7 @@ fn default_for_pragma_frag() -> Cow<'static, str> {
 ///
 /// Auto imports the functions that JSX transpiles to.
 /// classic does not automatic import anything.
+/// solid compiles JSX to the template cloning of Solid, and imports its runtime helpers.
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ReactJsxRuntime {
@@ -33,6 +34,10 @@ pub enum ReactJsxRuntime {
     /// The default runtime is switched to automatic in Babel 8.
     #[default]
     Automatic,
+    /// Compiles native elements to templates for [Solid](https://www.solidjs.com).
+    ///
+    /// The helpers are imported from `solid-js/web`, or from `importSource` if it is set.
+    Solid,
 }
 
 impl ReactJsxRuntime {
@@ -43,6 +48,10 @@ impl ReactJsxRuntime {
     pub fn is_automatic(self) -> bool {
         self == Self::Automatic
     }
+
+    pub fn is_solid(self) -> bool {
+        self == Self::Solid
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -91,6 +100,15 @@ pub struct ReactOptions {
     /// Defaults to `react`.
     #[serde(default = "default_for_import_source")]
     pub import_source: Cow<'static, str>,
+
+    /// Hoists constant elements out of functions and merges adjacent static text children.
+    ///
+    /// Similar to [plugin-transform-react-constant-elements](https://babeljs.io/docs/babel-plugin-transform-react-constant-elements),
+    /// but only host elements such as `<div>` with literal attributes and children are hoisted.
+    /// Not applied in development mode.
+    ///
+    /// Defaults to `false`.
+    pub optimize: bool,
     //
     // React Classic Runtime
     //
@@ -133,6 +151,7 @@ impl Default for ReactOptions {
             throw_if_namespace: default_as_true(),
             pure: default_as_true(),
             import_source: default_for_import_source(),
+            optimize: false,
             pragma: default_for_pragma(),
             pragma_frag: default_for_pragma_frag(),
             use_built_ins: None,
@@ -156,7 +175,7 @@ impl ReactOptions {
 
     /// Scan through all comments and find the following pragmas
     ///
-    /// * @jsxRuntime classic / automatic
+    /// * @jsxRuntime classic / automatic / solid
     ///
     /// The comment does not need to be a jsdoc,
     /// otherwise `JSDoc` could be used instead.
@@ -182,6 +201,10 @@ impl ReactOptions {
                     self.runtime = ReactJsxRuntime::Automatic;
                     continue;
                 }
+                Some("solid") => {
+                    self.runtime = ReactJsxRuntime::Solid;
+                    continue;
+                }
                 _ => {}
             }
 
@@ -11,15 +11,15 @@ use oxc_span::{Atom, SPAN};
 use oxc_syntax::operator::AssignmentOperator;
 use rustc_hash::FxHashSet;
 
-use super::collector::TypeScriptReferenceCollector;
+use super::{
+    collector::TypeScriptReferenceCollector,
+    diagnostics::{parameter_property_binding_pattern, parameter_property_outside_implementation},
+};
 
 pub struct TypeScriptAnnotations<'a> {
     #[allow(dead_code)]
     options: Rc<TypeScriptOptions>,
     ctx: Ctx<'a>,
-    /// Assignments to be added to the constructor body
-    assignments: Vec<'a, Statement<'a>>,
-    has_super_call: bool,
 
     has_jsx_element: bool,
     has_jsx_fragment: bool,
@@ -42,8 +42,6 @@ impl<'a> TypeScriptAnnotations<'a> {
         };
 
         Self {
-            has_super_call: false,
-            assignments: ctx.ast.new_vec(),
             options: Rc::clone(options),
             ctx: Rc::clone(ctx),
             has_jsx_element: false,
@@ -63,23 +61,267 @@ impl<'a> TypeScriptAnnotations<'a> {
     // Creates `this.name = name`
     fn create_this_property_assignment(&self, name: &Atom<'a>) -> Statement<'a> {
         let ast = &self.ctx.ast;
+        let member = ast.static_member(
+            SPAN,
+            ast.this_expression(SPAN),
+            ast.identifier_name(SPAN, name),
+            false,
+        );
+        self.create_this_assignment(
+            member,
+            ast.identifier_reference_expression(ast.identifier_reference(SPAN, name)),
+        )
+    }
+
+    // Creates `this.key` for a field, `None` if the key is private or computed
+    fn create_this_member(&self, key: &PropertyKey<'a>) -> Option<MemberExpression<'a>> {
+        let ast = &self.ctx.ast;
+        let this = ast.this_expression(SPAN);
+        Some(match key {
+            PropertyKey::StaticIdentifier(ident) => {
+                ast.static_member(SPAN, this, ast.identifier_name(SPAN, &ident.name), false)
+            }
+            PropertyKey::StringLiteral(lit) => ast.computed_member(
+                SPAN,
+                this,
+                ast.literal_string_expression(ast.copy(&**lit)),
+                false,
+            ),
+            PropertyKey::NumericLiteral(lit) => ast.computed_member(
+                SPAN,
+                this,
+                ast.literal_number_expression(ast.copy(&**lit)),
+                false,
+            ),
+            _ => return None,
+        })
+    }
 
+    fn create_this_assignment(
+        &self,
+        member: MemberExpression<'a>,
+        value: Expression<'a>,
+    ) -> Statement<'a> {
+        let ast = &self.ctx.ast;
         ast.expression_statement(
             SPAN,
             ast.assignment_expression(
                 SPAN,
                 AssignmentOperator::Assign,
-                ast.simple_assignment_target_member_expression(ast.static_member(
-                    SPAN,
-                    ast.this_expression(SPAN),
-                    ast.identifier_name(SPAN, name),
-                    false,
-                )),
-                ast.identifier_reference_expression(ast.identifier_reference(SPAN, name)),
+                ast.simple_assignment_target_member_expression(member),
+                value,
             ),
         )
     }
 
+    /// Lowers the parameter properties of the constructor, `constructor(private x) {}`, to
+    /// `this.x = x`, which runs right after `super()`, or first in the constructor of a base class.
+    ///
+    /// With `use_define_for_class_fields`, the instance fields are defined when `super()` returns,
+    /// so they are initialized before the parameter properties are assigned. Otherwise the fields
+    /// are assigned in the constructor, so their initializers are moved after the parameter
+    /// properties, `y = 1` to `this.y = 1`, and a constructor is added if the class has none.
+    fn transform_parameter_properties(&mut self, class: &mut Class<'a>) {
+        let is_declare = class.is_declare();
+        let mut statements = self.ctx.ast.new_vec();
+        for element in &class.body.body {
+            let ClassElement::MethodDefinition(def) = element else { continue };
+            if def.kind != MethodDefinitionKind::Constructor {
+                continue;
+            }
+            // Overloads and ambient classes have no constructor body to assign in.
+            let is_implementation = !is_declare && def.value.body.is_some();
+            for param in &def.value.params.items {
+                if param.accessibility.is_none() && !param.readonly && !param.r#override {
+                    continue;
+                }
+                if !is_implementation {
+                    self.ctx.error(parameter_property_outside_implementation(param.span));
+                } else if let Some(name) = param.pattern.get_identifier() {
+                    statements.push(self.create_this_property_assignment(name));
+                } else {
+                    self.ctx.error(parameter_property_binding_pattern(param.span));
+                }
+            }
+        }
+        if is_declare {
+            return;
+        }
+
+        let mut first_field_index = None;
+        if !self.options.use_define_for_class_fields {
+            let mut index = 0;
+            class.body.body.retain_mut(|element| {
+                let keep = self.move_field_initializer(element, &mut statements);
+                if !keep {
+                    first_field_index.get_or_insert(index);
+                }
+                index += 1;
+                keep
+            });
+        }
+        if statements.is_empty() {
+            return;
+        }
+
+        let is_derived = class.super_class.is_some();
+        let constructor = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(def)
+                if def.kind == MethodDefinitionKind::Constructor && def.value.body.is_some() =>
+            {
+                def.value.body.as_mut()
+            }
+            _ => None,
+        });
+        if let Some(body) = constructor {
+            self.insert_constructor_statements(body, statements, is_derived);
+        } else {
+            let constructor = self.create_constructor(statements, is_derived);
+            class.body.body.insert(first_field_index.unwrap_or(0), constructor);
+        }
+    }
+
+    /// Moves the initializer of an instance field to `statements`, for `[[Set]]` semantics.
+    /// Returns whether the field is kept in the class, i.e. it is static, private, decorated or
+    /// has a computed key.
+    fn move_field_initializer(
+        &self,
+        element: &mut ClassElement<'a>,
+        statements: &mut Vec<'a, Statement<'a>>,
+    ) -> bool {
+        let ClassElement::PropertyDefinition(prop) = element else { return true };
+        if prop.r#static
+            || prop.declare
+            || prop.r#type != PropertyDefinitionType::PropertyDefinition
+            || !prop.decorators.is_empty()
+        {
+            return true;
+        }
+        let Some(member) = self.create_this_member(&prop.key) else { return true };
+        if let Some(value) = prop.value.take() {
+            statements.push(self.create_this_assignment(member, value));
+        }
+        false
+    }
+
+    /// Creates `constructor() { ...statements }`,
+    /// or `constructor() { super(...arguments); ...statements }` for a derived class.
+    fn create_constructor(
+        &self,
+        statements: Vec<'a, Statement<'a>>,
+        is_derived: bool,
+    ) -> ClassElement<'a> {
+        let ast = &self.ctx.ast;
+        let mut body = ast.new_vec();
+        if is_derived {
+            let arguments = ast.identifier_reference_expression(IdentifierReference::new(
+                SPAN,
+                "arguments".into(),
+            ));
+            let arguments =
+                ast.new_vec_single(Argument::SpreadElement(ast.spread_element(SPAN, arguments)));
+            let super_call = ast.call_expression(SPAN, ast.super_(SPAN), arguments, false, None);
+            body.push(ast.expression_statement(SPAN, super_call));
+        }
+        body.extend(statements);
+        let params = ast.formal_parameters(
+            SPAN,
+            FormalParameterKind::UniqueFormalParameters,
+            ast.new_vec(),
+            None,
+        );
+        let body = ast.function_body(SPAN, ast.new_vec(), body);
+        let function =
+            ast.plain_function(FunctionType::FunctionExpression, SPAN, None, params, Some(body));
+        ast.class_constructor(SPAN, function)
+    }
+
+    fn insert_constructor_statements(
+        &self,
+        body: &mut FunctionBody<'a>,
+        statements: Vec<'a, Statement<'a>>,
+        is_derived: bool,
+    ) {
+        if !is_derived {
+            body.statements.splice(0..0, statements);
+        } else if !self.insert_after_super_calls(&mut body.statements, &statements) {
+            // `super()` is not a statement, e.g. `return super()`.
+            body.statements.extend(statements);
+        }
+    }
+
+    /// Inserts `statements` after each `super()` call, including the ones in blocks and branches.
+    /// Returns whether a `super()` call is found.
+    fn insert_after_super_calls(
+        &self,
+        stmts: &mut Vec<'a, Statement<'a>>,
+        statements: &Vec<'a, Statement<'a>>,
+    ) -> bool {
+        let mut found = false;
+        let mut index = 0;
+        while index < stmts.len() {
+            if is_super_call_statement(&stmts[index]) {
+                stmts.splice(index + 1..=index, self.ctx.ast.copy(statements));
+                index += statements.len();
+                found = true;
+            } else {
+                found |= self.insert_after_nested_super_calls(&mut stmts[index], statements);
+            }
+            index += 1;
+        }
+        found
+    }
+
+    fn insert_after_nested_super_calls(
+        &self,
+        stmt: &mut Statement<'a>,
+        statements: &Vec<'a, Statement<'a>>,
+    ) -> bool {
+        match stmt {
+            Statement::BlockStatement(block) => {
+                self.insert_after_super_calls(&mut block.body, statements)
+            }
+            Statement::IfStatement(stmt) => {
+                let mut found = self.insert_into_branch(&mut stmt.consequent, statements);
+                if let Some(alternate) = &mut stmt.alternate {
+                    found |= self.insert_into_branch(alternate, statements);
+                }
+                found
+            }
+            Statement::TryStatement(stmt) => {
+                let mut found = self.insert_after_super_calls(&mut stmt.block.body, statements);
+                if let Some(handler) = &mut stmt.handler {
+                    found |= self.insert_after_super_calls(&mut handler.body.body, statements);
+                }
+                if let Some(finalizer) = &mut stmt.finalizer {
+                    found |= self.insert_after_super_calls(&mut finalizer.body, statements);
+                }
+                found
+            }
+            _ => false,
+        }
+    }
+
+    /// Transform a branch which is a super call to a block statement
+    /// ```ts
+    /// if (true) super() else super();
+    /// // to
+    /// if (true) { super(); this.x = x } else { super(); this.x = x }
+    /// ```
+    fn insert_into_branch(
+        &self,
+        stmt: &mut Statement<'a>,
+        statements: &Vec<'a, Statement<'a>>,
+    ) -> bool {
+        if !is_super_call_statement(stmt) {
+            return self.insert_after_nested_super_calls(stmt, statements);
+        }
+        let mut body = self.ctx.ast.new_vec_single(self.ctx.ast.move_statement(stmt));
+        body.extend(self.ctx.ast.copy(statements));
+        *stmt = self.ctx.ast.block_statement(self.ctx.ast.block(SPAN, body));
+        true
+    }
+
     // Remove type only imports/exports
     pub fn transform_program_on_exit(
         &self,
@@ -214,6 +456,7 @@ impl<'a> TypeScriptAnnotations<'a> {
     }
 
     pub fn transform_class(&mut self, class: &mut Class<'a>) {
+        self.transform_parameter_properties(class);
         class.type_parameters = None;
         class.super_type_parameters = None;
         class.implements = None;
@@ -246,6 +489,8 @@ impl<'a> TypeScriptAnnotations<'a> {
 
     pub fn transform_formal_parameter(&mut self, param: &mut FormalParameter<'a>) {
         param.accessibility = None;
+        param.readonly = false;
+        param.r#override = false;
     }
 
     pub fn transform_function(&mut self, func: &mut Function<'a>) {
@@ -259,48 +504,11 @@ impl<'a> TypeScriptAnnotations<'a> {
     }
 
     pub fn transform_method_definition(&mut self, def: &mut MethodDefinition<'a>) {
-        // Collects parameter properties so that we can add an assignment
-        // for each of them in the constructor body.
-        if def.kind == MethodDefinitionKind::Constructor {
-            for param in &def.value.params.items {
-                if !param.is_public() {
-                    continue;
-                }
-
-                if let Some(id) = param.pattern.get_identifier() {
-                    let assignment = self.create_this_property_assignment(id);
-                    self.assignments.push(assignment);
-                }
-            }
-        }
-
         def.accessibility = None;
         def.optional = false;
         def.r#override = false;
     }
 
-    pub fn transform_method_definition_on_exit(&mut self, def: &mut MethodDefinition<'a>) {
-        if def.kind == MethodDefinitionKind::Constructor && !self.assignments.is_empty() {
-            // When the constructor doesn't have a super call,
-            // we simply add assignments to the bottom of the function body
-            if self.has_super_call {
-                self.assignments.clear();
-            } else {
-                def.value
-                    .body
-                    .get_or_insert_with(|| {
-                        self.ctx.ast.function_body(
-                            SPAN,
-                            self.ctx.ast.new_vec(),
-                            self.ctx.ast.new_vec(),
-                        )
-                    })
-                    .statements
-                    .extend(self.assignments.drain(..));
-            }
-        }
-    }
-
     pub fn transform_new_expression(&mut self, expr: &mut NewExpression<'a>) {
         expr.type_parameters = None;
     }
@@ -334,52 +542,6 @@ impl<'a> TypeScriptAnnotations<'a> {
             // Ignore ModuleDeclaration as it's handled in the program
             _ => true,
         });
-
-        // Add assignments after super calls
-        if !self.assignments.is_empty() {
-            let mut super_indexes = vec![];
-            for (index, stmt) in stmts.iter().rev().enumerate() {
-                if matches!(stmt, Statement::ExpressionStatement(stmt) if stmt.expression.is_super_call_expression())
-                {
-                    super_indexes.push(index);
-                }
-            }
-            if !super_indexes.is_empty() {
-                self.has_super_call = true;
-                for index in super_indexes.iter().rev() {
-                    stmts.splice((index + 1)..=*index, self.ctx.ast.copy(&self.assignments));
-                }
-            }
-        }
-    }
-
-    /// Transform if statement's consequent and alternate to block statements if they are super calls
-    /// ```ts
-    /// if (true) super() else super();
-    /// // to
-    /// if (true) { super() } else { super() }
-    /// ```
-    pub fn transform_if_statement(&mut self, stmt: &mut IfStatement<'a>) {
-        if !self.assignments.is_empty() {
-            if matches!(&stmt.consequent, Statement::ExpressionStatement(expr) if expr.expression.is_super_call_expression())
-            {
-                stmt.consequent =
-                    self.ctx.ast.block_statement(self.ctx.ast.block(
-                        SPAN,
-                        self.ctx.ast.new_vec_single(self.ctx.ast.copy(&stmt.consequent)),
-                    ));
-            }
-            if let Some(alternate) = &stmt.alternate {
-                if matches!(alternate, Statement::ExpressionStatement(expr) if expr.expression.is_super_call_expression())
-                {
-                    stmt.alternate =
-                        Some(self.ctx.ast.block_statement(self.ctx.ast.block(
-                            SPAN,
-                            self.ctx.ast.new_vec_single(self.ctx.ast.copy(alternate)),
-                        )));
-                }
-            }
-        }
     }
 
     pub fn transform_tagged_template_expression(
@@ -397,3 +559,7 @@ impl<'a> TypeScriptAnnotations<'a> {
         self.has_jsx_fragment = true;
     }
 }
+
+fn is_super_call_statement(stmt: &Statement) -> bool {
+    matches!(stmt, Statement::ExpressionStatement(stmt) if stmt.expression.is_super_call_expression())
+}
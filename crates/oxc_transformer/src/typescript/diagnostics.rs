@@ -15,3 +15,19 @@ pub fn ambient_module_nested(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Ambient modules cannot be nested in other modules or namespaces.")
         .with_label(span0)
 }
+
+pub fn non_erasable_syntax(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("{x0} is not erasable TypeScript syntax."))
+        .with_help("Rewrite it as JavaScript with type annotations, or compile the file with the full TypeScript transform, as stripping its types changes its behavior.")
+        .with_label(span1)
+}
+
+pub fn parameter_property_outside_implementation(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("A parameter property is only allowed in a constructor implementation.")
+        .with_label(span0)
+}
+
+pub fn parameter_property_binding_pattern(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("A parameter property may not be declared using a binding pattern.")
+        .with_label(span0)
+}
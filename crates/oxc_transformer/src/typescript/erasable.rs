@@ -0,0 +1,126 @@
+//! Erasable Syntax Only
+//!
+//! Checks whether a file only uses TypeScript syntax which can be stripped by replacing it with
+//! whitespace, such as type annotations, interfaces and `declare` declarations, the same
+//! constraints as Node.js's `--experimental-strip-types`.
+//!
+//! Reported are all syntax with runtime semantics:
+//!
+//! * `enum` and `const enum` declarations
+//! * namespaces which are instantiated, i.e. contain values
+//! * parameter properties, `constructor(private x) {}`
+//! * parameter decorators, which only exist as legacy `experimentalDecorators`
+//! * `import x = y` of a value and `export = x`
+//!
+//! Nothing inside a `declare` namespace or class is reported.
+
+use oxc_ast::{
+    ast::*,
+    visit::walk::{
+        walk_class, walk_formal_parameter, walk_module_declaration, walk_ts_module_declaration,
+    },
+    Visit,
+};
+use oxc_span::GetSpan;
+
+use crate::context::Ctx;
+
+use super::diagnostics::non_erasable_syntax;
+
+pub struct TypeScriptErasableSyntax<'a> {
+    ctx: Ctx<'a>,
+    in_namespace: bool,
+}
+
+impl<'a> TypeScriptErasableSyntax<'a> {
+    pub fn new(ctx: &Ctx<'a>) -> Self {
+        Self { ctx: Ctx::clone(ctx), in_namespace: false }
+    }
+
+    pub fn check(mut self, program: &Program<'a>) {
+        self.visit_program(program);
+    }
+}
+
+impl<'a> Visit<'a> for TypeScriptErasableSyntax<'a> {
+    fn visit_enum(&mut self, decl: &TSEnumDeclaration<'a>) {
+        if !decl.modifiers.is_contains_declare() {
+            self.ctx.error(non_erasable_syntax("An enum declaration", decl.id.span));
+        }
+    }
+
+    fn visit_ts_module_declaration(&mut self, decl: &TSModuleDeclaration<'a>) {
+        if !is_instantiated(decl) {
+            return;
+        }
+        // Nested namespaces are reported once, with the outermost one.
+        if !self.in_namespace {
+            self.ctx.error(non_erasable_syntax("A namespace with values", decl.id.span()));
+        }
+        let in_namespace = std::mem::replace(&mut self.in_namespace, true);
+        walk_ts_module_declaration(self, decl);
+        self.in_namespace = in_namespace;
+    }
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        if !class.is_declare() {
+            walk_class(self, class);
+        }
+    }
+
+    fn visit_formal_parameter(&mut self, param: &FormalParameter<'a>) {
+        if param.accessibility.is_some() || param.readonly || param.r#override {
+            self.ctx.error(non_erasable_syntax("A parameter property", param.span));
+        }
+        for decorator in &param.decorators {
+            self.ctx.error(non_erasable_syntax("A parameter decorator", decorator.span));
+        }
+        walk_formal_parameter(self, param);
+    }
+
+    fn visit_ts_import_equals_declaration(&mut self, decl: &TSImportEqualsDeclaration<'a>) {
+        if decl.import_kind.is_value() {
+            self.ctx.error(non_erasable_syntax("An `import =` declaration", decl.span));
+        }
+    }
+
+    fn visit_module_declaration(&mut self, decl: &ModuleDeclaration<'a>) {
+        if let ModuleDeclaration::TSExportAssignment(assignment) = decl {
+            self.ctx.error(non_erasable_syntax("An `export =` assignment", assignment.span));
+        }
+        walk_module_declaration(self, decl);
+    }
+}
+
+/// Whether the namespace `decl` contains values, and is therefore compiled to code.
+fn is_instantiated(decl: &TSModuleDeclaration) -> bool {
+    if decl.modifiers.is_contains_declare() || decl.id.is_string_literal() {
+        return false;
+    }
+    match &decl.body {
+        Some(TSModuleDeclarationBody::TSModuleDeclaration(decl)) => is_instantiated(decl),
+        Some(TSModuleDeclarationBody::TSModuleBlock(block)) => {
+            block.body.iter().any(is_instantiated_statement)
+        }
+        None => false,
+    }
+}
+
+fn is_instantiated_statement(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::EmptyStatement(_) => false,
+        Statement::ExportNamedDeclaration(decl) => {
+            decl.declaration.as_ref().map_or(true, is_instantiated_declaration)
+        }
+        _ => stmt.as_declaration().map_or(true, is_instantiated_declaration),
+    }
+}
+
+fn is_instantiated_declaration(decl: &Declaration) -> bool {
+    match decl {
+        Declaration::TSModuleDeclaration(decl) => is_instantiated(decl),
+        Declaration::TSEnumDeclaration(decl) => !decl.modifiers.is_contains_declare(),
+        Declaration::TSImportEqualsDeclaration(decl) => decl.import_kind.is_value(),
+        _ => !decl.is_typescript_syntax(),
+    }
+}
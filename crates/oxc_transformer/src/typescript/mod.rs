@@ -2,6 +2,7 @@ mod annotations;
 mod collector;
 mod diagnostics;
 mod r#enum;
+mod erasable;
 mod module;
 mod namespace;
 mod options;
@@ -16,7 +17,7 @@ use crate::context::Ctx;
 
 use self::{
     annotations::TypeScriptAnnotations, collector::TypeScriptReferenceCollector,
-    r#enum::TypeScriptEnum,
+    erasable::TypeScriptErasableSyntax, r#enum::TypeScriptEnum,
 };
 
 pub use self::options::TypeScriptOptions;
@@ -69,6 +70,9 @@ impl<'a> TypeScript<'a> {
 // Transforms
 impl<'a> TypeScript<'a> {
     pub fn transform_program(&self, program: &mut Program<'a>, ctx: &mut TraverseCtx) {
+        if self.options.erasable_syntax_only && !self.ctx.source_type.is_typescript_definition() {
+            TypeScriptErasableSyntax::new(&self.ctx).check(program);
+        }
         self.transform_program_for_namespace(program, ctx);
     }
 
@@ -120,10 +124,6 @@ impl<'a> TypeScript<'a> {
         self.annotations.transform_method_definition(def);
     }
 
-    pub fn transform_method_definition_on_exit(&mut self, def: &mut MethodDefinition<'a>) {
-        self.annotations.transform_method_definition_on_exit(def);
-    }
-
     pub fn transform_new_expression(&mut self, expr: &mut NewExpression<'a>) {
         self.annotations.transform_new_expression(expr);
     }
@@ -145,19 +145,19 @@ impl<'a> TypeScript<'a> {
                     None
                 }
             }
-            match_module_declaration!(Statement) => {
-                if let ModuleDeclaration::ExportNamedDeclaration(decl) =
-                    stmt.to_module_declaration_mut()
-                {
+            match_module_declaration!(Statement) => match stmt.to_module_declaration_mut() {
+                ModuleDeclaration::ExportNamedDeclaration(decl) => {
                     if let Some(Declaration::TSEnumDeclaration(ts_enum_decl)) = &decl.declaration {
                         self.r#enum.transform_ts_enum(ts_enum_decl, true, ctx)
                     } else {
                         None
                     }
-                } else {
-                    None
                 }
-            }
+                ModuleDeclaration::TSExportAssignment(export_assignment) => {
+                    self.transform_ts_export_assignment(export_assignment)
+                }
+                _ => None,
+            },
             _ => None,
         };
 
@@ -166,10 +166,6 @@ impl<'a> TypeScript<'a> {
         }
     }
 
-    pub fn transform_if_statement(&mut self, stmt: &mut IfStatement<'a>) {
-        self.annotations.transform_if_statement(stmt);
-    }
-
     pub fn transform_tagged_template_expression(
         &mut self,
         expr: &mut TaggedTemplateExpression<'a>,
@@ -192,18 +188,14 @@ impl<'a> TypeScript<'a> {
             Declaration::TSImportEqualsDeclaration(ts_import_equals)
                 if ts_import_equals.import_kind.is_value() =>
             {
-                *decl = self.transform_ts_import_equals(ts_import_equals);
+                *decl = Declaration::VariableDeclaration(
+                    self.transform_ts_import_equals(ts_import_equals),
+                );
             }
             _ => {}
         }
     }
 
-    pub fn transform_module_declaration(&mut self, module_decl: &mut ModuleDeclaration<'a>) {
-        if let ModuleDeclaration::TSExportAssignment(ts_export_assignment) = &mut *module_decl {
-            self.transform_ts_export_assignment(ts_export_assignment);
-        }
-    }
-
     pub fn transform_jsx_element(&mut self, elem: &mut JSXElement<'a>) {
         self.annotations.transform_jsx_element(elem);
     }
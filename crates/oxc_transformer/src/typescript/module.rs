@@ -1,6 +1,7 @@
 use oxc_allocator::Box;
 use oxc_ast::ast::*;
 use oxc_span::SPAN;
+use oxc_syntax::operator::AssignmentOperator;
 
 use super::TypeScript;
 
@@ -33,7 +34,7 @@ impl<'a> TypeScript<'a> {
     pub fn transform_ts_import_equals(
         &self,
         decl: &mut Box<'a, TSImportEqualsDeclaration<'a>>,
-    ) -> Declaration<'a> {
+    ) -> Box<'a, VariableDeclaration<'a>> {
         let kind = VariableDeclarationKind::Var;
         let decls = {
             let binding_identifier = BindingIdentifier::new(SPAN, decl.id.name.clone());
@@ -69,19 +70,44 @@ impl<'a> TypeScript<'a> {
                 false,
             ))
         };
-        let variable_declaration =
-            self.ctx.ast.variable_declaration(SPAN, kind, decls, Modifiers::empty());
-
-        Declaration::VariableDeclaration(variable_declaration)
+        self.ctx.ast.variable_declaration(SPAN, kind, decls, Modifiers::empty())
     }
 
+    /// ```TypeScript
+    /// export = foo;
+    ///
+    /// ```JavaScript
+    /// module.exports = foo;
+    /// ```
+    ///
+    /// Only CommonJS can express `export =`, ES modules are reported and left untouched.
     pub fn transform_ts_export_assignment(
-        &mut self,
+        &self,
         export_assignment: &mut TSExportAssignment<'a>,
-    ) {
+    ) -> Option<Statement<'a>> {
         if self.ctx.source_type.is_module() {
             self.ctx
                 .error(super::diagnostics::export_assignment_unsupported(export_assignment.span));
+            return None;
         }
+
+        let left = {
+            let object = self
+                .ctx
+                .ast
+                .identifier_reference_expression(self.ctx.ast.identifier_reference(SPAN, "module"));
+            let property = self.ctx.ast.identifier_name(SPAN, "exports");
+            self.ctx.ast.simple_assignment_target_member_expression(
+                self.ctx.ast.static_member(SPAN, object, property, false),
+            )
+        };
+        let right = self.ctx.ast.move_expression(&mut export_assignment.expression);
+        let expr = self.ctx.ast.assignment_expression(
+            export_assignment.span,
+            AssignmentOperator::Assign,
+            left,
+            right,
+        );
+        Some(self.ctx.ast.expression_statement(export_assignment.span, expr))
     }
 }
@@ -29,6 +29,18 @@ impl<'a> TypeScript<'a> {
         // Collect all binding names. Such as function name and class name.
         let mut names: FxHashSet<Atom<'a>> = FxHashSet::default();
 
+        // Namespaces merged with a class, function or enum reuse the binding of that declaration,
+        // regardless of whether the declaration comes before or after the namespace.
+        let root_scope_id = ctx.scopes().root_scope_id();
+        for (name, symbol_id) in ctx.scopes().get_bindings(root_scope_id) {
+            let flags = ctx.symbols().get_flag(*symbol_id);
+            if flags.contains(SymbolFlags::NameSpaceModule)
+                && flags.intersects(SymbolFlags::Class | SymbolFlags::Function | SymbolFlags::Enum)
+            {
+                names.insert(self.ctx.ast.new_atom(name));
+            }
+        }
+
         // Recreate the statements vec for memory efficiency.
         // Inserting the `let` declaration multiple times will reallocate the whole statements vec
         // every time a namespace declaration is encountered.
@@ -196,6 +208,30 @@ impl<'a> TypeScript<'a> {
                     names.insert(enum_decl.id.name.clone());
                     new_stmts.push(Statement::TSEnumDeclaration(enum_decl));
                 }
+                // `import X = N.Y` -> `var X = N.Y`
+                Statement::TSImportEqualsDeclaration(mut import_decl) => {
+                    if import_decl.import_kind.is_type() {
+                        continue;
+                    }
+                    is_empty = false;
+                    names.insert(import_decl.id.name.clone());
+                    let var_decl = self.transform_ts_import_equals(&mut import_decl);
+                    new_stmts.push(Statement::VariableDeclaration(var_decl));
+                }
+                Statement::FunctionDeclaration(func_decl) if !func_decl.is_typescript_syntax() => {
+                    is_empty = false;
+                    if let Some(ident) = &func_decl.id {
+                        names.insert(ident.name.clone());
+                    }
+                    new_stmts.push(Statement::FunctionDeclaration(func_decl));
+                }
+                Statement::VariableDeclaration(var_decl) if !var_decl.is_typescript_syntax() => {
+                    is_empty = false;
+                    var_decl.bound_names(&mut |id| {
+                        names.insert(id.name.clone());
+                    });
+                    new_stmts.push(Statement::VariableDeclaration(var_decl));
+                }
                 Statement::ExportNamedDeclaration(export_decl) => {
                     let export_decl = export_decl.unbox();
                     if let Some(decl) = export_decl.declaration {
@@ -235,6 +271,16 @@ impl<'a> TypeScript<'a> {
                                 let stmts = self.handle_variable_declaration(var_decl, &name);
                                 new_stmts.extend(stmts);
                             }
+                            // `export import X = N.Y` -> `var X = _M.X = N.Y`
+                            Declaration::TSImportEqualsDeclaration(mut import_decl)
+                                if import_decl.import_kind.is_value() =>
+                            {
+                                is_empty = false;
+                                names.insert(import_decl.id.name.clone());
+                                let var_decl = self.transform_ts_import_equals(&mut import_decl);
+                                let stmts = self.handle_variable_declaration(var_decl, &name);
+                                new_stmts.extend(stmts);
+                            }
                             Declaration::TSModuleDeclaration(module_decl) => {
                                 if module_decl.id.is_string_literal() {
                                     self.ctx.error(ambient_module_nested(module_decl.span));
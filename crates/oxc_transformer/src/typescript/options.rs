@@ -42,6 +42,21 @@ pub struct TypeScriptOptions {
     // When enabled, type-only class fields are only removed if they are prefixed with the declare modifier:
     #[serde(default = "default_as_true")]
     pub allow_declare_fields: bool,
+
+    /// When enabled, report all syntax which can not be removed by stripping types, such as enums,
+    /// namespaces with values and parameter properties.
+    /// Use this to check that a file runs with type stripping only, e.g. Node.js's `--experimental-strip-types`.
+    /// Defaults to false.
+    pub erasable_syntax_only: bool,
+
+    /// When enabled, class fields are defined with `[[Define]]` semantics when the constructor
+    /// runs `super()`, or starts in a base class, so they are initialized before the parameter
+    /// properties are assigned.
+    /// When disabled, class fields are assigned in the constructor after the parameter properties,
+    /// like `tsc` with `useDefineForClassFields: false`.
+    /// Defaults to true.
+    #[serde(default = "default_as_true")]
+    pub use_define_for_class_fields: bool,
 }
 
 impl TypeScriptOptions {
@@ -89,6 +104,8 @@ impl Default for TypeScriptOptions {
             only_remove_type_imports: false,
             allow_namespaces: default_as_true(),
             allow_declare_fields: default_as_true(),
+            erasable_syntax_only: false,
+            use_define_for_class_fields: default_as_true(),
         }
     }
 }
@@ -1,9 +1,14 @@
 use std::{env, process::Command};
 
 fn main() {
-    // Re-run if NodeJS build script or AST types change
+    // Re-run if NodeJS build script, AST types or the version of the AST change
     println!("cargo:rerun-if-changed=scripts");
     println!("cargo:rerun-if-changed=../oxc_ast/src/ast");
+    println!("cargo:rerun-if-changed=../oxc_ast/Cargo.toml");
+    // Serialized types outside of `oxc_ast`, for `estree_schema.json`
+    println!("cargo:rerun-if-changed=../oxc_span/src/span.rs");
+    println!("cargo:rerun-if-changed=../oxc_span/src/source_type.rs");
+    println!("cargo:rerun-if-changed=../oxc_syntax/src/operator.rs");
 
     // Exit if on CI.
     // The built files should be checked into git, so want to run tests etc on what's actually in repo,
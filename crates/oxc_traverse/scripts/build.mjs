@@ -5,12 +5,15 @@
  * - `src/traverse.rs`
  * - `src/ancestor.rs`
  * - `src/walk.rs`
+ * - `../oxc_ast/ast_schema.json`
+ * - `../oxc_ast/estree_schema.json`, from the serialized types in `oxc_ast`, `oxc_span` and
+ *   `oxc_syntax`
  *
  * This is a quick-and-dirty version written in JS for speed of implementation.
  * We should do this properly with a Rust build script using `syn` etc.
  */
 
-import {writeFile} from 'fs/promises';
+import {readFile, writeFile} from 'fs/promises';
 import {exec} from 'child_process';
 import {join as pathJoin} from 'path';
 import {fileURLToPath} from 'url';
@@ -19,6 +22,8 @@ import getTypesFromCode from './lib/parse.mjs';
 import generateTraverseTraitCode from './lib/traverse.mjs';
 import generateAncestorsCode from './lib/ancestor.mjs';
 import generateWalkFunctionsCode from './lib/walk.mjs';
+import {generateSchema} from './lib/schema.mjs';
+import {generateEstreeSchema, getSerializedTypesFromCode} from './lib/estree.mjs';
 
 const execAsync = promisify(exec);
 
@@ -31,6 +36,20 @@ await writeToFile('traverse.rs', generateTraverseTraitCode(types));
 await writeToFile('ancestor.rs', generateAncestorsCode(types));
 await writeToFile('walk.rs', generateWalkFunctionsCode(types));
 
+const astDirPath = pathJoin(fileURLToPath(import.meta.url), '../../../oxc_ast');
+const astVersion = (await readFile(pathJoin(astDirPath, 'Cargo.toml'), 'utf8'))
+    .match(/^version += "(.+)"$/m)[1];
+const schemaPath = pathJoin(astDirPath, 'ast_schema.json');
+console.log('Writing:', schemaPath);
+await writeFile(schemaPath, generateSchema(types, astVersion));
+
+const estreeSchemaPath = pathJoin(astDirPath, 'estree_schema.json');
+console.log('Writing:', estreeSchemaPath);
+await writeFile(
+    estreeSchemaPath,
+    generateEstreeSchema(await getSerializedTypesFromCode(), astVersion)
+);
+
 async function writeToFile(filename, code) {
     code = `${PREAMBLE}${code}`;
     const path = pathJoin(outputDirPath, filename);
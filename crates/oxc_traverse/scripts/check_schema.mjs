@@ -0,0 +1,35 @@
+/*
+ * Check the AST schema against the schema of an earlier release.
+ *
+ * Usage: `node ./scripts/check_schema.mjs <path to the ast_schema.json of the earlier release>`
+ *
+ * Lists the changes to the AST since then, and fails when there are breaking changes
+ * but the version of `oxc_ast` is still semver compatible with the earlier release.
+ */
+
+import {readFile} from 'fs/promises';
+import {join as pathJoin} from 'path';
+import {fileURLToPath} from 'url';
+import {diffSchemas, isSemverCompatible} from './lib/schema.mjs';
+
+const [oldPath] = process.argv.slice(2);
+if (!oldPath) {
+    console.error('Usage: node ./scripts/check_schema.mjs <path to ast_schema.json>');
+    process.exit(1);
+}
+
+const schemaPath = pathJoin(fileURLToPath(import.meta.url), '../../../oxc_ast/ast_schema.json');
+const old = JSON.parse(await readFile(oldPath, 'utf8')),
+    current = JSON.parse(await readFile(schemaPath, 'utf8'));
+
+const {breaking, compatible} = diffSchemas(old, current);
+for (const change of compatible) console.log(`${change}`);
+for (const change of breaking) console.log(`Breaking: ${change}`);
+
+if (breaking.length > 0 && isSemverCompatible(old.version, current.version)) {
+    console.error(
+        `\n${breaking.length} breaking change(s) to the AST since ${old.version}, `
+        + `but oxc_ast ${current.version} is semver compatible with it.`
+    );
+    process.exit(1);
+}
@@ -0,0 +1,442 @@
+/*
+ * ESTree schema.
+ *
+ * Describes the JSON which `Program::to_json` produces, rather than the Rust types of the AST
+ * which `ast_schema.json` describes. It is derived from the `serde` and `tsify` attributes of
+ * the AST types, and from the serializers in `crates/oxc_ast/src/serialize.rs`, whose output is
+ * declared on the types with `tsify(type = "...")` and in the `TS_APPEND_CONTENT` sections.
+ *
+ * It is written to `crates/oxc_ast/estree_schema.json`, so tools in other languages can generate
+ * typed bindings for the JSON AST:
+ *
+ * - `nodes`: objects with a `type` field, keyed by the name of their Rust type. `type` is a
+ *   string, or an array of strings for nodes like `Function` whose `type` depends on the node.
+ *   Several types may serialize to the same `type`, e.g. all identifiers are `Identifier`.
+ *   `flatten` lists unions whose fields are merged into the node.
+ * - `objects`: objects without a `type` field.
+ * - `unions`: one of several nodes, objects or other unions.
+ * - `enums`: one of several strings.
+ * - `aliases`: any other type.
+ *
+ * Every node has `start` and `end` fields, the UTF-8 byte offsets of the node in the source text.
+ * Types are `{kind: 'string' | 'number' | 'boolean' | 'null'}`, `{kind: 'ref', name}`,
+ * `{kind: 'array', element}`, `{kind: 'nullable', type}` and `{kind: 'union', types}`.
+ */
+
+import {readFile} from 'fs/promises';
+import {join as pathJoin} from 'path';
+import {fileURLToPath} from 'url';
+import assert from 'assert';
+
+const FILES = [
+    ['oxc_ast/src/ast', ['js.rs', 'jsx.rs', 'literal.rs', 'ts.rs']],
+    ['oxc_span/src', ['span.rs', 'source_type.rs']],
+    ['oxc_syntax/src', ['operator.rs']],
+];
+
+const STRING = {kind: 'string'},
+    NUMBER = {kind: 'number'},
+    BOOLEAN = {kind: 'boolean'},
+    NULL = {kind: 'null'};
+
+const PRIMITIVES = {
+    Atom: STRING,
+    CompactStr: STRING,
+    String: STRING,
+    str: STRING,
+    bool: BOOLEAN,
+    boolean: BOOLEAN,
+    string: STRING,
+    number: NUMBER,
+    f64: NUMBER,
+    u32: NUMBER,
+    u8: NUMBER,
+    ScopeId: NUMBER,
+    SymbolId: NUMBER,
+    ReferenceId: NUMBER,
+};
+
+/** Types with a hand-written `Serialize` impl, other than those declared with `tsify`. */
+const CUSTOM_SERIALIZERS = {
+    // `Elision` is `null` in `ArrayExpression::elements`.
+    Elision: NULL,
+    // `RegExpFlags` is the flags as written after the pattern, e.g. `"gi"`.
+    RegExpFlags: STRING,
+};
+
+/**
+ * Parse the serialized types from Rust files.
+ */
+export async function getSerializedTypesFromCode() {
+    const cratesDirPath = pathJoin(fileURLToPath(import.meta.url), '../../../../');
+
+    const types = Object.create(null),
+        interfaces = Object.create(null);
+    for (const [dir, filenames] of FILES) {
+        for (const filename of filenames) {
+            const code = await readFile(pathJoin(cratesDirPath, dir, filename), 'utf8');
+            parseFile(code, filename, types);
+            parseAppendedInterfaces(code, interfaces);
+        }
+    }
+    // Interfaces of the serializers in `serialize.rs`, which have no Rust type of their own.
+    for (const [name, type] of Object.entries(interfaces)) {
+        if (!types[name]) types[name] = type;
+    }
+    return types;
+}
+
+function parseFile(code, filename, types) {
+    const lines = code.split(/\r?\n/).map(
+        line => line.replace(/\s+/g, ' ').replace(/ ?\/\/.*$/, '').trim()
+    );
+    let attrs = [];
+    for (let lineIndex = 0; lineIndex < lines.length; lineIndex++) {
+        const line = lines[lineIndex];
+        if (line.startsWith('#[')) {
+            let attr;
+            [attr, lineIndex] = readAttr(lines, lineIndex);
+            attrs.push(attr);
+            continue;
+        }
+        const match = line.match(/^pub (enum|struct) ([A-Za-z]+)(?:<'a>)?( \{|\(|;)/);
+        if (!match || !attrs.some(isSerializedDerive)) {
+            if (line !== '') attrs = [];
+            continue;
+        }
+
+        const [, kind, name, opening] = match,
+            serde = serdeArgs(attrs),
+            startLineIndex = lineIndex;
+        attrs = [];
+
+        // A unit struct is serialized as `null`.
+        if (opening === ';') {
+            types[name] = {kind: 'alias', name, type: NULL};
+            continue;
+        }
+        if (opening === '(') {
+            const tupleMatch = line.match(/^pub struct [A-Za-z]+(?:<'a>)?\((.+)\);$/);
+            assert(tupleMatch && serde.transparent, `Cannot parse tuple struct '${name}' in '${filename}'`);
+            types[name] = {kind: 'alias', name, type: rustTypeRef(tupleMatch[1])};
+            continue;
+        }
+
+        const itemLines = [];
+        while (true) {
+            const line = lines[++lineIndex];
+            if (line === '}') break;
+            if (line !== '') itemLines.push(line);
+        }
+
+        types[name] = kind === 'struct'
+            ? parseStruct(name, serde, itemLines, filename, startLineIndex)
+            : parseEnum(name, serde, itemLines, filename, startLineIndex);
+    }
+}
+
+function parseStruct(name, serde, lines, filename, startLineIndex) {
+    const fields = [];
+    let attrs = [];
+    for (let i = 0; i < lines.length; i++) {
+        if (lines[i].startsWith('#[')) {
+            let attr;
+            [attr, i] = readAttr(lines, i);
+            attrs.push(attr);
+            continue;
+        }
+
+        const match = lines[i].match(/^(?:pub )?(?:r#)?([a-z_]+): (.+),$/);
+        assert(
+            match,
+            `Cannot parse line ${startLineIndex + i} in '${filename}' as struct field: '${lines[i]}'`
+        );
+        const [, rustName, rustType] = match,
+            fieldSerde = serdeArgs(attrs),
+            tsType = tsifyType(attrs);
+        attrs = [];
+        if (fieldSerde.skip) continue;
+
+        fields.push({
+            name: fieldSerde.rename ?? renameField(rustName, serde.rename_all),
+            type: tsType ? tsTypeRef(tsType) : rustTypeRef(rustType),
+            flatten: !!fieldSerde.flatten,
+        });
+    }
+    return {kind: 'struct', name, tag: serde.tag ? serde.rename ?? name : null, fields};
+}
+
+function parseEnum(name, serde, lines, filename, startLineIndex) {
+    const variants = [],
+        inherits = [];
+    let attrs = [];
+    for (let i = 0; i < lines.length; i++) {
+        if (lines[i].startsWith('#[')) {
+            let attr;
+            [attr, i] = readAttr(lines, i);
+            attrs.push(attr);
+            continue;
+        }
+
+        const inheritMatch = lines[i].match(/^@inherit ([A-Za-z]+)$/);
+        if (inheritMatch) {
+            inherits.push(inheritMatch[1]);
+            continue;
+        }
+
+        const match = lines[i].match(/^([A-Za-z0-9]+)(?:\((.+?)\))?(?: ?= ?\d+)?,?$/);
+        assert(
+            match,
+            `Cannot parse line ${startLineIndex + i} in '${filename}' as enum variant: '${lines[i]}'`
+        );
+        const [, variantName, rustType] = match,
+            variantSerde = serdeArgs(attrs);
+        attrs = [];
+        variants.push({
+            name: variantSerde.rename ?? renameVariant(variantName, serde.rename_all),
+            type: rustType ? rustTypeRef(rustType) : null,
+        });
+    }
+
+    if (variants.every(variant => !variant.type) && inherits.length === 0) {
+        return {kind: 'enum', name, values: variants.map(variant => variant.name)};
+    }
+    assert(
+        serde.untagged && variants.every(variant => variant.type),
+        `Enum '${name}' in '${filename}' is neither untagged nor fieldless`
+    );
+    const types = [
+        ...variants.map(variant => variant.type),
+        ...inherits.map(inherit => ({kind: 'ref', name: inherit})),
+    ];
+    return {kind: 'union', name, types};
+}
+
+/**
+ * Parse the `export interface Name extends Span { ... }` declarations of the hand-written
+ * serializers in the `TS_APPEND_CONTENT` sections.
+ */
+function parseAppendedInterfaces(code, interfaces) {
+    for (const [, body] of code.matchAll(/const TS_APPEND_CONTENT: &'static str = r#"([^]*?)"#;/g)) {
+        for (const [, name, members] of body.matchAll(/export interface (\w+) extends Span \{([^}]*)\}/g)) {
+            let tag = null;
+            const fields = [{name: 'span', type: {kind: 'ref', name: 'Span'}, flatten: true}];
+            for (const member of members.split(/[,\n]/).map(member => member.trim())) {
+                if (member === '') continue;
+                const match = member.match(/^(\w+)(\??): (.+)$/);
+                assert(match, `Cannot parse member of interface '${name}': '${member}'`);
+                const [, fieldName, optional, tsType] = match;
+                const tagMatch = fieldName === 'type' && tsType.match(/^"(.+)"$/);
+                if (tagMatch) {
+                    tag = tagMatch[1];
+                } else {
+                    // Optional members are `Option`s, which are serialized as `null`.
+                    const type = optional ? nullable(tsTypeRef(tsType)) : tsTypeRef(tsType);
+                    fields.push({name: fieldName, type, flatten: false});
+                }
+            }
+            interfaces[name] = {kind: 'struct', name, tag, fields};
+        }
+    }
+}
+
+function readAttr(lines, lineIndex) {
+    let attr = lines[lineIndex];
+    while (!isBalanced(attr)) attr += ` ${lines[++lineIndex]}`;
+    attr = attr.replace(/\( /g, '(').replace(/,? \)/g, ')');
+    return [attr, lineIndex];
+}
+
+function isBalanced(attr) {
+    let depth = 0;
+    for (const char of attr) {
+        if (char === '[' || char === '(') depth++;
+        if (char === ']' || char === ')') depth--;
+    }
+    return depth === 0;
+}
+
+function isSerializedDerive(attr) {
+    return /^#\[cfg_attr\(feature = "serialize", derive\(.*\b(Serialize|Tsify)\b.*\)\)\]$/.test(attr);
+}
+
+function serdeArgs(attrs) {
+    const args = {};
+    for (const attr of attrs) {
+        const match = attr.match(/^#\[cfg_attr\(feature = "serialize", serde\((.+)\)\)\]$/);
+        if (!match) continue;
+        for (const [, key, value] of match[1].matchAll(/(\w+)(?: = "([^"]*)")?/g)) {
+            args[key] = value ?? true;
+        }
+    }
+    return args;
+}
+
+function tsifyType(attrs) {
+    for (const attr of attrs) {
+        const match = attr.match(/^#\[cfg_attr\(feature = "serialize", tsify\(type = "(.+)"\)\)\]$/);
+        if (match) return match[1];
+    }
+    return null;
+}
+
+function renameField(name, renameAll) {
+    assert(!renameAll || renameAll === 'camelCase', `Unexpected rename_all: ${renameAll}`);
+    return renameAll ? name.replace(/_([a-z])/g, (_, c) => c.toUpperCase()) : name;
+}
+
+function renameVariant(name, renameAll) {
+    switch (renameAll) {
+        case undefined:
+            return name;
+        case 'lowercase':
+            return name.toLowerCase();
+        case 'camelCase':
+            return name[0].toLowerCase() + name.slice(1);
+        default:
+            throw new Error(`Unexpected rename_all: ${renameAll}`);
+    }
+}
+
+/** The serialized type of a Rust type, e.g. `Vec<'a, Option<Expression<'a>>>`. */
+function rustTypeRef(rustType) {
+    rustType = rustType.replace(/&'a /g, '').replace(/<'a>/g, '').replace(/<'a, ?/g, '<');
+    const match = rustType.match(/^([A-Za-z]+)<(.+)>$/);
+    if (!match) return namedRef(rustType);
+    const [, wrapper, inner] = match;
+    switch (wrapper) {
+        case 'Box':
+        case 'Cell':
+            return rustTypeRef(inner);
+        case 'Vec':
+            return {kind: 'array', element: rustTypeRef(inner)};
+        case 'Option':
+            return nullable(rustTypeRef(inner));
+        default:
+            throw new Error(`Unexpected type: ${rustType}`);
+    }
+}
+
+/** The serialized type of a TypeScript type, e.g. `Array<Expression | null>`. */
+function tsTypeRef(tsType) {
+    tsType = tsType.trim();
+    const members = splitUnion(tsType);
+    if (members.length > 1) {
+        const types = members.filter(member => member !== 'null').map(tsTypeRef),
+            type = types.length === 1 ? types[0] : {kind: 'union', types};
+        return types.length < members.length ? nullable(type) : type;
+    }
+    if (tsType.startsWith('(') && tsType.endsWith(')')) return tsTypeRef(tsType.slice(1, -1));
+    const match = tsType.match(/^Array<(.+)>$/);
+    if (match) return {kind: 'array', element: tsTypeRef(match[1])};
+    return tsType === 'null' ? NULL : namedRef(tsType);
+}
+
+function splitUnion(tsType) {
+    const members = [];
+    let depth = 0,
+        start = 0;
+    for (let i = 0; i < tsType.length; i++) {
+        const char = tsType[i];
+        if (char === '<' || char === '(') depth++;
+        if (char === '>' || char === ')') depth--;
+        if (char === '|' && depth === 0) {
+            members.push(tsType.slice(start, i).trim());
+            start = i + 1;
+        }
+    }
+    members.push(tsType.slice(start).trim());
+    return members;
+}
+
+function namedRef(name) {
+    return PRIMITIVES[name] ?? {kind: 'ref', name};
+}
+
+function nullable(type) {
+    return type.kind === 'nullable' ? type : {kind: 'nullable', type};
+}
+
+export function generateEstreeSchema(types, version) {
+    const schema = {version, nodes: {}, objects: {}, unions: {}, enums: {}, aliases: {}};
+    for (const [name, type] of Object.entries(CUSTOM_SERIALIZERS)) {
+        schema.aliases[name] = type;
+    }
+
+    for (const type of Object.values(types)) {
+        if (CUSTOM_SERIALIZERS[type.name]) continue;
+        switch (type.kind) {
+            case 'struct': {
+                let {fields, flatten} = expandFields(type, types),
+                    tag = type.tag;
+                // A `type` field of an enum, e.g. `Function::type`, is the tag of the node.
+                const typeField = fields.find(field => field.name === 'type'),
+                    typeEnum = typeField?.type.kind === 'ref' && types[typeField.type.name];
+                if (!tag && typeEnum?.kind === 'enum') {
+                    tag = typeEnum.values;
+                    fields = fields.filter(field => field !== typeField);
+                }
+                const entry = tag ? {type: tag, fields} : {fields};
+                if (flatten.length > 0) entry.flatten = flatten;
+                (tag ? schema.nodes : schema.objects)[type.name] = entry;
+                break;
+            }
+            case 'union':
+                schema.unions[type.name] = type.types;
+                break;
+            case 'enum':
+                schema.enums[type.name] = type.values;
+                break;
+            case 'alias':
+                schema.aliases[type.name] = type.type;
+                break;
+        }
+    }
+
+    const names = new Set(Object.values(schema).flatMap(section => Object.keys(section)));
+    const checkRefs = (type, owner) => {
+        if (type.kind === 'ref') {
+            assert(names.has(type.name), `Unknown type '${type.name}' in '${owner}'`);
+        } else if (type.kind === 'array') {
+            checkRefs(type.element, owner);
+        } else if (type.kind === 'nullable') {
+            checkRefs(type.type, owner);
+        } else if (type.kind === 'union') {
+            for (const member of type.types) checkRefs(member, owner);
+        }
+    };
+    for (const [name, entry] of [...Object.entries(schema.nodes), ...Object.entries(schema.objects)]) {
+        for (const field of entry.fields) checkRefs(field.type, name);
+        for (const type of entry.flatten ?? []) checkRefs(type, name);
+    }
+    for (const [name, members] of Object.entries(schema.unions)) {
+        for (const member of members) checkRefs(member, name);
+    }
+    for (const [name, type] of Object.entries(schema.aliases)) checkRefs(type, name);
+
+    return `${JSON.stringify(schema, null, 2)}\n`;
+}
+
+/**
+ * The fields of a struct, with the fields of flattened structs merged into it.
+ * Flattened unions can only be resolved with the JSON, so are returned separately.
+ */
+function expandFields(type, types) {
+    const fields = [],
+        flatten = [];
+    for (const field of type.fields) {
+        if (!field.flatten) {
+            fields.push({name: field.name, type: field.type});
+            continue;
+        }
+        const flattened = field.type.kind === 'ref' ? types[field.type.name] : null;
+        if (flattened?.kind === 'struct' && !flattened.tag) {
+            const expanded = expandFields(flattened, types);
+            fields.push(...expanded.fields);
+            flatten.push(...expanded.flatten);
+        } else {
+            flatten.push(field.type);
+        }
+    }
+    return {fields, flatten};
+}
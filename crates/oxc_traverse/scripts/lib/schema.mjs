@@ -0,0 +1,99 @@
+/*
+ * AST schema.
+ *
+ * Describes the fields of every visited AST struct and the variants of every visited AST enum,
+ * with the version of `oxc_ast` they belong to. It is written to `crates/oxc_ast/ast_schema.json`,
+ * so crates outside this repo can generate code for the AST without parsing its Rust source,
+ * and changes to the AST can be checked against the version of `oxc_ast`.
+ */
+
+export function generateSchema(types, version) {
+    const schema = {version, types: {}};
+    for (const type of Object.values(types)) {
+        if (type.kind === 'struct') {
+            schema.types[type.name] = {
+                kind: 'struct',
+                fields: type.fields.map(({name, typeName}) => ({name, type: typeName})),
+                scope: type.scopeArgs?.scope ?? null,
+            };
+        } else {
+            schema.types[type.name] = {
+                kind: 'enum',
+                variants: type.variants.map(({name, typeName, discriminant}) => ({
+                    name,
+                    type: typeName,
+                    discriminant,
+                })),
+                inherits: type.inherits,
+            };
+        }
+    }
+    return `${JSON.stringify(schema, null, 2)}\n`;
+}
+
+/**
+ * Changes from the `old` schema to the `current` one.
+ *
+ * Adding a type is compatible, the visitors get a method for it with a default implementation.
+ * Every other change is breaking: structs are built and destructured with all their fields,
+ * and enums are matched exhaustively.
+ */
+export function diffSchemas(old, current) {
+    const breaking = [],
+        compatible = [];
+    for (const [name, oldType] of Object.entries(old.types)) {
+        const type = current.types[name];
+        if (!type) {
+            breaking.push(`Removed \`${name}\``);
+        } else if (type.kind !== oldType.kind) {
+            breaking.push(`Changed \`${name}\` from ${oldType.kind} to ${type.kind}`);
+        } else if (type.kind === 'struct') {
+            diffMembers(name, 'field', oldType.fields, type.fields, breaking);
+        } else {
+            diffMembers(name, 'variant', oldType.variants, type.variants, breaking);
+            for (const inherit of oldType.inherits) {
+                if (!type.inherits.includes(inherit)) {
+                    breaking.push(`Removed variants of \`${inherit}\` from \`${name}\``);
+                }
+            }
+            for (const inherit of type.inherits) {
+                if (!oldType.inherits.includes(inherit)) {
+                    breaking.push(`Added variants of \`${inherit}\` to \`${name}\``);
+                }
+            }
+        }
+    }
+    for (const name of Object.keys(current.types)) {
+        if (!old.types[name]) compatible.push(`Added \`${name}\``);
+    }
+    return {breaking, compatible};
+}
+
+function diffMembers(typeName, memberKind, oldMembers, members, breaking) {
+    for (const oldMember of oldMembers) {
+        const member = members.find(member => member.name === oldMember.name);
+        if (!member) {
+            breaking.push(`Removed ${memberKind} \`${typeName}::${oldMember.name}\``);
+        } else if (member.type !== oldMember.type) {
+            breaking.push(
+                `Changed the type of ${memberKind} \`${typeName}::${oldMember.name}\``
+                + ` from \`${oldMember.type}\` to \`${member.type}\``
+            );
+        }
+    }
+    for (const member of members) {
+        if (!oldMembers.some(oldMember => oldMember.name === member.name)) {
+            breaking.push(`Added ${memberKind} \`${typeName}::${member.name}\``);
+        }
+    }
+}
+
+/**
+ * Whether a crate at `version` can replace one at `oldVersion` under semver,
+ * where `0.x` versions are only compatible with the same minor version.
+ */
+export function isSemverCompatible(oldVersion, version) {
+    const [oldMajor, oldMinor] = oldVersion.split('.').map(Number),
+        [major, minor] = version.split('.').map(Number);
+    return oldMajor === major && (major !== 0 || oldMinor === minor);
+}
@@ -1,8 +1,12 @@
 use oxc_allocator::{Allocator, Box};
-use oxc_ast::AstBuilder;
+use oxc_ast::{
+    ast::{BindingIdentifier, Expression, IdentifierReference},
+    AstBuilder, CloneIn,
+};
 use oxc_semantic::{ScopeTree, SymbolTable};
-use oxc_span::CompactStr;
+use oxc_span::{CompactStr, SPAN};
 use oxc_syntax::{
+    reference::ReferenceFlag,
     scope::{ScopeFlags, ScopeId},
     symbol::SymbolFlags,
 };
@@ -12,7 +16,7 @@ use crate::ancestor::{Ancestor, AncestorType};
 mod ancestry;
 pub use ancestry::TraverseAncestry;
 mod scoping;
-pub use scoping::TraverseScoping;
+pub use scoping::{BoundIdentifier, Subtree, TraverseScoping};
 
 /// Traverse context.
 ///
@@ -23,6 +27,9 @@ pub use scoping::TraverseScoping;
 /// * Get scopes tree and symbols table via [`scopes`], [`symbols`], [`scopes_mut`], [`symbols_mut`],
 ///   [`find_scope`], [`find_scope_by_flags`].
 /// * Create AST nodes via AST builder [`ast`].
+/// * Add and remove identifiers while keeping the symbols table in sync via
+///   [`create_binding_in_nearest_var_scope`], [`create_reference`],
+///   [`replace_expression_keeping_refs`] and [`delete_subtree_updating_semantic`].
 /// * Allocate into arena via [`alloc`].
 ///
 /// # Namespaced APIs
@@ -99,6 +106,10 @@ pub use scoping::TraverseScoping;
 /// [`find_scope`]: `TraverseCtx::find_scope`
 /// [`find_scope_by_flags`]: `TraverseCtx::find_scope_by_flags`
 /// [`ast`]: `TraverseCtx::ast`
+/// [`create_binding_in_nearest_var_scope`]: `TraverseCtx::create_binding_in_nearest_var_scope`
+/// [`create_reference`]: `TraverseCtx::create_reference`
+/// [`replace_expression_keeping_refs`]: `TraverseCtx::replace_expression_keeping_refs`
+/// [`delete_subtree_updating_semantic`]: `TraverseCtx::delete_subtree_updating_semantic`
 /// [`alloc`]: `TraverseCtx::alloc`
 pub struct TraverseCtx<'a> {
     pub ancestry: TraverseAncestry<'a>,
@@ -292,10 +303,99 @@ impl<'a> TraverseCtx<'a> {
     pub fn generate_uid_in_current_scope(&mut self, name: &str, flags: SymbolFlags) -> CompactStr {
         self.scoping.generate_uid_in_current_scope(name, flags)
     }
+
+    /// Create a binding with a UID based on `name_hint` in the nearest scope which `var`s are
+    /// hoisted to.
+    ///
+    /// This is a shortcut for `ctx.scoping.create_binding_in_nearest_var_scope`.
+    pub fn create_binding_in_nearest_var_scope(
+        &mut self,
+        name_hint: &str,
+        flags: SymbolFlags,
+    ) -> BoundIdentifier {
+        self.scoping.create_binding_in_nearest_var_scope(name_hint, flags)
+    }
+
+    /// Create a `BindingIdentifier` which declares `binding`.
+    pub fn create_binding_identifier(&self, binding: &BoundIdentifier) -> BindingIdentifier<'a> {
+        let ident = BindingIdentifier::new(SPAN, self.ast.new_atom(&binding.name));
+        ident.symbol_id.set(Some(binding.symbol_id));
+        ident
+    }
+
+    /// Create an `IdentifierReference` to `binding`, and add the reference to the symbols table.
+    pub fn create_reference(
+        &mut self,
+        binding: &BoundIdentifier,
+        flag: ReferenceFlag,
+    ) -> IdentifierReference<'a> {
+        self.create_identifier_reference(&binding.name, Some(binding), flag)
+    }
+
+    /// Create an `IdentifierReference` to the global `name`, and add it to the unresolved
+    /// references.
+    pub fn create_unbound_reference(
+        &mut self,
+        name: &str,
+        flag: ReferenceFlag,
+    ) -> IdentifierReference<'a> {
+        self.create_identifier_reference(name, None, flag)
+    }
+
+    /// Delete the scopes, bindings and references of `node`, which is removed from the AST.
+    ///
+    /// This is a shortcut for `ctx.scoping.delete_subtree_updating_semantic`.
+    pub fn delete_subtree_updating_semantic<T: Subtree<'a>>(&mut self, node: &T) {
+        self.scoping.delete_subtree_updating_semantic(node);
+    }
+
+    /// Replace `expr` with `replacement`, and delete the scopes, bindings and references of `expr`
+    /// which are not moved into `replacement`.
+    ///
+    /// This is a shortcut for `ctx.scoping.replace_expression_keeping_refs`.
+    pub fn replace_expression_keeping_refs(
+        &mut self,
+        expr: &mut Expression<'a>,
+        replacement: Expression<'a>,
+    ) {
+        self.scoping.replace_expression_keeping_refs(expr, replacement);
+    }
+
+    /// Clone `node` into the arena, with new scopes, symbols and references for the clone.
+    ///
+    /// `scope_id` is the scope which contains `node`.
+    ///
+    /// This is a shortcut for `ctx.scoping.clone_in`.
+    pub fn clone_in<T: CloneIn<'a>>(
+        &mut self,
+        node: &T,
+        scope_id: ScopeId,
+        reset_spans: bool,
+    ) -> T::Cloned {
+        self.scoping.clone_in(node, scope_id, self.ast.allocator, reset_spans)
+    }
 }
 
 // Methods used internally within crate
 impl<'a> TraverseCtx<'a> {
+    fn create_identifier_reference(
+        &mut self,
+        name: &str,
+        binding: Option<&BoundIdentifier>,
+        flag: ReferenceFlag,
+    ) -> IdentifierReference<'a> {
+        let reference_id = self.scoping.create_reference_id(
+            SPAN,
+            CompactStr::from(name),
+            binding.map(|binding| binding.symbol_id),
+            flag,
+        );
+        let mut ident = IdentifierReference::new(SPAN, self.ast.new_atom(name));
+        ident.reference_id.set(Some(reference_id));
+        ident.reference_flag = flag;
+        ident
+    }
+
     /// Shortcut for `self.ancestry.push_stack`, to make `walk_*` methods less verbose.
     ///
     /// # SAFETY
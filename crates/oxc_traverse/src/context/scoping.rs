@@ -1,12 +1,20 @@
-use std::str;
+use std::{
+    cell::Cell,
+    collections::{HashMap, HashSet},
+    str,
+};
 
 use compact_str::{format_compact, CompactString};
 
-use oxc_semantic::{ScopeTree, SymbolTable};
-use oxc_span::{CompactStr, SPAN};
+use oxc_allocator::{Allocator, Vec};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstKind, CloneCtx, CloneIds, CloneIn, Visit};
+use oxc_semantic::{AstNodeId, Reference, ScopeTree, SymbolTable};
+use oxc_span::{CompactStr, Span, SPAN};
 use oxc_syntax::{
+    reference::{ReferenceFlag, ReferenceId},
     scope::{ScopeFlags, ScopeId},
-    symbol::SymbolFlags,
+    symbol::{SymbolFlags, SymbolId},
 };
 
 use super::FinderRet;
@@ -179,8 +187,7 @@ impl TraverseScoping {
         let name = CompactStr::new(&self.find_uid_name(name));
 
         // Add binding to scope
-        let symbol_id = self.symbols.create_symbol(SPAN, name.as_str(), flags, scope_id);
-        self.scopes.add_binding(scope_id, name.clone(), symbol_id);
+        self.add_binding(&name, scope_id, flags);
 
         name
     }
@@ -189,6 +196,101 @@ impl TraverseScoping {
     pub fn generate_uid_in_current_scope(&mut self, name: &str, flags: SymbolFlags) -> CompactStr {
         self.generate_uid(name, self.current_scope_id, flags)
     }
+
+    /// Create a binding with a UID based on `name_hint` in the nearest scope which `var`s are
+    /// hoisted to, i.e. the current function, class static block or the program.
+    ///
+    /// The caller must still add a declaration of the binding to the AST, whose
+    /// `BindingIdentifier` has the returned `symbol_id`.
+    pub fn create_binding_in_nearest_var_scope(
+        &mut self,
+        name_hint: &str,
+        flags: SymbolFlags,
+    ) -> BoundIdentifier {
+        let scope_id = self
+            .find_scope(|scope_id| {
+                if self.scopes.get_flags(scope_id).is_var() {
+                    FinderRet::Found(scope_id)
+                } else {
+                    FinderRet::Continue
+                }
+            })
+            .unwrap_or_else(|| self.scopes.root_scope_id());
+        let name = CompactStr::new(&self.find_uid_name(name_hint));
+        let symbol_id = self.add_binding(&name, scope_id, flags);
+        BoundIdentifier { name, symbol_id }
+    }
+
+    /// Create a reference to `symbol_id`, or an unresolved reference to the global `name` if
+    /// `symbol_id` is `None`.
+    pub fn create_reference_id(
+        &mut self,
+        span: Span,
+        name: CompactStr,
+        symbol_id: Option<SymbolId>,
+        flag: ReferenceFlag,
+    ) -> ReferenceId {
+        // Nodes created by a transform are not in `AstNodes`.
+        let mut reference = Reference::new(span, name.clone(), AstNodeId::new(0), flag);
+        if let Some(symbol_id) = symbol_id {
+            reference.set_symbol_id(symbol_id);
+        }
+        let reference_id = self.symbols.create_reference(reference);
+        if let Some(symbol_id) = symbol_id {
+            self.symbols.add_resolved_reference(symbol_id, reference_id);
+        } else {
+            let root_scope_id = self.scopes.root_scope_id();
+            self.scopes.add_unresolved_reference(root_scope_id, name, reference_id);
+        }
+        reference_id
+    }
+
+    /// Delete the scopes, bindings and references of `node`, which is removed from the AST.
+    ///
+    /// References are removed from the symbols they resolve to, or from the unresolved references,
+    /// bindings from their scopes, and scopes from their parents. The ids stay allocated, so that
+    /// the ids of other nodes do not change.
+    pub fn delete_subtree_updating_semantic<'a, T: Subtree<'a>>(&mut self, node: &T) {
+        let ids = SubtreeIds::of(node);
+        self.delete_ids(&ids, &SubtreeIds::default());
+    }
+
+    /// Replace `expr` with `replacement`, and delete the scopes, bindings and references of `expr`
+    /// which are not moved into `replacement`.
+    pub fn replace_expression_keeping_refs<'a>(
+        &mut self,
+        expr: &mut Expression<'a>,
+        replacement: Expression<'a>,
+    ) {
+        let old = std::mem::replace(expr, replacement);
+        let kept = SubtreeIds::of(expr);
+        self.delete_ids(&SubtreeIds::of(&old), &kept);
+    }
+
+    /// Clone `node` into `allocator`, with new scopes, symbols and references for the clone.
+    ///
+    /// `scope_id` is the scope which contains `node`. Scopes created by nodes inside `node`, and
+    /// the symbols bound in them, are duplicated, so the clone declares its own bindings and its
+    /// references resolve to them. Bindings of enclosing scopes are shared by both copies, like
+    /// a `var` which is declared twice.
+    ///
+    /// If `reset_spans` is `true`, spans of the cloned nodes are empty.
+    pub fn clone_in<'a, T: CloneIn<'a>>(
+        &mut self,
+        node: &T,
+        scope_id: ScopeId,
+        allocator: &'a Allocator,
+        reset_spans: bool,
+    ) -> T::Cloned {
+        let mut ids = ScopingCloneIds {
+            scoping: self,
+            scope_id,
+            scopes: HashMap::new(),
+            symbols: HashMap::new(),
+        };
+        let mut ctx = CloneCtx::new(allocator).with_reset_spans(reset_spans).with_ids(&mut ids);
+        node.clone_in_with(&mut ctx)
+    }
 }
 
 // Methods used internally within crate
@@ -209,6 +311,44 @@ impl TraverseScoping {
         self.current_scope_id = scope_id;
     }
 
+    /// Add a symbol named `name` to the symbols table, and bind it in `scope_id`.
+    fn add_binding(
+        &mut self,
+        name: &CompactStr,
+        scope_id: ScopeId,
+        flags: SymbolFlags,
+    ) -> SymbolId {
+        let symbol_id = self.symbols.create_symbol(SPAN, name.as_str(), flags, scope_id);
+        // Keep `declarations` in step with the other columns of the symbols table.
+        self.symbols.add_declaration(AstNodeId::new(0));
+        self.scopes.add_binding(scope_id, name.clone(), symbol_id);
+        symbol_id
+    }
+
+    /// Delete the scopes, symbols and references of `ids` which are not in `kept`.
+    fn delete_ids(&mut self, ids: &SubtreeIds, kept: &SubtreeIds) {
+        for &reference_id in ids.references.difference(&kept.references) {
+            let reference = self.symbols.get_reference(reference_id);
+            let (symbol_id, name) = (reference.symbol_id(), reference.name().clone());
+            if let Some(symbol_id) = symbol_id {
+                self.symbols.remove_resolved_reference(symbol_id, reference_id);
+            } else {
+                let root_scope_id = self.scopes.root_scope_id();
+                self.scopes.remove_unresolved_reference(root_scope_id, &name, reference_id);
+            }
+        }
+        for &symbol_id in ids.symbols.difference(&kept.symbols) {
+            let scope_id = self.symbols.get_scope_id(symbol_id);
+            let name = self.symbols.names[symbol_id].clone();
+            if self.scopes.get_binding(scope_id, &name) == Some(symbol_id) {
+                self.scopes.remove_binding(scope_id, &name);
+            }
+        }
+        for &scope_id in ids.scopes.difference(&kept.scopes) {
+            self.scopes.detach_scope(scope_id);
+        }
+    }
+
     /// Find a variable name which can be used as a UID
     fn find_uid_name(&self, name: &str) -> CompactString {
         let mut name = create_uid_name_base(name);
@@ -320,3 +460,170 @@ fn create_uid_name_base(name: &str) -> CompactString {
     str.push_str(name);
     str
 }
+
+/// [CloneIds] for [TraverseScoping::clone_in].
+struct ScopingCloneIds<'s> {
+    scoping: &'s mut TraverseScoping,
+    /// Scope which contains the cloned node.
+    scope_id: ScopeId,
+    scopes: HashMap<ScopeId, ScopeId>,
+    symbols: HashMap<SymbolId, SymbolId>,
+}
+
+impl ScopingCloneIds<'_> {
+    /// Whether `scope_id` is created by a node inside the cloned node.
+    fn is_cloned_scope(&self, scope_id: ScopeId) -> bool {
+        scope_id != self.scope_id
+            && self.scoping.scopes.ancestors(scope_id).any(|id| id == self.scope_id)
+    }
+}
+
+impl CloneIds for ScopingCloneIds<'_> {
+    fn clone_scope_id(&mut self, scope_id: ScopeId) -> ScopeId {
+        if !self.is_cloned_scope(scope_id) {
+            return scope_id;
+        }
+        if let Some(&cloned_id) = self.scopes.get(&scope_id) {
+            return cloned_id;
+        }
+        let parent_id =
+            self.scoping.scopes.get_parent_id(scope_id).map(|id| self.clone_scope_id(id));
+        let scopes = &mut self.scoping.scopes;
+        let cloned_id = scopes.add_scope(parent_id, scopes.get_flags(scope_id));
+        scopes.add_node_id(cloned_id, scopes.get_node_id(scope_id));
+        self.scopes.insert(scope_id, cloned_id);
+        cloned_id
+    }
+
+    fn clone_symbol_id(&mut self, symbol_id: SymbolId) -> SymbolId {
+        let scope_id = self.scoping.symbols.get_scope_id(symbol_id);
+        if !self.is_cloned_scope(scope_id) {
+            return symbol_id;
+        }
+        if let Some(&cloned_id) = self.symbols.get(&symbol_id) {
+            return cloned_id;
+        }
+        let cloned_scope_id = self.clone_scope_id(scope_id);
+        let symbols = &mut self.scoping.symbols;
+        let name = CompactStr::new(symbols.get_name(symbol_id));
+        let cloned_id = symbols.create_symbol(
+            symbols.get_span(symbol_id),
+            &name,
+            symbols.get_flag(symbol_id),
+            cloned_scope_id,
+        );
+        symbols.add_declaration(symbols.get_declaration(symbol_id));
+        self.scoping.scopes.add_binding(cloned_scope_id, name, cloned_id);
+        self.symbols.insert(symbol_id, cloned_id);
+        cloned_id
+    }
+
+    fn clone_reference_id(&mut self, reference_id: ReferenceId) -> ReferenceId {
+        let mut reference = self.scoping.symbols.get_reference(reference_id).clone();
+        let symbol_id = reference.symbol_id().map(|id| self.clone_symbol_id(id));
+        if let Some(symbol_id) = symbol_id {
+            reference.set_symbol_id(symbol_id);
+        }
+        let name = reference.name().clone();
+        let cloned_id = self.scoping.symbols.create_reference(reference);
+        if let Some(symbol_id) = symbol_id {
+            self.scoping.symbols.add_resolved_reference(symbol_id, cloned_id);
+        } else {
+            let root_scope_id = self.scoping.scopes.root_scope_id();
+            self.scoping.scopes.add_unresolved_reference(root_scope_id, name, cloned_id);
+        }
+        cloned_id
+    }
+}
+
+/// A binding created by [TraverseScoping::create_binding_in_nearest_var_scope].
+#[derive(Debug, Clone)]
+pub struct BoundIdentifier {
+    pub name: CompactStr,
+    pub symbol_id: SymbolId,
+}
+
+/// An AST node which can be deleted with [TraverseScoping::delete_subtree_updating_semantic].
+pub trait Subtree<'a> {
+    fn visit_with<V: Visit<'a>>(&self, visitor: &mut V);
+}
+
+macro_rules! impl_subtree {
+    ($($ty:ident => $visit:ident),* $(,)?) => {
+        $(
+            impl<'a> Subtree<'a> for $ty<'a> {
+                fn visit_with<V: Visit<'a>>(&self, visitor: &mut V) {
+                    visitor.$visit(self);
+                }
+            }
+        )*
+    };
+}
+
+impl_subtree!(
+    Expression => visit_expression,
+    Statement => visit_statement,
+    Declaration => visit_declaration,
+    VariableDeclarator => visit_variable_declarator,
+    BindingPattern => visit_binding_pattern,
+    FormalParameters => visit_formal_parameters,
+    Class => visit_class,
+    Argument => visit_argument,
+);
+
+impl<'a> Subtree<'a> for Function<'a> {
+    fn visit_with<V: Visit<'a>>(&self, visitor: &mut V) {
+        visitor.visit_function(self, None);
+    }
+}
+
+impl<'a> Subtree<'a> for Vec<'a, Statement<'a>> {
+    fn visit_with<V: Visit<'a>>(&self, visitor: &mut V) {
+        visitor.visit_statements(self);
+    }
+}
+
+/// The ids of the scopes, symbols and references in an AST node.
+#[derive(Default)]
+struct SubtreeIds {
+    scopes: HashSet<ScopeId>,
+    symbols: HashSet<SymbolId>,
+    references: HashSet<ReferenceId>,
+}
+
+impl SubtreeIds {
+    fn of<'a, T: Subtree<'a>>(node: &T) -> Self {
+        let mut ids = Self::default();
+        node.visit_with(&mut ids);
+        ids
+    }
+}
+
+impl<'a> Visit<'a> for SubtreeIds {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let scope_id: &Cell<Option<ScopeId>> = match kind {
+            AstKind::BlockStatement(node) => &node.scope_id,
+            AstKind::ForStatement(node) => &node.scope_id,
+            AstKind::ForInStatement(node) => &node.scope_id,
+            AstKind::ForOfStatement(node) => &node.scope_id,
+            AstKind::SwitchStatement(node) => &node.scope_id,
+            AstKind::CatchClause(node) => &node.scope_id,
+            AstKind::Function(node) => &node.scope_id,
+            AstKind::ArrowFunctionExpression(node) => &node.scope_id,
+            AstKind::Class(node) => &node.scope_id,
+            AstKind::StaticBlock(node) => &node.scope_id,
+            AstKind::TSEnumDeclaration(node) => &node.scope_id,
+            AstKind::TSModuleBlock(node) => &node.scope_id,
+            _ => return,
+        };
+        self.scopes.extend(scope_id.get());
+    }
+
+    fn visit_binding_identifier(&mut self, ident: &BindingIdentifier<'a>) {
+        self.symbols.extend(ident.symbol_id.get());
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        self.references.extend(ident.reference_id.get());
+    }
+}
@@ -68,7 +68,9 @@ use oxc_span::SourceType;
 pub mod ancestor;
 pub use ancestor::Ancestor;
 mod context;
-pub use context::{FinderRet, TraverseAncestry, TraverseCtx, TraverseScoping};
+pub use context::{
+    BoundIdentifier, FinderRet, Subtree, TraverseAncestry, TraverseCtx, TraverseScoping,
+};
 #[allow(clippy::module_inception)]
 mod traverse;
 pub use traverse::Traverse;
@@ -0,0 +1,125 @@
+use oxc_allocator::Allocator;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_parser::Parser;
+use oxc_semantic::{check_semantic_after_transform, SemanticMismatch, SymbolFlags};
+use oxc_span::{SourceType, SPAN};
+use oxc_syntax::reference::ReferenceFlag;
+use oxc_traverse::{traverse_mut, BoundIdentifier, Traverse, TraverseCtx};
+
+fn run<'a, T: Traverse<'a>>(traverser: &mut T, allocator: &'a Allocator, source_text: &'a str) {
+    let source_type = SourceType::default().with_module(true);
+    let program = Parser::new(allocator, source_text, source_type).parse().program;
+    let program = allocator.alloc(program);
+    traverse_mut(traverser, program, source_text, source_type, allocator);
+}
+
+/// Replaces binary expressions with a `var` of the enclosing function, declared at its start.
+#[derive(Default)]
+struct ExtractBinaryExpressions {
+    temps: Vec<BoundIdentifier>,
+    mismatches: Vec<SemanticMismatch>,
+    /// The number of references to the parameters and the unresolved references after the
+    /// transform.
+    references: Vec<(&'static str, usize)>,
+}
+
+impl<'a> Traverse<'a> for ExtractBinaryExpressions {
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !matches!(expr, Expression::BinaryExpression(_)) {
+            return;
+        }
+        let temp =
+            ctx.create_binding_in_nearest_var_scope("sum", SymbolFlags::FunctionScopedVariable);
+        let reference = ctx.create_reference(&temp, ReferenceFlag::Read);
+        let replacement = ctx.ast.identifier_reference_expression(reference);
+        ctx.replace_expression_keeping_refs(expr, replacement);
+        self.temps.push(temp);
+    }
+
+    fn exit_function_body(&mut self, body: &mut FunctionBody<'a>, ctx: &mut TraverseCtx<'a>) {
+        for temp in self.temps.drain(..) {
+            let id = ctx.create_binding_identifier(&temp);
+            let declarator = ctx.ast.variable_declarator(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.binding_pattern(ctx.ast.binding_pattern_identifier(id), None, false),
+                None,
+                false,
+            );
+            let decl = ctx.ast.variable_declaration(
+                SPAN,
+                VariableDeclarationKind::Var,
+                ctx.ast.new_vec_single(declarator),
+                Modifiers::empty(),
+            );
+            body.statements.insert(0, Statement::VariableDeclaration(decl));
+        }
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.mismatches = check_semantic_after_transform(ctx.symbols(), ctx.scopes(), program);
+        for name in ["a", "b", "_sum"] {
+            let symbol_id = ctx.symbols().get_symbol_id_from_name(name).unwrap();
+            self.references.push((name, ctx.symbols().get_resolved_reference_count(symbol_id)));
+        }
+        for name in ["c", "g"] {
+            let unresolved = ctx.scopes().root_unresolved_references().get(name);
+            self.references.push((name, unresolved.map_or(0, Vec::len)));
+        }
+    }
+}
+
+#[test]
+fn create_and_replace() {
+    let allocator = Allocator::default();
+    let mut traverser = ExtractBinaryExpressions::default();
+    run(&mut traverser, &allocator, "function f(a, b) { g(a + b * c) }");
+    assert_eq!(traverser.mismatches, vec![]);
+    assert_eq!(traverser.references, vec![("a", 0), ("b", 0), ("_sum", 1), ("c", 0), ("g", 1)]);
+}
+
+/// Removes block statements.
+#[derive(Default)]
+struct DeleteBlocks {
+    mismatches: Vec<SemanticMismatch>,
+    x_references: usize,
+    has_child_scopes: bool,
+    has_unresolved_references: bool,
+}
+
+impl<'a> Traverse<'a> for DeleteBlocks {
+    fn enter_statements(
+        &mut self,
+        stmts: &mut oxc_allocator::Vec<'a, Statement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        for stmt in stmts.iter() {
+            if matches!(stmt, Statement::BlockStatement(_)) {
+                ctx.delete_subtree_updating_semantic(stmt);
+            }
+        }
+        stmts.retain(|stmt| !matches!(stmt, Statement::BlockStatement(_)));
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.mismatches = check_semantic_after_transform(ctx.symbols(), ctx.scopes(), program);
+        let x = ctx.symbols().get_symbol_id_from_name("x").unwrap();
+        self.x_references = ctx.symbols().get_resolved_reference_count(x);
+        let root_scope_id = ctx.scopes().root_scope_id();
+        self.has_child_scopes =
+            ctx.scopes().get_child_ids(root_scope_id).is_some_and(|ids| !ids.is_empty());
+        self.has_unresolved_references = !ctx.scopes().root_unresolved_references().is_empty();
+    }
+}
+
+#[test]
+fn delete_subtree() {
+    let allocator = Allocator::default();
+    let mut traverser = DeleteBlocks::default();
+    run(&mut traverser, &allocator, "let x = 1; { let y = x; g(y, () => y); } x;");
+    assert_eq!(traverser.mismatches, vec![]);
+    assert_eq!(traverser.x_references, 1);
+    assert!(!traverser.has_child_scopes);
+    assert!(!traverser.has_unresolved_references);
+}
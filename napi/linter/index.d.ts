@@ -0,0 +1,75 @@
+/* tslint:disable */
+/* eslint-disable */
+
+/* auto-generated by NAPI-RS */
+
+export interface LintOptions {
+  /**
+   * Name of the linted file, which determines the language of the source text,
+   * e.g. `.tsx` or the script of a `.vue` file, and which files rules like `jest/*` apply to.
+   *
+   * Default: "index.js"
+   */
+  sourceFilename?: string
+  sourceType?: 'script' | 'module' | 'unambiguous' | undefined
+  /**
+   * Configuration in the format of `.oxlintrc.json`, e.g. `{ rules: { "no-debugger": "error" } }`.
+   *
+   * Default: the `correctness` rules as warnings, like `oxlint` without a configuration file.
+   */
+  config?: Record<string, any>
+}
+export interface LintResult {
+  /** Syntax errors and diagnostics of the enabled rules, in the order of the source text. */
+  diagnostics: Array<Diagnostic>
+}
+export interface Diagnostic {
+  /** The message, prefixed by the plugin and the name of the rule, e.g. `eslint(no-debugger): ...` */
+  message: string
+  severity: 'error' | 'warning' | 'advice'
+  help?: string
+  labels: Array<Label>
+  /** Edit which fixes the diagnostic. */
+  fix?: Fix
+}
+/** Offsets are UTF-8 byte offsets into the source text. */
+export interface Label {
+  message?: string
+  start: number
+  end: number
+}
+/**
+ * Replaces the source text from `start` to `end` by `content`.
+ *
+ * Offsets are UTF-8 byte offsets into the source text.
+ */
+export interface Fix {
+  content: string
+  start: number
+  end: number
+  /**
+   * * safe: never changes the behavior of the code, applied by `oxlint --fix`
+   * * suggestion: may change the behavior in rare cases, e.g. of unusual code
+   * * dangerous: may change the behavior of the code
+   */
+  kind: 'safe' | 'suggestion' | 'dangerous'
+}
+/**
+ * Lint `source_text` with the rules of `options.config`.
+ *
+ * # Errors
+ *
+ * * The file extension of `options.source_filename` is not supported
+ * * `options.config` is not a valid configuration
+ */
+export function lintSync(sourceText: string, options?: LintOptions | undefined | null): LintResult
+/**
+ * # Errors
+ *
+ * Same as `lint_sync`.
+ *
+ * # Panics
+ *
+ * * Tokio crashes
+ */
+export function lintAsync(sourceText: string, options?: LintOptions | undefined | null): Promise<LintResult>
@@ -0,0 +1,206 @@
+use std::{path::Path, rc::Rc};
+
+use napi::{Error, Result};
+use napi_derive::napi;
+
+use oxc_allocator::Allocator;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+use oxc_linter::{
+    partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
+    FixKind, LintContext, Linter, OxlintConfig,
+};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+#[napi(object)]
+#[derive(Default)]
+pub struct LintOptions {
+    /// Name of the linted file, which determines the language of the source text,
+    /// e.g. `.tsx` or the script of a `.vue` file, and which files rules like `jest/*` apply to.
+    ///
+    /// Default: "index.js"
+    pub source_filename: Option<String>,
+    #[napi(ts_type = "'script' | 'module' | 'unambiguous' | undefined")]
+    pub source_type: Option<String>,
+    /// Configuration in the format of `.oxlintrc.json`, e.g. `{ rules: { "no-debugger": "error" } }`.
+    ///
+    /// Default: the `correctness` rules as warnings, like `oxlint` without a configuration file.
+    #[napi(ts_type = "Record<string, any>")]
+    pub config: Option<serde_json::Value>,
+}
+
+#[napi(object)]
+pub struct LintResult {
+    /// Syntax errors and diagnostics of the enabled rules, in the order of the source text.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[napi(object)]
+pub struct Diagnostic {
+    /// The message, prefixed by the plugin and the name of the rule, e.g. `eslint(no-debugger): ...`
+    pub message: String,
+    #[napi(ts_type = "'error' | 'warning' | 'advice'")]
+    pub severity: String,
+    pub help: Option<String>,
+    pub labels: Vec<Label>,
+    /// Edit which fixes the diagnostic.
+    pub fix: Option<Fix>,
+}
+
+/// Offsets are UTF-8 byte offsets into the source text.
+#[napi(object)]
+pub struct Label {
+    pub message: Option<String>,
+    pub start: u32,
+    pub end: u32,
+}
+
+/// Replaces the source text from `start` to `end` by `content`.
+///
+/// Offsets are UTF-8 byte offsets into the source text.
+#[napi(object)]
+pub struct Fix {
+    pub content: String,
+    pub start: u32,
+    pub end: u32,
+    /// * safe: never changes the behavior of the code, applied by `oxlint --fix`
+    /// * suggestion: may change the behavior in rare cases, e.g. of unusual code
+    /// * dangerous: may change the behavior of the code
+    #[napi(ts_type = "'safe' | 'suggestion' | 'dangerous'")]
+    pub kind: &'static str,
+}
+
+impl Diagnostic {
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(error: &OxcDiagnostic, fix: Option<Fix>, start: u32) -> Self {
+        let labels = error
+            .labels
+            .iter()
+            .flatten()
+            .map(|label| Label {
+                message: label.label().map(ToString::to_string),
+                start: start + label.offset() as u32,
+                end: start + (label.offset() + label.len()) as u32,
+            })
+            .collect();
+        Self {
+            message: error.message.clone(),
+            severity: match error.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Advice => "advice",
+            }
+            .to_string(),
+            help: error.help.clone(),
+            labels,
+            fix,
+        }
+    }
+}
+
+fn source_type(options: &LintOptions, path: &Path) -> Result<Option<SourceType>> {
+    let source_type = match SourceType::from_path(path) {
+        Ok(source_type) => source_type,
+        Err(_) if LINT_PARTIAL_LOADER_EXT.contains(&extension(path)) => return Ok(None),
+        Err(error) => return Err(Error::from_reason(error.0)),
+    };
+    Ok(Some(match options.source_type.as_deref() {
+        Some("script") => source_type.with_script(true),
+        Some("module") => source_type.with_module(true),
+        _ => source_type,
+    }))
+}
+
+fn extension(path: &Path) -> &str {
+    path.extension().and_then(std::ffi::OsStr::to_str).unwrap_or_default()
+}
+
+fn lint_source(linter: &Linter, path: &Path, source: JavaScriptSource) -> Vec<Diagnostic> {
+    #[allow(clippy::cast_possible_truncation)]
+    let start = source.start as u32;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source.source_text, source.source_type)
+        .allow_return_outside_function(true)
+        .parse();
+    if !ret.errors.is_empty() {
+        return ret.errors.iter().map(|error| Diagnostic::new(error, None, start)).collect();
+    }
+
+    let program = allocator.alloc(ret.program);
+    let semantic_ret = SemanticBuilder::new(source.source_text, source.source_type)
+        .with_trivias(ret.trivias)
+        .with_check_syntax_error(true)
+        .build(program);
+    if !semantic_ret.errors.is_empty() {
+        return semantic_ret
+            .errors
+            .iter()
+            .map(|error| Diagnostic::new(error, None, start))
+            .collect();
+    }
+
+    let ctx =
+        LintContext::new(path.to_path_buf().into_boxed_path(), &Rc::new(semantic_ret.semantic));
+    let mut messages = linter.run(ctx);
+    messages.sort_unstable_by_key(|message| (message.start(), message.end()));
+    messages
+        .into_iter()
+        .map(|message| {
+            let fix = message.fix.map(|fix| Fix {
+                content: fix.content.into_owned(),
+                start: start + fix.span.start,
+                end: start + fix.span.end,
+                kind: if fix.kind.contains(FixKind::Dangerous) {
+                    "dangerous"
+                } else if fix.kind.contains(FixKind::Suggestion) {
+                    "suggestion"
+                } else {
+                    "safe"
+                },
+            });
+            Diagnostic::new(&message.error, fix, start)
+        })
+        .collect()
+}
+
+/// Lint `source_text` with the rules of `options.config`.
+///
+/// # Errors
+///
+/// * The file extension of `options.source_filename` is not supported
+/// * `options.config` is not a valid configuration
+#[allow(clippy::needless_pass_by_value)]
+#[napi]
+pub fn lint_sync(source_text: String, options: Option<LintOptions>) -> Result<LintResult> {
+    let options = options.unwrap_or_default();
+    let path = Path::new(options.source_filename.as_deref().unwrap_or("index.js"));
+    let source_type = source_type(&options, path)?;
+
+    let mut linter = Linter::default().with_fix(FixKind::all());
+    if let Some(config) = &options.config {
+        let config = serde_json::from_value::<OxlintConfig>(config.clone())
+            .map_err(|error| Error::from_reason(format!("Invalid configuration: {error}")))?;
+        linter = linter.with_config(config);
+    }
+
+    let sources = match source_type {
+        Some(source_type) => vec![JavaScriptSource::new(&source_text, source_type, 0)],
+        None => PartialLoader::parse(extension(path), &source_text).unwrap_or_default(),
+    };
+    let diagnostics =
+        sources.into_iter().flat_map(|source| lint_source(&linter, path, source)).collect();
+    Ok(LintResult { diagnostics })
+}
+
+/// # Errors
+///
+/// Same as `lint_sync`.
+///
+/// # Panics
+///
+/// * Tokio crashes
+#[napi]
+pub async fn lint_async(source_text: String, options: Option<LintOptions>) -> Result<LintResult> {
+    tokio::spawn(async move { lint_sync(source_text, options) }).await.unwrap()
+}
@@ -0,0 +1,30 @@
+import oxc from './index.js';
+import assert from 'assert';
+
+console.log(`Testing on ${process.platform}-${process.arch}`)
+
+const sourceText = "debugger; if (x === NaN) {}";
+const options = { config: { rules: { 'no-debugger': 'error' } } };
+
+function test(ret) {
+  console.log(ret);
+  assert(ret.diagnostics.length == 2);
+  const [debug, nan] = ret.diagnostics;
+  assert(debug.message.startsWith('eslint(no-debugger)'));
+  assert(debug.severity == 'error');
+  assert(debug.fix.kind == 'suggestion' && debug.fix.content == '');
+  assert(debug.fix.start == 0 && debug.fix.end == 9);
+  assert(nan.message.startsWith('eslint(use-isnan)'));
+  assert(nan.severity == 'warning');
+}
+
+test(oxc.lintSync(sourceText, options));
+
+assert(oxc.lintSync("let x = ;").diagnostics[0].severity == 'error');
+assert.throws(() => oxc.lintSync(sourceText, { sourceFilename: 'index.txt' }));
+
+async function main() {
+  test(await oxc.lintAsync(sourceText, options));
+}
+
+main()
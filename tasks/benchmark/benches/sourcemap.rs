@@ -19,7 +19,7 @@ fn bench_sourcemap(criterion: &mut Criterion) {
             let codegen_options =
                 CodegenOptions { enable_source_map: true, ..CodegenOptions::default() };
             b.iter(|| {
-                let CodegenReturn { source_map, source_text } = Codegen::<false>::new(
+                let CodegenReturn { source_map, source_text, .. } = Codegen::<false>::new(
                     file.file_name.as_str(),
                     source_text,
                     codegen_options.clone(),
@@ -1,6 +1,7 @@
 use std::path::{Path, PathBuf};
 
 use oxc_allocator::Allocator;
+use oxc_ast::AstDiff;
 use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
@@ -46,6 +47,7 @@ fn write_failure(
     source_text1: &str,
     parser_result2: &str,
     source_text2: &str,
+    ast_diff: &str,
 ) {
     let base_path = Path::new(&format!("./tasks/coverage/failures/{case_name}"))
         .join(file_name)
@@ -60,6 +62,7 @@ fn write_failure(
         .expect("Error writing parser_result2.json");
     std::fs::write(base_path.join("source_text2.ts"), source_text2)
         .expect("Error writing source_text2.ts");
+    std::fs::write(base_path.join("ast_diff.txt"), ast_diff).expect("Error writing ast_diff.txt");
 }
 
 /// Idempotency test
@@ -81,6 +84,7 @@ fn get_normal_result(
     let result = source_text1 == source_text2;
 
     if !result {
+        let ast_diff = AstDiff::new(&parse_result1.program, &parse_result2.program).to_string();
         let parse_result1 = format!(
             "Panicked: {:#?}\nErrors:\n{:#?}\nProgram:\n{:#?}",
             parse_result1.panicked, parse_result1.errors, parse_result1.program
@@ -98,6 +102,7 @@ fn get_normal_result(
             &source_text1,
             &parse_result2,
             &source_text2,
+            &ast_diff,
         );
     }
 
@@ -123,6 +128,7 @@ fn get_minify_result(
     let result = source_text1 == source_text2;
 
     if !result {
+        let ast_diff = AstDiff::new(&parse_result1.program, &parse_result2.program).to_string();
         let parse_result1 = format!(
             "Panicked: {:#?}\nErrors:\n{:#?}\nProgram:\n{:#?}",
             parse_result1.panicked, parse_result1.errors, parse_result1.program
@@ -140,6 +146,7 @@ fn get_minify_result(
             &source_text1,
             &parse_result2,
             &source_text2,
+            &ast_diff,
         );
     }
 
@@ -153,7 +160,7 @@ fn get_typescript_result(
     source_text: &str,
     source_type: SourceType,
 ) -> bool {
-    let options = CodegenOptions { enable_source_map: false, enable_typescript: true };
+    let options = CodegenOptions { enable_typescript: true, ..CodegenOptions::default() };
     let allocator = Allocator::default();
     let parse_result1 = Parser::new(&allocator, source_text, source_type).parse();
     let source_text1 = Codegen::<false>::new("", source_text, options.clone())
@@ -165,6 +172,7 @@ fn get_typescript_result(
     let result = source_text1 == source_text2;
 
     if !result {
+        let ast_diff = AstDiff::new(&parse_result1.program, &parse_result2.program).to_string();
         let parse_result1 = format!(
             "Panicked: {:#?}\nErrors:\n{:#?}\nProgram:\n{:#?}",
             parse_result1.panicked, parse_result1.errors, parse_result1.program
@@ -182,6 +190,7 @@ fn get_typescript_result(
             &source_text1.clone(),
             &parse_result2,
             &source_text2,
+            &ast_diff,
         );
     }
 
@@ -0,0 +1,372 @@
+//! Differential comparison of the minifier against esbuild and terser.
+//!
+//! Every file of `tasks/libs.txt` is minified by oxc, and by `esbuild` and `terser` when they
+//! are installed. The output sizes and whether each output parses again are written to
+//! `tasks/coverage/minifier_differential.snap`, followed by the constructs on which oxc spends
+//! the most bytes compared to the smallest output, to guide which minifier passes to build next.
+//!
+//! The bytes of a construct are the bytes of its nodes which are not covered by a child node,
+//! so the `if(` and `)` of an `if` statement count towards `IfStatement` while its test
+//! expression counts towards its own construct.
+
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{AstKind, Visit};
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_minifier::{Minifier, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType};
+use oxc_tasks_common::{project_root, TestFiles};
+
+use crate::{
+    suite::{Case, Suite, TestResult},
+    AppArgs,
+};
+
+/// Number of constructs listed in the regression table.
+const REGRESSION_COUNT: usize = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tool {
+    Oxc,
+    Esbuild,
+    Terser,
+}
+
+impl Tool {
+    const ALL: [Self; 3] = [Self::Oxc, Self::Esbuild, Self::Terser];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Oxc => "oxc",
+            Self::Esbuild => "esbuild",
+            Self::Terser => "terser",
+        }
+    }
+
+    /// Command which reads the source text from stdin and writes the minified code to stdout.
+    fn command(self, source_type: SourceType) -> Option<Command> {
+        match self {
+            Self::Oxc => None,
+            Self::Esbuild => {
+                let loader = if source_type.is_jsx() { "--loader=jsx" } else { "--loader=js" };
+                let mut command = Command::new("esbuild");
+                command.args(["--minify", loader]);
+                Some(command)
+            }
+            Self::Terser => {
+                let mut command = Command::new("terser");
+                command.args(["--compress", "--mangle"]);
+                if source_type.is_module() {
+                    command.arg("--module");
+                }
+                Some(command)
+            }
+        }
+    }
+}
+
+pub struct ToolOutput {
+    tool: Tool,
+    size: usize,
+    /// Whether the output is parsed without errors.
+    valid: bool,
+    /// Bytes of the output spent on each construct.
+    constructs: BTreeMap<String, usize>,
+}
+
+impl ToolOutput {
+    fn new(tool: Tool, source_text: &str, source_type: SourceType) -> Self {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let valid = ret.errors.is_empty() && !ret.panicked;
+        let mut sizes = ConstructSizes::default();
+        if valid {
+            sizes.visit_program(&ret.program);
+        }
+        Self { tool, size: source_text.len(), valid, constructs: sizes.constructs }
+    }
+}
+
+pub struct DifferentialSuite {
+    test_root: PathBuf,
+    test_cases: Vec<DifferentialCase>,
+}
+
+impl DifferentialSuite {
+    pub fn new() -> Self {
+        Self { test_root: project_root(), test_cases: vec![] }
+    }
+}
+
+impl Suite<DifferentialCase> for DifferentialSuite {
+    fn get_test_root(&self) -> &Path {
+        &self.test_root
+    }
+
+    fn save_test_cases(&mut self, tests: Vec<DifferentialCase>) {
+        self.test_cases.extend(tests);
+    }
+
+    fn get_test_cases(&self) -> &Vec<DifferentialCase> {
+        &self.test_cases
+    }
+
+    fn get_test_cases_mut(&mut self) -> &mut Vec<DifferentialCase> {
+        &mut self.test_cases
+    }
+
+    fn read_test_cases(&mut self, _name: &str, args: &AppArgs) {
+        let filter = args.filter.as_ref();
+        let cases = TestFiles::new()
+            .files()
+            .iter()
+            .filter(|file| filter.map_or(true, |query| file.file_name.contains(query)))
+            .map(|file| {
+                DifferentialCase::new(file.file_name.clone().into(), file.source_text.clone())
+            })
+            .collect::<Vec<_>>();
+        self.save_test_cases(cases);
+    }
+
+    fn run_coverage(&self, name: &str, _args: &AppArgs) {
+        let mut tests = self.get_test_cases().iter().collect::<Vec<_>>();
+        tests.sort_by_key(|case| case.path());
+
+        let report = DifferentialReport::new(&tests).to_string();
+        println!("{report}");
+
+        let path = project_root().join(format!("tasks/coverage/{name}.snap"));
+        let mut file = File::create(path).unwrap();
+        file.write_all(report.as_bytes()).unwrap();
+    }
+}
+
+pub struct DifferentialCase {
+    path: PathBuf,
+    code: String,
+    source_type: SourceType,
+    result: TestResult,
+    outputs: Vec<ToolOutput>,
+}
+
+impl DifferentialCase {
+    fn output(&self, tool: Tool) -> Option<&ToolOutput> {
+        self.outputs.iter().find(|output| output.tool == tool)
+    }
+
+    fn minify(&self, tool: Tool) -> Option<String> {
+        match tool.command(self.source_type) {
+            None => Some(self.minify_with_oxc()),
+            Some(command) => run_command(command, &self.code),
+        }
+    }
+
+    fn minify_with_oxc(&self) -> String {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, &self.code, self.source_type).parse().program;
+        let program = allocator.alloc(program);
+        Minifier::new(MinifierOptions::default()).build(&allocator, program);
+        Codegen::<true>::new("", &self.code, CodegenOptions::default()).build(program).source_text
+    }
+}
+
+/// Output of `command` for `source_text` on stdin, or `None` if the command is not installed or
+/// fails.
+fn run_command(mut command: Command, source_text: &str) -> Option<String> {
+    let mut child =
+        command.stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::null()).spawn().ok()?;
+    let mut stdin = child.stdin.take()?;
+    let source_text = source_text.to_string();
+    // Write from another thread, so that a full stdout pipe cannot block the write.
+    let writer = thread::spawn(move || stdin.write_all(source_text.as_bytes()));
+    let output = child.wait_with_output().ok()?;
+    writer.join().ok()?.ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+impl Case for DifferentialCase {
+    fn new(path: PathBuf, code: String) -> Self {
+        let source_type = SourceType::from_path(&path).unwrap();
+        Self { path, code, source_type, result: TestResult::ToBeRun, outputs: vec![] }
+    }
+
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn test_result(&self) -> &TestResult {
+        &self.result
+    }
+
+    fn run(&mut self) {
+        self.outputs = Tool::ALL
+            .into_iter()
+            .filter_map(|tool| {
+                let output = self.minify(tool)?;
+                Some(ToolOutput::new(tool, &output, self.source_type))
+            })
+            .collect();
+        self.result = if self.output(Tool::Oxc).is_some_and(|output| output.valid) {
+            TestResult::Passed
+        } else {
+            TestResult::ParseError(String::new(), false)
+        };
+    }
+}
+
+/// Exclusive byte count of each construct, see the module documentation.
+#[derive(Default)]
+struct ConstructSizes {
+    /// Size and bytes covered by children, of each node being visited.
+    stack: Vec<(u32, u32)>,
+    constructs: BTreeMap<String, usize>,
+}
+
+impl<'a> Visit<'a> for ConstructSizes {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        self.stack.push((kind.span().size(), 0));
+    }
+
+    fn leave_node(&mut self, kind: AstKind<'a>) {
+        let Some((size, covered)) = self.stack.pop() else { return };
+        if let Some((_, parent_covered)) = self.stack.last_mut() {
+            *parent_covered += size;
+        }
+        *self.constructs.entry(construct_name(&kind)).or_default() +=
+            size.saturating_sub(covered) as usize;
+    }
+}
+
+fn construct_name(kind: &AstKind) -> String {
+    match kind {
+        AstKind::Directive(_) => "Directive".into(),
+        AstKind::StringLiteral(_) => "StringLiteral".into(),
+        AstKind::BinaryExpression(expr) => format!("BinaryExpression({})", expr.operator.as_str()),
+        AstKind::UnaryExpression(expr) => format!("UnaryExpression({})", expr.operator.as_str()),
+        _ => kind.debug_name().split('(').next().unwrap_or_default().to_string(),
+    }
+}
+
+/// Size table of every file, and the constructs with the biggest size regressions.
+struct DifferentialReport<'a> {
+    cases: &'a [&'a DifferentialCase],
+    /// Tools which produced output for at least one file.
+    tools: Vec<Tool>,
+}
+
+impl<'a> DifferentialReport<'a> {
+    fn new(cases: &'a [&'a DifferentialCase]) -> Self {
+        let tools = Tool::ALL
+            .into_iter()
+            .filter(|&tool| cases.iter().any(|case| case.output(tool).is_some()))
+            .collect();
+        Self { cases, tools }
+    }
+
+    /// Bytes per construct of each tool, summed over the files which every tool minified into
+    /// valid code.
+    fn construct_totals(&self) -> BTreeMap<&'a str, Vec<usize>> {
+        let mut totals = BTreeMap::<&str, Vec<usize>>::new();
+        for case in self.cases {
+            let outputs = self.tools.iter().map(|&tool| case.output(tool)).collect::<Vec<_>>();
+            if !outputs.iter().all(|output| output.is_some_and(|output| output.valid)) {
+                continue;
+            }
+            for (i, output) in outputs.into_iter().flatten().enumerate() {
+                for (construct, &size) in &output.constructs {
+                    let sizes = totals
+                        .entry(construct.as_str())
+                        .or_insert_with(|| vec![0; self.tools.len()]);
+                    sizes[i] += size;
+                }
+            }
+        }
+        totals
+    }
+
+    fn write_sizes(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:>10}", "Original")?;
+        for tool in &self.tools {
+            write!(f, " | {:>10}", tool.as_str())?;
+        }
+        writeln!(f, " | File")?;
+        for case in self.cases {
+            write!(f, "{:>10}", case.code.len())?;
+            for &tool in &self.tools {
+                let size = case.output(tool).map_or_else(
+                    || "-".to_string(),
+                    |output| {
+                        if output.valid {
+                            output.size.to_string()
+                        } else {
+                            format!("{} (invalid)", output.size)
+                        }
+                    },
+                );
+                write!(f, " | {size:>10}")?;
+            }
+            writeln!(f, " | {}", case.path.to_string_lossy())?;
+        }
+        Ok(())
+    }
+
+    fn write_regressions(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut regressions = self
+            .construct_totals()
+            .into_iter()
+            .filter_map(|(construct, sizes)| {
+                let best = sizes[1..].iter().min()?;
+                let regression = sizes[0].checked_sub(*best).filter(|&diff| diff > 0)?;
+                Some((construct, sizes, regression))
+            })
+            .collect::<Vec<_>>();
+        regressions.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(b.0)));
+
+        write!(f, "{:<40}", "Construct")?;
+        for tool in &self.tools {
+            write!(f, " | {:>10}", tool.as_str())?;
+        }
+        writeln!(f, " | {:>10}", "Regression")?;
+        for (construct, sizes, regression) in regressions.into_iter().take(REGRESSION_COUNT) {
+            write!(f, "{construct:<40}")?;
+            for size in sizes {
+                write!(f, " | {size:>10}")?;
+            }
+            writeln!(f, " | {regression:>10}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for DifferentialReport<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Minified sizes in bytes:\n")?;
+        self.write_sizes(f)?;
+        let missing = Tool::ALL
+            .into_iter()
+            .filter(|tool| !self.tools.contains(tool))
+            .map(Tool::as_str)
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            writeln!(f, "\nNot installed: {}", missing.join(", "))?;
+        }
+        if self.tools.len() > 1 {
+            writeln!(f, "\nBiggest size regressions by construct:\n")?;
+            self.write_regressions(f)?;
+        }
+        Ok(())
+    }
+}
@@ -9,6 +9,7 @@ mod test262_meta;
 mod typescript;
 // Tools
 mod codegen;
+mod differential;
 mod minifier;
 mod prettier;
 mod sourcemap;
@@ -24,6 +25,7 @@ use sourcemap::{SourcemapCase, SourcemapSuite};
 use crate::{
     babel::{BabelCase, BabelSuite},
     codegen::{CodegenBabelCase, CodegenMiscCase, CodegenTest262Case, CodegenTypeScriptCase},
+    differential::DifferentialSuite,
     minifier::{MinifierBabelCase, MinifierTest262Case},
     misc::{MiscCase, MiscSuite},
     prettier::{PrettierBabelCase, PrettierMiscCase, PrettierTest262Case, PrettierTypeScriptCase},
@@ -143,6 +145,11 @@ impl AppArgs {
         Test262Suite::<MinifierTest262Case>::new().run("minifier_test262", self);
         BabelSuite::<MinifierBabelCase>::new().run("minifier_babel", self);
     }
+
+    /// Compare the minifier against esbuild and terser, if they are installed.
+    pub fn run_minifier_differential(&self) {
+        DifferentialSuite::new().run("minifier_differential", self);
+    }
 }
 
 #[test]
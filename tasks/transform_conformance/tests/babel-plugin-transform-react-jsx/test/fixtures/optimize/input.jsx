@@ -0,0 +1,12 @@
+function App() {
+  return (
+    <div className="app">
+      <h1>Hello {"world"}</h1>
+      <Counter />
+    </div>
+  );
+}
+
+const Static = () => <p>static</p>;
+
+const element = <span>top level</span>;
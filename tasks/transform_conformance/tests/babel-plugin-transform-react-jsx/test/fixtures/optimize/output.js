@@ -0,0 +1,8 @@
+import { jsx as _jsx, jsxs as _jsxs } from "react/jsx-runtime";
+const _el = _jsx("h1", { children: "Hello world" });
+const _el2 = _jsx("p", { children: "static" });
+function App() {
+  return _jsxs("div", { className: "app", children: [_el, _jsx(Counter, {})] });
+}
+const Static = () => _el2;
+const element = _jsx("span", { children: "top level" });
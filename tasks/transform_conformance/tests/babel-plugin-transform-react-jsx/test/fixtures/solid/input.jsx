@@ -0,0 +1,21 @@
+const App = (props) => {
+  const [count, setCount] = createSignal(0);
+  let input;
+  return (
+    <div class="app" classList={{ active: props.active }}>
+      <h1 title={props.title}>Hello {props.name}!</h1>
+      <button onClick={() => setCount(count() + 1)} onMouseEnter={props.onEnter}>
+        Clicked {count()} times
+      </button>
+      <input ref={input} value={props.value} disabled />
+      <Counter initial={count()} label="count">
+        <span>{count()}</span>
+      </Counter>
+      {props.footer}
+    </div>
+  );
+};
+
+const Static = () => <p className="static">static &amp; "quoted"</p>;
+
+const List = (props) => <>{props.items}<Item {...props} name="a" /></>;
@@ -0,0 +1,36 @@
+import { classList as _$classList, effect as _$effect, setAttribute as _$setAttribute, insert as _$insert, use as _$use, template as _$template, createComponent as _$createComponent, mergeProps as _$mergeProps, delegateEvents as _$delegateEvents } from "solid-js/web";
+const _tmpl$2 = _$template("<span></span>");
+const _tmpl$ = _$template("<div class=\"app\"><h1>Hello <!>!</h1><button>Clicked <!> times</button><input disabled><!></div>");
+const _tmpl$3 = _$template("<p class=\"static\">static &amp; \"quoted\"</p>");
+const App = (props) => {
+  const [count, setCount] = createSignal(0);
+  let input;
+  return (() => {
+    const _el$ = _tmpl$(), _el$2 = _el$.firstChild, _el$3 = _el$2.firstChild.nextSibling, _el$4 = _el$2.nextSibling, _el$5 = _el$4.firstChild.nextSibling, _el$6 = _el$4.nextSibling, _el$7 = _el$6.nextSibling;
+    _$effect((_p$) => _$classList(_el$, { active: props.active }, _p$));
+    _$effect(() => _$setAttribute(_el$2, "title", props.title));
+    _$insert(_el$2, () => props.name, _el$3);
+    _el$4.$$click = () => setCount(count() + 1);
+    _el$4.addEventListener("mouseenter", props.onEnter);
+    typeof input === "function" ? _$use(input, _el$6) : input = _el$6;
+    _$effect(() => _el$6.value = props.value);
+    _$insert(_el$, _$createComponent(Counter, {
+      get initial() {
+        return count();
+      },
+      label: "count",
+      get children() {
+        return (() => {
+          const _el$8 = _tmpl$2();
+          _$insert(_el$8, count);
+          return _el$8;
+        })();
+      }
+    }), _el$7);
+    _$insert(_el$, () => props.footer, null);
+    return _el$;
+  })();
+};
+const Static = () => _tmpl$3();
+const List = (props) => [() => props.items, _$createComponent(Item, _$mergeProps(props, { name: "a" }))];
+_$delegateEvents(["click"]);
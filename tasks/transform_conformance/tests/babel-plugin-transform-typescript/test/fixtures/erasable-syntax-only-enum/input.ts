@@ -0,0 +1,3 @@
+const enum E {
+  A,
+}
@@ -0,0 +1,3 @@
+class C {
+  constructor(private x: number) {}
+}
@@ -0,0 +1,20 @@
+interface I {
+  a: number;
+}
+type T = I | string;
+namespace N {
+  export type U = T;
+}
+declare namespace D {
+  export const x: number;
+}
+declare enum E {
+  A,
+}
+class C {
+  declare b: string;
+  constructor(value: number) {}
+  f(x: T): I {
+    return x as I;
+  }
+}
@@ -0,0 +1,6 @@
+class C {
+  constructor(value) {}
+  f(x) {
+    return x;
+  }
+}
@@ -0,0 +1,9 @@
+namespace N {
+  function Foo() {}
+  namespace Foo {
+    export const x = 1;
+  }
+  import Bar = M.Bar;
+  export import Baz = M.Baz;
+  export const bar = Bar;
+}
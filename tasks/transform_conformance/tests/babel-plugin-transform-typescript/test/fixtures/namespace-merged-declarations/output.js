@@ -0,0 +1,10 @@
+let N;
+(function (_N) {
+  function Foo() {}
+  (function (_Foo) {
+    const x = _Foo.x = 1;
+  })(Foo || (Foo = {}));
+  var Bar = M.Bar;
+  var Baz = _N.Baz = M.Baz;
+  const bar = _N.bar = Bar;
+})(N || (N = {}));
@@ -0,0 +1,6 @@
+namespace E {
+  export const y = 1;
+}
+enum E {
+  A,
+}
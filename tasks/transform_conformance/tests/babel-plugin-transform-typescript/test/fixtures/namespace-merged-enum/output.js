@@ -0,0 +1,7 @@
+(function (_E) {
+  const y = _E.y = 1;
+})(E || (E = {}));
+var E = /*#__PURE__*/function (E) {
+  E[E["A"] = 0] = "A";
+  return E;
+}(E || {});
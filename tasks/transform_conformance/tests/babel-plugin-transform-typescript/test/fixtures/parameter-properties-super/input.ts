@@ -0,0 +1,17 @@
+class Base {
+  y = 1;
+  constructor(private x: number, public readonly z = 2) {
+    console.log(this.x);
+  }
+}
+
+class Derived extends Base {
+  w = 3;
+  constructor(protected a: number, override z: number) {
+    if (a) super(a, z);
+    else {
+      super(0, z);
+    }
+    this.log();
+  }
+}
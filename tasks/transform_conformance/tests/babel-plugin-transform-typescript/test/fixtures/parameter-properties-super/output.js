@@ -0,0 +1,23 @@
+class Base {
+  y = 1;
+  constructor(x, z = 2) {
+    this.x = x;
+    this.z = z;
+    console.log(this.x);
+  }
+}
+class Derived extends Base {
+  w = 3;
+  constructor(a, z) {
+    if (a) {
+      super(a, z);
+      this.a = a;
+      this.z = z;
+    } else {
+      super(0, z);
+      this.a = a;
+      this.z = z;
+    }
+    this.log();
+  }
+}
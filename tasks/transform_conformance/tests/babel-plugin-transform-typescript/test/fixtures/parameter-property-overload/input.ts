@@ -0,0 +1,4 @@
+class C {
+  constructor(private x: number);
+  constructor(x: number | string) {}
+}
@@ -0,0 +1,13 @@
+class A {
+  x = 1;
+  declare d: string;
+  static s = 2;
+  #p = 3;
+  "quoted" = 4;
+  uninitialized: number;
+  constructor(public a: number) {}
+}
+
+class B extends A {
+  y = this.a;
+}
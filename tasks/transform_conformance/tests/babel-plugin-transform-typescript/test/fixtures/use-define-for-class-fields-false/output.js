@@ -0,0 +1,15 @@
+class A {
+  static s = 2;
+  #p = 3;
+  constructor(a) {
+    this.a = a;
+    this.x = 1;
+    this["quoted"] = 4;
+  }
+}
+class B extends A {
+  constructor() {
+    super(...arguments);
+    this.y = this.a;
+  }
+}
@@ -5,8 +5,7 @@
  * @property {string[]} flags - The flags of the symbols.
  * @property {number[]} scopeIds - The scope IDs of the symbols.
  * @property {number[]} declarations - The declarations of the symbols.
- * @property {Array<number[]>} resolvedReferences - The resolved references of the symbols.
- * @property {Array<{span: {start: number, end: number}, name: string, node_id: number, symbol_id: number|null, flag: string}>} references - The references of the symbols.
+ * @property {Array<{span: {start: number, end: number}, name: string, nodeId: number, symbolId: number|null, flag: string}>} references - The references, with the symbols they resolve to.
  */
 
 /**
@@ -20,6 +19,12 @@ let cacheSymbols = null
  */
 export const renderSymbols = (symbols) => {
   const target = []
+  const resolvedReferences = symbols.declarations.map(() => [])
+  symbols.references.forEach((reference, referenceId) => {
+    if (reference.symbolId !== null) {
+      resolvedReferences[reference.symbolId].push({ referenceId, ...reference })
+    }
+  })
   symbols.declarations.forEach((nodeId, index) => {
     target.push({
       name: symbols.names[index],
@@ -27,7 +32,7 @@ export const renderSymbols = (symbols) => {
       symbolId: index,
       nodeId,
       span: symbols.spans[index],
-      references: symbols.resolvedReferences[index].map((id) => ({ referenceId: id, ...symbols.references[id] })),
+      references: resolvedReferences[index],
     })
   })
   cacheSymbols = target